@@ -0,0 +1,15 @@
+use burn_common::graph::Graph;
+
+use crate::runtime::{AutodiffClient, AutodiffClientImpl};
+
+/// Export the graph of the autodiff nodes that are still tracked, i.e. the nodes that are part of
+/// a future backward pass.
+pub fn graph() -> Graph {
+    AutodiffClientImpl::new().graph()
+}
+
+/// Save the autodiff graph to a file, as JSON when the file has the `json` extension and as DOT
+/// otherwise.
+pub fn save(path: impl AsRef<std::path::Path>) -> std::io::Result<()> {
+    graph().save(path)
+}
@@ -12,6 +12,54 @@ pub trait Step: Send + core::fmt::Debug {
     fn node(&self) -> NodeID;
     /// The parents of the node associated to the step.
     fn parents(&self) -> Vec<NodeID>;
+    /// The name of the step, used when exporting the graph.
+    fn name(&self) -> &'static str {
+        short_type_name(core::any::type_name_of_val(self))
+    }
 }
 
 pub type StepBoxed = Box<dyn Step>;
+
+/// Remove the module path and the generic arguments from a type name.
+pub(crate) fn short_type_name(name: &'static str) -> &'static str {
+    let mut end = name.len();
+
+    if name.ends_with('>') {
+        let mut depth = 0;
+
+        for (index, char) in name.char_indices().rev() {
+            match char {
+                '>' => depth += 1,
+                '<' => depth -= 1,
+                _ => {}
+            }
+
+            if depth == 0 {
+                end = index;
+                break;
+            }
+        }
+    }
+
+    let name = &name[..end];
+    name.rsplit("::").next().unwrap_or(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_shorten_type_names() {
+        assert_eq!(
+            short_type_name("burn_autodiff::tensor::RootStep"),
+            "RootStep"
+        );
+        assert_eq!(
+            short_type_name(
+                "burn_autodiff::ops::tensor::<impl burn_autodiff::Autodiff<B>>::float_add::Add<B>"
+            ),
+            "Add"
+        );
+    }
+}
@@ -31,6 +31,10 @@ mod backend;
 
 pub(crate) mod runtime;
 
+/// Export the autodiff graph for visualization.
+#[cfg(feature = "dump")]
+pub mod dump;
+
 pub use backend::*;
 
 #[cfg(feature = "export_tests")]
@@ -7,7 +7,7 @@ use crate::{
         strategy::CheckpointStrategy,
     },
     grads::Gradients,
-    graph::{ComputingProperty, NodeID, NodeRef, Requirement, Step},
+    graph::{ComputingProperty, NodeID, NodeRef, Requirement, Step, short_type_name},
     tensor::AutodiffTensor,
 };
 use alloc::{boxed::Box, vec::Vec};
@@ -268,6 +268,10 @@ where
     fn depth(&self) -> usize {
         self.ops.node.order
     }
+
+    fn name(&self) -> &'static str {
+        short_type_name(core::any::type_name::<T>())
+    }
 }
 
 #[derive(new, Debug)]
@@ -1,13 +1,19 @@
 use core::ops::Range;
 
 use burn_tensor::{
-    Device, Shape, TensorData,
+    Device, ElementConversion, Shape, TensorData, TensorMetadata,
     backend::Backend,
     ops::{FloatTensor, IntTensor, QTensorOps, QuantizedTensor},
-    quantization::{QuantScheme, QuantizationParametersPrimitive},
+    quantization::{QuantScheme, QuantizationParametersPrimitive, quantization_blocks},
 };
 
-use crate::{Autodiff, checkpoint::strategy::CheckpointStrategy};
+use crate::{
+    Autodiff,
+    checkpoint::{base::Checkpointer, strategy::CheckpointStrategy},
+    grads::Gradients,
+    graph::NodeID,
+    ops::{Backward, Ops, OpsKind},
+};
 
 impl<B: Backend, C: CheckpointStrategy> QTensorOps<Self> for Autodiff<B, C> {
     fn q_from_data(_data: TensorData, _device: &Device<Self>) -> QuantizedTensor<Self> {
@@ -33,6 +39,91 @@ impl<B: Backend, C: CheckpointStrategy> QTensorOps<Self> for Autodiff<B, C> {
         todo!()
     }
 
+    fn fake_quantize(
+        tensor: FloatTensor<Self>,
+        scheme: &QuantScheme,
+        qparams: QuantizationParametersPrimitive<Self>,
+    ) -> FloatTensor<Self> {
+        #[derive(Debug)]
+        struct FakeQuantize;
+
+        impl<B: Backend> Backward<B, 2> for FakeQuantize {
+            type State = (NodeID, NodeID, QuantScheme);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 2>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let [node_tensor, node_scales] = ops.parents;
+                let grad = grads.consume::<B>(&ops.node);
+
+                let (tensor_state, scales_state, scheme) = ops.state;
+                let tensor =
+                    checkpointer.retrieve_node_output::<B::FloatTensorPrimitive>(tensor_state);
+                let scales =
+                    checkpointer.retrieve_node_output::<B::FloatTensorPrimitive>(scales_state);
+                let shape = tensor.shape();
+                let shape_scales = scales.shape();
+                let (a, b) = scheme.value.range();
+
+                let (blocks, scales) = quantization_blocks::<B>(&scheme, tensor, scales);
+                let grad = B::float_reshape(grad, blocks.shape());
+                let values = B::float_div(blocks, scales);
+                let inside = B::bool_into_float(B::bool_and(
+                    B::float_greater_equal_elem(values.clone(), a.elem()),
+                    B::float_lower_equal_elem(values.clone(), b.elem()),
+                ));
+
+                if let Some(node) = node_scales {
+                    // LSQ: round(x / s) - x / s inside the range, the clamped bound otherwise.
+                    let quantized =
+                        B::float_clamp(B::float_round(values.clone()), a.elem(), b.elem());
+                    let value = B::float_sub(quantized, B::float_mul(values, inside.clone()));
+                    let grad = B::float_sum_dim(B::float_mul(grad.clone(), value), 1);
+                    grads.register::<B>(node.id, B::float_reshape(grad, shape_scales));
+                }
+
+                if let Some(node) = node_tensor {
+                    // Straight-through estimator, only for values inside the range.
+                    let grad = B::float_mul(grad, inside);
+                    grads.register::<B>(node.id, B::float_reshape(grad, shape));
+                }
+            }
+        }
+
+        let scales = qparams.scales;
+
+        match FakeQuantize
+            .prepare::<C>([tensor.node.clone(), scales.node.clone()])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let tensor_state = prep.checkpoint(&tensor);
+                let scales_state = prep.checkpoint(&scales);
+                prep.finish(
+                    (tensor_state, scales_state, *scheme),
+                    B::fake_quantize(
+                        tensor.primitive,
+                        scheme,
+                        QuantizationParametersPrimitive {
+                            scales: scales.primitive,
+                        },
+                    ),
+                )
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::fake_quantize(
+                tensor.primitive,
+                scheme,
+                QuantizationParametersPrimitive {
+                    scales: scales.primitive,
+                },
+            )),
+        }
+    }
+
     fn q_device(tensor: &QuantizedTensor<Self>) -> Device<Self> {
         B::q_device(tensor)
     }
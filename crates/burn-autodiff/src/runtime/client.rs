@@ -12,6 +12,9 @@ pub trait AutodiffClient: Send + Clone {
     fn register(&self, node_id: NodeRefCount, step: StepBoxed, actions: CheckpointerBuilder);
     /// Call backpropagation from the given tensor.
     fn backward<B: Backend>(&self, tensor: AutodiffTensor<B>) -> Gradients;
+    /// Export the graph of the nodes that are still tracked.
+    #[cfg(feature = "dump")]
+    fn graph(&self) -> burn_common::graph::Graph;
 }
 
 /// Client implementation in used.
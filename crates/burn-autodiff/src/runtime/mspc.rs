@@ -27,6 +27,10 @@ enum Message {
         grads: Gradients,
         callback: Sender<Gradients>,
     },
+    #[cfg(feature = "dump")]
+    Graph {
+        callback: Sender<burn_common::graph::Graph>,
+    },
 }
 impl ChannelClient {
     pub(crate) fn new() -> Self {
@@ -54,6 +58,10 @@ impl ChannelClient {
                         let grads = server.backward(grads, node_id);
                         callback.send(grads).unwrap();
                     }
+                    #[cfg(feature = "dump")]
+                    Message::Graph { callback } => {
+                        callback.send(server.graph()).unwrap();
+                    }
                 }
             }
         });
@@ -91,4 +99,16 @@ impl AutodiffClient for ChannelClient {
             Err(err) => panic!("Error during backward {err:?}"),
         }
     }
+
+    #[cfg(feature = "dump")]
+    fn graph(&self) -> burn_common::graph::Graph {
+        let (callback, receiver) = std::sync::mpsc::channel();
+
+        self.sender.send(Message::Graph { callback }).unwrap();
+
+        match receiver.recv() {
+            Ok(graph) => graph,
+            Err(err) => panic!("Error while exporting the graph {err:?}"),
+        }
+    }
 }
@@ -46,4 +46,12 @@ impl AutodiffClient for MutexClient {
 
         gradients
     }
+
+    #[cfg(feature = "dump")]
+    fn graph(&self) -> burn_common::graph::Graph {
+        match SERVER.lock().as_ref() {
+            Some(server) => server.graph(),
+            None => AutodiffServer::default().graph(),
+        }
+    }
 }
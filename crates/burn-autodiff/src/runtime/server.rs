@@ -51,6 +51,29 @@ impl AutodiffServer {
         gradients
     }
 
+    /// Export the graph of the nodes that are still tracked.
+    #[cfg(feature = "dump")]
+    pub fn graph(&self) -> burn_common::graph::Graph {
+        let mut graph = burn_common::graph::Graph::new("autodiff");
+
+        for (id, step) in self.steps.iter() {
+            graph.node(
+                id.value,
+                alloc::format!("{}\norder {}", step.name(), step.depth()),
+                "step",
+                None,
+            );
+
+            for parent in step.parents() {
+                if self.steps.contains_key(&parent) {
+                    graph.edge(parent.value, id.value, None);
+                }
+            }
+        }
+
+        graph
+    }
+
     fn build_tape(
         &mut self,
         node: NodeID,
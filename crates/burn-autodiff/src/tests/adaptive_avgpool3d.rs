@@ -0,0 +1,79 @@
+#[burn_tensor_testgen::testgen(ad_adaptive_avg_pool3d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::adaptive_avg_pool3d;
+    use burn_tensor::{Shape, Tensor, Tolerance};
+
+    #[test]
+    fn test_adaptive_avg_pool3d_simple() {
+        let test = AdaptiveAvgPool3dTestCase {
+            batch_size: 1,
+            channels: 1,
+            depth: 3,
+            height: 4,
+            width: 3,
+            output_size: [2, 3, 2],
+        };
+
+        test.assert_output(TestTensor::from_floats(
+            [[[
+                [
+                    [0.125, 0.25, 0.125],
+                    [0.25, 0.5, 0.25],
+                    [0.25, 0.5, 0.25],
+                    [0.125, 0.25, 0.125],
+                ],
+                [
+                    [0.25, 0.5, 0.25],
+                    [0.5, 1.0, 0.5],
+                    [0.5, 1.0, 0.5],
+                    [0.25, 0.5, 0.25],
+                ],
+                [
+                    [0.125, 0.25, 0.125],
+                    [0.25, 0.5, 0.25],
+                    [0.25, 0.5, 0.25],
+                    [0.125, 0.25, 0.125],
+                ],
+            ]]],
+            &Default::default(),
+        ));
+    }
+
+    struct AdaptiveAvgPool3dTestCase {
+        batch_size: usize,
+        channels: usize,
+        depth: usize,
+        height: usize,
+        width: usize,
+        output_size: [usize; 3],
+    }
+
+    impl AdaptiveAvgPool3dTestCase {
+        fn assert_output(self, x_grad: TestTensor<5>) {
+            let shape_x = Shape::new([
+                self.batch_size,
+                self.channels,
+                self.depth,
+                self.height,
+                self.width,
+            ]);
+            let device = Default::default();
+            let x = TestAutodiffTensor::from_data(
+                TestTensorInt::arange(0..shape_x.num_elements() as i64, &device)
+                    .reshape::<5, _>(shape_x)
+                    .into_data(),
+                &device,
+            )
+            .require_grad();
+            let output = adaptive_avg_pool3d(x.clone(), self.output_size);
+            let grads = output.backward();
+            let x_grad_actual = x.grad(&grads).unwrap();
+
+            x_grad.to_data().assert_approx_eq::<FloatType>(
+                &x_grad_actual.into_data(),
+                Tolerance::default().set_half_precision_relative(1e-3),
+            );
+        }
+    }
+}
@@ -0,0 +1,130 @@
+#[burn_tensor_testgen::testgen(ad_avg_pool3d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::avg_pool3d;
+    use burn_tensor::{Shape, Tensor, Tolerance};
+
+    #[test]
+    fn test_avg_pool3d_complex() {
+        let test = AvgPool3dTestCase {
+            batch_size: 1,
+            channels: 1,
+            kernel_size: [3, 3, 2],
+            padding: [1, 1, 1],
+            stride: [2, 2, 2],
+            depth: 3,
+            height: 4,
+            width: 4,
+            count_include_pad: true,
+        };
+
+        test.assert_output(TestTensor::from_floats(
+            [[[
+                [
+                    [0.05556, 0.05556, 0.05556, 0.05556],
+                    [0.11111, 0.11111, 0.11111, 0.11111],
+                    [0.05556, 0.05556, 0.05556, 0.05556],
+                    [0.05556, 0.05556, 0.05556, 0.05556],
+                ],
+                [
+                    [0.11111, 0.11111, 0.11111, 0.11111],
+                    [0.22222, 0.22222, 0.22222, 0.22222],
+                    [0.11111, 0.11111, 0.11111, 0.11111],
+                    [0.11111, 0.11111, 0.11111, 0.11111],
+                ],
+                [
+                    [0.05556, 0.05556, 0.05556, 0.05556],
+                    [0.11111, 0.11111, 0.11111, 0.11111],
+                    [0.05556, 0.05556, 0.05556, 0.05556],
+                    [0.05556, 0.05556, 0.05556, 0.05556],
+                ],
+            ]]],
+            &Default::default(),
+        ));
+    }
+
+    #[test]
+    fn test_avg_pool3d_complex_dont_include_pad() {
+        let test = AvgPool3dTestCase {
+            batch_size: 1,
+            channels: 1,
+            kernel_size: [3, 3, 2],
+            padding: [1, 1, 1],
+            stride: [2, 2, 2],
+            depth: 3,
+            height: 4,
+            width: 4,
+            count_include_pad: false,
+        };
+
+        test.assert_output(TestTensor::from_floats(
+            [[[
+                [
+                    [0.25, 0.125, 0.125, 0.25],
+                    [0.41667, 0.20833, 0.20833, 0.41667],
+                    [0.16667, 0.08333, 0.08333, 0.16667],
+                    [0.16667, 0.08333, 0.08333, 0.16667],
+                ],
+                [
+                    [0.5, 0.25, 0.25, 0.5],
+                    [0.83333, 0.41667, 0.41667, 0.83333],
+                    [0.33333, 0.16667, 0.16667, 0.33333],
+                    [0.33333, 0.16667, 0.16667, 0.33333],
+                ],
+                [
+                    [0.25, 0.125, 0.125, 0.25],
+                    [0.41667, 0.20833, 0.20833, 0.41667],
+                    [0.16667, 0.08333, 0.08333, 0.16667],
+                    [0.16667, 0.08333, 0.08333, 0.16667],
+                ],
+            ]]],
+            &Default::default(),
+        ));
+    }
+
+    struct AvgPool3dTestCase {
+        batch_size: usize,
+        channels: usize,
+        kernel_size: [usize; 3],
+        padding: [usize; 3],
+        stride: [usize; 3],
+        depth: usize,
+        height: usize,
+        width: usize,
+        count_include_pad: bool,
+    }
+
+    impl AvgPool3dTestCase {
+        fn assert_output(self, x_grad: TestTensor<5>) {
+            let shape_x = Shape::new([
+                self.batch_size,
+                self.channels,
+                self.depth,
+                self.height,
+                self.width,
+            ]);
+            let device = Default::default();
+            let x = TestAutodiffTensor::from_data(
+                TestTensorInt::arange(0..shape_x.num_elements() as i64, &device)
+                    .reshape::<5, _>(shape_x)
+                    .into_data(),
+                &device,
+            )
+            .require_grad();
+            let output = avg_pool3d(
+                x.clone(),
+                self.kernel_size,
+                self.stride,
+                self.padding,
+                self.count_include_pad,
+            );
+            let grads = output.backward();
+            let x_grad_actual = x.grad(&grads).unwrap();
+
+            x_grad.to_data().assert_approx_eq::<FloatType>(
+                &x_grad_actual.into_data(),
+                Tolerance::default().set_half_precision_relative(1e-3),
+            );
+        }
+    }
+}
@@ -0,0 +1,83 @@
+#[burn_tensor_testgen::testgen(ad_fake_quantize)]
+mod tests {
+    use super::*;
+    use burn_tensor::{
+        TensorData, Tolerance,
+        ops::{FloatElem, QuantizedTensor},
+        quantization::{QTensorPrimitive, QuantLevel, QuantValue, QuantizationParameters},
+    };
+
+    #[test]
+    fn should_diff_fake_quantize() {
+        let device = Default::default();
+        let scheme = QuantizedTensor::<TestBackend>::default_scheme()
+            .with_value(QuantValue::Q8S)
+            .with_level(QuantLevel::Tensor);
+        let tensor =
+            TestAutodiffTensor::<1>::from_floats([-0.5, 0.123, 0.5, 2.0], &device).require_grad();
+        let scales = TestAutodiffTensor::<1>::from_floats([0.01], &device).require_grad();
+
+        let output = tensor.clone().fake_quantize(
+            &scheme,
+            QuantizationParameters {
+                scales: scales.clone(),
+            },
+        );
+        let grads = output.clone().sum().backward();
+
+        output
+            .into_data()
+            .assert_approx_eq::<FloatElem<TestBackend>>(
+                &TensorData::from([-0.5, 0.12, 0.5, 1.27]),
+                Tolerance::default(),
+            );
+        // The clipped value doesn't propagate its gradient.
+        tensor
+            .grad(&grads)
+            .unwrap()
+            .to_data()
+            .assert_eq(&TensorData::from([1.0, 1.0, 1.0, 0.0]), false);
+        // LSQ: round(x / s) - x / s inside the range, the upper bound for the clipped value.
+        scales
+            .grad(&grads)
+            .unwrap()
+            .to_data()
+            .assert_approx_eq::<FloatElem<TestBackend>>(
+                &TensorData::from([126.7]),
+                Tolerance::rel_abs(1e-3, 1e-3),
+            );
+    }
+
+    #[test]
+    fn should_diff_fake_quantize_per_block() {
+        let device = Default::default();
+        let scheme = QuantizedTensor::<TestBackend>::default_scheme()
+            .with_value(QuantValue::Q8S)
+            .with_level(QuantLevel::Block(2));
+        let tensor =
+            TestAutodiffTensor::<2>::from_floats([[0.5, 2.0], [0.25, 2.0]], &device).require_grad();
+        let scales = TestAutodiffTensor::<1>::from_floats([0.01, 1.0], &device).require_grad();
+
+        let output = tensor.clone().fake_quantize(
+            &scheme,
+            QuantizationParameters {
+                scales: scales.clone(),
+            },
+        );
+        let grads = output.sum().backward();
+
+        tensor
+            .grad(&grads)
+            .unwrap()
+            .to_data()
+            .assert_eq(&TensorData::from([[1.0, 0.0], [1.0, 1.0]]), false);
+        scales
+            .grad(&grads)
+            .unwrap()
+            .to_data()
+            .assert_approx_eq::<FloatElem<TestBackend>>(
+                &TensorData::from([127.0, -0.25]),
+                Tolerance::rel_abs(1e-3, 1e-3),
+            );
+    }
+}
@@ -0,0 +1,59 @@
+#[burn_tensor_testgen::testgen(ad_max_pool3d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::max_pool3d;
+    use burn_tensor::{Shape, Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn test_max_pool3d_with_padding() {
+        let kernel_size = [2, 2, 2];
+        let padding = [1, 1, 1];
+        let stride = [1, 1, 1];
+        let dilation = [1, 1, 1];
+
+        let device = Default::default();
+        // Values shuffled with a multiplier coprime with the number of elements.
+        let x = TestAutodiffTensor::from_data(
+            TestTensorInt::arange(0..48, &device)
+                .mul_scalar(37)
+                .remainder_scalar(48)
+                .reshape::<5, _>(Shape::new([1, 1, 3, 4, 4]))
+                .into_data(),
+            &device,
+        )
+        .require_grad();
+        let x_grad_expected = TestAutodiffTensor::<5>::from_floats(
+            [[[
+                [
+                    [1.0, 3.0, 1.0, 1.0],
+                    [1.0, 3.0, 1.0, 1.0],
+                    [1.0, 7.0, 1.0, 1.0],
+                    [3.0, 0.0, 4.0, 2.0],
+                ],
+                [
+                    [1.0, 0.0, 3.0, 1.0],
+                    [1.0, 0.0, 7.0, 1.0],
+                    [1.0, 0.0, 0.0, 2.0],
+                    [3.0, 0.0, 0.0, 8.0],
+                ],
+                [
+                    [4.0, 1.0, 0.0, 8.0],
+                    [4.0, 1.0, 0.0, 0.0],
+                    [4.0, 1.0, 1.0, 1.0],
+                    [8.0, 4.0, 2.0, 2.0],
+                ],
+            ]]],
+            &device,
+        );
+
+        let output = max_pool3d(x.clone(), kernel_size, stride, padding, dilation);
+        let grads = output.backward();
+
+        // Asserts
+        let x_grad_actual = x.grad(&grads).unwrap();
+        x_grad_expected
+            .to_data()
+            .assert_approx_eq::<FT>(&x_grad_actual.to_data(), Tolerance::default());
+    }
+}
@@ -54,10 +54,13 @@ mod tests {
     // burn_tensor::testgen_module_conv_transpose2d!();
     // burn_tensor::testgen_module_max_pool1d!();
     // burn_tensor::testgen_module_max_pool2d!();
+    // burn_tensor::testgen_module_max_pool3d!();
     // burn_tensor::testgen_module_avg_pool1d!();
     // burn_tensor::testgen_module_avg_pool2d!();
+    // burn_tensor::testgen_module_avg_pool3d!();
     // burn_tensor::testgen_module_adaptive_avg_pool1d!();
     // burn_tensor::testgen_module_adaptive_avg_pool2d!();
+    // burn_tensor::testgen_module_adaptive_avg_pool3d!();
 
     // test ops
     burn_tensor::testgen_add!();
@@ -137,10 +140,13 @@ mod tests {
     // burn_autodiff::testgen_ad_conv_transpose2d!();
     // burn_autodiff::testgen_ad_max_pool1d!();
     // burn_autodiff::testgen_ad_max_pool2d!();
+    // burn_autodiff::testgen_ad_max_pool3d!();
     // burn_autodiff::testgen_ad_avg_pool1d!();
     // burn_autodiff::testgen_ad_avg_pool2d!();
+    // burn_autodiff::testgen_ad_avg_pool3d!();
     // burn_autodiff::testgen_ad_adaptive_avg_pool1d!();
     // burn_autodiff::testgen_ad_adaptive_avg_pool2d!();
+    // burn_autodiff::testgen_ad_adaptive_avg_pool3d!();
     burn_autodiff::testgen_module_backward!();
 
     // Tensor
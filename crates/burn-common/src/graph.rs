@@ -0,0 +1,214 @@
+use alloc::{format, string::String, vec::Vec};
+use core::fmt::Write;
+use serde::{Deserialize, Serialize};
+
+/// A directed graph that can be exported for visualization.
+///
+/// The same schema is used for every dump, so a single viewer can display fusion streams,
+/// execution plans and autodiff graphs.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct Graph {
+    /// The name of the graph.
+    pub name: String,
+    /// The nodes of the graph.
+    pub nodes: Vec<GraphNode>,
+    /// The edges of the graph.
+    pub edges: Vec<GraphEdge>,
+    /// The groups of nodes.
+    pub clusters: Vec<GraphCluster>,
+}
+
+/// A node of a [graph](Graph).
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct GraphNode {
+    /// The unique identifier of the node.
+    pub id: u64,
+    /// The text displayed for the node.
+    pub label: String,
+    /// The kind of node, used by viewers to style it.
+    pub kind: String,
+    /// The cluster containing the node, if any.
+    pub cluster: Option<u64>,
+}
+
+/// An edge between two [nodes](GraphNode).
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct GraphEdge {
+    /// The source node.
+    pub from: u64,
+    /// The target node.
+    pub to: u64,
+    /// The text displayed for the edge.
+    pub label: Option<String>,
+}
+
+/// A group of [nodes](GraphNode).
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct GraphCluster {
+    /// The unique identifier of the cluster.
+    pub id: u64,
+    /// The text displayed for the cluster.
+    pub label: String,
+    /// The parent cluster, if any.
+    pub parent: Option<u64>,
+}
+
+impl Graph {
+    /// Create an empty graph with the given name.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Add a node to the graph.
+    pub fn node(
+        &mut self,
+        id: u64,
+        label: impl Into<String>,
+        kind: impl Into<String>,
+        cluster: Option<u64>,
+    ) {
+        self.nodes.push(GraphNode {
+            id,
+            label: label.into(),
+            kind: kind.into(),
+            cluster,
+        });
+    }
+
+    /// Add an edge to the graph.
+    pub fn edge(&mut self, from: u64, to: u64, label: Option<String>) {
+        self.edges.push(GraphEdge { from, to, label });
+    }
+
+    /// Add a cluster to the graph.
+    pub fn cluster(&mut self, id: u64, label: impl Into<String>, parent: Option<u64>) {
+        self.clusters.push(GraphCluster {
+            id,
+            label: label.into(),
+            parent,
+        });
+    }
+
+    /// Format the graph using the [DOT](https://graphviz.org/doc/info/lang.html) language.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::new();
+
+        writeln!(dot, "digraph {} {{", quote(&self.name)).unwrap();
+        writeln!(dot, "  node [shape=box];").unwrap();
+
+        self.write_cluster(&mut dot, None, 1);
+
+        for edge in self.edges.iter() {
+            match &edge.label {
+                Some(label) => writeln!(
+                    dot,
+                    "  n{} -> n{} [label={}];",
+                    edge.from,
+                    edge.to,
+                    quote(label)
+                ),
+                None => writeln!(dot, "  n{} -> n{};", edge.from, edge.to),
+            }
+            .unwrap();
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+
+    fn write_cluster(&self, dot: &mut String, cluster: Option<u64>, depth: usize) {
+        let indent = "  ".repeat(depth);
+
+        for node in self.nodes.iter().filter(|node| node.cluster == cluster) {
+            writeln!(
+                dot,
+                "{indent}n{} [label={}, class={}];",
+                node.id,
+                quote(&node.label),
+                quote(&node.kind)
+            )
+            .unwrap();
+        }
+
+        for child in self.clusters.iter().filter(|child| child.parent == cluster) {
+            writeln!(dot, "{indent}subgraph cluster_{} {{", child.id).unwrap();
+            writeln!(dot, "{indent}  label={};", quote(&child.label)).unwrap();
+            self.write_cluster(dot, Some(child.id), depth + 1);
+            writeln!(dot, "{indent}}}").unwrap();
+        }
+    }
+
+    /// Format the graph as JSON.
+    #[cfg(feature = "dump")]
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("Graph should be serializable.")
+    }
+
+    /// Save the graph to a file.
+    ///
+    /// The graph is saved as JSON when the file has the `json` extension, and as DOT otherwise.
+    #[cfg(feature = "dump")]
+    pub fn save(&self, path: impl AsRef<std::path::Path>) -> std::io::Result<()> {
+        let path = path.as_ref();
+
+        let content = match path.extension().and_then(|ext| ext.to_str()) {
+            Some("json") => self.to_json(),
+            _ => self.to_dot(),
+        };
+
+        std::fs::write(path, content)
+    }
+}
+
+fn quote(value: &str) -> String {
+    let escaped = value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n");
+
+    format!("\"{escaped}\"")
+}
+
+impl core::fmt::Display for Graph {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&self.to_dot())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_format_clusters_and_edges_as_dot() {
+        let mut graph = Graph::new("plan");
+        graph.cluster(0, "elemwise", None);
+        graph.node(0, "Float::Exp", "operation", Some(0));
+        graph.node(1, "Base::Reshape", "operation", None);
+        graph.edge(0, 1, Some("t2".into()));
+
+        let expected = r#"digraph "plan" {
+  node [shape=box];
+  n1 [label="Base::Reshape", class="operation"];
+  subgraph cluster_0 {
+    label="elemwise";
+    n0 [label="Float::Exp", class="operation"];
+  }
+  n0 -> n1 [label="t2"];
+}
+"#;
+
+        assert_eq!(graph.to_dot(), expected);
+    }
+
+    #[test]
+    fn should_escape_labels() {
+        let mut graph = Graph::new("escape");
+        graph.node(0, "a \"b\"\nc", "node", None);
+
+        assert!(graph.to_dot().contains(r#"label="a \"b\"\nc""#));
+    }
+}
@@ -16,6 +16,9 @@ pub use rayon;
 
 extern crate alloc;
 
+/// Graph export utilities.
+pub mod graph;
+
 /// Network utilities.
 #[cfg(feature = "network")]
 pub mod network;
@@ -1,4 +1,6 @@
-use burn_tensor::backend::Backend;
+use burn_tensor::{
+    BasicOps, Bool, Element, ElementConversion, Int, Shape, Tensor, TensorData, backend::Backend,
+};
 
 #[cfg(test)]
 use crate::TestBackend;
@@ -18,6 +20,145 @@ pub trait Batcher<B: Backend, I, O>: Send + Sync {
     fn batch(&self, items: Vec<I>, device: &B::Device) -> O;
 }
 
+/// Variable-length sequences padded to the same length.
+///
+/// The mask follows the convention of the [padding mask](crate::nn::attention::GeneratePaddingMask),
+/// e.g. to be used as the [padding mask](crate::nn::attention::MhaInput::mask_pad) of attention
+/// modules.
+pub struct PaddedSequences<B: Backend, const D: usize, K> {
+    /// The padded sequences of shape `[batch_size, seq_length, ...]`.
+    pub tensor: Tensor<B, D, K>,
+
+    /// The mask of shape `[batch_size, seq_length]`, `true` at the padded positions.
+    pub mask: Tensor<B, 2, Bool>,
+}
+
+/// Pads variable-length sequences to the length of the longest one and stacks them, e.g. to
+/// collate the items of a [bucket batch strategy](super::BucketBatchStrategy).
+///
+/// # Arguments
+///
+/// * `sequences` - The sequences of shape `[seq_length, ...]`, whose lengths can differ.
+/// * `pad_value` - The value of the padded positions.
+/// * `max_seq_length` - The maximum length of the sequences, the longer ones are truncated.
+/// * `device` - The device of the padded sequences.
+///
+/// # Returns
+///
+/// The padded sequences of shape `[batch_size, seq_length, ...]`, with their padding mask.
+pub fn pad_sequences<B, const D: usize, const D2: usize, K, E>(
+    sequences: Vec<Tensor<B, D, K>>,
+    pad_value: E,
+    max_seq_length: Option<usize>,
+    device: &B::Device,
+) -> PaddedSequences<B, D2, K>
+where
+    B: Backend,
+    K: BasicOps<B>,
+    K::Elem: Element,
+    E: ElementConversion + Copy,
+{
+    assert!(
+        !sequences.is_empty(),
+        "There should be at least one sequence to pad"
+    );
+    assert_eq!(
+        D2,
+        D + 1,
+        "The padded sequences should have one more dimension"
+    );
+
+    let lengths = sequences
+        .iter()
+        .map(|sequence| {
+            let length = sequence.dims()[0];
+            max_seq_length.map_or(length, |max| length.min(max))
+        })
+        .collect::<Vec<_>>();
+    let seq_length = lengths.iter().copied().max().unwrap_or_default();
+
+    let sequences = sequences
+        .into_iter()
+        .zip(lengths.iter())
+        .map(|(sequence, length)| {
+            let mut sequence = sequence.to_device(device);
+            if sequence.dims()[0] > *length {
+                sequence = sequence.narrow(0, 0, *length);
+            }
+            if *length == seq_length {
+                return sequence;
+            }
+
+            let mut shape = sequence.dims();
+            shape[0] = seq_length - length;
+            let padding = Tensor::full(shape, pad_value, device);
+
+            Tensor::cat(alloc::vec![sequence, padding], 0)
+        })
+        .collect();
+
+    let mask = lengths
+        .iter()
+        .flat_map(|length| (0..seq_length).map(move |position| position >= *length))
+        .collect::<Vec<_>>();
+    let mask = Tensor::from_data(
+        TensorData::new(mask, Shape::new([lengths.len(), seq_length])),
+        device,
+    );
+
+    PaddedSequences {
+        tensor: Tensor::stack(sequences, 0),
+        mask,
+    }
+}
+
+/// A batch of token ids padded to the same length.
+#[derive(Clone, Debug)]
+pub struct TokenBatch<B: Backend> {
+    /// The token ids of shape `[batch_size, seq_length]`.
+    pub tokens: Tensor<B, 2, Int>,
+
+    /// The padding mask of shape `[batch_size, seq_length]`, `true` at the padded positions.
+    ///
+    /// The attention mask of other libraries, `1` at the tokens, is `mask_pad.bool_not().int()`.
+    pub mask_pad: Tensor<B, 2, Bool>,
+}
+
+/// Batcher padding the token ids of tokenized texts, e.g. produced by the `TokenizerMapper` of
+/// the text datasets.
+#[derive(new, Clone, Debug)]
+pub struct TokenBatcher {
+    /// The id of the padding token.
+    pad_id: usize,
+    /// The maximum length of the sequences, the longer ones are truncated.
+    max_seq_length: Option<usize>,
+}
+
+impl<B: Backend> Batcher<B, Vec<usize>, TokenBatch<B>> for TokenBatcher {
+    fn batch(&self, items: Vec<Vec<usize>>, device: &B::Device) -> TokenBatch<B> {
+        let sequences = items
+            .into_iter()
+            .map(|ids| {
+                let length = ids.len();
+                let ids = ids.into_iter().map(|id| id as i64).collect::<Vec<_>>();
+                Tensor::from_data(TensorData::new(ids, [length]), device)
+            })
+            .collect();
+
+        let padded = pad_sequences::<B, 1, 2, Int, _>(
+            sequences,
+            self.pad_id as i64,
+            self.max_seq_length,
+            device,
+        );
+
+        TokenBatch {
+            tokens: padded.tensor,
+            mask_pad: padded.mask,
+        }
+    }
+}
+
 /// Test batcher
 #[cfg(test)]
 #[derive(new, Clone)]
@@ -29,3 +170,75 @@ impl<I> Batcher<TestBackend, I, Vec<I>> for TestBatcher {
         items
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Tolerance, ops::FloatElem};
+
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn should_pad_sequences_with_mask() {
+        let device = Default::default();
+        let sequences = vec![
+            Tensor::<TestBackend, 1, Int>::from_ints([1, 2, 3], &device),
+            Tensor::from_ints([4], &device),
+            Tensor::from_ints([5, 6], &device),
+        ];
+
+        let padded = pad_sequences::<_, 1, 2, _, _>(sequences, 0, None, &device);
+
+        padded
+            .tensor
+            .into_data()
+            .assert_eq(&TensorData::from([[1, 2, 3], [4, 0, 0], [5, 6, 0]]), false);
+        padded.mask.into_data().assert_eq(
+            &TensorData::from([
+                [false, false, false],
+                [false, true, true],
+                [false, false, true],
+            ]),
+            false,
+        );
+    }
+
+    #[test]
+    fn should_pad_and_truncate_feature_sequences() {
+        let device = Default::default();
+        let sequences = vec![
+            Tensor::<TestBackend, 2>::from_floats([[1.0, 1.0], [2.0, 2.0], [3.0, 3.0]], &device),
+            Tensor::from_floats([[4.0, 4.0]], &device),
+        ];
+
+        let padded: PaddedSequences<TestBackend, 3, _> =
+            pad_sequences(sequences, -1.0, Some(2), &device);
+
+        padded.tensor.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([[[1.0, 1.0], [2.0, 2.0]], [[4.0, 4.0], [-1.0, -1.0]]]),
+            Tolerance::default(),
+        );
+        padded
+            .mask
+            .into_data()
+            .assert_eq(&TensorData::from([[false, false], [false, true]]), false);
+    }
+
+    #[test]
+    fn should_batch_token_ids() {
+        let device = Default::default();
+        let batcher = TokenBatcher::new(0, Some(3));
+
+        let batch: TokenBatch<TestBackend> =
+            batcher.batch(vec![vec![5, 6, 7, 8], vec![9]], &device);
+
+        batch
+            .tokens
+            .into_data()
+            .assert_eq(&TensorData::from([[5, 6, 7], [9, 0, 0]]), false);
+        batch.mask_pad.into_data().assert_eq(
+            &TensorData::from([[false, false, false], [false, true, true]]),
+            false,
+        );
+    }
+}
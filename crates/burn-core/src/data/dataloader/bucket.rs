@@ -0,0 +1,195 @@
+use super::BatchStrategy;
+use std::{collections::VecDeque, sync::Arc};
+
+/// A strategy to batch items of similar lengths under a token budget.
+///
+/// The items are grouped in buckets by their length, and a bucket is batched when adding an item
+/// would make the padded batch exceed the token budget, i.e. when the number of items times the
+/// length of the longest item would be greater than `max_tokens`. Batching items of similar lengths
+/// minimizes the padding of variable-length sequences.
+///
+/// Since the batches don't have a fixed size, the dataset is split evenly between the workers of a
+/// [multi-threaded data loader](super::MultiThreadDataLoader).
+pub struct BucketBatchStrategy<I> {
+    length: Arc<dyn Fn(&I) -> usize + Send + Sync>,
+    max_tokens: usize,
+    boundaries: Vec<usize>,
+    buckets: Vec<Bucket<I>>,
+    batches: VecDeque<Vec<I>>,
+}
+
+struct Bucket<I> {
+    items: Vec<I>,
+    max_length: usize,
+}
+
+impl<I> Bucket<I> {
+    fn new() -> Self {
+        Self {
+            items: Vec::new(),
+            max_length: 0,
+        }
+    }
+
+    fn take(&mut self) -> Vec<I> {
+        self.max_length = 0;
+        std::mem::take(&mut self.items)
+    }
+}
+
+impl<I> BucketBatchStrategy<I> {
+    /// Creates a new strategy to batch items of similar lengths under a token budget.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_tokens` - The maximum number of tokens of a padded batch. An item longer than the
+    ///   budget is batched alone.
+    /// * `boundaries` - The upper length bounds of the buckets, in increasing order. The items
+    ///   longer than the last boundary are grouped in an additional bucket.
+    /// * `length` - The function returning the length of an item, e.g. its number of tokens.
+    ///
+    /// # Returns
+    ///
+    /// The strategy.
+    pub fn new<F>(max_tokens: usize, boundaries: Vec<usize>, length: F) -> Self
+    where
+        F: Fn(&I) -> usize + Send + Sync + 'static,
+    {
+        Self::with_length(max_tokens, boundaries, Arc::new(length))
+    }
+
+    fn with_length(
+        max_tokens: usize,
+        boundaries: Vec<usize>,
+        length: Arc<dyn Fn(&I) -> usize + Send + Sync>,
+    ) -> Self {
+        assert!(max_tokens > 0, "The token budget should be greater than 0");
+        assert!(
+            boundaries.windows(2).all(|bounds| bounds[0] < bounds[1]),
+            "The bucket boundaries should be in increasing order, got {boundaries:?}"
+        );
+
+        Self {
+            length,
+            max_tokens,
+            buckets: (0..=boundaries.len()).map(|_| Bucket::new()).collect(),
+            boundaries,
+            batches: VecDeque::new(),
+        }
+    }
+}
+
+impl<I: Send + Sync + 'static> BatchStrategy<I> for BucketBatchStrategy<I> {
+    fn add(&mut self, item: I) {
+        let length = (self.length)(&item);
+        let index = self.boundaries.partition_point(|bound| *bound < length);
+        let bucket = &mut self.buckets[index];
+
+        let max_length = bucket.max_length.max(length);
+        if !bucket.items.is_empty() && (bucket.items.len() + 1) * max_length > self.max_tokens {
+            self.batches.push_back(bucket.take());
+        }
+
+        bucket.max_length = bucket.max_length.max(length);
+        bucket.items.push(item);
+
+        if bucket.items.len() * bucket.max_length >= self.max_tokens {
+            self.batches.push_back(bucket.take());
+        }
+    }
+
+    fn batch(&mut self, force: bool) -> Option<Vec<I>> {
+        if let Some(items) = self.batches.pop_front() {
+            return Some(items);
+        }
+
+        if !force {
+            return None;
+        }
+
+        self.buckets
+            .iter_mut()
+            .find(|bucket| !bucket.items.is_empty())
+            .map(Bucket::take)
+    }
+
+    fn clone_dyn(&self) -> Box<dyn BatchStrategy<I>> {
+        Box::new(Self::with_length(
+            self.max_tokens,
+            self.boundaries.clone(),
+            self.length.clone(),
+        ))
+    }
+
+    fn batch_size(&self) -> Option<usize> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::dataloader::{DataLoaderBuilder, batcher::TestBatcher};
+    use crate::data::dataset::InMemDataset;
+
+    fn lengths(batch: Vec<String>) -> Vec<usize> {
+        batch.iter().map(String::len).collect()
+    }
+
+    #[test]
+    fn should_batch_items_of_similar_lengths() {
+        let mut strategy = BucketBatchStrategy::new(8, vec![2, 4], |item: &String| item.len());
+
+        for item in ["a", "abcd", "bc", "abc", "d", "e", "xy"] {
+            strategy.add(item.to_string());
+        }
+
+        // The two items of lengths 3 and 4, then the four items of lengths 1 and 2 fill the budget.
+        assert_eq!(lengths(strategy.batch(false).unwrap()), [4, 3]);
+        assert_eq!(lengths(strategy.batch(false).unwrap()), [1, 2, 1, 1]);
+        assert_eq!(strategy.batch(false), None);
+
+        strategy.add("efgh".to_string());
+        strategy.add("z".to_string());
+        assert_eq!(strategy.batch(false), None);
+
+        assert_eq!(lengths(strategy.batch(true).unwrap()), [2, 1]);
+        assert_eq!(lengths(strategy.batch(true).unwrap()), [4]);
+        assert_eq!(strategy.batch(true), None);
+    }
+
+    #[test]
+    fn should_batch_long_items_alone() {
+        let mut strategy = BucketBatchStrategy::new(4, vec![], |item: &String| item.len());
+
+        strategy.add("ab".to_string());
+        strategy.add("abcdef".to_string());
+
+        assert_eq!(lengths(strategy.batch(false).unwrap()), [2]);
+        assert_eq!(lengths(strategy.batch(false).unwrap()), [6]);
+        assert_eq!(strategy.batch(true), None);
+    }
+
+    #[test]
+    fn should_load_all_items_with_workers() {
+        let items = (1..=20)
+            .map(|length| "a".repeat(length))
+            .collect::<Vec<_>>();
+        let dataloader = DataLoaderBuilder::new(TestBatcher::new())
+            .batch_strategy(BucketBatchStrategy::new(
+                24,
+                vec![5, 10, 15],
+                |item: &String| item.len(),
+            ))
+            .num_workers(2)
+            .build(InMemDataset::new(items));
+
+        let mut total = 0;
+        for batch in dataloader.iter() {
+            let max_length = batch.iter().map(String::len).max().unwrap();
+            assert!(batch.len() == 1 || batch.len() * max_length <= 24);
+            total += batch.len();
+        }
+        assert_eq!(total, 20);
+    }
+}
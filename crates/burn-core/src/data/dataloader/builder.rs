@@ -60,6 +60,24 @@ where
         self
     }
 
+    /// Sets the strategy to batch items, e.g. a [bucket batch strategy](super::BucketBatchStrategy)
+    /// batching items of similar lengths.
+    ///
+    /// # Arguments
+    ///
+    /// * `strategy` - The batch strategy.
+    ///
+    /// # Returns
+    ///
+    /// The data loader builder.
+    pub fn batch_strategy<S>(mut self, strategy: S) -> Self
+    where
+        S: BatchStrategy<I> + 'static,
+    {
+        self.strategy = Some(Box::new(strategy));
+        self
+    }
+
     /// Sets the seed for shuffling.
     ///
     /// Each time the dataloader starts a new iteration, the dataset will be shuffled.
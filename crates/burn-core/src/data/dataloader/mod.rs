@@ -1,5 +1,6 @@
 mod base;
 mod batch;
+mod bucket;
 mod builder;
 mod multithread;
 mod strategy;
@@ -11,6 +12,7 @@ pub mod split;
 
 pub use base::*;
 pub use batch::*;
+pub use bucket::*;
 pub use builder::*;
 pub use multithread::*;
 pub use strategy::*;
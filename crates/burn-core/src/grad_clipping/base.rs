@@ -1,6 +1,6 @@
 use crate as burn;
 
-use crate::{config::Config, tensor::Tensor};
+use crate::{config::Config, optim::GradientsParams, tensor::Tensor};
 use burn_tensor::backend::Backend;
 
 /// Gradient Clipping provides a way to mitigate exploding gradients
@@ -56,6 +56,27 @@ impl GradientClipping {
         }
     }
 
+    /// Scale all the gradients jointly so that their global L2 norm doesn't exceed `max_norm`.
+    ///
+    /// Unlike the other clipping methods, it needs every gradient at once, so it isn't applied by
+    /// the optimizers but before the optimizer step, e.g. by the learner. When training with data
+    /// parallelism, this must be called after the gradients are all-reduced so that every device
+    /// applies the same scaling.
+    ///
+    /// # Returns
+    ///
+    /// The global norm before clipping, or `None` if there is no gradient.
+    pub fn clip_gradients_global_norm<B: Backend>(
+        grads: &mut GradientsParams,
+        max_norm: f32,
+    ) -> Option<Tensor<B, 1>> {
+        let norm = grads.l2_norm::<B>()?;
+        let clip_coef = max_norm / norm.clone().add_scalar(1e-6); // avoid div by zero
+        grads.scale(clip_coef.clamp_max(1.0));
+
+        Some(norm)
+    }
+
     fn clip_by_value<B: Backend, const D: usize>(
         &self,
         grad: Tensor<B, D>,
@@ -91,7 +112,8 @@ impl GradientClipping {
 mod tests {
     use super::*;
     use crate::TestBackend;
-    use crate::tensor::Tensor;
+    use crate::module::ParamId;
+    use crate::tensor::{Tensor, TensorData, Tolerance};
 
     #[test]
     fn test_clip_by_value() {
@@ -128,6 +150,56 @@ mod tests {
             assert!(value <= 0.88);
         }
     }
+
+    #[test]
+    fn test_clip_gradients_global_norm() {
+        let device = Default::default();
+        let id_1 = ParamId::new();
+        let id_2 = ParamId::new();
+        let mut grads = GradientsParams::new();
+        grads.register(
+            id_1,
+            Tensor::<TestBackend, 2>::from_floats([[3.0, 0.0], [0.0, 4.0]], &device),
+        );
+        grads.register(
+            id_2,
+            Tensor::<TestBackend, 1>::from_floats([0.0, 12.0], &device),
+        );
+
+        let norm =
+            GradientClipping::clip_gradients_global_norm::<TestBackend>(&mut grads, 6.5).unwrap();
+
+        let tolerance = Tolerance::default();
+        norm.into_data()
+            .assert_approx_eq::<f32>(&TensorData::from([13.0]), tolerance);
+        grads
+            .get::<TestBackend, 2>(id_1)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq::<f32>(&TensorData::from([[1.5, 0.0], [0.0, 2.0]]), tolerance);
+        grads
+            .get::<TestBackend, 1>(id_2)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq::<f32>(&TensorData::from([0.0, 6.0]), tolerance);
+    }
+
+    #[test]
+    fn test_clip_gradients_global_norm_no_clipping() {
+        let id = ParamId::new();
+        let gradient = Tensor::<TestBackend, 1>::from_floats([0.3, 0.4], &Default::default());
+        let mut grads = GradientsParams::new();
+        grads.register(id, gradient.clone());
+
+        GradientClipping::clip_gradients_global_norm::<TestBackend>(&mut grads, 1.0);
+
+        grads
+            .get::<TestBackend, 1>(id)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq::<f32>(&gradient.into_data(), Tolerance::default());
+    }
+
     #[test]
     fn test_clip_by_norm_no_clipping() {
         let gradient: Tensor<TestBackend, 2> = Tensor::from_floats(
@@ -1,4 +1,4 @@
-use super::{ParamId, Quantizer};
+use super::{FakeQuantizer, ParamId, Quantizer};
 use crate::{
     record::Record,
     tensor::backend::{AutodiffBackend, Backend},
@@ -207,6 +207,11 @@ pub trait Module<B: Backend>: Clone + Send + core::fmt::Debug {
     fn quantize_weights(self, quantizer: &mut Quantizer) -> Self {
         self.map(quantizer)
     }
+
+    /// Fake quantize the weights of the module for quantization-aware training.
+    fn fake_quantize_weights(self, quantizer: &mut FakeQuantizer) -> Self {
+        self.map(quantizer)
+    }
 }
 
 /// Module visitor trait.
@@ -217,6 +222,10 @@ pub trait ModuleVisitor<B: Backend> {
     fn visit_int<const D: usize>(&mut self, _id: ParamId, _tensor: &Tensor<B, D, Int>) {}
     /// Visit a bool tensor in the module.
     fn visit_bool<const D: usize>(&mut self, _id: ParamId, _tensor: &Tensor<B, D, Bool>) {}
+    /// Enter a submodule, named after its struct field or its index in a collection.
+    fn enter_module(&mut self, _name: &str) {}
+    /// Exit the submodule entered with [enter_module](ModuleVisitor::enter_module).
+    fn exit_module(&mut self, _name: &str) {}
 }
 
 /// Module mapper trait.
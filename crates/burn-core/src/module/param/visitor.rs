@@ -1,6 +1,6 @@
 use super::ParamId;
 use crate::module::{Module, ModuleVisitor};
-use alloc::vec::Vec;
+use alloc::{string::String, vec::Vec};
 use burn_tensor::{Bool, Int, Tensor, backend::Backend};
 use core::marker::PhantomData;
 
@@ -36,3 +36,112 @@ pub fn list_param_ids<M: Module<B>, B: Backend>(module: &M) -> Vec<ParamId> {
 
     params_ids
 }
+
+struct ParamPathCollector<'a, M> {
+    path: Vec<String>,
+    params: &'a mut Vec<(String, ParamId)>,
+    phantom: PhantomData<M>,
+}
+
+impl<M> ParamPathCollector<'_, M> {
+    fn register(&mut self, id: ParamId) {
+        self.params.push((self.path.join("."), id));
+    }
+}
+
+impl<B, M> ModuleVisitor<B> for ParamPathCollector<'_, M>
+where
+    B: Backend,
+    M: Module<B>,
+{
+    fn visit_float<const D: usize>(&mut self, id: ParamId, _tensor: &Tensor<B, D>) {
+        self.register(id);
+    }
+    fn visit_int<const D: usize>(&mut self, id: ParamId, _tensor: &Tensor<B, D, Int>) {
+        self.register(id);
+    }
+    fn visit_bool<const D: usize>(&mut self, id: ParamId, _tensor: &Tensor<B, D, Bool>) {
+        self.register(id);
+    }
+    fn enter_module(&mut self, name: &str) {
+        self.path.push(name.into());
+    }
+    fn exit_module(&mut self, _name: &str) {
+        self.path.pop();
+    }
+}
+
+/// List all the parameters in a module with their path, e.g. `encoder.layers.0.weight`.
+pub fn list_param_paths<M: Module<B>, B: Backend>(module: &M) -> Vec<(String, ParamId)> {
+    let mut params = Vec::new();
+    let mut visitor = ParamPathCollector {
+        path: Vec::new(),
+        params: &mut params,
+        phantom: PhantomData::<M>,
+    };
+    module.visit(&mut visitor);
+
+    params
+}
+
+/// If the [path](list_param_paths) matches the pattern, where `*` matches any sequence of characters.
+pub(crate) fn matches_pattern(pattern: &str, path: &str) -> bool {
+    let Some((prefix, rest)) = pattern.split_once('*') else {
+        return pattern == path;
+    };
+    let Some(path) = path.strip_prefix(prefix) else {
+        return false;
+    };
+
+    (0..=path.len())
+        .filter(|index| path.is_char_boundary(*index))
+        .any(|index| matches_pattern(rest, &path[index..]))
+}
+
+struct WeightCollector {
+    path: Vec<String>,
+    weights: Vec<(String, ParamId, Vec<usize>)>,
+}
+
+impl<B: Backend> ModuleVisitor<B> for WeightCollector {
+    fn visit_float<const D: usize>(&mut self, id: ParamId, tensor: &Tensor<B, D>) {
+        if D >= 2 && self.path.last().is_some_and(|name| name == "weight") {
+            self.weights
+                .push((self.path.join("."), id, tensor.dims().to_vec()));
+        }
+    }
+    fn enter_module(&mut self, name: &str) {
+        self.path.push(name.into());
+    }
+    fn exit_module(&mut self, _name: &str) {
+        self.path.pop();
+    }
+}
+
+/// List the weights of the modules whose path matches one of the [patterns](matches_pattern), with
+/// their path and shape.
+///
+/// The weights are the float parameters named `weight` with at least two dimensions.
+pub(crate) fn list_weights<M: Module<B>, B: Backend>(
+    module: &M,
+    patterns: &[String],
+) -> Vec<(String, ParamId, Vec<usize>)> {
+    let mut collector = WeightCollector {
+        path: Vec::new(),
+        weights: Vec::new(),
+    };
+    module.visit(&mut collector);
+
+    collector
+        .weights
+        .into_iter()
+        .filter(|(path, _, _)| {
+            let module_path = path.strip_suffix("weight").unwrap_or_default();
+            let module_path = module_path.strip_suffix('.').unwrap_or(module_path);
+
+            patterns
+                .iter()
+                .any(|pattern| matches_pattern(pattern, module_path))
+        })
+        .collect()
+}
@@ -1,10 +1,12 @@
 use burn_tensor::{
     Tensor,
     backend::Backend,
-    quantization::{Calibration, QuantScheme, compute_q_params, compute_range},
+    quantization::{
+        Calibration, QuantScheme, QuantizationParameters, compute_q_params, compute_range,
+    },
 };
 
-use crate::module::{ModuleMapper, ParamId};
+use crate::module::{Module, ModuleMapper, ParamId};
 
 /// Describes how to quantize a module.
 pub struct Quantizer {
@@ -22,11 +24,85 @@ impl<B: Backend> ModuleMapper<B> for Quantizer {
     }
 }
 
+/// Describes how to fake quantize the weights of a module during quantization-aware training.
+///
+/// The weights are quantized and dequantized on each forward pass, so the model learns to
+/// compensate the quantization error, while gradients update the full precision weights through a
+/// straight-through estimator.
+///
+/// Only weights with at least two dimensions are fake quantized, biases and other vectors are
+/// kept in full precision.
+///
+/// The scales are computed from the detached weights with the [calibration](Calibration) on each
+/// pass, so they aren't learned. Learnable [LSQ](https://arxiv.org/abs/1902.08153) scales are only
+/// available for activations, see [FakeQuantize](crate::nn::FakeQuantize).
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let mut quantizer = FakeQuantizer { calibration: Calibration::MinMax, scheme };
+///
+/// // Training step, the mapped module keeps the parameter ids of the model.
+/// let loss = model.clone().fake_quantize_weights(&mut quantizer).forward(input);
+/// let grads = GradientsParams::from_grads(loss.backward(), &model);
+/// let model = optim.step(lr, model, grads);
+///
+/// // Once trained.
+/// let model = quantizer.quantize_module(model.valid());
+/// ```
+pub struct FakeQuantizer {
+    /// The calibration method used in quantization.
+    pub calibration: Calibration,
+    /// The quantization scheme.
+    pub scheme: QuantScheme,
+}
+
+impl FakeQuantizer {
+    /// Quantize the weights of a module trained with fake quantization, using the same scheme.
+    pub fn quantize_module<B: Backend, M: Module<B>>(&self, module: M) -> M {
+        module.map(&mut QatConversion { quantizer: self })
+    }
+
+    fn qparams<B: Backend, const D: usize>(
+        &self,
+        tensor: &Tensor<B, D>,
+    ) -> QuantizationParameters<B> {
+        let range = compute_range(&self.scheme, tensor, &self.calibration);
+        compute_q_params(&self.scheme, range)
+    }
+}
+
+impl<B: Backend> ModuleMapper<B> for FakeQuantizer {
+    fn map_float<const D: usize>(&mut self, _id: ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        if D < 2 {
+            return tensor;
+        }
+
+        let qparams = self.qparams(&tensor.clone().detach());
+        tensor.fake_quantize(&self.scheme, qparams)
+    }
+}
+
+struct QatConversion<'a> {
+    quantizer: &'a FakeQuantizer,
+}
+
+impl<B: Backend> ModuleMapper<B> for QatConversion<'_> {
+    fn map_float<const D: usize>(&mut self, _id: ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        if D < 2 {
+            return tensor;
+        }
+
+        let qparams = self.quantizer.qparams(&tensor);
+        tensor.quantize(&self.quantizer.scheme, qparams)
+    }
+}
+
 #[cfg(all(test, not(feature = "test-tch")))]
 mod tests {
     use crate::{
         TestBackend,
-        module::{Module, Quantizer},
+        module::{FakeQuantizer, Module, Quantizer},
         nn::{
             Linear, LinearConfig,
             transformer::{TransformerEncoder, TransformerEncoderConfig, TransformerEncoderInput},
@@ -174,4 +250,29 @@ mod tests {
             Tolerance::permissive(),
         );
     }
+
+    #[test]
+    fn should_quantize_fake_quantized_linear() {
+        let device: Device<B> = Default::default();
+        let linear: Linear<B> = LinearConfig::new(32, 32).init(&device);
+        let signal = Tensor::<B, 2>::random([1, 32], Distribution::Default, &device);
+        let scheme = <QuantizedTensor<B> as QTensorPrimitive>::default_scheme()
+            .with_value(QuantValue::Q8S)
+            .with_level(QuantLevel::Tensor)
+            .with_param(QuantParam::F32);
+        let mut quantizer = FakeQuantizer {
+            calibration: Calibration::MinMax,
+            scheme,
+        };
+
+        let result = linear
+            .clone()
+            .fake_quantize_weights(&mut quantizer)
+            .forward(signal.clone());
+        let q_result = quantizer.quantize_module(linear).forward(signal);
+
+        result
+            .into_data()
+            .assert_approx_eq::<f32>(&q_result.into_data(), Tolerance::permissive());
+    }
 }
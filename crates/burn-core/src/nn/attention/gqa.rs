@@ -0,0 +1,512 @@
+use crate as burn;
+
+use crate::module::{Content, DisplaySettings, Module, ModuleDisplay};
+use crate::nn::attention::{MhaInput, MhaOutput};
+use crate::nn::cache::KvCache;
+use crate::nn::{
+    Dropout, DropoutConfig, Initializer, Linear, LinearConfig, RotaryEncoding, RotaryEncodingConfig,
+};
+use crate::{
+    config::Config,
+    tensor::{Bool, Int, Tensor, backend::Backend},
+};
+
+use burn_tensor::activation::{quiet_softmax, softmax};
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use num_traits::Float as _;
+
+/// Configuration to create a [Grouped Query Attention](GroupedQueryAttention) layer using the [init function](GroupedQueryAttentionConfig::init).
+#[derive(Config, Debug)]
+pub struct GroupedQueryAttentionConfig {
+    /// The size of the input and output features.
+    pub d_model: usize,
+    /// The number of query heads.
+    pub n_heads: usize,
+    /// The number of key and value heads, which must divide the number of query heads.
+    ///
+    /// Equal to `n_heads` for multi-head attention and to `1` for multi-query attention.
+    pub n_kv_heads: usize,
+    /// The size of each head. Default: `d_model / n_heads`
+    #[config(default = "None")]
+    pub d_head: Option<usize>,
+    /// The dropout rate. Default: 0.0
+    #[config(default = 0.0)]
+    pub dropout: f64,
+    /// The minimum value a float can take. Default: -1.0e4
+    /// This is used to mask attention scores before calculating attention weights.
+    /// A value too low might result in NaN.
+    #[config(default = -1.0e4)]
+    pub min_float: f64,
+    /// Use "quiet softmax" instead of regular softmax.
+    #[config(default = false)]
+    pub quiet_softmax: bool,
+    /// If the linear layers have a bias. Default: false
+    #[config(default = false)]
+    pub bias: bool,
+    /// The rotary encoding applied to the queries and keys of each head, its `d_model` must be the
+    /// size of each head. Default: None
+    #[config(default = "None")]
+    pub rotary: Option<RotaryEncodingConfig>,
+    /// Restricts each token to attend to itself and the previous `window - 1` tokens, which also
+    /// makes the attention causal. Default: None
+    #[config(default = "None")]
+    pub sliding_window: Option<usize>,
+    /// The type of function used to initialize neural network parameters
+    #[config(
+        default = "Initializer::KaimingUniform{gain:1.0/num_traits::Float::sqrt(3.0), fan_out_only:false}"
+    )]
+    pub initializer: Initializer,
+}
+
+/// The grouped query attention module as described in the paper
+/// [GQA: Training Generalized Multi-Query Transformer Models from Multi-Head Checkpoints](https://arxiv.org/abs/2305.13245).
+///
+/// Each group of `n_heads / n_kv_heads` query heads shares the same key and value head, which
+/// reduces the size of the [key-value cache](KvCache) used during incremental decoding.
+///
+/// # Params
+///
+/// - `query`: [`Linear`] layer with `d_model` input and `n_heads * d_head` output features.
+/// - `key`: [`Linear`] layer with `d_model` input and `n_kv_heads * d_head` output features.
+/// - `value`: [`Linear`] layer with `d_model` input and `n_kv_heads * d_head` output features.
+/// - `output`: [`Linear`] layer with `n_heads * d_head` input and `d_model` output features.
+///
+/// Should be created with [GroupedQueryAttentionConfig].
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct GroupedQueryAttention<B: Backend> {
+    /// Linear layer to transform the input features into the query space.
+    pub query: Linear<B>,
+    /// Linear layer to transform the input features into the key space.
+    pub key: Linear<B>,
+    /// Linear layer to transform the input features into the value space.
+    pub value: Linear<B>,
+    /// Linear layer to transform the output features back to the original space.
+    pub output: Linear<B>,
+    /// Rotary encoding applied to the queries and keys.
+    pub rotary: Option<RotaryEncoding<B>>,
+    /// Dropout layer.
+    pub dropout: Dropout,
+    /// The number of query heads.
+    pub n_heads: usize,
+    /// The number of key and value heads.
+    pub n_kv_heads: usize,
+    /// The size of each head.
+    pub d_head: usize,
+    /// Minimum value a float can take.
+    pub min_float: f64,
+    /// Use "quiet softmax" instead of regular softmax.
+    pub quiet_softmax: bool,
+    /// The size of the sliding window.
+    pub sliding_window: Option<usize>,
+}
+
+impl<B: Backend> ModuleDisplay for GroupedQueryAttention<B> {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        content
+            .add("n_heads", &self.n_heads)
+            .add("n_kv_heads", &self.n_kv_heads)
+            .add("d_head", &self.d_head)
+            .add("rotary", &self.rotary.is_some())
+            .add("sliding_window", &self.sliding_window.unwrap_or_default())
+            .add("dropout", &self.dropout.prob)
+            .optional()
+    }
+}
+
+impl GroupedQueryAttentionConfig {
+    /// Initialize a new [grouped query attention](GroupedQueryAttention) module.
+    pub fn init<B: Backend>(&self, device: &B::Device) -> GroupedQueryAttention<B> {
+        assert!(
+            self.n_kv_heads > 0 && self.n_heads % self.n_kv_heads == 0,
+            "The number of key and value heads ({}) should divide the number of heads ({})",
+            self.n_kv_heads,
+            self.n_heads
+        );
+        let d_head = self.d_head.unwrap_or(self.d_model / self.n_heads);
+        let rotary = self.rotary.as_ref().map(|config| {
+            assert_eq!(
+                config.d_model, d_head,
+                "The rotary encoding size should be the size of each head"
+            );
+            config.init(device)
+        });
+        if let Some(window) = self.sliding_window {
+            assert!(window > 0, "The sliding window should be greater than 0");
+        }
+
+        let linear = |d_input, d_output| {
+            LinearConfig::new(d_input, d_output)
+                .with_bias(self.bias)
+                .with_initializer(self.initializer.clone())
+                .init(device)
+        };
+
+        GroupedQueryAttention {
+            query: linear(self.d_model, self.n_heads * d_head),
+            key: linear(self.d_model, self.n_kv_heads * d_head),
+            value: linear(self.d_model, self.n_kv_heads * d_head),
+            output: linear(self.n_heads * d_head, self.d_model),
+            rotary,
+            dropout: DropoutConfig::new(self.dropout).init(),
+            n_heads: self.n_heads,
+            n_kv_heads: self.n_kv_heads,
+            d_head,
+            min_float: self.min_float,
+            quiet_softmax: self.quiet_softmax,
+            sliding_window: self.sliding_window,
+        }
+    }
+}
+
+impl<B: Backend> GroupedQueryAttention<B> {
+    /// Applies the forward pass on the input tensors.
+    ///
+    /// See [GroupedQueryAttention](GroupedQueryAttention) for more information.
+    ///
+    /// # Shapes
+    ///
+    /// - query: `[batch_size, seq_length_1, d_model]`
+    /// - key: `[batch_size, seq_length_2, d_model]`
+    /// - value: `[batch_size, seq_length_2, d_model]`
+    /// - output: `[batch_size, seq_length_1, d_model]`
+    pub fn forward(&self, input: MhaInput<B>) -> MhaOutput<B> {
+        let query = self.rotate(self.heads(input.query, &self.query, self.n_heads), 0);
+        let key = self.rotate(self.heads(input.key, &self.key, self.n_kv_heads), 0);
+        let value = self.heads(input.value, &self.value, self.n_kv_heads);
+
+        let mask_window = self.sliding_window.map(|_| {
+            let [_, _, seq_length_2, _] = key.dims();
+            let positions = Tensor::arange(0..seq_length_2 as i64, &key.device());
+            self.positions_mask(0, query.dims()[2], positions)
+        });
+
+        self.attention(
+            query,
+            key,
+            value,
+            input.mask_pad,
+            input.mask_attn,
+            mask_window,
+        )
+    }
+
+    /// Applies the forward pass on the new tokens of a sequence, using a [cache](KvCache) of the
+    /// keys and values of the previous tokens.
+    ///
+    /// The attention is causal and the new tokens are placed after the cached ones, so the cache
+    /// capacity should be at least the sliding window plus the number of new tokens minus one.
+    /// The masks of the input apply to the cached entries, see [positions](KvCache::positions).
+    ///
+    /// # Shapes
+    ///
+    /// - query: `[batch_size, seq_length, d_model]`
+    /// - key: `[batch_size, seq_length, d_model]`
+    /// - value: `[batch_size, seq_length, d_model]`
+    /// - output: `[batch_size, seq_length, d_model]`
+    pub fn forward_cache(&self, input: MhaInput<B>, cache: &mut KvCache<B>) -> MhaOutput<B> {
+        let start = cache.len();
+
+        let query = self.rotate(self.heads(input.query, &self.query, self.n_heads), start);
+        let key = self.rotate(self.heads(input.key, &self.key, self.n_kv_heads), start);
+        let value = self.heads(input.value, &self.value, self.n_kv_heads);
+        let seq_length = query.dims()[2];
+
+        let (key, value) = cache.append(key, value);
+        let mask_positions = self.positions_mask(start, seq_length, cache.positions_tensor());
+
+        self.attention(
+            query,
+            key,
+            value,
+            input.mask_pad,
+            input.mask_attn,
+            Some(mask_positions),
+        )
+    }
+
+    /// Creates an empty [key-value cache](KvCache) for the layer.
+    pub fn cache(&self, batch_size: usize, capacity: usize, device: &B::Device) -> KvCache<B> {
+        KvCache::new(batch_size, self.n_kv_heads, capacity, self.d_head, device)
+    }
+
+    fn heads(&self, x: Tensor<B, 3>, linear: &Linear<B>, n_heads: usize) -> Tensor<B, 4> {
+        let [batch_size, seq_length, _d_model] = x.dims();
+        linear
+            .forward(x)
+            .reshape([batch_size, seq_length, n_heads, self.d_head])
+            .swap_dims(1, 2)
+    }
+
+    fn rotate(&self, heads: Tensor<B, 4>, start: usize) -> Tensor<B, 4> {
+        match &self.rotary {
+            Some(rotary) => rotary.apply(heads, start),
+            None => heads,
+        }
+    }
+
+    /// Masks the keys after each query, and outside of its sliding window.
+    fn positions_mask(
+        &self,
+        start: usize,
+        seq_length: usize,
+        key_positions: Tensor<B, 1, Int>,
+    ) -> Tensor<B, 2, Bool> {
+        let num_keys = key_positions.dims()[0];
+        let query_positions = Tensor::<B, 1, Int>::arange(
+            start as i64..(start + seq_length) as i64,
+            &key_positions.device(),
+        );
+
+        let distance = query_positions
+            .reshape([seq_length, 1])
+            .expand([seq_length, num_keys])
+            - key_positions
+                .reshape([1, num_keys])
+                .expand([seq_length, num_keys]);
+        let mask = distance.clone().lower_elem(0);
+
+        match self.sliding_window {
+            Some(window) => mask.bool_or(distance.greater_equal_elem(window as i64)),
+            None => mask,
+        }
+    }
+
+    fn attention(
+        &self,
+        query: Tensor<B, 4>,
+        key: Tensor<B, 4>,
+        value: Tensor<B, 4>,
+        mask_pad: Option<Tensor<B, 2, Bool>>,
+        mask_attn: Option<Tensor<B, 3, Bool>>,
+        mask_positions: Option<Tensor<B, 2, Bool>>,
+    ) -> MhaOutput<B> {
+        let [batch_size, n_heads, seq_length_1, d_head] = query.dims();
+        let seq_length_2 = key.dims()[2];
+        let n_groups = n_heads / self.n_kv_heads;
+
+        // The query heads of a group are merged with the sequence, to share the key and value heads
+        // without repeating them.
+        let mut attn_scores = query
+            .reshape([batch_size, self.n_kv_heads, n_groups * seq_length_1, d_head])
+            .matmul(key.transpose())
+            .div_scalar((d_head as f32).sqrt())
+            .reshape([batch_size, n_heads, seq_length_1, seq_length_2]);
+        attn_scores = self.dropout.forward(attn_scores);
+
+        if let Some(mask_pad) = mask_pad {
+            attn_scores = attn_scores.mask_fill(
+                mask_pad.reshape([batch_size, 1, 1, seq_length_2]),
+                self.min_float,
+            );
+        }
+
+        if let Some(mask_attn) = mask_attn {
+            attn_scores = attn_scores.mask_fill(
+                mask_attn.reshape([batch_size, 1, seq_length_1, seq_length_2]),
+                self.min_float,
+            );
+        }
+
+        if let Some(mask_positions) = mask_positions {
+            attn_scores = attn_scores.mask_fill(
+                mask_positions.reshape([1, 1, seq_length_1, seq_length_2]),
+                self.min_float,
+            );
+        }
+
+        let weights = if self.quiet_softmax {
+            quiet_softmax(attn_scores, 3)
+        } else {
+            softmax(attn_scores, 3)
+        };
+
+        let context = weights
+            .clone()
+            .reshape([
+                batch_size,
+                self.n_kv_heads,
+                n_groups * seq_length_1,
+                seq_length_2,
+            ])
+            .matmul(value)
+            .reshape([batch_size, n_heads, seq_length_1, d_head])
+            .swap_dims(1, 2)
+            .reshape([batch_size, seq_length_1, n_heads * d_head]);
+        let context = self.output.forward(context);
+
+        MhaOutput { weights, context }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use crate::nn::attention::{
+        MultiHeadAttentionConfig, generate_autoregressive_mask, generate_sliding_window_mask,
+    };
+    use crate::tensor::{Distribution, Shape};
+    use alloc::vec::Vec;
+    use burn_tensor::Tolerance;
+    use burn_tensor::ops::FloatElem;
+
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn test_grouped_query_attention_shapes() {
+        let [batch_size, seq_length, d_model, n_heads, n_kv_heads] = [3, 5, 16, 4, 2];
+        let device = Default::default();
+        let gqa = GroupedQueryAttentionConfig::new(d_model, n_heads, n_kv_heads)
+            .init::<TestBackend>(&device);
+        let input = MhaInput::self_attn(Tensor::random(
+            [batch_size, seq_length, d_model],
+            Distribution::Default,
+            &device,
+        ));
+
+        let output = gqa.forward(input);
+
+        assert_eq!(gqa.key.weight.dims(), [d_model, n_kv_heads * 4]);
+        assert_eq!(
+            output.context.shape(),
+            Shape::new([batch_size, seq_length, d_model])
+        );
+        assert_eq!(
+            output.weights.shape(),
+            Shape::new([batch_size, n_heads, seq_length, seq_length])
+        );
+    }
+
+    #[test]
+    fn test_same_output_as_multi_head_attention_without_groups() {
+        let [batch_size, seq_length, d_model, n_heads] = [2, 4, 8, 2];
+        let device = Default::default();
+        let mha = MultiHeadAttentionConfig::new(d_model, n_heads).init::<TestBackend>(&device);
+        let mut gqa = GroupedQueryAttentionConfig::new(d_model, n_heads, n_heads)
+            .with_bias(true)
+            .init::<TestBackend>(&device);
+        gqa.query = mha.query.clone();
+        gqa.key = mha.key.clone();
+        gqa.value = mha.value.clone();
+        gqa.output = mha.output.clone();
+
+        let tensor = Tensor::<TestBackend, 3>::random(
+            [batch_size, seq_length, d_model],
+            Distribution::Default,
+            &device,
+        );
+
+        let expected = mha.forward(MhaInput::self_attn(tensor.clone()));
+        let output = gqa.forward(MhaInput::self_attn(tensor));
+
+        output
+            .context
+            .into_data()
+            .assert_approx_eq::<FT>(&expected.context.into_data(), Tolerance::default());
+    }
+
+    #[test]
+    fn test_sliding_window_same_output_as_mask() {
+        let [batch_size, seq_length, d_model] = [2, 5, 8];
+        let device = Default::default();
+        let mut gqa = GroupedQueryAttentionConfig::new(d_model, 4, 2).init::<TestBackend>(&device);
+        let tensor = Tensor::<TestBackend, 3>::random(
+            [batch_size, seq_length, d_model],
+            Distribution::Default,
+            &device,
+        );
+
+        let mask = generate_sliding_window_mask(batch_size, seq_length, 2, &device);
+        let expected = gqa.forward(MhaInput::self_attn(tensor.clone()).mask_attn(mask));
+        gqa.sliding_window = Some(2);
+        let output = gqa.forward(MhaInput::self_attn(tensor));
+
+        output
+            .context
+            .into_data()
+            .assert_approx_eq::<FT>(&expected.context.into_data(), Tolerance::default());
+    }
+
+    #[test]
+    fn test_autoregressive_mask_should_have_same_output_as_cached_decoding() {
+        let [batch_size, seq_length, d_model] = [2, 5, 16];
+        let device = Default::default();
+        let gqa = GroupedQueryAttentionConfig::new(d_model, 4, 1)
+            .with_rotary(Some(RotaryEncodingConfig::new(seq_length, 4)))
+            .init::<TestBackend>(&device);
+        let tensor = Tensor::<TestBackend, 3>::random(
+            [batch_size, seq_length, d_model],
+            Distribution::Default,
+            &device,
+        );
+
+        let mask = generate_autoregressive_mask(batch_size, seq_length, &device);
+        let expected = gqa.forward(MhaInput::self_attn(tensor.clone()).mask_attn(mask));
+
+        // Prefill the first two tokens, then decode one token at a time.
+        let mut cache = gqa.cache(batch_size, seq_length, &device);
+        let mut outputs = Vec::new();
+        for range in [0..2, 2..3, 3..4, 4..5] {
+            let tokens = tensor.clone().slice([0..batch_size, range, 0..d_model]);
+            outputs.push(
+                gqa.forward_cache(MhaInput::self_attn(tokens), &mut cache)
+                    .context,
+            );
+        }
+
+        Tensor::cat(outputs, 1)
+            .into_data()
+            .assert_approx_eq::<FT>(&expected.context.into_data(), Tolerance::default());
+    }
+
+    #[test]
+    fn test_sliding_window_should_have_same_output_as_ring_buffer_decoding() {
+        let [batch_size, seq_length, d_model, window] = [1, 6, 8, 3];
+        let device = Default::default();
+        let gqa = GroupedQueryAttentionConfig::new(d_model, 2, 1)
+            .with_sliding_window(Some(window))
+            .init::<TestBackend>(&device);
+        let tensor = Tensor::<TestBackend, 3>::random(
+            [batch_size, seq_length, d_model],
+            Distribution::Default,
+            &device,
+        );
+
+        let expected = gqa.forward(MhaInput::self_attn(tensor.clone()));
+
+        let mut cache = gqa.cache(batch_size, window, &device);
+        let mut outputs = Vec::new();
+        for i in 0..seq_length {
+            let token = tensor.clone().slice([0..batch_size, i..i + 1, 0..d_model]);
+            outputs.push(
+                gqa.forward_cache(MhaInput::self_attn(token), &mut cache)
+                    .context,
+            );
+        }
+
+        assert_eq!(cache.len(), seq_length);
+        Tensor::cat(outputs, 1)
+            .into_data()
+            .assert_approx_eq::<FT>(&expected.context.into_data(), Tolerance::default());
+    }
+
+    #[test]
+    fn display() {
+        let gqa =
+            GroupedQueryAttentionConfig::new(8, 4, 2).init::<TestBackend>(&Default::default());
+
+        assert_eq!(
+            alloc::format!("{gqa}"),
+            "GroupedQueryAttention {n_heads: 4, n_kv_heads: 2, d_head: 2, rotary: false, \
+            sliding_window: 0, dropout: 0, params: 192}"
+        );
+    }
+}
@@ -15,6 +15,26 @@ pub fn generate_autoregressive_mask<B: Backend>(
     mask.expand([batch_size, seq_length, seq_length])
 }
 
+/// Generate a sliding window attention mask.
+///
+/// Each token attends to itself and the previous `window - 1` tokens.
+pub fn generate_sliding_window_mask<B: Backend>(
+    batch_size: usize,
+    seq_length: usize,
+    window: usize,
+    device: &B::Device,
+) -> Tensor<B, 3, Bool> {
+    assert!(window > 0, "The sliding window should be greater than 0");
+
+    let shape = [seq_length, seq_length];
+    let mask = Tensor::<B, 2, Bool>::tril_mask(shape, 0, device).bool_or(Tensor::triu_mask(
+        shape,
+        1 - window as i64,
+        device,
+    ));
+    mask.expand([batch_size, seq_length, seq_length])
+}
+
 /// Generate a padding attention mask.
 pub struct GeneratePaddingMask<B: Backend> {
     /// The generated tensor.
@@ -103,6 +123,23 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_generate_sliding_window_mask() {
+        let device = <TestBackend as Backend>::Device::default();
+
+        let mask = generate_sliding_window_mask::<TestBackend>(1, 4, 2, &device);
+
+        mask.into_data().assert_eq(
+            &TensorData::from([[
+                [false, true, true, true],
+                [false, false, true, true],
+                [true, false, false, true],
+                [true, true, false, false],
+            ]]),
+            false,
+        );
+    }
+
     #[test]
     fn test_generate_padding_mask() {
         let device = <TestBackend as Backend>::Device::default();
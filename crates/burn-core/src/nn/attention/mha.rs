@@ -104,13 +104,13 @@ impl<B: Backend> ModuleDisplay for MultiHeadAttention<B> {
 #[derive(Debug, Clone)]
 pub struct MhaInput<B: Backend> {
     /// Shape `[batch_size, seq_length_1, d_model]`
-    query: Tensor<B, 3>,
+    pub(crate) query: Tensor<B, 3>,
     /// Shape `[batch_size, seq_length_2, d_model]`
-    key: Tensor<B, 3>,
+    pub(crate) key: Tensor<B, 3>,
     /// Shape `[batch_size, seq_length_2, d_model]`
-    value: Tensor<B, 3>,
-    mask_pad: Option<Tensor<B, 2, Bool>>,
-    mask_attn: Option<Tensor<B, 3, Bool>>,
+    pub(crate) value: Tensor<B, 3>,
+    pub(crate) mask_pad: Option<Tensor<B, 2, Bool>>,
+    pub(crate) mask_attn: Option<Tensor<B, 3, Bool>>,
 }
 
 impl MultiHeadAttentionConfig {
@@ -0,0 +1,174 @@
+use alloc::vec::Vec;
+
+use crate::tensor::backend::Backend;
+use crate::tensor::{Int, Tensor, TensorData};
+
+/// A preallocated key-value cache for incremental decoding.
+///
+/// The keys and values are written in a ring buffer of fixed capacity: once full, the oldest
+/// entries are overwritten, so attention only covers the last `capacity` tokens.
+pub struct KvCache<B: Backend> {
+    keys: Tensor<B, 4>,
+    values: Tensor<B, 4>,
+    len: usize,
+}
+
+impl<B: Backend> KvCache<B> {
+    /// Creates a new empty cache of shape `[batch_size, n_heads, capacity, d_head]`.
+    pub fn new(
+        batch_size: usize,
+        n_heads: usize,
+        capacity: usize,
+        d_head: usize,
+        device: &B::Device,
+    ) -> Self {
+        assert!(capacity > 0, "The cache capacity should be greater than 0");
+
+        Self {
+            keys: Tensor::zeros([batch_size, n_heads, capacity, d_head], device),
+            values: Tensor::zeros([batch_size, n_heads, capacity, d_head], device),
+            len: 0,
+        }
+    }
+
+    /// The number of tokens the cache can hold.
+    pub fn capacity(&self) -> usize {
+        self.keys.dims()[2]
+    }
+
+    /// The number of tokens appended to the cache, which is the position of the next token.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// If no token was appended to the cache.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Empties the cache, keeping its memory.
+    pub fn reset(&mut self) {
+        self.len = 0;
+    }
+
+    /// Appends the keys and values of new tokens, returning all the cached keys and values.
+    ///
+    /// The cached entries aren't ordered by position once the cache is full, see
+    /// [positions](KvCache::positions).
+    ///
+    /// # Shapes
+    ///
+    /// - keys: `[batch_size, n_heads, seq_length, d_head]`
+    /// - values: `[batch_size, n_heads, seq_length, d_head]`
+    /// - output: `[batch_size, n_heads, min(len, capacity), d_head]`
+    pub fn append(
+        &mut self,
+        keys: Tensor<B, 4>,
+        values: Tensor<B, 4>,
+    ) -> (Tensor<B, 4>, Tensor<B, 4>) {
+        let [batch_size, n_heads, seq_length, d_head] = keys.dims();
+        let capacity = self.capacity();
+        assert!(
+            seq_length <= capacity,
+            "Can't append {seq_length} tokens to a cache of capacity {capacity}"
+        );
+
+        let start = self.len % capacity;
+        let num_first = seq_length.min(capacity - start);
+        let ranges = [
+            (start..start + num_first, 0..num_first),
+            (0..seq_length - num_first, num_first..seq_length),
+        ];
+
+        for (slots, tokens) in ranges.into_iter().filter(|(slots, _)| !slots.is_empty()) {
+            let slots = [0..batch_size, 0..n_heads, slots, 0..d_head];
+            let tokens = [0..batch_size, 0..n_heads, tokens, 0..d_head];
+            let keys = keys.clone().slice(tokens.clone());
+            let values = values.clone().slice(tokens);
+
+            self.keys
+                .inplace(|cache| cache.slice_assign(slots.clone(), keys));
+            self.values
+                .inplace(|cache| cache.slice_assign(slots, values));
+        }
+        self.len += seq_length;
+
+        let num_cached = self.len.min(capacity);
+        let cached = [0..batch_size, 0..n_heads, 0..num_cached, 0..d_head];
+
+        (
+            self.keys.clone().slice(cached.clone()),
+            self.values.clone().slice(cached),
+        )
+    }
+
+    /// The position of the token of each cached entry.
+    pub fn positions(&self) -> Vec<usize> {
+        let capacity = self.capacity();
+
+        if self.len <= capacity {
+            return (0..self.len).collect();
+        }
+
+        let last = self.len - 1;
+        (0..capacity)
+            .map(|slot| last - (last - slot) % capacity)
+            .collect()
+    }
+
+    /// The [positions](KvCache::positions) of the cached entries as a tensor.
+    pub fn positions_tensor(&self) -> Tensor<B, 1, Int> {
+        let positions = self
+            .positions()
+            .into_iter()
+            .map(|position| position as i64)
+            .collect::<Vec<_>>();
+        let num_positions = positions.len();
+
+        Tensor::from_data(
+            TensorData::new(positions, [num_positions]),
+            &self.keys.device(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use crate::tensor::Distribution;
+
+    #[test]
+    fn should_append_until_capacity() {
+        let device = Default::default();
+        let mut cache = KvCache::<TestBackend>::new(1, 2, 4, 3, &device);
+        let keys = Tensor::<TestBackend, 4>::random([1, 2, 3, 3], Distribution::Default, &device);
+
+        let (cached, _) = cache.append(keys.clone(), keys.clone());
+
+        assert_eq!(cache.len(), 3);
+        assert_eq!(cache.positions(), [0, 1, 2]);
+        cached.into_data().assert_eq(&keys.into_data(), true);
+    }
+
+    #[test]
+    fn should_overwrite_oldest_entries_when_full() {
+        let device = Default::default();
+        let mut cache = KvCache::<TestBackend>::new(1, 1, 3, 1, &device);
+        let tokens = |values: [f32; 2]| {
+            Tensor::<TestBackend, 1>::from_floats(values, &device).reshape([1, 1, 2, 1])
+        };
+
+        cache.append(tokens([0.0, 1.0]), tokens([0.0, 1.0]));
+        let (keys, values) = cache.append(tokens([2.0, 3.0]), tokens([2.0, 3.0]));
+
+        let expected = TensorData::from([[[[3.0], [1.0], [2.0]]]]);
+        keys.into_data().assert_eq(&expected, false);
+        values.into_data().assert_eq(&expected, false);
+        assert_eq!(cache.positions(), [3, 1, 2]);
+
+        cache.reset();
+        assert!(cache.is_empty());
+        assert_eq!(cache.positions(), Vec::<usize>::new());
+    }
+}
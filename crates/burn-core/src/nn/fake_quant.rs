@@ -0,0 +1,246 @@
+use crate as burn;
+use crate::module::{Content, DisplaySettings, Ignored, ModuleDisplay};
+
+use crate::{
+    config::Config,
+    module::{Module, Param, RunningState},
+    tensor::{Tensor, backend::Backend},
+};
+use burn_tensor::quantization::{
+    CalibrationRange, QuantLevel, QuantScheme, QuantizationParameters, compute_q_params,
+};
+
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use num_traits::Float as _;
+
+/// Configuration to create a [FakeQuantize](FakeQuantize) layer using the [init function](FakeQuantizeConfig::init).
+#[derive(Config, Debug)]
+pub struct FakeQuantizeConfig {
+    /// The quantization scheme, only [tensor level](QuantLevel::Tensor) quantization is supported.
+    pub scheme: QuantScheme,
+    /// Learn the scale with [LSQ](https://arxiv.org/abs/1902.08153) instead of observing the range
+    /// of the inputs. Default: false
+    #[config(default = false)]
+    pub learnable_scale: bool,
+    /// The initial value of the learnable scale. Default: 1.0
+    #[config(default = 1.0)]
+    pub initial_scale: f64,
+    /// The momentum used to update the observed range. Default: 0.1
+    #[config(default = 0.1)]
+    pub momentum: f64,
+}
+
+/// Simulates the quantization of activations during quantization-aware training.
+///
+/// The input is quantized and dequantized, with gradients flowing through a straight-through
+/// estimator. The scale is either computed from a moving average of the absolute maximum of the
+/// inputs, updated during training, or learned.
+///
+/// Should be created using [FakeQuantizeConfig].
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct FakeQuantize<B: Backend> {
+    /// The learnable scale.
+    pub scale: Option<Param<Tensor<B, 1>>>,
+    /// The moving average of the absolute maximum of the inputs.
+    pub abs_max: RunningState<Tensor<B, 1>>,
+    /// The quantization scheme.
+    pub scheme: Ignored<QuantScheme>,
+    /// Momentum used to update the observed range.
+    pub momentum: f64,
+}
+
+impl FakeQuantizeConfig {
+    /// Initialize a new [fake quantize](FakeQuantize) module.
+    pub fn init<B: Backend>(&self, device: &B::Device) -> FakeQuantize<B> {
+        assert!(
+            matches!(self.scheme.level, QuantLevel::Tensor),
+            "FakeQuantize only supports tensor level quantization, got {:?}",
+            self.scheme.level
+        );
+
+        let scale = self
+            .learnable_scale
+            .then(|| Param::from_tensor(Tensor::full([1], self.initial_scale, device)));
+
+        FakeQuantize {
+            scale,
+            abs_max: RunningState::new(Tensor::zeros([1], device)),
+            scheme: Ignored(self.scheme),
+            momentum: self.momentum,
+        }
+    }
+}
+
+impl<B: Backend> FakeQuantize<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// See [FakeQuantize](FakeQuantize) for more information.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[..., any]`
+    /// - output: `[..., any]`
+    pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        let qparams = match &self.scale {
+            Some(scale) => QuantizationParameters {
+                scales: self.lsq_scale(scale.val(), input.shape().num_elements()),
+            },
+            None => {
+                if B::ad_enabled() {
+                    self.observe(&input);
+                }
+                self.qparams()
+            }
+        };
+
+        input.fake_quantize(&self.scheme, qparams)
+    }
+
+    /// The quantization parameters computed from the observed range, or the learned scale.
+    pub fn qparams(&self) -> QuantizationParameters<B> {
+        if let Some(scale) = &self.scale {
+            return QuantizationParameters {
+                scales: scale.val(),
+            };
+        }
+
+        // Avoids a zero scale before observing any input.
+        let abs_max = self.abs_max.value().clamp_min(f32::EPSILON);
+        let range = CalibrationRange {
+            min: abs_max.clone().neg(),
+            max: abs_max,
+        };
+        compute_q_params(&self.scheme, range)
+    }
+
+    fn observe<const D: usize>(&self, input: &Tensor<B, D>) {
+        let abs_max = input.clone().detach().abs().max();
+        let running = self.abs_max.value_sync().to_device(&abs_max.device());
+
+        // The first observation initializes the moving average.
+        let running = running
+            .clone()
+            .mask_where(running.equal_elem(0.0), abs_max.clone());
+        let running = running
+            .mul_scalar(1.0 - self.momentum)
+            .add(abs_max.mul_scalar(self.momentum));
+
+        self.abs_max.update(running.detach());
+    }
+
+    fn lsq_scale(&self, scale: Tensor<B, 1>, num_elements: usize) -> Tensor<B, 1> {
+        // Scales the gradient of the scale by 1 / sqrt(num_elements * b), keeping its value.
+        let (_, b) = self.scheme.value.range();
+        let factor = 1.0 / (num_elements as f64 * b as f64).sqrt();
+        let scaled = scale.clone().mul_scalar(factor);
+
+        scaled.clone() + (scale - scaled).detach()
+    }
+}
+
+impl<B: Backend> ModuleDisplay for FakeQuantize<B> {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        content
+            .add("learnable_scale", &self.scale.is_some())
+            .add("momentum", &self.momentum)
+            .optional()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{TestAutodiffBackend, TestBackend, module::AutodiffModule};
+    use burn_tensor::{
+        TensorData, Tolerance,
+        ops::{FloatElem, QuantizedTensor},
+        quantization::{QTensorPrimitive, QuantValue},
+    };
+
+    type FT = FloatElem<TestBackend>;
+
+    fn scheme() -> QuantScheme {
+        QuantizedTensor::<TestBackend>::default_scheme()
+            .with_value(QuantValue::Q8S)
+            .with_level(QuantLevel::Tensor)
+    }
+
+    #[test]
+    fn should_observe_range_during_training() {
+        let device = Default::default();
+        let module = FakeQuantizeConfig::new(scheme())
+            .with_momentum(0.5)
+            .init::<TestAutodiffBackend>(&device);
+
+        module.forward(Tensor::<TestAutodiffBackend, 1>::from_floats(
+            [-1.0, 0.5],
+            &device,
+        ));
+        module.forward(Tensor::<TestAutodiffBackend, 1>::from_floats(
+            [3.0, 0.5],
+            &device,
+        ));
+
+        module
+            .abs_max
+            .value()
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([2.0]), Tolerance::default());
+
+        // Not updated during inference.
+        let module = module.valid();
+        module.forward(Tensor::<TestBackend, 1>::from_floats([10.0], &device));
+        module
+            .abs_max
+            .value()
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([2.0]), Tolerance::default());
+    }
+
+    #[test]
+    fn should_fake_quantize_with_observed_range() {
+        let device = Default::default();
+        let module = FakeQuantizeConfig::new(scheme()).init::<TestAutodiffBackend>(&device);
+
+        let output = module.forward(Tensor::<TestAutodiffBackend, 1>::from_floats(
+            [-1.8, -1.0, 0.0, 0.5],
+            &device,
+        ));
+
+        output.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([-1.8, -1.0062993, 0.0, 0.496063]),
+            Tolerance::default(),
+        );
+    }
+
+    #[test]
+    fn should_learn_scale() {
+        let device = Default::default();
+        let module = FakeQuantizeConfig::new(scheme())
+            .with_learnable_scale(true)
+            .with_initial_scale(0.01)
+            .init::<TestAutodiffBackend>(&device);
+
+        let output = module.forward(Tensor::<TestAutodiffBackend, 1>::from_floats(
+            [0.5, 2.0],
+            &device,
+        ));
+        let grads = output.sum().backward();
+        let scale = module.scale.unwrap();
+
+        // The LSQ gradient (127.0) scaled by 1 / sqrt(2 * 127).
+        scale
+            .grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([7.968_689]), Tolerance::default());
+    }
+}
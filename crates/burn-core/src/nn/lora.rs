@@ -0,0 +1,482 @@
+use crate as burn;
+use crate::module::{
+    Content, DisplaySettings, ModuleDisplay, ModuleMapper, ParamId, list_param_paths, list_weights,
+};
+use crate::nn::{Dropout, DropoutConfig, Initializer, Linear};
+
+use crate::{
+    config::Config,
+    module::{Module, Param},
+    tensor::{Tensor, backend::Backend},
+};
+use alloc::{string::String, vec::Vec};
+use burn_tensor::module::linear;
+use hashbrown::HashMap;
+
+/// Configuration to create [LoRA](Lora) adapters for a module using the [init function](LoraConfig::init).
+#[derive(Config, Debug)]
+pub struct LoraConfig {
+    /// The rank of the adapters.
+    pub rank: usize,
+    /// The updates are scaled by `alpha / rank`.
+    pub alpha: f64,
+    /// Patterns of the paths of the adapted modules, e.g. `*.query` or `encoder.*`, where `*` matches
+    /// any sequence of characters. Default: all the modules
+    #[config(default = "alloc::vec![\"*\".into()]")]
+    pub targets: Vec<String>,
+    /// The dropout probability applied to the inputs of the adapters during training, only
+    /// supported by the [wrapped layers](LoraLinear), so it must be zero to
+    /// [adapt any module](LoraConfig::init). Default: 0.0
+    #[config(default = 0.0)]
+    pub dropout: f64,
+    /// The type of function used to initialize the down projections, the up projections are
+    /// initialized to zeros.
+    #[config(
+        default = "Initializer::KaimingUniform{gain:1.0/num_traits::Float::sqrt(5.0), fan_out_only:false}"
+    )]
+    pub initializer: Initializer,
+}
+
+/// Low-rank adapters ([LoRA](https://arxiv.org/abs/2106.09685)) of the weights of a module.
+///
+/// Each adapted weight `W` is replaced by `W + alpha / rank * down * up`, the weight being seen as a
+/// matrix of shape `[d_0, d_1 * ... * d_n]`. This covers the weights of [Linear](crate::nn::Linear)
+/// and [convolution](crate::nn::conv::Conv2d) modules, including the projections of
+/// [multi-head attention](crate::nn::attention::MultiHeadAttention).
+///
+/// The adapters are kept separate from the module, so only their weights are trained and recorded.
+/// They are [applied](Lora::apply) to the module before each forward pass, and can be
+/// [merged](Lora::merge) into its weights once trained. Since the updates are added to the
+/// weights, no dropout is applied to the adapters.
+///
+/// When the type of the layer can be changed, prefer [LoraLinear], which is part of the module
+/// tree, so its adapter is used by the normal forward pass and saved in the module record.
+///
+/// Should be created using [LoraConfig].
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct Lora<B: Backend> {
+    /// The adapters of each adapted weight.
+    pub adapters: Vec<LoraAdapter<B>>,
+    /// The scaling of the updates, `alpha / rank`.
+    pub scaling: f64,
+}
+
+/// Low-rank adapter of a single weight.
+#[derive(Module, Debug)]
+pub struct LoraAdapter<B: Backend> {
+    /// The [path](list_param_paths) of the adapted weight.
+    pub path: String,
+    /// Matrix of shape `[d_0, rank]`.
+    pub down: Param<Tensor<B, 2>>,
+    /// Matrix of shape `[rank, d_1 * ... * d_n]` initialized to zeros.
+    pub up: Param<Tensor<B, 2>>,
+}
+
+impl LoraConfig {
+    /// Initialize the [LoRA](Lora) adapters of the weights of the targeted modules.
+    ///
+    /// The weights are the float parameters named `weight` with at least two dimensions.
+    ///
+    /// # Panics
+    ///
+    /// If the [dropout](LoraConfig::dropout) isn't zero, since it can't be applied to updates
+    /// added to the weights. Use [init_linear](LoraConfig::init_linear) instead.
+    pub fn init<B: Backend, M: Module<B>>(&self, module: &M, device: &B::Device) -> Lora<B> {
+        assert!(self.rank > 0, "LoRA rank should be greater than 0");
+        assert!(
+            self.dropout == 0.0,
+            "LoRA dropout is only supported by the wrapped layers, got {}",
+            self.dropout
+        );
+
+        let adapters = list_weights(module, &self.targets)
+            .into_iter()
+            .map(|(path, _, dims)| {
+                let d_0 = dims[0];
+                let d_rest = dims[1..].iter().product::<usize>();
+
+                LoraAdapter {
+                    path,
+                    down: self.initializer.init_with(
+                        [d_0, self.rank],
+                        Some(d_0),
+                        Some(self.rank),
+                        device,
+                    ),
+                    up: Initializer::Zeros.init([self.rank, d_rest], device),
+                }
+            })
+            .collect();
+
+        Lora {
+            adapters,
+            scaling: self.alpha / self.rank as f64,
+        }
+    }
+
+    /// Wrap a [linear](Linear) layer with a [LoRA](LoraLinear) adapter, freezing its parameters.
+    ///
+    /// The [targets](LoraConfig::targets) are ignored.
+    pub fn init_linear<B: Backend>(&self, linear: Linear<B>) -> LoraLinear<B> {
+        assert!(self.rank > 0, "LoRA rank should be greater than 0");
+
+        let device = linear.weight.device();
+        let [d_input, d_output] = linear.weight.dims();
+
+        LoraLinear {
+            linear: linear.no_grad(),
+            down: self.initializer.init_with(
+                [d_input, self.rank],
+                Some(d_input),
+                Some(self.rank),
+                &device,
+            ),
+            up: Initializer::Zeros.init([self.rank, d_output], &device),
+            scaling: self.alpha / self.rank as f64,
+            dropout: DropoutConfig::new(self.dropout).init(),
+        }
+    }
+}
+
+impl<B: Backend> Lora<B> {
+    /// Returns the module with the adapted weights.
+    ///
+    /// All the parameters of the module are detached, so only the adapters are trained.
+    pub fn apply<M: Module<B>>(&self, module: M) -> M {
+        let deltas = self.deltas(&module);
+
+        module.map(&mut LoraMapper {
+            deltas,
+            freeze: true,
+        })
+    }
+
+    /// Merges the adapters into the weights of the module, e.g. to export the fine-tuned module.
+    pub fn merge<M: Module<B>>(&self, module: M) -> M {
+        let deltas = self.deltas(&module);
+
+        module.map(&mut LoraMapper {
+            deltas,
+            freeze: false,
+        })
+    }
+
+    fn deltas<M: Module<B>>(&self, module: &M) -> HashMap<ParamId, Tensor<B, 2>> {
+        let ids = list_param_paths(module)
+            .into_iter()
+            .collect::<HashMap<_, _>>();
+
+        self.adapters
+            .iter()
+            .map(|adapter| {
+                let id = *ids.get(&adapter.path).unwrap_or_else(|| {
+                    panic!("The module has no parameter at path {}", adapter.path)
+                });
+                let delta = adapter
+                    .down
+                    .val()
+                    .matmul(adapter.up.val())
+                    .mul_scalar(self.scaling);
+
+                (id, delta)
+            })
+            .collect()
+    }
+}
+
+impl<B: Backend> ModuleDisplay for Lora<B> {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        let rank = self
+            .adapters
+            .first()
+            .map(|adapter| adapter.up.dims()[0])
+            .unwrap_or_default();
+
+        content
+            .add("adapters", &self.adapters.len())
+            .add("rank", &rank)
+            .add("scaling", &self.scaling)
+            .optional()
+    }
+}
+
+/// A [linear](Linear) layer with a low-rank adapter ([LoRA](https://arxiv.org/abs/2106.09685)).
+///
+/// The parameters of the wrapped layer are frozen, and the output of the adapter,
+/// `alpha / rank * dropout(x) * down * up`, is added to the output of the layer. The adapter is part
+/// of the module, so it is saved in its record.
+///
+/// Should be created using [LoraConfig::init_linear].
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct LoraLinear<B: Backend> {
+    /// The frozen linear layer.
+    pub linear: Linear<B>,
+    /// Matrix of shape `[d_input, rank]`.
+    pub down: Param<Tensor<B, 2>>,
+    /// Matrix of shape `[rank, d_output]` initialized to zeros.
+    pub up: Param<Tensor<B, 2>>,
+    /// The scaling of the updates, `alpha / rank`.
+    pub scaling: f64,
+    /// The dropout applied to the inputs of the adapter.
+    pub dropout: Dropout,
+}
+
+impl<B: Backend> LoraLinear<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[..., d_input]`
+    /// - output: `[..., d_output]`
+    pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        let output = self.linear.forward(input.clone());
+
+        let update = linear(self.dropout.forward(input), self.down.val(), None);
+        let update = linear(update, self.up.val(), None);
+
+        output + update.mul_scalar(self.scaling)
+    }
+
+    /// Merges the adapter into the weight of the linear layer, e.g. to export the fine-tuned
+    /// module.
+    pub fn merge(self) -> Linear<B> {
+        let delta = self
+            .down
+            .val()
+            .matmul(self.up.val())
+            .mul_scalar(self.scaling);
+        let mut linear = self.linear;
+        linear.weight = linear.weight.map(|weight| weight + delta.detach());
+
+        linear
+    }
+}
+
+impl<B: Backend> ModuleDisplay for LoraLinear<B> {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        let [d_input, d_output] = self.linear.weight.dims();
+
+        content
+            .add("d_input", &d_input)
+            .add("d_output", &d_output)
+            .add("rank", &self.up.dims()[0])
+            .add("scaling", &self.scaling)
+            .optional()
+    }
+}
+
+struct LoraMapper<B: Backend> {
+    deltas: HashMap<ParamId, Tensor<B, 2>>,
+    freeze: bool,
+}
+
+impl<B: Backend> ModuleMapper<B> for LoraMapper<B> {
+    fn map_float<const D: usize>(&mut self, id: ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        let delta = self.deltas.remove(&id);
+
+        if self.freeze {
+            let tensor = tensor.detach();
+            return match delta {
+                Some(delta) => {
+                    let shape = tensor.shape();
+                    tensor + delta.reshape(shape)
+                }
+                None => tensor,
+            };
+        }
+
+        match delta {
+            Some(delta) => {
+                let require_grad = tensor.is_require_grad();
+                let shape = tensor.shape();
+                let merged = tensor.detach() + delta.reshape(shape).detach();
+
+                merged.set_require_grad(require_grad)
+            }
+            None => tensor,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        TestAutodiffBackend, TestBackend,
+        module::AutodiffModule,
+        nn::{
+            LinearConfig,
+            attention::{MhaInput, MultiHeadAttentionConfig},
+            conv::Conv2dConfig,
+        },
+        optim::GradientsParams,
+        tensor::Distribution,
+    };
+    use burn_tensor::{Tolerance, ops::FloatElem};
+
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn should_adapt_targeted_attention_projections() {
+        let device = Default::default();
+        let mha = MultiHeadAttentionConfig::new(8, 2).init::<TestBackend>(&device);
+
+        let lora = LoraConfig::new(2, 4.0)
+            .with_targets(alloc::vec!["query".into(), "val*".into()])
+            .init(&mha, &device);
+
+        let paths = lora
+            .adapters
+            .iter()
+            .map(|adapter| adapter.path.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(paths, ["query.weight", "value.weight"]);
+        assert_eq!(lora.adapters[0].down.dims(), [8, 2]);
+        assert_eq!(lora.adapters[0].up.dims(), [2, 8]);
+        assert_eq!(lora.scaling, 2.0);
+
+        // The up projections are initialized to zeros.
+        let x = Tensor::<TestBackend, 3>::random([2, 3, 8], Distribution::Default, &device);
+        let expected = mha.forward(MhaInput::self_attn(x.clone())).context;
+        let output = lora.apply(mha).forward(MhaInput::self_attn(x)).context;
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&expected.into_data(), Tolerance::default());
+    }
+
+    #[test]
+    #[should_panic(expected = "LoRA dropout is only supported by the wrapped layers")]
+    fn should_reject_dropout_without_wrapped_layers() {
+        let device = Default::default();
+        let conv = Conv2dConfig::new([2, 4], [3, 3]).init::<TestBackend>(&device);
+
+        LoraConfig::new(2, 4.0)
+            .with_dropout(0.1)
+            .init(&conv, &device);
+    }
+
+    #[test]
+    fn should_only_train_adapters() {
+        let device = Default::default();
+        let linear = LinearConfig::new(4, 6).init::<TestAutodiffBackend>(&device);
+        let lora = LoraConfig::new(2, 2.0).init(&linear, &device);
+
+        let x = Tensor::<TestAutodiffBackend, 2>::random([3, 4], Distribution::Default, &device);
+        let grads = lora.apply(linear.clone()).forward(x).sum().backward();
+
+        assert!(linear.weight.grad(&grads).is_none());
+        assert!(linear.bias.as_ref().unwrap().grad(&grads).is_none());
+        assert!(lora.adapters[0].up.grad(&grads).is_some());
+
+        let grads = GradientsParams::from_grads(grads, &lora);
+        assert_eq!(grads.len(), 2);
+    }
+
+    #[test]
+    fn should_merge_adapters_into_weights() {
+        let device = Default::default();
+        let conv = Conv2dConfig::new([2, 4], [3, 3]).init::<TestAutodiffBackend>(&device);
+        let mut lora = LoraConfig::new(2, 2.0).init(&conv, &device);
+        lora.adapters[0].up =
+            Param::from_tensor(Tensor::random([2, 18], Distribution::Default, &device));
+
+        let x = Tensor::<TestBackend, 4>::random([1, 2, 5, 5], Distribution::Default, &device);
+        let expected = lora.valid().apply(conv.valid()).forward(x.clone());
+        let merged = lora.merge(conv);
+
+        assert!(merged.weight.is_require_grad());
+        merged
+            .valid()
+            .forward(x)
+            .into_data()
+            .assert_approx_eq::<FT>(&expected.into_data(), Tolerance::default());
+    }
+
+    #[test]
+    fn should_load_adapters_from_record() {
+        let device = Default::default();
+        let linear = LinearConfig::new(4, 6).init::<TestBackend>(&device);
+        let lora = LoraConfig::new(2, 2.0).init(&linear, &device);
+        let expected = lora.adapters[0].down.val();
+
+        let lora = LoraConfig::new(2, 2.0)
+            .init(&linear, &device)
+            .load_record(lora.into_record());
+
+        lora.adapters[0]
+            .down
+            .val()
+            .into_data()
+            .assert_eq(&expected.into_data(), true);
+    }
+
+    #[test]
+    fn lora_linear_should_only_train_adapter() {
+        let device = Default::default();
+        let linear = LinearConfig::new(4, 6).init::<TestAutodiffBackend>(&device);
+        let lora = LoraConfig::new(2, 2.0).init_linear(linear.clone());
+
+        // The up projection is initialized to zeros.
+        let x = Tensor::<TestAutodiffBackend, 3>::random([2, 3, 4], Distribution::Default, &device);
+        let output = lora.forward(x.clone());
+        output
+            .clone()
+            .into_data()
+            .assert_approx_eq::<FT>(&linear.forward(x).into_data(), Tolerance::default());
+
+        let grads = output.sum().backward();
+        assert!(lora.linear.weight.grad(&grads).is_none());
+        assert!(lora.up.grad(&grads).is_some());
+
+        let grads = GradientsParams::from_grads(grads, &lora);
+        assert_eq!(grads.len(), 2);
+    }
+
+    #[test]
+    fn lora_linear_should_save_adapter_in_record() {
+        let device = Default::default();
+        let config = LoraConfig::new(2, 2.0);
+        let mut lora = config.init_linear(LinearConfig::new(4, 6).init::<TestBackend>(&device));
+        lora.up = Param::from_tensor(Tensor::random([2, 6], Distribution::Default, &device));
+
+        let loaded = config
+            .init_linear(LinearConfig::new(4, 6).init::<TestBackend>(&device))
+            .load_record(lora.clone().into_record());
+
+        let x = Tensor::<TestBackend, 2>::random([3, 4], Distribution::Default, &device);
+        loaded
+            .forward(x.clone())
+            .into_data()
+            .assert_approx_eq::<FT>(&lora.forward(x).into_data(), Tolerance::default());
+    }
+
+    #[test]
+    fn lora_linear_should_merge_adapter_into_weight() {
+        let device = Default::default();
+        let mut lora = LoraConfig::new(2, 2.0)
+            .init_linear(LinearConfig::new(4, 6).init::<TestBackend>(&device));
+        lora.up = Param::from_tensor(Tensor::random([2, 6], Distribution::Default, &device));
+
+        let x = Tensor::<TestBackend, 2>::random([3, 4], Distribution::Default, &device);
+        let expected = lora.forward(x.clone());
+
+        lora.merge()
+            .forward(x)
+            .into_data()
+            .assert_approx_eq::<FT>(&expected.into_data(), Tolerance::default());
+    }
+}
@@ -0,0 +1,408 @@
+use crate as burn;
+
+use crate::module::{Content, DisplaySettings, ModuleDisplay};
+use crate::tensor::backend::Backend;
+use crate::tensor::{Bool, Int, Tensor};
+use crate::{config::Config, module::Module};
+use alloc::vec;
+
+use super::Reduction;
+
+/// Log probability of the impossible alignments, finite to keep the log-sum-exp stable.
+const NEG_INF: f32 = -1e30;
+
+/// Configuration to create a [CTC loss](CtcLoss).
+#[derive(Config, Debug)]
+pub struct CtcLossConfig {
+    /// The index of the blank class. Default: 0
+    #[config(default = 0)]
+    pub blank: usize,
+    /// Zero the infinite losses of the inputs too short to be aligned with their targets.
+    /// Default: false
+    #[config(default = false)]
+    pub zero_infinity: bool,
+}
+
+impl CtcLossConfig {
+    /// Initialize [CTC loss](CtcLoss).
+    pub fn init(&self) -> CtcLoss {
+        CtcLoss {
+            blank: self.blank,
+            zero_infinity: self.zero_infinity,
+        }
+    }
+}
+
+/// Calculate the Connectionist Temporal Classification loss between unsegmented input sequences
+/// and their target sequences.
+///
+/// The loss is the negative log likelihood of the targets, summed over all the alignments of the
+/// targets with the inputs computed with the forward algorithm.
+///
+/// See [Connectionist Temporal Classification: Labelling Unsegmented Sequence Data with Recurrent Neural Networks](https://www.cs.toronto.edu/~graves/icml_2006.pdf).
+#[derive(Module, Debug, Clone)]
+#[module(custom_display)]
+pub struct CtcLoss {
+    /// The index of the blank class.
+    pub blank: usize,
+    /// Zero the infinite losses.
+    pub zero_infinity: bool,
+}
+
+impl ModuleDisplay for CtcLoss {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        content
+            .add("blank", &self.blank)
+            .add("zero_infinity", &self.zero_infinity)
+            .optional()
+    }
+}
+
+impl CtcLoss {
+    /// Compute the loss of each sequence, then reduce to a single loss value.
+    ///
+    /// `Reduction::Auto` behaves as `Reduction::Mean`, which divides the loss of each sequence by
+    /// its target length before averaging over the batch.
+    ///
+    /// # Shapes
+    ///
+    /// - log_probs: `[batch_size, seq_length, num_classes]`
+    /// - targets: `[batch_size, max_target_length]`
+    /// - input_lengths: `[batch_size]`
+    /// - target_lengths: `[batch_size]`
+    /// - output: `[1]`
+    pub fn forward<B: Backend>(
+        &self,
+        log_probs: Tensor<B, 3>,
+        targets: Tensor<B, 2, Int>,
+        input_lengths: Tensor<B, 1, Int>,
+        target_lengths: Tensor<B, 1, Int>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        let loss =
+            self.forward_no_reduction(log_probs, targets, input_lengths, target_lengths.clone());
+        match reduction {
+            Reduction::Mean | Reduction::Auto => {
+                loss.div(target_lengths.float().clamp_min(1.0)).mean()
+            }
+            Reduction::Sum => loss.sum(),
+        }
+    }
+
+    /// Compute the loss of each sequence.
+    ///
+    /// The targets are padded to the maximum target length, and the inputs after their length are
+    /// ignored.
+    ///
+    /// # Shapes
+    ///
+    /// - log_probs: `[batch_size, seq_length, num_classes]`
+    /// - targets: `[batch_size, max_target_length]`
+    /// - input_lengths: `[batch_size]`
+    /// - target_lengths: `[batch_size]`
+    /// - output: `[batch_size]`
+    pub fn forward_no_reduction<B: Backend>(
+        &self,
+        log_probs: Tensor<B, 3>,
+        targets: Tensor<B, 2, Int>,
+        input_lengths: Tensor<B, 1, Int>,
+        target_lengths: Tensor<B, 1, Int>,
+    ) -> Tensor<B, 1> {
+        let [batch_size, seq_length, num_classes] = log_probs.dims();
+        let [batch_size_targets, max_target_length] = targets.dims();
+        assert_eq!(
+            batch_size, batch_size_targets,
+            "Batch size of log_probs ({batch_size}) must match batch size of targets ({batch_size_targets})"
+        );
+        assert!(
+            self.blank < num_classes,
+            "Blank index ({}) must be lower than the number of classes ({num_classes})",
+            self.blank
+        );
+        let device = log_probs.device();
+        let blank = self.blank as i64;
+        let num_states = 2 * max_target_length + 1;
+
+        // The targets interleaved with blanks, e.g. [blank, a, blank, b, blank].
+        let targets = targets.clamp(0, num_classes as i64 - 1);
+        let blanks = Tensor::<B, 2, Int>::full([batch_size, max_target_length], blank, &device);
+        let states = Tensor::cat(
+            vec![
+                Tensor::stack::<3>(vec![blanks, targets], 2)
+                    .reshape([batch_size, 2 * max_target_length]),
+                Tensor::full([batch_size, 1], blank, &device),
+            ],
+            1,
+        );
+
+        // A label can be reached from the previous label when they differ.
+        let skip = states.clone().not_equal_elem(blank).bool_and(
+            states
+                .clone()
+                .not_equal(shift_states(states.clone(), blank)),
+        );
+        let skip_penalty = Tensor::<B, 2>::zeros([batch_size, num_states], &device)
+            .mask_fill(skip.bool_not(), NEG_INF);
+
+        let emissions = log_probs.gather(
+            2,
+            states
+                .unsqueeze_dim::<3>(1)
+                .expand([batch_size, seq_length, num_states]),
+        );
+        let emission = |t: usize| {
+            emissions
+                .clone()
+                .slice([0..batch_size, t..t + 1, 0..num_states])
+                .reshape([batch_size, num_states])
+        };
+
+        // Alignments start with a blank or the first label.
+        let first_states = Tensor::<B, 1, Int>::arange(0..num_states as i64, &device)
+            .greater_equal_elem(2)
+            .unsqueeze::<2>()
+            .expand([batch_size, num_states]);
+        let mut alpha = emission(0).mask_fill(first_states, NEG_INF);
+
+        for t in 1..seq_length {
+            let next = log_sum_exp(vec![
+                alpha.clone(),
+                shift(alpha.clone(), 1),
+                shift(alpha.clone(), 2) + skip_penalty.clone(),
+            ]) + emission(t);
+
+            let ended: Tensor<B, 2, Bool> = input_lengths
+                .clone()
+                .lower_equal_elem(t as i64)
+                .unsqueeze_dim::<2>(1)
+                .expand([batch_size, num_states]);
+            alpha = next.mask_where(ended, alpha);
+        }
+
+        // Alignments end with the last label or the following blank.
+        let last_state = target_lengths
+            .clone()
+            .mul_scalar(2)
+            .reshape([batch_size, 1]);
+        let last_label = alpha
+            .clone()
+            .gather(1, last_state.clone().sub_scalar(1).clamp_min(0))
+            .mask_fill(last_state.clone().equal_elem(0), NEG_INF);
+        let log_likelihood = log_sum_exp(vec![alpha.gather(1, last_state), last_label]);
+
+        let loss = log_likelihood.neg().reshape([batch_size]);
+        let impossible = loss.clone().greater_elem(-NEG_INF / 2.0);
+        let infinity = if self.zero_infinity {
+            0.0
+        } else {
+            f32::INFINITY
+        };
+
+        loss.mask_fill(impossible, infinity)
+    }
+}
+
+/// The previous state of each state, skipping the blank in between.
+fn shift_states<B: Backend>(states: Tensor<B, 2, Int>, blank: i64) -> Tensor<B, 2, Int> {
+    let [batch_size, num_states] = states.dims();
+    let padding = Tensor::full([batch_size, num_states.min(2)], blank, &states.device());
+
+    if num_states <= 2 {
+        return padding;
+    }
+
+    Tensor::cat(
+        vec![padding, states.slice([0..batch_size, 0..num_states - 2])],
+        1,
+    )
+}
+
+/// Shifts the log probabilities of the states to the next ones.
+fn shift<B: Backend>(alpha: Tensor<B, 2>, steps: usize) -> Tensor<B, 2> {
+    let [batch_size, num_states] = alpha.dims();
+    let padding = Tensor::full(
+        [batch_size, num_states.min(steps)],
+        NEG_INF,
+        &alpha.device(),
+    );
+
+    if num_states <= steps {
+        return padding;
+    }
+
+    Tensor::cat(
+        vec![padding, alpha.slice([0..batch_size, 0..num_states - steps])],
+        1,
+    )
+}
+
+fn log_sum_exp<B: Backend>(tensors: alloc::vec::Vec<Tensor<B, 2>>) -> Tensor<B, 2> {
+    let max = tensors
+        .iter()
+        .cloned()
+        .reduce(|a, b| a.max_pair(b))
+        .unwrap()
+        .detach();
+
+    tensors
+        .into_iter()
+        .map(|tensor| (tensor - max.clone()).exp())
+        .reduce(|a, b| a + b)
+        .unwrap()
+        .log()
+        + max
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use crate::tensor::TensorData;
+    use burn_tensor::{Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    fn log_probs() -> TensorData {
+        TensorData::from([
+            [
+                [-1.151251, -0.751251, -1.551251],
+                [-0.66238, -1.76238, -1.16238],
+                [-1.101943, -1.001943, -1.201943],
+            ],
+            [
+                [-1.899574, -0.599574, -1.199574],
+                [-0.782352, -0.982352, -1.782352],
+                [-0.945911, -1.345911, -1.045911],
+            ],
+            [
+                [-1.18027, -1.68027, -0.68027],
+                [-1.111901, -0.911901, -1.311901],
+                [-0.551445, -1.551445, -1.551445],
+            ],
+        ])
+    }
+
+    fn inputs<B: Backend>(
+        device: &B::Device,
+    ) -> (Tensor<B, 2, Int>, Tensor<B, 1, Int>, Tensor<B, 1, Int>) {
+        (
+            Tensor::from_ints([[1, 2], [1, 1], [2, 0]], device),
+            Tensor::from_ints([3, 3, 2], device),
+            Tensor::from_ints([2, 2, 1], device),
+        )
+    }
+
+    #[test]
+    fn test_ctc_loss() {
+        let device = Default::default();
+        let log_probs = Tensor::<TestBackend, 3>::from_data(log_probs(), &device);
+        let (targets, input_lengths, target_lengths) = inputs(&device);
+
+        let loss = CtcLossConfig::new().init();
+        let loss_no_reduction = loss.forward_no_reduction(
+            log_probs.clone(),
+            targets.clone(),
+            input_lengths.clone(),
+            target_lengths.clone(),
+        );
+        let loss_mean = loss.forward(
+            log_probs,
+            targets,
+            input_lengths,
+            target_lengths,
+            Reduction::Mean,
+        );
+
+        loss_no_reduction.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([1.574289, 2.727838, 0.952625]),
+            Tolerance::default(),
+        );
+        loss_mean
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([1.034563]), Tolerance::default());
+    }
+
+    #[test]
+    fn test_ctc_loss_zero_infinity() {
+        let device = Default::default();
+        let log_probs = Tensor::<TestBackend, 3>::from_data(log_probs(), &device);
+        // Repeated labels need a blank in between, which doesn't fit in two inputs.
+        let targets = Tensor::from_ints([[1, 1], [1, 2], [2, 0]], &device);
+        let input_lengths = Tensor::from_ints([2, 3, 2], &device);
+        let target_lengths = Tensor::from_ints([2, 2, 0], &device);
+
+        let loss = CtcLossConfig::new().init().forward_no_reduction(
+            log_probs.clone(),
+            targets.clone(),
+            input_lengths.clone(),
+            target_lengths.clone(),
+        );
+        let loss_zero_infinity = CtcLossConfig::new()
+            .with_zero_infinity(true)
+            .init()
+            .forward_no_reduction(log_probs, targets, input_lengths, target_lengths);
+
+        let loss = loss.into_data().to_vec::<f32>().unwrap();
+        assert_eq!(loss[0], f32::INFINITY);
+        // An empty target is aligned with blanks only: -(-1.18027 - 1.111901).
+        assert!((loss[2] - 2.292171).abs() < 1e-4);
+        let loss_zero_infinity = loss_zero_infinity.into_data().to_vec::<f32>().unwrap();
+        assert_eq!(loss_zero_infinity[0], 0.0);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_ctc_ad_loss() {
+        let device = Default::default();
+        let log_probs =
+            Tensor::<crate::TestAutodiffBackend, 3>::from_data(log_probs(), &device).require_grad();
+        let (targets, input_lengths, target_lengths) = inputs(&device);
+
+        let loss = CtcLossConfig::new().init();
+        let grads = loss
+            .forward(
+                log_probs.clone(),
+                targets,
+                input_lengths,
+                target_lengths,
+                Reduction::Sum,
+            )
+            .backward();
+
+        log_probs
+            .grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq::<FT>(
+                &TensorData::from([
+                    [
+                        [-0.07877, -0.92123, 0.0],
+                        [-0.353, -0.19627, -0.45073],
+                        [-0.23662, 0.0, -0.76338],
+                    ],
+                    [[0.0, -1.0, 0.0], [-1.0, 0.0, 0.0], [0.0, -1.0, 0.0]],
+                    [
+                        [-0.21448, 0.0, -0.78552],
+                        [-0.43191, 0.0, -0.56809],
+                        [0.0, 0.0, 0.0],
+                    ],
+                ]),
+                Tolerance::absolute(1e-4),
+            );
+    }
+
+    #[test]
+    fn display() {
+        let loss = CtcLossConfig::new().init();
+
+        assert_eq!(
+            alloc::format!("{loss}"),
+            "CtcLoss {blank: 0, zero_infinity: false}"
+        );
+    }
+}
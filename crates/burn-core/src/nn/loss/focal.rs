@@ -0,0 +1,212 @@
+use crate as burn;
+
+use crate::module::{Content, DisplaySettings, ModuleDisplay};
+use crate::tensor::backend::Backend;
+use crate::tensor::{Tensor, activation::relu, activation::sigmoid};
+use crate::{config::Config, module::Module};
+
+use super::Reduction;
+
+/// Configuration to create a [focal loss](FocalLoss).
+#[derive(Config, Debug)]
+pub struct FocalLossConfig {
+    /// The weight of the positive targets, the negative targets are weighted by `1 - alpha`.
+    /// No weighting is applied when `None`. Default: Some(0.25)
+    #[config(default = "Some(0.25)")]
+    pub alpha: Option<f32>,
+    /// The focusing parameter reducing the loss of the well-classified examples. Default: 2.0
+    #[config(default = 2.0)]
+    pub gamma: f64,
+}
+
+impl FocalLossConfig {
+    /// Initialize [focal loss](FocalLoss).
+    pub fn init(&self) -> FocalLoss {
+        if let Some(alpha) = self.alpha {
+            assert!(
+                (0.0..=1.0).contains(&alpha),
+                "Alpha of focal loss should be in interval [0, 1]. Got {alpha}"
+            );
+        }
+        assert!(
+            self.gamma >= 0.0,
+            "Gamma of focal loss should be non-negative. Got {}",
+            self.gamma
+        );
+
+        FocalLoss {
+            alpha: self.alpha,
+            gamma: self.gamma,
+        }
+    }
+}
+
+/// Calculate the focal loss between the input logits and the binary targets.
+///
+/// The binary cross entropy of each element is scaled down by how well it is classified,
+/// focusing the training on the hard examples:
+///
+/// ```text
+/// FL(p_t) = -alpha_t * (1 - p_t)^gamma * log(p_t)
+/// ```
+///
+/// where `p_t` is the predicted probability of the target.
+///
+/// See [Focal Loss for Dense Object Detection](https://arxiv.org/abs/1708.02002).
+#[derive(Module, Debug, Clone)]
+#[module(custom_display)]
+pub struct FocalLoss {
+    /// The weight of the positive targets.
+    pub alpha: Option<f32>,
+    /// The focusing parameter.
+    pub gamma: f64,
+}
+
+impl ModuleDisplay for FocalLoss {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        content
+            .add("alpha", &self.alpha)
+            .add("gamma", &self.gamma)
+            .optional()
+    }
+}
+
+impl FocalLoss {
+    /// Compute the loss element-wise for the logits and targets, then reduce
+    /// to a single loss value.
+    ///
+    /// `Reduction::Auto` behaves as `Reduction::Mean`.
+    ///
+    /// # Shapes
+    ///
+    /// - logits: `[...dims]`
+    /// - targets: `[...dims]` with values in `[0, 1]`
+    /// - output: `[1]`
+    pub fn forward<const D: usize, B: Backend>(
+        &self,
+        logits: Tensor<B, D>,
+        targets: Tensor<B, D>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        let loss = self.forward_no_reduction(logits, targets);
+        match reduction {
+            Reduction::Mean | Reduction::Auto => loss.mean(),
+            Reduction::Sum => loss.sum(),
+        }
+    }
+
+    /// Compute the loss element-wise for the logits and targets.
+    ///
+    /// # Shapes
+    ///
+    /// - logits: `[...dims]`
+    /// - targets: `[...dims]` with values in `[0, 1]`
+    /// - output: `[...dims]`
+    pub fn forward_no_reduction<const D: usize, B: Backend>(
+        &self,
+        logits: Tensor<B, D>,
+        targets: Tensor<B, D>,
+    ) -> Tensor<B, D> {
+        let (dims_logits, dims_targets) = (logits.dims(), targets.dims());
+        assert!(
+            dims_logits == dims_targets,
+            "Shape of logits ({dims_logits:?}) should match shape of targets ({dims_targets:?})."
+        );
+
+        // Numerically stable binary cross entropy with logits.
+        let cross_entropy = relu(logits.clone()) - logits.clone() * targets.clone()
+            + logits.clone().abs().neg().exp().log1p();
+
+        let probs = sigmoid(logits);
+        let probs_target =
+            probs.clone() * targets.clone() + (probs.neg() + 1.0) * (targets.clone().neg() + 1.0);
+        let loss = cross_entropy * (probs_target.neg() + 1.0).powf_scalar(self.gamma);
+
+        match self.alpha {
+            Some(alpha) => loss * (targets * (2.0 * alpha - 1.0) + (1.0 - alpha)),
+            None => loss,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use crate::tensor::TensorData;
+    use burn_tensor::{Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+    type TestTensor<const D: usize> = Tensor<TestBackend, D>;
+
+    #[test]
+    fn test_focal_loss() {
+        let device = Default::default();
+        let logits = TestTensor::<2>::from_floats([[0.5, -1.0], [2.0, 0.0]], &device);
+        let targets = TestTensor::<2>::from_floats([[1.0, 0.0], [0.0, 1.0]], &device);
+
+        let loss = FocalLossConfig::new().init();
+        let loss_no_reduction = loss.forward_no_reduction(logits.clone(), targets.clone());
+        let loss_mean = loss.forward(logits.clone(), targets.clone(), Reduction::Mean);
+
+        loss_no_reduction.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([[0.016893, 0.016994], [1.237559, 0.043322]]),
+            Tolerance::default(),
+        );
+        loss_mean
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([0.328692]), Tolerance::default());
+
+        // Without focusing nor weighting, the focal loss is the binary cross entropy.
+        let loss = FocalLossConfig::new()
+            .with_alpha(None)
+            .with_gamma(0.0)
+            .init();
+        loss.forward_no_reduction(logits, targets)
+            .into_data()
+            .assert_approx_eq::<FT>(
+                &TensorData::from([[0.474077, 0.313262], [2.126928, 0.693147]]),
+                Tolerance::default(),
+            );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_focal_ad_loss() {
+        type TestAutodiffTensor = Tensor<crate::TestAutodiffBackend, 2>;
+
+        let device = Default::default();
+        let logits =
+            TestAutodiffTensor::from_floats([[0.5, -1.0], [2.0, 0.0]], &device).require_grad();
+        let targets = TestAutodiffTensor::from_floats([[1.0, 0.0], [0.0, 1.0]], &device);
+
+        let loss = FocalLossConfig::new().init();
+        let grads = loss
+            .forward(logits.clone(), targets, Reduction::Sum)
+            .backward();
+
+        logits
+            .grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq::<FT>(
+                &TensorData::from([[-0.034484, 0.039436], [0.807535, -0.074572]]),
+                Tolerance::default(),
+            );
+    }
+
+    #[test]
+    fn display() {
+        let loss = FocalLossConfig::new().init();
+
+        assert_eq!(
+            alloc::format!("{loss}"),
+            "FocalLoss {alpha: 0.25, gamma: 2}"
+        );
+    }
+}
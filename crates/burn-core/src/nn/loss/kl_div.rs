@@ -0,0 +1,230 @@
+use crate as burn;
+
+use crate::module::{Content, DisplaySettings, ModuleDisplay};
+use crate::tensor::Tensor;
+use crate::tensor::backend::Backend;
+use crate::{config::Config, module::Module};
+
+use super::Reduction;
+
+/// Configuration to create a [KL divergence loss](KlDivLoss).
+#[derive(Config, Debug)]
+pub struct KlDivLossConfig {
+    /// The targets are given as log probabilities instead of probabilities. Default: false
+    #[config(default = false)]
+    pub log_target: bool,
+}
+
+impl KlDivLossConfig {
+    /// Initialize [KL divergence loss](KlDivLoss).
+    pub fn init(&self) -> KlDivLoss {
+        KlDivLoss {
+            log_target: self.log_target,
+        }
+    }
+}
+
+/// Calculate the Kullback-Leibler divergence between the target distribution and the input
+/// log probabilities.
+///
+/// ```text
+/// L(x, y) = y * (log(y) - x)
+/// ```
+///
+/// The inputs are expected to be log probabilities, e.g. the output of a
+/// [log_softmax](crate::tensor::activation::log_softmax).
+///
+/// The [mean](Reduction::Mean) reduction averages the loss over all the elements, so it is the KL
+/// divergence divided by the number of classes. Use
+/// [forward_batch_mean](KlDivLoss::forward_batch_mean) to get the KL divergence of each
+/// distribution averaged over the batch.
+#[derive(Module, Debug, Clone)]
+#[module(custom_display)]
+pub struct KlDivLoss {
+    /// The targets are given as log probabilities.
+    pub log_target: bool,
+}
+
+impl ModuleDisplay for KlDivLoss {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        content.add("log_target", &self.log_target).optional()
+    }
+}
+
+impl KlDivLoss {
+    /// Compute the loss element-wise for the inputs and targets, then reduce
+    /// to a single loss value.
+    ///
+    /// `Reduction::Auto` behaves as `Reduction::Mean`.
+    ///
+    /// # Shapes
+    ///
+    /// - inputs: `[...dims]`
+    /// - targets: `[...dims]`
+    /// - output: `[1]`
+    pub fn forward<const D: usize, B: Backend>(
+        &self,
+        inputs: Tensor<B, D>,
+        targets: Tensor<B, D>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        let loss = self.forward_no_reduction(inputs, targets);
+        match reduction {
+            Reduction::Mean | Reduction::Auto => loss.mean(),
+            Reduction::Sum => loss.sum(),
+        }
+    }
+
+    /// Compute the loss element-wise for the inputs and targets, then sum it and divide it by the
+    /// batch size, i.e. the size of the first dimension.
+    ///
+    /// Unlike the [mean](Reduction::Mean) reduction, which divides by the number of elements, this
+    /// gives the KL divergence of each distribution averaged over the batch.
+    ///
+    /// # Shapes
+    ///
+    /// - inputs: `[batch_size, ...dims]`
+    /// - targets: `[batch_size, ...dims]`
+    /// - output: `[1]`
+    pub fn forward_batch_mean<const D: usize, B: Backend>(
+        &self,
+        inputs: Tensor<B, D>,
+        targets: Tensor<B, D>,
+    ) -> Tensor<B, 1> {
+        let batch_size = inputs.dims()[0];
+        let loss = self.forward_no_reduction(inputs, targets);
+
+        loss.sum().div_scalar(batch_size as f32)
+    }
+
+    /// Compute the loss element-wise for the inputs and targets.
+    ///
+    /// # Shapes
+    ///
+    /// - inputs: `[...dims]`
+    /// - targets: `[...dims]`
+    /// - output: `[...dims]`
+    pub fn forward_no_reduction<const D: usize, B: Backend>(
+        &self,
+        inputs: Tensor<B, D>,
+        targets: Tensor<B, D>,
+    ) -> Tensor<B, D> {
+        let (dims_inputs, dims_targets) = (inputs.dims(), targets.dims());
+        assert!(
+            dims_inputs == dims_targets,
+            "Shape of inputs ({dims_inputs:?}) should match shape of targets ({dims_targets:?})."
+        );
+
+        if self.log_target {
+            targets.clone().exp() * (targets - inputs)
+        } else {
+            // Zero targets don't contribute to the loss, avoid multiplying by log(0).
+            let log_targets = targets.clone().clamp_min(f32::MIN_POSITIVE).log();
+            targets * (log_targets - inputs)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use crate::tensor::TensorData;
+    use burn_tensor::{Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+    type TestTensor<const D: usize> = Tensor<TestBackend, D>;
+
+    fn inputs() -> TensorData {
+        // log([[0.2, 0.3, 0.5], [0.6, 0.3, 0.1]])
+        TensorData::from([
+            [-1.609438, -1.203973, -0.693147],
+            [-0.510826, -1.203973, -2.302585],
+        ])
+    }
+
+    #[test]
+    fn test_kl_div_loss() {
+        let device = Default::default();
+        let inputs = TestTensor::<2>::from_data(inputs(), &device);
+        let targets = TestTensor::<2>::from_floats([[0.1, 0.4, 0.5], [0.3, 0.3, 0.4]], &device);
+
+        let loss = KlDivLossConfig::new().init();
+        let loss_no_reduction = loss.forward_no_reduction(inputs.clone(), targets.clone());
+        let loss_sum = loss.forward(inputs.clone(), targets.clone(), Reduction::Sum);
+        let loss_mean = loss.forward(inputs.clone(), targets.clone(), Reduction::Mean);
+        let loss_batch_mean = loss.forward_batch_mean(inputs.clone(), targets.clone());
+        let loss_log_target = KlDivLossConfig::new()
+            .with_log_target(true)
+            .init()
+            .forward_no_reduction(inputs, targets.clone().log());
+
+        let expected = TensorData::from([[-0.069315, 0.115073, 0.0], [-0.207944, 0.0, 0.554518]]);
+        loss_no_reduction
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+        loss_log_target
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+        loss_sum
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([0.392332]), Tolerance::default());
+        loss_mean
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([0.065389]), Tolerance::default());
+        loss_batch_mean
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([0.196166]), Tolerance::default());
+    }
+
+    #[test]
+    fn test_kl_div_loss_zero_target() {
+        let device = Default::default();
+        let inputs = TestTensor::<1>::from_floats([-1.0, -0.5], &device);
+        let targets = TestTensor::<1>::from_floats([0.0, 1.0], &device);
+
+        let loss = KlDivLossConfig::new()
+            .init()
+            .forward_no_reduction(inputs, targets);
+
+        loss.into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([0.0, 0.5]), Tolerance::default());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_kl_div_ad_loss() {
+        type TestAutodiffTensor = Tensor<crate::TestAutodiffBackend, 2>;
+
+        let device = Default::default();
+        let inputs = TestAutodiffTensor::from_data(inputs(), &device).require_grad();
+        let targets = TestAutodiffTensor::from_floats([[0.1, 0.4, 0.5], [0.3, 0.3, 0.4]], &device);
+
+        let loss = KlDivLossConfig::new().init();
+        let grads = loss
+            .forward(inputs.clone(), targets, Reduction::Mean)
+            .backward();
+
+        // d/dx = -y / n
+        inputs
+            .grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq::<FT>(
+                &TensorData::from([[-0.016667, -0.066667, -0.083333], [-0.05, -0.05, -0.066667]]),
+                Tolerance::default(),
+            );
+    }
+
+    #[test]
+    fn display() {
+        let loss = KlDivLossConfig::new().init();
+
+        assert_eq!(alloc::format!("{loss}"), "KlDivLoss {log_target: false}");
+    }
+}
@@ -0,0 +1,172 @@
+use crate as burn;
+
+use crate::module::{Content, DisplaySettings, ModuleDisplay};
+use crate::tensor::backend::Backend;
+use crate::tensor::{Tensor, activation::relu};
+use crate::{config::Config, module::Module};
+
+use super::Reduction;
+
+/// Configuration to create a [margin ranking loss](MarginRankingLoss).
+#[derive(Config, Debug)]
+pub struct MarginRankingLossConfig {
+    /// The margin by which the inputs should be ranked. Default: 0.0
+    #[config(default = 0.0)]
+    pub margin: f32,
+}
+
+impl MarginRankingLossConfig {
+    /// Initialize [margin ranking loss](MarginRankingLoss).
+    pub fn init(&self) -> MarginRankingLoss {
+        MarginRankingLoss {
+            margin: self.margin,
+        }
+    }
+}
+
+/// Calculate the margin ranking loss between two inputs and the target ranking.
+///
+/// ```text
+/// L(x1, x2, y) = max(0, -y * (x1 - x2) + margin)
+/// ```
+///
+/// where the target `y` is `1` if `x1` should be ranked higher than `x2`, and `-1` otherwise.
+#[derive(Module, Debug, Clone)]
+#[module(custom_display)]
+pub struct MarginRankingLoss {
+    /// The margin by which the inputs should be ranked.
+    pub margin: f32,
+}
+
+impl ModuleDisplay for MarginRankingLoss {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        content.add("margin", &self.margin).optional()
+    }
+}
+
+impl MarginRankingLoss {
+    /// Compute the loss element-wise for the inputs and targets, then reduce
+    /// to a single loss value.
+    ///
+    /// `Reduction::Auto` behaves as `Reduction::Mean`.
+    ///
+    /// # Shapes
+    ///
+    /// - input1: `[...dims]`
+    /// - input2: `[...dims]`
+    /// - targets: `[...dims]` with values 1 or -1
+    /// - output: `[1]`
+    pub fn forward<const D: usize, B: Backend>(
+        &self,
+        input1: Tensor<B, D>,
+        input2: Tensor<B, D>,
+        targets: Tensor<B, D>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        let loss = self.forward_no_reduction(input1, input2, targets);
+        match reduction {
+            Reduction::Mean | Reduction::Auto => loss.mean(),
+            Reduction::Sum => loss.sum(),
+        }
+    }
+
+    /// Compute the loss element-wise for the inputs and targets.
+    ///
+    /// # Shapes
+    ///
+    /// - input1: `[...dims]`
+    /// - input2: `[...dims]`
+    /// - targets: `[...dims]` with values 1 or -1
+    /// - output: `[...dims]`
+    pub fn forward_no_reduction<const D: usize, B: Backend>(
+        &self,
+        input1: Tensor<B, D>,
+        input2: Tensor<B, D>,
+        targets: Tensor<B, D>,
+    ) -> Tensor<B, D> {
+        let (dims1, dims2, dims_targets) = (input1.dims(), input2.dims(), targets.dims());
+        assert!(
+            dims1 == dims2 && dims1 == dims_targets,
+            "Shapes of input1 ({dims1:?}), input2 ({dims2:?}) and targets ({dims_targets:?}) should match."
+        );
+
+        relu((input1 - input2).mul(targets).neg().add_scalar(self.margin))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use crate::tensor::TensorData;
+    use burn_tensor::{Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+    type TestTensor<const D: usize> = Tensor<TestBackend, D>;
+
+    #[test]
+    fn test_margin_ranking_loss() {
+        let device = Default::default();
+        let input1 = TestTensor::<1>::from_floats([1.0, 2.0, 0.5, -1.0], &device);
+        let input2 = TestTensor::<1>::from_floats([0.0, 2.5, 0.5, 1.0], &device);
+        let targets = TestTensor::<1>::from_floats([1.0, 1.0, -1.0, -1.0], &device);
+
+        let loss = MarginRankingLossConfig::new().with_margin(0.2).init();
+        let loss_no_reduction =
+            loss.forward_no_reduction(input1.clone(), input2.clone(), targets.clone());
+        let loss_mean = loss.forward(input1, input2, targets, Reduction::Mean);
+
+        loss_no_reduction.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([0.0, 0.7, 0.2, 0.0]),
+            Tolerance::default(),
+        );
+        loss_mean
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([0.225]), Tolerance::default());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_margin_ranking_ad_loss() {
+        type TestAutodiffTensor = Tensor<crate::TestAutodiffBackend, 1>;
+
+        let device = Default::default();
+        let input1 = TestAutodiffTensor::from_floats([1.0, 2.0, 0.5, -1.0], &device).require_grad();
+        let input2 = TestAutodiffTensor::from_floats([0.0, 2.5, 0.5, 1.0], &device).require_grad();
+        let targets = TestAutodiffTensor::from_floats([1.0, 1.0, -1.0, -1.0], &device);
+
+        let loss = MarginRankingLossConfig::new().with_margin(0.2).init();
+        let grads = loss
+            .forward(input1.clone(), input2.clone(), targets, Reduction::Sum)
+            .backward();
+
+        input1
+            .grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq::<FT>(
+                &TensorData::from([0.0, -1.0, 1.0, 0.0]),
+                Tolerance::default(),
+            );
+        input2
+            .grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq::<FT>(
+                &TensorData::from([0.0, 1.0, -1.0, 0.0]),
+                Tolerance::default(),
+            );
+    }
+
+    #[test]
+    fn display() {
+        let loss = MarginRankingLossConfig::new().with_margin(0.5).init();
+
+        assert_eq!(alloc::format!("{loss}"), "MarginRankingLoss {margin: 0.5}");
+    }
+}
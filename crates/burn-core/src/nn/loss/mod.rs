@@ -1,15 +1,27 @@
 mod binary_cross_entropy;
 mod cosine_embedding;
 mod cross_entropy;
+mod ctc;
+mod focal;
 mod huber;
+mod kl_div;
+mod margin_ranking;
 mod mse;
+mod nll;
 mod poisson;
 mod reduction;
+mod triplet_margin;
 
 pub use binary_cross_entropy::*;
 pub use cosine_embedding::*;
 pub use cross_entropy::*;
+pub use ctc::*;
+pub use focal::*;
 pub use huber::*;
+pub use kl_div::*;
+pub use margin_ranking::*;
 pub use mse::*;
+pub use nll::*;
 pub use poisson::*;
 pub use reduction::*;
+pub use triplet_margin::*;
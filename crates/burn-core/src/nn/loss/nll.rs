@@ -0,0 +1,267 @@
+use crate as burn;
+
+use crate::module::{Content, DisplaySettings, ModuleDisplay};
+use crate::tensor::{Int, Tensor, backend::Backend};
+use crate::{config::Config, module::Module};
+use alloc::vec::Vec;
+
+use super::Reduction;
+
+/// Configuration to create a [negative log likelihood loss](NllLoss).
+#[derive(Config, Debug)]
+pub struct NllLossConfig {
+    /// The target index ignored in the loss calculation.
+    pub ignore_index: Option<usize>,
+
+    /// The weight of each class.
+    ///
+    /// The order of the weight vector should correspond to the label integer assignment.
+    pub weights: Option<Vec<f32>>,
+}
+
+impl NllLossConfig {
+    /// Initialize [negative log likelihood loss](NllLoss).
+    pub fn init<B: Backend>(&self, device: &B::Device) -> NllLoss<B> {
+        if let Some(weights) = self.weights.as_ref() {
+            assert!(
+                weights.iter().all(|e| e >= &0.),
+                "Weights of negative log likelihood loss have to be non-negative."
+            );
+        }
+
+        NllLoss {
+            ignore_index: self.ignore_index,
+            weights: self
+                .weights
+                .as_ref()
+                .map(|e| Tensor::<B, 1>::from_floats(e.as_slice(), device)),
+        }
+    }
+}
+
+/// Calculate the negative log likelihood loss from the input log probabilities and the targets.
+///
+/// ```text
+/// L(x, y) = -weight[y] * x[y]
+/// ```
+///
+/// The inputs are expected to be log probabilities, e.g. the output of a
+/// [log_softmax](crate::tensor::activation::log_softmax).
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct NllLoss<B: Backend> {
+    /// The target index ignored in the loss calculation.
+    pub ignore_index: Option<usize>,
+    /// The weight of each class.
+    pub weights: Option<Tensor<B, 1>>,
+}
+
+impl<B: Backend> ModuleDisplay for NllLoss<B> {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        content
+            .add("ignore_index", &self.ignore_index)
+            .add("weights", &self.weights)
+            .optional()
+    }
+}
+
+impl<B: Backend> NllLoss<B> {
+    /// Compute the loss of each sample, then reduce to a single loss value.
+    ///
+    /// `Reduction::Auto` behaves as `Reduction::Mean`, which averages the loss by the sum of the
+    /// weights of the targets that aren't ignored.
+    ///
+    /// # Shapes
+    ///
+    /// - log_probs: `[batch_size, num_classes]`
+    /// - targets: `[batch_size]`
+    /// - output: `[1]`
+    pub fn forward(
+        &self,
+        log_probs: Tensor<B, 2>,
+        targets: Tensor<B, 1, Int>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        let loss = self.forward_no_reduction(log_probs, targets.clone());
+        match reduction {
+            Reduction::Mean | Reduction::Auto => loss.sum() / self.target_weights(targets).sum(),
+            Reduction::Sum => loss.sum(),
+        }
+    }
+
+    /// Compute the loss of each sample, ignored targets have a loss of zero.
+    ///
+    /// # Shapes
+    ///
+    /// - log_probs: `[batch_size, num_classes]`
+    /// - targets: `[batch_size]`
+    /// - output: `[batch_size]`
+    pub fn forward_no_reduction(
+        &self,
+        log_probs: Tensor<B, 2>,
+        targets: Tensor<B, 1, Int>,
+    ) -> Tensor<B, 1> {
+        let [batch_size, num_classes] = log_probs.dims();
+        let [targets_size] = targets.dims();
+        assert!(
+            batch_size == targets_size,
+            "Shape of targets ({targets_size}) should correspond to outer shape of log_probs ({batch_size})."
+        );
+
+        // Ignored targets may be out of range, gather a valid class and mask it afterward.
+        let indices = targets.clone().clamp(0, num_classes as i64 - 1);
+        let log_likelihood = log_probs
+            .gather(1, indices.reshape([batch_size, 1]))
+            .reshape([batch_size]);
+
+        log_likelihood.neg() * self.target_weights(targets)
+    }
+
+    /// The weight of each target, zero for the ignored targets.
+    fn target_weights(&self, targets: Tensor<B, 1, Int>) -> Tensor<B, 1> {
+        let [batch_size] = targets.dims();
+        let weights = match &self.weights {
+            Some(weights) => {
+                let [num_classes] = weights.dims();
+                weights
+                    .clone()
+                    .gather(0, targets.clone().clamp(0, num_classes as i64 - 1))
+            }
+            None => Tensor::ones([batch_size], &targets.device()),
+        };
+
+        match self.ignore_index {
+            Some(index) => weights.mask_fill(targets.equal_elem(index as i64), 0.0),
+            None => weights,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use crate::tensor::TensorData;
+    use burn_tensor::{Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    fn log_probs() -> TensorData {
+        TensorData::from([
+            [-1.2, -0.8, -1.4],
+            [-0.5, -1.5, -1.9],
+            [-2.0, -0.4, -1.3],
+            [-1.1, -1.1, -1.1],
+        ])
+    }
+
+    #[test]
+    fn test_nll_loss() {
+        let device = Default::default();
+        let log_probs = Tensor::<TestBackend, 2>::from_data(log_probs(), &device);
+        let targets = Tensor::<TestBackend, 1, Int>::from_ints([1, 0, 2, 2], &device);
+
+        let loss = NllLossConfig::new().init(&device);
+        let loss_no_reduction = loss.forward_no_reduction(log_probs.clone(), targets.clone());
+        let loss_mean = loss.forward(log_probs.clone(), targets.clone(), Reduction::Mean);
+        let loss_sum = loss.forward(log_probs, targets, Reduction::Sum);
+
+        loss_no_reduction.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([0.8, 0.5, 1.3, 1.1]),
+            Tolerance::default(),
+        );
+        loss_mean
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([0.925]), Tolerance::default());
+        loss_sum
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([3.7]), Tolerance::default());
+    }
+
+    #[test]
+    fn test_nll_loss_ignore_index_and_weights() {
+        let device = Default::default();
+        let log_probs = Tensor::<TestBackend, 2>::from_data(log_probs(), &device);
+        let targets = Tensor::<TestBackend, 1, Int>::from_ints([1, 0, 2, 2], &device);
+
+        let loss = NllLossConfig::new()
+            .with_ignore_index(Some(0))
+            .with_weights(Some(alloc::vec![1.0, 2.0, 0.5]))
+            .init(&device);
+        let loss_no_reduction = loss.forward_no_reduction(log_probs.clone(), targets.clone());
+        let loss_mean = loss.forward(log_probs, targets, Reduction::Mean);
+
+        loss_no_reduction.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([1.6, 0.0, 0.65, 0.55]),
+            Tolerance::default(),
+        );
+        // (1.6 + 0.65 + 0.55) / (2.0 + 0.5 + 0.5)
+        loss_mean
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([0.933333]), Tolerance::default());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_nll_ad_loss() {
+        type TestAutodiffBackend = crate::TestAutodiffBackend;
+
+        let device = Default::default();
+        let log_probs =
+            Tensor::<TestAutodiffBackend, 2>::from_data(log_probs(), &device).require_grad();
+        let targets = Tensor::<TestAutodiffBackend, 1, Int>::from_ints([1, 0, 2, 2], &device);
+
+        let loss = NllLossConfig::new()
+            .with_ignore_index(Some(0))
+            .with_weights(Some(alloc::vec![1.0, 2.0, 0.5]))
+            .init(&device);
+        let grads = loss
+            .forward(log_probs.clone(), targets, Reduction::Mean)
+            .backward();
+
+        // d/dx[y] = -weight[y] / sum(weights)
+        log_probs
+            .grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq::<FT>(
+                &TensorData::from([
+                    [0.0, -0.666667, 0.0],
+                    [0.0, 0.0, 0.0],
+                    [0.0, 0.0, -0.166667],
+                    [0.0, 0.0, -0.166667],
+                ]),
+                Tolerance::default(),
+            );
+    }
+
+    #[test]
+    #[should_panic = "Shape of targets"]
+    fn test_shape_tensors() {
+        let device = Default::default();
+        let log_probs = Tensor::<TestBackend, 2>::from_data(log_probs(), &device);
+        let targets = Tensor::<TestBackend, 1, Int>::from_ints([1, 0], &device);
+
+        let _loss = NllLossConfig::new()
+            .init(&device)
+            .forward_no_reduction(log_probs, targets);
+    }
+
+    #[test]
+    fn display() {
+        let device = Default::default();
+        let loss = NllLossConfig::new()
+            .with_ignore_index(Some(0))
+            .init::<TestBackend>(&device);
+
+        assert_eq!(
+            alloc::format!("{loss}"),
+            "NllLoss {ignore_index: 0, weights: None}"
+        );
+    }
+}
@@ -0,0 +1,239 @@
+use crate as burn;
+
+use crate::module::{Content, DisplaySettings, ModuleDisplay};
+use crate::tensor::backend::Backend;
+use crate::tensor::{Tensor, activation::relu};
+use crate::{config::Config, module::Module};
+
+use super::Reduction;
+
+/// Configuration to create a [triplet margin loss](TripletMarginLoss).
+#[derive(Config, Debug)]
+pub struct TripletMarginLossConfig {
+    /// The margin between the positive and negative distances. Default: 1.0
+    #[config(default = 1.0)]
+    pub margin: f32,
+    /// The norm degree of the pairwise distance. Default: 2.0
+    #[config(default = 2.0)]
+    pub p: f64,
+    /// A small value added to the differences to avoid a zero norm. Default: 1e-6
+    #[config(default = 1e-6)]
+    pub eps: f64,
+    /// Use the distance between the positive and negative samples when it's smaller than the
+    /// distance between the anchor and negative samples. Default: false
+    #[config(default = false)]
+    pub swap: bool,
+}
+
+impl TripletMarginLossConfig {
+    /// Initialize [triplet margin loss](TripletMarginLoss).
+    pub fn init(&self) -> TripletMarginLoss {
+        assert!(
+            self.p > 0.0,
+            "The norm degree of the triplet margin loss must be positive."
+        );
+        TripletMarginLoss {
+            margin: self.margin,
+            p: self.p,
+            eps: self.eps,
+            swap: self.swap,
+        }
+    }
+}
+
+/// Calculate the triplet margin loss between anchor, positive and negative samples.
+///
+/// ```text
+/// L(a, p, n) = max(0, d(a, p) - d(a, n) + margin)
+/// d(x, y) = ||x - y + eps||_p
+/// ```
+///
+/// See [Learning local feature descriptors with triplets and shallow convolutional neural networks](http://www.bmva.org/bmvc/2016/papers/paper119/index.html).
+#[derive(Module, Debug, Clone)]
+#[module(custom_display)]
+pub struct TripletMarginLoss {
+    /// The margin between the positive and negative distances.
+    pub margin: f32,
+    /// The norm degree of the pairwise distance.
+    pub p: f64,
+    /// A small value added to the differences to avoid a zero norm.
+    pub eps: f64,
+    /// Use the distance between the positive and negative samples when it's smaller.
+    pub swap: bool,
+}
+
+impl ModuleDisplay for TripletMarginLoss {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        content
+            .add("margin", &self.margin)
+            .add("p", &self.p)
+            .add("eps", &self.eps)
+            .add("swap", &self.swap)
+            .optional()
+    }
+}
+
+impl TripletMarginLoss {
+    /// Compute the loss of each triplet, then reduce to a single loss value.
+    ///
+    /// `Reduction::Auto` behaves as `Reduction::Mean`.
+    ///
+    /// # Shapes
+    ///
+    /// - anchor: `[batch_size, d_model]`
+    /// - positive: `[batch_size, d_model]`
+    /// - negative: `[batch_size, d_model]`
+    /// - output: `[1]`
+    pub fn forward<B: Backend>(
+        &self,
+        anchor: Tensor<B, 2>,
+        positive: Tensor<B, 2>,
+        negative: Tensor<B, 2>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        let loss = self.forward_no_reduction(anchor, positive, negative);
+        match reduction {
+            Reduction::Mean | Reduction::Auto => loss.mean(),
+            Reduction::Sum => loss.sum(),
+        }
+    }
+
+    /// Compute the loss of each triplet.
+    ///
+    /// # Shapes
+    ///
+    /// - anchor: `[batch_size, d_model]`
+    /// - positive: `[batch_size, d_model]`
+    /// - negative: `[batch_size, d_model]`
+    /// - output: `[batch_size]`
+    pub fn forward_no_reduction<B: Backend>(
+        &self,
+        anchor: Tensor<B, 2>,
+        positive: Tensor<B, 2>,
+        negative: Tensor<B, 2>,
+    ) -> Tensor<B, 1> {
+        let (dims_anchor, dims_positive, dims_negative) =
+            (anchor.dims(), positive.dims(), negative.dims());
+        assert!(
+            dims_anchor == dims_positive && dims_anchor == dims_negative,
+            "Shapes of anchor ({dims_anchor:?}), positive ({dims_positive:?}) and negative ({dims_negative:?}) should match."
+        );
+
+        let distance_positive = self.distance(anchor.clone(), positive.clone());
+        let mut distance_negative = self.distance(anchor, negative.clone());
+
+        if self.swap {
+            distance_negative = distance_negative.min_pair(self.distance(positive, negative));
+        }
+
+        relu(distance_positive - distance_negative + self.margin).squeeze(1)
+    }
+
+    fn distance<B: Backend>(&self, x1: Tensor<B, 2>, x2: Tensor<B, 2>) -> Tensor<B, 2> {
+        (x1 - x2)
+            .add_scalar(self.eps)
+            .abs()
+            .powf_scalar(self.p)
+            .sum_dim(1)
+            .powf_scalar(1.0 / self.p)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use crate::tensor::TensorData;
+    use burn_tensor::{Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+    type TestTensor<const D: usize> = Tensor<TestBackend, D>;
+
+    #[test]
+    fn test_triplet_margin_loss() {
+        let device = Default::default();
+        let anchor = TestTensor::<2>::from_floats([[0.0, 0.0], [1.0, 1.0]], &device);
+        let positive = TestTensor::<2>::from_floats([[3.0, 4.0], [1.0, 2.0]], &device);
+        let negative = TestTensor::<2>::from_floats([[0.0, 1.0], [4.0, 5.0]], &device);
+
+        let loss = TripletMarginLossConfig::new().with_eps(0.0).init();
+        let loss_no_reduction =
+            loss.forward_no_reduction(anchor.clone(), positive.clone(), negative.clone());
+        let loss_sum = loss.forward(
+            anchor.clone(),
+            positive.clone(),
+            negative.clone(),
+            Reduction::Sum,
+        );
+
+        // d(a, p) = [5, 1], d(a, n) = [1, 5]
+        loss_no_reduction
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([5.0, 0.0]), Tolerance::default());
+        loss_sum
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([5.0]), Tolerance::default());
+
+        // d(p, n) = [sqrt(18), sqrt(18)]
+        let loss = TripletMarginLossConfig::new()
+            .with_eps(0.0)
+            .with_swap(true)
+            .init();
+        loss.forward_no_reduction(anchor, positive, negative)
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([5.0, 0.0]), Tolerance::default());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_triplet_margin_ad_loss() {
+        type TestAutodiffTensor = Tensor<crate::TestAutodiffBackend, 2>;
+
+        let device = Default::default();
+        let anchor = TestAutodiffTensor::from_floats([[0.0, 0.0]], &device).require_grad();
+        let positive = TestAutodiffTensor::from_floats([[3.0, 4.0]], &device).require_grad();
+        let negative = TestAutodiffTensor::from_floats([[0.0, 1.0]], &device).require_grad();
+
+        let loss = TripletMarginLossConfig::new().with_eps(0.0).init();
+        let grads = loss
+            .forward(
+                anchor.clone(),
+                positive.clone(),
+                negative.clone(),
+                Reduction::Sum,
+            )
+            .backward();
+
+        // d(a - p) / da = (a - p) / ||a - p||, d(a - n) / da = (a - n) / ||a - n||
+        anchor
+            .grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([[-0.6, 0.2]]), Tolerance::default());
+        positive
+            .grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([[0.6, 0.8]]), Tolerance::default());
+        negative
+            .grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([[0.0, -1.0]]), Tolerance::default());
+    }
+
+    #[test]
+    fn display() {
+        let loss = TripletMarginLossConfig::new().init();
+
+        assert_eq!(
+            alloc::format!("{loss}"),
+            "TripletMarginLoss {margin: 1, p: 2, eps: 0.000001, swap: false}"
+        );
+    }
+}
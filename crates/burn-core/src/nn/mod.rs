@@ -27,8 +27,11 @@ pub use activation::{
 
 mod dropout;
 mod embedding;
+mod fake_quant;
 mod initializer;
 mod linear;
+mod lora;
+mod observer;
 mod padding;
 mod pos_encoding;
 mod rnn;
@@ -36,12 +39,15 @@ mod rope_encoding;
 mod unfold;
 
 pub mod norm;
-pub use norm::{batch::*, group::*, instance::*, layer::*, rms::*};
+pub use norm::{batch::*, group::*, instance::*, layer::*, rms::*, spectral::*, weight::*};
 
 pub use dropout::*;
 pub use embedding::*;
+pub use fake_quant::*;
 pub use initializer::*;
 pub use linear::*;
+pub use lora::*;
+pub use observer::*;
 pub use padding::*;
 pub use pos_encoding::*;
 pub use rnn::*;
@@ -17,6 +17,8 @@ pub(crate) mod group;
 pub(crate) mod instance;
 pub(crate) mod layer;
 pub(crate) mod rms;
+pub(crate) mod spectral;
+pub(crate) mod weight;
 
 mod normalization_wrapper;
 
@@ -26,3 +28,5 @@ pub use instance::*;
 pub use layer::*;
 pub use normalization_wrapper::*;
 pub use rms::*;
+pub use spectral::*;
+pub use weight::*;
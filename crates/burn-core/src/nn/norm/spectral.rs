@@ -0,0 +1,382 @@
+use crate as burn;
+use crate::module::{
+    Content, DisplaySettings, ModuleDisplay, ModuleMapper, ParamId, RunningState, list_param_paths,
+    list_weights, matches_pattern,
+};
+
+use crate::{
+    config::Config,
+    module::Module,
+    tensor::{Distribution, Tensor, backend::Backend},
+};
+use alloc::{string::String, vec::Vec};
+use hashbrown::HashMap;
+
+use super::weight::to_matrix;
+
+/// Configuration to create the [spectral normalization](SpectralNorm) of a module using the
+/// [init function](SpectralNormConfig::init).
+#[derive(Config, Debug)]
+pub struct SpectralNormConfig {
+    /// Patterns of the paths of the normalized modules, e.g. `*.conv` or `discriminator.*`, where
+    /// `*` matches any sequence of characters. Default: all the modules
+    #[config(default = "alloc::vec![\"*\".into()]")]
+    pub targets: Vec<String>,
+    /// The number of power iterations at each training forward pass. Default: 1
+    #[config(default = 1)]
+    pub n_power_iterations: usize,
+    /// A value required for numerical stability. Default: 1e-12
+    #[config(default = 1e-12)]
+    pub epsilon: f64,
+    /// The dimension of the weights holding the output features for the modules whose path
+    /// matches the pattern, the other dimensions being flattened. The first matching pattern is
+    /// used.
+    ///
+    /// Other weights use the dimension `0`, as for [convolutions](crate::nn::conv::Conv2d).
+    /// [Transposed convolutions](crate::nn::conv::ConvTranspose2d) hold their output features in
+    /// the dimension `1`, so they need a pattern. Default: no pattern
+    #[config(default = "Vec::new()")]
+    pub dims: Vec<(String, usize)>,
+}
+
+/// [Spectral normalization](https://arxiv.org/abs/1802.05957) of the weights of a module.
+///
+/// Each normalized weight `w` is divided by its spectral norm `sigma(w)`, its largest singular
+/// value, the weight being seen as a matrix of shape `[d_dim, d_0 * ... * d_n]` where `dim` holds
+/// the output features, see [dims](SpectralNormConfig::dims). The spectral norm is estimated with
+/// the power iteration method, whose singular vectors are stored as running states updated during
+/// training.
+///
+/// The weights are still trained in the module. The normalization is [applied](SpectralNorm::apply)
+/// to the module before each forward pass, and can be [removed](SpectralNorm::remove) to export
+/// the module with plain weights.
+///
+/// Should be created using [SpectralNormConfig].
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct SpectralNorm<B: Backend> {
+    /// The power iteration state of each normalized weight.
+    pub states: Vec<SpectralNormState<B>>,
+    /// The number of power iterations at each training forward pass.
+    pub n_power_iterations: usize,
+    /// A value required for numerical stability.
+    pub epsilon: f64,
+}
+
+/// Power iteration state of a single weight.
+#[derive(Module, Debug)]
+pub struct SpectralNormState<B: Backend> {
+    /// The [path](list_param_paths) of the normalized weight.
+    pub path: String,
+    /// The dimension of the output features.
+    pub dim: usize,
+    /// The left singular vector of shape `[d_dim]`.
+    pub u: RunningState<Tensor<B, 1>>,
+    /// The right singular vector of shape `[d_0 * ... * d_n]`, without `d_dim`.
+    pub v: RunningState<Tensor<B, 1>>,
+}
+
+impl SpectralNormConfig {
+    /// Initialize the [spectral normalization](SpectralNorm) of the weights of the targeted modules
+    /// with random singular vectors.
+    ///
+    /// The weights are the float parameters named `weight` with at least two dimensions.
+    pub fn init<B: Backend, M: Module<B>>(
+        &self,
+        module: &M,
+        device: &B::Device,
+    ) -> SpectralNorm<B> {
+        let random = |size: usize| {
+            let vector = Tensor::random([size], Distribution::Normal(0.0, 1.0), device);
+            RunningState::new(normalize(vector, self.epsilon))
+        };
+
+        let states = list_weights(module, &self.targets)
+            .into_iter()
+            .map(|(path, _, dims)| {
+                let dim = self.dim(&path);
+                assert!(
+                    dim < dims.len(),
+                    "Spectral normalization dimension {dim} is out of range for the weight {path} of shape {dims:?}",
+                );
+                let columns = dims
+                    .iter()
+                    .enumerate()
+                    .filter(|(index, _)| *index != dim)
+                    .map(|(_, size)| size)
+                    .product();
+
+                SpectralNormState {
+                    path,
+                    dim,
+                    u: random(dims[dim]),
+                    v: random(columns),
+                }
+            })
+            .collect();
+
+        SpectralNorm {
+            states,
+            n_power_iterations: self.n_power_iterations,
+            epsilon: self.epsilon,
+        }
+    }
+
+    fn dim(&self, path: &str) -> usize {
+        let module_path = path.strip_suffix("weight").unwrap_or_default();
+        let module_path = module_path.strip_suffix('.').unwrap_or(module_path);
+
+        self.dims
+            .iter()
+            .find(|(pattern, _)| matches_pattern(pattern, module_path))
+            .map(|(_, dim)| *dim)
+            .unwrap_or(0)
+    }
+}
+
+impl<B: Backend> SpectralNorm<B> {
+    /// Returns the module with the normalized weights.
+    ///
+    /// During training, the singular vectors are updated by the power iterations.
+    pub fn apply<M: Module<B>>(&self, module: M) -> M {
+        let states = self.states(&module);
+
+        module.map(&mut SpectralNormMapper {
+            states,
+            n_power_iterations: self.n_power_iterations,
+            epsilon: self.epsilon,
+            training: B::ad_enabled(),
+        })
+    }
+
+    /// Removes the normalization, writing the normalized weights into the module, e.g. to export
+    /// the trained module.
+    pub fn remove<M: Module<B>>(&self, module: M) -> M {
+        let states = self.states(&module);
+
+        module.map(&mut SpectralNormMapper {
+            states,
+            n_power_iterations: self.n_power_iterations,
+            epsilon: self.epsilon,
+            training: false,
+        })
+    }
+
+    fn states<M: Module<B>>(&self, module: &M) -> HashMap<ParamId, &SpectralNormState<B>> {
+        let ids = list_param_paths(module)
+            .into_iter()
+            .collect::<HashMap<_, _>>();
+
+        self.states
+            .iter()
+            .map(|state| {
+                let id = *ids.get(&state.path).unwrap_or_else(|| {
+                    panic!("The module has no parameter at path {}", state.path)
+                });
+
+                (id, state)
+            })
+            .collect()
+    }
+}
+
+impl<B: Backend> ModuleDisplay for SpectralNorm<B> {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        content
+            .add("states", &self.states.len())
+            .add("n_power_iterations", &self.n_power_iterations)
+            .add("epsilon", &self.epsilon)
+            .optional()
+    }
+}
+
+fn normalize<B: Backend>(vector: Tensor<B, 1>, epsilon: f64) -> Tensor<B, 1> {
+    let norm = vector.clone().powi_scalar(2).sum().sqrt();
+
+    vector / norm.clamp_min(epsilon)
+}
+
+struct SpectralNormMapper<'a, B: Backend> {
+    states: HashMap<ParamId, &'a SpectralNormState<B>>,
+    n_power_iterations: usize,
+    epsilon: f64,
+    training: bool,
+}
+
+impl<B: Backend> ModuleMapper<B> for SpectralNormMapper<'_, B> {
+    fn map_float<const D: usize>(&mut self, id: ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        let Some(state) = self.states.remove(&id) else {
+            return tensor;
+        };
+        let device = tensor.device();
+        let matrix = to_matrix(tensor.clone(), state.dim);
+        let [rows, columns] = matrix.dims();
+
+        let (mut u, mut v) = match self.training {
+            true => (state.u.value_sync(), state.v.value_sync()),
+            false => (state.u.value(), state.v.value()),
+        };
+        u = u.to_device(&device);
+        v = v.to_device(&device);
+
+        if self.training {
+            let weight = matrix.clone().detach();
+            for _ in 0..self.n_power_iterations {
+                v = weight
+                    .clone()
+                    .transpose()
+                    .matmul(u.reshape([rows, 1]))
+                    .reshape([columns]);
+                v = normalize(v, self.epsilon);
+                u = weight
+                    .clone()
+                    .matmul(v.clone().reshape([columns, 1]))
+                    .reshape([rows]);
+                u = normalize(u, self.epsilon);
+            }
+
+            state.u.update(u.clone());
+            state.v.update(v.clone());
+        }
+
+        // sigma = u^T W v, the singular vectors being constants.
+        let sigma = u
+            .reshape([1, rows])
+            .matmul(matrix)
+            .matmul(v.reshape([columns, 1]))
+            .reshape([1; D]);
+
+        match self.training {
+            true => tensor / sigma,
+            false => {
+                let require_grad = tensor.is_require_grad();
+                (tensor / sigma).detach().set_require_grad(require_grad)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        TestAutodiffBackend, TestBackend,
+        module::{AutodiffModule, Param},
+        nn::{
+            LinearConfig,
+            conv::{Conv2dConfig, ConvTranspose2dConfig},
+        },
+        tensor::TensorData,
+    };
+    use burn_tensor::{Tolerance, ops::FloatElem};
+
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn should_estimate_spectral_norm_during_training() {
+        let device = Default::default();
+        let mut linear = LinearConfig::new(2, 2).init::<TestAutodiffBackend>(&device);
+        linear.weight = Param::from_tensor(Tensor::from_floats([[3.0, 0.0], [0.0, 1.0]], &device));
+
+        let spectral_norm = SpectralNormConfig::new()
+            .with_n_power_iterations(20)
+            .init(&linear, &device);
+        let weight = spectral_norm.apply(linear.clone()).weight.val();
+
+        weight.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([[1.0, 0.0], [0.0, 1.0 / 3.0]]),
+            Tolerance::default(),
+        );
+
+        // The estimated singular vectors are kept for inference.
+        let weight = spectral_norm.valid().apply(linear.valid()).weight.val();
+        weight.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([[1.0, 0.0], [0.0, 1.0 / 3.0]]),
+            Tolerance::default(),
+        );
+    }
+
+    #[test]
+    fn should_normalize_transposed_convolution_over_output_features() {
+        let device = Default::default();
+        let mut conv =
+            ConvTranspose2dConfig::new([2, 2], [1, 2]).init::<TestAutodiffBackend>(&device);
+        // Weight of shape `[channels_in, channels_out, 1, 2]`, whose spectral norm is 5 over the
+        // output features, as with `spectral_norm(conv, dim=1)` in PyTorch, but 4 over the input
+        // features.
+        conv.weight = Param::from_tensor(Tensor::from_floats(
+            [[[[3.0, 0.0]], [[0.0, 0.0]]], [[[0.0, 4.0]], [[0.0, 0.0]]]],
+            &device,
+        ));
+
+        let spectral_norm = SpectralNormConfig::new()
+            .with_n_power_iterations(20)
+            .with_dims(vec![("*".into(), 1)])
+            .init(&conv, &device);
+        let weight = spectral_norm.apply(conv.clone()).weight.val();
+
+        assert_eq!(spectral_norm.states[0].u.value().dims(), [2]);
+        assert_eq!(spectral_norm.states[0].v.value().dims(), [4]);
+        weight
+            .into_data()
+            .assert_approx_eq::<FT>(&(conv.weight.val() / 5.0).into_data(), Tolerance::default());
+    }
+
+    #[test]
+    fn should_not_update_state_during_inference() {
+        let device = Default::default();
+        let conv = Conv2dConfig::new([2, 4], [3, 3]).init::<TestBackend>(&device);
+        let spectral_norm = SpectralNormConfig::new().init(&conv, &device);
+
+        assert_eq!(spectral_norm.states[0].u.value().dims(), [4]);
+        assert_eq!(spectral_norm.states[0].v.value().dims(), [18]);
+
+        let expected = spectral_norm.states[0].u.value();
+        let _conv = spectral_norm.apply(conv);
+        spectral_norm.states[0]
+            .u
+            .value()
+            .into_data()
+            .assert_eq(&expected.into_data(), true);
+    }
+
+    #[test]
+    fn should_train_module_weights() {
+        let device = Default::default();
+        let linear = LinearConfig::new(4, 6).init::<TestAutodiffBackend>(&device);
+        let spectral_norm = SpectralNormConfig::new().init(&linear, &device);
+
+        let x = Tensor::<TestAutodiffBackend, 2>::ones([3, 4], &device);
+        let grads = spectral_norm
+            .apply(linear.clone())
+            .forward(x)
+            .sum()
+            .backward();
+
+        assert!(linear.weight.grad(&grads).is_some());
+    }
+
+    #[test]
+    fn should_remove_normalization() {
+        let device = Default::default();
+        let linear = LinearConfig::new(4, 6).init::<TestAutodiffBackend>(&device);
+        let spectral_norm = SpectralNormConfig::new().init(&linear, &device);
+
+        let expected = spectral_norm.valid().apply(linear.valid()).weight.val();
+        let removed = spectral_norm.remove(linear);
+
+        assert!(removed.weight.is_require_grad());
+        removed
+            .weight
+            .val()
+            .inner()
+            .into_data()
+            .assert_approx_eq::<FT>(&expected.into_data(), Tolerance::default());
+    }
+}
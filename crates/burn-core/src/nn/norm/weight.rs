@@ -0,0 +1,360 @@
+use crate as burn;
+use crate::module::{
+    Content, DisplaySettings, ModuleDisplay, ModuleMapper, ModuleVisitor, ParamId,
+    list_param_paths, list_weights, matches_pattern,
+};
+
+use crate::{
+    config::Config,
+    module::{Module, Param},
+    tensor::{Tensor, backend::Backend},
+};
+use alloc::{string::String, vec::Vec};
+use hashbrown::HashMap;
+
+/// Configuration to create the [weight normalization](WeightNorm) of a module using the
+/// [init function](WeightNormConfig::init).
+#[derive(Config, Debug)]
+pub struct WeightNormConfig {
+    /// Patterns of the paths of the normalized modules, e.g. `*.conv` or `generator.*`, where `*`
+    /// matches any sequence of characters. Default: all the modules
+    #[config(default = "alloc::vec![\"*\".into()]")]
+    pub targets: Vec<String>,
+    /// The dimension of the weights holding the output features for the modules whose path
+    /// matches the pattern, a norm is computed for each of them. The first matching pattern is
+    /// used.
+    ///
+    /// Other weights use `1` for matrices, since [Linear](crate::nn::Linear) weights are
+    /// `[d_input, d_output]`, and `0` otherwise, as for [convolutions](crate::nn::conv::Conv2d).
+    /// [Transposed convolutions](crate::nn::conv::ConvTranspose2d) hold their output features in
+    /// the dimension `1`, so they need a pattern. Default: no pattern
+    #[config(default = "Vec::new()")]
+    pub dims: Vec<(String, usize)>,
+}
+
+/// [Weight normalization](https://arxiv.org/abs/1602.07868) of the weights of a module.
+///
+/// Each normalized weight `w` is reparametrized as `w = g * v / ||v||`, decoupling its magnitude
+/// `g` from its direction `v`. The norms are computed over all the dimensions except the one
+/// holding the output features, see [dims](WeightNormConfig::dims).
+///
+/// The parametrization is kept separate from the module, so `g` and `v` are trained and recorded
+/// in place of the weights. It is [applied](WeightNorm::apply) to the module before each forward
+/// pass, and can be [removed](WeightNorm::remove) to export the module with plain weights.
+///
+/// Should be created using [WeightNormConfig].
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct WeightNorm<B: Backend> {
+    /// The parametrization of each normalized weight.
+    pub params: Vec<WeightNormParam<B>>,
+}
+
+/// Weight normalization parameters of a single weight.
+#[derive(Module, Debug)]
+pub struct WeightNormParam<B: Backend> {
+    /// The [path](list_param_paths) of the normalized weight.
+    pub path: String,
+    /// The dimension of the output features.
+    pub dim: usize,
+    /// The magnitude of shape `[d_dim, 1]`.
+    pub g: Param<Tensor<B, 2>>,
+    /// The direction of shape `[d_dim, d_0 * ... * d_n]`, the other dimensions being flattened.
+    pub v: Param<Tensor<B, 2>>,
+}
+
+impl WeightNormConfig {
+    /// Initialize the [weight normalization](WeightNorm) of the weights of the targeted modules,
+    /// preserving their values.
+    ///
+    /// The weights are the float parameters named `weight` with at least two dimensions.
+    pub fn init<B: Backend, M: Module<B>>(&self, module: &M, device: &B::Device) -> WeightNorm<B> {
+        let weights = list_weights(module, &self.targets)
+            .into_iter()
+            .map(|(path, id, dims)| {
+                let dim = self.dim(&path, dims.len());
+                assert!(
+                    dim < dims.len(),
+                    "Weight normalization dimension {dim} is out of range for the weight {path} of shape {dims:?}",
+                );
+
+                (path, id, dim)
+            })
+            .collect::<Vec<_>>();
+
+        let mut collector = MatrixCollector {
+            dims: weights.iter().map(|(_, id, dim)| (*id, *dim)).collect(),
+            matrices: HashMap::new(),
+        };
+        module.visit(&mut collector);
+
+        let params = weights
+            .into_iter()
+            .map(|(path, id, dim)| {
+                let v = collector.matrices.remove(&id).unwrap().to_device(device);
+                let g = norm(v.clone());
+
+                WeightNormParam {
+                    path,
+                    dim,
+                    g: Param::from_tensor(g),
+                    v: Param::from_tensor(v),
+                }
+            })
+            .collect();
+
+        WeightNorm { params }
+    }
+
+    fn dim(&self, path: &str, rank: usize) -> usize {
+        let module_path = path.strip_suffix("weight").unwrap_or_default();
+        let module_path = module_path.strip_suffix('.').unwrap_or(module_path);
+
+        self.dims
+            .iter()
+            .find(|(pattern, _)| matches_pattern(pattern, module_path))
+            .map(|(_, dim)| *dim)
+            .unwrap_or(if rank == 2 { 1 } else { 0 })
+    }
+}
+
+impl<B: Backend> WeightNorm<B> {
+    /// Returns the module with the normalized weights.
+    ///
+    /// The weights of the module are replaced, so `g` and `v` are trained in their place.
+    pub fn apply<M: Module<B>>(&self, module: M) -> M {
+        let weights = self.weights(&module);
+
+        module.map(&mut WeightNormMapper {
+            weights,
+            detach: false,
+        })
+    }
+
+    /// Removes the parametrization, writing the normalized weights into the module, e.g. to export
+    /// the trained module.
+    pub fn remove<M: Module<B>>(&self, module: M) -> M {
+        let weights = self.weights(&module);
+
+        module.map(&mut WeightNormMapper {
+            weights,
+            detach: true,
+        })
+    }
+
+    fn weights<M: Module<B>>(&self, module: &M) -> HashMap<ParamId, (Tensor<B, 2>, usize)> {
+        let ids = list_param_paths(module)
+            .into_iter()
+            .collect::<HashMap<_, _>>();
+
+        self.params
+            .iter()
+            .map(|param| {
+                let id = *ids.get(&param.path).unwrap_or_else(|| {
+                    panic!("The module has no parameter at path {}", param.path)
+                });
+                let v = param.v.val();
+                let weight = param.g.val() * v.clone() / norm(v);
+
+                (id, (weight, param.dim))
+            })
+            .collect()
+    }
+}
+
+impl<B: Backend> ModuleDisplay for WeightNorm<B> {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        content.add("params", &self.params.len()).optional()
+    }
+}
+
+/// The norm of each row of the matrix.
+fn norm<B: Backend>(matrix: Tensor<B, 2>) -> Tensor<B, 2> {
+    matrix.powi_scalar(2).sum_dim(1).sqrt()
+}
+
+/// The weight seen as a matrix of shape `[d_dim, d_0 * ... * d_n]`.
+pub(super) fn to_matrix<B: Backend, const D: usize>(
+    weight: Tensor<B, D>,
+    dim: usize,
+) -> Tensor<B, 2> {
+    let weight = weight.swap_dims(0, dim);
+    let rows = weight.dims()[0];
+    let columns = weight.shape().num_elements() / rows;
+
+    weight.reshape([rows, columns])
+}
+
+fn from_matrix<B: Backend, const D: usize>(
+    matrix: Tensor<B, 2>,
+    mut dims: [usize; D],
+    dim: usize,
+) -> Tensor<B, D> {
+    dims.swap(0, dim);
+
+    matrix.reshape(dims).swap_dims(0, dim)
+}
+
+struct MatrixCollector<B: Backend> {
+    dims: HashMap<ParamId, usize>,
+    matrices: HashMap<ParamId, Tensor<B, 2>>,
+}
+
+impl<B: Backend> ModuleVisitor<B> for MatrixCollector<B> {
+    fn visit_float<const D: usize>(&mut self, id: ParamId, tensor: &Tensor<B, D>) {
+        if let Some(dim) = self.dims.get(&id) {
+            let matrix = to_matrix(tensor.clone().detach(), *dim);
+            self.matrices.insert(id, matrix);
+        }
+    }
+}
+
+struct WeightNormMapper<B: Backend> {
+    weights: HashMap<ParamId, (Tensor<B, 2>, usize)>,
+    detach: bool,
+}
+
+impl<B: Backend> ModuleMapper<B> for WeightNormMapper<B> {
+    fn map_float<const D: usize>(&mut self, id: ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        let Some((weight, dim)) = self.weights.remove(&id) else {
+            return tensor;
+        };
+        let weight = from_matrix(weight, tensor.dims(), dim);
+
+        match self.detach {
+            true => weight.detach().set_require_grad(tensor.is_require_grad()),
+            false => weight,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        TestAutodiffBackend, TestBackend,
+        module::AutodiffModule,
+        nn::{LinearConfig, conv::Conv1dConfig},
+        tensor::{Distribution, TensorData},
+    };
+    use burn_tensor::{Tolerance, ops::FloatElem};
+
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn should_preserve_weights_at_init() {
+        let device = Default::default();
+        let conv = Conv1dConfig::new(2, 4, 3).init::<TestBackend>(&device);
+        let weight_norm = WeightNormConfig::new().init(&conv, &device);
+
+        assert_eq!(weight_norm.params[0].path, "weight");
+        assert_eq!(weight_norm.params[0].g.dims(), [4, 1]);
+        assert_eq!(weight_norm.params[0].v.dims(), [4, 6]);
+
+        let expected = conv.weight.val();
+        weight_norm
+            .apply(conv)
+            .weight
+            .val()
+            .into_data()
+            .assert_approx_eq::<FT>(&expected.into_data(), Tolerance::default());
+    }
+
+    #[test]
+    fn should_normalize_linear_along_output_dim() {
+        let device = Default::default();
+        let mut linear = LinearConfig::new(2, 3).init::<TestBackend>(&device);
+        linear.weight = Param::from_tensor(Tensor::from_floats(
+            [[3.0, 0.0, 1.0], [4.0, 2.0, 0.0]],
+            &device,
+        ));
+
+        let mut weight_norm = WeightNormConfig::new().init(&linear, &device);
+        assert_eq!(weight_norm.params[0].dim, 1);
+        weight_norm.params[0]
+            .g
+            .val()
+            .into_data()
+            .assert_approx_eq::<FT>(
+                &TensorData::from([[5.0], [2.0], [1.0]]),
+                Tolerance::default(),
+            );
+
+        weight_norm.params[0].g = Param::from_tensor(Tensor::ones([3, 1], &device));
+        weight_norm
+            .apply(linear)
+            .weight
+            .val()
+            .into_data()
+            .assert_approx_eq::<FT>(
+                &TensorData::from([[0.6, 0.0, 1.0], [0.8, 1.0, 0.0]]),
+                Tolerance::default(),
+            );
+    }
+
+    #[test]
+    fn should_normalize_along_dim_of_matching_pattern() {
+        let device = Default::default();
+        let conv = Conv1dConfig::new(2, 4, 3).init::<TestBackend>(&device);
+        let weight_norm = WeightNormConfig::new()
+            .with_dims(alloc::vec![("*".into(), 1)])
+            .init(&conv, &device);
+
+        assert_eq!(weight_norm.params[0].dim, 1);
+        assert_eq!(weight_norm.params[0].g.dims(), [2, 1]);
+        assert_eq!(weight_norm.params[0].v.dims(), [2, 12]);
+
+        let expected = conv.weight.val();
+        weight_norm
+            .apply(conv)
+            .weight
+            .val()
+            .into_data()
+            .assert_approx_eq::<FT>(&expected.into_data(), Tolerance::default());
+    }
+
+    #[test]
+    fn should_only_train_parametrization() {
+        let device = Default::default();
+        let linear = LinearConfig::new(4, 6).init::<TestAutodiffBackend>(&device);
+        let weight_norm = WeightNormConfig::new().init(&linear, &device);
+
+        let x = Tensor::<TestAutodiffBackend, 2>::random([3, 4], Distribution::Default, &device);
+        let grads = weight_norm
+            .apply(linear.clone())
+            .forward(x)
+            .sum()
+            .backward();
+
+        assert!(linear.weight.grad(&grads).is_none());
+        assert!(linear.bias.as_ref().unwrap().grad(&grads).is_some());
+        assert!(weight_norm.params[0].g.grad(&grads).is_some());
+        assert!(weight_norm.params[0].v.grad(&grads).is_some());
+    }
+
+    #[test]
+    fn should_remove_parametrization() {
+        let device = Default::default();
+        let conv = Conv1dConfig::new(2, 4, 3).init::<TestAutodiffBackend>(&device);
+        let mut weight_norm = WeightNormConfig::new().init(&conv, &device);
+        weight_norm.params[0].g =
+            Param::from_tensor(Tensor::random([4, 1], Distribution::Default, &device));
+
+        let x = Tensor::<TestBackend, 3>::random([1, 2, 5], Distribution::Default, &device);
+        let expected = weight_norm.valid().apply(conv.valid()).forward(x.clone());
+        let removed = weight_norm.remove(conv);
+
+        assert!(removed.weight.is_require_grad());
+        removed
+            .valid()
+            .forward(x)
+            .into_data()
+            .assert_approx_eq::<FT>(&expected.into_data(), Tolerance::default());
+    }
+}
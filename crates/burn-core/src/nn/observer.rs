@@ -0,0 +1,655 @@
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use crate as burn;
+use crate::module::{Content, DisplaySettings, Ignored, ModuleDisplay, ModuleDisplayDefault};
+
+use crate::{
+    config::Config,
+    module::{AutodiffModule, Devices, Module, ModuleMapper, ModuleVisitor, ParamId, RunningState},
+    tensor::{
+        ElementConversion, Int, Tensor,
+        backend::{AutodiffBackend, Backend},
+    },
+};
+use burn_tensor::quantization::{
+    CalibrationRange, QuantLevel, QuantScheme, QuantizationParameters, compute_q_params,
+};
+
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use num_traits::Float as _;
+
+/// Method used by an [observer](Observer) to compute the range of the activations.
+#[derive(Config, Debug, PartialEq)]
+pub enum ObserverCalibration {
+    /// The minimum and maximum of all the observed values.
+    MinMax,
+    /// Moving average of the minimum and maximum of each batch.
+    MovingAverage {
+        /// The momentum of the moving average.
+        momentum: f64,
+    },
+    /// Percentile of the absolute values, e.g. `99.99`, clipping the outliers.
+    Percentile {
+        /// The percentile, between 0 and 100.
+        percentile: f64,
+    },
+    /// Range minimizing the KL divergence between the distribution of the absolute values and
+    /// its quantized version.
+    Entropy,
+}
+
+/// Configuration to create an [Observer](Observer) layer using the [init function](ObserverConfig::init).
+#[derive(Config, Debug)]
+pub struct ObserverConfig {
+    /// The quantization scheme, only [tensor level](QuantLevel::Tensor) quantization is supported.
+    pub scheme: QuantScheme,
+    /// The calibration method. Default: MinMax
+    #[config(default = "ObserverCalibration::MinMax")]
+    pub calibration: ObserverCalibration,
+    /// The number of bins of the histogram of absolute values, used by the percentile and entropy
+    /// calibrations. Default: 2048
+    #[config(default = 2048)]
+    pub num_bins: usize,
+}
+
+/// Collects statistics on the activations flowing through it to compute static quantization
+/// parameters.
+///
+/// While calibrating, the observer records the range of its inputs and returns them unchanged.
+/// Once [calibrated](Observer::calibrate), the inputs are quantized with the computed
+/// [parameters](Observer::qparams), so the following operations can run on quantized tensors when
+/// the scheme [propagates](burn_tensor::quantization::QuantPropagation) the quantization.
+///
+/// The calibrated scale and the [calibrated](Calibrated) flag are saved in the record, so a
+/// calibrated observer stays calibrated once loaded. The parameters of all the observers of a model are listed with
+/// [collect_qparams](collect_qparams).
+///
+/// Should be created using [ObserverConfig].
+///
+/// # Example
+///
+/// ```rust,ignore
+/// for batch in calibration_loader.iter() {
+///     model.forward(batch.inputs);
+/// }
+/// model.observer = model.observer.calibrate();
+/// ```
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct Observer<B: Backend> {
+    /// The observed minimum.
+    pub min: RunningState<Tensor<B, 1>>,
+    /// The observed maximum.
+    pub max: RunningState<Tensor<B, 1>>,
+    /// The histogram of the absolute values, between zero and the observed absolute maximum.
+    pub histogram: RunningState<Tensor<B, 1>>,
+    /// The calibrated scale, zero until [calibrated](Observer::calibrate).
+    pub activation_scale: RunningState<Tensor<B, 1>>,
+    /// If the observer is [calibrated](Observer::calibrate).
+    pub calibrated: Calibrated,
+    /// The calibration method.
+    pub calibration: Ignored<ObserverCalibration>,
+    /// The quantization scheme.
+    pub scheme: Ignored<QuantScheme>,
+}
+
+impl ObserverConfig {
+    /// Initialize a new [observer](Observer) module.
+    pub fn init<B: Backend>(&self, device: &B::Device) -> Observer<B> {
+        assert!(
+            matches!(self.scheme.level, QuantLevel::Tensor),
+            "Observer only supports tensor level quantization, got {:?}",
+            self.scheme.level
+        );
+        assert!(
+            self.num_bins > 0,
+            "The histogram must have at least one bin"
+        );
+
+        Observer {
+            min: RunningState::new(Tensor::full([1], f32::MAX, device)),
+            max: RunningState::new(Tensor::full([1], f32::MIN, device)),
+            histogram: RunningState::new(Tensor::zeros([self.num_bins], device)),
+            activation_scale: RunningState::new(Tensor::zeros([1], device)),
+            calibrated: Calibrated(false),
+            calibration: Ignored(self.calibration.clone()),
+            scheme: Ignored(self.scheme),
+        }
+    }
+}
+
+impl<B: Backend> Observer<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// See [Observer](Observer) for more information.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[..., any]`
+    /// - output: `[..., any]`
+    pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        if self.is_calibrated() {
+            return input.quantize(&self.scheme, self.qparams());
+        }
+
+        self.observe(input.clone().detach());
+        input
+    }
+
+    /// Compute the quantization parameters from the observed statistics and quantize the
+    /// following inputs.
+    pub fn calibrate(mut self) -> Self {
+        let min = self.min.value();
+        let max = self.max.value();
+
+        let abs_max = match &*self.calibration {
+            ObserverCalibration::MinMax | ObserverCalibration::MovingAverage { .. } => {
+                min.abs().max_pair(max.abs())
+            }
+            ObserverCalibration::Percentile { percentile } => {
+                self.histogram_threshold(min, max, |hist| histogram_percentile(hist, *percentile))
+            }
+            ObserverCalibration::Entropy => {
+                let (_, b) = self.scheme.value.range();
+                let num_levels = b as usize + 1;
+                self.histogram_threshold(min, max, |hist| histogram_entropy(hist, num_levels))
+            }
+        };
+
+        // Avoids a zero scale when only zeros were observed.
+        let abs_max = abs_max.clamp_min(f32::EPSILON);
+        let qparams = compute_q_params(
+            &self.scheme,
+            CalibrationRange {
+                min: abs_max.clone().neg(),
+                max: abs_max,
+            },
+        );
+
+        self.activation_scale.update(qparams.scales);
+        self.calibrated = Calibrated(true);
+        self
+    }
+
+    /// If the observer is [calibrated](Observer::calibrate).
+    pub fn is_calibrated(&self) -> bool {
+        self.calibrated.0
+    }
+
+    /// The quantization parameters of the activations, once [calibrated](Observer::calibrate).
+    pub fn qparams(&self) -> QuantizationParameters<B> {
+        QuantizationParameters {
+            scales: self.activation_scale.value(),
+        }
+    }
+
+    fn observe<const D: usize>(&self, input: Tensor<B, D>) {
+        let device = input.device();
+        let batch_min = input.clone().min();
+        let batch_max = input.clone().max();
+        let min = self.min.value_sync().to_device(&device);
+        let max = self.max.value_sync().to_device(&device);
+        // Nothing was observed before the first batch.
+        let first = max.clone().lower(min.clone());
+        let abs_max = min
+            .clone()
+            .abs()
+            .max_pair(max.clone().abs())
+            .mask_fill(first.clone(), 0.0);
+
+        let (min, max) = match &*self.calibration {
+            ObserverCalibration::MovingAverage { momentum } => {
+                // The first batch initializes the moving average.
+                let min = min.mask_where(first.clone(), batch_min.clone());
+                let max = max.mask_where(first, batch_max.clone());
+
+                (
+                    min.mul_scalar(1.0 - momentum)
+                        .add(batch_min.mul_scalar(*momentum)),
+                    max.mul_scalar(1.0 - momentum)
+                        .add(batch_max.mul_scalar(*momentum)),
+                )
+            }
+            _ => (min.min_pair(batch_min), max.max_pair(batch_max)),
+        };
+
+        if matches!(
+            *self.calibration,
+            ObserverCalibration::Percentile { .. } | ObserverCalibration::Entropy
+        ) {
+            let new_abs_max = min.clone().abs().max_pair(max.clone().abs());
+            self.update_histogram(input, abs_max, new_abs_max);
+        }
+
+        self.min.update(min);
+        self.max.update(max);
+    }
+
+    fn update_histogram<const D: usize>(
+        &self,
+        input: Tensor<B, D>,
+        abs_max: Tensor<B, 1>,
+        new_abs_max: Tensor<B, 1>,
+    ) {
+        let device = input.device();
+        let histogram = self.histogram.value_sync().to_device(&device);
+        let [num_bins] = histogram.dims();
+        let new_abs_max = new_abs_max.clamp_min(f32::EPSILON);
+
+        // Moves the counts of the previous bins to the bins covering the new range.
+        let centers = Tensor::<B, 1, Int>::arange(0..num_bins as i64, &device)
+            .float()
+            .add_scalar(0.5)
+            .mul(abs_max)
+            .div_scalar(num_bins as f64);
+        let indices = self.bin_indices(centers, new_abs_max.clone(), num_bins);
+        let histogram = Tensor::zeros([num_bins], &device).scatter(0, indices, histogram);
+
+        let values = input.abs().flatten::<1>(0, D - 1);
+        let counts = values.ones_like();
+        let indices = self.bin_indices(values, new_abs_max, num_bins);
+
+        self.histogram.update(histogram.scatter(0, indices, counts));
+    }
+
+    fn bin_indices(
+        &self,
+        values: Tensor<B, 1>,
+        abs_max: Tensor<B, 1>,
+        num_bins: usize,
+    ) -> Tensor<B, 1, Int> {
+        values
+            .div(abs_max)
+            .mul_scalar(num_bins as f64)
+            .floor()
+            .clamp(0.0, (num_bins - 1) as f64)
+            .int()
+    }
+
+    fn histogram_threshold<F: FnOnce(&[f32]) -> f32>(
+        &self,
+        min: Tensor<B, 1>,
+        max: Tensor<B, 1>,
+        func: F,
+    ) -> Tensor<B, 1> {
+        let abs_max = min.abs().max_pair(max.abs());
+        let histogram: Vec<f32> = self.histogram.value().into_data().iter::<f32>().collect();
+        let ratio = func(&histogram) / histogram.len() as f32;
+
+        abs_max.mul_scalar(ratio)
+    }
+}
+
+/// Whether an [observer](Observer) is [calibrated](Observer::calibrate).
+///
+/// The flag is kept on the host, so that the forward pass doesn't read the scale back from its
+/// device, and is saved in the record.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Calibrated(pub bool);
+
+impl<B: Backend> Module<B> for Calibrated {
+    type Record = bool;
+
+    fn visit<V: ModuleVisitor<B>>(&self, _visitor: &mut V) {
+        // Nothing to do
+    }
+
+    fn map<M: ModuleMapper<B>>(self, _mapper: &mut M) -> Self {
+        self
+    }
+
+    fn load_record(self, record: Self::Record) -> Self {
+        Self(record)
+    }
+
+    fn into_record(self) -> Self::Record {
+        self.0
+    }
+
+    fn to_device(self, _: &B::Device) -> Self {
+        self
+    }
+
+    fn fork(self, _: &B::Device) -> Self {
+        self
+    }
+
+    fn collect_devices(&self, devices: Devices<B>) -> Devices<B> {
+        devices
+    }
+}
+
+impl<B: AutodiffBackend> AutodiffModule<B> for Calibrated {
+    type InnerModule = Calibrated;
+
+    fn valid(&self) -> Self::InnerModule {
+        *self
+    }
+}
+
+impl ModuleDisplayDefault for Calibrated {
+    fn content(&self, content: Content) -> Option<Content> {
+        content.add_formatted(&self.0).optional()
+    }
+}
+
+impl ModuleDisplay for Calibrated {}
+
+/// Name of the [observer](Observer) field holding the calibrated scale, used to find the observers
+/// of a module.
+const ACTIVATION_SCALE: &str = "activation_scale";
+
+struct QParamsCollector<B: Backend> {
+    path: Vec<String>,
+    qparams: Vec<(String, QuantizationParameters<B>)>,
+}
+
+impl<B: Backend> ModuleVisitor<B> for QParamsCollector<B> {
+    fn visit_float<const D: usize>(&mut self, _id: ParamId, tensor: &Tensor<B, D>) {
+        if self.path.last().map(String::as_str) != Some(ACTIVATION_SCALE) || D != 1 {
+            return;
+        }
+
+        let scales = tensor.clone().reshape([-1]);
+        // Uncalibrated observers have a zero scale.
+        if scales.clone().into_scalar().elem::<f32>() > 0.0 {
+            let path = self.path[..self.path.len() - 1].join(".");
+            self.qparams.push((path, QuantizationParameters { scales }));
+        }
+    }
+
+    fn enter_module(&mut self, name: &str) {
+        self.path.push(name.to_string());
+    }
+
+    fn exit_module(&mut self, _name: &str) {
+        self.path.pop();
+    }
+}
+
+/// Collect the quantization parameters of the calibrated [observers](Observer) of a module, with
+/// the path of each observer, e.g. `encoder.layers.0.observer`.
+pub fn collect_qparams<B: Backend, M: Module<B>>(
+    module: &M,
+) -> Vec<(String, QuantizationParameters<B>)> {
+    let mut collector = QParamsCollector {
+        path: Vec::new(),
+        qparams: Vec::new(),
+    };
+    module.visit(&mut collector);
+
+    collector.qparams
+}
+
+/// The upper edge, in bins, of the bin reaching the percentile of the counts.
+fn histogram_percentile(histogram: &[f32], percentile: f64) -> f32 {
+    let total: f64 = histogram.iter().map(|count| *count as f64).sum();
+    let target = total * percentile.clamp(0.0, 100.0) / 100.0;
+
+    let mut cumulative = 0.0;
+    for (index, count) in histogram.iter().enumerate() {
+        cumulative += *count as f64;
+        if cumulative >= target {
+            return (index + 1) as f32;
+        }
+    }
+
+    histogram.len() as f32
+}
+
+/// The threshold, in bins, minimizing the KL divergence between the clipped histogram and its
+/// quantization to `num_levels` levels.
+fn histogram_entropy(histogram: &[f32], num_levels: usize) -> f32 {
+    let num_bins = histogram.len();
+    if num_bins <= num_levels {
+        return num_bins as f32;
+    }
+
+    let mut best = (f64::INFINITY, num_bins);
+
+    for threshold in num_levels..=num_bins {
+        // Reference distribution, with the clipped outliers in the last bin.
+        let mut reference: Vec<f64> = histogram[..threshold]
+            .iter()
+            .map(|count| *count as f64)
+            .collect();
+        let outliers: f64 = histogram[threshold..]
+            .iter()
+            .map(|count| *count as f64)
+            .sum();
+        reference[threshold - 1] += outliers;
+
+        // Quantized distribution, spreading each level over its non-empty bins.
+        let mut candidate = alloc::vec![0.0; threshold];
+        for level in 0..num_levels {
+            let start = level * threshold / num_levels;
+            let end = ((level + 1) * threshold / num_levels).max(start + 1);
+            let bins = &histogram[start..end];
+            let non_empty = bins.iter().filter(|count| **count > 0.0).count();
+            if non_empty == 0 {
+                continue;
+            }
+            let value = bins.iter().map(|count| *count as f64).sum::<f64>() / non_empty as f64;
+            for (index, count) in bins.iter().enumerate() {
+                if *count > 0.0 {
+                    candidate[start + index] = value;
+                }
+            }
+        }
+
+        let divergence = kl_divergence(&reference, &candidate);
+        if divergence < best.0 {
+            best = (divergence, threshold);
+        }
+    }
+
+    best.1 as f32
+}
+
+fn kl_divergence(reference: &[f64], candidate: &[f64]) -> f64 {
+    const EPSILON: f64 = 1e-10;
+
+    let total_reference: f64 = reference.iter().sum();
+    let total_candidate: f64 = candidate.iter().sum();
+    if total_reference == 0.0 || total_candidate == 0.0 {
+        return f64::INFINITY;
+    }
+
+    reference
+        .iter()
+        .zip(candidate)
+        .filter(|(p, _)| **p > 0.0)
+        .map(|(p, q)| {
+            let p = p / total_reference;
+            let q = (q / total_candidate).max(EPSILON);
+            p * (p / q).ln()
+        })
+        .sum()
+}
+
+impl<B: Backend> ModuleDisplay for Observer<B> {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        content
+            .add("calibration", &format!("{:?}", *self.calibration))
+            .add("calibrated", &self.is_calibrated())
+            .optional()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        TestBackend,
+        record::{BinBytesRecorder, FullPrecisionSettings, Recorder},
+    };
+    use burn_tensor::{
+        TensorData, Tolerance,
+        ops::{FloatElem, QuantizedTensor},
+        quantization::{QTensorPrimitive, QuantValue},
+    };
+
+    type FT = FloatElem<TestBackend>;
+
+    fn observer(calibration: ObserverCalibration, num_bins: usize) -> Observer<TestBackend> {
+        let scheme = QuantizedTensor::<TestBackend>::default_scheme()
+            .with_value(QuantValue::Q8S)
+            .with_level(QuantLevel::Tensor);
+
+        ObserverConfig::new(scheme)
+            .with_calibration(calibration)
+            .with_num_bins(num_bins)
+            .init(&Default::default())
+    }
+
+    fn observe(observer: &Observer<TestBackend>, values: [f32; 4]) {
+        let input = Tensor::<TestBackend, 1>::from_floats(values, &Default::default());
+        let output = observer.forward(input.clone());
+
+        output.into_data().assert_eq(&input.into_data(), true);
+    }
+
+    #[test]
+    fn min_max_observer_should_compute_qparams() {
+        let observer = observer(ObserverCalibration::MinMax, 16);
+        observe(&observer, [-1.0, 0.0, 0.5, 0.2]);
+        observe(&observer, [-0.2, 0.0, 1.8, 0.2]);
+
+        let observer = observer.calibrate();
+
+        observer
+            .qparams()
+            .scales
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([0.014_173_23]), Tolerance::default());
+    }
+
+    #[test]
+    fn calibration_should_be_restored_from_record() {
+        let device = Default::default();
+        let observer = observer(ObserverCalibration::MinMax, 16);
+        observe(&observer, [-1.0, 0.0, 0.5, 1.8]);
+        let calibrated = observer.calibrate();
+
+        let recorder = BinBytesRecorder::<FullPrecisionSettings>::default();
+        let bytes = recorder
+            .record(calibrated.clone().into_record(), ())
+            .unwrap();
+        let record = recorder.load(bytes, &device).unwrap();
+        let loaded = self::observer(ObserverCalibration::MinMax, 16);
+        assert!(!loaded.is_calibrated());
+        let loaded = loaded.load_record(record);
+
+        assert!(loaded.is_calibrated());
+        loaded
+            .qparams()
+            .scales
+            .into_data()
+            .assert_eq(&calibrated.qparams().scales.into_data(), true);
+    }
+
+    #[test]
+    fn should_collect_qparams_of_calibrated_observers() {
+        let calibrated = observer(ObserverCalibration::MinMax, 16);
+        observe(&calibrated, [-1.0, 0.0, 0.5, 1.8]);
+        let calibrated = calibrated.calibrate();
+        let module = alloc::vec![observer(ObserverCalibration::MinMax, 16), calibrated];
+
+        let qparams = collect_qparams(&module);
+
+        assert_eq!(qparams.len(), 1);
+        assert_eq!(qparams[0].0, "1");
+        qparams[0]
+            .1
+            .scales
+            .clone()
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([0.014_173_23]), Tolerance::default());
+    }
+
+    #[test]
+    fn moving_average_observer_should_average_ranges() {
+        let observer = observer(ObserverCalibration::MovingAverage { momentum: 0.5 }, 16);
+        observe(&observer, [-1.0, 0.0, 0.5, 0.2]);
+        observe(&observer, [-3.0, 0.0, 1.5, 0.2]);
+
+        observer
+            .min
+            .value()
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([-2.0]), Tolerance::default());
+        observer
+            .max
+            .value()
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([1.0]), Tolerance::default());
+    }
+
+    #[test]
+    fn percentile_observer_should_clip_outliers() {
+        let observer = observer(ObserverCalibration::Percentile { percentile: 75.0 }, 8);
+        observe(&observer, [0.1, -0.1, 0.2, 8.0]);
+
+        observer.histogram.value().into_data().assert_eq(
+            &TensorData::from([3.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0]),
+            false,
+        );
+
+        let observer = observer.calibrate();
+
+        // 75% of the values are in the first bin, covering [0, 1].
+        observer
+            .qparams()
+            .scales
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([2.0 / 254.0]), Tolerance::default());
+    }
+
+    #[test]
+    fn histogram_should_be_rebinned_when_range_grows() {
+        let observer = observer(ObserverCalibration::Entropy, 4);
+        observe(&observer, [0.1, 0.3, 0.6, 0.9]);
+        observe(&observer, [0.1, 0.1, 1.0, 2.0]);
+
+        observer
+            .histogram
+            .value()
+            .into_data()
+            .assert_eq(&TensorData::from([4.0, 2.0, 1.0, 1.0]), false);
+    }
+
+    #[test]
+    fn entropy_should_keep_range_without_outliers() {
+        let histogram = [4.0, 4.0, 4.0, 4.0];
+
+        assert_eq!(histogram_entropy(&histogram, 2), 4.0);
+    }
+
+    #[test]
+    fn entropy_should_clip_outliers() {
+        let mut histogram: Vec<f32> = (0..32).map(|i| 1000.0 - 30.0 * i as f32).collect();
+        histogram.extend([0.0; 95]);
+        histogram.push(1.0);
+
+        assert_eq!(histogram_entropy(&histogram, 16), 32.0);
+    }
+
+    #[test]
+    fn percentile_should_find_bin() {
+        let histogram = [1.0, 1.0, 1.0, 1.0];
+
+        assert_eq!(histogram_percentile(&histogram, 50.0), 2.0);
+        assert_eq!(histogram_percentile(&histogram, 100.0), 4.0);
+    }
+}
@@ -0,0 +1,391 @@
+use crate as burn;
+
+use crate::module::{Content, DisplaySettings, Module, ModuleDisplay, Param};
+use crate::nn::{Dropout, DropoutConfig, Gelu, Initializer, Linear, LinearConfig};
+use crate::{
+    config::Config,
+    tensor::{Bool, Int, Tensor, backend::Backend},
+};
+
+use burn_tensor::activation::softmax;
+
+/// Configuration to create a [mixture of experts](MixtureOfExperts) layer using the [init function](MixtureOfExpertsConfig::init).
+#[derive(Config, Debug)]
+pub struct MixtureOfExpertsConfig {
+    /// The size of the input and output features.
+    pub d_model: usize,
+    /// The size of the hidden inner features of each expert.
+    pub d_ff: usize,
+    /// The number of experts.
+    pub n_experts: usize,
+    /// The number of experts each token is dispatched to. Default: 2
+    #[config(default = 2)]
+    pub top_k: usize,
+    /// The number of tokens each expert can process, relative to an even distribution of the
+    /// tokens. Tokens exceeding the capacity of an expert are dropped, `n_experts / top_k` never
+    /// drops tokens. Default: 1.25
+    #[config(default = 1.25)]
+    pub capacity_factor: f64,
+    /// Renormalize the gates of the `top_k` experts of each token so they sum to one. With
+    /// `top_k = 1`, every gate would be one and the router would get no gradient from the output.
+    /// Default: false
+    #[config(default = false)]
+    pub normalize_gates: bool,
+    /// The dropout rate. Default: 0.1
+    #[config(default = 0.1)]
+    pub dropout: f64,
+    /// The type of function used to initialize neural network parameters
+    #[config(
+        default = "Initializer::KaimingUniform{gain:1.0/num_traits::Float::sqrt(3.0), fan_out_only:false}"
+    )]
+    pub initializer: Initializer,
+}
+
+/// Applies a sparse mixture of [position-wise feed-forward](crate::nn::transformer::PositionWiseFeedForward)
+/// experts to the input tensor, as in [Switch Transformers](https://arxiv.org/abs/2101.03961).
+///
+/// A learned router dispatches each token to its `top_k` experts, whose outputs are combined with
+/// the router probabilities, optionally [renormalized](MixtureOfExpertsConfig::normalize_gates).
+/// Each expert processes at most `capacity` tokens, prioritizing the first choice of every token,
+/// and dropped tokens have a zero output.
+///
+/// The weights of the experts are stored as batched tensors, so all the experts are computed with a
+/// single batched matrix multiplication.
+///
+/// Should be created using [MixtureOfExpertsConfig]
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct MixtureOfExperts<B: Backend> {
+    /// Linear layer with `d_model` input features and `n_experts` output features.
+    pub router: Linear<B>,
+    /// Tensor of shape `[n_experts, d_model, d_ff]`.
+    pub weight_inner: Param<Tensor<B, 3>>,
+    /// Tensor of shape `[n_experts, d_ff]`.
+    pub bias_inner: Param<Tensor<B, 2>>,
+    /// Tensor of shape `[n_experts, d_ff, d_model]`.
+    pub weight_outer: Param<Tensor<B, 3>>,
+    /// Tensor of shape `[n_experts, d_model]`.
+    pub bias_outer: Param<Tensor<B, 2>>,
+    /// Dropout layer.
+    pub dropout: Dropout,
+    /// GELU activation function.
+    pub gelu: Gelu,
+    /// The number of experts each token is dispatched to.
+    pub top_k: usize,
+    /// The capacity of each expert relative to an even distribution of the tokens.
+    pub capacity_factor: f64,
+    /// If the gates of the experts of each token are renormalized.
+    pub normalize_gates: bool,
+}
+
+/// [Mixture of experts](MixtureOfExperts) outputs.
+#[derive(Debug, Clone)]
+pub struct MixtureOfExpertsOutput<B: Backend, const D: usize> {
+    /// The output tensor `[..., d_model]`.
+    pub output: Tensor<B, D>,
+    /// The auxiliary load balancing loss, minimized when the tokens are evenly distributed
+    /// between the experts. It should be scaled, e.g. by `0.01`, and added to the training loss.
+    pub aux_loss: Tensor<B, 1>,
+    /// The fraction of the token assignments dispatched to each expert `[n_experts]`, before
+    /// dropping the tokens exceeding their capacity.
+    pub expert_load: Tensor<B, 1>,
+}
+
+impl<B: Backend> ModuleDisplay for MixtureOfExperts<B> {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        let [n_experts, d_model, d_ff] = self.weight_inner.shape().dims();
+
+        content
+            .add("d_model", &d_model)
+            .add("d_ff", &d_ff)
+            .add("n_experts", &n_experts)
+            .add("top_k", &self.top_k)
+            .add("capacity_factor", &self.capacity_factor)
+            .add("normalize_gates", &self.normalize_gates)
+            .add("prob", &self.dropout.prob)
+            .optional()
+    }
+}
+
+impl MixtureOfExpertsConfig {
+    /// Initialize a new [mixture of experts](MixtureOfExperts) module.
+    pub fn init<B: Backend>(&self, device: &B::Device) -> MixtureOfExperts<B> {
+        assert!(
+            self.top_k > 0 && self.top_k <= self.n_experts,
+            "The number of experts per token ({}) should be between 1 and the number of experts ({})",
+            self.top_k,
+            self.n_experts
+        );
+        assert!(
+            self.capacity_factor > 0.0,
+            "The capacity factor should be positive"
+        );
+
+        let init = |shape: [usize; 3]| {
+            self.initializer
+                .init_with(shape, Some(shape[1]), Some(shape[2]), device)
+        };
+        let init_bias = |shape: [usize; 2], fan_in| {
+            self.initializer
+                .init_with(shape, Some(fan_in), Some(shape[1]), device)
+        };
+
+        MixtureOfExperts {
+            router: LinearConfig::new(self.d_model, self.n_experts)
+                .with_bias(false)
+                .with_initializer(self.initializer.clone())
+                .init(device),
+            weight_inner: init([self.n_experts, self.d_model, self.d_ff]),
+            bias_inner: init_bias([self.n_experts, self.d_ff], self.d_model),
+            weight_outer: init([self.n_experts, self.d_ff, self.d_model]),
+            bias_outer: init_bias([self.n_experts, self.d_model], self.d_ff),
+            dropout: DropoutConfig::new(self.dropout).init(),
+            gelu: Gelu::new(),
+            top_k: self.top_k,
+            capacity_factor: self.capacity_factor,
+            normalize_gates: self.normalize_gates,
+        }
+    }
+}
+
+impl<B: Backend> MixtureOfExperts<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// See [MixtureOfExperts](MixtureOfExperts) for more information.
+    ///
+    /// # Shapes
+    ///
+    /// - tensor: `[..., d_model]`
+    /// - output: `[..., d_model]`
+    pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> MixtureOfExpertsOutput<B, D> {
+        let shape = input.shape();
+        let [n_experts, d_model, d_ff] = self.weight_inner.dims();
+        let num_tokens = shape.num_elements() / d_model;
+        let num_entries = num_tokens * self.top_k;
+        let capacity = self.capacity(num_tokens, n_experts);
+        let device = input.device();
+
+        let tokens = input.reshape([num_tokens, d_model]);
+        let probs = softmax(self.router.forward(tokens.clone()), 1);
+
+        // Each entry dispatches a token to one of its experts, ordered by choice and then by token.
+        let (_, experts) = probs.clone().topk_with_indices(self.top_k, 1);
+        let mut gates = probs.clone().gather(1, experts.clone());
+        if self.normalize_gates {
+            gates = gates.clone().div(gates.sum_dim(1));
+        }
+        let experts = experts.transpose().reshape([num_entries]);
+        let gates = gates.transpose().reshape([num_entries]);
+
+        // Sorting the entries by expert, then by priority, gives their position in the expert
+        // queue.
+        let entries = Tensor::<B, 1, Int>::arange(0..num_entries as i64, &device);
+        let (sorted, order) = experts
+            .clone()
+            .mul_scalar(num_entries as i64)
+            .add(entries.clone())
+            .sort_with_indices(0);
+        let sorted_experts = sorted.div_scalar(num_entries as i64);
+
+        let assignments = experts.one_hot::<2>(n_experts).float().sum_dim(0);
+        let first_entries = assignments
+            .clone()
+            .matmul(Tensor::<B, 2, Bool>::tril_mask([n_experts, n_experts], 0, &device).float())
+            .int()
+            .reshape([n_experts]);
+        let positions = entries - first_entries.select(0, sorted_experts.clone());
+
+        // Dropped entries are dispatched to an extra slot.
+        let num_slots = n_experts * capacity;
+        let slots = sorted_experts
+            .mul_scalar(capacity as i64)
+            .add(positions.clone())
+            .mask_fill(
+                positions.greater_equal_elem(capacity as i64),
+                num_slots as i64,
+            );
+        let token_indices = order.clone().remainder_scalar(num_tokens as i64);
+
+        let dispatched = Tensor::zeros([num_slots + 1, d_model], &device).select_assign(
+            0,
+            slots.clone(),
+            tokens.select(0, token_indices.clone()),
+        );
+        let dispatched = dispatched
+            .slice([0..num_slots])
+            .reshape([n_experts, capacity, d_model]);
+
+        let x = dispatched
+            .matmul(self.weight_inner.val())
+            .add(self.bias_inner.val().reshape([n_experts, 1, d_ff]));
+        let x = self.gelu.forward(x);
+        let x = self.dropout.forward(x);
+        let x = x
+            .matmul(self.weight_outer.val())
+            .add(self.bias_outer.val().reshape([n_experts, 1, d_model]));
+
+        let x = Tensor::cat(
+            alloc::vec![
+                x.reshape([num_slots, d_model]),
+                Tensor::zeros([1, d_model], &device)
+            ],
+            0,
+        );
+        let combined = x
+            .select(0, slots)
+            .mul(gates.select(0, order).reshape([num_entries, 1]));
+        let output =
+            Tensor::zeros([num_tokens, d_model], &device).select_assign(0, token_indices, combined);
+
+        // Switch Transformers load balancing loss.
+        let expert_load = assignments
+            .reshape([n_experts])
+            .div_scalar(num_entries as f32);
+        let mean_probs = probs.mean_dim(0).reshape([n_experts]);
+        let aux_loss = (expert_load.clone() * mean_probs)
+            .sum()
+            .mul_scalar(n_experts as f32);
+
+        MixtureOfExpertsOutput {
+            output: output.reshape(shape),
+            aux_loss,
+            expert_load,
+        }
+    }
+
+    fn capacity(&self, num_tokens: usize, n_experts: usize) -> usize {
+        let capacity = self.capacity_factor * (num_tokens * self.top_k) as f64 / n_experts as f64;
+
+        (capacity.ceil() as usize).clamp(1, num_tokens)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::transformer::PositionWiseFeedForwardConfig;
+    use crate::tensor::{Distribution, TensorData};
+    use crate::{TestAutodiffBackend, TestBackend};
+    use burn_tensor::{Tolerance, ops::FloatElem};
+
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn single_expert_should_match_feed_forward() {
+        let device = Default::default();
+        let pwff = PositionWiseFeedForwardConfig::new(4, 8).init::<TestBackend>(&device);
+        let mut moe = MixtureOfExpertsConfig::new(4, 8, 1)
+            .with_top_k(1)
+            .init::<TestBackend>(&device);
+        moe.weight_inner = Param::from_tensor(pwff.linear_inner.weight.val().unsqueeze());
+        moe.bias_inner =
+            Param::from_tensor(pwff.linear_inner.bias.as_ref().unwrap().val().unsqueeze());
+        moe.weight_outer = Param::from_tensor(pwff.linear_outer.weight.val().unsqueeze());
+        moe.bias_outer =
+            Param::from_tensor(pwff.linear_outer.bias.as_ref().unwrap().val().unsqueeze());
+
+        let input = Tensor::<TestBackend, 3>::random([2, 3, 4], Distribution::Default, &device);
+        let output = moe.forward(input.clone());
+
+        output
+            .output
+            .into_data()
+            .assert_approx_eq::<FT>(&pwff.forward(input).into_data(), Tolerance::default());
+        output
+            .expert_load
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([1.0]), Tolerance::default());
+    }
+
+    #[test]
+    fn should_drop_tokens_exceeding_capacity() {
+        let device = Default::default();
+        let mut moe = MixtureOfExpertsConfig::new(2, 4, 2)
+            .with_top_k(1)
+            .with_capacity_factor(1.0)
+            .init::<TestBackend>(&device);
+        // All the tokens are routed to the first expert, which can process two tokens.
+        moe.router.weight =
+            Param::from_tensor(Tensor::from_floats([[1.0, -1.0], [1.0, -1.0]], &device));
+
+        let output = moe.forward(Tensor::<TestBackend, 2>::ones([4, 2], &device));
+
+        let tokens = output.output;
+        tokens
+            .clone()
+            .slice([2..4])
+            .into_data()
+            .assert_eq(&TensorData::zeros::<f32, _>([2, 2]), false);
+        tokens
+            .clone()
+            .slice([0..1])
+            .into_data()
+            .assert_approx_eq::<FT>(&tokens.slice([1..2]).into_data(), Tolerance::default());
+        output
+            .expert_load
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([1.0, 0.0]), Tolerance::default());
+        // 2 * (1.0 * softmax([2, -2])[0])
+        output
+            .aux_loss
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([1.964_028]), Tolerance::default());
+    }
+
+    #[test]
+    fn router_should_get_gradient_with_single_expert_per_token() {
+        let device = Default::default();
+        let moe = MixtureOfExpertsConfig::new(4, 8, 2)
+            .with_top_k(1)
+            .with_capacity_factor(2.0)
+            .with_dropout(0.0)
+            .init::<TestAutodiffBackend>(&device);
+
+        let output = moe.forward(Tensor::<TestAutodiffBackend, 2>::random(
+            [3, 4],
+            Distribution::Default,
+            &device,
+        ));
+        let grads = output.output.sum().backward();
+
+        let grad = moe.router.weight.grad(&grads).unwrap();
+        assert!(grad.abs().sum().into_scalar() > 0.0);
+    }
+
+    #[test]
+    fn should_dispatch_to_top_k_experts() {
+        let device = Default::default();
+        let moe = MixtureOfExpertsConfig::new(4, 8, 4).init::<TestBackend>(&device);
+
+        let output = moe.forward(Tensor::<TestBackend, 3>::random(
+            [2, 5, 4],
+            Distribution::Default,
+            &device,
+        ));
+
+        assert_eq!(output.output.dims(), [2, 5, 4]);
+        output
+            .expert_load
+            .sum()
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([1.0]), Tolerance::default());
+    }
+
+    #[test]
+    fn display() {
+        let config = MixtureOfExpertsConfig::new(2, 4, 3);
+        let moe = config.init::<TestBackend>(&Default::default());
+
+        assert_eq!(
+            alloc::format!("{moe}"),
+            "MixtureOfExperts {d_model: 2, d_ff: 4, n_experts: 3, top_k: 2, \
+            capacity_factor: 1.25, normalize_gates: false, prob: 0.1, params: 72}"
+        );
+    }
+}
@@ -65,6 +65,12 @@ impl<B: Backend> SimpleOptimizer<B> for AdaGrad {
         (tensor - grad, Some(state))
     }
 
+    fn with_weight_decay_penalty(&self, penalty: f32) -> Self {
+        let mut optim = self.clone();
+        optim.weight_decay = Some(WeightDecay::new(&WeightDecayConfig::new(penalty)));
+        optim
+    }
+
     fn to_device<const D: usize>(mut state: Self::State<D>, device: &Device<B>) -> Self::State<D> {
         state.lr_decay = state.lr_decay.to_device(device);
         state
@@ -71,6 +71,12 @@ impl<B: Backend> SimpleOptimizer<B> for AdamW {
         (tensor_updated - raw_delta.mul_scalar(lr), Some(state))
     }
 
+    fn with_weight_decay_penalty(&self, penalty: f32) -> Self {
+        let mut optim = self.clone();
+        optim.weight_decay = penalty;
+        optim
+    }
+
     fn to_device<const D: usize>(mut state: Self::State<D>, device: &Device<B>) -> Self::State<D> {
         state.momentum = state.momentum.to_device(device);
         state
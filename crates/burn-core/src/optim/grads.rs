@@ -2,12 +2,13 @@
 use burn_collective::{CollectiveError, PeerId, ReduceOperation, all_reduce};
 
 use burn_tensor::{
-    Tensor,
+    Shape, Tensor, TensorMetadata, TensorPrimitive,
     backend::{AutodiffBackend, Backend},
     container::TensorContainer,
 };
 
 use crate::module::{AutodiffModule, ParamId};
+use alloc::{vec, vec::Vec};
 
 use super::visitor::{GradientsParamsChangeDevice, GradientsParamsConverter};
 
@@ -102,6 +103,57 @@ impl GradientsParams {
         self.len() == 0
     }
 
+    /// Compute the L2 norm of all the gradients, as if they were flattened into a single vector.
+    ///
+    /// The norm is returned on the device of the first gradient in [parameter id](ParamId) order,
+    /// or `None` if no gradient is registered.
+    pub fn l2_norm<B: Backend>(&self) -> Option<Tensor<B, 1>> {
+        let mut sum: Option<Tensor<B, 1>> = None;
+
+        for id in self.sorted_ids() {
+            let grad = self.container.get::<B>(&id).unwrap().tensor();
+            let squared = B::float_sum(B::float_mul(grad.clone(), grad));
+            let squared = Tensor::<B, 1>::from_primitive(TensorPrimitive::Float(squared));
+
+            sum = Some(match sum {
+                Some(sum) => {
+                    let device = sum.device();
+                    sum + squared.to_device(&device)
+                }
+                None => squared,
+            });
+        }
+
+        sum.map(|sum| sum.sqrt())
+    }
+
+    /// Multiply every gradient by a single-element `factor`.
+    pub fn scale<B: Backend>(&mut self, factor: Tensor<B, 1>) {
+        for id in self.sorted_ids() {
+            let grad = self.container.remove::<B>(&id).unwrap().tensor();
+            let device = B::float_device(&grad);
+            let rank = grad.shape().num_dims();
+
+            let factor = factor.clone().to_device(&device).into_primitive().tensor();
+            let factor = B::float_reshape(factor, Shape::from(vec![1; rank]));
+            let grad = B::float_mul(grad, factor);
+
+            self.container
+                .register::<B>(id, TensorPrimitive::Float(grad));
+        }
+    }
+
+    fn sorted_ids(&self) -> Vec<ParamId> {
+        let mut ids = self
+            .container
+            .ids()
+            .into_iter()
+            .copied()
+            .collect::<Vec<ParamId>>();
+        ids.sort();
+        ids
+    }
+
     /// Change the device of each tensor gradients registered for the given [module](AutodiffModule).
     pub fn to_device<B: AutodiffBackend, M: AutodiffModule<B>>(
         mut self,
@@ -120,18 +172,10 @@ impl GradientsParams {
         peer_id: PeerId,
         op: ReduceOperation,
     ) -> Result<Self, CollectiveError> {
-        let mut ids = self
-            .container
-            .ids()
-            .into_iter()
-            .copied()
-            .collect::<Vec<ParamId>>();
         // This is crucial, since the all-reduce operations need to happen in the same order for the same parameters on all nodes!
-        ids.sort();
+        let ids = self.sorted_ids();
 
         for id in ids {
-            use burn_tensor::TensorPrimitive;
-
             let Some(grad) = self.container.remove::<B>(&id) else {
                 todo!()
             };
@@ -10,6 +10,7 @@ mod adamw;
 mod base;
 mod grad_accum;
 mod grads;
+mod param_group;
 mod rmsprop;
 mod sgd;
 mod simple;
@@ -21,6 +22,7 @@ pub use adamw::*;
 pub use base::*;
 pub use grad_accum::*;
 pub use grads::*;
+pub use param_group::*;
 pub use rmsprop::*;
 pub use sgd::*;
 pub use simple::*;
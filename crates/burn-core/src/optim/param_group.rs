@@ -0,0 +1,311 @@
+use alloc::{string::String, vec::Vec};
+use burn_tensor::backend::Backend;
+use hashbrown::HashMap;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    LearningRate,
+    module::{Module, ParamId, list_param_paths, matches_pattern},
+    record::{PrecisionSettings, Record},
+};
+
+/// A group of parameters optimized with their own learning rate multiplier and weight decay.
+///
+/// Parameters are selected by [id](ParamId) or by module path, see
+/// [list_param_paths](crate::module::list_param_paths). Path patterns can use `*` to match any
+/// sequence of characters, e.g. `encoder.*` or `*.bias`.
+///
+/// A parameter belongs to the first group that selects it, parameters without a group use the
+/// optimizer defaults.
+///
+/// Groups are saved in the optimizer record and restored when loading it. Records saved without
+/// groups keep the groups already added to the optimizer.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParamGroup {
+    /// Path patterns of the selected parameters.
+    pub paths: Vec<String>,
+    /// Ids of the selected parameters.
+    pub ids: Vec<ParamId>,
+    /// Multiplies the learning rate of the optimizer step, e.g. provided by a
+    /// [scheduler](crate::lr_scheduler::LrScheduler).
+    ///
+    /// Parameters with a multiplier of zero are frozen.
+    pub lr_multiplier: LearningRate,
+    /// Replaces the weight decay penalty of the optimizer.
+    pub weight_decay: Option<f32>,
+}
+
+impl Default for ParamGroup {
+    fn default() -> Self {
+        Self {
+            paths: Vec::new(),
+            ids: Vec::new(),
+            lr_multiplier: 1.0,
+            weight_decay: None,
+        }
+    }
+}
+
+impl ParamGroup {
+    /// Create a group selecting the parameters whose path matches the pattern.
+    pub fn from_path(pattern: impl Into<String>) -> Self {
+        Self::default().with_path(pattern)
+    }
+
+    /// Create a group selecting the given parameters.
+    pub fn from_ids(ids: impl IntoIterator<Item = ParamId>) -> Self {
+        Self::default().with_ids(ids)
+    }
+
+    /// Also select the parameters whose path matches the pattern.
+    pub fn with_path(mut self, pattern: impl Into<String>) -> Self {
+        self.paths.push(pattern.into());
+        self
+    }
+
+    /// Also select the given parameters.
+    pub fn with_ids(mut self, ids: impl IntoIterator<Item = ParamId>) -> Self {
+        self.ids.extend(ids);
+        self
+    }
+
+    /// Set the learning rate multiplier.
+    pub fn with_lr_multiplier(mut self, lr_multiplier: LearningRate) -> Self {
+        self.lr_multiplier = lr_multiplier;
+        self
+    }
+
+    /// Set the weight decay penalty, `0.0` disables weight decay.
+    pub fn with_weight_decay(mut self, penalty: f32) -> Self {
+        self.weight_decay = Some(penalty);
+        self
+    }
+
+    /// Freeze the parameters of the group.
+    pub fn frozen(self) -> Self {
+        self.with_lr_multiplier(0.0)
+    }
+
+    /// If the group selects the parameter.
+    pub fn contains(&self, path: &str, id: ParamId) -> bool {
+        self.ids.contains(&id)
+            || self
+                .paths
+                .iter()
+                .any(|pattern| matches_pattern(pattern, path))
+    }
+
+    /// If the parameters of the group aren't updated.
+    pub fn is_frozen(&self) -> bool {
+        self.lr_multiplier == 0.0
+    }
+}
+
+/// Assign each parameter of the module to the index of the first group selecting it.
+pub(crate) fn assign_param_groups<B: Backend, M: Module<B>>(
+    groups: &[ParamGroup],
+    module: &M,
+) -> HashMap<ParamId, usize> {
+    if groups.is_empty() {
+        return HashMap::new();
+    }
+
+    list_param_paths(module)
+        .into_iter()
+        .filter_map(|(path, id)| {
+            groups
+                .iter()
+                .position(|group| group.contains(&path, id))
+                .map(|index| (id, index))
+        })
+        .collect()
+}
+
+/// [Parameter group](ParamGroup) record item.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ParamGroupItem {
+    paths: Vec<String>,
+    ids: Vec<String>,
+    lr_multiplier: LearningRate,
+    weight_decay: Option<f32>,
+}
+
+impl<B: Backend> Record<B> for ParamGroup {
+    type Item<S: PrecisionSettings> = ParamGroupItem;
+
+    fn into_item<S: PrecisionSettings>(self) -> Self::Item<S> {
+        ParamGroupItem {
+            paths: self.paths,
+            ids: self.ids.into_iter().map(ParamId::serialize).collect(),
+            lr_multiplier: self.lr_multiplier,
+            weight_decay: self.weight_decay,
+        }
+    }
+
+    fn from_item<S: PrecisionSettings>(item: Self::Item<S>, _device: &B::Device) -> Self {
+        Self {
+            paths: item.paths,
+            ids: item.ids.iter().map(|id| ParamId::deserialize(id)).collect(),
+            lr_multiplier: item.lr_multiplier,
+            weight_decay: item.weight_decay,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        TestAutodiffBackend,
+        nn::{Linear, LinearConfig},
+        optim::{AdamConfig, GradientsParams, Optimizer, SgdConfig},
+        record::{BinBytesRecorder, FullPrecisionSettings, Recorder},
+        tensor::{Distribution, Tensor},
+    };
+    use burn_tensor::Tolerance;
+    use burn_tensor::ops::FloatElem;
+
+    type FT = FloatElem<TestAutodiffBackend>;
+
+    const LEARNING_RATE: LearningRate = 0.1;
+
+    #[test]
+    fn should_match_path_patterns() {
+        assert!(matches_pattern(
+            "encoder.layers.0.weight",
+            "encoder.layers.0.weight"
+        ));
+        assert!(matches_pattern("encoder.*", "encoder.layers.0.weight"));
+        assert!(matches_pattern("*.bias", "decoder.linear.bias"));
+        assert!(matches_pattern("*norm*.gamma", "layers.1.norm_2.gamma"));
+        assert!(!matches_pattern("encoder.*", "decoder.layers.0.weight"));
+        assert!(!matches_pattern("*.bias", "decoder.linear.weight"));
+    }
+
+    #[test]
+    fn should_select_params_by_path_or_id() {
+        let id = ParamId::new();
+        let group = ParamGroup::from_path("*.bias").with_ids([id]);
+
+        assert!(group.contains("linear.bias", ParamId::new()));
+        assert!(group.contains("linear.weight", id));
+        assert!(!group.contains("linear.weight", ParamId::new()));
+    }
+
+    #[test]
+    fn frozen_group_should_not_update_params() {
+        let device = Default::default();
+        let layer = layer(&device);
+        let mut optim = SgdConfig::new()
+            .init::<TestAutodiffBackend, Linear<TestAutodiffBackend>>()
+            .with_param_group(ParamGroup::from_path("bias").frozen());
+
+        let grads = gradients(&layer, &device);
+        let layer_updated = optim.step(LEARNING_RATE, layer.clone(), grads);
+
+        let bias = layer.bias.as_ref().unwrap().val().into_data();
+        let bias_updated = layer_updated.bias.unwrap().val().into_data();
+        bias_updated.assert_eq(&bias, true);
+        assert_ne!(
+            layer_updated.weight.val().into_data(),
+            layer.weight.val().into_data()
+        );
+    }
+
+    #[test]
+    fn group_should_scale_learning_rate() {
+        let device = Default::default();
+        let layer = layer(&device);
+        let mut optim = SgdConfig::new()
+            .init::<TestAutodiffBackend, Linear<TestAutodiffBackend>>()
+            .with_param_group(ParamGroup::from_ids([layer.weight.id]).with_lr_multiplier(0.5));
+        let mut optim_expected = SgdConfig::new().init();
+
+        let grads = gradients(&layer, &device);
+        let layer_updated = optim.step(LEARNING_RATE, layer.clone(), grads);
+        let grads = gradients(&layer, &device);
+        let layer_expected = optim_expected.step(LEARNING_RATE * 0.5, layer, grads);
+
+        layer_updated
+            .weight
+            .val()
+            .into_data()
+            .assert_approx_eq::<FT>(
+                &layer_expected.weight.val().into_data(),
+                Tolerance::default(),
+            );
+    }
+
+    #[test]
+    fn groups_should_be_restored_from_record() {
+        let device = Default::default();
+        let layer = layer(&device);
+        let group = ParamGroup::from_path("bias")
+            .with_ids([ParamId::new()])
+            .with_weight_decay(0.0)
+            .frozen();
+        let mut optim = AdamConfig::new()
+            .init::<TestAutodiffBackend, Linear<TestAutodiffBackend>>()
+            .with_param_group(group.clone());
+        let grads = gradients(&layer, &device);
+        let layer = optim.step(LEARNING_RATE, layer, grads);
+
+        let recorder = BinBytesRecorder::<FullPrecisionSettings>::default();
+        let bytes = recorder.record(optim.to_record(), ()).unwrap();
+        let record = recorder.load(bytes, &device).unwrap();
+
+        let mut optim = AdamConfig::new()
+            .init::<TestAutodiffBackend, Linear<TestAutodiffBackend>>()
+            .load_record(record);
+        assert_eq!(optim.to_record().groups, alloc::vec![group]);
+        assert_eq!(optim.to_record().len(), 1);
+
+        let grads = gradients(&layer, &device);
+        let layer_updated = optim.step(LEARNING_RATE, layer.clone(), grads);
+        let bias = layer.bias.as_ref().unwrap().val().into_data();
+        let bias_updated = layer_updated.bias.unwrap().val().into_data();
+        bias_updated.assert_eq(&bias, true);
+    }
+
+    #[test]
+    fn should_load_record_saved_without_groups() {
+        let device = Default::default();
+        let layer = layer(&device);
+        let mut optim =
+            AdamConfig::new().init::<TestAutodiffBackend, Linear<TestAutodiffBackend>>();
+        let grads = gradients(&layer, &device);
+        let layer = optim.step(LEARNING_RATE, layer, grads);
+
+        // Records were saved as a map of the parameter states before groups were added.
+        let recorder = BinBytesRecorder::<FullPrecisionSettings>::default();
+        let bytes = recorder.record(optim.to_record().states, ()).unwrap();
+        let record = recorder.load(bytes, &device).unwrap();
+
+        let group = ParamGroup::from_path("bias").frozen();
+        let mut optim = AdamConfig::new()
+            .init::<TestAutodiffBackend, Linear<TestAutodiffBackend>>()
+            .with_param_group(group.clone())
+            .load_record(record);
+        assert_eq!(optim.to_record().groups, alloc::vec![group]);
+        assert_eq!(optim.to_record().len(), 2);
+
+        let grads = gradients(&layer, &device);
+        let layer_updated = optim.step(LEARNING_RATE, layer.clone(), grads);
+        let bias = layer.bias.as_ref().unwrap().val().into_data();
+        let bias_updated = layer_updated.bias.unwrap().val().into_data();
+        bias_updated.assert_eq(&bias, true);
+    }
+
+    fn layer(device: &<TestAutodiffBackend as Backend>::Device) -> Linear<TestAutodiffBackend> {
+        LinearConfig::new(6, 6).with_bias(true).init(device)
+    }
+
+    fn gradients(
+        layer: &Linear<TestAutodiffBackend>,
+        device: &<TestAutodiffBackend as Backend>::Device,
+    ) -> GradientsParams {
+        let x = Tensor::<TestAutodiffBackend, 2>::random([2, 6], Distribution::Default, device);
+        let grads = layer.forward(x).sum().backward();
+        GradientsParams::from_grads(grads, layer)
+    }
+}
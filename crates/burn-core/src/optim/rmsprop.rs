@@ -125,6 +125,12 @@ impl<B: Backend> SimpleOptimizer<B> for RmsProp {
         (tensor - delta, Some(state))
     }
 
+    fn with_weight_decay_penalty(&self, penalty: f32) -> Self {
+        let mut optim = self.clone();
+        optim.weight_decay = Some(WeightDecay::new(&WeightDecayConfig::new(penalty)));
+        optim
+    }
+
     fn to_device<const D: usize>(mut state: Self::State<D>, device: &Device<B>) -> Self::State<D> {
         state.square_avg = state.square_avg.to_device(device);
         state.centered = state.centered.to_device(device);
@@ -88,6 +88,12 @@ impl<B: Backend> SimpleOptimizer<B> for Sgd<B> {
         (tensor - delta, Some(state))
     }
 
+    fn with_weight_decay_penalty(&self, penalty: f32) -> Self {
+        let mut optim = self.clone();
+        optim.weight_decay = Some(WeightDecay::new(&WeightDecayConfig::new(penalty)));
+        optim
+    }
+
     fn to_device<const D: usize>(mut state: Self::State<D>, device: &B::Device) -> Self::State<D> {
         state.momentum = state.momentum.map(|state| state.to_device(device));
         state
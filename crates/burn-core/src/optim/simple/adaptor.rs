@@ -1,10 +1,14 @@
-use super::{SimpleOptimizer, record::AdaptorRecord};
+use super::{
+    SimpleOptimizer,
+    record::{AdaptorRecord, OptimizerAdaptorRecord},
+};
 use crate::{
     LearningRate,
     grad_clipping::GradientClipping,
     module::{AutodiffModule, ModuleMapper, ParamId},
-    optim::{GradientsParams, Optimizer},
+    optim::{GradientsParams, Optimizer, ParamGroup, assign_param_groups},
 };
+use alloc::vec::Vec;
 use burn_tensor::{Tensor, backend::AutodiffBackend};
 use core::marker::PhantomData;
 use hashbrown::HashMap;
@@ -22,6 +26,16 @@ where
     records: HashMap<ParamId, AdaptorRecord<O, B>>,
     module: PhantomData<M>,
     grad_clipping: Option<GradientClipping>,
+    groups: Vec<ParamGroup>,
+    group_cache: Option<ParamGroupCache<O>>,
+}
+
+/// The group of each parameter and the optimizer of each group, computed on the first step since
+/// the parameters of a module keep their ids between steps.
+#[derive(Clone)]
+struct ParamGroupCache<O> {
+    assignments: HashMap<ParamId, usize>,
+    optims: Vec<Option<O>>,
 }
 
 impl<O, B, M> From<O> for OptimizerAdaptor<O, M, B>
@@ -36,6 +50,8 @@ where
             records: HashMap::new(),
             module: PhantomData,
             grad_clipping: None,
+            groups: Vec::new(),
+            group_cache: None,
         }
     }
 }
@@ -60,6 +76,21 @@ where
         self
     }
 
+    /// Adds a parameter group.
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - The parameter group, taking precedence over the groups added after it.
+    ///
+    /// # Returns
+    ///
+    /// The optimizer.
+    pub fn with_param_group(mut self, group: ParamGroup) -> Self {
+        self.groups.push(group);
+        self.group_cache = None;
+        self
+    }
+
     #[cfg(test)]
     pub(crate) fn has_gradient_clipping(&self) -> bool {
         self.grad_clipping.is_some()
@@ -72,25 +103,50 @@ where
     M: AutodiffModule<B>,
     O: SimpleOptimizer<B::InnerBackend>,
 {
-    type Record = HashMap<ParamId, AdaptorRecord<O, B>>;
+    type Record = OptimizerAdaptorRecord<O, B>;
 
     fn step(&mut self, lr: LearningRate, module: M, mut grads: GradientsParams) -> M {
+        let groups = &self.groups;
+        let optim = &self.optim;
+        let cache = self.group_cache.get_or_insert_with(|| ParamGroupCache {
+            assignments: assign_param_groups::<B, M>(groups, &module),
+            optims: groups
+                .iter()
+                .map(|group| {
+                    group
+                        .weight_decay
+                        .map(|penalty| optim.with_weight_decay_penalty(penalty))
+                })
+                .collect(),
+        });
+
         let mut mapper = SimpleOptimizerMapper::<M, B, O>::new(
             &self.optim,
             &mut self.records,
             &mut grads,
             lr,
             self.grad_clipping.as_ref(),
+            &self.groups,
+            &cache.optims,
+            &cache.assignments,
         );
         module.map(&mut mapper)
     }
 
     fn to_record(&self) -> Self::Record {
-        self.records.clone()
+        OptimizerAdaptorRecord {
+            states: self.records.clone(),
+            groups: self.groups.clone(),
+        }
     }
 
     fn load_record(mut self, record: Self::Record) -> Self {
-        self.records = record;
+        self.records = record.states;
+        // Records saved without groups keep the groups added to the optimizer.
+        if !record.groups.is_empty() {
+            self.groups = record.groups;
+            self.group_cache = None;
+        }
         self
     }
 }
@@ -108,6 +164,9 @@ where
     lr: LearningRate,
     phantom: PhantomData<M>,
     grad_clipping: Option<&'a GradientClipping>,
+    groups: &'a [ParamGroup],
+    group_optims: &'a [Option<O>],
+    assignments: &'a HashMap<ParamId, usize>,
 }
 
 impl<M, B, O> ModuleMapper<B> for SimpleOptimizerMapper<'_, M, B, O>
@@ -120,6 +179,15 @@ where
         let grad = self.grads.remove(id);
 
         if let Some(grad) = grad {
+            let group = self.assignments.get(&id).copied();
+            let lr_multiplier = group.map_or(1.0, |index| self.groups[index].lr_multiplier);
+            if lr_multiplier == 0.0 {
+                return tensor;
+            }
+            let optimizer = group
+                .and_then(|index| self.group_optims[index].as_ref())
+                .unwrap_or(self.optimizer);
+
             let device = grad.device();
             let is_require_grad = tensor.is_require_grad();
             let (key, record) = self.records.remove_entry(&id).unzip();
@@ -130,8 +198,8 @@ where
                 grad
             };
 
-            let (tensor, state) = self.optimizer.step(
-                self.lr,
+            let (tensor, state) = optimizer.step(
+                self.lr * lr_multiplier,
                 tensor.inner(),
                 clipped_grad,
                 record.map(|record| O::to_device(record.into_state(), &device)),
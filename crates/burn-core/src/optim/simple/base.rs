@@ -25,6 +25,14 @@ where
         state: Option<Self::State<D>>,
     ) -> (Tensor<B, D>, Option<Self::State<D>>);
 
+    /// Returns a copy of the optimizer using the given weight decay penalty, used by
+    /// [parameter groups](crate::optim::ParamGroup).
+    ///
+    /// Optimizers without weight decay are returned unchanged.
+    fn with_weight_decay_penalty(&self, _penalty: f32) -> Self {
+        self.clone()
+    }
+
     /// Change the device of the state.
     ///
     /// This function will be called accordingly to have the state on the same device as the
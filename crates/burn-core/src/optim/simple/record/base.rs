@@ -1,10 +1,18 @@
 use super::{AdaptorRecordItemV1, AdaptorRecordV1};
 use crate::{
-    optim::SimpleOptimizer,
+    module::ParamId,
+    optim::{ParamGroup, ParamGroupItem, SimpleOptimizer},
     record::{PrecisionSettings, Record},
 };
+use alloc::{string::String, vec::Vec};
 use burn_tensor::backend::AutodiffBackend;
-use serde::{Deserialize, Serialize};
+use core::{fmt, marker::PhantomData};
+use hashbrown::HashMap;
+use serde::{
+    Deserialize, Deserializer, Serialize, Serializer,
+    de::{MapAccess, Visitor},
+    ser::SerializeMap,
+};
 
 /// [Optimizer adaptor](crate::optim::simple::adaptor::OptimizerAdaptor) record.
 ///
@@ -91,3 +99,154 @@ where
         Self::V1(AdaptorRecordV1::from_state(state))
     }
 }
+
+/// [Optimizer adaptor](crate::optim::simple::adaptor::OptimizerAdaptor) record, holding the state
+/// of each parameter and the [parameter groups](ParamGroup).
+pub struct OptimizerAdaptorRecord<O, B>
+where
+    O: SimpleOptimizer<B::InnerBackend>,
+    B: AutodiffBackend,
+{
+    /// The optimizer state of each parameter.
+    pub states: HashMap<ParamId, AdaptorRecord<O, B>>,
+    /// The parameter groups.
+    pub groups: Vec<ParamGroup>,
+}
+
+/// The key of the parameter groups in the serialized record, which can't collide with the
+/// serialized [parameter ids](ParamId).
+const GROUPS_KEY: &str = "param_groups";
+
+/// [Optimizer adaptor record](OptimizerAdaptorRecord) item.
+///
+/// Serialized as a map from the parameter ids to their state, with the groups under a reserved
+/// key, so the records saved as a map of states only can still be loaded.
+pub struct OptimizerAdaptorRecordItem<
+    O: SimpleOptimizer<B::InnerBackend>,
+    B: AutodiffBackend,
+    S: PrecisionSettings,
+> {
+    states: HashMap<String, AdaptorRecordItem<O, B, S>>,
+    groups: Vec<ParamGroupItem>,
+}
+
+impl<O, B, S> Serialize for OptimizerAdaptorRecordItem<O, B, S>
+where
+    O: SimpleOptimizer<B::InnerBackend>,
+    B: AutodiffBackend,
+    S: PrecisionSettings,
+{
+    fn serialize<Ser: Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+        let mut map = serializer.serialize_map(Some(self.states.len() + 1))?;
+        map.serialize_entry(GROUPS_KEY, &self.groups)?;
+        for (id, state) in self.states.iter() {
+            map.serialize_entry(id, state)?;
+        }
+        map.end()
+    }
+}
+
+impl<'de, O, B, S> Deserialize<'de> for OptimizerAdaptorRecordItem<O, B, S>
+where
+    O: SimpleOptimizer<B::InnerBackend>,
+    B: AutodiffBackend,
+    S: PrecisionSettings,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_map(OptimizerAdaptorRecordVisitor(PhantomData))
+    }
+}
+
+struct OptimizerAdaptorRecordVisitor<O, B, S>(PhantomData<(O, B, S)>);
+
+impl<'de, O, B, S> Visitor<'de> for OptimizerAdaptorRecordVisitor<O, B, S>
+where
+    O: SimpleOptimizer<B::InnerBackend>,
+    B: AutodiffBackend,
+    S: PrecisionSettings,
+{
+    type Value = OptimizerAdaptorRecordItem<O, B, S>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a map of optimizer states")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut states = HashMap::with_capacity(map.size_hint().unwrap_or(0));
+        let mut groups = Vec::new();
+
+        while let Some(key) = map.next_key::<String>()? {
+            if key == GROUPS_KEY {
+                groups = map.next_value()?;
+            } else {
+                states.insert(key, map.next_value()?);
+            }
+        }
+
+        Ok(OptimizerAdaptorRecordItem { states, groups })
+    }
+}
+
+impl<O, B> Record<B> for OptimizerAdaptorRecord<O, B>
+where
+    O: SimpleOptimizer<B::InnerBackend>,
+    B: AutodiffBackend,
+{
+    type Item<S: PrecisionSettings> = OptimizerAdaptorRecordItem<O, B, S>;
+
+    fn into_item<S: PrecisionSettings>(self) -> Self::Item<S> {
+        OptimizerAdaptorRecordItem {
+            states: Record::<B>::into_item(self.states),
+            groups: Record::<B>::into_item(self.groups),
+        }
+    }
+
+    fn from_item<S: PrecisionSettings>(item: Self::Item<S>, device: &B::Device) -> Self {
+        Self {
+            states: Record::<B>::from_item(item.states, device),
+            groups: Record::<B>::from_item(item.groups, device),
+        }
+    }
+}
+
+impl<O, B> Clone for OptimizerAdaptorRecord<O, B>
+where
+    O: SimpleOptimizer<B::InnerBackend>,
+    B: AutodiffBackend,
+{
+    fn clone(&self) -> Self {
+        Self {
+            states: self.states.clone(),
+            groups: self.groups.clone(),
+        }
+    }
+}
+
+impl<O, B> From<HashMap<ParamId, AdaptorRecord<O, B>>> for OptimizerAdaptorRecord<O, B>
+where
+    O: SimpleOptimizer<B::InnerBackend>,
+    B: AutodiffBackend,
+{
+    fn from(states: HashMap<ParamId, AdaptorRecord<O, B>>) -> Self {
+        Self {
+            states,
+            groups: Vec::new(),
+        }
+    }
+}
+
+impl<O, B> OptimizerAdaptorRecord<O, B>
+where
+    O: SimpleOptimizer<B::InnerBackend>,
+    B: AutodiffBackend,
+{
+    /// The number of parameters with an optimizer state.
+    pub fn len(&self) -> usize {
+        self.states.len()
+    }
+
+    /// If no parameter has an optimizer state.
+    pub fn is_empty(&self) -> bool {
+        self.states.is_empty()
+    }
+}
@@ -265,6 +265,72 @@ mod num_params {
     }
 }
 
+mod param_paths {
+    use super::*;
+    use burn::module::list_param_paths;
+
+    #[test]
+    fn should_list_param_paths_composed() {
+        let device = <TestBackend as Backend>::Device::default();
+        let module = ModuleComposed::<TestBackend>::new(&device);
+
+        let paths = list_param_paths(&module)
+            .into_iter()
+            .map(|(path, _id)| path)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            paths,
+            vec![
+                "weight",
+                "basic.weight_basic",
+                "tuple.0.weight_basic",
+                "tuple.1.weight_basic",
+            ]
+        );
+    }
+
+    #[test]
+    fn should_list_param_paths_enum() {
+        let device = <TestBackend as Backend>::Device::default();
+        let composed = ModuleComposed::<TestBackend>::new(&device);
+        let module = ModuleEnumNested::AnotherEnum(ModuleEnum::Composed(composed));
+
+        let paths = list_param_paths(&module)
+            .into_iter()
+            .map(|(path, _id)| path)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            paths,
+            vec![
+                "AnotherEnum.Composed.weight",
+                "AnotherEnum.Composed.basic.weight_basic",
+                "AnotherEnum.Composed.tuple.0.weight_basic",
+                "AnotherEnum.Composed.tuple.1.weight_basic",
+            ]
+        );
+    }
+
+    #[test]
+    fn should_list_param_paths_const_generic() {
+        let device = <TestBackend as Backend>::Device::default();
+        let module = ModuleWithConstGeneric::<TestBackend, 2> {
+            modules: [ModuleBasic::new(&device), ModuleBasic::new(&device)],
+        };
+
+        let paths = list_param_paths(&module)
+            .into_iter()
+            .map(|(path, _id)| path)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            paths,
+            vec!["modules.0.weight_basic", "modules.1.weight_basic"]
+        );
+    }
+}
+
 #[cfg(feature = "std")]
 mod require_grad {
     use burn_tensor::backend::AutodiffBackend;
@@ -12,7 +12,7 @@ use burn_cubecl_fusion::{
 };
 use burn_fusion::stream::{Operation, OrderedExecution};
 use burn_fusion::{FusionBackend, FusionRuntime, client::MutexFusionClient};
-use burn_ir::{BackendIr, TensorHandle};
+use burn_ir::{BackendIr, TensorHandle, optim::PassPipeline};
 use burn_tensor::{DType, Shape};
 use core::marker::PhantomData;
 use half::{bf16, f16};
@@ -147,6 +147,10 @@ impl<R: CubeRuntime, BT: BoolElement> FusionRuntime for FusionCubeRuntime<R, BT>
             )),
         ]
     }
+
+    fn passes(_device: &Self::FusionDevice) -> PassPipeline {
+        PassPipeline::default()
+    }
 }
 
 /// Fusion runtime for JIT runtimes.
@@ -20,6 +20,10 @@ pub mod transform;
 #[cfg(feature = "audio")]
 pub mod audio;
 
+/// Text tokenizers.
+#[cfg(feature = "text")]
+pub mod text;
+
 /// Vision datasets.
 #[cfg(feature = "vision")]
 pub mod vision;
@@ -0,0 +1,42 @@
+use thiserror::Error;
+
+/// A tokenizer converting text to the ids of the tokens of a vocabulary, and back.
+pub trait Tokenizer: Send + Sync {
+    /// Converts the text to token ids.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text to encode.
+    /// * `special_tokens` - Whether to add the special tokens of the tokenizer, e.g. `[CLS]` and
+    ///   `[SEP]`.
+    fn encode(&self, text: &str, special_tokens: bool) -> Vec<usize>;
+
+    /// Adds the special tokens to the ids of an encoded text, e.g. after truncating it.
+    fn add_special_tokens(&self, ids: Vec<usize>) -> Vec<usize> {
+        ids
+    }
+
+    /// Converts token ids back to text, skipping the special tokens.
+    fn decode(&self, ids: &[usize]) -> String;
+
+    /// The number of tokens in the vocabulary, including the added tokens.
+    fn vocab_size(&self) -> usize;
+
+    /// The id of the given token, if it is in the vocabulary.
+    fn token_to_id(&self, token: &str) -> Option<usize>;
+
+    /// The token of the given id, if it is in the vocabulary.
+    fn id_to_token(&self, id: usize) -> Option<String>;
+}
+
+/// Error type for the [text tokenizers](crate::text::TextTokenizer).
+#[derive(Error, Debug)]
+pub enum TokenizerError {
+    /// I/O operation error.
+    #[error("I/O error: `{0}`")]
+    IOError(String),
+
+    /// Parsing error.
+    #[error("Parsing error: `{0}`")]
+    ParsingError(String),
+}
@@ -0,0 +1,267 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+/// Byte-pair encoding model, which splits words into characters and merges the pairs of tokens
+/// in the order of the merges, like GPT-2 and RoBERTa.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(from = "BpeConfig")]
+pub struct Bpe {
+    vocab: HashMap<String, usize>,
+    tokens: HashMap<usize, String>,
+    merges: HashMap<(String, String), usize>,
+    unk_token: Option<String>,
+    continuing_subword_prefix: Option<String>,
+    end_of_word_suffix: Option<String>,
+    fuse_unk: bool,
+    byte_fallback: bool,
+    ignore_merges: bool,
+}
+
+/// A merge of the `tokenizer.json`, either `"a b"` or `["a", "b"]`.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Merge {
+    Pair(String, String),
+    Joined(String),
+}
+
+#[derive(Deserialize)]
+struct BpeConfig {
+    vocab: HashMap<String, usize>,
+    merges: Vec<Merge>,
+    #[serde(default)]
+    unk_token: Option<String>,
+    #[serde(default)]
+    continuing_subword_prefix: Option<String>,
+    #[serde(default)]
+    end_of_word_suffix: Option<String>,
+    #[serde(default)]
+    fuse_unk: bool,
+    #[serde(default)]
+    byte_fallback: bool,
+    #[serde(default)]
+    ignore_merges: bool,
+}
+
+impl From<BpeConfig> for Bpe {
+    fn from(config: BpeConfig) -> Self {
+        let merges = config
+            .merges
+            .into_iter()
+            .filter_map(|merge| match merge {
+                Merge::Pair(left, right) => Some((left, right)),
+                Merge::Joined(merge) => merge
+                    .split_once(' ')
+                    .map(|(left, right)| (left.to_string(), right.to_string())),
+            })
+            .collect();
+
+        Self {
+            unk_token: config.unk_token,
+            continuing_subword_prefix: config.continuing_subword_prefix,
+            end_of_word_suffix: config.end_of_word_suffix,
+            fuse_unk: config.fuse_unk,
+            byte_fallback: config.byte_fallback,
+            ignore_merges: config.ignore_merges,
+            ..Self::new(config.vocab, merges)
+        }
+    }
+}
+
+impl Bpe {
+    /// Creates a BPE model from its vocabulary and its merges, in priority order.
+    pub fn new(vocab: HashMap<String, usize>, merges: Vec<(String, String)>) -> Self {
+        let tokens = vocab
+            .iter()
+            .map(|(token, id)| (*id, token.clone()))
+            .collect();
+        let merges = merges
+            .into_iter()
+            .enumerate()
+            .map(|(rank, merge)| (merge, rank))
+            .collect();
+
+        Self {
+            vocab,
+            tokens,
+            merges,
+            unk_token: None,
+            continuing_subword_prefix: None,
+            end_of_word_suffix: None,
+            fuse_unk: false,
+            byte_fallback: false,
+            ignore_merges: false,
+        }
+    }
+
+    /// Sets the token of the characters that are not in the vocabulary.
+    pub fn with_unk_token(mut self, unk_token: Option<String>) -> Self {
+        self.unk_token = unk_token;
+        self
+    }
+
+    /// Sets the prefix of the tokens that do not start a word.
+    pub fn with_continuing_subword_prefix(mut self, prefix: Option<String>) -> Self {
+        self.continuing_subword_prefix = prefix;
+        self
+    }
+
+    /// Sets the suffix of the tokens that end a word.
+    pub fn with_end_of_word_suffix(mut self, suffix: Option<String>) -> Self {
+        self.end_of_word_suffix = suffix;
+        self
+    }
+
+    /// Sets whether consecutive unknown tokens are fused into one.
+    pub fn with_fuse_unk(mut self, fuse_unk: bool) -> Self {
+        self.fuse_unk = fuse_unk;
+        self
+    }
+
+    /// Sets whether the unknown characters are encoded with their `<0xXX>` byte tokens.
+    pub fn with_byte_fallback(mut self, byte_fallback: bool) -> Self {
+        self.byte_fallback = byte_fallback;
+        self
+    }
+
+    /// Tokenizes a word.
+    pub fn tokenize(&self, word: &str) -> Vec<usize> {
+        if self.ignore_merges
+            && let Some(id) = self.vocab.get(word)
+        {
+            return vec![*id];
+        }
+
+        let len = word.chars().count();
+        let mut symbols = word
+            .chars()
+            .enumerate()
+            .map(|(i, c)| {
+                let mut symbol = String::new();
+                if i > 0
+                    && let Some(prefix) = &self.continuing_subword_prefix
+                {
+                    symbol.push_str(prefix);
+                }
+                symbol.push(c);
+                if i == len - 1
+                    && let Some(suffix) = &self.end_of_word_suffix
+                {
+                    symbol.push_str(suffix);
+                }
+                symbol
+            })
+            .collect::<Vec<_>>();
+
+        // Merge the pair with the best rank until none can be merged
+        loop {
+            let best = symbols
+                .windows(2)
+                .enumerate()
+                .filter_map(|(i, pair)| {
+                    self.merges
+                        .get(&(pair[0].clone(), pair[1].clone()))
+                        .map(|rank| (*rank, i))
+                })
+                .min();
+            let Some((_, i)) = best else {
+                break;
+            };
+
+            let right = symbols.remove(i + 1);
+            let right = match &self.continuing_subword_prefix {
+                Some(prefix) => right.strip_prefix(prefix.as_str()).unwrap_or(&right),
+                None => &right,
+            };
+            symbols[i].push_str(right);
+        }
+
+        let unk_id = self
+            .unk_token
+            .as_ref()
+            .and_then(|unk_token| self.vocab.get(unk_token));
+        let mut ids = Vec::with_capacity(symbols.len());
+        let mut previous_unk = false;
+        for symbol in symbols {
+            if let Some(id) = self.vocab.get(&symbol) {
+                ids.push(*id);
+                previous_unk = false;
+                continue;
+            }
+
+            if self.byte_fallback {
+                let bytes = symbol
+                    .bytes()
+                    .map(|byte| self.vocab.get(&format!("<0x{byte:02X}>")).copied())
+                    .collect::<Option<Vec<_>>>();
+                if let Some(bytes) = bytes {
+                    ids.extend(bytes);
+                    previous_unk = false;
+                    continue;
+                }
+            }
+            if let Some(unk_id) = unk_id
+                && !(self.fuse_unk && previous_unk)
+            {
+                ids.push(*unk_id);
+            }
+            previous_unk = true;
+        }
+
+        ids
+    }
+
+    /// The id of the given token, if it is in the vocabulary.
+    pub fn token_to_id(&self, token: &str) -> Option<usize> {
+        self.vocab.get(token).copied()
+    }
+
+    /// The token of the given id, if it is in the vocabulary.
+    pub fn id_to_token(&self, id: usize) -> Option<&str> {
+        self.tokens.get(&id).map(String::as_str)
+    }
+
+    /// The number of tokens in the vocabulary.
+    pub fn vocab_size(&self) -> usize {
+        self.vocab.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vocab(tokens: &[&str]) -> HashMap<String, usize> {
+        tokens
+            .iter()
+            .enumerate()
+            .map(|(id, token)| (token.to_string(), id))
+            .collect()
+    }
+
+    #[test]
+    fn should_merge_pairs_by_rank() {
+        let bpe = Bpe::new(
+            vocab(&["<unk>", "l", "o", "w", "e", "r", "lo", "low", "er", "lower"]),
+            vec![
+                ("l".to_string(), "o".to_string()),
+                ("lo".to_string(), "w".to_string()),
+                ("e".to_string(), "r".to_string()),
+            ],
+        )
+        .with_unk_token(Some("<unk>".to_string()))
+        .with_fuse_unk(true);
+
+        assert_eq!(bpe.tokenize("lower"), vec![7, 8]);
+        assert_eq!(bpe.tokenize("low"), vec![7]);
+        assert_eq!(bpe.tokenize("lowxxer"), vec![7, 0, 8]);
+    }
+
+    #[test]
+    fn should_fall_back_to_bytes() {
+        let bpe = Bpe::new(vocab(&["a", "<0xC3>", "<0xA9>"]), Vec::new()).with_byte_fallback(true);
+
+        assert_eq!(bpe.tokenize("aé"), vec![0, 1, 2]);
+    }
+}
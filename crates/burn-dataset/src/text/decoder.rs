@@ -0,0 +1,274 @@
+use serde::Deserialize;
+
+use super::{PrependScheme, normalizer::Pattern, pre_tokenizer::byte_chars};
+
+/// Conversion of the tokens back to text, following the `decoder` of a Hugging Face
+/// `tokenizer.json`.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type")]
+pub enum Decoder {
+    /// Maps the characters of the [byte-level](super::PreTokenizer::ByteLevel) tokens back to
+    /// bytes.
+    ByteLevel,
+    /// Joins the tokens with spaces, except the tokens continuing a word.
+    WordPiece {
+        /// The prefix of the tokens that do not start a word.
+        #[serde(default = "default_prefix")]
+        prefix: String,
+        /// Whether to remove the spaces before the punctuation and in the English contractions.
+        #[serde(default = "default_true")]
+        cleanup: bool,
+    },
+    /// Replaces the [metaspace](super::PreTokenizer::Metaspace) character with spaces.
+    Metaspace {
+        /// The character replacing the spaces.
+        #[serde(default = "default_replacement")]
+        replacement: char,
+        /// When the replacement character was prepended, to remove the first space.
+        #[serde(default)]
+        prepend_scheme: PrependScheme,
+    },
+    /// Replaces the suffix of the tokens that end a word with a space.
+    #[serde(rename = "BPEDecoder")]
+    Bpe {
+        /// The suffix of the tokens that end a word.
+        #[serde(default = "default_suffix")]
+        suffix: String,
+    },
+    /// Replaces every occurrence of the pattern in the tokens.
+    Replace {
+        /// The pattern to replace.
+        pattern: Pattern,
+        /// The replacement.
+        content: String,
+    },
+    /// Decodes the `<0xXX>` byte tokens.
+    ByteFallback,
+    /// Joins the tokens.
+    Fuse,
+    /// Removes characters at the start and the end of the tokens.
+    Strip {
+        /// The removed character.
+        content: char,
+        /// The maximum number of characters removed at the start.
+        start: usize,
+        /// The maximum number of characters removed at the end.
+        stop: usize,
+    },
+    /// Applies the decoders in order.
+    Sequence {
+        /// The decoders.
+        decoders: Vec<Decoder>,
+    },
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_prefix() -> String {
+    "##".to_string()
+}
+
+fn default_replacement() -> char {
+    '▁'
+}
+
+fn default_suffix() -> String {
+    "</w>".to_string()
+}
+
+impl Decoder {
+    /// Converts the tokens to text.
+    pub fn decode(&self, tokens: Vec<String>) -> String {
+        self.decode_chain(tokens).concat()
+    }
+
+    fn decode_chain(&self, tokens: Vec<String>) -> Vec<String> {
+        match self {
+            Decoder::ByteLevel => {
+                let chars = byte_chars();
+                let bytes = tokens
+                    .concat()
+                    .chars()
+                    .flat_map(
+                        |c| match chars.iter().position(|byte_char| *byte_char == c) {
+                            Some(byte) => vec![byte as u8],
+                            None => c.to_string().into_bytes(),
+                        },
+                    )
+                    .collect::<Vec<_>>();
+
+                vec![String::from_utf8_lossy(&bytes).into_owned()]
+            }
+            Decoder::WordPiece { prefix, cleanup } => tokens
+                .into_iter()
+                .enumerate()
+                .map(|(i, token)| {
+                    let token = match token.strip_prefix(prefix.as_str()) {
+                        Some(token) => token.to_string(),
+                        None if i > 0 => format!(" {token}"),
+                        None => token,
+                    };
+                    match cleanup {
+                        true => cleanup_spaces(&token),
+                        false => token,
+                    }
+                })
+                .collect(),
+            Decoder::Metaspace {
+                replacement,
+                prepend_scheme,
+            } => tokens
+                .into_iter()
+                .enumerate()
+                .map(|(i, token)| {
+                    let token = token.replace(*replacement, " ");
+                    match i == 0 && *prepend_scheme != PrependScheme::Never {
+                        true => token.strip_prefix(' ').unwrap_or(&token).to_string(),
+                        false => token,
+                    }
+                })
+                .collect(),
+            Decoder::Bpe { suffix } => {
+                let last = tokens.len().saturating_sub(1);
+                tokens
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, token)| match i == last {
+                        true => token.replace(suffix.as_str(), ""),
+                        false => token.replace(suffix.as_str(), " "),
+                    })
+                    .collect()
+            }
+            Decoder::Replace { pattern, content } => tokens
+                .into_iter()
+                .map(|token| token.replace(pattern.as_str(), content))
+                .collect(),
+            Decoder::ByteFallback => {
+                let mut decoded = Vec::with_capacity(tokens.len());
+                let mut bytes = Vec::new();
+                for token in tokens {
+                    let byte = token
+                        .strip_prefix("<0x")
+                        .and_then(|token| token.strip_suffix('>'))
+                        .filter(|hex| hex.len() == 2)
+                        .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+                    match byte {
+                        Some(byte) => bytes.push(byte),
+                        None => {
+                            flush_bytes(&mut bytes, &mut decoded);
+                            decoded.push(token);
+                        }
+                    }
+                }
+                flush_bytes(&mut bytes, &mut decoded);
+
+                decoded
+            }
+            Decoder::Fuse => vec![tokens.concat()],
+            Decoder::Strip {
+                content,
+                start,
+                stop,
+            } => tokens
+                .into_iter()
+                .map(|token| {
+                    let chars = token.chars().collect::<Vec<_>>();
+                    let leading = chars.iter().take(*start).take_while(|c| *c == content);
+                    let first = leading.count();
+                    let trailing = chars[first..]
+                        .iter()
+                        .rev()
+                        .take(*stop)
+                        .take_while(|c| *c == content);
+                    let last = chars.len() - trailing.count();
+
+                    chars[first..last].iter().collect()
+                })
+                .collect(),
+            Decoder::Sequence { decoders } => decoders
+                .iter()
+                .fold(tokens, |tokens, decoder| decoder.decode_chain(tokens)),
+        }
+    }
+}
+
+/// Decodes the accumulated bytes, with a replacement character for every invalid byte.
+fn flush_bytes(bytes: &mut Vec<u8>, decoded: &mut Vec<String>) {
+    if bytes.is_empty() {
+        return;
+    }
+
+    match String::from_utf8(core::mem::take(bytes)) {
+        Ok(text) => decoded.push(text),
+        Err(err) => decoded.extend(err.as_bytes().iter().map(|_| "\u{fffd}".to_string())),
+    }
+}
+
+/// Removes the spaces before the punctuation and in the English contractions.
+fn cleanup_spaces(text: &str) -> String {
+    [
+        (" .", "."),
+        (" ?", "?"),
+        (" !", "!"),
+        (" ,", ","),
+        (" ' ", "'"),
+        (" n't", "n't"),
+        (" 'm", "'m"),
+        (" do not", " don't"),
+        (" 's", "'s"),
+        (" 've", "'ve"),
+        (" 're", "'re"),
+    ]
+    .iter()
+    .fold(text.to_string(), |text, (from, to)| text.replace(from, to))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tokens(tokens: &[&str]) -> Vec<String> {
+        tokens.iter().map(|token| token.to_string()).collect()
+    }
+
+    #[test]
+    fn wordpiece_should_join_subwords() {
+        let decoder = Decoder::WordPiece {
+            prefix: "##".to_string(),
+            cleanup: true,
+        };
+
+        assert_eq!(
+            decoder.decode(tokens(&["un", "##aff", "##able", "day", "!"])),
+            "unaffable day!"
+        );
+    }
+
+    #[test]
+    fn byte_level_should_map_back_to_bytes() {
+        assert_eq!(
+            Decoder::ByteLevel.decode(tokens(&["Hello", "Ġw", "Ã©", "Ċ"])),
+            "Hello wé\n"
+        );
+    }
+
+    #[test]
+    fn sequence_should_decode_sentencepiece_tokens() {
+        let decoder: Decoder = serde_json::from_str(
+            r#"{"type": "Sequence", "decoders": [
+                {"type": "Replace", "pattern": {"String": "▁"}, "content": " "},
+                {"type": "ByteFallback"},
+                {"type": "Fuse"},
+                {"type": "Strip", "content": " ", "start": 1, "stop": 0}
+            ]}"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            decoder.decode(tokens(&["▁Hello", "▁w", "<0xC3>", "<0xA9>"])),
+            "Hello wé"
+        );
+    }
+}
@@ -0,0 +1,116 @@
+use crate::transform::Mapper;
+
+use super::Tokenizer;
+
+/// Mapper converting the text of the items to token ids, to use with a
+/// [mapper dataset](crate::transform::MapperDataset).
+///
+/// The ids can be batched as padded tensors with the `TokenBatcher` of burn.
+#[derive(Debug, Clone)]
+pub struct TokenizerMapper<T> {
+    tokenizer: T,
+    special_tokens: bool,
+    max_length: Option<usize>,
+}
+
+impl<T: Tokenizer> TokenizerMapper<T> {
+    /// Creates a mapper adding the special tokens, without truncating the texts.
+    pub fn new(tokenizer: T) -> Self {
+        Self {
+            tokenizer,
+            special_tokens: true,
+            max_length: None,
+        }
+    }
+
+    /// Sets whether to add the special tokens of the tokenizer.
+    pub fn with_special_tokens(mut self, special_tokens: bool) -> Self {
+        self.special_tokens = special_tokens;
+        self
+    }
+
+    /// Sets the maximum number of ids, including the special tokens. The longer texts are
+    /// truncated before the special tokens are added.
+    pub fn with_max_length(mut self, max_length: Option<usize>) -> Self {
+        self.max_length = max_length;
+        self
+    }
+}
+
+impl<T, I> Mapper<I, Vec<usize>> for TokenizerMapper<T>
+where
+    T: Tokenizer,
+    I: AsRef<str>,
+{
+    fn map(&self, item: &I) -> Vec<usize> {
+        let mut ids = self.tokenizer.encode(item.as_ref(), false);
+        if !self.special_tokens {
+            if let Some(max_length) = self.max_length {
+                ids.truncate(max_length);
+            }
+            return ids;
+        }
+
+        if let Some(max_length) = self.max_length {
+            let special_tokens = self.tokenizer.add_special_tokens(Vec::new()).len();
+            ids.truncate(max_length.saturating_sub(special_tokens));
+        }
+        self.tokenizer.add_special_tokens(ids)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Dataset, InMemDataset, transform::MapperDataset};
+
+    /// Tokenizes the characters as their code points, between the `0` and `1` special tokens.
+    struct CharTokenizer;
+
+    impl Tokenizer for CharTokenizer {
+        fn encode(&self, text: &str, special_tokens: bool) -> Vec<usize> {
+            let ids = text.chars().map(|c| c as usize).collect();
+            match special_tokens {
+                true => self.add_special_tokens(ids),
+                false => ids,
+            }
+        }
+
+        fn add_special_tokens(&self, ids: Vec<usize>) -> Vec<usize> {
+            [0].into_iter().chain(ids).chain([1]).collect()
+        }
+
+        fn decode(&self, ids: &[usize]) -> String {
+            ids.iter()
+                .filter(|id| **id > 1)
+                .filter_map(|id| char::from_u32(*id as u32))
+                .collect()
+        }
+
+        fn vocab_size(&self) -> usize {
+            char::MAX as usize + 1
+        }
+
+        fn token_to_id(&self, token: &str) -> Option<usize> {
+            let mut chars = token.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => Some(c as usize),
+                _ => None,
+            }
+        }
+
+        fn id_to_token(&self, id: usize) -> Option<String> {
+            char::from_u32(id as u32).map(String::from)
+        }
+    }
+
+    #[test]
+    fn should_map_texts_to_truncated_ids() {
+        let dataset = InMemDataset::new(vec!["abc".to_string(), "abcdef".to_string()]);
+        let mapper = TokenizerMapper::new(CharTokenizer).with_max_length(Some(5));
+        let dataset = MapperDataset::new(dataset, mapper);
+
+        assert_eq!(dataset.get(0), Some(vec![0, 97, 98, 99, 1]));
+        assert_eq!(dataset.get(1), Some(vec![0, 97, 98, 99, 1]));
+    }
+}
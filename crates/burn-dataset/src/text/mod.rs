@@ -0,0 +1,23 @@
+mod base;
+mod bpe;
+mod decoder;
+mod mapper;
+mod model;
+mod normalizer;
+mod post_processor;
+mod pre_tokenizer;
+mod tokenizer;
+mod unigram;
+mod wordpiece;
+
+pub use base::*;
+pub use bpe::*;
+pub use decoder::*;
+pub use mapper::*;
+pub use model::*;
+pub use normalizer::*;
+pub use post_processor::*;
+pub use pre_tokenizer::*;
+pub use tokenizer::*;
+pub use unigram::*;
+pub use wordpiece::*;
@@ -0,0 +1,55 @@
+use serde::Deserialize;
+
+use super::{Bpe, Unigram, WordPiece};
+
+/// The model splitting the words into tokens, following the `model` of a Hugging Face
+/// `tokenizer.json`.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(tag = "type")]
+pub enum TokenizerModel {
+    /// Byte-pair encoding.
+    #[serde(rename = "BPE")]
+    Bpe(Bpe),
+    /// WordPiece.
+    WordPiece(WordPiece),
+    /// Unigram.
+    Unigram(Unigram),
+}
+
+impl TokenizerModel {
+    /// Tokenizes a word.
+    pub fn tokenize(&self, word: &str) -> Vec<usize> {
+        match self {
+            TokenizerModel::Bpe(model) => model.tokenize(word),
+            TokenizerModel::WordPiece(model) => model.tokenize(word),
+            TokenizerModel::Unigram(model) => model.tokenize(word),
+        }
+    }
+
+    /// The id of the given token, if it is in the vocabulary.
+    pub fn token_to_id(&self, token: &str) -> Option<usize> {
+        match self {
+            TokenizerModel::Bpe(model) => model.token_to_id(token),
+            TokenizerModel::WordPiece(model) => model.token_to_id(token),
+            TokenizerModel::Unigram(model) => model.token_to_id(token),
+        }
+    }
+
+    /// The token of the given id, if it is in the vocabulary.
+    pub fn id_to_token(&self, id: usize) -> Option<&str> {
+        match self {
+            TokenizerModel::Bpe(model) => model.id_to_token(id),
+            TokenizerModel::WordPiece(model) => model.id_to_token(id),
+            TokenizerModel::Unigram(model) => model.id_to_token(id),
+        }
+    }
+
+    /// The number of tokens in the vocabulary.
+    pub fn vocab_size(&self) -> usize {
+        match self {
+            TokenizerModel::Bpe(model) => model.vocab_size(),
+            TokenizerModel::WordPiece(model) => model.vocab_size(),
+            TokenizerModel::Unigram(model) => model.vocab_size(),
+        }
+    }
+}
@@ -0,0 +1,212 @@
+use serde::Deserialize;
+use unicode_normalization::{UnicodeNormalization, char::is_combining_mark};
+
+/// A pattern matched by the [normalizers](Normalizer) and [decoders](super::Decoder).
+///
+/// Only plain strings are supported, regular expressions are not.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub enum Pattern {
+    /// Matches the string.
+    String(String),
+}
+
+impl Pattern {
+    pub(crate) fn as_str(&self) -> &str {
+        match self {
+            Pattern::String(pattern) => pattern,
+        }
+    }
+}
+
+/// Normalization of the text before it is split into words, following the `normalizer` of a
+/// Hugging Face `tokenizer.json`.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type")]
+pub enum Normalizer {
+    /// Lowercases the text.
+    Lowercase,
+    /// Unicode canonical composition.
+    #[serde(rename = "NFC")]
+    Nfc,
+    /// Unicode canonical decomposition.
+    #[serde(rename = "NFD")]
+    Nfd,
+    /// Unicode compatibility composition.
+    #[serde(rename = "NFKC")]
+    Nfkc,
+    /// Unicode compatibility decomposition.
+    #[serde(rename = "NFKD")]
+    Nfkd,
+    /// Removes the whitespaces at the start and the end of the text.
+    Strip {
+        /// Whether to remove the whitespaces at the start.
+        #[serde(default = "default_true")]
+        strip_left: bool,
+        /// Whether to remove the whitespaces at the end.
+        #[serde(default = "default_true")]
+        strip_right: bool,
+    },
+    /// Removes the combining marks, usually after a [decomposition](Normalizer::Nfd).
+    StripAccents,
+    /// Replaces every occurrence of the pattern.
+    Replace {
+        /// The pattern to replace.
+        pattern: Pattern,
+        /// The replacement.
+        content: String,
+    },
+    /// Prepends a string to non-empty texts.
+    Prepend {
+        /// The prepended string.
+        prepend: String,
+    },
+    /// The normalization of BERT.
+    BertNormalizer {
+        /// Whether to remove the control characters and turn all the whitespaces into spaces.
+        #[serde(default = "default_true")]
+        clean_text: bool,
+        /// Whether to put spaces around the CJK characters, so that they are split into words.
+        #[serde(default = "default_true")]
+        handle_chinese_chars: bool,
+        /// Whether to remove the accents, which defaults to `lowercase`.
+        #[serde(default)]
+        strip_accents: Option<bool>,
+        /// Whether to lowercase the text.
+        #[serde(default = "default_true")]
+        lowercase: bool,
+    },
+    /// Applies the normalizers in order.
+    Sequence {
+        /// The normalizers.
+        normalizers: Vec<Normalizer>,
+    },
+}
+
+fn default_true() -> bool {
+    true
+}
+
+impl Normalizer {
+    /// Normalizes the text.
+    pub fn normalize(&self, text: &str) -> String {
+        match self {
+            Normalizer::Lowercase => text.to_lowercase(),
+            Normalizer::Nfc => text.nfc().collect(),
+            Normalizer::Nfd => text.nfd().collect(),
+            Normalizer::Nfkc => text.nfkc().collect(),
+            Normalizer::Nfkd => text.nfkd().collect(),
+            Normalizer::Strip {
+                strip_left,
+                strip_right,
+            } => {
+                let mut text = text;
+                if *strip_left {
+                    text = text.trim_start();
+                }
+                if *strip_right {
+                    text = text.trim_end();
+                }
+                text.to_string()
+            }
+            Normalizer::StripAccents => text.chars().filter(|c| !is_combining_mark(*c)).collect(),
+            Normalizer::Replace { pattern, content } => text.replace(pattern.as_str(), content),
+            Normalizer::Prepend { prepend } => match text.is_empty() {
+                true => String::new(),
+                false => format!("{prepend}{text}"),
+            },
+            Normalizer::BertNormalizer {
+                clean_text,
+                handle_chinese_chars,
+                strip_accents,
+                lowercase,
+            } => {
+                let mut normalized = String::with_capacity(text.len());
+                for c in text.chars() {
+                    if *clean_text {
+                        if c == '\0' || c == '\u{fffd}' || is_control(c) {
+                            continue;
+                        }
+                        if c.is_whitespace() {
+                            normalized.push(' ');
+                            continue;
+                        }
+                    }
+                    if *handle_chinese_chars && is_chinese_char(c) {
+                        normalized.push(' ');
+                        normalized.push(c);
+                        normalized.push(' ');
+                        continue;
+                    }
+                    normalized.push(c);
+                }
+
+                if strip_accents.unwrap_or(*lowercase) {
+                    normalized = normalized
+                        .nfd()
+                        .filter(|c| !is_combining_mark(*c))
+                        .collect();
+                }
+                if *lowercase {
+                    normalized = normalized.to_lowercase();
+                }
+
+                normalized
+            }
+            Normalizer::Sequence { normalizers } => normalizers
+                .iter()
+                .fold(text.to_string(), |text, normalizer| {
+                    normalizer.normalize(&text)
+                }),
+        }
+    }
+}
+
+/// Whether the character is a control character, not counting the whitespaces.
+fn is_control(c: char) -> bool {
+    !matches!(c, '\t' | '\n' | '\r') && c.is_control()
+}
+
+/// Whether the character is in the CJK Unicode blocks.
+fn is_chinese_char(c: char) -> bool {
+    matches!(
+        c as u32,
+        0x4E00..=0x9FFF
+            | 0x3400..=0x4DBF
+            | 0x20000..=0x2A6DF
+            | 0x2A700..=0x2B73F
+            | 0x2B740..=0x2B81F
+            | 0x2B820..=0x2CEAF
+            | 0xF900..=0xFAFF
+            | 0x2F800..=0x2FA1F
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bert_normalizer_should_clean_lowercase_and_strip_accents() {
+        let normalizer: Normalizer = serde_json::from_str(
+            r#"{"type": "BertNormalizer", "clean_text": true, "handle_chinese_chars": true, "strip_accents": null, "lowercase": true}"#,
+        )
+        .unwrap();
+
+        assert_eq!(normalizer.normalize("Héllo\tWörld\u{0}"), "hello world");
+        assert_eq!(normalizer.normalize("中文"), " 中  文 ");
+    }
+
+    #[test]
+    fn sequence_should_apply_normalizers_in_order() {
+        let normalizer: Normalizer = serde_json::from_str(
+            r#"{"type": "Sequence", "normalizers": [
+                {"type": "Prepend", "prepend": "▁"},
+                {"type": "Replace", "pattern": {"String": " "}, "content": "▁"}
+            ]}"#,
+        )
+        .unwrap();
+
+        assert_eq!(normalizer.normalize("Hello world"), "▁Hello▁world");
+        assert_eq!(normalizer.normalize(""), "");
+    }
+}
@@ -0,0 +1,118 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+/// A piece of a [template](PostProcessor::TemplateProcessing).
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub enum TemplatePiece {
+    /// A special token.
+    SpecialToken {
+        /// The name of the special token.
+        id: String,
+    },
+    /// The encoded sequence.
+    Sequence {
+        /// The name of the sequence, `A` for single sequences.
+        id: String,
+    },
+}
+
+/// The ids of a special token of a [template](PostProcessor::TemplateProcessing).
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct SpecialTokenIds {
+    /// The ids of the special token.
+    pub ids: Vec<usize>,
+}
+
+/// Addition of the special tokens around the encoded text, following the `post_processor` of a
+/// Hugging Face `tokenizer.json`.
+///
+/// Only single sequences are supported, not pairs of sequences.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type")]
+pub enum PostProcessor {
+    /// Adds the special tokens of a template.
+    TemplateProcessing {
+        /// The template of single sequences.
+        single: Vec<TemplatePiece>,
+        /// The ids of the special tokens of the template.
+        special_tokens: HashMap<String, SpecialTokenIds>,
+    },
+    /// Adds the classification token before the text, and the separator token after it.
+    BertProcessing {
+        /// The separator token and its id.
+        sep: (String, usize),
+        /// The classification token and its id.
+        cls: (String, usize),
+    },
+    /// Adds the classification token before the text, and the separator token after it.
+    RobertaProcessing {
+        /// The separator token and its id.
+        sep: (String, usize),
+        /// The classification token and its id.
+        cls: (String, usize),
+    },
+    /// Adds no tokens.
+    ByteLevel,
+    /// Applies the post-processors in order.
+    Sequence {
+        /// The post-processors.
+        processors: Vec<PostProcessor>,
+    },
+}
+
+impl PostProcessor {
+    /// Adds the special tokens to the ids of the encoded text.
+    pub fn process(&self, ids: Vec<usize>) -> Vec<usize> {
+        match self {
+            PostProcessor::TemplateProcessing {
+                single,
+                special_tokens,
+            } => single
+                .iter()
+                .flat_map(|piece| match piece {
+                    TemplatePiece::SpecialToken { id } => special_tokens
+                        .get(id)
+                        .map(|token| token.ids.clone())
+                        .unwrap_or_default(),
+                    TemplatePiece::Sequence { .. } => ids.clone(),
+                })
+                .collect(),
+            PostProcessor::BertProcessing { sep, cls }
+            | PostProcessor::RobertaProcessing { sep, cls } => {
+                [cls.1].into_iter().chain(ids).chain([sep.1]).collect()
+            }
+            PostProcessor::ByteLevel => ids,
+            PostProcessor::Sequence { processors } => processors
+                .iter()
+                .fold(ids, |ids, processor| processor.process(ids)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn template_should_add_special_tokens() {
+        let processor: PostProcessor = serde_json::from_str(
+            r#"{
+                "type": "TemplateProcessing",
+                "single": [
+                    {"SpecialToken": {"id": "[CLS]", "type_id": 0}},
+                    {"Sequence": {"id": "A", "type_id": 0}},
+                    {"SpecialToken": {"id": "[SEP]", "type_id": 0}}
+                ],
+                "pair": [],
+                "special_tokens": {
+                    "[CLS]": {"id": "[CLS]", "ids": [101], "tokens": ["[CLS]"]},
+                    "[SEP]": {"id": "[SEP]", "ids": [102], "tokens": ["[SEP]"]}
+                }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(processor.process(vec![7, 8]), vec![101, 7, 8, 102]);
+    }
+}
@@ -0,0 +1,403 @@
+use serde::Deserialize;
+
+/// How a [pre-tokenizer](PreTokenizer) keeps the delimiters it splits on.
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SplitBehavior {
+    /// The delimiters are removed.
+    Removed,
+    /// Every delimiter is a word of its own.
+    #[default]
+    Isolated,
+    /// The delimiters end the previous word.
+    MergedWithPrevious,
+    /// The delimiters start the next word.
+    MergedWithNext,
+    /// The consecutive delimiters are a word of their own.
+    Contiguous,
+}
+
+/// When the [metaspace pre-tokenizer](PreTokenizer::Metaspace) prepends the replacement character.
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum PrependScheme {
+    /// Before every text.
+    #[default]
+    Always,
+    /// Only before the start of the input, not after the added tokens.
+    First,
+    /// Never.
+    Never,
+}
+
+/// Splitting of the normalized text into words, which are tokenized separately, following the
+/// `pre_tokenizer` of a Hugging Face `tokenizer.json`.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type")]
+pub enum PreTokenizer {
+    /// Splits on the whitespaces, and between the word characters and the other characters.
+    Whitespace,
+    /// Splits on the whitespaces.
+    WhitespaceSplit,
+    /// Splits on the whitespaces, with every punctuation character as a word of its own.
+    BertPreTokenizer,
+    /// Splits on the punctuation characters.
+    Punctuation {
+        /// How the punctuation characters are kept.
+        #[serde(default)]
+        behavior: SplitBehavior,
+    },
+    /// Splits on a character.
+    CharDelimiterSplit {
+        /// The delimiter, which is removed.
+        delimiter: char,
+    },
+    /// Splits the digits from the other characters.
+    Digits {
+        /// Whether every digit is a word of its own, instead of the numbers.
+        #[serde(default)]
+        individual_digits: bool,
+    },
+    /// Splits like GPT-2 and maps every byte of the words to a printable character, so that the
+    /// vocabulary does not need unknown tokens.
+    ByteLevel {
+        /// Whether to add a space before the text, so that the first word is like the others.
+        #[serde(default = "default_true")]
+        add_prefix_space: bool,
+        /// Whether to split the words like GPT-2, instead of only mapping the bytes.
+        #[serde(default = "default_true")]
+        use_regex: bool,
+    },
+    /// Replaces the spaces with a character, and splits before it, like SentencePiece.
+    Metaspace {
+        /// The character replacing the spaces.
+        #[serde(default = "default_replacement")]
+        replacement: char,
+        /// When to prepend the replacement character.
+        #[serde(default)]
+        prepend_scheme: PrependScheme,
+        /// Whether to split before the replacement character.
+        #[serde(default = "default_true")]
+        split: bool,
+    },
+    /// Applies the pre-tokenizers in order.
+    Sequence {
+        /// The pre-tokenizers.
+        pretokenizers: Vec<PreTokenizer>,
+    },
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_replacement() -> char {
+    '▁'
+}
+
+impl PreTokenizer {
+    /// Splits the text into words.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The normalized text.
+    /// * `first` - Whether the text is at the start of the input, instead of following an added
+    ///   token.
+    pub fn pre_tokenize(&self, text: &str, first: bool) -> Vec<String> {
+        self.split(vec![text.to_string()], first)
+    }
+
+    fn split(&self, words: Vec<String>, first: bool) -> Vec<String> {
+        match self {
+            PreTokenizer::Sequence { pretokenizers } => {
+                pretokenizers.iter().fold(words, |words, pre_tokenizer| {
+                    pre_tokenizer.split(words, first)
+                })
+            }
+            _ => words
+                .iter()
+                .enumerate()
+                .flat_map(|(i, word)| self.split_word(word, first && i == 0))
+                .filter(|word| !word.is_empty())
+                .collect(),
+        }
+    }
+
+    fn split_word(&self, word: &str, first: bool) -> Vec<String> {
+        match self {
+            PreTokenizer::Whitespace => {
+                let is_word = |c: char| c.is_alphanumeric() || c == '_';
+                word.split_whitespace()
+                    .flat_map(|word| split_runs(word, is_word))
+                    .collect()
+            }
+            PreTokenizer::WhitespaceSplit => word.split_whitespace().map(str::to_string).collect(),
+            PreTokenizer::BertPreTokenizer => word
+                .split_whitespace()
+                .flat_map(|word| split_on(word, is_punctuation, SplitBehavior::Isolated))
+                .collect(),
+            PreTokenizer::Punctuation { behavior } => split_on(word, is_punctuation, *behavior),
+            PreTokenizer::CharDelimiterSplit { delimiter } => {
+                split_on(word, |c| c == *delimiter, SplitBehavior::Removed)
+            }
+            PreTokenizer::Digits { individual_digits } => match individual_digits {
+                true => split_on(word, |c| c.is_ascii_digit(), SplitBehavior::Isolated),
+                false => split_on(word, |c| c.is_ascii_digit(), SplitBehavior::Contiguous),
+            },
+            PreTokenizer::ByteLevel {
+                add_prefix_space,
+                use_regex,
+            } => {
+                let word = match *add_prefix_space && !word.starts_with(' ') {
+                    true => format!(" {word}"),
+                    false => word.to_string(),
+                };
+                let words = match use_regex {
+                    true => split_gpt2(&word),
+                    false => vec![word],
+                };
+                let chars = byte_chars();
+
+                words
+                    .iter()
+                    .map(|word| word.bytes().map(|byte| chars[byte as usize]).collect())
+                    .collect()
+            }
+            PreTokenizer::Metaspace {
+                replacement,
+                prepend_scheme,
+                split,
+            } => {
+                let mut word = word.replace(' ', &replacement.to_string());
+                let prepend = match prepend_scheme {
+                    PrependScheme::Always => true,
+                    PrependScheme::First => first,
+                    PrependScheme::Never => false,
+                };
+                if prepend && !word.starts_with(*replacement) {
+                    word.insert(0, *replacement);
+                }
+
+                match split {
+                    true => split_on(&word, |c| c == *replacement, SplitBehavior::MergedWithNext),
+                    false => vec![word],
+                }
+            }
+            PreTokenizer::Sequence { .. } => self.split(vec![word.to_string()], first),
+        }
+    }
+}
+
+/// Whether the character is a punctuation character, counting the ASCII symbols like BERT.
+fn is_punctuation(c: char) -> bool {
+    c.is_ascii_punctuation() || (!c.is_ascii() && !c.is_alphanumeric() && !c.is_whitespace())
+}
+
+/// Splits the word on the characters matching the delimiter predicate.
+fn split_on(
+    word: &str,
+    is_delimiter: impl Fn(char) -> bool,
+    behavior: SplitBehavior,
+) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut current = String::new();
+    let mut previous_delimiter = false;
+
+    for c in word.chars() {
+        if !is_delimiter(c) {
+            if previous_delimiter && behavior == SplitBehavior::Contiguous {
+                words.push(core::mem::take(&mut current));
+            }
+            current.push(c);
+            previous_delimiter = false;
+            continue;
+        }
+
+        match behavior {
+            SplitBehavior::Removed => words.push(core::mem::take(&mut current)),
+            SplitBehavior::Isolated => {
+                words.push(core::mem::take(&mut current));
+                words.push(c.to_string());
+            }
+            SplitBehavior::MergedWithPrevious => {
+                current.push(c);
+                words.push(core::mem::take(&mut current));
+            }
+            SplitBehavior::MergedWithNext => {
+                words.push(core::mem::take(&mut current));
+                current.push(c);
+            }
+            SplitBehavior::Contiguous => {
+                if !previous_delimiter {
+                    words.push(core::mem::take(&mut current));
+                }
+                current.push(c);
+            }
+        }
+        previous_delimiter = true;
+    }
+    words.push(current);
+
+    words.retain(|word| !word.is_empty());
+    words
+}
+
+/// Splits the word into the runs of characters matching the predicate or not.
+fn split_runs(word: &str, predicate: impl Fn(char) -> bool) -> Vec<String> {
+    let mut words: Vec<String> = Vec::new();
+    let mut previous = None;
+
+    for c in word.chars() {
+        let matches = predicate(c);
+        match (words.last_mut(), previous == Some(matches)) {
+            (Some(last), true) => last.push(c),
+            _ => words.push(c.to_string()),
+        }
+        previous = Some(matches);
+    }
+
+    words
+}
+
+/// Splits the text like the GPT-2 regular expression
+/// `'s|'t|'re|'ve|'m|'ll|'d| ?\p{L}+| ?\p{N}+| ?[^\s\p{L}\p{N}]+|\s+(?!\S)|\s+`.
+fn split_gpt2(text: &str) -> Vec<String> {
+    #[derive(PartialEq, Clone, Copy)]
+    enum Class {
+        Letter,
+        Number,
+        Other,
+        Space,
+    }
+    let class = |c: char| match c {
+        c if c.is_whitespace() => Class::Space,
+        c if c.is_alphabetic() => Class::Letter,
+        c if c.is_numeric() => Class::Number,
+        _ => Class::Other,
+    };
+
+    let chars = text.chars().collect::<Vec<_>>();
+    let run_end = |start: usize, class_of_run: Class| {
+        let mut end = start;
+        while end < chars.len() && class(chars[end]) == class_of_run {
+            end += 1;
+        }
+        end
+    };
+
+    let mut words = Vec::new();
+    let mut start = 0;
+    while start < chars.len() {
+        let c = chars[start];
+
+        if c == '\'' {
+            let rest = chars[start + 1..].iter().take(2).collect::<String>();
+            let contraction = ["s", "t", "re", "ve", "m", "ll", "d"]
+                .into_iter()
+                .find(|contraction| rest.starts_with(contraction));
+            if let Some(contraction) = contraction {
+                let end = start + 1 + contraction.len();
+                words.push(chars[start..end].iter().collect());
+                start = end;
+                continue;
+            }
+        }
+
+        let end = match class(c) {
+            // A space is merged with the following word
+            Class::Space if c == ' ' && start + 1 < chars.len() => match class(chars[start + 1]) {
+                Class::Space => None,
+                next => Some(run_end(start + 1, next)),
+            },
+            Class::Space => None,
+            class => Some(run_end(start, class)),
+        };
+        let end = end.unwrap_or_else(|| {
+            // The whitespaces before a word leave the last one to the word
+            let end = run_end(start, Class::Space);
+            match end < chars.len() && end - start > 1 {
+                true => end - 1,
+                false => end,
+            }
+        });
+
+        words.push(chars[start..end].iter().collect());
+        start = end;
+    }
+
+    words
+}
+
+/// The printable characters of the bytes, mapping the bytes of the printable ASCII and Latin-1
+/// characters to themselves and the other bytes to the following code points, like GPT-2.
+pub(crate) fn byte_chars() -> [char; 256] {
+    let mut chars = ['\0'; 256];
+    let mut next = 256;
+
+    for (byte, c) in chars.iter_mut().enumerate() {
+        let printable = matches!(byte, 0x21..=0x7E | 0xA1..=0xAC | 0xAE..=0xFF);
+        *c = match printable {
+            true => char::from_u32(byte as u32).unwrap(),
+            false => {
+                next += 1;
+                char::from_u32(next - 1).unwrap()
+            }
+        };
+    }
+
+    chars
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn byte_level_should_split_like_gpt2() {
+        let pre_tokenizer = PreTokenizer::ByteLevel {
+            add_prefix_space: false,
+            use_regex: true,
+        };
+
+        assert_eq!(
+            pre_tokenizer.pre_tokenize("Hello world, it's 2024!  ok\n", true),
+            vec![
+                "Hello", "Ġworld", ",", "Ġit", "'s", "Ġ2024", "!", "Ġ", "Ġok", "Ċ"
+            ]
+        );
+    }
+
+    #[test]
+    fn bert_pre_tokenizer_should_isolate_punctuation() {
+        let pre_tokenizer = PreTokenizer::BertPreTokenizer;
+
+        assert_eq!(
+            pre_tokenizer.pre_tokenize("hello, world!!", true),
+            vec!["hello", ",", "world", "!", "!"]
+        );
+    }
+
+    #[test]
+    fn metaspace_should_prepend_and_split() {
+        let pre_tokenizer = PreTokenizer::Metaspace {
+            replacement: '▁',
+            prepend_scheme: PrependScheme::First,
+            split: true,
+        };
+
+        assert_eq!(
+            pre_tokenizer.pre_tokenize("Hello  world", true),
+            vec!["▁Hello", "▁", "▁world"]
+        );
+        assert_eq!(pre_tokenizer.pre_tokenize("world", false), vec!["world"]);
+    }
+
+    #[test]
+    fn whitespace_should_split_words_and_symbols() {
+        let pre_tokenizer = PreTokenizer::Whitespace;
+
+        assert_eq!(
+            pre_tokenizer.pre_tokenize("Hey friend!?  snake_case", true),
+            vec!["Hey", "friend", "!?", "snake_case"]
+        );
+    }
+}
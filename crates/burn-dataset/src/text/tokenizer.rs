@@ -0,0 +1,386 @@
+use std::path::Path;
+
+use serde::Deserialize;
+
+use super::{
+    Decoder, Normalizer, PostProcessor, PreTokenizer, Tokenizer, TokenizerError, TokenizerModel,
+};
+
+/// A token added to the vocabulary of the model, which is matched in the text before it is
+/// normalized and split into words.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct AddedToken {
+    /// The id of the token.
+    pub id: usize,
+    /// The text of the token.
+    pub content: String,
+    /// Whether the token is a special token, which is skipped when decoding.
+    #[serde(default)]
+    pub special: bool,
+    /// Whether to remove the whitespaces before the token.
+    #[serde(default)]
+    pub lstrip: bool,
+    /// Whether to remove the whitespaces after the token.
+    #[serde(default)]
+    pub rstrip: bool,
+}
+
+/// The padding of a `tokenizer.json`.
+#[derive(Deserialize)]
+struct PaddingConfig {
+    pad_id: usize,
+}
+
+/// The pipeline of a `tokenizer.json`.
+#[derive(Deserialize)]
+struct TokenizerConfig {
+    model: TokenizerModel,
+    #[serde(default)]
+    normalizer: Option<Normalizer>,
+    #[serde(default)]
+    pre_tokenizer: Option<PreTokenizer>,
+    #[serde(default)]
+    post_processor: Option<PostProcessor>,
+    #[serde(default)]
+    decoder: Option<Decoder>,
+    #[serde(default)]
+    added_tokens: Vec<AddedToken>,
+    #[serde(default)]
+    padding: Option<PaddingConfig>,
+}
+
+/// A part of the text, split on the added tokens.
+enum Segment<'a> {
+    Text(&'a str),
+    Token(&'a AddedToken),
+}
+
+/// A tokenizer normalizing the text, splitting it into words, tokenizing the words with a
+/// [BPE](super::Bpe), [WordPiece](super::WordPiece) or [Unigram](super::Unigram) model and
+/// adding the special tokens, like the tokenizers of Hugging Face.
+///
+/// # Example
+///
+/// ```rust, no_run
+/// use burn_dataset::text::{TextTokenizer, Tokenizer};
+///
+/// let tokenizer = TextTokenizer::from_file("tokenizer.json").unwrap();
+/// let ids = tokenizer.encode("Hello world!", true);
+/// println!("{}", tokenizer.decode(&ids));
+/// ```
+#[derive(Debug, Clone)]
+pub struct TextTokenizer {
+    model: TokenizerModel,
+    normalizer: Option<Normalizer>,
+    pre_tokenizer: Option<PreTokenizer>,
+    post_processor: Option<PostProcessor>,
+    decoder: Option<Decoder>,
+    added_tokens: Vec<AddedToken>,
+    pad_id: Option<usize>,
+}
+
+impl TextTokenizer {
+    /// Creates a tokenizer only splitting the text into tokens with the model.
+    pub fn new(model: TokenizerModel) -> Self {
+        Self {
+            model,
+            normalizer: None,
+            pre_tokenizer: None,
+            post_processor: None,
+            decoder: None,
+            added_tokens: Vec::new(),
+            pad_id: None,
+        }
+    }
+
+    /// Loads a tokenizer from a Hugging Face `tokenizer.json` file.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, TokenizerError> {
+        let json = std::fs::read_to_string(path.as_ref()).map_err(|err| {
+            TokenizerError::IOError(format!("{}: {err}", path.as_ref().display()))
+        })?;
+
+        Self::from_json(&json)
+    }
+
+    /// Loads a tokenizer from the content of a Hugging Face `tokenizer.json` file.
+    ///
+    /// The tokenizers using regular expression patterns, or components like the `Precompiled`
+    /// normalizer and the `Split` pre-tokenizer, are not supported and fail to parse.
+    pub fn from_json(json: &str) -> Result<Self, TokenizerError> {
+        let config: TokenizerConfig = serde_json::from_str(json)
+            .map_err(|err| TokenizerError::ParsingError(err.to_string()))?;
+
+        Ok(Self::new(config.model)
+            .with_normalizer(config.normalizer)
+            .with_pre_tokenizer(config.pre_tokenizer)
+            .with_post_processor(config.post_processor)
+            .with_decoder(config.decoder)
+            .with_added_tokens(config.added_tokens)
+            .with_pad_id(config.padding.map(|padding| padding.pad_id)))
+    }
+
+    /// Sets the normalization of the text.
+    pub fn with_normalizer(mut self, normalizer: Option<Normalizer>) -> Self {
+        self.normalizer = normalizer;
+        self
+    }
+
+    /// Sets the splitting of the text into words.
+    pub fn with_pre_tokenizer(mut self, pre_tokenizer: Option<PreTokenizer>) -> Self {
+        self.pre_tokenizer = pre_tokenizer;
+        self
+    }
+
+    /// Sets the addition of the special tokens.
+    pub fn with_post_processor(mut self, post_processor: Option<PostProcessor>) -> Self {
+        self.post_processor = post_processor;
+        self
+    }
+
+    /// Sets the conversion of the tokens back to text, which joins the tokens with spaces
+    /// by default.
+    pub fn with_decoder(mut self, decoder: Option<Decoder>) -> Self {
+        self.decoder = decoder;
+        self
+    }
+
+    /// Sets the tokens matched in the text before it is normalized.
+    pub fn with_added_tokens(mut self, mut added_tokens: Vec<AddedToken>) -> Self {
+        added_tokens.retain(|token| !token.content.is_empty());
+        // The longest tokens are matched first
+        added_tokens.sort_by_key(|token| core::cmp::Reverse(token.content.len()));
+        self.added_tokens = added_tokens;
+        self
+    }
+
+    /// Sets the id of the padding token.
+    pub fn with_pad_id(mut self, pad_id: Option<usize>) -> Self {
+        self.pad_id = pad_id;
+        self
+    }
+
+    /// The id of the padding token, if the tokenizer pads the sequences.
+    pub fn pad_id(&self) -> Option<usize> {
+        self.pad_id
+    }
+
+    /// Splits the text on the added tokens.
+    fn split_added_tokens<'a>(&'a self, text: &'a str) -> Vec<Segment<'a>> {
+        let mut segments = Vec::new();
+        let mut start = 0;
+        let mut offset = 0;
+
+        while offset < text.len() {
+            let token = self
+                .added_tokens
+                .iter()
+                .find(|token| text[offset..].starts_with(token.content.as_str()));
+            let Some(token) = token else {
+                offset += text[offset..].chars().next().map_or(1, char::len_utf8);
+                continue;
+            };
+
+            let mut previous = &text[start..offset];
+            if token.lstrip {
+                previous = previous.trim_end();
+            }
+            segments.push(Segment::Text(previous));
+            segments.push(Segment::Token(token));
+
+            offset += token.content.len();
+            if token.rstrip {
+                offset = text.len() - text[offset..].trim_start().len();
+            }
+            start = offset;
+        }
+        segments.push(Segment::Text(&text[start..]));
+
+        segments
+    }
+}
+
+impl Tokenizer for TextTokenizer {
+    fn encode(&self, text: &str, special_tokens: bool) -> Vec<usize> {
+        let mut ids = Vec::new();
+
+        for (i, segment) in self.split_added_tokens(text).into_iter().enumerate() {
+            let text = match segment {
+                Segment::Token(token) => {
+                    ids.push(token.id);
+                    continue;
+                }
+                Segment::Text(text) if text.is_empty() => continue,
+                Segment::Text(text) => text,
+            };
+
+            let text = match &self.normalizer {
+                Some(normalizer) => normalizer.normalize(text),
+                None => text.to_string(),
+            };
+            let words = match &self.pre_tokenizer {
+                Some(pre_tokenizer) => pre_tokenizer.pre_tokenize(&text, i == 0),
+                None => vec![text],
+            };
+            for word in words {
+                ids.extend(self.model.tokenize(&word));
+            }
+        }
+
+        match special_tokens {
+            true => self.add_special_tokens(ids),
+            false => ids,
+        }
+    }
+
+    fn add_special_tokens(&self, ids: Vec<usize>) -> Vec<usize> {
+        match &self.post_processor {
+            Some(post_processor) => post_processor.process(ids),
+            None => ids,
+        }
+    }
+
+    fn decode(&self, ids: &[usize]) -> String {
+        let tokens = ids
+            .iter()
+            .filter(|id| {
+                !self
+                    .added_tokens
+                    .iter()
+                    .any(|token| token.special && token.id == **id)
+            })
+            .filter_map(|id| self.id_to_token(*id))
+            .collect::<Vec<_>>();
+
+        match &self.decoder {
+            Some(decoder) => decoder.decode(tokens),
+            None => tokens.join(" "),
+        }
+    }
+
+    fn vocab_size(&self) -> usize {
+        let added = self
+            .added_tokens
+            .iter()
+            .filter(|token| self.model.id_to_token(token.id).is_none())
+            .count();
+
+        self.model.vocab_size() + added
+    }
+
+    fn token_to_id(&self, token: &str) -> Option<usize> {
+        self.added_tokens
+            .iter()
+            .find(|added| added.content == token)
+            .map(|added| added.id)
+            .or_else(|| self.model.token_to_id(token))
+    }
+
+    fn id_to_token(&self, id: usize) -> Option<String> {
+        self.added_tokens
+            .iter()
+            .find(|added| added.id == id)
+            .map(|added| added.content.clone())
+            .or_else(|| self.model.id_to_token(id).map(str::to_string))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BERT_TOKENIZER: &str = r###"{
+        "version": "1.0",
+        "truncation": null,
+        "padding": {"strategy": "BatchLongest", "pad_id": 0, "pad_token": "[PAD]"},
+        "added_tokens": [
+            {"id": 0, "content": "[PAD]", "single_word": false, "lstrip": false, "rstrip": false, "normalized": false, "special": true},
+            {"id": 1, "content": "[UNK]", "single_word": false, "lstrip": false, "rstrip": false, "normalized": false, "special": true},
+            {"id": 2, "content": "[CLS]", "single_word": false, "lstrip": false, "rstrip": false, "normalized": false, "special": true},
+            {"id": 3, "content": "[SEP]", "single_word": false, "lstrip": false, "rstrip": false, "normalized": false, "special": true},
+            {"id": 4, "content": "[MASK]", "single_word": false, "lstrip": false, "rstrip": false, "normalized": false, "special": true}
+        ],
+        "normalizer": {"type": "BertNormalizer", "clean_text": true, "handle_chinese_chars": true, "strip_accents": null, "lowercase": true},
+        "pre_tokenizer": {"type": "BertPreTokenizer"},
+        "post_processor": {
+            "type": "TemplateProcessing",
+            "single": [
+                {"SpecialToken": {"id": "[CLS]", "type_id": 0}},
+                {"Sequence": {"id": "A", "type_id": 0}},
+                {"SpecialToken": {"id": "[SEP]", "type_id": 0}}
+            ],
+            "pair": [],
+            "special_tokens": {
+                "[CLS]": {"id": "[CLS]", "ids": [2], "tokens": ["[CLS]"]},
+                "[SEP]": {"id": "[SEP]", "ids": [3], "tokens": ["[SEP]"]}
+            }
+        },
+        "decoder": {"type": "WordPiece", "prefix": "##", "cleanup": true},
+        "model": {
+            "type": "WordPiece",
+            "unk_token": "[UNK]",
+            "continuing_subword_prefix": "##",
+            "max_input_chars_per_word": 100,
+            "vocab": {
+                "[PAD]": 0, "[UNK]": 1, "[CLS]": 2, "[SEP]": 3, "[MASK]": 4,
+                "hello": 5, "world": 6, "!": 7, "un": 8, "##aff": 9, "##able": 10
+            }
+        }
+    }"###;
+
+    const GPT2_TOKENIZER: &str = r#"{
+        "added_tokens": [
+            {"id": 8, "content": "<|endoftext|>", "single_word": false, "lstrip": false, "rstrip": false, "normalized": true, "special": true}
+        ],
+        "normalizer": null,
+        "pre_tokenizer": {"type": "ByteLevel", "add_prefix_space": false, "trim_offsets": true, "use_regex": true},
+        "post_processor": {"type": "ByteLevel", "add_prefix_space": true, "trim_offsets": false, "use_regex": true},
+        "decoder": {"type": "ByteLevel", "add_prefix_space": true, "trim_offsets": true, "use_regex": true},
+        "model": {
+            "type": "BPE",
+            "dropout": null,
+            "unk_token": null,
+            "continuing_subword_prefix": "",
+            "end_of_word_suffix": "",
+            "fuse_unk": false,
+            "byte_fallback": false,
+            "vocab": {"h": 0, "i": 1, "Ġ": 2, "t": 3, "e": 4, "hi": 5, "Ġt": 6, "Ġte": 7},
+            "merges": ["h i", ["Ġ", "t"], "Ġt e"]
+        }
+    }"#;
+
+    #[test]
+    fn should_encode_and_decode_with_wordpiece() {
+        let tokenizer = TextTokenizer::from_json(BERT_TOKENIZER).unwrap();
+
+        let ids = tokenizer.encode("Hello, unaffable World!", true);
+        assert_eq!(ids, vec![2, 5, 1, 8, 9, 10, 6, 7, 3]);
+        assert_eq!(tokenizer.decode(&ids), "hello unaffable world!");
+        assert_eq!(tokenizer.encode("[CLS]hello", false), vec![2, 5]);
+        assert_eq!(tokenizer.pad_id(), Some(0));
+        assert_eq!(tokenizer.vocab_size(), 11);
+    }
+
+    #[test]
+    fn should_encode_and_decode_with_byte_level_bpe() {
+        let tokenizer = TextTokenizer::from_json(GPT2_TOKENIZER).unwrap();
+
+        let ids = tokenizer.encode("hi te<|endoftext|>", true);
+        assert_eq!(ids, vec![5, 7, 8]);
+        assert_eq!(tokenizer.decode(&ids), "hi te");
+        assert_eq!(tokenizer.token_to_id("<|endoftext|>"), Some(8));
+        assert_eq!(tokenizer.vocab_size(), 9);
+    }
+
+    #[test]
+    fn should_fail_to_parse_unsupported_components() {
+        let json = BERT_TOKENIZER.replace(
+            r#"{"type": "BertPreTokenizer"}"#,
+            r#"{"type": "Split", "pattern": {"Regex": "\\s+"}, "behavior": "Removed", "invert": false}"#,
+        );
+
+        assert!(matches!(
+            TextTokenizer::from_json(&json),
+            Err(TokenizerError::ParsingError(_))
+        ));
+    }
+}
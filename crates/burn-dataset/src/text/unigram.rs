@@ -0,0 +1,187 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+/// The score penalty of the unknown tokens, below the lowest score of the vocabulary.
+const UNK_PENALTY: f64 = 10.0;
+
+/// Unigram model, which splits words into the tokens with the most likely sequence of scores,
+/// like SentencePiece.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(from = "UnigramConfig")]
+pub struct Unigram {
+    pieces: Vec<(String, f64)>,
+    vocab: HashMap<String, usize>,
+    unk_id: Option<usize>,
+    byte_fallback: bool,
+    min_score: f64,
+    max_piece_chars: usize,
+}
+
+#[derive(Deserialize)]
+struct UnigramConfig {
+    vocab: Vec<(String, f64)>,
+    #[serde(default)]
+    unk_id: Option<usize>,
+    #[serde(default)]
+    byte_fallback: bool,
+}
+
+impl From<UnigramConfig> for Unigram {
+    fn from(config: UnigramConfig) -> Self {
+        Self::new(config.vocab, config.unk_id).with_byte_fallback(config.byte_fallback)
+    }
+}
+
+/// The best tokenization of the start of a word, ending with a token.
+#[derive(Clone, Copy)]
+struct Node {
+    score: f64,
+    start: usize,
+    id: Option<usize>,
+}
+
+impl Unigram {
+    /// Creates a Unigram model from the tokens with their log probabilities, and the id of the
+    /// unknown token.
+    pub fn new(pieces: Vec<(String, f64)>, unk_id: Option<usize>) -> Self {
+        let vocab = pieces
+            .iter()
+            .enumerate()
+            .map(|(id, (piece, _))| (piece.clone(), id))
+            .collect();
+        let min_score = pieces
+            .iter()
+            .map(|(_, score)| *score)
+            .fold(f64::INFINITY, f64::min);
+        let max_piece_chars = pieces
+            .iter()
+            .map(|(piece, _)| piece.chars().count())
+            .max()
+            .unwrap_or(1);
+
+        Self {
+            pieces,
+            vocab,
+            unk_id,
+            byte_fallback: false,
+            min_score,
+            max_piece_chars,
+        }
+    }
+
+    /// Sets whether the unknown characters are encoded with their `<0xXX>` byte tokens.
+    pub fn with_byte_fallback(mut self, byte_fallback: bool) -> Self {
+        self.byte_fallback = byte_fallback;
+        self
+    }
+
+    /// Tokenizes a word, with the Viterbi algorithm.
+    pub fn tokenize(&self, word: &str) -> Vec<usize> {
+        let chars = word.chars().collect::<Vec<_>>();
+        let unk_score = self.min_score - UNK_PENALTY;
+
+        // The best node ending at every character boundary
+        let mut best: Vec<Option<Node>> = vec![None; chars.len() + 1];
+        best[0] = Some(Node {
+            score: 0.0,
+            start: 0,
+            id: None,
+        });
+        for end in 1..=chars.len() {
+            for start in end.saturating_sub(self.max_piece_chars)..end {
+                let Some(previous) = best[start] else {
+                    continue;
+                };
+                let piece = chars[start..end].iter().collect::<String>();
+                let (id, score) = match self.vocab.get(&piece) {
+                    Some(id) => (Some(*id), self.pieces[*id].1),
+                    // A single unknown character
+                    None if end - start == 1 => (None, unk_score),
+                    None => continue,
+                };
+
+                let score = previous.score + score;
+                if best[end].is_none_or(|node| score > node.score) {
+                    best[end] = Some(Node { score, start, id });
+                }
+            }
+        }
+
+        // The pieces of the best path, with the consecutive unknown characters fused
+        let mut pieces: Vec<(Option<usize>, usize, usize)> = Vec::new();
+        let mut end = chars.len();
+        while end > 0 {
+            let node = best[end].unwrap();
+            match pieces.last_mut() {
+                Some((None, start, _)) if node.id.is_none() => *start = node.start,
+                _ => pieces.push((node.id, node.start, end)),
+            }
+            end = node.start;
+        }
+        pieces.reverse();
+
+        let mut ids = Vec::with_capacity(pieces.len());
+        for (id, start, end) in pieces {
+            if let Some(id) = id {
+                ids.push(id);
+                continue;
+            }
+
+            if self.byte_fallback {
+                let bytes = chars[start..end]
+                    .iter()
+                    .collect::<String>()
+                    .bytes()
+                    .map(|byte| self.vocab.get(&format!("<0x{byte:02X}>")).copied())
+                    .collect::<Option<Vec<_>>>();
+                if let Some(bytes) = bytes {
+                    ids.extend(bytes);
+                    continue;
+                }
+            }
+            ids.extend(self.unk_id);
+        }
+
+        ids
+    }
+
+    /// The id of the given token, if it is in the vocabulary.
+    pub fn token_to_id(&self, token: &str) -> Option<usize> {
+        self.vocab.get(token).copied()
+    }
+
+    /// The token of the given id, if it is in the vocabulary.
+    pub fn id_to_token(&self, id: usize) -> Option<&str> {
+        self.pieces.get(id).map(|(piece, _)| piece.as_str())
+    }
+
+    /// The number of tokens in the vocabulary.
+    pub fn vocab_size(&self) -> usize {
+        self.pieces.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_find_most_likely_tokens() {
+        let unigram = Unigram::new(
+            vec![
+                ("<unk>".to_string(), 0.0),
+                ("a".to_string(), -1.0),
+                ("b".to_string(), -1.0),
+                ("ab".to_string(), -1.5),
+                ("abc".to_string(), -4.0),
+                ("c".to_string(), -1.0),
+            ],
+            Some(0),
+        );
+
+        // `ab` + `c` (-2.5) is more likely than `abc` (-4.0) or `a` + `b` + `c` (-3.0)
+        assert_eq!(unigram.tokenize("abc"), vec![3, 5]);
+        assert_eq!(unigram.tokenize("xyab"), vec![0, 3]);
+    }
+}
@@ -0,0 +1,165 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+/// WordPiece model, which greedily splits words into the longest tokens of the vocabulary, like
+/// BERT.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(from = "WordPieceConfig")]
+pub struct WordPiece {
+    vocab: HashMap<String, usize>,
+    tokens: HashMap<usize, String>,
+    unk_token: String,
+    continuing_subword_prefix: String,
+    max_input_chars_per_word: usize,
+}
+
+#[derive(Deserialize)]
+struct WordPieceConfig {
+    vocab: HashMap<String, usize>,
+    #[serde(default = "default_unk_token")]
+    unk_token: String,
+    #[serde(default = "default_continuing_subword_prefix")]
+    continuing_subword_prefix: String,
+    #[serde(default = "default_max_input_chars_per_word")]
+    max_input_chars_per_word: usize,
+}
+
+fn default_unk_token() -> String {
+    "[UNK]".to_string()
+}
+
+fn default_continuing_subword_prefix() -> String {
+    "##".to_string()
+}
+
+fn default_max_input_chars_per_word() -> usize {
+    100
+}
+
+impl From<WordPieceConfig> for WordPiece {
+    fn from(config: WordPieceConfig) -> Self {
+        Self {
+            unk_token: config.unk_token,
+            continuing_subword_prefix: config.continuing_subword_prefix,
+            max_input_chars_per_word: config.max_input_chars_per_word,
+            ..Self::new(config.vocab)
+        }
+    }
+}
+
+impl WordPiece {
+    /// Creates a WordPiece model from its vocabulary, with the `[UNK]` unknown token and the `##`
+    /// prefix of the tokens that do not start a word.
+    pub fn new(vocab: HashMap<String, usize>) -> Self {
+        let tokens = vocab
+            .iter()
+            .map(|(token, id)| (*id, token.clone()))
+            .collect();
+
+        Self {
+            vocab,
+            tokens,
+            unk_token: default_unk_token(),
+            continuing_subword_prefix: default_continuing_subword_prefix(),
+            max_input_chars_per_word: default_max_input_chars_per_word(),
+        }
+    }
+
+    /// Sets the token of the words that cannot be tokenized.
+    pub fn with_unk_token(mut self, unk_token: String) -> Self {
+        self.unk_token = unk_token;
+        self
+    }
+
+    /// Sets the prefix of the tokens that do not start a word.
+    pub fn with_continuing_subword_prefix(mut self, prefix: String) -> Self {
+        self.continuing_subword_prefix = prefix;
+        self
+    }
+
+    /// The prefix of the tokens that do not start a word.
+    pub fn continuing_subword_prefix(&self) -> &str {
+        &self.continuing_subword_prefix
+    }
+
+    /// Tokenizes a word, which is a single unknown token when it cannot be split into tokens of
+    /// the vocabulary.
+    pub fn tokenize(&self, word: &str) -> Vec<usize> {
+        let unk = || {
+            self.vocab
+                .get(&self.unk_token)
+                .copied()
+                .into_iter()
+                .collect()
+        };
+        if word.chars().count() > self.max_input_chars_per_word {
+            return unk();
+        }
+
+        // The byte offsets of the characters, and of the end of the word
+        let boundaries = word
+            .char_indices()
+            .map(|(offset, _)| offset)
+            .chain([word.len()])
+            .collect::<Vec<_>>();
+
+        let mut ids = Vec::new();
+        let mut start = 0;
+        while start < boundaries.len() - 1 {
+            let piece = (start + 1..boundaries.len()).rev().find_map(|end| {
+                let piece = &word[boundaries[start]..boundaries[end]];
+                let id = match start {
+                    0 => self.vocab.get(piece),
+                    _ => self
+                        .vocab
+                        .get(&format!("{}{piece}", self.continuing_subword_prefix)),
+                };
+                id.map(|id| (*id, end))
+            });
+
+            match piece {
+                Some((id, end)) => {
+                    ids.push(id);
+                    start = end;
+                }
+                None => return unk(),
+            }
+        }
+
+        ids
+    }
+
+    /// The id of the given token, if it is in the vocabulary.
+    pub fn token_to_id(&self, token: &str) -> Option<usize> {
+        self.vocab.get(token).copied()
+    }
+
+    /// The token of the given id, if it is in the vocabulary.
+    pub fn id_to_token(&self, id: usize) -> Option<&str> {
+        self.tokens.get(&id).map(String::as_str)
+    }
+
+    /// The number of tokens in the vocabulary.
+    pub fn vocab_size(&self) -> usize {
+        self.vocab.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_split_into_longest_pieces() {
+        let vocab = ["[UNK]", "un", "##aff", "##able", "aff"]
+            .iter()
+            .enumerate()
+            .map(|(id, token)| (token.to_string(), id))
+            .collect();
+        let wordpiece = WordPiece::new(vocab);
+
+        assert_eq!(wordpiece.tokenize("unaffable"), vec![1, 2, 3]);
+        assert_eq!(wordpiece.tokenize("unaffx"), vec![0]);
+    }
+}
@@ -0,0 +1,268 @@
+use crate::{Dataset, SqliteDataset, SqliteDatasetError, SqliteDatasetStorage};
+use indicatif::{ProgressBar, ProgressStyle};
+use serde::{Serialize, de::DeserializeOwned};
+use std::{fs, path::PathBuf};
+
+/// The table of the cache database storing the items.
+const CACHE_SPLIT: &str = "items";
+
+/// Dataset caching the items of a wrapped dataset in a SQLite database, e.g. to avoid recomputing
+/// expensive [mapper](crate::transform::MapperDataset) transforms every epoch.
+///
+/// The items are written to the cache when the dataset is created, unless the cache was already
+/// built with the same fingerprint, and read from the cache afterwards. The fingerprint should
+/// change with the wrapped dataset and the transforms, e.g. by including their parameters.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use burn_dataset::{SqliteDatasetStorage, transform::{CachedDataset, MapperDataset}};
+///
+/// let dataset = MapperDataset::new(dataset, ResizeImage::new(224, 224));
+/// let dataset = CachedDataset::new(
+///     dataset,
+///     SqliteDatasetStorage::from_name("imagenet-224"),
+///     "resize-224-v1",
+///     true,
+/// )?;
+/// ```
+pub struct CachedDataset<I> {
+    cache: Option<SqliteDataset<I>>,
+}
+
+impl<I> CachedDataset<I>
+where
+    I: Clone + Send + Sync + Serialize + DeserializeOwned,
+{
+    /// Creates a new cached dataset, building the cache unless it is up to date.
+    ///
+    /// # Arguments
+    ///
+    /// * `dataset` - The dataset to cache.
+    /// * `storage` - The storage of the cache database.
+    /// * `fingerprint` - The fingerprint of the wrapped dataset, the cache is rebuilt when it changes.
+    /// * `progress` - Whether to report the progress of the cache build with a progress bar.
+    pub fn new<D, F>(
+        dataset: D,
+        storage: SqliteDatasetStorage,
+        fingerprint: F,
+        progress: bool,
+    ) -> Result<Self, SqliteDatasetError>
+    where
+        D: Dataset<I>,
+        F: AsRef<str>,
+    {
+        if dataset.is_empty() {
+            return Ok(Self { cache: None });
+        }
+
+        let fingerprint = fingerprint.as_ref();
+        if !Self::is_cached(&storage, fingerprint) {
+            write(&dataset, &storage, fingerprint, progress)?;
+        }
+
+        Ok(Self {
+            cache: Some(storage.reader(CACHE_SPLIT)?),
+        })
+    }
+
+    /// Returns true if the cache database exists and was built with the given fingerprint.
+    pub fn is_cached(storage: &SqliteDatasetStorage, fingerprint: &str) -> bool {
+        storage.exists()
+            && fs::read_to_string(fingerprint_file(storage))
+                .is_ok_and(|cached| cached == fingerprint)
+    }
+}
+
+/// Writes every item of the dataset to the cache database.
+fn write<D, I>(
+    dataset: &D,
+    storage: &SqliteDatasetStorage,
+    fingerprint: &str,
+    progress: bool,
+) -> Result<(), SqliteDatasetError>
+where
+    D: Dataset<I>,
+    I: Clone + Send + Sync + Serialize + DeserializeOwned,
+{
+    // A stale fingerprint would validate a partially written cache.
+    let fingerprint_file = fingerprint_file(storage);
+    if fingerprint_file.exists() {
+        fs::remove_file(&fingerprint_file)?;
+    }
+
+    let progress = match progress {
+        true => ProgressBar::new(dataset.len() as u64),
+        false => ProgressBar::hidden(),
+    };
+    progress.set_style(
+        ProgressStyle::with_template("{msg}\n    {wide_bar:.cyan/blue} {pos}/{len} ({eta})")
+            .unwrap()
+            .progress_chars("▬  "),
+    );
+    progress.set_message(format!(
+        "Caching dataset to {}",
+        storage.db_file().display()
+    ));
+
+    let mut writer = storage.writer::<I>(true)?;
+    for item in dataset.iter() {
+        writer.write(CACHE_SPLIT, &item)?;
+        progress.inc(1);
+    }
+    writer.set_completed()?;
+    progress.finish();
+
+    fs::write(fingerprint_file, fingerprint)?;
+
+    Ok(())
+}
+
+fn fingerprint_file(storage: &SqliteDatasetStorage) -> PathBuf {
+    storage.db_file().with_extension("db.fingerprint")
+}
+
+impl<I> Dataset<I> for CachedDataset<I>
+where
+    I: Clone + Send + Sync + Serialize + DeserializeOwned,
+{
+    fn get(&self, index: usize) -> Option<I> {
+        self.cache.as_ref()?.get(index)
+    }
+
+    fn len(&self) -> usize {
+        self.cache.as_ref().map_or(0, |cache| cache.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        InMemDataset,
+        transform::{Mapper, MapperDataset},
+    };
+    use std::sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    };
+    use tempfile::tempdir;
+
+    #[derive(Clone)]
+    struct CountingMapper {
+        calls: Arc<AtomicUsize>,
+    }
+
+    impl Mapper<i64, String> for CountingMapper {
+        fn map(&self, item: &i64) -> String {
+            self.calls.fetch_add(1, Ordering::Relaxed);
+            format!("item {item}")
+        }
+    }
+
+    fn mapper() -> CountingMapper {
+        CountingMapper {
+            calls: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    fn cached(
+        items: Vec<i64>,
+        mapper: &CountingMapper,
+        storage: &SqliteDatasetStorage,
+        fingerprint: &str,
+    ) -> CachedDataset<String> {
+        let dataset = MapperDataset::new(InMemDataset::new(items), mapper.clone());
+        CachedDataset::new(dataset, storage.clone(), fingerprint, false).unwrap()
+    }
+
+    #[test]
+    fn cached_dataset_should_read_items_from_cache_with_same_fingerprint() {
+        let dir = tempdir().unwrap();
+        let storage = SqliteDatasetStorage::from_file(dir.path().join("cache.db"));
+        let mapper = mapper();
+
+        assert!(!CachedDataset::<String>::is_cached(&storage, "v1"));
+        let dataset = cached(vec![1, 2, 3], &mapper, &storage, "v1");
+        assert!(CachedDataset::<String>::is_cached(&storage, "v1"));
+        assert_eq!(mapper.calls.load(Ordering::Relaxed), 3);
+        assert_eq!(dataset.get(1), Some("item 2".to_string()));
+
+        let dataset = cached(vec![1, 2, 3], &mapper, &storage, "v1");
+        assert_eq!(
+            dataset.iter().collect::<Vec<_>>(),
+            vec!["item 1", "item 2", "item 3"]
+        );
+        assert_eq!(dataset.get(3), None);
+        assert_eq!(mapper.calls.load(Ordering::Relaxed), 3);
+    }
+
+    #[test]
+    fn cached_dataset_should_rebuild_when_fingerprint_changes() {
+        let dir = tempdir().unwrap();
+        let storage = SqliteDatasetStorage::from_file(dir.path().join("cache.db"));
+        let mapper = mapper();
+
+        cached(vec![1, 2, 3], &mapper, &storage, "v1");
+        let dataset = cached(vec![4, 5], &mapper, &storage, "v2");
+
+        assert!(!CachedDataset::<String>::is_cached(&storage, "v1"));
+        assert!(CachedDataset::<String>::is_cached(&storage, "v2"));
+        assert_eq!(mapper.calls.load(Ordering::Relaxed), 5);
+        assert_eq!(dataset.iter().collect::<Vec<_>>(), vec!["item 4", "item 5"]);
+    }
+
+    #[test]
+    fn cached_dataset_should_rebuild_without_fingerprint_file() {
+        let dir = tempdir().unwrap();
+        let storage = SqliteDatasetStorage::from_file(dir.path().join("cache.db"));
+        let mapper = mapper();
+
+        cached(vec![1, 2, 3], &mapper, &storage, "v1");
+        // E.g. a build interrupted before the fingerprint is written.
+        fs::remove_file(fingerprint_file(&storage)).unwrap();
+        assert!(!CachedDataset::<String>::is_cached(&storage, "v1"));
+
+        let dataset = cached(vec![1, 2, 3], &mapper, &storage, "v1");
+        assert_eq!(mapper.calls.load(Ordering::Relaxed), 6);
+        assert_eq!(dataset.len(), 3);
+    }
+
+    #[test]
+    fn cached_dataset_should_rebuild_without_database() {
+        let dir = tempdir().unwrap();
+        let storage = SqliteDatasetStorage::from_file(dir.path().join("cache.db"));
+        let mapper = mapper();
+
+        cached(vec![1, 2, 3], &mapper, &storage, "v1");
+        fs::remove_file(storage.db_file()).unwrap();
+        assert!(!CachedDataset::<String>::is_cached(&storage, "v1"));
+
+        let dataset = cached(vec![1, 2, 3], &mapper, &storage, "v1");
+        assert_eq!(mapper.calls.load(Ordering::Relaxed), 6);
+        assert_eq!(dataset.get(0), Some("item 1".to_string()));
+    }
+
+    #[test]
+    fn cached_dataset_should_not_write_empty_dataset() {
+        let dir = tempdir().unwrap();
+        let storage = SqliteDatasetStorage::from_file(dir.path().join("cache.db"));
+
+        let dataset = cached(Vec::new(), &mapper(), &storage, "v1");
+
+        assert!(dataset.is_empty());
+        assert!(!storage.exists());
+    }
+
+    #[test]
+    fn cached_dataset_should_return_storage_errors() {
+        let dir = tempdir().unwrap();
+        // The parent of the database is a file, so the database can't be created.
+        let file = dir.path().join("file");
+        fs::write(&file, "").unwrap();
+        let storage = SqliteDatasetStorage::from_file(file.join("cache.db"));
+        let dataset = MapperDataset::new(InMemDataset::new(vec![1]), mapper());
+
+        assert!(CachedDataset::new(dataset, storage, "v1", false).is_err());
+    }
+}
@@ -0,0 +1,544 @@
+use crate::Dataset;
+use crate::transform::{RngSource, shuffled_indices};
+use rand::{Rng, SeedableRng, distr::weighted::WeightedIndex, rngs::StdRng};
+use std::{marker::PhantomData, ops::DerefMut, sync::Mutex};
+
+/// What to do when a source of an [interleaved dataset](InterleavedDataset) is exhausted.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ExhaustionStrategy {
+    /// Stop the interleaving as soon as a source is exhausted.
+    #[default]
+    Stop,
+
+    /// Restart the exhausted sources for a new epoch until the interleaved dataset has the given
+    /// size.
+    Restart(usize),
+
+    /// Restart the exhausted sources for a new epoch until every source has been exhausted at
+    /// least once, oversampling the sources with few items relative to their weight.
+    ///
+    /// The expected size must be at most 100 times the number of items of the sources, otherwise
+    /// use [Restart](ExhaustionStrategy::Restart) with an explicit size.
+    Oversample,
+}
+
+/// Options to configure an [InterleavedDataset].
+#[derive(Debug, Clone, PartialEq)]
+pub struct InterleavedDatasetOptions {
+    /// The mixture weights of the sources, uniform when not set.
+    pub weights: Option<Vec<f64>>,
+
+    /// What to do when a source is exhausted.
+    pub exhaustion: ExhaustionStrategy,
+
+    /// Whether to shuffle the items of each source at every epoch.
+    pub shuffle: bool,
+
+    /// The source of the random number generator.
+    pub rng_source: RngSource,
+}
+
+impl Default for InterleavedDatasetOptions {
+    fn default() -> Self {
+        Self {
+            weights: None,
+            exhaustion: ExhaustionStrategy::Stop,
+            shuffle: false,
+            rng_source: RngSource::Default,
+        }
+    }
+}
+
+impl InterleavedDatasetOptions {
+    /// Set the mixture weights of the sources, which don't need to sum to one.
+    pub fn with_weights(self, weights: Vec<f64>) -> Self {
+        Self {
+            weights: Some(weights),
+            ..self
+        }
+    }
+
+    /// Set the exhaustion strategy.
+    pub fn with_exhaustion(self, exhaustion: ExhaustionStrategy) -> Self {
+        Self { exhaustion, ..self }
+    }
+
+    /// Set whether to shuffle the items of each source at every epoch.
+    pub fn with_shuffle(self, shuffle: bool) -> Self {
+        Self { shuffle, ..self }
+    }
+
+    /// Set the `RngSource`.
+    pub fn with_rng<R>(self, rng: R) -> Self
+    where
+        R: Into<RngSource>,
+    {
+        Self {
+            rng_source: rng.into(),
+            ..self
+        }
+    }
+
+    /// Use a rng, built from a seed.
+    pub fn with_seed(self, seed: u64) -> Self {
+        self.with_rng(seed)
+    }
+}
+
+/// Statistics of a source of an [interleaved dataset](InterleavedDataset).
+#[derive(Debug, Clone, PartialEq)]
+pub struct SourceStats {
+    /// The number of items of the source.
+    pub len: usize,
+
+    /// The number of items drawn from the source.
+    pub num_items: usize,
+
+    /// The ratio of the interleaved dataset drawn from the source.
+    pub ratio: f64,
+
+    /// The number of epochs over the source, i.e. the number of items drawn relative to its size.
+    pub epochs: f64,
+}
+
+/// Interleave multiple datasets, drawing each item from a source chosen with a probability
+/// proportional to its mixture weight.
+///
+/// The items are drawn lazily from a seed taken when the dataset is created, so the same seed
+/// always produces the same dataset. Within an epoch, the items of a source are drawn in order, or
+/// in a random order derived from the seed and the epoch with
+/// [shuffling](InterleavedDatasetOptions::with_shuffle).
+///
+/// Creating the dataset replays all the draws once, in `O(size)` time, to know its size and the
+/// number of items drawn from each source. Only the state of the draws every 1024 items is kept,
+/// which makes sequential access cheap while random access replays at most that many draws.
+///
+/// Consider using a [composed dataset](crate::transform::ComposedDataset) to concatenate the
+/// datasets end to end instead.
+pub struct InterleavedDataset<D, I> {
+    datasets: Vec<D>,
+    schedule: Schedule,
+    input: PhantomData<I>,
+}
+
+/// The number of items between two checkpoints of the draws of an [InterleavedDataset].
+const CHECKPOINT_INTERVAL: usize = 1024;
+
+/// The maximum expected size of an [oversampled](ExhaustionStrategy::Oversample) dataset,
+/// relative to the number of items of the sources.
+const MAX_OVERSAMPLING: f64 = 100.0;
+
+impl<D, I> InterleavedDataset<D, I>
+where
+    D: Dataset<I>,
+    I: Send + Sync,
+{
+    /// Creates a new interleaved dataset.
+    ///
+    /// # Arguments
+    ///
+    /// * `datasets` - The source datasets.
+    /// * `options` - The options to configure the interleaved dataset.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of weights differs from the number of datasets, or if the weights are
+    /// negative or not finite. With [oversampling](ExhaustionStrategy::Oversample), it also panics
+    /// if the expected size is more than 100 times the number of items of the sources.
+    pub fn new(datasets: Vec<D>, options: InterleavedDatasetOptions) -> Self {
+        let weights = options.weights.unwrap_or_else(|| vec![1.0; datasets.len()]);
+        assert_eq!(
+            weights.len(),
+            datasets.len(),
+            "There should be one weight per dataset"
+        );
+        assert!(
+            weights
+                .iter()
+                .all(|weight| weight.is_finite() && *weight >= 0.0),
+            "The weights should be positive and finite"
+        );
+
+        let lengths = datasets.iter().map(|dataset| dataset.len()).collect();
+        let schedule = Schedule::new(
+            lengths,
+            &weights,
+            options.exhaustion,
+            options.shuffle,
+            options.rng_source.into(),
+        );
+
+        Self {
+            datasets,
+            schedule,
+            input: PhantomData,
+        }
+    }
+
+    /// The index of the source the item at the given index is drawn from.
+    pub fn source(&self, index: usize) -> Option<usize> {
+        self.schedule.item(index).map(|(source, _)| source)
+    }
+
+    /// The statistics of each source.
+    pub fn source_stats(&self) -> Vec<SourceStats> {
+        self.datasets
+            .iter()
+            .zip(self.schedule.counts.iter())
+            .map(|(dataset, num_items)| SourceStats {
+                len: dataset.len(),
+                num_items: *num_items,
+                ratio: *num_items as f64 / self.schedule.len.max(1) as f64,
+                epochs: *num_items as f64 / dataset.len().max(1) as f64,
+            })
+            .collect()
+    }
+}
+
+/// The order of the items of an [InterleavedDataset], drawn lazily from checkpoints.
+struct Schedule {
+    lengths: Vec<usize>,
+    distribution: Option<WeightedIndex<f64>>,
+    exhaustion: ExhaustionStrategy,
+    shuffle: bool,
+    seed: u64,
+    len: usize,
+    counts: Vec<usize>,
+    checkpoints: Vec<Draws>,
+    state: Mutex<ScheduleState>,
+}
+
+/// The state of the draws after a number of items.
+#[derive(Clone)]
+struct Draws {
+    step: usize,
+    rng: StdRng,
+    cursors: Vec<usize>,
+    epochs: Vec<usize>,
+    exhausted: Vec<bool>,
+}
+
+/// An item drawn from a source, at a position of one of its epochs.
+struct Draw {
+    source: usize,
+    epoch: usize,
+    position: usize,
+}
+
+struct ScheduleState {
+    draws: Draws,
+    /// The order of the last epoch used of each shuffled source.
+    orders: Vec<Option<(usize, Vec<usize>)>>,
+}
+
+impl Schedule {
+    fn new(
+        lengths: Vec<usize>,
+        weights: &[f64],
+        exhaustion: ExhaustionStrategy,
+        shuffle: bool,
+        mut rng: StdRng,
+    ) -> Self {
+        // Empty sources can't be drawn from, so they are never sampled.
+        let weights = weights
+            .iter()
+            .zip(lengths.iter())
+            .map(|(weight, len)| if *len == 0 { 0.0 } else { *weight })
+            .collect::<Vec<_>>();
+        let distribution = WeightedIndex::new(&weights).ok();
+
+        if exhaustion == ExhaustionStrategy::Oversample && distribution.is_some() {
+            check_oversampling(&lengths, &weights);
+        }
+
+        let seed = rng.random();
+        let initial = Draws {
+            step: 0,
+            rng: StdRng::seed_from_u64(seed),
+            cursors: vec![0; lengths.len()],
+            epochs: vec![0; lengths.len()],
+            exhausted: weights.iter().map(|weight| *weight == 0.0).collect(),
+        };
+
+        let mut schedule = Self {
+            lengths,
+            distribution,
+            exhaustion,
+            shuffle,
+            seed,
+            len: 0,
+            counts: Vec::new(),
+            checkpoints: Vec::new(),
+            state: Mutex::new(ScheduleState {
+                orders: Vec::new(),
+                draws: initial.clone(),
+            }),
+        };
+
+        // The draws are replayed once to know the size and to keep the checkpoints.
+        let mut draws = initial;
+        let mut counts = vec![0; schedule.lengths.len()];
+        loop {
+            if draws.step % CHECKPOINT_INTERVAL == 0 {
+                schedule.checkpoints.push(draws.clone());
+            }
+            match schedule.next(&mut draws) {
+                Some(draw) => counts[draw.source] += 1,
+                None => break,
+            }
+        }
+
+        schedule.len = draws.step;
+        schedule.counts = counts;
+        schedule.state.get_mut().unwrap().orders = vec![None; schedule.lengths.len()];
+        schedule
+    }
+
+    /// Draws the next item, if any.
+    fn next(&self, draws: &mut Draws) -> Option<Draw> {
+        let distribution = self.distribution.as_ref()?;
+        if let ExhaustionStrategy::Restart(size) = self.exhaustion
+            && draws.step >= size
+        {
+            return None;
+        }
+
+        let source = draws.rng.sample(distribution);
+        if draws.cursors[source] == self.lengths[source] {
+            match self.exhaustion {
+                ExhaustionStrategy::Stop => return None,
+                ExhaustionStrategy::Restart(_) => {}
+                ExhaustionStrategy::Oversample => {
+                    draws.exhausted[source] = true;
+                    if draws.exhausted.iter().all(|exhausted| *exhausted) {
+                        return None;
+                    }
+                }
+            }
+            draws.cursors[source] = 0;
+            draws.epochs[source] += 1;
+        }
+
+        let draw = Draw {
+            source,
+            epoch: draws.epochs[source],
+            position: draws.cursors[source],
+        };
+        draws.cursors[source] += 1;
+        draws.step += 1;
+
+        Some(draw)
+    }
+
+    /// The source and the index in the source of the item at the given index.
+    fn item(&self, index: usize) -> Option<(usize, usize)> {
+        if index >= self.len {
+            return None;
+        }
+
+        let mut state = self.state.lock().unwrap();
+        let state = state.deref_mut();
+
+        // Resume from the closest checkpoint unless the item is ahead in the current one.
+        let checkpoint = &self.checkpoints[index / CHECKPOINT_INTERVAL];
+        if state.draws.step > index || state.draws.step < checkpoint.step {
+            state.draws = checkpoint.clone();
+        }
+
+        let mut draw = None;
+        while state.draws.step <= index {
+            draw = self.next(&mut state.draws);
+        }
+        let draw = draw.expect("The items are drawn up to the size of the schedule");
+
+        let position = match self.shuffle {
+            true => {
+                let order = &mut state.orders[draw.source];
+                if !matches!(order, Some((epoch, _)) if *epoch == draw.epoch) {
+                    let mut rng = epoch_rng(self.seed, draw.source, draw.epoch);
+                    let indices = shuffled_indices(self.lengths[draw.source], &mut rng);
+                    *order = Some((draw.epoch, indices));
+                }
+                order.as_ref().unwrap().1[draw.position]
+            }
+            false => draw.position,
+        };
+
+        Some((draw.source, position))
+    }
+}
+
+/// Checks that oversampling stops within a reasonable size, since a source with few items
+/// relative to its weight could otherwise take a very long time to be exhausted.
+fn check_oversampling(lengths: &[usize], weights: &[f64]) {
+    let total_weight = weights.iter().sum::<f64>();
+    let total_items = lengths.iter().sum::<usize>();
+    // The expected number of draws to exhaust each source once.
+    let expected = lengths
+        .iter()
+        .zip(weights.iter())
+        .filter(|(_, weight)| **weight > 0.0)
+        .map(|(len, weight)| *len as f64 * total_weight / weight)
+        .fold(0.0, f64::max);
+
+    assert!(
+        expected <= MAX_OVERSAMPLING * total_items as f64,
+        "Oversampling would draw about {expected:.0} items, more than {MAX_OVERSAMPLING} times \
+        the {total_items} items of the sources; use ExhaustionStrategy::Restart with a size instead"
+    );
+}
+
+/// The random number generator of an epoch of a source.
+fn epoch_rng(seed: u64, source: usize, epoch: usize) -> StdRng {
+    let mut bytes = [0; 32];
+    bytes[..8].copy_from_slice(&seed.to_le_bytes());
+    bytes[8..16].copy_from_slice(&(source as u64).to_le_bytes());
+    bytes[16..24].copy_from_slice(&(epoch as u64).to_le_bytes());
+    StdRng::from_seed(bytes)
+}
+
+impl<D, I> Dataset<I> for InterleavedDataset<D, I>
+where
+    D: Dataset<I>,
+    I: Send + Sync,
+{
+    fn get(&self, index: usize) -> Option<I> {
+        let (source, index) = self.schedule.item(index)?;
+        self.datasets[source].get(index)
+    }
+
+    fn len(&self) -> usize {
+        self.schedule.len
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::InMemDataset;
+
+    fn sources() -> Vec<InMemDataset<usize>> {
+        vec![
+            InMemDataset::new((0..10).collect()),
+            InMemDataset::new((100..130).collect()),
+        ]
+    }
+
+    #[test]
+    fn interleaved_dataset_should_stop_on_first_exhausted_source() {
+        let dataset = InterleavedDataset::new(
+            sources(),
+            InterleavedDatasetOptions::default().with_seed(42),
+        );
+
+        let items = dataset.iter().collect::<Vec<_>>();
+        let stats = dataset.source_stats();
+
+        let first = items.iter().filter(|item| **item < 100).collect::<Vec<_>>();
+        let second = items
+            .iter()
+            .filter(|item| **item >= 100)
+            .collect::<Vec<_>>();
+        assert!(first.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(second.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(first.len() == 10 || second.len() == 30);
+        assert_eq!(stats[0].num_items + stats[1].num_items, dataset.len());
+    }
+
+    #[test]
+    fn interleaved_dataset_should_restart_until_size() {
+        let options = InterleavedDatasetOptions::default()
+            .with_weights(vec![3.0, 1.0])
+            .with_exhaustion(ExhaustionStrategy::Restart(400))
+            .with_shuffle(true)
+            .with_seed(7);
+        let dataset = InterleavedDataset::new(sources(), options.clone());
+
+        let stats = dataset.source_stats();
+
+        assert_eq!(dataset.len(), 400);
+        assert!((0.65..0.85).contains(&stats[0].ratio), "{stats:?}");
+        assert!(stats[0].epochs > 20.0, "{stats:?}");
+        assert_eq!(
+            dataset.iter().collect::<Vec<_>>(),
+            InterleavedDataset::new(sources(), options)
+                .iter()
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn interleaved_dataset_should_support_random_access() {
+        let dataset = InterleavedDataset::new(
+            sources(),
+            InterleavedDatasetOptions::default()
+                .with_exhaustion(ExhaustionStrategy::Restart(3000))
+                .with_shuffle(true)
+                .with_seed(11),
+        );
+
+        let items = dataset.iter().collect::<Vec<_>>();
+        let mut reversed = (0..dataset.len())
+            .rev()
+            .map(|index| dataset.get(index).unwrap())
+            .collect::<Vec<_>>();
+        reversed.reverse();
+
+        assert_eq!(items.len(), 3000);
+        assert_eq!(items, reversed);
+        assert_eq!(dataset.get(3000), None);
+    }
+
+    #[test]
+    fn interleaved_dataset_should_shuffle_every_epoch() {
+        let dataset = InterleavedDataset::new(
+            vec![InMemDataset::new((0..10).collect::<Vec<usize>>())],
+            InterleavedDatasetOptions::default()
+                .with_exhaustion(ExhaustionStrategy::Restart(50))
+                .with_shuffle(true)
+                .with_seed(5),
+        );
+
+        let items = dataset.iter().collect::<Vec<_>>();
+        let epochs = items.chunks(10).collect::<Vec<_>>();
+
+        for epoch in epochs.iter() {
+            let mut sorted = epoch.to_vec();
+            sorted.sort();
+            assert_eq!(sorted, (0..10).collect::<Vec<_>>());
+        }
+        assert!(epochs.windows(2).any(|pair| pair[0] != pair[1]));
+    }
+
+    #[test]
+    #[should_panic(expected = "Oversampling would draw about")]
+    fn interleaved_dataset_should_reject_large_oversampling() {
+        InterleavedDataset::new(
+            sources(),
+            InterleavedDatasetOptions::default()
+                .with_weights(vec![1.0, 0.0001])
+                .with_exhaustion(ExhaustionStrategy::Oversample)
+                .with_seed(3),
+        );
+    }
+
+    #[test]
+    fn interleaved_dataset_should_oversample_until_all_exhausted() {
+        let dataset = InterleavedDataset::new(
+            sources(),
+            InterleavedDatasetOptions::default()
+                .with_exhaustion(ExhaustionStrategy::Oversample)
+                .with_seed(3),
+        );
+
+        let stats = dataset.source_stats();
+
+        assert!(stats.iter().all(|stats| stats.epochs >= 1.0), "{stats:?}");
+        assert!(stats[0].epochs > 1.0, "{stats:?}");
+        let first_source = match dataset.get(0) {
+            Some(item) if item < 100 => 0,
+            _ => 1,
+        };
+        assert_eq!(dataset.source(0), Some(first_source));
+    }
+}
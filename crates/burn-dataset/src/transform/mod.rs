@@ -3,28 +3,44 @@
 //! This module provides a collection of [`crate::Dataset`] composition wrappers;
 //! providing composition, subset selection, sampling, random shuffling, and windowing.
 //!
+//! * [`CachedDataset`] - caches the items of a dataset in a SQLite database.
 //! * [`ComposedDataset`] - composes a list of datasets.
+//! * [`InterleavedDataset`] - interleaves a list of datasets with mixture weights.
 //! * [`PartialDataset`] - selects a contiguous index range subset of a dataset.
 //! * [`ShuffledDataset`] - a randomly shuffled / mutably shuffle-able dataset;
 //!   a thin wrapper around [`SelectionDataset`].
 //! * [`SamplerDataset`] - samples a dataset; support for with/without replacement,
 //!   and under/oversampling.
 //! * [`SelectionDataset`] - selects a subset of a dataset via indices; support for shuffling.
+//! * [`WeightedSamplerDataset`] - samples a dataset with per-item or class-balanced weights.
 //! * [`WindowsDataset`] - creates a sliding window over a dataset.
+//!
+//! The [`stratified_split`] and [`stratified_k_fold`] functions split a dataset preserving the
+//! proportion of each label.
+#[cfg(any(feature = "sqlite", feature = "sqlite-bundled"))]
+mod cached;
 mod composed;
+mod interleaved;
 mod mapper;
 mod options;
 mod partial;
 mod sampler;
 mod selection;
 mod shuffle;
+mod stratified;
+mod weighted;
 mod window;
 
+#[cfg(any(feature = "sqlite", feature = "sqlite-bundled"))]
+pub use cached::*;
 pub use composed::*;
+pub use interleaved::*;
 pub use mapper::*;
 pub use options::*;
 pub use partial::*;
 pub use sampler::*;
 pub use selection::*;
 pub use shuffle::*;
+pub use stratified::*;
+pub use weighted::*;
 pub use window::*;
@@ -0,0 +1,199 @@
+use crate::Dataset;
+use crate::transform::{RngSource, SelectionDataset};
+use rand::prelude::SliceRandom;
+use rand::rngs::StdRng;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::Arc;
+
+/// Groups the indices of a dataset by label, each group being shuffled.
+///
+/// The groups are ordered by the first appearance of their label, so that the result only
+/// depends on the random number generator.
+fn shuffled_groups<D, I, L, F>(dataset: &D, label: F, rng: &mut StdRng) -> Vec<Vec<usize>>
+where
+    D: Dataset<I>,
+    L: Eq + Hash,
+    F: Fn(&I) -> L,
+{
+    let mut groups: Vec<Vec<usize>> = Vec::new();
+    let mut positions = HashMap::new();
+
+    for (index, item) in dataset.iter().enumerate() {
+        let position = *positions.entry(label(&item)).or_insert_with(|| {
+            groups.push(Vec::new());
+            groups.len() - 1
+        });
+        groups[position].push(index);
+    }
+
+    for group in groups.iter_mut() {
+        group.shuffle(rng);
+    }
+
+    groups
+}
+
+/// Splits a dataset into a train and a validation dataset preserving the proportion of each
+/// label.
+///
+/// # Arguments
+///
+/// * `dataset` - The dataset to split.
+/// * `label` - The function extracting the label of an item.
+/// * `valid_ratio` - The ratio of the items of each label in the validation dataset.
+/// * `rng_source` - The source of the random number generator.
+///
+/// # Returns
+///
+/// The train and validation datasets, sharing the same wrapped dataset.
+///
+/// # Panics
+///
+/// Panics if the ratio is not between 0 and 1.
+pub fn stratified_split<S, D, I, L, F, R>(
+    dataset: S,
+    label: F,
+    valid_ratio: f64,
+    rng_source: R,
+) -> (SelectionDataset<D, I>, SelectionDataset<D, I>)
+where
+    S: Into<Arc<D>>,
+    D: Dataset<I>,
+    I: Clone + Send + Sync,
+    L: Eq + Hash,
+    F: Fn(&I) -> L,
+    R: Into<RngSource>,
+{
+    assert!(
+        (0.0..=1.0).contains(&valid_ratio),
+        "The validation ratio should be between 0 and 1: {valid_ratio}"
+    );
+
+    let dataset = dataset.into();
+    let mut rng: StdRng = rng_source.into().into();
+    let groups = shuffled_groups(dataset.as_ref(), label, &mut rng);
+
+    let mut train = Vec::new();
+    let mut valid = Vec::new();
+    for group in groups {
+        let num_valid = (group.len() as f64 * valid_ratio).round() as usize;
+        valid.extend_from_slice(&group[..num_valid]);
+        train.extend_from_slice(&group[num_valid..]);
+    }
+    train.shuffle(&mut rng);
+    valid.shuffle(&mut rng);
+
+    (
+        SelectionDataset::from_indices_unchecked(dataset.clone(), train),
+        SelectionDataset::from_indices_unchecked(dataset, valid),
+    )
+}
+
+/// Splits a dataset into `k` folds preserving the proportion of each label, for k-fold
+/// cross-validation.
+///
+/// # Arguments
+///
+/// * `dataset` - The dataset to split.
+/// * `label` - The function extracting the label of an item.
+/// * `k` - The number of folds.
+/// * `rng_source` - The source of the random number generator.
+///
+/// # Returns
+///
+/// For each fold, the train dataset made of the other folds and the validation dataset made of
+/// the fold, all sharing the same wrapped dataset.
+///
+/// # Panics
+///
+/// Panics if `k` is less than 2.
+pub fn stratified_k_fold<S, D, I, L, F, R>(
+    dataset: S,
+    label: F,
+    k: usize,
+    rng_source: R,
+) -> Vec<(SelectionDataset<D, I>, SelectionDataset<D, I>)>
+where
+    S: Into<Arc<D>>,
+    D: Dataset<I>,
+    I: Clone + Send + Sync,
+    L: Eq + Hash,
+    F: Fn(&I) -> L,
+    R: Into<RngSource>,
+{
+    assert!(k >= 2, "There should be at least 2 folds: {k}");
+
+    let dataset = dataset.into();
+    let mut rng: StdRng = rng_source.into().into();
+    let groups = shuffled_groups(dataset.as_ref(), label, &mut rng);
+
+    // Dealing the items of each label in turn keeps the fold sizes within one item.
+    let mut folds = vec![Vec::new(); k];
+    for (position, index) in groups.into_iter().flatten().enumerate() {
+        folds[position % k].push(index);
+    }
+
+    (0..k)
+        .map(|fold| {
+            let mut train = folds
+                .iter()
+                .enumerate()
+                .filter(|(other, _)| *other != fold)
+                .flat_map(|(_, indices)| indices.iter().copied())
+                .collect::<Vec<_>>();
+            let mut valid = folds[fold].clone();
+            train.shuffle(&mut rng);
+            valid.shuffle(&mut rng);
+
+            (
+                SelectionDataset::from_indices_unchecked(dataset.clone(), train),
+                SelectionDataset::from_indices_unchecked(dataset.clone(), valid),
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::InMemDataset;
+
+    fn labeled_dataset() -> InMemDataset<(usize, u8)> {
+        InMemDataset::new((0..100).map(|i| (i, (i % 10 < 2) as u8)).collect())
+    }
+
+    #[test]
+    fn stratified_split_should_preserve_label_proportions() {
+        let (train, valid) = stratified_split(labeled_dataset(), |(_, label)| *label, 0.25, 42);
+
+        let positives = |dataset: &SelectionDataset<InMemDataset<_>, (usize, u8)>| {
+            dataset.iter().filter(|(_, label)| *label == 1).count()
+        };
+
+        assert_eq!(train.len(), 75);
+        assert_eq!(valid.len(), 25);
+        assert_eq!(positives(&train), 15);
+        assert_eq!(positives(&valid), 5);
+
+        let mut items = train.iter().chain(valid.iter()).collect::<Vec<_>>();
+        items.sort();
+        assert_eq!(items, labeled_dataset().iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn stratified_k_fold_should_cover_every_item_once() {
+        let folds = stratified_k_fold(labeled_dataset(), |(_, label)| *label, 5, 42);
+
+        let mut validated = Vec::new();
+        for (train, valid) in folds.iter() {
+            assert_eq!(train.len(), 80);
+            assert_eq!(valid.len(), 20);
+            assert_eq!(valid.iter().filter(|(_, label)| *label == 1).count(), 4);
+            validated.extend(valid.iter().map(|(index, _)| index));
+        }
+
+        validated.sort();
+        assert_eq!(validated, (0..100).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,277 @@
+use crate::Dataset;
+use crate::transform::SamplerDatasetOptions;
+use rand::prelude::SliceRandom;
+use rand::{Rng, distr::weighted::WeightedIndex, rngs::StdRng};
+use std::{collections::HashMap, hash::Hash, marker::PhantomData, ops::DerefMut, sync::Mutex};
+
+/// Sample items from a dataset with a probability proportional to their weight.
+///
+/// This is useful to over-sample the rare items of an imbalanced dataset, e.g. with
+/// [class-balanced](WeightedSamplerDataset::class_balanced) weights.
+///
+/// The [options](SamplerDatasetOptions) are the same as the ones of the
+/// [sampler dataset](crate::transform::SamplerDataset):
+///
+/// * With replacement (Default): every item is drawn independently from the weighted
+///   distribution.
+///
+/// * Without replacement: every cycle draws `size` distinct items with a probability proportional
+///   to their weight. The size must be smaller than the number of items with a positive weight,
+///   otherwise every item would be drawn and the weights would have no effect.
+pub struct WeightedSamplerDataset<D, I> {
+    dataset: D,
+    weights: Vec<f64>,
+    size: usize,
+    state: Mutex<WeightedSamplerState>,
+    input: PhantomData<I>,
+}
+
+enum WeightedSamplerState {
+    WithReplacement(StdRng, WeightedIndex<f64>),
+    WithoutReplacement(StdRng, Vec<usize>),
+}
+
+impl<D, I> WeightedSamplerDataset<D, I>
+where
+    D: Dataset<I>,
+    I: Send + Sync,
+{
+    /// Creates a new weighted sampler dataset.
+    ///
+    /// # Arguments
+    ///
+    /// * `dataset` - The dataset to wrap.
+    /// * `weights` - The weight of each item of the dataset, which don't need to sum to one.
+    /// * `options` - The options to configure the sampler dataset.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of weights differs from the size of the dataset, or if the weights
+    /// are negative, not finite or all zero. Without replacement, it also panics if the size isn't
+    /// smaller than the number of items with a positive weight, e.g. with the default size.
+    pub fn new<O>(dataset: D, weights: Vec<f64>, options: O) -> Self
+    where
+        O: Into<SamplerDatasetOptions>,
+    {
+        assert_eq!(
+            weights.len(),
+            dataset.len(),
+            "There should be one weight per item of the dataset"
+        );
+        assert!(
+            weights
+                .iter()
+                .all(|weight| weight.is_finite() && *weight >= 0.0),
+            "The weights should be positive and finite"
+        );
+        assert!(
+            weights.iter().any(|weight| *weight > 0.0),
+            "At least one weight should be greater than zero"
+        );
+
+        let options = options.into();
+        let size = options.size_config.resolve(dataset.len());
+        let rng = options.rng_source.into();
+
+        if !options.replace_samples {
+            let candidates = weights.iter().filter(|weight| **weight > 0.0).count();
+            assert!(
+                size < candidates,
+                "Without replacement, the size ({size}) should be smaller than the number of items \
+                with a positive weight ({candidates}), otherwise the weights have no effect"
+            );
+        }
+
+        let state = match options.replace_samples {
+            true => WeightedSamplerState::WithReplacement(
+                rng,
+                WeightedIndex::new(&weights).expect("The weights should be valid"),
+            ),
+            false => WeightedSamplerState::WithoutReplacement(rng, Vec::with_capacity(size)),
+        };
+
+        Self {
+            dataset,
+            weights,
+            size,
+            state: Mutex::new(state),
+            input: PhantomData,
+        }
+    }
+
+    /// Creates a new weighted sampler dataset drawing every class with the same probability.
+    ///
+    /// The weight of each item is the inverse of the number of items sharing its label.
+    ///
+    /// # Arguments
+    ///
+    /// * `dataset` - The dataset to wrap.
+    /// * `label` - The function extracting the label of an item.
+    /// * `options` - The options to configure the sampler dataset.
+    pub fn class_balanced<L, F, O>(dataset: D, label: F, options: O) -> Self
+    where
+        L: Eq + Hash,
+        F: Fn(&I) -> L,
+        O: Into<SamplerDatasetOptions>,
+    {
+        let labels = dataset.iter().map(|item| label(&item)).collect::<Vec<_>>();
+
+        let mut counts = HashMap::new();
+        for label in labels.iter() {
+            *counts.entry(label).or_insert(0usize) += 1;
+        }
+
+        let weights = labels
+            .iter()
+            .map(|label| 1.0 / counts[label] as f64)
+            .collect();
+
+        Self::new(dataset, weights, options)
+    }
+
+    /// The weight of each item of the dataset.
+    pub fn weights(&self) -> &[f64] {
+        &self.weights
+    }
+
+    fn index(&self) -> usize {
+        match self.state.lock().unwrap().deref_mut() {
+            WeightedSamplerState::WithReplacement(rng, distribution) => rng.sample(&*distribution),
+            WeightedSamplerState::WithoutReplacement(rng, indices) => {
+                if indices.is_empty() {
+                    indices.extend(weighted_choose_multiple(&self.weights, self.size, rng));
+                    indices.shuffle(rng);
+                }
+
+                indices.pop().expect("Indices are refilled when empty.")
+            }
+        }
+    }
+}
+
+/// Draws `amount` distinct indices with a probability proportional to their weight, using the
+/// exponential keys of Efraimidis and Spirakis.
+fn weighted_choose_multiple(weights: &[f64], amount: usize, rng: &mut StdRng) -> Vec<usize> {
+    let mut keys = weights
+        .iter()
+        .enumerate()
+        .filter(|(_, weight)| **weight > 0.0)
+        .map(|(index, weight)| (rng.random::<f64>().powf(1.0 / weight), index))
+        .collect::<Vec<_>>();
+    keys.sort_by(|a, b| b.0.total_cmp(&a.0));
+
+    keys.into_iter()
+        .take(amount)
+        .map(|(_, index)| index)
+        .collect()
+}
+
+impl<D, I> Dataset<I> for WeightedSamplerDataset<D, I>
+where
+    D: Dataset<I>,
+    I: Send + Sync,
+{
+    fn get(&self, index: usize) -> Option<I> {
+        if index >= self.size {
+            return None;
+        }
+
+        self.dataset.get(self.index())
+    }
+
+    fn len(&self) -> usize {
+        self.size
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::InMemDataset;
+
+    #[test]
+    fn weighted_sampler_should_never_draw_zero_weights() {
+        let dataset = InMemDataset::new(vec![0, 1, 2, 3]);
+        let sampler = WeightedSamplerDataset::new(
+            dataset,
+            vec![0.0, 1.0, 0.0, 3.0],
+            SamplerDatasetOptions::default()
+                .with_fixed_size(1000)
+                .with_seed(42),
+        );
+
+        let items = sampler.iter().collect::<Vec<_>>();
+        let ones = items.iter().filter(|item| **item == 1).count();
+
+        assert_eq!(items.len(), 1000);
+        assert!(items.iter().all(|item| *item == 1 || *item == 3));
+        assert!(
+            (150..350).contains(&ones),
+            "{ones} items drawn with weight 1"
+        );
+    }
+
+    #[test]
+    fn class_balanced_sampler_should_draw_classes_uniformly() {
+        let items = (0..100).map(|i| (i, i < 90)).collect();
+        let dataset = InMemDataset::new(items);
+        let sampler = WeightedSamplerDataset::class_balanced(
+            dataset,
+            |(_, majority)| *majority,
+            SamplerDatasetOptions::default()
+                .with_fixed_size(2000)
+                .with_seed(7),
+        );
+
+        let minority = sampler.iter().filter(|(_, majority)| !majority).count();
+
+        assert_eq!(sampler.weights()[0], 1.0 / 90.0);
+        assert_eq!(sampler.weights()[99], 1.0 / 10.0);
+        assert!((850..1150).contains(&minority), "{minority} minority items");
+    }
+
+    #[test]
+    fn weighted_sampler_without_replacement_should_draw_distinct_weighted_items() {
+        let dataset = InMemDataset::new(vec![0, 1, 2, 3]);
+        let sampler = WeightedSamplerDataset::new(
+            dataset,
+            vec![1.0, 1.0, 0.0, 8.0],
+            SamplerDatasetOptions::default()
+                .without_replacement()
+                .with_fixed_size(2)
+                .with_seed(3),
+        );
+
+        let mut counts = [0; 4];
+        for _ in 0..500 {
+            let items = sampler.iter().collect::<Vec<_>>();
+
+            assert_eq!(items.len(), 2);
+            assert_ne!(items[0], items[1]);
+            items.iter().for_each(|item| counts[*item] += 1);
+        }
+
+        assert_eq!(counts[2], 0);
+        assert!(
+            counts[3] > 400,
+            "{} cycles with the heaviest item",
+            counts[3]
+        );
+        assert!(
+            counts[0] < 350 && counts[1] < 350,
+            "{counts:?} items drawn over 500 cycles"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "the weights have no effect")]
+    fn weighted_sampler_without_replacement_should_reject_default_size() {
+        let dataset = InMemDataset::new(vec![0, 1, 2, 3]);
+
+        WeightedSamplerDataset::new(
+            dataset,
+            vec![1.0, 2.0, 3.0, 4.0],
+            SamplerDatasetOptions::default().without_replacement(),
+        );
+    }
+}
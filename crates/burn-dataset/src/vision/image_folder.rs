@@ -76,6 +76,8 @@ pub enum Annotation {
 /// Segmentation mask annotation.
 /// For semantic segmentation, a mask has a single channel (C = 1).
 /// For instance segmentation, there may be multiple masks per image (C >= 1).
+///
+/// The channels are stored one after the other, each in row-major order.
 #[derive(Debug, Clone, PartialEq)]
 pub struct SegmentationMask {
     /// Segmentation mask.
@@ -118,6 +120,26 @@ enum AnnotationRaw {
     MultiLabel(Vec<String>),
     BoundingBoxes(Vec<BoundingBox>),
     SegmentationMask(PathBuf),
+    InstanceMasks {
+        width: usize,
+        height: usize,
+        instances: Vec<InstanceRaw>,
+    },
+}
+
+/// Raw instance segmentation, decoded when the item is loaded.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+enum SegmentationRaw {
+    /// Polygons with flattened `[x1, y1, x2, y2, ...]` coordinates.
+    Polygons(Vec<Vec<f32>>),
+    /// Run-length encoded counts in column-major order, starting with the background.
+    Rle(Vec<usize>),
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+struct InstanceRaw {
+    segmentation: SegmentationRaw,
+    label: usize,
 }
 
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -193,7 +215,172 @@ fn parse_image_annotation(
             })
         }
         AnnotationRaw::BoundingBoxes(v) => Annotation::BoundingBoxes(v.clone()),
+        AnnotationRaw::InstanceMasks {
+            width,
+            height,
+            instances,
+        } => Annotation::SegmentationMask(SegmentationMask {
+            mask: instances
+                .iter()
+                .flat_map(|instance| instance_mask(instance, *width, *height))
+                .collect(),
+        }),
+    }
+}
+
+/// Decode an instance segmentation to a mask with the instance label, and zero elsewhere.
+fn instance_mask(instance: &InstanceRaw, width: usize, height: usize) -> Vec<usize> {
+    let mut mask = vec![0; width * height];
+
+    match &instance.segmentation {
+        SegmentationRaw::Polygons(polygons) => {
+            // A pixel belongs to the instance when its center is inside a polygon (even-odd rule)
+            for y in 0..height {
+                for x in 0..width {
+                    let (px, py) = (x as f32 + 0.5, y as f32 + 0.5);
+                    let inside = polygons
+                        .iter()
+                        .any(|polygon| polygon_contains(polygon, px, py));
+                    if inside {
+                        mask[y * width + x] = instance.label;
+                    }
+                }
+            }
+        }
+        SegmentationRaw::Rle(counts) => {
+            let mut position = 0;
+            for (i, count) in counts.iter().enumerate() {
+                let end = (position + count).min(width * height);
+                // Odd runs are the foreground
+                if i % 2 == 1 {
+                    for p in position..end {
+                        let (x, y) = (p / height, p % height);
+                        mask[y * width + x] = instance.label;
+                    }
+                }
+                position = end;
+            }
+        }
+    }
+
+    mask
+}
+
+/// Check if a point is inside a polygon with flattened `[x1, y1, x2, y2, ...]` coordinates.
+fn polygon_contains(polygon: &[f32], px: f32, py: f32) -> bool {
+    let points = polygon.chunks_exact(2).collect::<Vec<_>>();
+    let mut inside = false;
+
+    for (i, current) in points.iter().enumerate() {
+        let previous = points[(i + points.len() - 1) % points.len()];
+        let (x1, y1) = (current[0], current[1]);
+        let (x2, y2) = (previous[0], previous[1]);
+
+        if (y1 > py) != (y2 > py) && px < (x2 - x1) * (py - y1) / (y2 - y1) + x1 {
+            inside = !inside;
+        }
+    }
+
+    inside
+}
+
+/// Decode the compressed run-length encoding counts of the COCO API.
+fn decode_coco_rle(encoded: &str) -> Result<Vec<usize>, ImageLoaderError> {
+    let invalid = || ImageLoaderError::ParsingError(format!("Invalid RLE counts: {encoded}"));
+    let mut bytes = encoded.bytes().peekable();
+    let mut counts: Vec<i64> = Vec::new();
+
+    while bytes.peek().is_some() {
+        let mut value = 0i64;
+        let mut shift = 0;
+        loop {
+            let byte = bytes.next().ok_or_else(invalid)?;
+            let chunk = byte
+                .checked_sub(48)
+                .filter(|c| *c < 64)
+                .ok_or_else(invalid)? as i64;
+            if shift >= 60 {
+                return Err(invalid());
+            }
+
+            value |= (chunk & 0x1f) << shift;
+            shift += 5;
+            if chunk & 0x20 == 0 {
+                // Sign extension of negative deltas
+                if chunk & 0x10 != 0 {
+                    value |= -1 << shift;
+                }
+                break;
+            }
+        }
+
+        // Counts after the first two are encoded as the delta with the count two runs before
+        if counts.len() > 2 {
+            value += counts[counts.len() - 2];
+        }
+        counts.push(value);
     }
+
+    counts
+        .into_iter()
+        .map(|count| usize::try_from(count).map_err(|_| invalid()))
+        .collect()
+}
+
+/// Parse the segmentation of a COCO annotation, either polygons or a run-length encoding.
+fn parse_coco_segmentation(
+    segmentation: &Value,
+    width: usize,
+    height: usize,
+) -> Result<SegmentationRaw, ImageLoaderError> {
+    if let Some(polygons) = segmentation.as_array() {
+        let polygons = polygons
+            .iter()
+            .map(|polygon| {
+                polygon
+                    .as_array()
+                    .ok_or_else(|| ImageLoaderError::ParsingError("Invalid polygon".to_string()))?
+                    .iter()
+                    .map(|v| {
+                        v.as_f64().map(|v| v as f32).ok_or_else(|| {
+                            ImageLoaderError::ParsingError("Invalid polygon coordinate".to_string())
+                        })
+                    })
+                    .collect::<Result<Vec<f32>, _>>()
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        return Ok(SegmentationRaw::Polygons(polygons));
+    }
+
+    let size = segmentation["size"]
+        .as_array()
+        .map(|size| size.iter().filter_map(Value::as_u64).collect::<Vec<_>>())
+        .ok_or_else(|| ImageLoaderError::ParsingError("Missing RLE size".to_string()))?;
+    if size != [height as u64, width as u64] {
+        return Err(ImageLoaderError::ParsingError(format!(
+            "RLE size {size:?} does not match the image size [{height}, {width}]"
+        )));
+    }
+
+    let counts = match &segmentation["counts"] {
+        Value::String(encoded) => decode_coco_rle(encoded)?,
+        Value::Array(counts) => counts
+            .iter()
+            .map(|v| {
+                v.as_u64()
+                    .map(|v| v as usize)
+                    .ok_or_else(|| ImageLoaderError::ParsingError("Invalid RLE count".to_string()))
+            })
+            .collect::<Result<Vec<_>, _>>()?,
+        _ => {
+            return Err(ImageLoaderError::ParsingError(
+                "Missing RLE counts".to_string(),
+            ));
+        }
+    };
+
+    Ok(SegmentationRaw::Rle(counts))
 }
 
 /// Retrieve all available classes from the COCO JSON
@@ -306,6 +493,211 @@ fn parse_coco_bbox_annotations(
     Ok(annotations)
 }
 
+/// Retrieve instance segmentation annotations from COCO JSON
+fn parse_coco_mask_annotations(
+    json: &serde_json::Value,
+) -> Result<HashMap<u64, AnnotationRaw>, ImageLoaderError> {
+    let mut annotations = HashMap::new();
+
+    // Every image has a mask annotation, even without instances
+    if let Some(json_images) = json["images"].as_array() {
+        for image in json_images {
+            let image_id = image["id"].as_u64().ok_or_else(|| {
+                ImageLoaderError::ParsingError("Invalid image ID in image list".to_string())
+            })?;
+            let (width, height) = match (image["width"].as_u64(), image["height"].as_u64()) {
+                (Some(width), Some(height)) => (width as usize, height as usize),
+                _ => {
+                    return Err(ImageLoaderError::ParsingError(format!(
+                        "Missing size of image {image_id}"
+                    )));
+                }
+            };
+
+            annotations.insert(
+                image_id,
+                AnnotationRaw::InstanceMasks {
+                    width,
+                    height,
+                    instances: Vec::new(),
+                },
+            );
+        }
+    }
+
+    if let Some(json_annotations) = json["annotations"].as_array() {
+        for annotation in json_annotations {
+            let image_id = annotation["image_id"].as_u64().ok_or_else(|| {
+                ImageLoaderError::ParsingError("Invalid image ID in annotation".into())
+            })?;
+
+            let label = annotation["category_id"]
+                .as_u64()
+                .ok_or_else(|| {
+                    ImageLoaderError::ParsingError("Invalid class ID in annotations".to_string())
+                })
+                .and_then(|v| {
+                    usize::try_from(v).map_err(|_| {
+                        ImageLoaderError::ParsingError(
+                            "Class ID in annotations out of usize range".to_string(),
+                        )
+                    })
+                })?;
+
+            let Some(AnnotationRaw::InstanceMasks {
+                width,
+                height,
+                instances,
+            }) = annotations.get_mut(&image_id)
+            else {
+                return Err(ImageLoaderError::ParsingError(format!(
+                    "Annotation for unknown image {image_id}"
+                )));
+            };
+
+            let segmentation =
+                parse_coco_segmentation(&annotation["segmentation"], *width, *height)?;
+            instances.push(InstanceRaw {
+                segmentation,
+                label,
+            });
+        }
+    }
+
+    Ok(annotations)
+}
+
+/// Retrieve the bounding boxes of a YOLO label file, with normalized
+/// `class x_center y_center width height` lines.
+fn parse_yolo_annotation(
+    label_path: &Path,
+    image_width: usize,
+    image_height: usize,
+    num_classes: usize,
+) -> Result<Vec<BoundingBox>, ImageLoaderError> {
+    // Images without label file have no objects
+    if !label_path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let content = fs::read_to_string(label_path).map_err(|e| {
+        ImageLoaderError::IOError(format!("Failed to read {}: {e}", label_path.display()))
+    })?;
+
+    content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let invalid = || {
+                ImageLoaderError::ParsingError(format!(
+                    "Invalid YOLO label in {}: {line}",
+                    label_path.display()
+                ))
+            };
+
+            let values = line.split_whitespace().collect::<Vec<_>>();
+            if values.len() != BBOX_MIN_NUM_VALUES + 1 {
+                return Err(invalid());
+            }
+
+            let label = values[0].parse::<usize>().map_err(|_| invalid())?;
+            if label >= num_classes {
+                return Err(ImageLoaderError::ParsingError(format!(
+                    "Class ID {label} out of range in {}",
+                    label_path.display()
+                )));
+            }
+
+            let coords = values[1..]
+                .iter()
+                .map(|v| v.parse::<f32>().map_err(|_| invalid()))
+                .collect::<Result<Vec<_>, _>>()?;
+            let (width, height) = (image_width as f32, image_height as f32);
+            let (box_width, box_height) = (coords[2] * width, coords[3] * height);
+
+            Ok(BoundingBox {
+                coords: [
+                    coords[0] * width - box_width / 2.0,
+                    coords[1] * height - box_height / 2.0,
+                    box_width,
+                    box_height,
+                ],
+                label,
+            })
+        })
+        .collect()
+}
+
+/// Retrieve the image file name and the bounding boxes of a Pascal VOC XML annotation.
+fn parse_voc_annotation(
+    annotation_path: &Path,
+    classes: &HashMap<String, usize>,
+) -> Result<(String, Vec<BoundingBox>), ImageLoaderError> {
+    let content = fs::read_to_string(annotation_path).map_err(|e| {
+        ImageLoaderError::IOError(format!("Failed to read {}: {e}", annotation_path.display()))
+    })?;
+    let document = roxmltree::Document::parse(&content).map_err(|e| {
+        ImageLoaderError::ParsingError(format!(
+            "Failed to parse {}: {e}",
+            annotation_path.display()
+        ))
+    })?;
+
+    let child_text = |node: roxmltree::Node, tag: &str| {
+        node.children()
+            .find(|child| child.has_tag_name(tag))
+            .and_then(|child| child.text())
+            .map(str::trim)
+            .ok_or_else(|| {
+                ImageLoaderError::ParsingError(format!(
+                    "Missing <{tag}> in {}",
+                    annotation_path.display()
+                ))
+            })
+    };
+
+    let root = document.root_element();
+    let file_name = child_text(root, "filename")?.to_string();
+
+    let mut bboxes = Vec::new();
+    for object in root.children().filter(|node| node.has_tag_name("object")) {
+        let name = child_text(object, "name")?;
+        let label = *classes.get(name).ok_or_else(|| {
+            ImageLoaderError::ParsingError(format!(
+                "Unknown class {name} in {}",
+                annotation_path.display()
+            ))
+        })?;
+
+        let bndbox = object
+            .children()
+            .find(|child| child.has_tag_name("bndbox"))
+            .ok_or_else(|| {
+                ImageLoaderError::ParsingError(format!(
+                    "Missing <bndbox> in {}",
+                    annotation_path.display()
+                ))
+            })?;
+        let coord = |tag: &str| {
+            child_text(bndbox, tag)?.parse::<f32>().map_err(|_| {
+                ImageLoaderError::ParsingError(format!(
+                    "Invalid <{tag}> in {}",
+                    annotation_path.display()
+                ))
+            })
+        };
+        let (x_min, y_min) = (coord("xmin")?, coord("ymin")?);
+        let (x_max, y_max) = (coord("xmax")?, coord("ymax")?);
+
+        bboxes.push(BoundingBox {
+            coords: [x_min, y_min, x_max - x_min, y_max - y_min],
+            label,
+        });
+    }
+
+    Ok((file_name, bboxes))
+}
+
 /// Retrieve all available images from the COCO JSON
 fn parse_coco_images<P: AsRef<Path>>(
     images_path: &P,
@@ -676,6 +1068,158 @@ impl ImageFolderDataset {
         Ok(Self { dataset })
     }
 
+    /// Create a COCO instance segmentation dataset based on the annotations JSON and image
+    /// directory.
+    ///
+    /// The polygons and run-length encodings (RLE) of the instances are decoded to a
+    /// [segmentation mask](SegmentationMask) with one channel per instance, where the pixels of
+    /// the instance have the value of its category ID and the others are zero.
+    ///
+    /// # Arguments
+    ///
+    /// * `annotations_json` - Path to the JSON file containing annotations in COCO format (for
+    ///   example instances_train2017.json).
+    ///
+    /// * `images_path` - Path containing the images matching the annotations JSON.
+    ///
+    /// # Returns
+    /// A new dataset instance.
+    pub fn new_coco_instance_segmentation<A: AsRef<Path>, I: AsRef<Path>>(
+        annotations_json: A,
+        images_path: I,
+    ) -> Result<Self, ImageLoaderError> {
+        let file = fs::File::open(annotations_json)
+            .map_err(|e| ImageLoaderError::IOError(format!("Failed to open annotations: {e}")))?;
+        let json: Value = serde_json::from_reader(file).map_err(|e| {
+            ImageLoaderError::ParsingError(format!("Failed to parse annotations: {e}"))
+        })?;
+
+        let classes = parse_coco_classes(&json)?;
+        let annotations = parse_coco_mask_annotations(&json)?;
+        let items = parse_coco_images(&images_path, annotations, &json)?;
+        let dataset = InMemDataset::new(items);
+        let mapper = PathToImageDatasetItem { classes };
+        let dataset = MapperDataset::new(dataset, mapper);
+
+        Ok(Self { dataset })
+    }
+
+    /// Create a YOLO detection dataset based on the images and labels directories.
+    ///
+    /// Each image has a label file with the same relative path and a `txt` extension, where each
+    /// line is a `class x_center y_center width height` box normalized by the image size. Images
+    /// without label file have no objects.
+    ///
+    /// # Arguments
+    ///
+    /// * `images_path` - Path containing the images.
+    /// * `labels_path` - Path containing the label files.
+    /// * `classes` - Dataset class names, in the order of the class IDs.
+    ///
+    /// # Returns
+    /// A new dataset instance.
+    pub fn new_yolo_detection<I, L, S>(
+        images_path: I,
+        labels_path: L,
+        classes: &[S],
+    ) -> Result<Self, ImageLoaderError>
+    where
+        I: AsRef<Path>,
+        L: AsRef<Path>,
+        S: AsRef<str>,
+    {
+        let images_path = images_path.as_ref();
+        let pattern = format!("*.{{{}}}", SUPPORTED_FILES.join(","));
+
+        let mut items = Vec::new();
+        for image_path in Self::walk_files(images_path, &pattern)? {
+            let (width, height) = image::image_dimensions(&image_path).map_err(|e| {
+                ImageLoaderError::IOError(format!("Failed to read {}: {e}", image_path.display()))
+            })?;
+
+            let relative_path = image_path
+                .strip_prefix(images_path)
+                .map_err(|e| ImageLoaderError::IOError(e.to_string()))?;
+            let label_path = labels_path
+                .as_ref()
+                .join(relative_path)
+                .with_extension("txt");
+            let bboxes =
+                parse_yolo_annotation(&label_path, width as usize, height as usize, classes.len())?;
+
+            items.push(ImageDatasetItemRaw::new(
+                image_path,
+                AnnotationRaw::BoundingBoxes(bboxes),
+            ));
+        }
+
+        Self::with_items(items, classes)
+    }
+
+    /// Create a Pascal VOC detection dataset based on the XML annotations and images directories.
+    ///
+    /// # Arguments
+    ///
+    /// * `annotations_path` - Path containing the XML annotations, one per image.
+    /// * `images_path` - Path containing the images named in the annotations.
+    /// * `classes` - Dataset class names, the box labels being their index.
+    ///
+    /// # Returns
+    /// A new dataset instance.
+    pub fn new_voc_detection<A, I, S>(
+        annotations_path: A,
+        images_path: I,
+        classes: &[S],
+    ) -> Result<Self, ImageLoaderError>
+    where
+        A: AsRef<Path>,
+        I: AsRef<Path>,
+        S: AsRef<str>,
+    {
+        let classes_map = classes
+            .iter()
+            .enumerate()
+            .map(|(idx, cls)| (cls.as_ref().to_string(), idx))
+            .collect();
+
+        let mut items = Vec::new();
+        for annotation_path in Self::walk_files(annotations_path, "*.xml")? {
+            let (file_name, bboxes) = parse_voc_annotation(&annotation_path, &classes_map)?;
+
+            let image_path = images_path.as_ref().join(file_name);
+            if !image_path.exists() {
+                return Err(ImageLoaderError::IOError(format!(
+                    "Image {} not found",
+                    image_path.display()
+                )));
+            }
+
+            items.push(ImageDatasetItemRaw::new(
+                image_path,
+                AnnotationRaw::BoundingBoxes(bboxes),
+            ));
+        }
+
+        Self::with_items(items, classes)
+    }
+
+    /// List the files matching the pattern in the root folder, ordered by path.
+    fn walk_files<P: AsRef<Path>>(
+        root: P,
+        pattern: &str,
+    ) -> Result<Vec<PathBuf>, ImageLoaderError> {
+        let walker = globwalk::GlobWalkerBuilder::from_patterns(root.as_ref(), &[pattern])
+            .follow_links(true)
+            .sort_by(|p1: &DirEntry, p2: &DirEntry| p1.path().cmp(p2.path()))
+            .build()
+            .map_err(|err| ImageLoaderError::Unknown(format!("{err:?}")))?;
+
+        Ok(walker
+            .filter_map(Result::ok)
+            .map(|entry| entry.path().to_path_buf())
+            .collect())
+    }
+
     /// Create an image dataset with the specified items.
     ///
     /// # Arguments
@@ -729,6 +1273,9 @@ mod tests {
     const SEGMASK_ROOT: &str = "tests/data/segmask_folder";
     const COCO_JSON: &str = "tests/data/dataset_coco.json";
     const COCO_IMAGES: &str = "tests/data/image_folder_coco";
+    const COCO_SEGMENTATION_JSON: &str = "tests/data/dataset_coco_segmentation.json";
+    const YOLO_LABELS: &str = "tests/data/yolo_labels";
+    const VOC_ANNOTATIONS: &str = "tests/data/voc_annotations";
 
     #[test]
     pub fn image_folder_dataset() {
@@ -1121,4 +1668,141 @@ mod tests {
             }
         }
     }
+
+    fn bboxes_by_file_name(dataset: &ImageFolderDataset) -> HashMap<String, Vec<BoundingBox>> {
+        dataset
+            .iter()
+            .map(|item| {
+                let file_name = Path::new(&item.image_path)
+                    .file_name()
+                    .unwrap()
+                    .to_string_lossy()
+                    .into_owned();
+                match item.annotation {
+                    Annotation::BoundingBoxes(v) => (file_name, v),
+                    _ => panic!("unexpected annotation"),
+                }
+            })
+            .collect()
+    }
+
+    fn assert_bbox_approx_eq(actual: &BoundingBox, expected: &BoundingBox, tolerance: f32) {
+        assert_eq!(actual.label, expected.label);
+        for (a, e) in actual.coords.iter().zip(expected.coords.iter()) {
+            assert!((a - e).abs() <= tolerance, "{actual:?} != {expected:?}");
+        }
+    }
+
+    #[test]
+    pub fn yolo_detection_dataset() {
+        let dataset =
+            ImageFolderDataset::new_yolo_detection(COCO_IMAGES, YOLO_LABELS, &["dot", "triangle"])
+                .unwrap();
+        assert_eq!(dataset.len(), 3);
+
+        let bboxes = bboxes_by_file_name(&dataset);
+
+        // Same boxes as the COCO annotations, normalized with 6 decimals
+        let two_dots_and_triangle = &bboxes["two_dots_and_triangle.jpg"];
+        assert_eq!(two_dots_and_triangle.len(), 3);
+        assert_bbox_approx_eq(
+            &two_dots_and_triangle[2],
+            &BoundingBox {
+                coords: [15.097_662, 3.389_271, 12.632_737, 11.180_193],
+                label: 1,
+            },
+            1e-3,
+        );
+        assert_eq!(bboxes["dot_triangle.jpg"].len(), 2);
+        // No label file
+        assert!(bboxes["one_dot.jpg"].is_empty());
+    }
+
+    #[test]
+    pub fn yolo_detection_dataset_invalid_class() {
+        let result = ImageFolderDataset::new_yolo_detection(COCO_IMAGES, YOLO_LABELS, &["dot"]);
+        assert!(matches!(result, Err(ImageLoaderError::ParsingError(_))));
+    }
+
+    #[test]
+    pub fn voc_detection_dataset() {
+        let dataset = ImageFolderDataset::new_voc_detection(
+            VOC_ANNOTATIONS,
+            COCO_IMAGES,
+            &["dot", "triangle"],
+        )
+        .unwrap();
+        assert_eq!(dataset.len(), 3);
+
+        let bboxes = bboxes_by_file_name(&dataset);
+
+        assert_eq!(
+            bboxes["two_dots_and_triangle.jpg"],
+            vec![
+                BoundingBox {
+                    coords: [3.0, 18.0, 11.0, 11.0],
+                    label: 0,
+                },
+                BoundingBox {
+                    coords: [3.0, 3.0, 11.0, 11.0],
+                    label: 0,
+                },
+                BoundingBox {
+                    coords: [15.0, 3.0, 13.0, 12.0],
+                    label: 1,
+                },
+            ]
+        );
+        assert_eq!(bboxes["dot_triangle.jpg"].len(), 2);
+        assert_eq!(bboxes["one_dot.jpg"].len(), 1);
+    }
+
+    #[test]
+    pub fn coco_instance_segmentation_dataset() {
+        let dataset =
+            ImageFolderDataset::new_coco_instance_segmentation(COCO_SEGMENTATION_JSON, COCO_IMAGES)
+                .unwrap();
+        assert_eq!(dataset.len(), 3);
+
+        let mask = |index: usize| match dataset.get(index).unwrap().annotation {
+            Annotation::SegmentationMask(mask) => mask.mask,
+            _ => panic!("unexpected annotation"),
+        };
+
+        // Polygon square from (2, 2) to (6, 6) of category 1
+        let polygon = mask(0);
+        assert_eq!(polygon.len(), 32 * 32);
+        assert_eq!(polygon.iter().filter(|&&v| v == 1).count(), 16);
+        assert_eq!(polygon.iter().filter(|&&v| v != 0 && v != 1).count(), 0);
+        assert_eq!(polygon[2 * 32 + 2], 1);
+        assert_eq!(polygon[5 * 32 + 5], 1);
+        assert_eq!(polygon[6 * 32 + 6], 0);
+
+        // Same 3x4 block from (2, 2), uncompressed RLE of category 2 and compressed RLE of
+        // category 1
+        let rle = mask(1);
+        assert_eq!(rle.len(), 2 * 32 * 32);
+        let (uncompressed, compressed) = rle.split_at(32 * 32);
+        let block = (2..6)
+            .flat_map(|y| (2..5).map(move |x| y * 32 + x))
+            .collect::<Vec<_>>();
+        for (i, (u, c)) in uncompressed.iter().zip(compressed).enumerate() {
+            let inside = block.contains(&i);
+            assert_eq!(*u, if inside { 2 } else { 0 });
+            assert_eq!(*c, if inside { 1 } else { 0 });
+        }
+
+        // No instances
+        assert!(mask(2).is_empty());
+    }
+
+    #[test]
+    pub fn decode_coco_rle_counts() {
+        assert_eq!(
+            decode_coco_rle("R24l0000nj0").unwrap(),
+            vec![66, 4, 28, 4, 28, 4, 890]
+        );
+        // Truncated count
+        assert!(decode_coco_rle("R").is_err());
+    }
 }
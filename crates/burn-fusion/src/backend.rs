@@ -3,7 +3,7 @@ use crate::{
     client::FusionClient,
     stream::{Context, OrderedExecution},
 };
-use burn_ir::{BackendIr, OperationIr, TensorHandle};
+use burn_ir::{BackendIr, OperationIr, TensorHandle, optim::PassPipeline};
 use burn_tensor::{
     Device, Element,
     backend::{Backend, DeviceOps},
@@ -177,6 +177,15 @@ pub trait FusionRuntime: Send + Sync + Sized + core::fmt::Debug {
     fn optimizations(
         device: Self::FusionDevice,
     ) -> Vec<Box<dyn OptimizationBuilder<Self::Optimization>>>;
+
+    /// The graph-level passes used to rewrite operations before they are enqueued on a stream.
+    ///
+    /// No pass is applied unless the runtime opts in, usually with the
+    /// [default](PassPipeline::default) pipeline. Since streams are executed lazily, only the
+    /// rewrites [forwarding](PassPipeline::forward) existing tensors are applied.
+    fn passes(_device: &Self::FusionDevice) -> PassPipeline {
+        PassPipeline::empty()
+    }
 }
 
 /// Trait that allows an existing [backend](Backend) to specify graph optimizations using
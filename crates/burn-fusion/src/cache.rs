@@ -0,0 +1,14 @@
+use burn_tensor::Device;
+
+use crate::{FusionBackend, client::FusionClient, get_client};
+
+/// Execute the operations queued on the current stream of the given device and persist the
+/// execution plans found so far.
+///
+/// Plans are also saved when streams are synced, at most once every few seconds, so calling this
+/// function before exiting makes sure the plans found at the end of a run are kept.
+///
+/// See `BURN_FUSION_CACHE_DIR` to change where the plans are saved.
+pub fn flush<B: FusionBackend>(device: &Device<B>) {
+    get_client::<B>(device).flush_cache()
+}
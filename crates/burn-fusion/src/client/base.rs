@@ -20,6 +20,12 @@ where
         O: Operation<R> + 'static;
     /// Register all lazy computation.
     fn drain(&self);
+    /// Dump the state of the fusion server for visualization.
+    #[cfg(feature = "dump")]
+    fn dump(&self) -> crate::dump::FusionDump;
+    /// Register all lazy computation and persist the execution plans found so far.
+    #[cfg(feature = "cache")]
+    fn flush_cache(&self);
     /// Get the current device used by all operations handled by this client.
     fn device(&self) -> &FusionDevice<R>;
     /// Create a new [fusion tensor](FusionTensor), but with no resources allocated to it.
@@ -51,6 +51,17 @@ where
         self.server.lock().drain_stream(id);
     }
 
+    #[cfg(feature = "dump")]
+    fn dump(&self) -> crate::dump::FusionDump {
+        self.server.lock().dump()
+    }
+
+    #[cfg(feature = "cache")]
+    fn flush_cache(&self) {
+        let id = StreamId::current();
+        self.server.lock().flush_cache(id);
+    }
+
     fn tensor_uninitialized(&self, shape: Vec<usize>, dtype: DType) -> FusionTensor<R> {
         let id = self.server.lock().create_empty_handle();
 
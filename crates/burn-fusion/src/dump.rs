@@ -0,0 +1,256 @@
+use std::path::Path;
+
+use burn_common::graph::Graph;
+use burn_ir::{OperationIr, TensorId, TensorStatus};
+use burn_tensor::Device;
+use hashbrown::HashMap;
+
+use crate::{
+    FusionBackend, NumOperations,
+    client::FusionClient,
+    get_client,
+    stream::{
+        StreamId,
+        store::{ExecutionPlan, ExecutionStrategy, ExecutionTrigger},
+    },
+};
+
+/// The state of the fusion server for a device, exported for visualization.
+#[derive(Debug, Clone)]
+pub struct FusionDump {
+    /// The operations registered on each stream that are not executed yet.
+    pub streams: Graph,
+    /// The execution plans found so far, grouped by the optimization they are executed with.
+    pub plans: Graph,
+}
+
+impl FusionDump {
+    /// Save the dump in the given directory, both in the DOT and JSON formats.
+    pub fn save(&self, directory: impl AsRef<Path>) -> std::io::Result<()> {
+        let directory = directory.as_ref();
+        std::fs::create_dir_all(directory)?;
+
+        for (name, graph) in [("streams", &self.streams), ("plans", &self.plans)] {
+            graph.save(directory.join(format!("{name}.dot")))?;
+            graph.save(directory.join(format!("{name}.json")))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Dump the state of the fusion server of the given device.
+pub fn dump<B: FusionBackend>(device: &Device<B>) -> FusionDump {
+    get_client::<B>(device).dump()
+}
+
+/// Build a graph from the operations queued on each stream.
+pub(crate) fn streams_graph<'a>(
+    streams: impl Iterator<Item = (&'a StreamId, &'a [OperationIr])>,
+) -> Graph {
+    let mut builder = GraphBuilder::new("streams");
+
+    for (id, operations) in streams {
+        let cluster = builder.cluster(format!("stream {id:?}"), None);
+        let mut flow = TensorFlow::default();
+
+        for operation in operations {
+            builder.operation(operation, Some(cluster), &mut flow);
+        }
+    }
+
+    builder.graph
+}
+
+/// Build a graph from the execution plans, showing which operations are executed together.
+pub(crate) fn plans_graph<O: NumOperations>(plans: &[ExecutionPlan<O>]) -> Graph {
+    let mut builder = GraphBuilder::new("plans");
+
+    for (id, plan) in plans.iter().enumerate() {
+        let triggers = plan
+            .triggers
+            .iter()
+            .map(|trigger| match trigger {
+                ExecutionTrigger::OnOperations(ops) => format!("on {} operations", ops.len()),
+                ExecutionTrigger::OnSync => "on sync".into(),
+                ExecutionTrigger::Always => "always".into(),
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        let cluster = builder.cluster(format!("plan {id} ({triggers})"), None);
+        let mut flow = TensorFlow::default();
+
+        builder.strategy(
+            &plan.operations,
+            &plan.optimization.strategy,
+            cluster,
+            &mut flow,
+        );
+    }
+
+    builder.graph
+}
+
+struct GraphBuilder {
+    graph: Graph,
+    num_nodes: u64,
+    num_clusters: u64,
+}
+
+/// Keep track of the node producing each tensor.
+#[derive(Default)]
+struct TensorFlow {
+    producers: HashMap<TensorId, u64>,
+}
+
+impl GraphBuilder {
+    fn new(name: &str) -> Self {
+        Self {
+            graph: Graph::new(name),
+            num_nodes: 0,
+            num_clusters: 0,
+        }
+    }
+
+    fn cluster(&mut self, label: String, parent: Option<u64>) -> u64 {
+        let id = self.num_clusters;
+        self.num_clusters += 1;
+        self.graph.cluster(id, label, parent);
+        id
+    }
+
+    fn operation(&mut self, operation: &OperationIr, cluster: Option<u64>, flow: &mut TensorFlow) {
+        let id = self.num_nodes;
+        self.num_nodes += 1;
+
+        let nodes = operation.nodes();
+        let mut label = operation_name(operation);
+
+        for node in nodes.iter() {
+            if node.status == TensorStatus::NotInit {
+                label += &format!("\n{:?} {:?}", node.shape, node.dtype);
+            }
+        }
+
+        let kind = match operation {
+            OperationIr::Drop(_) => "drop",
+            _ => "operation",
+        };
+        self.graph.node(id, label, kind, cluster);
+
+        for node in nodes {
+            match node.status {
+                TensorStatus::NotInit => {
+                    flow.producers.insert(node.id, id);
+                }
+                TensorStatus::ReadOnly | TensorStatus::ReadWrite => {
+                    if let Some(producer) = flow.producers.get(&node.id) {
+                        self.graph.edge(*producer, id, Some(node.id.to_string()));
+                    }
+                }
+            }
+        }
+    }
+
+    fn strategy<O: NumOperations>(
+        &mut self,
+        operations: &[OperationIr],
+        strategy: &ExecutionStrategy<O>,
+        parent: u64,
+        flow: &mut TensorFlow,
+    ) {
+        match strategy {
+            ExecutionStrategy::Optimization { opt, ordering } => {
+                let label = format!("{} ({} operations)", variant(opt), opt.len());
+                let cluster = self.cluster(label, Some(parent));
+
+                for index in ordering.iter() {
+                    self.operation(&operations[*index], Some(cluster), flow);
+                }
+            }
+            ExecutionStrategy::Operations { ordering } => {
+                let cluster = self.cluster("not fused".into(), Some(parent));
+
+                for index in ordering.iter() {
+                    self.operation(&operations[*index], Some(cluster), flow);
+                }
+            }
+            ExecutionStrategy::Composed(strategies) => {
+                for strategy in strategies {
+                    self.strategy(operations, strategy, parent, flow);
+                }
+            }
+        }
+    }
+}
+
+/// The name of the operation, e.g. `Float::Exp`.
+fn operation_name(operation: &OperationIr) -> String {
+    match operation {
+        OperationIr::BaseFloat(op) | OperationIr::BaseInt(op) | OperationIr::BaseBool(op) => {
+            format!("Base::{}", variant(op))
+        }
+        OperationIr::NumericFloat(_, op) => format!("Numeric::{}", variant(op)),
+        OperationIr::NumericInt(_, op) => format!("Numeric::{}", variant(op)),
+        OperationIr::Bool(op) => format!("Bool::{}", variant(op)),
+        OperationIr::Int(op) => format!("Int::{}", variant(op)),
+        OperationIr::Float(_, op) => format!("Float::{}", variant(op)),
+        OperationIr::Module(op) => format!("Module::{}", variant(op)),
+        OperationIr::Init(_) => "Init".into(),
+        OperationIr::Custom(op) => format!("Custom::{}", op.id),
+        OperationIr::Drop(_) => "Drop".into(),
+    }
+}
+
+/// The name of the enum variant, taken from its debug representation.
+fn variant<T: core::fmt::Debug>(value: &T) -> String {
+    let debug = format!("{value:?}");
+
+    debug
+        .split(['(', ' ', '{'])
+        .next()
+        .unwrap_or_default()
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn_ir::{FloatOperationIr, TensorIr, UnaryOpIr};
+    use burn_tensor::DType;
+
+    #[test]
+    fn should_link_operations_through_tensors() {
+        let operations = [
+            exp(
+                tensor(0, TensorStatus::ReadOnly),
+                tensor(1, TensorStatus::NotInit),
+            ),
+            exp(
+                tensor(1, TensorStatus::ReadWrite),
+                tensor(2, TensorStatus::NotInit),
+            ),
+        ];
+        let stream = StreamId::current();
+
+        let graph = streams_graph([(&stream, operations.as_slice())].into_iter());
+
+        assert_eq!(graph.nodes.len(), 2);
+        assert_eq!(graph.nodes[0].label, "Float::Exp\n[2, 2] F32");
+        assert_eq!(graph.edges.len(), 1);
+        assert_eq!((graph.edges[0].from, graph.edges[0].to), (0, 1));
+    }
+
+    fn tensor(id: u64, status: TensorStatus) -> TensorIr {
+        TensorIr {
+            id: TensorId::new(id),
+            shape: vec![2, 2],
+            status,
+            dtype: DType::F32,
+        }
+    }
+
+    fn exp(input: TensorIr, out: TensorIr) -> OperationIr {
+        OperationIr::Float(DType::F32, FloatOperationIr::Exp(UnaryOpIr { input, out }))
+    }
+}
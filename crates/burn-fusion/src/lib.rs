@@ -14,6 +14,14 @@ pub mod client;
 /// Stream module exposing all tensor operations that can be optimized.
 pub mod stream;
 
+/// Dump the fusion state of a device for visualization.
+#[cfg(feature = "dump")]
+pub mod dump;
+
+/// Persist the execution plans found on a device between processes.
+#[cfg(feature = "cache")]
+pub mod cache;
+
 /// Search module for stream optimizations.
 pub(crate) mod search;
 
@@ -9,7 +9,7 @@ use burn_tensor::{
     ops::{
         ConvOptions, ConvTransposeOptions, DeformConv2dBackward, DeformConvOptions, FloatTensor,
         IntTensor, InterpolateOptions, MaxPool1dBackward, MaxPool1dWithIndices, MaxPool2dBackward,
-        MaxPool2dWithIndices, ModuleOps,
+        MaxPool2dWithIndices, MaxPool3dBackward, MaxPool3dWithIndices, ModuleOps,
         conv::{
             calculate_conv_output_size, calculate_conv_transpose_output_size,
             calculate_pool_output_size,
@@ -725,6 +725,60 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
         out
     }
 
+    fn avg_pool3d(
+        x: FloatTensor<Self>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        count_include_pad: bool,
+    ) -> FloatTensor<Self> {
+        make_ops!(
+            AvgPool3dOps,
+            AvgPool3dOpIr,
+            |args: &AvgPool3dOpIr, handles: &mut HandleContainer<B::Handle>| {
+                let x = handles.get_float_tensor::<B>(&args.x);
+                let output = B::avg_pool3d(
+                    x,
+                    args.kernel_size,
+                    args.stride,
+                    args.padding,
+                    args.count_include_pad,
+                );
+
+                handles.register_float_tensor::<B>(&args.out.id, output);
+            }
+        );
+
+        let size_0 =
+            calculate_pool_output_size(kernel_size[0], stride[0], padding[0], 1, x.shape[2]);
+        let size_1 =
+            calculate_pool_output_size(kernel_size[1], stride[1], padding[1], 1, x.shape[3]);
+        let size_2 =
+            calculate_pool_output_size(kernel_size[2], stride[2], padding[2], 1, x.shape[4]);
+
+        let mut streams = OperationStreams::default();
+        streams.tensor(&x);
+
+        let shape = vec![x.shape[0], x.shape[1], size_0, size_1, size_2];
+        let out = x.client.tensor_uninitialized(shape, B::FloatElem::dtype());
+
+        let desc = AvgPool3dOpIr {
+            x: x.into_ir(),
+            kernel_size,
+            stride,
+            padding,
+            count_include_pad,
+            out: out.to_ir_out(),
+        };
+        out.client.register(
+            streams,
+            OperationIr::Module(ModuleOperationIr::AvgPool3d(desc.clone())),
+            AvgPool3dOps::<B>::new(desc),
+        );
+
+        out
+    }
+
     fn avg_pool1d_backward(
         x: FloatTensor<Self>,
         grad: FloatTensor<Self>,
@@ -831,6 +885,59 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
         out
     }
 
+    fn avg_pool3d_backward(
+        x: FloatTensor<Self>,
+        grad: FloatTensor<Self>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        count_include_pad: bool,
+    ) -> FloatTensor<Self> {
+        make_ops!(
+            AvgPool3dBackwardOps,
+            AvgPool3dBackwardOpIr,
+            |args: &AvgPool3dBackwardOpIr, handles: &mut HandleContainer<B::Handle>| {
+                let x = handles.get_float_tensor::<B>(&args.x);
+                let grad = handles.get_float_tensor::<B>(&args.grad);
+                let output = B::avg_pool3d_backward(
+                    x,
+                    grad,
+                    args.kernel_size,
+                    args.stride,
+                    args.padding,
+                    args.count_include_pad,
+                );
+
+                handles.register_float_tensor::<B>(&args.out.id, output);
+            }
+        );
+
+        let mut streams = OperationStreams::default();
+        streams.tensor(&x);
+        streams.tensor(&grad);
+
+        let out = x
+            .client
+            .tensor_uninitialized(x.shape.clone(), B::FloatElem::dtype());
+
+        let desc = AvgPool3dBackwardOpIr {
+            x: x.into_ir(),
+            grad: grad.into_ir(),
+            kernel_size,
+            stride,
+            padding,
+            count_include_pad,
+            out: out.to_ir_out(),
+        };
+        out.client.register(
+            streams,
+            OperationIr::Module(ModuleOperationIr::AvgPool3dBackward(desc.clone())),
+            AvgPool3dBackwardOps::<B>::new(desc),
+        );
+
+        out
+    }
+
     fn max_pool1d(
         x: FloatTensor<Self>,
         kernel_size: usize,
@@ -942,6 +1049,75 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
         out
     }
 
+    fn max_pool3d(
+        x: FloatTensor<Self>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        dilation: [usize; 3],
+    ) -> FloatTensor<Self> {
+        make_ops!(
+            MaxPool3dOps,
+            MaxPool3dOpIr,
+            |args: &MaxPool3dOpIr, handles: &mut HandleContainer<B::Handle>| {
+                let x = handles.get_float_tensor::<B>(&args.x);
+                let output = B::max_pool3d(
+                    x,
+                    args.kernel_size,
+                    args.stride,
+                    args.padding,
+                    args.dilation,
+                );
+
+                handles.register_float_tensor::<B>(&args.out.id, output);
+            }
+        );
+
+        let size_0 = calculate_pool_output_size(
+            kernel_size[0],
+            stride[0],
+            padding[0],
+            dilation[0],
+            x.shape[2],
+        );
+        let size_1 = calculate_pool_output_size(
+            kernel_size[1],
+            stride[1],
+            padding[1],
+            dilation[1],
+            x.shape[3],
+        );
+        let size_2 = calculate_pool_output_size(
+            kernel_size[2],
+            stride[2],
+            padding[2],
+            dilation[2],
+            x.shape[4],
+        );
+
+        let mut streams = OperationStreams::default();
+        streams.tensor(&x);
+
+        let shape = vec![x.shape[0], x.shape[1], size_0, size_1, size_2];
+        let out = x.client.tensor_uninitialized(shape, B::FloatElem::dtype());
+
+        let desc = MaxPool3dOpIr {
+            x: x.into_ir(),
+            kernel_size,
+            stride,
+            padding,
+            dilation,
+            out: out.to_ir_out(),
+        };
+        out.client.register(
+            streams,
+            OperationIr::Module(ModuleOperationIr::MaxPool3d(desc.clone())),
+            MaxPool3dOps::<B>::new(desc),
+        );
+
+        out
+    }
+
     fn max_pool1d_with_indices(
         x: FloatTensor<Self>,
         kernel_size: usize,
@@ -1062,6 +1238,80 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
         MaxPool2dWithIndices::new(out, out_indices)
     }
 
+    fn max_pool3d_with_indices(
+        x: FloatTensor<Self>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        dilation: [usize; 3],
+    ) -> MaxPool3dWithIndices<Self> {
+        make_ops!(
+            MaxPool3dWithIndicesOps,
+            MaxPool3dWithIndicesOpIr,
+            |args: &MaxPool3dWithIndicesOpIr, handles: &mut HandleContainer<B::Handle>| {
+                let x = handles.get_float_tensor::<B>(&args.x);
+                let output = B::max_pool3d_with_indices(
+                    x,
+                    args.kernel_size,
+                    args.stride,
+                    args.padding,
+                    args.dilation,
+                );
+
+                handles.register_float_tensor::<B>(&args.out.id, output.output);
+                handles.register_int_tensor::<B>(&args.out_indices.id, output.indices);
+            }
+        );
+
+        let size_0 = calculate_pool_output_size(
+            kernel_size[0],
+            stride[0],
+            padding[0],
+            dilation[0],
+            x.shape[2],
+        );
+        let size_1 = calculate_pool_output_size(
+            kernel_size[1],
+            stride[1],
+            padding[1],
+            dilation[1],
+            x.shape[3],
+        );
+        let size_2 = calculate_pool_output_size(
+            kernel_size[2],
+            stride[2],
+            padding[2],
+            dilation[2],
+            x.shape[4],
+        );
+
+        let mut streams = OperationStreams::default();
+        streams.tensor(&x);
+
+        let shape = vec![x.shape[0], x.shape[1], size_0, size_1, size_2];
+        let out = x
+            .client
+            .tensor_uninitialized(shape.clone(), B::FloatElem::dtype());
+        let out_indices = x.client.tensor_uninitialized(shape, B::IntElem::dtype());
+
+        let desc = MaxPool3dWithIndicesOpIr {
+            x: x.into_ir(),
+            kernel_size,
+            stride,
+            padding,
+            dilation,
+            out: out.to_ir_out(),
+            out_indices: out_indices.to_ir_out(),
+        };
+        out.client.register(
+            streams,
+            OperationIr::Module(ModuleOperationIr::MaxPool3dWithIndices(desc.clone())),
+            MaxPool3dWithIndicesOps::<B>::new(desc),
+        );
+
+        MaxPool3dWithIndices::new(out, out_indices)
+    }
+
     fn max_pool1d_with_indices_backward(
         x: FloatTensor<Self>,
         kernel_size: usize,
@@ -1182,6 +1432,66 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
         MaxPool2dBackward::new(out)
     }
 
+    fn max_pool3d_with_indices_backward(
+        x: FloatTensor<Self>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        dilation: [usize; 3],
+        output_grad: FloatTensor<Self>,
+        indices: IntTensor<Self>,
+    ) -> MaxPool3dBackward<Self> {
+        make_ops!(
+            MaxPool3dWithIndicesBackwardOps,
+            MaxPool3dWithIndicesBackwardOpIr,
+            |args: &MaxPool3dWithIndicesBackwardOpIr, handles: &mut HandleContainer<B::Handle>| {
+                let x = handles.get_float_tensor::<B>(&args.x);
+                let grad = handles.get_float_tensor::<B>(&args.grad);
+                let indices = handles.get_int_tensor::<B>(&args.indices);
+                let output = B::max_pool3d_with_indices_backward(
+                    x,
+                    args.kernel_size,
+                    args.stride,
+                    args.padding,
+                    args.dilation,
+                    grad,
+                    indices,
+                );
+
+                handles.register_float_tensor::<B>(&args.out.id, output.x_grad);
+            }
+        );
+
+        let mut streams = OperationStreams::default();
+        streams.tensor(&x);
+        streams.tensor(&output_grad);
+        streams.tensor(&indices);
+
+        let out = x
+            .client
+            .tensor_uninitialized(x.shape.clone(), B::FloatElem::dtype());
+
+        let desc = MaxPool3dWithIndicesBackwardOpIr {
+            x: x.into_ir(),
+            grad: output_grad.into_ir(),
+            indices: indices.into_ir(),
+            kernel_size,
+            stride,
+            padding,
+            dilation,
+            out: out.to_ir_out(),
+        };
+        out.client.register(
+            streams,
+            OperationIr::Module(ModuleOperationIr::MaxPool3dWithIndicesBackward(
+                desc.clone(),
+            )),
+            MaxPool3dWithIndicesBackwardOps::<B>::new(desc),
+        );
+
+        MaxPool3dBackward::new(out)
+    }
+
     fn adaptive_avg_pool1d(x: FloatTensor<Self>, output_size: usize) -> FloatTensor<Self> {
         make_ops!(
             AdaptiveAvgPool1dOps,
@@ -1246,6 +1556,44 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
         out
     }
 
+    fn adaptive_avg_pool3d(x: FloatTensor<Self>, output_size: [usize; 3]) -> FloatTensor<Self> {
+        make_ops!(
+            AdaptiveAvgPool3dOps,
+            AdaptiveAvgPool3dOpIr,
+            |args: &AdaptiveAvgPool3dOpIr, handles: &mut HandleContainer<B::Handle>| {
+                let x = handles.get_float_tensor::<B>(&args.x);
+                let output = B::adaptive_avg_pool3d(x, args.output_size);
+
+                handles.register_float_tensor::<B>(&args.out.id, output);
+            }
+        );
+
+        let mut streams = OperationStreams::default();
+        streams.tensor(&x);
+
+        let shape = vec![
+            x.shape[0],
+            x.shape[1],
+            output_size[0],
+            output_size[1],
+            output_size[2],
+        ];
+        let out = x.client.tensor_uninitialized(shape, B::FloatElem::dtype());
+
+        let desc = AdaptiveAvgPool3dOpIr {
+            x: x.into_ir(),
+            output_size,
+            out: out.to_ir_out(),
+        };
+        out.client.register(
+            streams,
+            OperationIr::Module(ModuleOperationIr::AdaptiveAvgPool3d(desc.clone())),
+            AdaptiveAvgPool3dOps::<B>::new(desc),
+        );
+
+        out
+    }
+
     fn adaptive_avg_pool1d_backward(
         x: FloatTensor<Self>,
         grad: FloatTensor<Self>,
@@ -1322,6 +1670,44 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
         out
     }
 
+    fn adaptive_avg_pool3d_backward(
+        x: FloatTensor<Self>,
+        grad: FloatTensor<Self>,
+    ) -> FloatTensor<Self> {
+        make_ops!(
+            AdaptiveAvgPool3dBackwardOps,
+            AdaptiveAvgPool3dBackwardOpIr,
+            |args: &AdaptiveAvgPool3dBackwardOpIr, handles: &mut HandleContainer<B::Handle>| {
+                let x = handles.get_float_tensor::<B>(&args.x);
+                let grad = handles.get_float_tensor::<B>(&args.grad);
+                let output = B::adaptive_avg_pool3d_backward(x, grad);
+
+                handles.register_float_tensor::<B>(&args.out.id, output);
+            }
+        );
+
+        let mut streams = OperationStreams::default();
+        streams.tensor(&x);
+        streams.tensor(&grad);
+
+        let out = x
+            .client
+            .tensor_uninitialized(x.shape.clone(), B::FloatElem::dtype());
+
+        let desc = AdaptiveAvgPool3dBackwardOpIr {
+            x: x.into_ir(),
+            grad: grad.into_ir(),
+            out: out.to_ir_out(),
+        };
+        out.client.register(
+            streams,
+            OperationIr::Module(ModuleOperationIr::AdaptiveAvgPool3dBackward(desc.clone())),
+            AdaptiveAvgPool3dBackwardOps::<B>::new(desc),
+        );
+
+        out
+    }
+
     fn interpolate(
         x: FloatTensor<Self>,
         output_size: [usize; 2],
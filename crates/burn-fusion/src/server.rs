@@ -37,6 +37,17 @@ where
         self.streams.drain(&mut self.handles, id)
     }
 
+    #[cfg(feature = "dump")]
+    pub fn dump(&self) -> crate::dump::FusionDump {
+        self.streams.dump()
+    }
+
+    #[cfg(feature = "cache")]
+    pub fn flush_cache(&mut self, id: StreamId) {
+        self.drain_stream(id);
+        self.streams.save_plans(true);
+    }
+
     pub fn create_empty_handle(&mut self) -> TensorId {
         self.handles.create_tensor_uninit()
     }
@@ -153,6 +153,7 @@ impl OperationConverter {
         };
 
         match dtype {
+            burn_tensor::DType::F64 => self.scalars.insert(id, ScalarValue::F64(elem.elem())),
             burn_tensor::DType::F32 => self.scalars.insert(id, ScalarValue::F32(elem.elem())),
             burn_tensor::DType::F16 => self.scalars.insert(id, ScalarValue::F16(elem.elem())),
             burn_tensor::DType::BF16 => self.scalars.insert(id, ScalarValue::BF16(elem.elem())),
@@ -320,6 +321,14 @@ impl RelativeOps for ModuleOperationIr {
                 count_include_pad: desc.count_include_pad,
                 out: desc.out.to_relative(converter),
             }),
+            ModuleOperationIr::AvgPool3d(desc) => ModuleOperationIr::AvgPool3d(AvgPool3dOpIr {
+                x: desc.x.to_relative(converter),
+                kernel_size: desc.kernel_size,
+                stride: desc.stride,
+                padding: desc.padding,
+                count_include_pad: desc.count_include_pad,
+                out: desc.out.to_relative(converter),
+            }),
             ModuleOperationIr::AvgPool1dBackward(desc) => {
                 ModuleOperationIr::AvgPool1dBackward(AvgPool1dBackwardOpIr {
                     x: desc.x.to_relative(converter),
@@ -342,6 +351,17 @@ impl RelativeOps for ModuleOperationIr {
                     out: desc.out.to_relative(converter),
                 })
             }
+            ModuleOperationIr::AvgPool3dBackward(desc) => {
+                ModuleOperationIr::AvgPool3dBackward(AvgPool3dBackwardOpIr {
+                    x: desc.x.to_relative(converter),
+                    grad: desc.grad.to_relative(converter),
+                    kernel_size: desc.kernel_size,
+                    stride: desc.stride,
+                    padding: desc.padding,
+                    count_include_pad: desc.count_include_pad,
+                    out: desc.out.to_relative(converter),
+                })
+            }
             ModuleOperationIr::AdaptiveAvgPool1d(desc) => {
                 ModuleOperationIr::AdaptiveAvgPool1d(AdaptiveAvgPool1dOpIr {
                     x: desc.x.to_relative(converter),
@@ -356,6 +376,13 @@ impl RelativeOps for ModuleOperationIr {
                     out: desc.out.to_relative(converter),
                 })
             }
+            ModuleOperationIr::AdaptiveAvgPool3d(desc) => {
+                ModuleOperationIr::AdaptiveAvgPool3d(AdaptiveAvgPool3dOpIr {
+                    x: desc.x.to_relative(converter),
+                    output_size: desc.output_size,
+                    out: desc.out.to_relative(converter),
+                })
+            }
             ModuleOperationIr::AdaptiveAvgPool1dBackward(desc) => {
                 ModuleOperationIr::AdaptiveAvgPool1dBackward(AdaptiveAvgPool1dBackwardOpIr {
                     x: desc.x.to_relative(converter),
@@ -370,6 +397,13 @@ impl RelativeOps for ModuleOperationIr {
                     out: desc.out.to_relative(converter),
                 })
             }
+            ModuleOperationIr::AdaptiveAvgPool3dBackward(desc) => {
+                ModuleOperationIr::AdaptiveAvgPool3dBackward(AdaptiveAvgPool3dBackwardOpIr {
+                    x: desc.x.to_relative(converter),
+                    grad: desc.grad.to_relative(converter),
+                    out: desc.out.to_relative(converter),
+                })
+            }
             ModuleOperationIr::MaxPool1d(desc) => ModuleOperationIr::MaxPool1d(MaxPool1dOpIr {
                 x: desc.x.to_relative(converter),
                 kernel_size: desc.kernel_size,
@@ -409,6 +443,14 @@ impl RelativeOps for ModuleOperationIr {
                 dilation: desc.dilation,
                 out: desc.out.to_relative(converter),
             }),
+            ModuleOperationIr::MaxPool3d(desc) => ModuleOperationIr::MaxPool3d(MaxPool3dOpIr {
+                x: desc.x.to_relative(converter),
+                kernel_size: desc.kernel_size,
+                stride: desc.stride,
+                padding: desc.padding,
+                dilation: desc.dilation,
+                out: desc.out.to_relative(converter),
+            }),
             ModuleOperationIr::MaxPool2dWithIndices(desc) => {
                 ModuleOperationIr::MaxPool2dWithIndices(MaxPool2dWithIndicesOpIr {
                     x: desc.x.to_relative(converter),
@@ -420,6 +462,17 @@ impl RelativeOps for ModuleOperationIr {
                     out_indices: desc.out_indices.to_relative(converter),
                 })
             }
+            ModuleOperationIr::MaxPool3dWithIndices(desc) => {
+                ModuleOperationIr::MaxPool3dWithIndices(MaxPool3dWithIndicesOpIr {
+                    x: desc.x.to_relative(converter),
+                    kernel_size: desc.kernel_size,
+                    stride: desc.stride,
+                    padding: desc.padding,
+                    dilation: desc.dilation,
+                    out: desc.out.to_relative(converter),
+                    out_indices: desc.out_indices.to_relative(converter),
+                })
+            }
             ModuleOperationIr::MaxPool2dWithIndicesBackward(desc) => {
                 ModuleOperationIr::MaxPool2dWithIndicesBackward(MaxPool2dWithIndicesBackwardOpIr {
                     x: desc.x.to_relative(converter),
@@ -432,6 +485,18 @@ impl RelativeOps for ModuleOperationIr {
                     out: desc.out.to_relative(converter),
                 })
             }
+            ModuleOperationIr::MaxPool3dWithIndicesBackward(desc) => {
+                ModuleOperationIr::MaxPool3dWithIndicesBackward(MaxPool3dWithIndicesBackwardOpIr {
+                    x: desc.x.to_relative(converter),
+                    grad: desc.grad.to_relative(converter),
+                    indices: desc.indices.to_relative(converter),
+                    kernel_size: desc.kernel_size,
+                    stride: desc.stride,
+                    padding: desc.padding,
+                    dilation: desc.dilation,
+                    out: desc.out.to_relative(converter),
+                })
+            }
             ModuleOperationIr::Interpolate(desc) => {
                 ModuleOperationIr::Interpolate(InterpolateOpIr {
                     x: desc.x.to_relative(converter),
@@ -27,6 +27,9 @@ mod base;
 mod context;
 mod multi;
 
+#[cfg(test)]
+pub(crate) mod tests;
+
 pub use base::*;
 pub use context::*;
 pub use execution::*;
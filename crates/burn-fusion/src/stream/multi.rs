@@ -1,6 +1,9 @@
 use std::sync::Arc;
 
-use burn_ir::{HandleContainer, OperationIr, TensorId, TensorIr, TensorStatus};
+use burn_ir::{
+    BaseOperationIr, HandleContainer, OperationIr, TensorId, TensorIr, TensorStatus,
+    optim::{History, PassPipeline},
+};
 use hashbrown::{HashMap, HashSet};
 
 use super::{
@@ -20,6 +23,9 @@ pub struct MultiStream<R: FusionRuntime> {
     streams: HashMap<StreamId, Stream<R>>,
     optimizations: ExecutionPlanStore<R::Optimization>,
     shared_tensors: SharedTensors,
+    passes: PassPipeline,
+    #[cfg(feature = "cache")]
+    cache: Option<super::store::PlanCache>,
     device: R::FusionDevice,
     #[cfg(feature = "memory-checks")]
     memory_checks: super::memory_checks::MemoryChecks,
@@ -34,14 +40,37 @@ enum DropAction {
 
 impl<R: FusionRuntime> MultiStream<R> {
     pub(crate) fn new(device: R::FusionDevice) -> Self {
-        Self {
+        #[allow(unused_mut)]
+        let mut streams = Self {
             streams: HashMap::new(),
             optimizations: ExecutionPlanStore::new(),
             shared_tensors: SharedTensors::default(),
+            passes: R::passes(&device),
+            #[cfg(feature = "cache")]
+            cache: None,
             device,
             #[cfg(feature = "memory-checks")]
             memory_checks: super::memory_checks::MemoryChecks::default(),
+        };
+
+        // Tests never read nor write the cache of the user.
+        #[cfg(all(feature = "cache", not(test)))]
+        streams.use_cache(super::store::PlanCache::new::<R>(&streams.device));
+
+        streams
+    }
+
+    /// Load the plans persisted in the given cache, where the plans found afterward are saved.
+    #[cfg(feature = "cache")]
+    fn use_cache(&mut self, cache: Option<super::store::PlanCache>) {
+        if let Some(cache) = &cache {
+            for plan in cache.load::<R>(&self.device) {
+                self.optimizations.add(plan);
+            }
+            self.optimizations.take_changed();
         }
+
+        self.cache = cache;
     }
 
     /// Register a new tensor operation.
@@ -52,6 +81,13 @@ impl<R: FusionRuntime> MultiStream<R> {
         operation: Arc<dyn Operation<R>>,
         handles: &mut HandleContainer<R::FusionHandle>,
     ) {
+        if let Some(operations) = self.rewrite(&streams, &repr) {
+            for op in operations {
+                self.register_rewritten(streams.current, op, handles);
+            }
+            return;
+        }
+
         let id = self.resolve_streams(&streams, handles, &mut repr);
 
         let drop_action = match &mut repr {
@@ -77,15 +113,7 @@ impl<R: FusionRuntime> MultiStream<R> {
         };
 
         let num_executed = self.enqueue_operation(id, repr, &streams, operation, handles);
-
-        if num_executed > 0
-            && let Some(stream) = self.streams.get_mut(&id)
-        {
-            let cleared = self.shared_tensors.on_executed_ops(id, stream);
-            self.clear_shared_tensors(&cleared, id);
-            let to_drop = self.shared_tensors.clear_tensors(cleared);
-            self.drop_shared_tensors(to_drop, handles, id);
-        }
+        self.on_executed_ops(id, num_executed, handles);
 
         let stream = match self.streams.get(&id) {
             Some(val) => val,
@@ -105,6 +133,90 @@ impl<R: FusionRuntime> MultiStream<R> {
         self.memory_checks.check(&self.streams, handles);
     }
 
+    /// Rewrite the operation using the [graph-level passes](PassPipeline).
+    ///
+    /// Only rewrites forwarding tensors are applied, since the other ones would need a new
+    /// backend [operation](Operation) to be executed. Operations involving tensors shared between
+    /// streams are never rewritten.
+    fn rewrite(&self, streams: &OperationStreams, repr: &OperationIr) -> Option<Vec<OperationIr>> {
+        if self.passes.is_empty() || matches!(repr, OperationIr::Drop(_)) {
+            return None;
+        }
+
+        if streams
+            .streams
+            .values()
+            .any(|stream_id| *stream_id != streams.current)
+        {
+            return None;
+        }
+
+        let stream = self.streams.get(&streams.current)?;
+
+        if repr
+            .nodes()
+            .iter()
+            .any(|node| self.shared_tensors.contains(&node.id))
+        {
+            return None;
+        }
+
+        let index = stream.queue.index.as_ref()?;
+        let history = History::new(&stream.queue.global, index);
+
+        self.passes
+            .forward(&history, repr)
+            .map(|rewrite| rewrite.into_operations(repr))
+    }
+
+    /// Register an operation created by a [rewrite](Self::rewrite).
+    fn register_rewritten(
+        &mut self,
+        id: StreamId,
+        mut repr: OperationIr,
+        handles: &mut HandleContainer<R::FusionHandle>,
+    ) {
+        let streams = OperationStreams {
+            streams: HashMap::new(),
+            current: id,
+        };
+        let id = self.resolve_streams(&streams, handles, &mut repr);
+
+        let operation: Arc<dyn Operation<R>> = match &repr {
+            OperationIr::Drop(tensor) => Arc::new(DropOp { id: tensor.id }),
+            OperationIr::BaseFloat(BaseOperationIr::Reshape(op))
+            | OperationIr::BaseInt(BaseOperationIr::Reshape(op))
+            | OperationIr::BaseBool(BaseOperationIr::Reshape(op)) => Arc::new(ForwardOp {
+                input: op.input.clone(),
+                out: op.out.id,
+            }),
+            _ => unreachable!("Only forwarding rewrites are applied on a stream."),
+        };
+
+        let num_executed = self.enqueue_operation(id, repr, &streams, operation, handles);
+        self.on_executed_ops(id, num_executed, handles);
+
+        #[cfg(feature = "memory-checks")]
+        self.memory_checks.check(&self.streams, handles);
+    }
+
+    /// Update the shared tensors after operations were executed on the given stream.
+    fn on_executed_ops(
+        &mut self,
+        id: StreamId,
+        num_executed: usize,
+        handles: &mut HandleContainer<R::FusionHandle>,
+    ) {
+        if num_executed > 0
+            && let Some(stream) = self.streams.get_mut(&id)
+        {
+            let cleared = self.shared_tensors.on_executed_ops(id, stream);
+            self.clear_shared_tensors(&cleared, id);
+            let to_drop = self.shared_tensors.clear_tensors(cleared);
+            self.drop_shared_tensors(to_drop, handles, id);
+        }
+    }
+
     /// Checks if the current operation is a drop.
     ///
     /// When a tensor is shared across multiple concurrent streams, dropping a tensor might cause a
@@ -141,7 +253,7 @@ impl<R: FusionRuntime> MultiStream<R> {
         let stream = match self.streams.get_mut(&id) {
             Some(stream) => stream,
             None => {
-                let stream = Stream::new(self.device.clone());
+                let stream = Stream::new(self.device.clone(), !self.passes.is_empty());
                 self.streams.insert(id, stream);
                 self.streams
                     .get_mut(&id)
@@ -165,6 +277,35 @@ impl<R: FusionRuntime> MultiStream<R> {
         num_executed
     }
 
+    /// Dump the queued operations and the execution plans for visualization.
+    #[cfg(feature = "dump")]
+    pub fn dump(&self) -> crate::dump::FusionDump {
+        let streams = self
+            .streams
+            .iter()
+            .map(|(id, stream)| (id, stream.queue.global.as_slice()));
+
+        crate::dump::FusionDump {
+            streams: crate::dump::streams_graph(streams),
+            plans: crate::dump::plans_graph(self.optimizations.plans()),
+        }
+    }
+
+    /// Persist the execution plans when new ones were found.
+    ///
+    /// Plans are saved when streams are synced rather than when they are found, and at most once
+    /// per save interval unless `force` is set, so that the cache is never written on every
+    /// operation.
+    #[cfg(feature = "cache")]
+    pub(crate) fn save_plans(&mut self, force: bool) {
+        if let Some(cache) = &mut self.cache
+            && (force || cache.is_due())
+            && self.optimizations.take_changed()
+        {
+            cache.save::<R>(self.optimizations.plans());
+        }
+    }
+
     /// Mark a tensor as read.
     #[allow(unused_variables)]
     pub fn mark_read(
@@ -209,6 +350,9 @@ impl<R: FusionRuntime> MultiStream<R> {
 
             self.drop_shared_tensors(to_drop, handles, id);
         }
+
+        #[cfg(feature = "cache")]
+        self.save_plans(false);
     }
 
     /// When one of the provided streams is different from the current stream, we drain them.
@@ -379,6 +523,20 @@ impl<R: FusionRuntime> MultiStream<R> {
     }
 }
 
+/// Forward the handle of a tensor to another tensor.
+#[derive(Debug)]
+struct ForwardOp {
+    input: TensorIr,
+    out: TensorId,
+}
+
+impl<R: FusionRuntime> Operation<R> for ForwardOp {
+    fn execute(&self, handles: &mut HandleContainer<R::FusionHandle>) {
+        let handle = handles.get_handle(&self.input.id, &self.input.status);
+        handles.register_handle(self.out, handle);
+    }
+}
+
 pub(crate) struct Stream<R: FusionRuntime> {
     pub(crate) queue: OperationQueue<R>,
     processor: Processor<R::Optimization>,
@@ -402,10 +560,15 @@ impl<R: FusionRuntime> StreamSegment<R::Optimization> for Segment<'_, R> {
 }
 
 impl<R: FusionRuntime> Stream<R> {
-    fn new(device: R::FusionDevice) -> Self {
+    fn new(device: R::FusionDevice, indexed: bool) -> Self {
+        let queue = match indexed {
+            true => OperationQueue::indexed(),
+            false => OperationQueue::new(),
+        };
+
         Self {
             processor: Processor::new(R::optimizations(device)),
-            queue: OperationQueue::new(),
+            queue,
             cursor: 0,
         }
     }
@@ -451,3 +614,167 @@ struct MultiSharedTensorAnalysis {
     /// Tensors that are shared with existing streams.
     existing: Vec<(TensorId, StreamId, u64)>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stream::tests::{TestDevice, TestRuntime};
+    use burn_ir::{BinaryOpIr, FloatOperationIr, NumericOperationIr, UnaryOpIr};
+    use burn_tensor::DType;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn rewritten_stream_should_produce_the_same_results() {
+        let (expected, num_exp) = execute::<false>();
+        let (actual, num_exp_rewritten) = execute::<true>();
+
+        assert_eq!(actual, expected);
+        assert_eq!(num_exp, 2);
+        assert_eq!(num_exp_rewritten, 1);
+    }
+
+    /// Execute `exp(x) + exp(x)` and returns the result with the number of executed `exp`.
+    fn execute<const PASSES: bool>() -> (Vec<f32>, usize) {
+        let mut streams = MultiStream::<TestRuntime<PASSES>>::new(TestDevice);
+        let mut handles = HandleContainer::new();
+        let num_exp = Arc::new(AtomicUsize::new(0));
+
+        handles.register_handle(TensorId::new(0), vec![0.0, 1.0, 2.0]);
+
+        for (input, out) in [(TensorStatus::ReadOnly, 1), (TensorStatus::ReadWrite, 2)] {
+            let input = tensor(0, input);
+            let out = tensor(out, TensorStatus::NotInit);
+            let operation = ExpOp {
+                input: input.clone(),
+                out: out.id,
+                num_exp: num_exp.clone(),
+            };
+            streams.register(
+                OperationStreams::default(),
+                OperationIr::Float(DType::F32, FloatOperationIr::Exp(UnaryOpIr { input, out })),
+                Arc::new(operation),
+                &mut handles,
+            );
+        }
+
+        let lhs = tensor(1, TensorStatus::ReadWrite);
+        let rhs = tensor(2, TensorStatus::ReadWrite);
+        let out = tensor(3, TensorStatus::NotInit);
+        let operation = AddOp {
+            lhs: lhs.clone(),
+            rhs: rhs.clone(),
+            out: out.id,
+        };
+        streams.register(
+            OperationStreams::default(),
+            OperationIr::NumericFloat(
+                DType::F32,
+                NumericOperationIr::Add(BinaryOpIr { lhs, rhs, out }),
+            ),
+            Arc::new(operation),
+            &mut handles,
+        );
+
+        streams.drain(&mut handles, StreamId::current());
+
+        let output = handles.get_handle(&TensorId::new(3), &TensorStatus::ReadOnly);
+        (output, num_exp.load(Ordering::Relaxed))
+    }
+
+    #[test]
+    #[cfg(feature = "cache")]
+    fn short_run_should_save_plans() {
+        use crate::stream::store::PlanCache;
+        type Runtime = TestRuntime<false>;
+
+        let dir = tempfile::tempdir().unwrap();
+        let cache = || PlanCache::with_root::<Runtime>(dir.path().into(), &TestDevice);
+        let num_plans = || cache().load::<Runtime>(&TestDevice).len();
+        let mut streams = MultiStream::<Runtime>::new(TestDevice);
+        let mut handles = HandleContainer::new();
+        streams.use_cache(Some(cache()));
+        handles.register_handle(TensorId::new(0), vec![0.0, 1.0, 2.0]);
+
+        // The first sync saves the plans.
+        let input = tensor(0, TensorStatus::ReadWrite);
+        let out = tensor(1, TensorStatus::NotInit);
+        let operation = ExpOp {
+            input: input.clone(),
+            out: out.id,
+            num_exp: Arc::new(AtomicUsize::new(0)),
+        };
+        streams.register(
+            OperationStreams::default(),
+            OperationIr::Float(DType::F32, FloatOperationIr::Exp(UnaryOpIr { input, out })),
+            Arc::new(operation),
+            &mut handles,
+        );
+        streams.drain(&mut handles, StreamId::current());
+        assert_eq!(num_plans(), 1);
+
+        // The following syncs wait for the save interval, unless the plans are flushed.
+        let lhs = tensor(1, TensorStatus::ReadOnly);
+        let rhs = tensor(1, TensorStatus::ReadWrite);
+        let out = tensor(2, TensorStatus::NotInit);
+        let operation = AddOp {
+            lhs: lhs.clone(),
+            rhs: rhs.clone(),
+            out: out.id,
+        };
+        streams.register(
+            OperationStreams::default(),
+            OperationIr::NumericFloat(
+                DType::F32,
+                NumericOperationIr::Add(BinaryOpIr { lhs, rhs, out }),
+            ),
+            Arc::new(operation),
+            &mut handles,
+        );
+        streams.drain(&mut handles, StreamId::current());
+        assert_eq!(num_plans(), 1);
+
+        streams.save_plans(true);
+        assert_eq!(num_plans(), 2);
+    }
+
+    fn tensor(id: u64, status: TensorStatus) -> TensorIr {
+        TensorIr {
+            id: TensorId::new(id),
+            shape: vec![3],
+            status,
+            dtype: DType::F32,
+        }
+    }
+
+    #[derive(Debug)]
+    struct ExpOp {
+        input: TensorIr,
+        out: TensorId,
+        num_exp: Arc<AtomicUsize>,
+    }
+
+    impl<const PASSES: bool> Operation<TestRuntime<PASSES>> for ExpOp {
+        fn execute(&self, handles: &mut HandleContainer<Vec<f32>>) {
+            self.num_exp.fetch_add(1, Ordering::Relaxed);
+            let input = handles.get_handle(&self.input.id, &self.input.status);
+            let output = input.iter().map(|value| value.exp()).collect();
+            handles.register_handle(self.out, output);
+        }
+    }
+
+    #[derive(Debug)]
+    struct AddOp {
+        lhs: TensorIr,
+        rhs: TensorIr,
+        out: TensorId,
+    }
+
+    impl<const PASSES: bool> Operation<TestRuntime<PASSES>> for AddOp {
+        fn execute(&self, handles: &mut HandleContainer<Vec<f32>>) {
+            let lhs = handles.get_handle(&self.lhs.id, &self.lhs.status);
+            let rhs = handles.get_handle(&self.rhs.id, &self.rhs.status);
+            let output = lhs.iter().zip(rhs.iter()).map(|(a, b)| a + b).collect();
+            handles.register_handle(self.out, output);
+        }
+    }
+}
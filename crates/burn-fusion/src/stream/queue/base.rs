@@ -3,7 +3,7 @@ use std::sync::Arc;
 use crate::FusionRuntime;
 use crate::stream::{OperationConverter, OperationStreams, RelativeOps, execution::Operation};
 use burn_common::id::StreamId;
-use burn_ir::{OperationIr, TensorId, TensorStatus};
+use burn_ir::{OperationIr, TensorId, TensorStatus, optim::HistoryIndex};
 
 use hashbrown::HashMap;
 
@@ -21,6 +21,8 @@ pub struct OperationQueue<R: FusionRuntime> {
     pub(crate) converter: OperationConverter,
     pub(crate) operations: Vec<Arc<dyn Operation<R>>>,
     pub(crate) variables: HashMap<TensorId, (StreamId, TensorStatus)>,
+    /// The index of the `global` list, only maintained when operations are rewritten.
+    pub(crate) index: Option<HistoryIndex>,
 }
 
 impl<R: FusionRuntime> Default for OperationQueue<R> {
@@ -38,6 +40,15 @@ impl<R: FusionRuntime> OperationQueue<R> {
             converter: OperationConverter::default(),
             operations: Vec::new(),
             variables: HashMap::new(),
+            index: None,
+        }
+    }
+
+    /// Create a new empty queue maintaining the [index](HistoryIndex) of its operations.
+    pub fn indexed() -> Self {
+        Self {
+            index: Some(HistoryIndex::default()),
+            ..Self::new()
         }
     }
 
@@ -62,6 +73,9 @@ impl<R: FusionRuntime> OperationQueue<R> {
         }
         let relative = global.to_relative(&mut self.converter);
         self.relative.push(relative);
+        if let Some(index) = &mut self.index {
+            index.push(&global);
+        }
         self.global.push(global);
         self.operations.push(operation);
     }
@@ -51,6 +51,9 @@ impl<R: FusionRuntime> OperationQueue<R> {
                 handles.free(tensor)
             });
 
+        if let Some(index) = &mut self.index {
+            index.drain(&self.global[0..num_drained]);
+        }
         self.global.drain(0..num_drained);
 
         self.reset_relative();
@@ -57,6 +57,11 @@ pub enum SharedTensorAnalysis {
 }
 
 impl SharedTensors {
+    /// If the given tensor is shared between multiple streams.
+    pub fn contains(&self, tensor_id: &TensorId) -> bool {
+        self.shared_tensors.contains_key(tensor_id)
+    }
+
     /// Function to call when a drop operation is registered on the given stream and tensor.
     pub fn on_drop(
         &mut self,
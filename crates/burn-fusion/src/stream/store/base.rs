@@ -11,6 +11,7 @@ use serde::{Deserialize, Serialize};
 pub(crate) struct ExecutionPlanStore<O> {
     plans: Vec<ExecutionPlan<O>>,
     index: ExecutionPlanIndex,
+    changed: bool,
 }
 
 /// How a list of operations should be executed.
@@ -25,7 +26,7 @@ pub(crate) enum ExecutionStrategy<O> {
 }
 
 /// The trigger that indicates when to stop exploring.
-#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub(crate) enum ExecutionTrigger {
     OnOperations(Vec<OperationIr>),
     OnSync,
@@ -51,6 +52,7 @@ impl<O> ExecutionPlanStore<O> {
         Self {
             plans: Vec::new(),
             index: ExecutionPlanIndex::default(),
+            changed: false,
         }
     }
 
@@ -71,10 +73,15 @@ impl<O> ExecutionPlanStore<O> {
         });
 
         self.plans.push(exploration);
+        self.changed = true;
 
         id
     }
 
+    pub fn plans(&self) -> &[ExecutionPlan<O>] {
+        &self.plans
+    }
+
     pub fn get_mut_unchecked(&mut self, id: ExecutionPlanId) -> &mut ExecutionPlan<O> {
         &mut self.plans[id]
     }
@@ -89,6 +96,12 @@ impl<O> ExecutionPlanStore<O> {
 
         if !criteria.contains(&trigger) {
             criteria.push(trigger);
+            self.changed = true;
         }
     }
+
+    /// Returns whether plans were added or updated since the last call.
+    pub fn take_changed(&mut self) -> bool {
+        core::mem::replace(&mut self.changed, false)
+    }
 }
@@ -0,0 +1,331 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use burn_ir::OperationIr;
+use burn_tensor::backend::DeviceOps;
+use serde::{Deserialize, Serialize};
+
+use super::{ExecutionPlan, ExecutionStrategy, ExecutionTrigger};
+use crate::{FusionRuntime, Optimization, search::BlockOptimization};
+
+/// Environment variable overriding the directory where the execution plans are persisted.
+pub(crate) const PLAN_CACHE_DIR_ENV: &str = "BURN_FUSION_CACHE_DIR";
+
+/// Persist the [execution plans](ExecutionPlan) found on a device, so that they can be reused
+/// between processes.
+///
+/// Plans are stored per runtime and device in a JSON file under `~/.cache/burn-fusion`, or under
+/// the directory set with the `BURN_FUSION_CACHE_DIR` environment variable. A cache written by a
+/// different version of the crate, runtime or device is ignored and overwritten. New plans are
+/// saved when streams are synced, or with [flush](crate::cache::flush).
+pub(crate) struct PlanCache {
+    path: PathBuf,
+    key: PlanCacheKey,
+    last_save: Option<Instant>,
+}
+
+/// What the persisted plans depend on.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+struct PlanCacheKey {
+    version: String,
+    runtime: String,
+    device: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct PlanCacheFile<S> {
+    key: PlanCacheKey,
+    plans: Vec<ExecutionPlanState<S>>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct ExecutionPlanState<S> {
+    operations: Vec<OperationIr>,
+    triggers: Vec<ExecutionTrigger>,
+    strategy: ExecutionStrategyState<S>,
+    ordering: Vec<usize>,
+}
+
+#[derive(Serialize, Deserialize)]
+enum ExecutionStrategyState<S> {
+    Optimization { state: S, ordering: Vec<usize> },
+    Operations { ordering: Vec<usize> },
+    Composed(Vec<ExecutionStrategyState<S>>),
+}
+
+const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// The minimum duration between two saves, so that plans found in a burst are written once.
+const SAVE_INTERVAL: Duration = Duration::from_secs(5);
+
+impl PlanCache {
+    /// Create the cache for the given runtime and device.
+    ///
+    /// Returns `None` when no cache directory can be found.
+    pub(crate) fn new<R: FusionRuntime>(device: &R::FusionDevice) -> Option<Self> {
+        let root = match std::env::var(PLAN_CACHE_DIR_ENV) {
+            Ok(dir) => PathBuf::from(dir),
+            Err(_) => dirs::home_dir()?.join(".cache").join("burn-fusion"),
+        };
+
+        Some(Self::with_root::<R>(root, device))
+    }
+
+    /// Create the cache for the given runtime and device under the given directory.
+    pub(crate) fn with_root<R: FusionRuntime>(root: PathBuf, device: &R::FusionDevice) -> Self {
+        let runtime = core::any::type_name::<R>();
+        let dir = runtime
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect::<String>();
+        let id = device.id();
+
+        Self {
+            path: root
+                .join(dir)
+                .join(format!("{}-{}.json", id.type_id, id.index_id)),
+            key: PlanCacheKey {
+                version: VERSION.to_string(),
+                runtime: runtime.to_string(),
+                device: format!("{device:?}"),
+            },
+            last_save: None,
+        }
+    }
+
+    /// Whether enough time passed since the last save to save again.
+    ///
+    /// The first save is always due, so that short processes persist the plans they found.
+    pub(crate) fn is_due(&self) -> bool {
+        self.last_save
+            .is_none_or(|last_save| last_save.elapsed() >= SAVE_INTERVAL)
+    }
+
+    /// Load the persisted plans.
+    ///
+    /// Missing, corrupted or outdated caches are ignored.
+    pub(crate) fn load<R: FusionRuntime>(
+        &self,
+        device: &R::FusionDevice,
+    ) -> Vec<ExecutionPlan<R::Optimization>> {
+        let file = match std::fs::read(&self.path) {
+            Ok(file) => file,
+            Err(_) => return Vec::new(),
+        };
+
+        let file: PlanCacheFile<R::OptimizationState> = match serde_json::from_slice(&file) {
+            Ok(file) => file,
+            Err(err) => {
+                log::warn!("Ignoring invalid fusion cache {:?}: {err}", self.path);
+                return Vec::new();
+            }
+        };
+
+        if file.key != self.key {
+            log::info!(
+                "Ignoring fusion cache {:?} written for {:?}",
+                self.path,
+                file.key
+            );
+            return Vec::new();
+        }
+
+        file.plans
+            .into_iter()
+            .filter(|plan| !plan.operations.is_empty())
+            .map(|plan| ExecutionPlan {
+                operations: plan.operations,
+                triggers: plan.triggers,
+                optimization: BlockOptimization::new(
+                    plan.strategy.into_strategy::<R>(device),
+                    plan.ordering,
+                ),
+            })
+            .collect()
+    }
+
+    /// Persist the given plans, replacing the previous ones.
+    pub(crate) fn save<R: FusionRuntime>(&mut self, plans: &[ExecutionPlan<R::Optimization>]) {
+        self.last_save = Some(Instant::now());
+
+        let file = PlanCacheFile {
+            key: self.key.clone(),
+            plans: plans
+                .iter()
+                .map(|plan| ExecutionPlanState {
+                    operations: plan.operations.clone(),
+                    triggers: plan.triggers.clone(),
+                    strategy: ExecutionStrategyState::from_strategy::<R>(
+                        &plan.optimization.strategy,
+                    ),
+                    ordering: plan.optimization.ordering.clone(),
+                })
+                .collect(),
+        };
+
+        if let Err(err) = write(&self.path, &file) {
+            log::warn!("Unable to save the fusion cache {:?}: {err}", self.path);
+        }
+    }
+}
+
+/// Write to a temporary file first, so that concurrent processes never read a partial cache.
+fn write<S: Serialize>(path: &Path, file: &PlanCacheFile<S>) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    let tmp = path.with_extension(format!("{}.tmp", std::process::id()));
+    std::fs::write(&tmp, serde_json::to_vec(file)?)?;
+    std::fs::rename(tmp, path)
+}
+
+impl<S> ExecutionStrategyState<S> {
+    fn from_strategy<R: FusionRuntime<OptimizationState = S>>(
+        strategy: &ExecutionStrategy<R::Optimization>,
+    ) -> Self {
+        match strategy {
+            ExecutionStrategy::Optimization { opt, ordering } => Self::Optimization {
+                state: opt.to_state(),
+                ordering: ordering.as_ref().clone(),
+            },
+            ExecutionStrategy::Operations { ordering } => Self::Operations {
+                ordering: ordering.as_ref().clone(),
+            },
+            ExecutionStrategy::Composed(strategies) => Self::Composed(
+                strategies
+                    .iter()
+                    .map(|strategy| Self::from_strategy::<R>(strategy))
+                    .collect(),
+            ),
+        }
+    }
+
+    fn into_strategy<R: FusionRuntime<OptimizationState = S>>(
+        self,
+        device: &R::FusionDevice,
+    ) -> ExecutionStrategy<R::Optimization> {
+        match self {
+            Self::Optimization { state, ordering } => ExecutionStrategy::Optimization {
+                opt: <R::Optimization as Optimization<R>>::from_state(device, state),
+                ordering: Arc::new(ordering),
+            },
+            Self::Operations { ordering } => ExecutionStrategy::Operations {
+                ordering: Arc::new(ordering),
+            },
+            Self::Composed(strategies) => ExecutionStrategy::Composed(
+                strategies
+                    .into_iter()
+                    .map(|strategy| Box::new(strategy.into_strategy::<R>(device)))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stream::tests::{TestDevice, TestOptimization, TestRuntime};
+    use burn_ir::{FloatOperationIr, TensorId, TensorIr, TensorStatus, UnaryOpIr};
+    use burn_tensor::DType;
+    use tempfile::tempdir;
+
+    type Runtime = TestRuntime<false>;
+
+    #[test]
+    fn plan_cache_should_load_saved_plans() {
+        let dir = tempdir().unwrap();
+        let mut cache = PlanCache::with_root::<Runtime>(dir.path().to_path_buf(), &TestDevice);
+
+        assert!(cache.load::<Runtime>(&TestDevice).is_empty());
+        assert!(cache.is_due());
+        cache.save::<Runtime>(&[plan()]);
+        assert!(!cache.is_due());
+
+        let plans = cache.load::<Runtime>(&TestDevice);
+        let expected = plan();
+
+        assert_eq!(plans.len(), 1);
+        assert_eq!(plans[0].operations, expected.operations);
+        assert_eq!(plans[0].triggers, expected.triggers);
+        assert_eq!(
+            plans[0].optimization.strategy,
+            expected.optimization.strategy
+        );
+        assert_eq!(
+            plans[0].optimization.ordering,
+            expected.optimization.ordering
+        );
+    }
+
+    #[test]
+    fn plan_cache_should_ignore_stale_plans() {
+        let dir = tempdir().unwrap();
+        let mut cache = PlanCache::with_root::<Runtime>(dir.path().to_path_buf(), &TestDevice);
+        cache.save::<Runtime>(&[plan()]);
+
+        let stale = |key: PlanCacheKey| PlanCache {
+            path: cache.path.clone(),
+            key,
+            last_save: None,
+        };
+        let outdated = stale(PlanCacheKey {
+            version: "0.0.0".to_string(),
+            ..cache.key.clone()
+        });
+        let other_runtime = stale(PlanCacheKey {
+            runtime: "other".to_string(),
+            ..cache.key.clone()
+        });
+        let other_device = stale(PlanCacheKey {
+            device: "other".to_string(),
+            ..cache.key.clone()
+        });
+
+        assert!(outdated.load::<Runtime>(&TestDevice).is_empty());
+        assert!(other_runtime.load::<Runtime>(&TestDevice).is_empty());
+        assert!(other_device.load::<Runtime>(&TestDevice).is_empty());
+
+        std::fs::write(&cache.path, "{").unwrap();
+        assert!(cache.load::<Runtime>(&TestDevice).is_empty());
+    }
+
+    #[test]
+    fn plan_cache_should_be_stored_per_runtime() {
+        let dir = tempdir().unwrap();
+        let cache =
+            PlanCache::with_root::<TestRuntime<false>>(dir.path().to_path_buf(), &TestDevice);
+        let other =
+            PlanCache::with_root::<TestRuntime<true>>(dir.path().to_path_buf(), &TestDevice);
+
+        assert_ne!(cache.path, other.path);
+        assert_ne!(cache.key, other.key);
+    }
+
+    fn plan() -> ExecutionPlan<TestOptimization> {
+        let input = TensorIr {
+            id: TensorId::new(0),
+            shape: vec![2],
+            status: TensorStatus::ReadOnly,
+            dtype: DType::F32,
+        };
+        let out = TensorIr {
+            id: TensorId::new(1),
+            status: TensorStatus::NotInit,
+            ..input.clone()
+        };
+
+        ExecutionPlan {
+            operations: vec![OperationIr::Float(
+                DType::F32,
+                FloatOperationIr::Exp(UnaryOpIr { input, out }),
+            )],
+            triggers: vec![ExecutionTrigger::OnSync],
+            optimization: BlockOptimization::new(ExecutionStrategy::operations(1), vec![0]),
+        }
+    }
+}
@@ -1,5 +1,9 @@
 mod base;
+#[cfg(feature = "cache")]
+mod cache;
 mod index;
 
 pub(crate) use base::*;
+#[cfg(feature = "cache")]
+pub(crate) use cache::*;
 pub(super) use index::*;
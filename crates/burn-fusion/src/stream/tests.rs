@@ -0,0 +1,122 @@
+//! Mock runtime types to test the streams.
+
+use burn_ir::{OperationIr, optim::PassPipeline};
+use burn_tensor::backend::{DeviceId, DeviceOps};
+
+use crate::{
+    FusionRuntime, NumOperations, Optimization, OptimizationBuilder, OptimizationProperties,
+    OptimizationStatus,
+    client::MutexFusionClient,
+    stream::{Context, OrderedExecution},
+};
+
+/// A runtime executing every operation on its own, with the default passes when `PASSES`
+/// is true.
+#[derive(Debug)]
+pub struct TestRuntime<const PASSES: bool>;
+
+impl<const PASSES: bool> FusionRuntime for TestRuntime<PASSES> {
+    type OptimizationState = ();
+    type Optimization = TestOptimization;
+    type FusionHandle = Vec<f32>;
+    type FusionDevice = TestDevice;
+    type FusionClient = MutexFusionClient<Self>;
+    type BoolRepr = u8;
+
+    fn optimizations(
+        _device: Self::FusionDevice,
+    ) -> Vec<Box<dyn OptimizationBuilder<Self::Optimization>>> {
+        vec![Box::new(TestOptimizationBuilder::default())]
+    }
+
+    fn passes(_device: &Self::FusionDevice) -> PassPipeline {
+        match PASSES {
+            true => PassPipeline::default(),
+            false => PassPipeline::empty(),
+        }
+    }
+}
+
+/// The device of the [test runtime](TestRuntime).
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TestDevice;
+
+impl DeviceOps for TestDevice {}
+
+impl burn_common::device::Device for TestDevice {
+    fn from_id(_device_id: DeviceId) -> Self {
+        Self
+    }
+
+    fn to_id(&self) -> DeviceId {
+        DeviceId {
+            type_id: 0,
+            index_id: 0,
+        }
+    }
+
+    fn device_count(_type_id: u16) -> usize {
+        1
+    }
+}
+
+/// A builder that is never ready, so that operations are queued until the stream is drained.
+#[derive(Clone, Default)]
+pub struct TestOptimizationBuilder {
+    len: usize,
+}
+
+impl OptimizationBuilder<TestOptimization> for TestOptimizationBuilder {
+    fn register(&mut self, _operation: &OperationIr) {
+        self.len += 1;
+    }
+
+    fn build(&self) -> TestOptimization {
+        unreachable!("The builder is never ready.")
+    }
+
+    fn reset(&mut self) {
+        self.len = 0;
+    }
+
+    fn status(&self) -> OptimizationStatus {
+        OptimizationStatus::Open
+    }
+
+    fn properties(&self) -> OptimizationProperties {
+        OptimizationProperties::default()
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn clone_dyn(&self) -> Box<dyn OptimizationBuilder<TestOptimization>> {
+        Box::new(self.clone())
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct TestOptimization;
+
+impl NumOperations for TestOptimization {
+    fn len(&self) -> usize {
+        0
+    }
+}
+
+impl<const PASSES: bool> Optimization<TestRuntime<PASSES>> for TestOptimization {
+    fn execute(
+        &mut self,
+        _context: &mut Context<'_, Vec<f32>>,
+        _execution: &OrderedExecution<TestRuntime<PASSES>>,
+    ) {
+        unreachable!("The optimization is never built.")
+    }
+
+    fn to_state(&self) {}
+
+    fn from_state(_device: &TestDevice, _state: ()) -> Self {
+        Self
+    }
+}
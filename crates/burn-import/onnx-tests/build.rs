@@ -37,6 +37,7 @@ fn main() {
         .input("tests/attention/attention_qk_output_3.onnx")
         .input("tests/avg_pool1d/avg_pool1d.onnx")
         .input("tests/avg_pool2d/avg_pool2d.onnx")
+        .input("tests/avg_pool3d/avg_pool3d.onnx")
         .input("tests/batch_norm/batch_norm.onnx")
         .input("tests/bitshift/bitshift_left.onnx")
         .input("tests/bitshift/bitshift_left_scalar.onnx")
@@ -97,6 +98,7 @@ fn main() {
         .input("tests/conv_transpose3d/conv_transpose3d.onnx")
         .input("tests/cos/cos.onnx")
         .input("tests/cosh/cosh.onnx")
+        .input("tests/cumsum/cumsum.onnx")
         .input("tests/depth_to_space/depth_to_space_dcr.onnx")
         .input("tests/depth_to_space/depth_to_space_crd.onnx")
         .input("tests/div/div.onnx")
@@ -104,6 +106,7 @@ fn main() {
         .input("tests/div/div_shape_tensor.onnx")
         .input("tests/div/div_broadcast.onnx")
         .input("tests/dropout/dropout.onnx")
+        .input("tests/einsum/einsum.onnx")
         .input("tests/equal/equal.onnx")
         .input("tests/equal/equal_shape.onnx")
         .input("tests/equal/equal_two_shapes.onnx")
@@ -124,6 +127,8 @@ fn main() {
         .input("tests/gather/gather_with_shape_indices.onnx")
         .input("tests/gather/gather_scalar_out.onnx")
         .input("tests/gather_elements/gather_elements.onnx")
+        .input("tests/gather_nd/gather_nd.onnx")
+        .input("tests/gather_nd/gather_nd_constant_indices.onnx")
         .input("tests/gelu/gelu.onnx")
         .input("tests/gemm/gemm.onnx")
         .input("tests/gemm/gemm_non_unit_alpha_beta.onnx")
@@ -137,11 +142,20 @@ fn main() {
         .input("tests/greater_or_equal/greater_or_equal_scalar.onnx")
         .input("tests/greater_or_equal/greater_or_equal_broadcast.onnx")
         .input("tests/group_norm/group_norm.onnx")
+        .input("tests/gru/gru.onnx")
+        .input("tests/gru/gru_bidirectional.onnx")
         .input("tests/hard_sigmoid/hard_sigmoid.onnx")
         .input("tests/identity/identity_constant.onnx")
         .input("tests/identity/identity_passthrough.onnx")
         .input("tests/identity/identity_chain.onnx")
         .input("tests/identity/identity_only.onnx")
+        .input("tests/if_op/if_op.onnx")
+        .input("tests/loop_op/loop_op.onnx")
+        .input("tests/lstm/lstm.onnx")
+        .input("tests/lstm/lstm_bidirectional.onnx")
+        .input("tests/scan/scan.onnx")
+        .input("tests/scatter_nd/scatter_nd.onnx")
+        .input("tests/scatter_elements/scatter_elements.onnx")
         .input("tests/instance_norm1d/instance_norm1d.onnx")
         .input("tests/instance_norm2d/instance_norm2d.onnx")
         .input("tests/instance_norm3d/instance_norm3d.onnx")
@@ -178,6 +192,7 @@ fn main() {
         .input("tests/max/max.onnx")
         .input("tests/maxpool1d/maxpool1d.onnx")
         .input("tests/maxpool2d/maxpool2d.onnx")
+        .input("tests/maxpool3d/maxpool3d.onnx")
         .input("tests/min/min.onnx")
         .input("tests/mean/mean.onnx")
         .input("tests/mul/mul.onnx")
@@ -185,6 +200,8 @@ fn main() {
         .input("tests/mul/mul_shape_tensor.onnx")
         .input("tests/mul/mul_broadcast.onnx")
         .input("tests/neg/neg.onnx")
+        .input("tests/non_max_suppression/non_max_suppression.onnx")
+        .input("tests/nonzero/nonzero.onnx")
         .input("tests/not/not.onnx")
         .input("tests/one_hot/one_hot.onnx")
         .input("tests/or/or.onnx")
@@ -193,6 +210,8 @@ fn main() {
         .input("tests/pow/pow.onnx")
         .input("tests/pow/pow_int.onnx")
         .input("tests/prelu/prelu.onnx")
+        .input("tests/quantization/qdq_conv.onnx")
+        .input("tests/quantization/qlinear_matmul.onnx")
         .input("tests/random_normal/random_normal.onnx")
         .input("tests/random_normal_like/random_normal_like.onnx")
         .input("tests/random_uniform/random_uniform.onnx")
@@ -1,6 +1,6 @@
 // Import the shared macro
 use crate::include_models;
-include_models!(avg_pool1d, avg_pool2d);
+include_models!(avg_pool1d, avg_pool2d, avg_pool3d);
 
 #[cfg(test)]
 mod tests {
@@ -113,4 +113,74 @@ mod tests {
             .to_data()
             .assert_approx_eq::<FT>(&expected3, tolerance);
     }
+
+    #[test]
+    fn avg_pool3d() {
+        // Initialize the model without weights (because the exported file does not contain them)
+        let device = Default::default();
+        let model: avg_pool3d::Model<TestBackend> = avg_pool3d::Model::new(&device);
+
+        // Run the model
+        let input = Tensor::<TestBackend, 5>::from_floats(
+            [[[
+                [
+                    [-1.884, 0.333, -0.062, -0.429],
+                    [-1.360, 0.521, 1.262, -0.913],
+                    [0.796, -0.283, -0.550, 1.227],
+                    [-0.120, -1.535, 0.646, 0.327],
+                ],
+                [
+                    [-0.300, -1.795, 1.906, 0.227],
+                    [-1.888, 1.081, 1.054, 0.767],
+                    [-0.340, -1.547, 0.906, 1.467],
+                    [1.720, 1.489, 0.726, -0.681],
+                ],
+                [
+                    [-1.404, -0.147, 1.538, -1.741],
+                    [-1.232, -1.431, -0.914, -1.329],
+                    [-1.316, 0.133, -1.286, 1.675],
+                    [1.832, 1.249, -1.562, 1.831],
+                ],
+            ]]],
+            &device,
+        );
+        let (output1, output2) = model.forward(input);
+        // Padding is excluded from the average.
+        let expected1 = TensorData::from([[[
+            [
+                [-1.092f32, 0.0955, -0.101],
+                [-0.698, 0.3055, 0.637],
+                [0.800, 0.3315, -0.177],
+            ],
+            [
+                [-0.852, 0.3755, -0.757],
+                [-1.194, -0.2505, 0.645],
+                [1.776, 0.4755, 0.575],
+            ],
+        ]]]);
+        // Padding is included in the average.
+        let expected2 = TensorData::from([[[
+            [
+                [-0.273f32, 0.0477, -0.0252],
+                [-0.349, 0.3055, 0.3185],
+                [0.200, 0.1658, -0.0443],
+            ],
+            [
+                [-0.213, 0.1878, -0.1893],
+                [-0.597, -0.2505, 0.3225],
+                [0.444, 0.2377, 0.1437],
+            ],
+        ]]]);
+
+        assert_eq!(output1.shape(), Shape::from([1, 1, 2, 3, 3]));
+        assert_eq!(output2.shape(), Shape::from([1, 1, 2, 3, 3]));
+
+        let tolerance = Tolerance::absolute(1e-3);
+        output1
+            .to_data()
+            .assert_approx_eq::<FT>(&expected1, tolerance);
+        output2
+            .to_data()
+            .assert_approx_eq::<FT>(&expected2, tolerance);
+    }
 }
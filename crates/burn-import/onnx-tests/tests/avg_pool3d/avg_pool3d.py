@@ -0,0 +1,38 @@
+#!/usr/bin/env python3
+"""Generate avg_pool3d.onnx using only the onnx library (no PyTorch required)"""
+
+import onnx
+from onnx import helper, TensorProto
+
+# AveragePool with a 3D kernel, remapped to AveragePool3d, with and without counting the padding
+nodes = [
+    helper.make_node(
+        'AveragePool',
+        inputs=['input'],
+        outputs=[output],
+        kernel_shape=[2, 2, 2],
+        strides=[1, 2, 2],
+        pads=[0, 1, 1, 0, 1, 1],
+        count_include_pad=count_include_pad,
+    )
+    for output, count_include_pad in [('output1', 0), ('output2', 1)]
+]
+
+graph_def = helper.make_graph(
+    nodes,
+    'avg_pool3d_model',
+    [helper.make_tensor_value_info('input', TensorProto.FLOAT, [1, 1, 3, 4, 4])],
+    [
+        helper.make_tensor_value_info('output1', TensorProto.FLOAT, [1, 1, 2, 3, 3]),
+        helper.make_tensor_value_info('output2', TensorProto.FLOAT, [1, 1, 2, 3, 3]),
+    ],
+)
+
+model_def = helper.make_model(
+    graph_def,
+    producer_name='avg_pool3d_test',
+    opset_imports=[helper.make_opsetid("", 16)]
+)
+
+onnx.save(model_def, 'avg_pool3d.onnx')
+print("Generated avg_pool3d.onnx")
@@ -0,0 +1,62 @@
+#!/usr/bin/env python3
+
+# used to generate model: cumsum.onnx
+
+import numpy as np
+import onnx
+import onnx.helper
+from onnx import TensorProto
+from onnx.reference import ReferenceEvaluator
+
+
+def build_model():
+    x = onnx.helper.make_tensor_value_info("x", TensorProto.FLOAT, [2, 3])
+    y = onnx.helper.make_tensor_value_info("y", TensorProto.FLOAT, [2, 3])
+    y_reverse = onnx.helper.make_tensor_value_info(
+        "y_reverse", TensorProto.FLOAT, [2, 3]
+    )
+
+    cumsum = onnx.helper.make_node(
+        "CumSum", inputs=["x", "axis"], outputs=["y"], name="CumSumNode"
+    )
+    cumsum_reverse = onnx.helper.make_node(
+        "CumSum",
+        inputs=["x", "axis"],
+        outputs=["y_reverse"],
+        name="CumSumReverseNode",
+        exclusive=1,
+        reverse=1,
+    )
+
+    graph = onnx.helper.make_graph(
+        [cumsum, cumsum_reverse],
+        "CumSumModel",
+        [x],
+        [y, y_reverse],
+        initializer=[onnx.helper.make_tensor("axis", TensorProto.INT64, [], [1])],
+    )
+
+    model = onnx.helper.make_model(
+        opset_imports=[onnx.helper.make_operatorsetid("", 16)],
+        graph=graph,
+        producer_name="ONNX_Generator",
+    )
+
+    return model
+
+
+if __name__ == "__main__":
+    onnx_model = build_model()
+    file_name = "cumsum.onnx"
+
+    onnx.checker.check_model(onnx_model)
+    onnx.save(onnx_model, file_name)
+    print(f"Finished exporting model to {file_name}")
+
+    x = np.array([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], dtype=np.float32)
+    print(f"Test input data: {repr(x)}")
+
+    session = ReferenceEvaluator(file_name, verbose=1)
+    y, y_reverse = session.run(None, {"x": x})
+    print(f"Test output y: {repr(y)}")
+    print(f"Test output y_reverse: {repr(y_reverse)}")
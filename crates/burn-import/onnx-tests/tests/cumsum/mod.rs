@@ -0,0 +1,28 @@
+// Import the shared macro
+use crate::include_models;
+include_models!(cumsum);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn::tensor::{Tensor, TensorData};
+
+    use crate::backend::TestBackend;
+
+    #[test]
+    fn cumsum() {
+        let device = Default::default();
+        let model: cumsum::Model<TestBackend> = cumsum::Model::new(&device);
+
+        let input = Tensor::<TestBackend, 2>::from_floats([[1., 2., 3.], [4., 5., 6.]], &device);
+        let (output, output_reverse) = model.forward(input);
+
+        output
+            .to_data()
+            .assert_eq(&TensorData::from([[1f32, 3., 6.], [4., 9., 15.]]), true);
+        // Exclusive sums from the end of the rows
+        output_reverse
+            .to_data()
+            .assert_eq(&TensorData::from([[5f32, 3., 0.], [11., 6., 0.]]), true);
+    }
+}
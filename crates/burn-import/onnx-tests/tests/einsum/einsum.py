@@ -0,0 +1,51 @@
+#!/usr/bin/env python3
+
+# used to generate model: einsum.onnx
+
+import numpy as np
+import onnx
+import onnx.helper
+from onnx import TensorProto
+from onnx.reference import ReferenceEvaluator
+
+
+def build_model():
+    a = onnx.helper.make_tensor_value_info("a", TensorProto.FLOAT, [2, 2, 3])
+    b = onnx.helper.make_tensor_value_info("b", TensorProto.FLOAT, [2, 3])
+    y = onnx.helper.make_tensor_value_info("y", TensorProto.FLOAT, [2, 2])
+
+    # Batched matrix-vector products, with the batch dimension last
+    einsum = onnx.helper.make_node(
+        "Einsum",
+        inputs=["a", "b"],
+        outputs=["y"],
+        name="EinsumNode",
+        equation="bij,bj->ib",
+    )
+
+    graph = onnx.helper.make_graph([einsum], "EinsumModel", [a, b], [y])
+
+    model = onnx.helper.make_model(
+        opset_imports=[onnx.helper.make_operatorsetid("", 16)],
+        graph=graph,
+        producer_name="ONNX_Generator",
+    )
+
+    return model
+
+
+if __name__ == "__main__":
+    onnx_model = build_model()
+    file_name = "einsum.onnx"
+
+    onnx.checker.check_model(onnx_model)
+    onnx.save(onnx_model, file_name)
+    print(f"Finished exporting model to {file_name}")
+
+    a = np.arange(12, dtype=np.float32).reshape(2, 2, 3)
+    b = np.array([[1.0, 0.0, -1.0], [2.0, 1.0, 0.0]], dtype=np.float32)
+    print(f"Test input data: {repr(a)}, {repr(b)}")
+
+    session = ReferenceEvaluator(file_name, verbose=1)
+    (y,) = session.run(None, {"a": a, "b": b})
+    print(f"Test output: {repr(y)}")
@@ -0,0 +1,28 @@
+// Import the shared macro
+use crate::include_models;
+include_models!(einsum);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn::tensor::{Tensor, TensorData};
+
+    use crate::backend::TestBackend;
+
+    #[test]
+    fn einsum() {
+        let device = Default::default();
+        let model: einsum::Model<TestBackend> = einsum::Model::new(&device);
+
+        let a = Tensor::<TestBackend, 3>::from_floats(
+            [[[0., 1., 2.], [3., 4., 5.]], [[6., 7., 8.], [9., 10., 11.]]],
+            &device,
+        );
+        let b = Tensor::<TestBackend, 2>::from_floats([[1., 0., -1.], [2., 1., 0.]], &device);
+        let output = model.forward(a, b);
+
+        output
+            .to_data()
+            .assert_eq(&TensorData::from([[-2f32, 19.], [-2., 28.]]), true);
+    }
+}
@@ -0,0 +1,41 @@
+// Import the shared macro
+use crate::include_models;
+include_models!(gather_nd, gather_nd_constant_indices);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn::tensor::{Int, Tensor, TensorData};
+
+    use crate::backend::TestBackend;
+
+    #[test]
+    fn gather_nd() {
+        let device = Default::default();
+        let model: gather_nd::Model<TestBackend> = gather_nd::Model::new(&device);
+
+        let data = Tensor::<TestBackend, 1>::from_floats([0., 1., 2., 3., 4., 5., 6., 7.], &device)
+            .reshape([2, 2, 2]);
+        let indices = Tensor::<TestBackend, 2, Int>::from_ints([[0, 1], [1, 0]], &device);
+        let output = model.forward(data, indices);
+
+        output
+            .to_data()
+            .assert_eq(&TensorData::from([[2f32, 3.], [4., 5.]]), true);
+    }
+
+    #[test]
+    fn gather_nd_constant_indices() {
+        let device = Default::default();
+        let model: gather_nd_constant_indices::Model<TestBackend> =
+            gather_nd_constant_indices::Model::new(&device);
+
+        let data = Tensor::<TestBackend, 1>::from_floats([0., 1., 2., 3., 4., 5., 6., 7.], &device)
+            .reshape([2, 2, 2]);
+        let output = model.forward(data);
+
+        output
+            .to_data()
+            .assert_eq(&TensorData::from([[2f32, 3.], [4., 5.]]), true);
+    }
+}
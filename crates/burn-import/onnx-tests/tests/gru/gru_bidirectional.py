@@ -0,0 +1,89 @@
+#!/usr/bin/env python3
+
+# used to generate model: gru_bidirectional.onnx
+
+import numpy as np
+import onnx
+import onnx.helper
+from onnx import TensorProto
+from onnx.reference import ReferenceEvaluator
+
+SEQ_LENGTH = 3
+BATCH_SIZE = 2
+INPUT_SIZE = 2
+HIDDEN_SIZE = 2
+
+
+def weights(name, shape, step):
+    # Small deterministic values, so that the expected outputs are easy to reproduce.
+    size = int(np.prod(shape))
+    values = [round(0.1 * ((i * step) % 7 - 3), 1) for i in range(size)]
+    return onnx.helper.make_tensor(name, TensorProto.FLOAT, shape, values)
+
+
+def build_model():
+    x = onnx.helper.make_tensor_value_info(
+        "x", TensorProto.FLOAT, [SEQ_LENGTH, BATCH_SIZE, INPUT_SIZE]
+    )
+    initial_h = onnx.helper.make_tensor_value_info(
+        "initial_h", TensorProto.FLOAT, [2, BATCH_SIZE, HIDDEN_SIZE]
+    )
+    y = onnx.helper.make_tensor_value_info(
+        "y", TensorProto.FLOAT, [SEQ_LENGTH, 2, BATCH_SIZE, HIDDEN_SIZE]
+    )
+    y_h = onnx.helper.make_tensor_value_info(
+        "y_h", TensorProto.FLOAT, [2, BATCH_SIZE, HIDDEN_SIZE]
+    )
+
+    # The forward and reverse weights are stacked along the first dimension.
+    gru = onnx.helper.make_node(
+        "GRU",
+        inputs=["x", "w", "r", "b", "", "initial_h"],
+        outputs=["y", "y_h"],
+        name="GruNode",
+        direction="bidirectional",
+        hidden_size=HIDDEN_SIZE,
+    )
+
+    graph = onnx.helper.make_graph(
+        [gru],
+        "GruBidirectionalModel",
+        [x, initial_h],
+        [y, y_h],
+        initializer=[
+            weights("w", [2, 3 * HIDDEN_SIZE, INPUT_SIZE], 3),
+            weights("r", [2, 3 * HIDDEN_SIZE, HIDDEN_SIZE], 5),
+            weights("b", [2, 6 * HIDDEN_SIZE], 2),
+        ],
+    )
+
+    model = onnx.helper.make_model(
+        opset_imports=[onnx.helper.make_operatorsetid("", 16)],
+        graph=graph,
+        producer_name="ONNX_Generator",
+    )
+
+    return model
+
+
+if __name__ == "__main__":
+    np.set_printoptions(precision=8)
+
+    onnx_model = build_model()
+    file_name = "gru_bidirectional.onnx"
+
+    onnx.checker.check_model(onnx_model)
+    onnx.save(onnx_model, file_name)
+    print(f"Finished exporting model to {file_name}")
+
+    x = np.arange(SEQ_LENGTH * BATCH_SIZE * INPUT_SIZE, dtype=np.float32)
+    x = (x * 0.1).reshape(SEQ_LENGTH, BATCH_SIZE, INPUT_SIZE)
+    initial_h = np.array(
+        [[[0.1, -0.1], [0.2, 0.3]], [[-0.2, 0.1], [0.0, 0.4]]], dtype=np.float32
+    )
+    print(f"Test input data: {repr(x)} {repr(initial_h)}")
+
+    session = ReferenceEvaluator(file_name, verbose=1)
+    y, y_h = session.run(None, {"x": x, "initial_h": initial_h})
+    print(f"Test output y: {repr(y)}")
+    print(f"Test output y_h: {repr(y_h)}")
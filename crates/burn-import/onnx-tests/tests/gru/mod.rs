@@ -0,0 +1,83 @@
+// Import the shared macro
+use crate::include_models;
+include_models!(gru, gru_bidirectional);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn::tensor::{Int, Tensor, TensorData, Tolerance, ops::FloatElem};
+
+    use crate::backend::TestBackend;
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn gru_reverse_sequence_lens() {
+        let device = Default::default();
+        let model: gru::Model<TestBackend> = gru::Model::new(&device);
+
+        // [seq_length, batch_size, input_size]
+        let x = Tensor::<TestBackend, 1>::from_floats(
+            [0.0, 0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 1.0, 1.1],
+            &device,
+        )
+        .reshape([3, 2, 2]);
+        let sequence_lens = Tensor::<TestBackend, 1, Int>::from_ints([3, 2], &device);
+        let (y, y_h) = model.forward(x, sequence_lens);
+
+        // The second sequence starts from its second step, and its last output is zero.
+        let expected_y = TensorData::from([
+            [[[0.051254f32, -0.088608], [0.067507, -0.060496]]],
+            [[[0.118774, -0.042625], [0.089525, -0.018546]]],
+            [[[0.125652, -0.005881], [0.0, 0.0]]],
+        ]);
+        let expected_y_h = TensorData::from([[[0.051254f32, -0.088608], [0.067507, -0.060496]]]);
+
+        y.to_data()
+            .assert_approx_eq::<FT>(&expected_y, Tolerance::default());
+        y_h.to_data()
+            .assert_approx_eq::<FT>(&expected_y_h, Tolerance::default());
+    }
+
+    #[test]
+    fn gru_bidirectional_initial_state() {
+        let device = Default::default();
+        let model: gru_bidirectional::Model<TestBackend> = gru_bidirectional::Model::new(&device);
+
+        // [seq_length, batch_size, input_size]
+        let x = Tensor::<TestBackend, 1>::from_floats(
+            [0.0, 0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 1.0, 1.1],
+            &device,
+        )
+        .reshape([3, 2, 2]);
+        let initial_h = Tensor::<TestBackend, 3>::from_floats(
+            [[[0.1, -0.1], [0.2, 0.3]], [[-0.2, 0.1], [0.0, 0.4]]],
+            &device,
+        );
+        let (y, y_h) = model.forward(x, initial_h);
+
+        // [seq_length, num_directions, batch_size, hidden_size]
+        let expected_y = TensorData::from([
+            [
+                [[0.121009f32, -0.162577], [0.204123, 0.039457]],
+                [[-0.110497, 0.397567], [-0.148763, 0.48755]],
+            ],
+            [
+                [[0.198333, -0.171716], [0.271225, -0.059814]],
+                [[-0.162334, 0.400877], [-0.18281, 0.522157]],
+            ],
+            [
+                [[0.303353, -0.158484], [0.368782, -0.091244]],
+                [[-0.19373, 0.33102], [-0.158084, 0.512648]],
+            ],
+        ]);
+        let expected_y_h = TensorData::from([
+            [[0.303353f32, -0.158484], [0.368782, -0.091244]],
+            [[-0.110497, 0.397567], [-0.148763, 0.48755]],
+        ]);
+
+        y.to_data()
+            .assert_approx_eq::<FT>(&expected_y, Tolerance::default());
+        y_h.to_data()
+            .assert_approx_eq::<FT>(&expected_y_h, Tolerance::default());
+    }
+}
@@ -0,0 +1,66 @@
+#!/usr/bin/env python3
+
+# used to generate model: if_op.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def main():
+    # The then branch adds a constant defined in the branch to the outer input "x".
+    then_branch = helper.make_graph(
+        nodes=[
+            helper.make_node(
+                "Constant",
+                inputs=[],
+                outputs=["offset"],
+                value=helper.make_tensor(
+                    name="offset_value",
+                    data_type=TensorProto.FLOAT,
+                    dims=[2, 3],
+                    vals=[1.0, 2.0, 3.0, 4.0, 5.0, 6.0],
+                ),
+            ),
+            helper.make_node("Add", inputs=["x", "offset"], outputs=["then_out"]),
+        ],
+        name="then_branch",
+        inputs=[],
+        outputs=[helper.make_tensor_value_info("then_out", TensorProto.FLOAT, [2, 3])],
+    )
+
+    # The else branch negates the outer input "x".
+    else_branch = helper.make_graph(
+        nodes=[helper.make_node("Neg", inputs=["x"], outputs=["else_out"])],
+        name="else_branch",
+        inputs=[],
+        outputs=[helper.make_tensor_value_info("else_out", TensorProto.FLOAT, [2, 3])],
+    )
+
+    if_node = helper.make_node(
+        "If",
+        inputs=["cond"],
+        outputs=["output"],
+        then_branch=then_branch,
+        else_branch=else_branch,
+    )
+
+    graph = helper.make_graph(
+        nodes=[if_node],
+        name="IfTest",
+        inputs=[
+            helper.make_tensor_value_info("cond", TensorProto.BOOL, []),
+            helper.make_tensor_value_info("x", TensorProto.FLOAT, [2, 3]),
+        ],
+        outputs=[helper.make_tensor_value_info("output", TensorProto.FLOAT, [2, 3])],
+    )
+
+    model = helper.make_model(
+        graph, producer_name="if_op_test", opset_imports=[helper.make_opsetid("", 16)]
+    )
+
+    onnx.save(model, "if_op.onnx")
+    print("Finished exporting model to if_op.onnx")
+
+
+if __name__ == "__main__":
+    main()
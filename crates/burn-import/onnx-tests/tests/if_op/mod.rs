@@ -0,0 +1,38 @@
+// Import the shared macro
+use crate::include_models;
+include_models!(if_op);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn::tensor::{Tensor, TensorData};
+
+    use crate::backend::TestBackend;
+
+    #[test]
+    fn if_op_then_branch() {
+        let device = Default::default();
+        let model: if_op::Model<TestBackend> = if_op::Model::default();
+
+        let input =
+            Tensor::<TestBackend, 2>::from_floats([[1.0, -2.0, 3.0], [-4.0, 5.0, -6.0]], &device);
+        let output = model.forward(true, input);
+
+        // The then branch adds the constant [[1, 2, 3], [4, 5, 6]] defined in the branch.
+        let expected = TensorData::from([[2.0f32, 0.0, 6.0], [0.0, 10.0, 0.0]]);
+        output.to_data().assert_eq(&expected, true);
+    }
+
+    #[test]
+    fn if_op_else_branch() {
+        let device = Default::default();
+        let model: if_op::Model<TestBackend> = if_op::Model::default();
+
+        let input =
+            Tensor::<TestBackend, 2>::from_floats([[1.0, -2.0, 3.0], [-4.0, 5.0, -6.0]], &device);
+        let output = model.forward(false, input);
+
+        let expected = TensorData::from([[-1.0f32, 2.0, -3.0], [4.0, -5.0, 6.0]]);
+        output.to_data().assert_eq(&expected, true);
+    }
+}
@@ -0,0 +1,62 @@
+#!/usr/bin/env python3
+
+# used to generate model: loop_op.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def main():
+    # The body adds the outer input "x" to the state, and also outputs the new state as a scan
+    # output. The condition is passed through unchanged.
+    body = helper.make_graph(
+        nodes=[
+            helper.make_node("Identity", inputs=["cond_in"], outputs=["cond_out"]),
+            helper.make_node("Add", inputs=["state_in", "x"], outputs=["state_out"]),
+            helper.make_node("Identity", inputs=["state_out"], outputs=["scan_out"]),
+        ],
+        name="body",
+        inputs=[
+            helper.make_tensor_value_info("iteration", TensorProto.INT64, []),
+            helper.make_tensor_value_info("cond_in", TensorProto.BOOL, []),
+            helper.make_tensor_value_info("state_in", TensorProto.FLOAT, [2, 3]),
+        ],
+        outputs=[
+            helper.make_tensor_value_info("cond_out", TensorProto.BOOL, []),
+            helper.make_tensor_value_info("state_out", TensorProto.FLOAT, [2, 3]),
+            helper.make_tensor_value_info("scan_out", TensorProto.FLOAT, [2, 3]),
+        ],
+    )
+
+    loop_node = helper.make_node(
+        "Loop",
+        inputs=["trip_count", "cond", "initial_state"],
+        outputs=["final_state", "states"],
+        body=body,
+    )
+
+    graph = helper.make_graph(
+        nodes=[loop_node],
+        name="LoopTest",
+        inputs=[
+            helper.make_tensor_value_info("trip_count", TensorProto.INT64, []),
+            helper.make_tensor_value_info("cond", TensorProto.BOOL, []),
+            helper.make_tensor_value_info("initial_state", TensorProto.FLOAT, [2, 3]),
+            helper.make_tensor_value_info("x", TensorProto.FLOAT, [2, 3]),
+        ],
+        outputs=[
+            helper.make_tensor_value_info("final_state", TensorProto.FLOAT, [2, 3]),
+            helper.make_tensor_value_info("states", TensorProto.FLOAT, [None, 2, 3]),
+        ],
+    )
+
+    model = helper.make_model(
+        graph, producer_name="loop_op_test", opset_imports=[helper.make_opsetid("", 16)]
+    )
+
+    onnx.save(model, "loop_op.onnx")
+    print("Finished exporting model to loop_op.onnx")
+
+
+if __name__ == "__main__":
+    main()
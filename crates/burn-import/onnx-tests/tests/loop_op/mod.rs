@@ -0,0 +1,32 @@
+// Import the shared macro
+use crate::include_models;
+include_models!(loop_op);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn::tensor::{Tensor, TensorData};
+
+    use crate::backend::TestBackend;
+
+    #[test]
+    fn loop_op() {
+        let device = Default::default();
+        let model: loop_op::Model<TestBackend> = loop_op::Model::new(&device);
+
+        let initial_state = Tensor::<TestBackend, 2>::zeros([2, 3], &device);
+        let input =
+            Tensor::<TestBackend, 2>::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+        let (final_state, states) = model.forward(3, true, initial_state, input);
+
+        let expected_final_state = TensorData::from([[3.0f32, 6.0, 9.0], [12.0, 15.0, 18.0]]);
+        let expected_states = TensorData::from([
+            [[1.0f32, 2.0, 3.0], [4.0, 5.0, 6.0]],
+            [[2.0, 4.0, 6.0], [8.0, 10.0, 12.0]],
+            [[3.0, 6.0, 9.0], [12.0, 15.0, 18.0]],
+        ]);
+
+        final_state.to_data().assert_eq(&expected_final_state, true);
+        states.to_data().assert_eq(&expected_states, true);
+    }
+}
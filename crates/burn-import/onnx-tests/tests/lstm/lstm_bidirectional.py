@@ -0,0 +1,84 @@
+#!/usr/bin/env python3
+
+# used to generate model: lstm_bidirectional.onnx
+
+import numpy as np
+import onnx
+import onnx.helper
+from onnx import TensorProto
+from onnx.reference import ReferenceEvaluator
+
+SEQ_LENGTH = 3
+BATCH_SIZE = 2
+INPUT_SIZE = 2
+HIDDEN_SIZE = 2
+
+
+def weights(name, shape, step):
+    # Small deterministic values, so that the expected outputs are easy to reproduce.
+    size = int(np.prod(shape))
+    values = [round(0.1 * ((i * step) % 7 - 3), 1) for i in range(size)]
+    return onnx.helper.make_tensor(name, TensorProto.FLOAT, shape, values)
+
+
+def build_model():
+    # With layout 1, the batch is the first dimension of the input and outputs.
+    x = onnx.helper.make_tensor_value_info(
+        "x", TensorProto.FLOAT, [BATCH_SIZE, SEQ_LENGTH, INPUT_SIZE]
+    )
+    y = onnx.helper.make_tensor_value_info(
+        "y", TensorProto.FLOAT, [BATCH_SIZE, SEQ_LENGTH, 2, HIDDEN_SIZE]
+    )
+    y_h = onnx.helper.make_tensor_value_info(
+        "y_h", TensorProto.FLOAT, [BATCH_SIZE, 2, HIDDEN_SIZE]
+    )
+
+    # The forward and reverse weights are stacked along the first dimension, without bias.
+    lstm = onnx.helper.make_node(
+        "LSTM",
+        inputs=["x", "w", "r"],
+        outputs=["y", "y_h"],
+        name="LstmNode",
+        direction="bidirectional",
+        hidden_size=HIDDEN_SIZE,
+        layout=1,
+    )
+
+    graph = onnx.helper.make_graph(
+        [lstm],
+        "LstmBidirectionalModel",
+        [x],
+        [y, y_h],
+        initializer=[
+            weights("w", [2, 4 * HIDDEN_SIZE, INPUT_SIZE], 3),
+            weights("r", [2, 4 * HIDDEN_SIZE, HIDDEN_SIZE], 5),
+        ],
+    )
+
+    model = onnx.helper.make_model(
+        opset_imports=[onnx.helper.make_operatorsetid("", 16)],
+        graph=graph,
+        producer_name="ONNX_Generator",
+    )
+
+    return model
+
+
+if __name__ == "__main__":
+    np.set_printoptions(precision=8)
+
+    onnx_model = build_model()
+    file_name = "lstm_bidirectional.onnx"
+
+    onnx.checker.check_model(onnx_model)
+    onnx.save(onnx_model, file_name)
+    print(f"Finished exporting model to {file_name}")
+
+    x = np.arange(SEQ_LENGTH * BATCH_SIZE * INPUT_SIZE, dtype=np.float32)
+    x = (x * 0.1).reshape(BATCH_SIZE, SEQ_LENGTH, INPUT_SIZE)
+    print(f"Test input data: {repr(x)}")
+
+    session = ReferenceEvaluator(file_name, verbose=1)
+    y, y_h = session.run(None, {"x": x})
+    print(f"Test output y: {repr(y)}")
+    print(f"Test output y_h: {repr(y_h)}")
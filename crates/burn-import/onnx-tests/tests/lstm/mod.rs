@@ -0,0 +1,80 @@
+// Import the shared macro
+use crate::include_models;
+include_models!(lstm, lstm_bidirectional);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn::tensor::{Tensor, TensorData, Tolerance, ops::FloatElem};
+
+    use crate::backend::TestBackend;
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn lstm_forward() {
+        let device = Default::default();
+        let model: lstm::Model<TestBackend> = lstm::Model::new(&device);
+
+        // [seq_length, batch_size, input_size]
+        let x = Tensor::<TestBackend, 1>::from_floats(
+            [0.0, 0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 1.0, 1.1],
+            &device,
+        )
+        .reshape([3, 2, 2]);
+        let initial_h = Tensor::<TestBackend, 3>::from_floats([[[0.1, -0.1], [0.2, 0.3]]], &device);
+        let (y, y_h, y_c) = model.forward(x, initial_h);
+
+        let expected_y = TensorData::from([
+            [[[-0.016287f32, -0.109139], [-0.028421, -0.100181]]],
+            [[[-0.033926, -0.181255], [-0.043361, -0.18924]]],
+            [[[-0.048008, -0.238455], [-0.05595, -0.249983]]],
+        ]);
+        let expected_y_h = TensorData::from([[[-0.048008f32, -0.238455], [-0.05595, -0.249983]]]);
+        let expected_y_c = TensorData::from([[[-0.081964f32, -0.529137], [-0.095745, -0.573323]]]);
+
+        y.to_data()
+            .assert_approx_eq::<FT>(&expected_y, Tolerance::default());
+        y_h.to_data()
+            .assert_approx_eq::<FT>(&expected_y_h, Tolerance::default());
+        y_c.to_data()
+            .assert_approx_eq::<FT>(&expected_y_c, Tolerance::default());
+    }
+
+    #[test]
+    fn lstm_bidirectional_batch_first() {
+        let device = Default::default();
+        let model: lstm_bidirectional::Model<TestBackend> = lstm_bidirectional::Model::new(&device);
+
+        // [batch_size, seq_length, input_size]
+        let x = Tensor::<TestBackend, 1>::from_floats(
+            [0.0, 0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 1.0, 1.1],
+            &device,
+        )
+        .reshape([2, 3, 2]);
+        let (y, y_h) = model.forward(x);
+
+        // [batch_size, seq_length, num_directions, hidden_size]
+        let expected_y = TensorData::from([
+            [
+                [[0.002475f32, 0.0], [-0.011186, 0.004366]],
+                [[-0.001047, -0.014849], [-0.026838, 0.015694]],
+                [[-0.007203, -0.037375], [-0.029166, 0.018584]],
+            ],
+            [
+                [[-0.011253, -0.043323], [-0.08042, 0.055698]],
+                [[-0.020775, -0.08071], [-0.086583, 0.063137]],
+                [[-0.029424, -0.114022], [-0.070006, 0.05391]],
+            ],
+        ]);
+        // The final state of the reverse direction is its output at the first step.
+        let expected_y_h = TensorData::from([
+            [[-0.007203f32, -0.037375], [-0.011186, 0.004366]],
+            [[-0.029424, -0.114022], [-0.08042, 0.055698]],
+        ]);
+
+        y.to_data()
+            .assert_approx_eq::<FT>(&expected_y, Tolerance::default());
+        y_h.to_data()
+            .assert_approx_eq::<FT>(&expected_y_h, Tolerance::default());
+    }
+}
@@ -1,6 +1,6 @@
 // Import the shared macro
 use crate::include_models;
-include_models!(maxpool1d, maxpool2d);
+include_models!(maxpool1d, maxpool2d, maxpool3d);
 
 #[cfg(test)]
 mod tests {
@@ -61,4 +61,51 @@ mod tests {
 
         output.to_data().assert_eq(&expected, true);
     }
+
+    #[test]
+    fn maxpool3d() {
+        // Initialize the model without weights (because the exported file does not contain them)
+        let device = Default::default();
+        let model: maxpool3d::Model<TestBackend> = maxpool3d::Model::new(&device);
+
+        // Run the model
+        let input = Tensor::<TestBackend, 5>::from_floats(
+            [[[
+                [
+                    [-1.884, 0.333, -0.062, -0.429],
+                    [-1.360, 0.521, 1.262, -0.913],
+                    [0.796, -0.283, -0.550, 1.227],
+                    [-0.120, -1.535, 0.646, 0.327],
+                ],
+                [
+                    [-0.300, -1.795, 1.906, 0.227],
+                    [-1.888, 1.081, 1.054, 0.767],
+                    [-0.340, -1.547, 0.906, 1.467],
+                    [1.720, 1.489, 0.726, -0.681],
+                ],
+                [
+                    [-1.404, -0.147, 1.538, -1.741],
+                    [-1.232, -1.431, -0.914, -1.329],
+                    [-1.316, 0.133, -1.286, 1.675],
+                    [1.832, 1.249, -1.562, 1.831],
+                ],
+            ]]],
+            &device,
+        );
+        let output = model.forward(input);
+        let expected = TensorData::from([[[
+            [
+                [-0.300f32, 1.906, 0.227],
+                [0.796, 1.262, 1.467],
+                [1.720, 1.489, 0.327],
+            ],
+            [
+                [-0.300, 1.906, 0.227],
+                [-0.340, 1.081, 1.675],
+                [1.832, 1.489, 1.831],
+            ],
+        ]]]);
+
+        output.to_data().assert_eq(&expected, true);
+    }
 }
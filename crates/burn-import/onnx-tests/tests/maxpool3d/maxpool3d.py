@@ -0,0 +1,32 @@
+#!/usr/bin/env python3
+"""Generate maxpool3d.onnx using only the onnx library (no PyTorch required)"""
+
+import onnx
+from onnx import helper, TensorProto
+
+# MaxPool with a 3D kernel, remapped to MaxPool3d
+maxpool_node = helper.make_node(
+    'MaxPool',
+    inputs=['input'],
+    outputs=['output'],
+    kernel_shape=[2, 2, 2],
+    strides=[1, 2, 2],
+    pads=[0, 1, 1, 0, 1, 1],
+    dilations=[1, 1, 1],
+)
+
+graph_def = helper.make_graph(
+    [maxpool_node],
+    'maxpool3d_model',
+    [helper.make_tensor_value_info('input', TensorProto.FLOAT, [1, 1, 3, 4, 4])],
+    [helper.make_tensor_value_info('output', TensorProto.FLOAT, [1, 1, 2, 3, 3])],
+)
+
+model_def = helper.make_model(
+    graph_def,
+    producer_name='maxpool3d_test',
+    opset_imports=[helper.make_opsetid("", 16)]
+)
+
+onnx.save(model_def, 'maxpool3d.onnx')
+print("Generated maxpool3d.onnx")
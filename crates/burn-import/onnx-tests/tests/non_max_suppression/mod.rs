@@ -0,0 +1,38 @@
+// Import the shared macro
+use crate::include_models;
+include_models!(non_max_suppression);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn::tensor::{Tensor, TensorData};
+
+    use crate::backend::TestBackend;
+
+    #[test]
+    fn non_max_suppression() {
+        let device = Default::default();
+        let model: non_max_suppression::Model<TestBackend> =
+            non_max_suppression::Model::new(&device);
+
+        let boxes = Tensor::<TestBackend, 3>::from_floats(
+            [[
+                [0.0, 0.0, 1.0, 1.0],
+                [0.0, 0.1, 1.0, 1.1],
+                [0.0, -0.1, 1.0, 0.9],
+                [0.0, 10.0, 1.0, 11.0],
+                [0.0, 10.1, 1.0, 11.1],
+                [0.0, 100.0, 1.0, 101.0],
+            ]],
+            &device,
+        );
+        let scores =
+            Tensor::<TestBackend, 3>::from_floats([[[0.9, 0.75, 0.6, 0.95, 0.5, 0.3]]], &device);
+        let output = model.forward(boxes, scores);
+
+        // The overlapping boxes are suppressed and the last box is below the score threshold
+        output
+            .to_data()
+            .assert_eq(&TensorData::from([[0i64, 0, 3], [0, 0, 0]]), false);
+    }
+}
@@ -0,0 +1,90 @@
+#!/usr/bin/env python3
+
+# used to generate model: non_max_suppression.onnx
+
+import numpy as np
+import onnx
+import onnx.helper
+from onnx import TensorProto
+from onnx.reference import ReferenceEvaluator
+
+
+def constant(name, data_type, value):
+    return onnx.helper.make_node(
+        "Constant",
+        inputs=[],
+        outputs=[name],
+        name=f"{name}_constant",
+        value=onnx.helper.make_tensor(name, data_type, [1], [value]),
+    )
+
+
+def build_model():
+    boxes = onnx.helper.make_tensor_value_info("boxes", TensorProto.FLOAT, [1, 6, 4])
+    scores = onnx.helper.make_tensor_value_info("scores", TensorProto.FLOAT, [1, 1, 6])
+    selected = onnx.helper.make_tensor_value_info(
+        "selected_indices", TensorProto.INT64, [None, 3]
+    )
+
+    # The boxes are given by their corners `[y1, x1, y2, x2]`
+    nms = onnx.helper.make_node(
+        "NonMaxSuppression",
+        inputs=[
+            "boxes",
+            "scores",
+            "max_output_boxes_per_class",
+            "iou_threshold",
+            "score_threshold",
+        ],
+        outputs=["selected_indices"],
+        name="NonMaxSuppressionNode",
+    )
+
+    graph = onnx.helper.make_graph(
+        [
+            constant("max_output_boxes_per_class", TensorProto.INT64, 3),
+            constant("iou_threshold", TensorProto.FLOAT, 0.5),
+            constant("score_threshold", TensorProto.FLOAT, 0.4),
+            nms,
+        ],
+        "NonMaxSuppressionModel",
+        [boxes, scores],
+        [selected],
+    )
+
+    model = onnx.helper.make_model(
+        opset_imports=[onnx.helper.make_operatorsetid("", 16)],
+        graph=graph,
+        producer_name="ONNX_Generator",
+    )
+
+    return model
+
+
+if __name__ == "__main__":
+    onnx_model = build_model()
+    file_name = "non_max_suppression.onnx"
+
+    onnx.checker.check_model(onnx_model)
+    onnx.save(onnx_model, file_name)
+    print(f"Finished exporting model to {file_name}")
+
+    boxes = np.array(
+        [
+            [
+                [0.0, 0.0, 1.0, 1.0],
+                [0.0, 0.1, 1.0, 1.1],
+                [0.0, -0.1, 1.0, 0.9],
+                [0.0, 10.0, 1.0, 11.0],
+                [0.0, 10.1, 1.0, 11.1],
+                [0.0, 100.0, 1.0, 101.0],
+            ]
+        ],
+        dtype=np.float32,
+    )
+    scores = np.array([[[0.9, 0.75, 0.6, 0.95, 0.5, 0.3]]], dtype=np.float32)
+    print(f"Test input data: {repr(boxes)}, {repr(scores)}")
+
+    session = ReferenceEvaluator(file_name, verbose=1)
+    (selected_indices,) = session.run(None, {"boxes": boxes, "scores": scores})
+    print(f"Test output: {repr(selected_indices)}")
@@ -0,0 +1,25 @@
+// Import the shared macro
+use crate::include_models;
+include_models!(nonzero);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn::tensor::{Tensor, TensorData};
+
+    use crate::backend::TestBackend;
+
+    #[test]
+    fn nonzero() {
+        let device = Default::default();
+        let model: nonzero::Model<TestBackend> = nonzero::Model::new(&device);
+
+        let input = Tensor::<TestBackend, 2>::from_floats([[1., 0., 2.], [0., 3., 0.]], &device);
+        let output = model.forward(input);
+
+        // The indices of the non-zero elements, one row per dimension
+        output
+            .to_data()
+            .assert_eq(&TensorData::from([[0i64, 0, 1], [0, 2, 1]]), false);
+    }
+}
@@ -0,0 +1,42 @@
+#!/usr/bin/env python3
+
+# used to generate model: nonzero.onnx
+
+import numpy as np
+import onnx
+import onnx.helper
+from onnx import TensorProto
+from onnx.reference import ReferenceEvaluator
+
+
+def build_model():
+    x = onnx.helper.make_tensor_value_info("x", TensorProto.FLOAT, [2, 3])
+    y = onnx.helper.make_tensor_value_info("y", TensorProto.INT64, [2, None])
+
+    nonzero = onnx.helper.make_node("NonZero", inputs=["x"], outputs=["y"], name="NonZeroNode")
+
+    graph = onnx.helper.make_graph([nonzero], "NonZeroModel", [x], [y])
+
+    model = onnx.helper.make_model(
+        opset_imports=[onnx.helper.make_operatorsetid("", 16)],
+        graph=graph,
+        producer_name="ONNX_Generator",
+    )
+
+    return model
+
+
+if __name__ == "__main__":
+    onnx_model = build_model()
+    file_name = "nonzero.onnx"
+
+    onnx.checker.check_model(onnx_model)
+    onnx.save(onnx_model, file_name)
+    print(f"Finished exporting model to {file_name}")
+
+    x = np.array([[1.0, 0.0, 2.0], [0.0, 3.0, 0.0]], dtype=np.float32)
+    print(f"Test input data: {repr(x)}")
+
+    session = ReferenceEvaluator(file_name, verbose=1)
+    (y,) = session.run(None, {"x": x})
+    print(f"Test output: {repr(y)}")
@@ -0,0 +1,51 @@
+// Import the shared macro
+use crate::include_models;
+include_models!(qdq_conv, qlinear_matmul);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn::tensor::{Int, Tensor, TensorData, Tolerance, ops::FloatElem};
+
+    use crate::backend::TestBackend;
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn qdq_conv() {
+        let device = Default::default();
+        let model: qdq_conv::Model<TestBackend> = qdq_conv::Model::new(&device);
+
+        let x = Tensor::<TestBackend, 1>::from_floats(
+            [-0.4, -0.3, -0.2, -0.1, 0.0, 0.1, 0.2, 0.3, 0.4],
+            &device,
+        )
+        .reshape([1, 1, 3, 3]);
+        let (x_q, y) = model.forward(x);
+
+        let expected_x_q =
+            TensorData::from([[[[108i64, 113, 118], [123, 128, 133], [138, 143, 148]]]]);
+        let expected_y = TensorData::from([[
+            [[0.09f32, 0.07], [0.03, 0.01]],
+            [[-0.355, -0.295], [-0.175, -0.115]],
+        ]]);
+
+        x_q.to_data().assert_eq(&expected_x_q, false);
+        y.to_data()
+            .assert_approx_eq::<FT>(&expected_y, Tolerance::default());
+    }
+
+    #[test]
+    fn qlinear_matmul() {
+        let device = Default::default();
+        let model: qlinear_matmul::Model<TestBackend> = qlinear_matmul::Model::new(&device);
+
+        let a =
+            Tensor::<TestBackend, 2, Int>::from_ints([[130, 120, 141], [100, 128, 255]], &device);
+        let y = model.forward(a);
+
+        // The second row saturates to the range of uint8
+        let expected = TensorData::from([[78i64, 111], [0, 255]]);
+
+        y.to_data().assert_eq(&expected, false);
+    }
+}
@@ -0,0 +1,88 @@
+#!/usr/bin/env python3
+
+# used to generate model: qdq_conv.onnx
+
+import numpy as np
+import onnx
+import onnx.helper
+from onnx import TensorProto
+from onnx.reference import ReferenceEvaluator
+
+
+def build_model():
+    x = onnx.helper.make_tensor_value_info("x", TensorProto.FLOAT, [1, 1, 3, 3])
+    x_q = onnx.helper.make_tensor_value_info("x_q", TensorProto.UINT8, [1, 1, 3, 3])
+    y = onnx.helper.make_tensor_value_info("y", TensorProto.FLOAT, [1, 2, 2, 2])
+
+    # The activations are quantized and dequantized at runtime, while the DequantizeLinear node of
+    # the int8 weights, quantized per output channel, is folded into the weights of the Conv.
+    quantize = onnx.helper.make_node(
+        "QuantizeLinear",
+        inputs=["x", "x_scale", "x_zero_point"],
+        outputs=["x_q"],
+        name="QuantizeLinearNode",
+    )
+    dequantize = onnx.helper.make_node(
+        "DequantizeLinear",
+        inputs=["x_q", "x_scale", "x_zero_point"],
+        outputs=["x_dq"],
+        name="DequantizeLinearNode",
+    )
+    dequantize_weights = onnx.helper.make_node(
+        "DequantizeLinear",
+        inputs=["w_q", "w_scale", "w_zero_point"],
+        outputs=["w"],
+        name="DequantizeWeightsNode",
+        axis=0,
+    )
+    conv = onnx.helper.make_node(
+        "Conv",
+        inputs=["x_dq", "w", "b"],
+        outputs=["y"],
+        name="ConvNode",
+        kernel_shape=[2, 2],
+    )
+
+    graph = onnx.helper.make_graph(
+        [quantize, dequantize, dequantize_weights, conv],
+        "QdqConvModel",
+        [x],
+        [x_q, y],
+        initializer=[
+            onnx.helper.make_tensor("x_scale", TensorProto.FLOAT, [], [0.02]),
+            onnx.helper.make_tensor("x_zero_point", TensorProto.UINT8, [], [128]),
+            onnx.helper.make_tensor(
+                "w_q", TensorProto.INT8, [2, 1, 2, 2], [1, -2, 3, -4, 5, 6, -7, 8]
+            ),
+            onnx.helper.make_tensor("w_scale", TensorProto.FLOAT, [2], [0.1, 0.05]),
+            onnx.helper.make_tensor("w_zero_point", TensorProto.INT8, [2], [0, 0]),
+            onnx.helper.make_tensor("b", TensorProto.FLOAT, [2], [0.1, -0.2]),
+        ],
+    )
+
+    model = onnx.helper.make_model(
+        opset_imports=[onnx.helper.make_operatorsetid("", 16)],
+        graph=graph,
+        producer_name="ONNX_Generator",
+    )
+
+    return model
+
+
+if __name__ == "__main__":
+    np.set_printoptions(precision=8)
+
+    onnx_model = build_model()
+    file_name = "qdq_conv.onnx"
+
+    onnx.checker.check_model(onnx_model)
+    onnx.save(onnx_model, file_name)
+    print(f"Finished exporting model to {file_name}")
+
+    x = (np.arange(9, dtype=np.float32) * 0.1 - 0.4).reshape(1, 1, 3, 3)
+    print(f"Test input data: {repr(x)}")
+
+    session = ReferenceEvaluator(file_name, verbose=1)
+    x_q, y = session.run(None, {"x": x})
+    print(f"Test output x_q: {repr(x_q)}")
+    print(f"Test output y: {repr(y)}")
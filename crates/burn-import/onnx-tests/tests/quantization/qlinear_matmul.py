@@ -0,0 +1,73 @@
+#!/usr/bin/env python3
+
+# used to generate model: qlinear_matmul.onnx
+
+import numpy as np
+import onnx
+import onnx.helper
+from onnx import TensorProto
+from onnx.reference import ReferenceEvaluator
+
+
+def build_model():
+    a = onnx.helper.make_tensor_value_info("a", TensorProto.UINT8, [2, 3])
+    y = onnx.helper.make_tensor_value_info("y", TensorProto.UINT8, [2, 2])
+
+    # The symmetric int8 weights are quantized per tensor, so they are kept quantized.
+    qlinear_matmul = onnx.helper.make_node(
+        "QLinearMatMul",
+        inputs=[
+            "a",
+            "a_scale",
+            "a_zero_point",
+            "b",
+            "b_scale",
+            "b_zero_point",
+            "y_scale",
+            "y_zero_point",
+        ],
+        outputs=["y"],
+        name="QLinearMatMulNode",
+    )
+
+    graph = onnx.helper.make_graph(
+        [qlinear_matmul],
+        "QLinearMatMulModel",
+        [a],
+        [y],
+        initializer=[
+            onnx.helper.make_tensor("a_scale", TensorProto.FLOAT, [], [0.1]),
+            onnx.helper.make_tensor("a_zero_point", TensorProto.UINT8, [], [128]),
+            onnx.helper.make_tensor(
+                "b", TensorProto.INT8, [3, 2], [10, -20, 30, 40, -50, 61]
+            ),
+            onnx.helper.make_tensor("b_scale", TensorProto.FLOAT, [], [0.05]),
+            onnx.helper.make_tensor("b_zero_point", TensorProto.INT8, [], [0]),
+            onnx.helper.make_tensor("y_scale", TensorProto.FLOAT, [], [0.2]),
+            onnx.helper.make_tensor("y_zero_point", TensorProto.UINT8, [], [100]),
+        ],
+    )
+
+    model = onnx.helper.make_model(
+        opset_imports=[onnx.helper.make_operatorsetid("", 16)],
+        graph=graph,
+        producer_name="ONNX_Generator",
+    )
+
+    return model
+
+
+if __name__ == "__main__":
+    onnx_model = build_model()
+    file_name = "qlinear_matmul.onnx"
+
+    onnx.checker.check_model(onnx_model)
+    onnx.save(onnx_model, file_name)
+    print(f"Finished exporting model to {file_name}")
+
+    a = np.array([[130, 120, 141], [100, 128, 255]], dtype=np.uint8)
+    print(f"Test input data: {repr(a)}")
+
+    session = ReferenceEvaluator(file_name, verbose=1)
+    (y,) = session.run(None, {"a": a})
+    print(f"Test output y: {repr(y)}")
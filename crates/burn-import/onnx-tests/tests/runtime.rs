@@ -0,0 +1,213 @@
+// Tests the ONNX runtime of burn-import against the code generated from the same models.
+#![allow(clippy::type_complexity)]
+
+mod backend;
+
+macro_rules! include_models {
+    ($($model:ident),*) => {
+        $(
+            #[allow(clippy::type_complexity, unused_variables)]
+            pub mod $model {
+                include!(concat!(env!("OUT_DIR"), concat!("/model/", stringify!($model), ".rs")));
+            }
+        )*
+    };
+}
+
+include_models!(
+    conv2d,
+    maxpool2d,
+    avg_pool2d,
+    batch_norm,
+    reduce_sum,
+    reduce_mean,
+    reduce_max,
+    flatten,
+    reshape,
+    transpose,
+    shape
+);
+
+use backend::TestBackend;
+use burn::tensor::{Tensor, Tolerance, ops::FloatElem};
+use burn_import::onnx::{OnnxModel, OnnxValue};
+
+type FT = FloatElem<TestBackend>;
+
+fn load(path: &str) -> OnnxModel<TestBackend> {
+    let path = format!("{}/tests/{path}.onnx", env!("CARGO_MANIFEST_DIR"));
+    OnnxModel::load(path, &Default::default()).unwrap()
+}
+
+fn run(
+    model: &OnnxModel<TestBackend>,
+    inputs: Vec<OnnxValue<TestBackend>>,
+) -> Vec<OnnxValue<TestBackend>> {
+    model.forward(inputs).unwrap()
+}
+
+fn assert_float_eq<const D: usize>(
+    actual: OnnxValue<TestBackend>,
+    expected: Tensor<TestBackend, D>,
+) {
+    let actual = actual.into_float::<D>().unwrap();
+
+    assert_eq!(actual.shape(), expected.shape());
+    actual
+        .into_data()
+        .assert_approx_eq::<FT>(&expected.into_data(), Tolerance::default());
+}
+
+fn pool_input() -> Tensor<TestBackend, 4> {
+    Tensor::from_floats(
+        [[[
+            [1.927, 1.487, 0.901, -2.106, 0.678],
+            [-1.235, -0.043, -1.605, -0.752, -0.687],
+            [-0.493, 0.241, -1.111, 0.092, -2.317],
+            [-0.217, -1.385, -0.396, 0.803, -0.622],
+            [-0.592, -0.063, -0.829, 0.331, -1.558],
+        ]]],
+        &Default::default(),
+    )
+}
+
+fn reduce_input() -> Tensor<TestBackend, 4> {
+    Tensor::from_floats(
+        [[[[1.0, 4.0, 9.0, 25.0], [2.0, 5.0, 10.0, 26.0]]]],
+        &Default::default(),
+    )
+}
+
+#[test]
+fn conv2d() {
+    let generated: conv2d::Model<TestBackend> = conv2d::Model::default();
+    let model = load("conv2d/conv2d");
+    let input = Tensor::<TestBackend, 4>::ones([2, 4, 10, 15], &Default::default());
+
+    let outputs = run(&model, vec![input.clone().into()]);
+
+    assert_float_eq(outputs[0].clone(), generated.forward(input));
+}
+
+#[test]
+fn maxpool2d() {
+    let generated: maxpool2d::Model<TestBackend> = maxpool2d::Model::new(&Default::default());
+    let model = load("maxpool2d/maxpool2d");
+
+    let outputs = run(&model, vec![pool_input().into()]);
+
+    assert_float_eq(outputs[0].clone(), generated.forward(pool_input()));
+}
+
+#[test]
+fn avg_pool2d() {
+    let generated: avg_pool2d::Model<TestBackend> = avg_pool2d::Model::new(&Default::default());
+    let model = load("avg_pool2d/avg_pool2d");
+    let input = pool_input();
+
+    let mut outputs = run(
+        &model,
+        vec![
+            input.clone().into(),
+            input.clone().into(),
+            input.clone().into(),
+        ],
+    )
+    .into_iter();
+    let (expected1, expected2, expected3) = generated.forward(input.clone(), input.clone(), input);
+
+    assert_float_eq(outputs.next().unwrap(), expected1);
+    assert_float_eq(outputs.next().unwrap(), expected2);
+    assert_float_eq(outputs.next().unwrap(), expected3);
+}
+
+#[test]
+fn batch_norm() {
+    let generated: batch_norm::Model<TestBackend> = batch_norm::Model::default();
+    let model = load("batch_norm/batch_norm");
+    let input = Tensor::<TestBackend, 3>::ones([1, 20, 1], &Default::default());
+
+    let outputs = run(&model, vec![input.clone().into()]);
+
+    assert_float_eq(outputs[0].clone(), generated.forward(input));
+}
+
+macro_rules! test_reduce {
+    ($name:ident, $path:expr) => {
+        #[test]
+        fn $name() {
+            let generated: $name::Model<TestBackend> = $name::Model::new(&Default::default());
+            let model = load($path);
+
+            let mut outputs = run(&model, vec![reduce_input().into()]).into_iter();
+            let (expected1, expected2, expected3, expected4, expected5, expected6) =
+                generated.forward(reduce_input());
+
+            for expected in [expected1, expected2] {
+                let actual = outputs.next().unwrap().into_scalar().unwrap();
+                assert!((actual - expected as f64).abs() < 1e-6);
+            }
+            assert_float_eq(outputs.next().unwrap(), expected3);
+            assert_float_eq(outputs.next().unwrap(), expected4);
+            assert_float_eq(outputs.next().unwrap(), expected5);
+            assert_float_eq(outputs.next().unwrap(), expected6);
+        }
+    };
+}
+
+test_reduce!(reduce_sum, "reduce/reduce_sum");
+test_reduce!(reduce_mean, "reduce/reduce_mean");
+test_reduce!(reduce_max, "reduce/reduce_max");
+
+#[test]
+fn flatten() {
+    let generated: flatten::Model<TestBackend> = flatten::Model::new(&Default::default());
+    let model = load("flatten/flatten");
+    let input = Tensor::<TestBackend, 3>::random(
+        [1, 5, 15],
+        burn::tensor::Distribution::Default,
+        &Default::default(),
+    );
+
+    let outputs = run(&model, vec![input.clone().into()]);
+
+    assert_float_eq(outputs[0].clone(), generated.forward(input));
+}
+
+#[test]
+fn reshape() {
+    let generated: reshape::Model<TestBackend> = reshape::Model::new(&Default::default());
+    let model = load("reshape/reshape");
+    let input = Tensor::<TestBackend, 1>::from_floats([0., 1., 2., 3.], &Default::default());
+
+    let outputs = run(&model, vec![input.clone().into()]);
+
+    assert_float_eq(outputs[0].clone(), generated.forward(input));
+}
+
+#[test]
+fn transpose() {
+    let generated: transpose::Model<TestBackend> = transpose::Model::new(&Default::default());
+    let model = load("transpose/transpose");
+    let input = Tensor::<TestBackend, 1, burn::tensor::Int>::arange(0..24, &Default::default())
+        .float()
+        .reshape([2, 3, 4]);
+
+    let outputs = run(&model, vec![input.clone().into()]);
+
+    assert_float_eq(outputs[0].clone(), generated.forward(input));
+}
+
+#[test]
+fn shape() {
+    let generated: shape::Model<TestBackend> = shape::Model::new(&Default::default());
+    let model = load("shape/shape");
+    let input = Tensor::<TestBackend, 2>::ones([4, 2], &Default::default());
+
+    let outputs = run(&model, vec![input.clone().into()]);
+
+    assert_eq!(
+        outputs[0].clone().into_shape().unwrap(),
+        generated.forward(input).to_vec()
+    );
+}
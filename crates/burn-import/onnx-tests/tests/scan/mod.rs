@@ -0,0 +1,41 @@
+// Import the shared macro
+use crate::include_models;
+include_models!(scan);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn::tensor::{Tensor, TensorData};
+
+    use crate::backend::TestBackend;
+
+    #[test]
+    fn scan_reverse_cumulative_sum() {
+        let device = Default::default();
+        let model: scan::Model<TestBackend> = scan::Model::new(&device);
+
+        let initial_sum = Tensor::<TestBackend, 1>::zeros([3], &device);
+        let sequence = Tensor::<TestBackend, 2>::from_floats(
+            [
+                [1.0, 2.0, 3.0],
+                [4.0, 5.0, 6.0],
+                [7.0, 8.0, 9.0],
+                [10.0, 11.0, 12.0],
+            ],
+            &device,
+        );
+        let (final_sum, sums) = model.forward(initial_sum, sequence);
+
+        // The rows are accumulated from the last one.
+        let expected_final_sum = TensorData::from([22.0f32, 26.0, 30.0]);
+        let expected_sums = TensorData::from([
+            [10.0f32, 11.0, 12.0],
+            [17.0, 19.0, 21.0],
+            [21.0, 24.0, 27.0],
+            [22.0, 26.0, 30.0],
+        ]);
+
+        final_sum.to_data().assert_eq(&expected_final_sum, true);
+        sums.to_data().assert_eq(&expected_sums, true);
+    }
+}
@@ -0,0 +1,59 @@
+#!/usr/bin/env python3
+
+# used to generate model: scan.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def main():
+    # The body computes the cumulative sum of the rows of the sequence.
+    body = helper.make_graph(
+        nodes=[
+            helper.make_node("Add", inputs=["sum_in", "row"], outputs=["sum_out"]),
+            helper.make_node("Identity", inputs=["sum_out"], outputs=["scan_out"]),
+        ],
+        name="body",
+        inputs=[
+            helper.make_tensor_value_info("sum_in", TensorProto.FLOAT, [3]),
+            helper.make_tensor_value_info("row", TensorProto.FLOAT, [3]),
+        ],
+        outputs=[
+            helper.make_tensor_value_info("sum_out", TensorProto.FLOAT, [3]),
+            helper.make_tensor_value_info("scan_out", TensorProto.FLOAT, [3]),
+        ],
+    )
+
+    # The sequence is scanned in reverse order.
+    scan_node = helper.make_node(
+        "Scan",
+        inputs=["initial_sum", "sequence"],
+        outputs=["final_sum", "sums"],
+        body=body,
+        num_scan_inputs=1,
+        scan_input_directions=[1],
+    )
+
+    graph = helper.make_graph(
+        nodes=[scan_node],
+        name="ScanTest",
+        inputs=[
+            helper.make_tensor_value_info("initial_sum", TensorProto.FLOAT, [3]),
+            helper.make_tensor_value_info("sequence", TensorProto.FLOAT, [4, 3]),
+        ],
+        outputs=[
+            helper.make_tensor_value_info("final_sum", TensorProto.FLOAT, [3]),
+            helper.make_tensor_value_info("sums", TensorProto.FLOAT, [4, 3]),
+        ],
+    )
+
+    model = helper.make_model(
+        graph, producer_name="scan_test", opset_imports=[helper.make_opsetid("", 16)]
+    )
+
+    onnx.save(model, "scan.onnx")
+    print("Finished exporting model to scan.onnx")
+
+
+if __name__ == "__main__":
+    main()
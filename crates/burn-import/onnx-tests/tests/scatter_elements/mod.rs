@@ -0,0 +1,36 @@
+// Import the shared macro
+use crate::include_models;
+include_models!(scatter_elements);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn::tensor::{Int, Tensor, TensorData, Tolerance, ops::FloatElem};
+
+    use crate::backend::TestBackend;
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn scatter_elements() {
+        let device = Default::default();
+        let model: scatter_elements::Model<TestBackend> = scatter_elements::Model::new(&device);
+
+        let data = Tensor::<TestBackend, 2>::from_floats(
+            [[0., 1., 2.], [3., 4., 5.], [6., 7., 8.]],
+            &device,
+        );
+        let indices = Tensor::<TestBackend, 2, Int>::from_ints([[1, 0, 2], [0, 2, 1]], &device);
+        let updates =
+            Tensor::<TestBackend, 2>::from_floats([[1.0, 1.1, 1.2], [2.0, 2.1, 2.2]], &device);
+        let (output1, output2) = model.forward(data, indices, updates);
+
+        output1.to_data().assert_approx_eq::<FT>(
+            &TensorData::from([[2f32, 1.1, 2.], [1., 4., 2.2], [6., 2.1, 1.2]]),
+            Tolerance::default(),
+        );
+        output2.to_data().assert_approx_eq::<FT>(
+            &TensorData::from([[1.1f32, 2., 3.2], [5., 6.2, 7.1], [6., 7., 8.]]),
+            Tolerance::default(),
+        );
+    }
+}
@@ -0,0 +1,70 @@
+#!/usr/bin/env python3
+
+# used to generate model: scatter_elements.onnx
+
+import numpy as np
+import onnx
+import onnx.helper
+from onnx import TensorProto
+from onnx.reference import ReferenceEvaluator
+
+
+def build_model():
+    data = onnx.helper.make_tensor_value_info("data", TensorProto.FLOAT, [3, 3])
+    indices = onnx.helper.make_tensor_value_info("indices", TensorProto.INT64, [2, 3])
+    updates = onnx.helper.make_tensor_value_info("updates", TensorProto.FLOAT, [2, 3])
+    output1 = onnx.helper.make_tensor_value_info("output1", TensorProto.FLOAT, [3, 3])
+    output2 = onnx.helper.make_tensor_value_info("output2", TensorProto.FLOAT, [3, 3])
+
+    # The updates replace the values along the first axis
+    scatter = onnx.helper.make_node(
+        "ScatterElements",
+        inputs=["data", "indices", "updates"],
+        outputs=["output1"],
+        name="ScatterElementsNode",
+        axis=0,
+    )
+    # The updates are added to the values along the last axis
+    scatter_add = onnx.helper.make_node(
+        "ScatterElements",
+        inputs=["data", "indices", "updates"],
+        outputs=["output2"],
+        name="ScatterElementsAddNode",
+        axis=-1,
+        reduction="add",
+    )
+
+    graph = onnx.helper.make_graph(
+        [scatter, scatter_add],
+        "ScatterElementsModel",
+        [data, indices, updates],
+        [output1, output2],
+    )
+
+    model = onnx.helper.make_model(
+        opset_imports=[onnx.helper.make_operatorsetid("", 16)],
+        graph=graph,
+        producer_name="ONNX_Generator",
+    )
+
+    return model
+
+
+if __name__ == "__main__":
+    onnx_model = build_model()
+    file_name = "scatter_elements.onnx"
+
+    onnx.checker.check_model(onnx_model)
+    onnx.save(onnx_model, file_name)
+    print(f"Finished exporting model to {file_name}")
+
+    data = np.arange(9, dtype=np.float32).reshape(3, 3)
+    indices = np.array([[1, 0, 2], [0, 2, 1]], dtype=np.int64)
+    updates = np.array([[1.0, 1.1, 1.2], [2.0, 2.1, 2.2]], dtype=np.float32)
+    print(f"Test input data: {repr(data)}, {repr(indices)}, {repr(updates)}")
+
+    session = ReferenceEvaluator(file_name, verbose=1)
+    output1, output2 = session.run(
+        None, {"data": data, "indices": indices, "updates": updates}
+    )
+    print(f"Test output: {repr(output1)}, {repr(output2)}")
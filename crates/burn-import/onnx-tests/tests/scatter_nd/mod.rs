@@ -0,0 +1,30 @@
+// Import the shared macro
+use crate::include_models;
+include_models!(scatter_nd);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn::tensor::{Int, Tensor, TensorData};
+
+    use crate::backend::TestBackend;
+
+    #[test]
+    fn scatter_nd() {
+        let device = Default::default();
+        let model: scatter_nd::Model<TestBackend> = scatter_nd::Model::new(&device);
+
+        let data = Tensor::<TestBackend, 2>::from_floats(
+            [[0., 1.], [2., 3.], [4., 5.], [6., 7.]],
+            &device,
+        );
+        let indices = Tensor::<TestBackend, 2, Int>::from_ints([[3], [1]], &device);
+        let updates = Tensor::<TestBackend, 2>::from_floats([[10., 11.], [12., 13.]], &device);
+        let output = model.forward(data, indices, updates);
+
+        output.to_data().assert_eq(
+            &TensorData::from([[0f32, 1.], [12., 13.], [4., 5.], [10., 11.]]),
+            true,
+        );
+    }
+}
@@ -0,0 +1,53 @@
+#!/usr/bin/env python3
+
+# used to generate model: scatter_nd.onnx
+
+import numpy as np
+import onnx
+import onnx.helper
+from onnx import TensorProto
+from onnx.reference import ReferenceEvaluator
+
+
+def build_model():
+    data = onnx.helper.make_tensor_value_info("data", TensorProto.FLOAT, [4, 2])
+    indices = onnx.helper.make_tensor_value_info("indices", TensorProto.INT64, [2, 1])
+    updates = onnx.helper.make_tensor_value_info("updates", TensorProto.FLOAT, [2, 2])
+    output = onnx.helper.make_tensor_value_info("output", TensorProto.FLOAT, [4, 2])
+
+    scatter_nd = onnx.helper.make_node(
+        "ScatterND",
+        inputs=["data", "indices", "updates"],
+        outputs=["output"],
+        name="ScatterNDNode",
+    )
+
+    graph = onnx.helper.make_graph(
+        [scatter_nd], "ScatterNDModel", [data, indices, updates], [output]
+    )
+
+    model = onnx.helper.make_model(
+        opset_imports=[onnx.helper.make_operatorsetid("", 16)],
+        graph=graph,
+        producer_name="ONNX_Generator",
+    )
+
+    return model
+
+
+if __name__ == "__main__":
+    onnx_model = build_model()
+    file_name = "scatter_nd.onnx"
+
+    onnx.checker.check_model(onnx_model)
+    onnx.save(onnx_model, file_name)
+    print(f"Finished exporting model to {file_name}")
+
+    data = np.arange(8, dtype=np.float32).reshape(4, 2)
+    indices = np.array([[3], [1]], dtype=np.int64)
+    updates = np.array([[10.0, 11.0], [12.0, 13.0]], dtype=np.float32)
+    print(f"Test input data: {repr(data)}, {repr(indices)}, {repr(updates)}")
+
+    session = ReferenceEvaluator(file_name, verbose=1)
+    (output,) = session.run(None, {"data": data, "indices": indices, "updates": updates})
+    print(f"Test output: {repr(output)}")
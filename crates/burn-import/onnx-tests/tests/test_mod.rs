@@ -35,9 +35,11 @@ pub mod conv;
 pub mod conv_transpose;
 pub mod cos;
 pub mod cosh;
+pub mod cumsum;
 pub mod depth_to_space;
 pub mod div;
 pub mod dropout;
+pub mod einsum;
 pub mod equal;
 pub mod erf;
 pub mod exp;
@@ -46,6 +48,7 @@ pub mod flatten;
 pub mod floor;
 pub mod gather;
 pub mod gather_elements;
+pub mod gather_nd;
 pub mod gelu;
 pub mod gemm;
 pub mod global_avr_pool;
@@ -53,8 +56,10 @@ pub mod graph_multiple_output_tracking;
 pub mod greater;
 pub mod greater_or_equal;
 pub mod group_norm;
+pub mod gru;
 pub mod hard_sigmoid;
 pub mod identity;
+pub mod if_op;
 pub mod initializer_to_const;
 pub mod instance_norm;
 pub mod is_inf;
@@ -66,6 +71,8 @@ pub mod less_or_equal;
 pub mod linear;
 pub mod log;
 pub mod log_softmax;
+pub mod loop_op;
+pub mod lstm;
 pub mod matmul;
 pub mod matmulinteger;
 pub mod max;
@@ -74,12 +81,15 @@ pub mod mean;
 pub mod min;
 pub mod mul;
 pub mod neg;
+pub mod non_max_suppression;
+pub mod nonzero;
 pub mod not;
 pub mod one_hot;
 pub mod or;
 pub mod pad;
 pub mod pow;
 pub mod prelu;
+pub mod quantization;
 pub mod random_normal;
 pub mod random_normal_like;
 pub mod random_uniform;
@@ -91,6 +101,9 @@ pub mod relu;
 pub mod reshape;
 pub mod resize;
 pub mod round;
+pub mod scan;
+pub mod scatter_elements;
+pub mod scatter_nd;
 pub mod shape;
 pub mod sigmoid;
 pub mod sign;
@@ -64,6 +64,11 @@ impl<PS: PrecisionSettings> BurnGraph<PS> {
         self.nodes.push(node);
     }
 
+    /// The registered nodes, e.g. to build a subgraph of a control flow node.
+    pub(crate) fn into_nodes(self) -> Vec<Node<PS>> {
+        self.nodes
+    }
+
     /// Save the state of each node in a record file.
     ///
     /// The `Default` trait will be implemented for the generated model, which will load the record
@@ -378,8 +383,8 @@ impl<PS: PrecisionSettings> BurnGraph<PS> {
 
     fn codegen_struct(&self) -> TokenStream {
         let mut body = quote! {};
-        self.nodes
-            .iter()
+        with_nested_nodes(self.nodes.iter().collect())
+            .into_iter()
             .filter_map(|node| node.field_type())
             .map(|field| {
                 let name = field.name();
@@ -414,13 +419,14 @@ impl<PS: PrecisionSettings> BurnGraph<PS> {
     fn codegen_new(&self) -> TokenStream {
         let mut body = quote! {};
 
-        self.nodes
+        let nodes = with_nested_nodes(self.nodes.iter().collect());
+
+        nodes
             .iter()
             .map(|node| node.field_init())
             .for_each(|code| body.extend(code));
 
-        let fields = self
-            .nodes
+        let fields = nodes
             .iter()
             .flat_map(|node| node.field_type())
             .map(|field| field.name().clone())
@@ -582,10 +588,8 @@ impl<PS: PrecisionSettings> Serialize for StructMap<'_, PS> {
     where
         S: serde::Serializer,
     {
-        let nodes_with_names = self
-            .0
-            .nodes
-            .iter()
+        let nodes_with_names = with_nested_nodes(self.0.nodes.iter().collect())
+            .into_iter()
             .filter_map(|node| node.field_type().map(|ty| (node, ty.name().clone())))
             .collect::<Vec<_>>();
         let mut map = serializer.serialize_map(Some(nodes_with_names.len()))?;
@@ -616,10 +620,8 @@ impl<PS: PrecisionSettings> Serialize for StructTuple<'_, PS> {
     where
         S: serde::Serializer,
     {
-        let nodes_with_names = self
-            .0
-            .nodes
-            .iter()
+        let nodes_with_names = with_nested_nodes(self.0.nodes.iter().collect())
+            .into_iter()
             .filter_map(|node| node.field_type().map(|ty| (node, ty.name().clone())))
             .collect::<Vec<_>>();
         let mut map = serializer.serialize_tuple(nodes_with_names.len())?;
@@ -632,6 +634,18 @@ impl<PS: PrecisionSettings> Serialize for StructTuple<'_, PS> {
     }
 }
 
+/// The nodes followed by the nodes of their subgraphs, which can have fields too.
+fn with_nested_nodes<PS: PrecisionSettings>(nodes: Vec<&Node<PS>>) -> Vec<&Node<PS>> {
+    nodes
+        .into_iter()
+        .flat_map(|node| {
+            let mut nodes = vec![node];
+            nodes.extend(with_nested_nodes(node.nested_nodes()));
+            nodes
+        })
+        .collect()
+}
+
 fn extract_type_name_by_type<T: ?Sized>() -> String {
     let full_type_name = type_name::<T>();
     full_type_name
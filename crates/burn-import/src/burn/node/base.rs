@@ -2,34 +2,52 @@ use std::marker::PhantomData;
 
 use super::{
     argmax::ArgMaxNode, argmin::ArgMinNode, attention::AttentionNode, avg_pool1d::AvgPool1dNode,
-    avg_pool2d::AvgPool2dNode, batch_norm::BatchNormNode, bernoulli::BernoulliNode,
-    binary::BinaryNode, bitshift::BitShiftNode, bitwiseand::BitwiseAndNode,
-    bitwisenot::BitwiseNotNode, bitwiseor::BitwiseOrNode, bitwisexor::BitwiseXorNode,
-    cast::CastNode, ceil::CeilNode, clip::ClipNode, concat::ConcatNode, constant::ConstantNode,
-    constant_of_shape::ConstantOfShapeNode, conv_transpose_1d::ConvTranspose1dNode,
-    conv_transpose_2d::ConvTranspose2dNode, conv_transpose_3d::ConvTranspose3dNode,
-    conv1d::Conv1dNode, conv2d::Conv2dNode, conv3d::Conv3dNode, depth_to_space::DepthToSpaceNode,
-    dropout::DropoutNode, expand::ExpandNode, floor::FloorNode, gather::GatherNode,
-    gather_elements::GatherElementsNode, gemm::GemmNode, global_avg_pool::GlobalAvgPoolNode,
-    group_norm::GroupNormNode, identity::IdentityNode, instance_norm::InstanceNormNode,
-    layer_norm::LayerNormNode, linear::LinearNode, matmul::MatmulNode,
-    matmul_integer::MatMulIntegerNode, max_pool1d::MaxPool1dNode, max_pool2d::MaxPool2dNode,
-    mean::MeanNode, one_hot::OneHotNode, pad::PadNode, prelu::PReluNode,
+    avg_pool2d::AvgPool2dNode, avg_pool3d::AvgPool3dNode, batch_norm::BatchNormNode,
+    bernoulli::BernoulliNode, binary::BinaryNode, bitshift::BitShiftNode,
+    bitwiseand::BitwiseAndNode, bitwisenot::BitwiseNotNode, bitwiseor::BitwiseOrNode,
+    bitwisexor::BitwiseXorNode, cast::CastNode, ceil::CeilNode, clip::ClipNode, concat::ConcatNode,
+    constant::ConstantNode, constant_of_shape::ConstantOfShapeNode, conv_integer::ConvIntegerNode,
+    conv_transpose_1d::ConvTranspose1dNode, conv_transpose_2d::ConvTranspose2dNode,
+    conv_transpose_3d::ConvTranspose3dNode, conv1d::Conv1dNode, conv2d::Conv2dNode,
+    conv3d::Conv3dNode, cumsum::CumSumNode, depth_to_space::DepthToSpaceNode,
+    dequantize_linear::DequantizeLinearNode, dropout::DropoutNode, einsum::EinsumNode,
+    expand::ExpandNode, floor::FloorNode, gather::GatherNode, gather_elements::GatherElementsNode,
+    gather_nd::GatherNDNode, gemm::GemmNode, global_avg_pool::GlobalAvgPoolNode,
+    group_norm::GroupNormNode, gru::GruNode, identity::IdentityNode, if_op::IfNode,
+    instance_norm::InstanceNormNode, layer_norm::LayerNormNode, linear::LinearNode,
+    loop_op::LoopNode, lstm::LstmNode, matmul::MatmulNode, matmul_integer::MatMulIntegerNode,
+    max_pool1d::MaxPool1dNode, max_pool2d::MaxPool2dNode, max_pool3d::MaxPool3dNode,
+    mean::MeanNode, non_max_suppression::NonMaxSuppressionNode, nonzero::NonZeroNode,
+    one_hot::OneHotNode, pad::PadNode, prelu::PReluNode, qlinear_conv::QLinearConvNode,
+    qlinear_matmul::QLinearMatMulNode, quantize_linear::QuantizeLinearNode,
     random_normal::RandomNormalNode, random_normal_like::RandomNormalLikeNode,
     random_uniform::RandomUniformNode, random_uniform_like::RandomUniformLikeNode,
     range::RangeNode, reduce::ReduceNode, reshape::ReshapeNode, resize::ResizeNode,
-    round::RoundNode, slice::SliceNode, space_to_depth::SpaceToDepthNode, split::SplitNode,
-    squeeze::SqueezeNode, sum::SumNode, tile::TileNode, top_k::TopKNode, trilu::TriluNode,
-    unary::UnaryNode, unsqueeze::UnsqueezeNode, where_op::WhereNode,
+    round::RoundNode, scan::ScanNode, scatter_elements::ScatterElementsNode,
+    scatter_nd::ScatterNDNode, slice::SliceNode, space_to_depth::SpaceToDepthNode,
+    split::SplitNode, squeeze::SqueezeNode, sum::SumNode, tile::TileNode, top_k::TopKNode,
+    trilu::TriluNode, unary::UnaryNode, unsqueeze::UnsqueezeNode, where_op::WhereNode,
 };
 use crate::burn::{BurnImports, Scope, Type};
-use burn::record::PrecisionSettings;
+use burn::{
+    record::PrecisionSettings,
+    tensor::{DType, TensorData},
+};
 use proc_macro2::TokenStream;
 use serde::Serialize;
 
 /// Backend used for serialization.
 pub type SerializationBackend = burn_ndarray::NdArray<f32>;
 
+/// Convert the data of a float field to the float precision of the record, keeping quantized data
+/// as is like the records do.
+pub(crate) fn float_field_data<PS: PrecisionSettings>(data: TensorData) -> TensorData {
+    match data.dtype {
+        DType::QFloat(_) => data,
+        _ => data.convert::<PS::FloatElem>(),
+    }
+}
+
 /// Codegen trait that should be implemented by all [node](Node) entries.
 pub trait NodeCodegen<PS: PrecisionSettings>: std::fmt::Debug {
     /// All types that are used as inputs during the forward pass.
@@ -85,6 +103,13 @@ pub trait NodeCodegen<PS: PrecisionSettings>: std::fmt::Debug {
     fn field_serialize<S: serde::Serializer>(&self, _serializer: S) -> Result<S::Ok, S::Error> {
         panic!("Serialization should be implemented when field_type is not None.");
     }
+
+    /// (Optional) The nodes of the subgraphs executed by the node, e.g. the branches of an if node.
+    ///
+    /// Their fields are declared in the model along with the fields of the other nodes.
+    fn nested_nodes(&self) -> Vec<&Node<PS>> {
+        vec![]
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -94,6 +119,7 @@ pub enum Node<PS: PrecisionSettings> {
     Attention(AttentionNode),
     AvgPool1d(AvgPool1dNode),
     AvgPool2d(AvgPool2dNode),
+    AvgPool3d(AvgPool3dNode),
     BatchNorm(BatchNormNode),
     Bernoulli(BernoulliNode),
     Binary(BinaryNode),
@@ -109,36 +135,54 @@ pub enum Node<PS: PrecisionSettings> {
     Conv1d(Conv1dNode),
     Conv2d(Conv2dNode),
     Conv3d(Conv3dNode),
+    ConvInteger(ConvIntegerNode),
     ConvTranspose1d(ConvTranspose1dNode),
     ConvTranspose2d(ConvTranspose2dNode),
     ConvTranspose3d(ConvTranspose3dNode),
+    CumSum(CumSumNode),
     DepthToSpace(DepthToSpaceNode),
+    DequantizeLinear(DequantizeLinearNode),
     PRelu(PReluNode),
     Dropout(DropoutNode),
+    Einsum(EinsumNode),
     Expand(ExpandNode),
     Floor(FloorNode),
     Ceil(CeilNode),
     Gather(GatherNode),
     GatherElements(GatherElementsNode),
+    GatherND(GatherNDNode),
     Gemm(GemmNode),
     GlobalAvgPool(GlobalAvgPoolNode),
     Identity(IdentityNode),
+    If(IfNode<PS>),
     InstanceNorm(InstanceNormNode),
     LayerNorm(LayerNormNode),
+    Loop(LoopNode<PS>),
+    Lstm(LstmNode),
     GroupNorm(GroupNormNode),
+    Gru(GruNode),
     Linear(LinearNode),
     Matmul(MatmulNode),
     MatmulInteger(MatMulIntegerNode),
     MaxPool1d(MaxPool1dNode),
     MaxPool2d(MaxPool2dNode),
+    MaxPool3d(MaxPool3dNode),
     Mean(MeanNode),
+    NonMaxSuppression(NonMaxSuppressionNode),
+    NonZero(NonZeroNode),
     OneHot(OneHotNode),
     Pad(PadNode),
+    QLinearConv(QLinearConvNode),
+    QLinearMatMul(QLinearMatMulNode),
+    QuantizeLinear(QuantizeLinearNode),
     Range(RangeNode),
     Reduce(ReduceNode),
     Reshape(ReshapeNode),
     Resize(ResizeNode),
     Round(RoundNode),
+    Scan(ScanNode<PS>),
+    ScatterElements(ScatterElementsNode),
+    ScatterND(ScatterNDNode),
     Slice(SliceNode),
     Squeeze(SqueezeNode),
     SpaceToDepth(SpaceToDepthNode),
@@ -169,6 +213,7 @@ macro_rules! match_all {
             Node::Attention(node) => $func(node),
             Node::AvgPool1d(node) => $func(node),
             Node::AvgPool2d(node) => $func(node),
+            Node::AvgPool3d(node) => $func(node),
             Node::BatchNorm(node) => $func(node),
             Node::Bernoulli(node) => $func(node),
             Node::Binary(node) => $func(node),
@@ -184,36 +229,54 @@ macro_rules! match_all {
             Node::Conv1d(node) => $func(node),
             Node::Conv2d(node) => $func(node),
             Node::Conv3d(node) => $func(node),
+            Node::ConvInteger(node) => $func(node),
             Node::ConvTranspose1d(node) => $func(node),
             Node::ConvTranspose2d(node) => $func(node),
             Node::ConvTranspose3d(node) => $func(node),
+            Node::CumSum(node) => $func(node),
             Node::DepthToSpace(node) => $func(node),
+            Node::DequantizeLinear(node) => $func(node),
             Node::PRelu(node) => $func(node),
             Node::Dropout(node) => $func(node),
+            Node::Einsum(node) => $func(node),
             Node::Expand(node) => $func(node),
             Node::Floor(node) => $func(node),
             Node::Ceil(node) => $func(node),
             Node::Gather(node) => $func(node),
             Node::GatherElements(node) => $func(node),
+            Node::GatherND(node) => $func(node),
             Node::Gemm(node) => $func(node),
             Node::GlobalAvgPool(node) => $func(node),
             Node::Identity(node) => $func(node),
+            Node::If(node) => $func(node),
             Node::InstanceNorm(node) => $func(node),
             Node::LayerNorm(node) => $func(node),
+            Node::Loop(node) => $func(node),
+            Node::Lstm(node) => $func(node),
             Node::GroupNorm(node) => $func(node),
+            Node::Gru(node) => $func(node),
             Node::Linear(node) => $func(node),
             Node::MatmulInteger(node) => $func(node),
             Node::Matmul(node) => $func(node),
             Node::MaxPool1d(node) => $func(node),
             Node::MaxPool2d(node) => $func(node),
+            Node::MaxPool3d(node) => $func(node),
             Node::Mean(node) => $func(node),
+            Node::NonMaxSuppression(node) => $func(node),
+            Node::NonZero(node) => $func(node),
             Node::OneHot(node) => $func(node),
             Node::Pad(node) => $func(node),
+            Node::QLinearConv(node) => $func(node),
+            Node::QLinearMatMul(node) => $func(node),
+            Node::QuantizeLinear(node) => $func(node),
             Node::Range(node) => $func(node),
             Node::Reduce(node) => $func(node),
             Node::Reshape(node) => $func(node),
             Node::Resize(node) => $func(node),
             Node::Round(node) => $func(node),
+            Node::Scan(node) => $func(node),
+            Node::ScatterElements(node) => $func(node),
+            Node::ScatterND(node) => $func(node),
             Node::Slice(node) => $func(node),
             Node::SpaceToDepth(node) => $func(node),
             Node::Squeeze(node) => $func(node),
@@ -252,6 +315,7 @@ impl<PS: PrecisionSettings> Node<PS> {
             Node::Attention(_) => "attention",
             Node::AvgPool1d(_) => "avg_pool1d",
             Node::AvgPool2d(_) => "avg_pool2d",
+            Node::AvgPool3d(_) => "avg_pool3d",
             Node::BatchNorm(_) => "batch_norm",
             Node::Bernoulli(_) => "bernoulli",
             Node::Binary(binary) => binary.binary_type.as_str(),
@@ -267,36 +331,54 @@ impl<PS: PrecisionSettings> Node<PS> {
             Node::Conv1d(_) => "conv1d",
             Node::Conv2d(_) => "conv2d",
             Node::Conv3d(_) => "conv3d",
+            Node::ConvInteger(_) => "conv_integer",
             Node::ConvTranspose1d(_) => "conv_transpose1d",
             Node::ConvTranspose2d(_) => "conv_transpose2d",
             Node::ConvTranspose3d(_) => "conv_transpose3d",
+            Node::CumSum(_) => "cumsum",
             Node::DepthToSpace(_) => "depth_to_space",
+            Node::DequantizeLinear(_) => "dequantize_linear",
             Node::PRelu(_) => "prelu",
             Node::Dropout(_) => "dropout",
+            Node::Einsum(_) => "einsum",
             Node::Expand(_) => "expand",
             Node::Floor(_) => "floor",
             Node::Ceil(_) => "ceil",
             Node::Gather(_) => "gather",
             Node::GatherElements(_) => "gather_elements",
+            Node::GatherND(_) => "gather_nd",
             Node::Gemm(_) => "gemm",
             Node::GlobalAvgPool(_) => "global_avg_pool",
             Node::Identity(_) => "identity",
+            Node::If(_) => "if",
             Node::InstanceNorm(_) => "instance_norm",
             Node::LayerNorm(_) => "layer_norm",
+            Node::Loop(_) => "loop",
+            Node::Lstm(_) => "lstm",
             Node::GroupNorm(_) => "group_norm",
+            Node::Gru(_) => "gru",
             Node::Linear(_) => "linear",
             Node::MatmulInteger(_) => "matmul_integer",
             Node::Matmul(_) => "matmul",
             Node::MaxPool1d(_) => "max_pool1d",
             Node::MaxPool2d(_) => "max_pool2d",
+            Node::MaxPool3d(_) => "max_pool3d",
             Node::Mean(_) => "mean",
+            Node::NonMaxSuppression(_) => "non_max_suppression",
+            Node::NonZero(_) => "nonzero",
             Node::OneHot(_) => "one_hot",
             Node::Pad(_) => "pad",
+            Node::QLinearConv(_) => "qlinear_conv",
+            Node::QLinearMatMul(_) => "qlinear_matmul",
+            Node::QuantizeLinear(_) => "quantize_linear",
             Node::Range(_) => "range",
             Node::Reduce(_) => "reduce",
             Node::Reshape(_) => "reshape",
             Node::Resize(_) => "resize",
             Node::Round(_) => "round",
+            Node::Scan(_) => "scan",
+            Node::ScatterElements(_) => "scatter_elements",
+            Node::ScatterND(_) => "scatter_nd",
             Node::Slice(_) => "slice",
             Node::SpaceToDepth(_) => "space_to_depth",
             Node::Squeeze(_) => "squeeze",
@@ -353,6 +435,10 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for Node<PS> {
         self
     }
 
+    fn nested_nodes(&self) -> Vec<&Node<PS>> {
+        match_all!(self, NodeCodegen::<PS>::nested_nodes)
+    }
+
     fn field_serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         match_all!(self, |node| NodeCodegen::<PS>::field_serialize(
             node, serializer
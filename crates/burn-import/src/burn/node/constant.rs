@@ -1,4 +1,4 @@
-use super::{Node, NodeCodegen};
+use super::{Node, NodeCodegen, float_field_data};
 use crate::burn::{ScalarKind, ScalarType, Scope, ShapeType, TensorType, ToTokens, Type};
 use burn::{
     module::ParamId,
@@ -210,7 +210,7 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for ConstantNode {
         if let ConstantValue::Tensor(tensor_type, data) = &self.value {
             let data = match tensor_type.kind {
                 crate::burn::TensorKind::Int => data.clone().convert::<PS::IntElem>(),
-                crate::burn::TensorKind::Float => data.clone().convert::<PS::FloatElem>(),
+                crate::burn::TensorKind::Float => float_field_data::<PS>(data.clone()),
                 crate::burn::TensorKind::Bool => data.clone(),
             };
             let data = ParamSerde::new(ParamId::new().to_string(), data);
@@ -0,0 +1,165 @@
+use super::{Node, NodeCodegen, conv2d::Conv2dNode, quantization::dequantize};
+use crate::burn::{BurnImports, Scope, TensorType, Type};
+use burn::record::PrecisionSettings;
+use onnx_ir::node::quantization::ConvIntegerConfig;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Maps an ONNX ConvInteger to a [burn `Conv2d`](burn::nn::conv::Conv2d).
+///
+/// The convolution is computed on the float values shifted by their zero point, which are exact
+/// integers, before converting the output to an int tensor. The weights are kept quantized with a
+/// scale of 1 when they are symmetric int8 values.
+#[derive(Debug, Clone)]
+pub struct ConvIntegerNode {
+    pub conv: Conv2dNode,
+    pub config: ConvIntegerConfig,
+}
+
+impl ConvIntegerNode {
+    pub fn new(conv: Conv2dNode, config: ConvIntegerConfig) -> Self {
+        Self { conv, config }
+    }
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for ConvIntegerNode {
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.conv.input.clone())]
+    }
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.conv.output.clone())]
+    }
+
+    fn field_type(&self) -> Option<Type> {
+        NodeCodegen::<PS>::field_type(&self.conv)
+    }
+
+    fn field_init(&self) -> Option<TokenStream> {
+        NodeCodegen::<PS>::field_init(&self.conv)
+    }
+
+    fn field_serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        NodeCodegen::<PS>::field_serialize(&self.conv, serializer)
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let TensorType { name, .. } = &self.conv.input;
+        let input = scope.tensor_use_owned(&self.conv.input, node_position);
+        let output = &self.conv.output.name;
+        let field = &self.conv.field.name;
+
+        let input = dequantize(input, &self.config.x, 4);
+
+        quote! {
+            let #output = {
+                let #name = #input;
+                self.#field.forward(#name).round().int()
+            };
+        }
+    }
+
+    fn register_imports(&self, imports: &mut BurnImports) {
+        NodeCodegen::<PS>::register_imports(&self.conv, imports)
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::ConvInteger(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn::{graph::BurnGraph, node::test::assert_tokens};
+    use burn::{record::FullPrecisionSettings, tensor::TensorData};
+    use onnx_ir::{
+        ElementType,
+        node::{conv2d::Conv2dConfig, padding::PaddingConfig2d, quantization::QuantizationParams},
+    };
+
+    #[test]
+    fn test_codegen() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+        let config = Conv2dConfig::new(
+            [1, 1],
+            [2, 2],
+            [1, 1],
+            PaddingConfig2d::Valid,
+            [1, 1],
+            1,
+            false,
+        );
+
+        graph.register(ConvIntegerNode::new(
+            Conv2dNode::new(
+                "convinteger1",
+                TensorType::new_int("x", 4),
+                TensorType::new_int("y", 4),
+                TensorData::from([2f32]),
+                None,
+                config.clone(),
+            ),
+            ConvIntegerConfig {
+                conv: config,
+                x: QuantizationParams {
+                    scale: vec![1.0],
+                    zero_point: vec![3],
+                    axis: 1,
+                    elem_type: ElementType::Uint8,
+                },
+                w: QuantizationParams {
+                    scale: vec![1.0],
+                    zero_point: vec![0],
+                    axis: 0,
+                    elem_type: ElementType::Int8,
+                },
+            },
+        ));
+
+        graph.register_input_output(vec!["x".to_string()], vec!["y".to_string()]);
+
+        let expected = quote! {
+            use burn::prelude::*;
+            use burn::nn::PaddingConfig2d;
+            use burn::nn::conv::Conv2d;
+            use burn::nn::conv::Conv2dConfig;
+
+            #[derive(Module, Debug)]
+            pub struct Model <B: Backend> {
+                convinteger1: Conv2d<B>,
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    let convinteger1 = Conv2dConfig::new([1, 1], [2, 2])
+                        .with_stride([1, 1])
+                        .with_padding(PaddingConfig2d::Valid)
+                        .with_dilation([1, 1])
+                        .with_groups(1)
+                        .with_bias(false)
+                        .init(device);
+
+                    Self {
+                        convinteger1,
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, x: Tensor<B, 4, Int>) -> Tensor<B, 4, Int> {
+                    let y = {
+                        let x = x.float().sub_scalar(3);
+                        self.convinteger1.forward(x).round().int()
+                    };
+
+                    y
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
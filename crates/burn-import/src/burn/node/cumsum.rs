@@ -0,0 +1,131 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{Scope, TensorType, ToTokens, Type};
+use burn::record::PrecisionSettings;
+use onnx_ir::node::cumsum::CumSumConfig;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+#[derive(Debug, Clone, new)]
+pub struct CumSumNode {
+    pub input: TensorType,
+    pub output: TensorType,
+    pub config: CumSumConfig,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for CumSumNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.input.clone())]
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let output = &self.output.name;
+        let axis = self.config.axis.to_tokens();
+
+        // A reverse sum is the sum of the flipped tensor, flipped back
+        let input = match self.config.reverse {
+            true => quote! { #input.flip([#axis]) },
+            false => input,
+        };
+        // An exclusive sum does not include the element itself
+        let sum = match self.config.exclusive {
+            true => quote! {
+                {
+                    let input = #input;
+                    input.clone().cumsum(#axis).sub(input)
+                }
+            },
+            false => quote! { #input.cumsum(#axis) },
+        };
+        let sum = match self.config.reverse {
+            true => quote! { #sum.flip([#axis]) },
+            false => sum,
+        };
+
+        quote! {
+            let #output = #sum;
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::CumSum(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn::{graph::BurnGraph, node::test::assert_tokens};
+    use burn::record::FullPrecisionSettings;
+
+    fn graph(exclusive: bool, reverse: bool) -> BurnGraph<FullPrecisionSettings> {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(CumSumNode::new(
+            TensorType::new_float("tensor1", 2),
+            TensorType::new_float("tensor2", 2),
+            CumSumConfig {
+                axis: 1,
+                exclusive,
+                reverse,
+            },
+        ));
+        graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor2".to_string()]);
+
+        graph
+    }
+
+    fn expected(body: TokenStream) -> TokenStream {
+        quote! {
+            use burn::prelude::*;
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 2>) -> Tensor<B, 2> {
+                    let tensor2 = #body;
+
+                    tensor2
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_codegen_cumsum() {
+        assert_tokens(
+            graph(false, false).codegen(),
+            expected(quote! { tensor1.cumsum(1) }),
+        );
+    }
+
+    #[test]
+    fn test_codegen_cumsum_exclusive_reverse() {
+        assert_tokens(
+            graph(true, true).codegen(),
+            expected(quote! {
+                {
+                    let input = tensor1.flip([1]);
+                    input.clone().cumsum(1).sub(input)
+                }
+                .flip([1])
+            }),
+        );
+    }
+}
@@ -0,0 +1,95 @@
+use super::{Node, NodeCodegen, quantization::dequantize};
+use crate::burn::{Scope, TensorType, Type};
+use burn::record::PrecisionSettings;
+use onnx_ir::node::quantization::QuantizationParams;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Dequantizes the quantized values of an int tensor.
+///
+/// The DequantizeLinear nodes of constant weights are folded by onnx-ir, so this node only
+/// dequantizes the values computed at runtime, e.g. the activations of a QDQ model.
+#[derive(Debug, Clone, new)]
+pub struct DequantizeLinearNode {
+    pub input: TensorType,
+    pub output: TensorType,
+    pub params: QuantizationParams,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for DequantizeLinearNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.input.clone())]
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let output = &self.output.name;
+        let dequantized = dequantize(input, &self.params, self.input.rank);
+
+        quote! {
+            let #output = #dequantized;
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::DequantizeLinear(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{graph::BurnGraph, node::test::assert_tokens};
+    use onnx_ir::ElementType;
+
+    #[test]
+    fn test_codegen_per_tensor() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(DequantizeLinearNode::new(
+            TensorType::new_int("tensor1", 4),
+            TensorType::new_float("tensor2", 4),
+            QuantizationParams {
+                scale: vec![0.5],
+                zero_point: vec![128],
+                axis: 1,
+                elem_type: ElementType::Uint8,
+            },
+        ));
+
+        graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor2".to_string()]);
+
+        let expected = quote! {
+            use burn::prelude::*;
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model<B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 4, Int>) -> Tensor<B, 4> {
+                    let tensor2 = tensor1.float().sub_scalar(128).mul_scalar(0.5);
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -0,0 +1,332 @@
+use super::{Node, NodeCodegen, gather_nd::dims_product};
+use crate::burn::{Scope, TensorType, ToTokens, Type};
+use burn::record::PrecisionSettings;
+use onnx_ir::node::einsum::EinsumConfig;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+#[derive(Debug, Clone, new)]
+pub struct EinsumNode {
+    pub inputs: Vec<TensorType>,
+    pub output: Type,
+    pub config: EinsumConfig,
+}
+
+/// The position of every subscript of `order` in `subscripts`.
+fn positions(subscripts: &[char], order: &[char]) -> Vec<usize> {
+    order
+        .iter()
+        .map(|subscript| {
+            subscripts
+                .iter()
+                .position(|other| other == subscript)
+                .unwrap()
+        })
+        .collect()
+}
+
+/// Sum the dimensions of `subscripts` that are not in the output, keeping them with a size of one.
+fn sum_missing(input: TokenStream, subscripts: &[char], output: &[char]) -> TokenStream {
+    subscripts
+        .iter()
+        .enumerate()
+        .filter(|(_, subscript)| !output.contains(subscript))
+        .fold(input, |input, (dim, _)| {
+            let dim = dim.to_tokens();
+            quote! { #input.sum_dim(#dim) }
+        })
+}
+
+/// Permute the dimensions of `subscripts` to the given order, unless it is the same order.
+fn permute(input: TokenStream, subscripts: &[char], order: &[char]) -> TokenStream {
+    if subscripts == order {
+        return input;
+    }
+
+    let axes = positions(subscripts, order).to_tokens();
+    quote! { #input.permute(#axes) }
+}
+
+impl EinsumNode {
+    fn forward_unary(&self, input: TokenStream) -> TokenStream {
+        let subscripts = &self.config.inputs[0];
+        let output = &self.config.output;
+
+        if output.is_empty() {
+            return quote! { #input.sum() };
+        }
+        if subscripts
+            .iter()
+            .all(|subscript| output.contains(subscript))
+        {
+            return permute(input, subscripts, output);
+        }
+
+        // The summed dimensions are removed by reshaping to the sizes of the remaining ones
+        let summed = sum_missing(input, subscripts, output);
+        let kept = subscripts
+            .iter()
+            .filter(|subscript| output.contains(subscript))
+            .copied()
+            .collect::<Vec<_>>();
+        let sizes = positions(subscripts, &kept).into_iter().map(|dim| {
+            let dim = dim.to_tokens();
+            quote! { dims[#dim] }
+        });
+        let reshaped = permute(quote! { input.reshape([#(#sizes),*]) }, &kept, output);
+
+        quote! {
+            {
+                let input = #summed;
+                let dims = input.dims();
+                #reshaped
+            }
+        }
+    }
+
+    fn forward_binary(&self, lhs: TokenStream, rhs: TokenStream) -> TokenStream {
+        let lhs_subscripts = &self.config.inputs[0];
+        let rhs_subscripts = &self.config.inputs[1];
+        let output = &self.config.output;
+
+        let filter = |subscripts: &[char], predicate: &dyn Fn(&char) -> bool| {
+            subscripts
+                .iter()
+                .filter(|subscript| predicate(subscript))
+                .copied()
+                .collect::<Vec<_>>()
+        };
+        // The dimensions of both inputs are either batch dimensions when they are in the output,
+        // or contracted when they are not. The free dimensions of one input are summed when
+        // they are not in the output.
+        let batch = filter(lhs_subscripts, &|s| {
+            rhs_subscripts.contains(s) && output.contains(s)
+        });
+        let contracted = filter(lhs_subscripts, &|s| {
+            rhs_subscripts.contains(s) && !output.contains(s)
+        });
+        let lhs_free = filter(lhs_subscripts, &|s| !rhs_subscripts.contains(s));
+        let rhs_free = filter(rhs_subscripts, &|s| !lhs_subscripts.contains(s));
+
+        let lhs_order = [batch.as_slice(), &lhs_free, &contracted].concat();
+        let rhs_order = [batch.as_slice(), &contracted, &rhs_free].concat();
+        let lhs = permute(
+            sum_missing(lhs, lhs_subscripts, &[&rhs_subscripts[..], output].concat()),
+            lhs_subscripts,
+            &lhs_order,
+        );
+        let rhs = permute(
+            sum_missing(rhs, rhs_subscripts, &[&lhs_subscripts[..], output].concat()),
+            rhs_subscripts,
+            &rhs_order,
+        );
+
+        let (batch_len, lhs_len, contracted_len) = (batch.len(), lhs_free.len(), contracted.len());
+        let lhs_shape = [
+            dims_product("lhs_dims", 0..batch_len),
+            dims_product("lhs_dims", batch_len..batch_len + lhs_len),
+            dims_product("lhs_dims", batch_len + lhs_len..lhs_order.len()),
+        ];
+        let rhs_shape = [
+            dims_product("rhs_dims", 0..batch_len),
+            dims_product("rhs_dims", batch_len..batch_len + contracted_len),
+            dims_product("rhs_dims", batch_len + contracted_len..rhs_order.len()),
+        ];
+        let product = quote! {
+            lhs.reshape([#(#lhs_shape),*]).matmul(rhs.reshape([#(#rhs_shape),*]))
+        };
+
+        let product = match output.is_empty() {
+            true => product,
+            false => {
+                // The product has the batch dimensions, followed by the free dimensions of both
+                // inputs that are in the output
+                let mut kept = Vec::new();
+                let mut sizes = Vec::new();
+                for (i, subscript) in lhs_order.iter().enumerate() {
+                    if !contracted.contains(subscript) && output.contains(subscript) {
+                        let i = i.to_tokens();
+                        kept.push(*subscript);
+                        sizes.push(quote! { lhs_dims[#i] });
+                    }
+                }
+                for (i, subscript) in rhs_order.iter().enumerate().skip(batch_len) {
+                    if !contracted.contains(subscript) && output.contains(subscript) {
+                        let i = i.to_tokens();
+                        kept.push(*subscript);
+                        sizes.push(quote! { rhs_dims[#i] });
+                    }
+                }
+
+                permute(quote! { #product.reshape([#(#sizes),*]) }, &kept, output)
+            }
+        };
+
+        quote! {
+            {
+                let lhs = #lhs;
+                let lhs_dims = lhs.dims();
+                let rhs = #rhs;
+                let rhs_dims = rhs.dims();
+                #product
+            }
+        }
+    }
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for EinsumNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![self.output.clone()]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        self.inputs
+            .iter()
+            .map(|input| Type::Tensor(input.clone()))
+            .collect()
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let inputs = self
+            .inputs
+            .iter()
+            .map(|input| scope.tensor_use_owned(input, node_position))
+            .collect::<Vec<_>>();
+        let output = self.output.name();
+
+        let result = match inputs.as_slice() {
+            [input] => self.forward_unary(input.clone()),
+            [lhs, rhs] => self.forward_binary(lhs.clone(), rhs.clone()),
+            _ => panic!("Einsum: only one or two inputs are supported"),
+        };
+
+        let result = match &self.output {
+            Type::Scalar(scalar_type) => {
+                let elem_type = scalar_type.ty();
+                quote! { #result.into_scalar().elem::<#elem_type>() }
+            }
+            Type::Tensor(_) => result,
+            _ => panic!("Einsum: the output must be a tensor or a scalar"),
+        };
+
+        quote! {
+            let #output = #result;
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Einsum(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn::{graph::BurnGraph, node::test::assert_tokens};
+    use burn::record::FullPrecisionSettings;
+
+    #[test]
+    fn test_codegen_einsum_batch_matmul() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(EinsumNode::new(
+            vec![
+                TensorType::new_float("tensor1", 3),
+                TensorType::new_float("tensor2", 3),
+            ],
+            Type::Tensor(TensorType::new_float("output", 3)),
+            EinsumConfig {
+                inputs: vec![vec!['b', 'i', 'k'], vec!['b', 'j', 'k']],
+                output: vec!['b', 'j', 'i'],
+            },
+        ));
+        graph.register_input_output(
+            vec!["tensor1".to_string(), "tensor2".to_string()],
+            vec!["output".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::prelude::*;
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 3>, tensor2: Tensor<B, 3>) -> Tensor<B, 3> {
+                    let output = {
+                        let lhs = tensor1;
+                        let lhs_dims = lhs.dims();
+                        let rhs = tensor2.permute([0, 2, 1]);
+                        let rhs_dims = rhs.dims();
+                        lhs.reshape([lhs_dims[0], lhs_dims[1], lhs_dims[2]])
+                            .matmul(rhs.reshape([rhs_dims[0], rhs_dims[1], rhs_dims[2]]))
+                            .reshape([lhs_dims[0], lhs_dims[1], rhs_dims[2]])
+                            .permute([0, 2, 1])
+                    };
+
+                    output
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_einsum_trace_free_sum() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(EinsumNode::new(
+            vec![TensorType::new_float("input", 3)],
+            Type::Tensor(TensorType::new_float("output", 2)),
+            EinsumConfig {
+                inputs: vec![vec!['i', 'j', 'k']],
+                output: vec!['k', 'i'],
+            },
+        ));
+        graph.register_input_output(vec!["input".to_string()], vec!["output".to_string()]);
+
+        let expected = quote! {
+            use burn::prelude::*;
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, input: Tensor<B, 3>) -> Tensor<B, 2> {
+                    let output = {
+                        let input = input.sum_dim(1);
+                        let dims = input.dims();
+                        input.reshape([dims[0], dims[2]]).permute([1, 0])
+                    };
+
+                    output
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -0,0 +1,278 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{Scope, TensorType, ToTokens, Type};
+use burn::record::PrecisionSettings;
+use core::ops::Range;
+use onnx_ir::node::gather_nd::GatherNDConfig;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+#[derive(Debug, Clone, new)]
+pub struct GatherNDNode {
+    pub data: TensorType,
+    pub indices: TensorType,
+    pub output: TensorType,
+    pub config: GatherNDConfig,
+}
+
+/// The product of the sizes of the dimensions in `range`, from the given array of dimensions of
+/// the generated code.
+pub(crate) fn dims_product(dims: &str, range: Range<usize>) -> TokenStream {
+    let dims = format_ident!("{}", dims);
+    let mut sizes = range.map(|i| {
+        let i = i.to_tokens();
+        quote! { #dims[#i] }
+    });
+
+    match sizes.next() {
+        Some(first) => sizes.fold(first, |product, size| quote! { #product * #size }),
+        None => quote! { 1 },
+    }
+}
+
+/// The same product as [dims_product], as an `i64`.
+pub(crate) fn dims_product_i64(dims: &str, range: Range<usize>) -> TokenStream {
+    match range.len() {
+        0 => quote! { 1 },
+        1 => {
+            let size = dims_product(dims, range);
+            quote! { #size as i64 }
+        }
+        _ => {
+            let product = dims_product(dims, range);
+            quote! { (#product) as i64 }
+        }
+    }
+}
+
+/// The flat index of the `[count, depth]` indices bound to `index` in the generated code, into the
+/// flattened dimensions `offset..offset + depth` of `dims`.
+pub(crate) fn flat_index(offset: usize, depth: usize) -> TokenStream {
+    if depth == 1 {
+        return quote! { index.reshape([count]) };
+    }
+
+    let strides = (0..depth).map(|j| dims_product_i64("dims", offset + j + 1..offset + depth));
+    let depth = depth.to_tokens();
+
+    quote! {
+        index
+            .reshape([count, #depth])
+            .mul(Tensor::<B, 1, Int>::from_ints([#(#strides),*], &*self.device).unsqueeze())
+            .sum_dim(1)
+            .reshape([count])
+    }
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for GatherNDNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        vec![
+            Type::Tensor(self.data.clone()),
+            Type::Tensor(self.indices.clone()),
+        ]
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let data = scope.tensor_use_owned(&self.data, node_position);
+        let indices = scope.tensor_use_owned(&self.indices, node_position);
+        let output = &self.output.name;
+
+        let data_rank = self.data.rank;
+        let indices_rank = self.indices.rank;
+        let batch_dims = self.config.batch_dims;
+        let depth = indices_rank + data_rank - 1 - batch_dims - self.output.rank;
+
+        let count = dims_product("index_dims", 0..indices_rank - 1);
+        let index_dims = match indices_rank {
+            1 => quote! {},
+            _ => quote! { let index_dims = index.dims(); },
+        };
+        let index = flat_index(batch_dims, depth);
+        // The indices of every batch are offset to the slices of their batch
+        let batch_offset = match batch_dims {
+            0 => quote! {},
+            _ => {
+                let batch = dims_product("dims", 0..batch_dims);
+                let slices = dims_product_i64("dims", batch_dims..batch_dims + depth);
+                quote! {
+                    let batch = #batch;
+                    let index = index
+                        .reshape([batch, count / batch])
+                        .add(
+                            Tensor::<B, 1, Int>::arange(0..batch as i64, &*self.device)
+                                .mul_scalar(#slices)
+                                .unsqueeze_dim(1),
+                        )
+                        .reshape([count]);
+                }
+            }
+        };
+        let rows = dims_product("dims", 0..batch_dims + depth);
+        let inner = dims_product("dims", batch_dims + depth..data_rank);
+        let output_dims = (0..indices_rank - 1)
+            .map(|i| {
+                let i = i.to_tokens();
+                quote! { index_dims[#i] }
+            })
+            .chain((batch_dims + depth..data_rank).map(|i| {
+                let i = i.to_tokens();
+                quote! { dims[#i] }
+            }));
+
+        quote! {
+            let #output = {
+                let values = #data;
+                let dims = values.dims();
+                let index = #indices;
+                #index_dims
+                let count = #count;
+                let index = #index;
+                #batch_offset
+                values
+                    .reshape([#rows, #inner])
+                    .select(0, index)
+                    .reshape([#(#output_dims),*])
+            };
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::GatherND(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn::{graph::BurnGraph, node::test::assert_tokens};
+    use burn::record::FullPrecisionSettings;
+
+    #[test]
+    fn test_codegen_gather_nd() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(GatherNDNode::new(
+            TensorType::new_float("data", 3),
+            TensorType::new_int("indices", 2),
+            TensorType::new_float("output", 2),
+            GatherNDConfig { batch_dims: 0 },
+        ));
+        graph.register_input_output(
+            vec!["data".to_string(), "indices".to_string()],
+            vec!["output".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::prelude::*;
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, data: Tensor<B, 3>, indices: Tensor<B, 2, Int>) -> Tensor<B, 2> {
+                    let output = {
+                        let values = data;
+                        let dims = values.dims();
+                        let index = indices;
+                        let index_dims = index.dims();
+                        let count = index_dims[0];
+                        let index = index
+                            .reshape([count, 2])
+                            .mul(
+                                Tensor::<B, 1, Int>::from_ints([dims[1] as i64, 1], &*self.device)
+                                    .unsqueeze()
+                            )
+                            .sum_dim(1)
+                            .reshape([count]);
+                        values
+                            .reshape([dims[0] * dims[1], dims[2]])
+                            .select(0, index)
+                            .reshape([index_dims[0], dims[2]])
+                    };
+
+                    output
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_gather_nd_batch_dims() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(GatherNDNode::new(
+            TensorType::new_float("data", 3),
+            TensorType::new_int("indices", 3),
+            TensorType::new_float("output", 3),
+            GatherNDConfig { batch_dims: 1 },
+        ));
+        graph.register_input_output(
+            vec!["data".to_string(), "indices".to_string()],
+            vec!["output".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::prelude::*;
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, data: Tensor<B, 3>, indices: Tensor<B, 3, Int>) -> Tensor<B, 3> {
+                    let output = {
+                        let values = data;
+                        let dims = values.dims();
+                        let index = indices;
+                        let index_dims = index.dims();
+                        let count = index_dims[0] * index_dims[1];
+                        let index = index.reshape([count]);
+                        let batch = dims[0];
+                        let index = index
+                            .reshape([batch, count / batch])
+                            .add(
+                                Tensor::<B, 1, Int>::arange(0..batch as i64, &*self.device)
+                                    .mul_scalar(dims[1] as i64)
+                                    .unsqueeze_dim(1),
+                            )
+                            .reshape([count]);
+                        values
+                            .reshape([dims[0] * dims[1], dims[2]])
+                            .select(0, index)
+                            .reshape([index_dims[0], index_dims[1], dims[2]])
+                    };
+
+                    output
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -0,0 +1,289 @@
+use super::{
+    Node, NodeCodegen, SerializationBackend,
+    rnn::{RnnIO, RnnLayout, RnnWeights, last_step},
+};
+use crate::burn::{BurnImports, OtherType, Scope, ToTokens, Type};
+use burn::{
+    module::ConstantRecord,
+    nn::gru::GruRecord,
+    record::{PrecisionSettings, Record},
+};
+use onnx_ir::node::{gru::GruConfig, rnn::RnnDirection};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use serde::Serialize;
+
+/// Maps an ONNX GRU to a [burn `Gru`](burn::nn::gru::Gru), or to a pair of them when
+/// bidirectional.
+#[derive(Debug, Clone)]
+pub struct GruNode {
+    pub field: OtherType,
+    pub io: RnnIO,
+    pub weights: RnnWeights,
+    pub config: GruConfig,
+}
+
+impl GruNode {
+    pub fn new<S: AsRef<str>>(name: S, io: RnnIO, weights: RnnWeights, config: GruConfig) -> Self {
+        let ty = match config.direction {
+            RnnDirection::Bidirectional => quote! { [Gru<B>; 2] },
+            _ => quote! { Gru<B> },
+        };
+
+        Self {
+            field: OtherType::new(name, ty),
+            io,
+            weights,
+            config,
+        }
+    }
+
+    /// The record of one direction, with the gates in the ONNX `zrh` order.
+    fn record<PS: PrecisionSettings>(&self, direction: usize) -> GruRecord<SerializationBackend> {
+        GruRecord {
+            update_gate: self.weights.gate_record::<PS>(direction, 0, 3),
+            reset_gate: self.weights.gate_record::<PS>(direction, 1, 3),
+            new_gate: self.weights.gate_record::<PS>(direction, 2, 3),
+            d_hidden: ConstantRecord::new(),
+            reset_after: ConstantRecord::new(),
+        }
+    }
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for GruNode {
+    fn input_types(&self) -> Vec<Type> {
+        self.io.input_types()
+    }
+    fn output_types(&self) -> Vec<Type> {
+        self.io.output_types()
+    }
+
+    fn field_type(&self) -> Option<Type> {
+        Some(Type::Other(self.field.clone()))
+    }
+
+    fn field_init(&self) -> Option<TokenStream> {
+        let name = &self.field.name;
+        let d_input = self.config.input_size.to_tokens();
+        let d_hidden = self.config.hidden_size.to_tokens();
+        let bias = self.config.bias;
+        let reset_after = self.config.linear_before_reset;
+        let init = quote! {
+            GruConfig::new(#d_input, #d_hidden, #bias)
+                .with_reset_after(#reset_after)
+                .init(device)
+        };
+        let tokens = match self.config.direction {
+            RnnDirection::Bidirectional => quote! {
+                let #name = [#init, #init];
+            },
+            _ => quote! {
+                let #name = #init;
+            },
+        };
+
+        Some(tokens)
+    }
+
+    fn field_serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.config.direction {
+            RnnDirection::Bidirectional => {
+                let record = [self.record::<PS>(0), self.record::<PS>(1)];
+
+                Record::into_item::<PS>(record).serialize(serializer)
+            }
+            _ => Record::into_item::<PS>(self.record::<PS>(0)).serialize(serializer),
+        }
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let field = &self.field.name;
+        let has_state = self.io.has_state();
+        let has_hidden = self.io.has_hidden_output();
+        let output = format_ident!("output");
+
+        let run = match self.config.direction {
+            RnnDirection::Bidirectional => {
+                let forward_output = format_ident!("forward_output");
+                let reverse_output = format_ident!("reverse_output");
+                let (forward_state, reverse_state) = match has_state {
+                    true => (
+                        quote! { Some(initial_h.clone().narrow(0, 0, 1).squeeze::<2>(0)) },
+                        quote! { Some(initial_h.narrow(0, 1, 1).squeeze::<2>(0)) },
+                    ),
+                    false => (quote! { None }, quote! { None }),
+                };
+                let hidden = match has_hidden {
+                    true => {
+                        let forward_hidden = last_step(&forward_output);
+                        let reverse_hidden = last_step(&reverse_output);
+                        quote! {
+                            let hidden = Tensor::stack::<3>(
+                                [#forward_hidden, #reverse_hidden].to_vec(),
+                                0,
+                            );
+                        }
+                    }
+                    false => quote! {},
+                };
+
+                quote! {
+                    let #forward_output = self.#field[0].forward(input.clone(), #forward_state);
+                    let #reverse_output = self.#field[1].forward(input.flip([1]), #reverse_state);
+                    #hidden
+                    let output = Tensor::cat(
+                        [#forward_output, #reverse_output.flip([1])].to_vec(),
+                        2,
+                    );
+                }
+            }
+            direction => {
+                let state = match has_state {
+                    true => quote! { Some(initial_h) },
+                    false => quote! { None },
+                };
+                let hidden = match has_hidden {
+                    true => {
+                        let hidden = last_step(&output);
+                        quote! {
+                            let hidden = #hidden;
+                        }
+                    }
+                    false => quote! {},
+                };
+
+                match direction {
+                    RnnDirection::Reverse => quote! {
+                        let output = self.#field.forward(input.flip([1]), #state);
+                        #hidden
+                        let output = output.flip([1]);
+                    },
+                    _ => quote! {
+                        let output = self.#field.forward(input, #state);
+                        #hidden
+                    },
+                }
+            }
+        };
+        let layout = RnnLayout::new(
+            self.config.batch_first,
+            self.config.direction == RnnDirection::Bidirectional,
+            self.config.hidden_size,
+            false,
+        );
+
+        self.io.forward(scope, node_position, layout, run)
+    }
+
+    fn register_imports(&self, imports: &mut BurnImports) {
+        imports.register("burn::nn::gru::Gru");
+        imports.register("burn::nn::gru::GruConfig");
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Gru(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn::{TensorType, graph::BurnGraph, node::test::assert_tokens};
+    use burn::{record::FullPrecisionSettings, tensor::TensorData};
+
+    #[test]
+    fn test_codegen_bigru() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(GruNode::new(
+            "gru",
+            RnnIO::new(
+                TensorType::new_float("input", 3),
+                None,
+                None,
+                None,
+                vec![
+                    TensorType::new_float("output", 4),
+                    TensorType::new_float("output_h", 3),
+                ],
+            ),
+            RnnWeights::new(
+                TensorData::zeros::<f32, _>([2, 6, 4]),
+                TensorData::zeros::<f32, _>([2, 6, 2]),
+                None,
+            ),
+            GruConfig {
+                input_size: 4,
+                hidden_size: 2,
+                direction: RnnDirection::Bidirectional,
+                batch_first: true,
+                bias: false,
+                linear_before_reset: true,
+            },
+        ));
+
+        graph.register_input_output(
+            vec!["input".to_string()],
+            vec!["output".to_string(), "output_h".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::prelude::*;
+            use burn::nn::gru::Gru;
+            use burn::nn::gru::GruConfig;
+
+            #[derive(Module, Debug)]
+            pub struct Model <B: Backend> {
+                gru: [Gru<B>; 2],
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    let gru = [
+                        GruConfig::new(4, 2, false).with_reset_after(true).init(device),
+                        GruConfig::new(4, 2, false).with_reset_after(true).init(device),
+                    ];
+
+                    Self {
+                        gru,
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, input: Tensor<B, 3>) -> (Tensor<B, 4>, Tensor<B, 3>) {
+                    let (output, output_h) = {
+                        let forward_output = self.gru[0].forward(input.clone(), None);
+                        let reverse_output = self.gru[1].forward(input.flip([1]), None);
+                        let hidden = Tensor::stack::<3>(
+                            [
+                                forward_output
+                                    .clone()
+                                    .narrow(1, forward_output.dims()[1] - 1, 1)
+                                    .squeeze::<2>(1),
+                                reverse_output
+                                    .clone()
+                                    .narrow(1, reverse_output.dims()[1] - 1, 1)
+                                    .squeeze::<2>(1)
+                            ]
+                            .to_vec(),
+                            0,
+                        );
+                        let output = Tensor::cat(
+                            [forward_output, reverse_output.flip([1])].to_vec(),
+                            2,
+                        );
+                        (output.reshape([0, 0, 2, 2]), hidden.swap_dims(0, 1))
+                    };
+
+                    (output, output_h)
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
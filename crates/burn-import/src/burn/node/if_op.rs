@@ -0,0 +1,178 @@
+use super::{
+    Node, NodeCodegen,
+    subgraph::{SubGraph, release_captured, tuple, use_scalar},
+};
+use crate::burn::{BurnImports, Scope, Type};
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Executes one of two subgraphs depending on a condition, with a Rust `if` expression.
+#[derive(Debug, Clone, new)]
+pub struct IfNode<PS: PrecisionSettings> {
+    pub condition: Type,
+    pub outputs: Vec<Type>,
+    pub then_branch: SubGraph<PS>,
+    pub else_branch: SubGraph<PS>,
+}
+
+impl<PS: PrecisionSettings> IfNode<PS> {
+    /// The values of the enclosing graph used by any branch.
+    fn captured(&self) -> Vec<Type> {
+        let mut captured = self.then_branch.captured.clone();
+        for ty in self.else_branch.captured.iter() {
+            if !captured.iter().any(|other| other.name() == ty.name()) {
+                captured.push(ty.clone());
+            }
+        }
+        captured
+    }
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for IfNode<PS> {
+    fn output_types(&self) -> Vec<Type> {
+        self.outputs.clone()
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        let mut inputs = vec![self.condition.clone()];
+        inputs.extend(self.captured());
+        inputs
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let condition = use_scalar(&self.condition, quote! { bool }, scope, node_position);
+        release_captured(&self.captured(), scope, node_position);
+
+        let (then_body, then_outputs) = self.then_branch.forward();
+        let (else_body, else_outputs) = self.else_branch.forward();
+        let then_outputs = tuple(&then_outputs);
+        let else_outputs = tuple(&else_outputs);
+
+        let outputs = self
+            .outputs
+            .iter()
+            .map(|output| {
+                let name = output.name();
+                quote! { #name }
+            })
+            .collect::<Vec<_>>();
+        let outputs = tuple(&outputs);
+
+        quote! {
+            let #outputs = if #condition {
+                #then_body
+                #then_outputs
+            } else {
+                #else_body
+                #else_outputs
+            };
+        }
+    }
+
+    fn register_imports(&self, imports: &mut BurnImports) {
+        self.then_branch.register_imports(imports);
+        self.else_branch.register_imports(imports);
+    }
+
+    fn nested_nodes(&self) -> Vec<&Node<PS>> {
+        self.then_branch
+            .nodes
+            .iter()
+            .chain(self.else_branch.nodes.iter())
+            .collect()
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::If(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        ScalarKind, ScalarType, TensorType,
+        graph::BurnGraph,
+        node::{test::assert_tokens, unary::UnaryNode},
+    };
+
+    #[test]
+    fn test_codegen_if() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+        let input = Type::Tensor(TensorType::new_float("tensor1", 2));
+
+        let then_branch = SubGraph::new(
+            vec![
+                UnaryNode::relu(
+                    Type::Tensor(TensorType::new_float("tensor1", 2)),
+                    Type::Tensor(TensorType::new_float("tensor2", 2)),
+                )
+                .into_node(),
+            ],
+            vec![],
+            vec![Type::Tensor(TensorType::new_float("tensor2", 2))],
+            vec![input.clone()],
+        );
+        let else_branch = SubGraph::new(
+            vec![
+                UnaryNode::neg(
+                    Type::Tensor(TensorType::new_float("tensor1", 2)),
+                    Type::Tensor(TensorType::new_float("tensor3", 2)),
+                )
+                .into_node(),
+            ],
+            vec![],
+            vec![Type::Tensor(TensorType::new_float("tensor3", 2))],
+            vec![input],
+        );
+
+        graph.register(IfNode::new(
+            Type::Scalar(ScalarType::new("cond", ScalarKind::Bool)),
+            vec![Type::Tensor(TensorType::new_float("output", 2))],
+            then_branch,
+            else_branch,
+        ));
+
+        graph.register_input_output(
+            vec!["cond".to_string(), "tensor1".to_string()],
+            vec!["output".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::prelude::*;
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, cond: bool, tensor1: Tensor<B, 2>) -> Tensor<B, 2> {
+                    let output = if cond {
+                        let tensor2 = burn::tensor::activation::relu(tensor1.clone());
+                        tensor2
+                    } else {
+                        let tensor3 = tensor1.clone().neg();
+                        tensor3
+                    };
+
+                    output
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -0,0 +1,331 @@
+use super::{
+    Node, NodeCodegen,
+    subgraph::{SubGraph, release_captured, tuple, use_scalar, use_value},
+};
+use crate::burn::{BurnImports, Scope, ToTokens, Type};
+use burn::record::PrecisionSettings;
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+
+/// Executes a subgraph repeatedly with a Rust `for` loop.
+///
+/// The loop stops after the maximum trip count or when the condition returned by the body is
+/// false. The scan outputs are stacked along a new first dimension, so the loop must execute at
+/// least one iteration when there are scan outputs.
+#[derive(Debug, Clone, new)]
+pub struct LoopNode<PS: PrecisionSettings> {
+    pub max_trip_count: Option<Type>,
+    pub condition: Option<Type>,
+    pub initial_values: Vec<Type>,
+    pub outputs: Vec<Type>,
+    /// The inputs are the iteration number, the condition and the loop carried dependencies. The
+    /// outputs are the condition, the loop carried dependencies and the scan outputs.
+    pub body: SubGraph<PS>,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for LoopNode<PS> {
+    fn output_types(&self) -> Vec<Type> {
+        self.outputs.clone()
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        self.max_trip_count
+            .iter()
+            .chain(self.condition.iter())
+            .chain(self.initial_values.iter())
+            .chain(self.body.captured.iter())
+            .cloned()
+            .collect()
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let max_trip_count = match &self.max_trip_count {
+            Some(ty) => use_scalar(ty, quote! { i64 }, scope, node_position),
+            None => quote! { i64::MAX },
+        };
+        let condition = self
+            .condition
+            .as_ref()
+            .map(|ty| use_scalar(ty, quote! { bool }, scope, node_position));
+        let initial_values = self
+            .initial_values
+            .iter()
+            .map(|ty| use_value(ty, scope, node_position))
+            .collect::<Vec<_>>();
+        release_captured(&self.body.captured, scope, node_position);
+
+        let iteration = self.body.inputs[0].name();
+        let cond = self.body.inputs[1].name();
+        let carried = self.body.inputs[2..]
+            .iter()
+            .map(|ty| ty.name().clone())
+            .collect::<Vec<_>>();
+        let scans = self.outputs[carried.len()..]
+            .iter()
+            .map(|ty| format_ident!("{}_scans", ty.name()))
+            .collect::<Vec<_>>();
+
+        let (body, body_outputs) = self.body.forward();
+        let cond_output = match &self.body.outputs[0] {
+            Type::Tensor(_) => {
+                let value = &body_outputs[0];
+                quote! { #value.into_scalar().elem::<bool>() }
+            }
+            _ => body_outputs[0].clone(),
+        };
+
+        // The loop carried dependencies, including the condition when it is checked.
+        let mut places = carried.clone();
+        let mut values = body_outputs[1..=carried.len()].to_vec();
+        let mut init = quote! {
+            #(let mut #carried = #initial_values;)*
+        };
+        let mut check = quote! {};
+        if let Some(condition) = condition {
+            places.insert(0, cond.clone());
+            values.insert(0, cond_output);
+            init.extend(quote! {
+                let mut #cond = #condition;
+            });
+            check = quote! {
+                if !#cond {
+                    break;
+                }
+            };
+        } else if self.body.uses(cond, 1) {
+            init.extend(quote! {
+                let #cond = true;
+            });
+        }
+        for scan in scans.iter() {
+            init.extend(quote! {
+                let mut #scan = [].to_vec();
+            });
+        }
+
+        let update = update(
+            &places,
+            &values,
+            &self.body.inputs,
+            &scans,
+            &body_outputs[carried.len() + 1..],
+        );
+        let iteration = match self.body.uses(iteration, 0) {
+            true => quote! { #iteration },
+            false => quote! { _ },
+        };
+
+        let ranks = self.outputs[carried.len()..]
+            .iter()
+            .map(|ty| ty.as_tensor().rank.to_tokens());
+        let results = carried
+            .iter()
+            .map(|name| quote! { #name })
+            .chain(
+                scans
+                    .iter()
+                    .zip(ranks)
+                    .map(|(scan, rank)| quote! { Tensor::stack::<#rank>(#scan, 0) }),
+            )
+            .collect::<Vec<_>>();
+        let results = tuple(&results);
+        let outputs = self
+            .outputs
+            .iter()
+            .map(|output| {
+                let name = output.name();
+                quote! { #name }
+            })
+            .collect::<Vec<_>>();
+        let outputs = tuple(&outputs);
+
+        quote! {
+            let #outputs = {
+                #init
+                for #iteration in 0..#max_trip_count {
+                    #check
+                    #body
+                    #update
+                }
+                #results
+            };
+        }
+    }
+
+    fn register_imports(&self, imports: &mut BurnImports) {
+        self.body.register_imports(imports);
+    }
+
+    fn nested_nodes(&self) -> Vec<&Node<PS>> {
+        self.body.nodes.iter().collect()
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Loop(self)
+    }
+}
+
+/// Assign the body outputs to the loop carried variables and push the scan outputs.
+///
+/// The outputs are evaluated in a single expression, in the order used to track their ownership,
+/// before updating any variable.
+pub(crate) fn update(
+    places: &[Ident],
+    values: &[TokenStream],
+    inputs: &[Type],
+    scans: &[Ident],
+    scan_values: &[TokenStream],
+) -> TokenStream {
+    // A variable passed through unchanged is assigned to itself, which requires a temporary.
+    let passthrough = places.iter().zip(values).any(|(place, value)| {
+        inputs.iter().any(|input| input.name() == place) && value.to_string() == place.to_string()
+    });
+
+    if scans.is_empty() && !passthrough {
+        let places = places
+            .iter()
+            .map(|place| quote! { #place })
+            .collect::<Vec<_>>();
+        let places = tuple(&places);
+        let values = tuple(values);
+
+        return quote! {
+            #places = #values;
+        };
+    }
+
+    let next = places
+        .iter()
+        .chain(scans)
+        .map(|name| format_ident!("{}_next", name))
+        .collect::<Vec<_>>();
+    let (next_places, next_scans) = next.split_at(places.len());
+    let next_tokens = next.iter().map(|name| quote! { #name }).collect::<Vec<_>>();
+    let next_tokens = tuple(&next_tokens);
+    let all_values = values
+        .iter()
+        .chain(scan_values)
+        .cloned()
+        .collect::<Vec<_>>();
+    let all_values = tuple(&all_values);
+
+    quote! {
+        let #next_tokens = #all_values;
+        #(#places = #next_places;)*
+        #(#scans.push(#next_scans);)*
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        ScalarKind, ScalarType, TensorType,
+        graph::BurnGraph,
+        node::{binary::BinaryNode, test::assert_tokens},
+    };
+
+    #[test]
+    fn test_codegen_loop() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        // Adds the input to the state at each iteration, and stacks the intermediate states.
+        let body = SubGraph::new(
+            vec![
+                BinaryNode::add(
+                    Type::Tensor(TensorType::new_float("state", 2)),
+                    Type::Tensor(TensorType::new_float("tensor1", 2)),
+                    Type::Tensor(TensorType::new_float("add1_out1", 2)),
+                )
+                .into_node(),
+            ],
+            vec![
+                Type::Scalar(ScalarType::new("iteration", ScalarKind::Int64)),
+                Type::Scalar(ScalarType::new("cond_in", ScalarKind::Bool)),
+                Type::Tensor(TensorType::new_float("state", 2)),
+            ],
+            vec![
+                Type::Scalar(ScalarType::new("cond_in", ScalarKind::Bool)),
+                Type::Tensor(TensorType::new_float("add1_out1", 2)),
+                Type::Tensor(TensorType::new_float("add1_out1", 2)),
+            ],
+            vec![Type::Tensor(TensorType::new_float("tensor1", 2))],
+        );
+
+        graph.register(LoopNode::new(
+            Some(Type::Scalar(ScalarType::new(
+                "trip_count",
+                ScalarKind::Int64,
+            ))),
+            Some(Type::Scalar(ScalarType::new("cond", ScalarKind::Bool))),
+            vec![Type::Tensor(TensorType::new_float("tensor2", 2))],
+            vec![
+                Type::Tensor(TensorType::new_float("output1", 2)),
+                Type::Tensor(TensorType::new_float("output2", 3)),
+            ],
+            body,
+        ));
+
+        graph.register_input_output(
+            vec![
+                "trip_count".to_string(),
+                "cond".to_string(),
+                "tensor2".to_string(),
+                "tensor1".to_string(),
+            ],
+            vec!["output1".to_string(), "output2".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::prelude::*;
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(
+                    &self,
+                    trip_count: i64,
+                    cond: bool,
+                    tensor2: Tensor<B, 2>,
+                    tensor1: Tensor<B, 2>
+                ) -> (Tensor<B, 2>, Tensor<B, 3>) {
+                    let (output1, output2) = {
+                        let mut state = tensor2;
+                        let mut cond_in = cond;
+                        let mut output2_scans = [].to_vec();
+                        for _ in 0..trip_count {
+                            if !cond_in {
+                                break;
+                            }
+                            let add1_out1 = state.add(tensor1.clone());
+                            let (cond_in_next, state_next, output2_scans_next) =
+                                (cond_in, add1_out1.clone(), add1_out1);
+                            cond_in = cond_in_next;
+                            state = state_next;
+                            output2_scans.push(output2_scans_next);
+                        }
+                        (state, Tensor::stack::<3>(output2_scans, 0))
+                    };
+
+                    (output1, output2)
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -0,0 +1,315 @@
+use super::{
+    Node, NodeCodegen, SerializationBackend,
+    rnn::{RnnIO, RnnLayout, RnnWeights},
+};
+use crate::burn::{BurnImports, OtherType, Scope, ToTokens, Type};
+use burn::{
+    module::ConstantRecord,
+    nn::{BiLstmRecord, LstmRecord},
+    record::{PrecisionSettings, Record},
+};
+use onnx_ir::node::{lstm::LstmConfig, rnn::RnnDirection};
+use proc_macro2::TokenStream;
+use quote::quote;
+use serde::Serialize;
+
+/// Maps an ONNX LSTM to a [burn `Lstm`](burn::nn::Lstm), or a [`BiLstm`](burn::nn::BiLstm) when
+/// bidirectional.
+#[derive(Debug, Clone)]
+pub struct LstmNode {
+    pub field: OtherType,
+    pub io: RnnIO,
+    pub weights: RnnWeights,
+    pub config: LstmConfig,
+}
+
+impl LstmNode {
+    pub fn new<S: AsRef<str>>(name: S, io: RnnIO, weights: RnnWeights, config: LstmConfig) -> Self {
+        let ty = match config.direction {
+            RnnDirection::Bidirectional => quote! { BiLstm<B> },
+            _ => quote! { Lstm<B> },
+        };
+
+        Self {
+            field: OtherType::new(name, ty),
+            io,
+            weights,
+            config,
+        }
+    }
+
+    /// The record of one direction, with the gates in the ONNX `iofc` order.
+    fn record<PS: PrecisionSettings>(&self, direction: usize) -> LstmRecord<SerializationBackend> {
+        LstmRecord {
+            input_gate: self.weights.gate_record::<PS>(direction, 0, 4),
+            output_gate: self.weights.gate_record::<PS>(direction, 1, 4),
+            forget_gate: self.weights.gate_record::<PS>(direction, 2, 4),
+            cell_gate: self.weights.gate_record::<PS>(direction, 3, 4),
+            d_hidden: ConstantRecord::new(),
+        }
+    }
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for LstmNode {
+    fn input_types(&self) -> Vec<Type> {
+        self.io.input_types()
+    }
+    fn output_types(&self) -> Vec<Type> {
+        self.io.output_types()
+    }
+
+    fn field_type(&self) -> Option<Type> {
+        Some(Type::Other(self.field.clone()))
+    }
+
+    fn field_init(&self) -> Option<TokenStream> {
+        let name = &self.field.name;
+        let d_input = self.config.input_size.to_tokens();
+        let d_hidden = self.config.hidden_size.to_tokens();
+        let bias = self.config.bias;
+        let config = match self.config.direction {
+            RnnDirection::Bidirectional => quote! { BiLstmConfig },
+            _ => quote! { LstmConfig },
+        };
+        let tokens = quote! {
+            let #name = #config::new(#d_input, #d_hidden, #bias).init(device);
+        };
+
+        Some(tokens)
+    }
+
+    fn field_serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.config.direction {
+            RnnDirection::Bidirectional => {
+                let record = BiLstmRecord::<SerializationBackend> {
+                    forward: self.record::<PS>(0),
+                    reverse: self.record::<PS>(1),
+                    d_hidden: ConstantRecord::new(),
+                };
+
+                Record::into_item::<PS>(record).serialize(serializer)
+            }
+            _ => Record::into_item::<PS>(self.record::<PS>(0)).serialize(serializer),
+        }
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let field = &self.field.name;
+        let state = match self.io.has_state() {
+            true => quote! { Some(LstmState::new(initial_c, initial_h)) },
+            false => quote! { None },
+        };
+        let final_state = match (self.io.has_hidden_output(), self.io.has_cell_output()) {
+            (true, true) => quote! { LstmState { hidden, cell } },
+            (true, false) => quote! { LstmState { hidden, .. } },
+            _ => quote! { _ },
+        };
+        let run = match self.config.direction {
+            RnnDirection::Reverse => quote! {
+                let (output, #final_state) = self.#field.forward(input.flip([1]), #state);
+                let output = output.flip([1]);
+            },
+            _ => quote! {
+                let (output, #final_state) = self.#field.forward(input, #state);
+            },
+        };
+        let layout = RnnLayout::new(
+            self.config.batch_first,
+            self.config.direction == RnnDirection::Bidirectional,
+            self.config.hidden_size,
+            true,
+        );
+
+        self.io.forward(scope, node_position, layout, run)
+    }
+
+    fn register_imports(&self, imports: &mut BurnImports) {
+        match self.config.direction {
+            RnnDirection::Bidirectional => {
+                imports.register("burn::nn::BiLstm");
+                imports.register("burn::nn::BiLstmConfig");
+            }
+            _ => {
+                imports.register("burn::nn::Lstm");
+                imports.register("burn::nn::LstmConfig");
+            }
+        }
+        if self.io.has_state() || self.io.has_hidden_output() {
+            imports.register("burn::nn::LstmState");
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Lstm(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn::{
+        TensorType,
+        graph::BurnGraph,
+        node::{rnn::SequenceLens, test::assert_tokens},
+    };
+    use burn::{record::FullPrecisionSettings, tensor::TensorData};
+
+    fn lstm_config(direction: RnnDirection) -> LstmConfig {
+        LstmConfig {
+            input_size: 4,
+            hidden_size: 2,
+            direction,
+            batch_first: false,
+            bias: false,
+        }
+    }
+
+    #[test]
+    fn test_codegen_lstm() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(LstmNode::new(
+            "lstm",
+            RnnIO::new(
+                TensorType::new_float("input", 3),
+                None,
+                Some(TensorType::new_float("initial_h", 3)),
+                None,
+                vec![
+                    TensorType::new_float("output", 4),
+                    TensorType::new_float("output_h", 3),
+                ],
+            ),
+            RnnWeights::new(
+                TensorData::zeros::<f32, _>([1, 8, 4]),
+                TensorData::zeros::<f32, _>([1, 8, 2]),
+                None,
+            ),
+            lstm_config(RnnDirection::Reverse),
+        ));
+
+        graph.register_input_output(
+            vec!["input".to_string(), "initial_h".to_string()],
+            vec!["output".to_string(), "output_h".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::prelude::*;
+            use burn::nn::Lstm;
+            use burn::nn::LstmConfig;
+            use burn::nn::LstmState;
+
+            #[derive(Module, Debug)]
+            pub struct Model <B: Backend> {
+                lstm: Lstm<B>,
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    let lstm = LstmConfig::new(4, 2, false).init(device);
+
+                    Self {
+                        lstm,
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(
+                    &self,
+                    input: Tensor<B, 3>,
+                    initial_h: Tensor<B, 3>
+                ) -> (Tensor<B, 4>, Tensor<B, 3>) {
+                    let (output, output_h) = {
+                        let initial_h = initial_h.squeeze::<2>(0);
+                        let initial_c = initial_h.zeros_like();
+                        let input = input.swap_dims(0, 1);
+                        let (output, LstmState { hidden, .. }) =
+                            self.lstm.forward(input.flip([1]), Some(LstmState::new(initial_c, initial_h)));
+                        let output = output.flip([1]);
+                        (
+                            output.swap_dims(0, 1).unsqueeze_dim::<4>(1),
+                            hidden.unsqueeze_dim::<3>(0)
+                        )
+                    };
+
+                    (output, output_h)
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_bilstm_sequence_lens() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(LstmNode::new(
+            "lstm",
+            RnnIO::new(
+                TensorType::new_float("input", 3),
+                Some(SequenceLens::Static(vec![3, 2])),
+                None,
+                None,
+                vec![TensorType::new_float("output", 4)],
+            ),
+            RnnWeights::new(
+                TensorData::zeros::<f32, _>([2, 8, 4]),
+                TensorData::zeros::<f32, _>([2, 8, 2]),
+                None,
+            ),
+            lstm_config(RnnDirection::Bidirectional),
+        ));
+
+        graph.register_input_output(vec!["input".to_string()], vec!["output".to_string()]);
+
+        let expected = quote! {
+            use burn::prelude::*;
+            use burn::nn::BiLstm;
+            use burn::nn::BiLstmConfig;
+
+            #[derive(Module, Debug)]
+            pub struct Model <B: Backend> {
+                lstm: BiLstm<B>,
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    let lstm = BiLstmConfig::new(4, 2, false).init(device);
+
+                    Self {
+                        lstm,
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, input: Tensor<B, 3>) -> Tensor<B, 4> {
+                    let output = {
+                        let sequence = input.swap_dims(0, 1);
+                        let seq_length = sequence.dims()[1];
+                        let mut outputs = [].to_vec();
+                        for (batch, length) in [3, 2].into_iter().enumerate() {
+                            let input = sequence.clone().narrow(0, batch, 1).narrow(1, 0, length);
+                            let (output, _) = self.lstm.forward(input, None);
+                            outputs.push(output.pad((0, 0, 0, seq_length - length), 0.0));
+                        }
+                        let output = Tensor::cat(outputs, 0);
+                        output.reshape([0, 0, 2, 2]).permute([1, 2, 0, 3])
+                    };
+
+                    output
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -5,6 +5,7 @@ pub(crate) mod argmin;
 pub(crate) mod attention;
 pub(crate) mod avg_pool1d;
 pub(crate) mod avg_pool2d;
+pub(crate) mod avg_pool3d;
 pub(crate) mod batch_norm;
 pub(crate) mod bernoulli;
 pub(crate) mod binary;
@@ -22,30 +23,46 @@ pub(crate) mod constant_of_shape;
 pub(crate) mod conv1d;
 pub(crate) mod conv2d;
 pub(crate) mod conv3d;
+pub(crate) mod conv_integer;
 pub(crate) mod conv_transpose_1d;
 pub(crate) mod conv_transpose_2d;
 pub(crate) mod conv_transpose_3d;
+pub(crate) mod cumsum;
 pub(crate) mod depth_to_space;
+pub(crate) mod dequantize_linear;
 pub(crate) mod dropout;
+pub(crate) mod einsum;
 pub(crate) mod expand;
 pub(crate) mod floor;
 pub(crate) mod gather;
 pub(crate) mod gather_elements;
+pub(crate) mod gather_nd;
 pub(crate) mod gemm;
 pub(crate) mod global_avg_pool;
 pub(crate) mod group_norm;
+pub(crate) mod gru;
 pub(crate) mod identity;
+pub(crate) mod if_op;
 pub(crate) mod instance_norm;
 pub(crate) mod layer_norm;
 pub(crate) mod linear;
+pub(crate) mod loop_op;
+pub(crate) mod lstm;
 pub(crate) mod matmul;
 pub(crate) mod matmul_integer;
 pub(crate) mod max_pool1d;
 pub(crate) mod max_pool2d;
+pub(crate) mod max_pool3d;
 pub(crate) mod mean;
+pub(crate) mod non_max_suppression;
+pub(crate) mod nonzero;
 pub(crate) mod one_hot;
 pub(crate) mod pad;
 pub(crate) mod prelu;
+pub(crate) mod qlinear_conv;
+pub(crate) mod qlinear_matmul;
+pub(crate) mod quantization;
+pub(crate) mod quantize_linear;
 pub(crate) mod random_normal;
 pub(crate) mod random_normal_like;
 pub(crate) mod random_uniform;
@@ -54,11 +71,16 @@ pub(crate) mod range;
 pub(crate) mod reduce;
 pub(crate) mod reshape;
 pub(crate) mod resize;
+pub(crate) mod rnn;
 pub(crate) mod round;
+pub(crate) mod scan;
+pub(crate) mod scatter_elements;
+pub(crate) mod scatter_nd;
 pub(crate) mod slice;
 pub(crate) mod space_to_depth;
 pub(crate) mod split;
 pub(crate) mod squeeze;
+pub(crate) mod subgraph;
 pub(crate) mod sum;
 pub(crate) mod tile;
 pub(crate) mod top_k;
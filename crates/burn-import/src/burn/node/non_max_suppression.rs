@@ -0,0 +1,220 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{Scope, TensorType, ToTokens, Type};
+use burn::record::PrecisionSettings;
+use onnx_ir::node::non_max_suppression::NonMaxSuppressionConfig;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Greedy non-maximum suppression, run on the host since the selected boxes depend on every
+/// previously selected box.
+#[derive(Debug, Clone, new)]
+pub struct NonMaxSuppressionNode {
+    pub boxes: TensorType,
+    pub scores: TensorType,
+    pub output: TensorType,
+    pub config: NonMaxSuppressionConfig,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for NonMaxSuppressionNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        vec![
+            Type::Tensor(self.boxes.clone()),
+            Type::Tensor(self.scores.clone()),
+        ]
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let boxes = scope.tensor_use_owned(&self.boxes, node_position);
+        let scores = scope.tensor_use_owned(&self.scores, node_position);
+        let output = &self.output.name;
+
+        let max_boxes = self.config.max_output_boxes_per_class.to_tokens();
+        let iou_threshold = self.config.iou_threshold.to_tokens();
+
+        // The corners are sorted as `[y1, x1, y2, x2]`, with the minimum coordinates first
+        let corners = match self.config.center_point_box {
+            true => quote! {
+                {
+                    let [x, y, width, height] = [b[0], b[1], b[2], b[3]];
+                    [y - height / 2.0, x - width / 2.0, y + height / 2.0, x + width / 2.0]
+                }
+            },
+            false => quote! {
+                [b[0].min(b[2]), b[1].min(b[3]), b[0].max(b[2]), b[1].max(b[3])]
+            },
+        };
+        let candidates = match self.config.score_threshold {
+            Some(threshold) => {
+                let threshold = threshold.to_tokens();
+                quote! { (0..num_boxes).filter(|&i| scores[i] > #threshold) }
+            }
+            None => quote! { (0..num_boxes) },
+        };
+
+        quote! {
+            let #output = {
+                let boxes = #boxes.into_data();
+                let scores = #scores.into_data();
+                let [num_batches, num_classes, num_boxes] = [scores.shape[0], scores.shape[1], scores.shape[2]];
+                let boxes = boxes.iter::<f32>().collect::<alloc::vec::Vec<_>>();
+                let scores = scores.iter::<f32>().collect::<alloc::vec::Vec<_>>();
+                let mut selected = alloc::vec::Vec::new();
+
+                for batch in 0..num_batches {
+                    let corners = boxes[batch * num_boxes * 4..(batch + 1) * num_boxes * 4]
+                        .chunks(4)
+                        .map(|b| #corners)
+                        .collect::<alloc::vec::Vec<_>>();
+                    let area = |[y1, x1, y2, x2]: [f32; 4]| (y2 - y1) * (x2 - x1);
+
+                    for class in 0..num_classes {
+                        let offset = (batch * num_classes + class) * num_boxes;
+                        let scores = &scores[offset..offset + num_boxes];
+                        let mut candidates = #candidates.collect::<alloc::vec::Vec<_>>();
+                        candidates.sort_by(|&a, &b| scores[b].total_cmp(&scores[a]));
+
+                        let mut kept = alloc::vec::Vec::<usize>::new();
+                        for i in candidates {
+                            if kept.len() == #max_boxes {
+                                break;
+                            }
+                            let [y1, x1, y2, x2] = corners[i];
+                            let suppressed = kept.iter().any(|&j| {
+                                let [other_y1, other_x1, other_y2, other_x2] = corners[j];
+                                let height = (y2.min(other_y2) - y1.max(other_y1)).max(0.0);
+                                let width = (x2.min(other_x2) - x1.max(other_x1)).max(0.0);
+                                let intersection = height * width;
+                                let union = area(corners[i]) + area(corners[j]) - intersection;
+                                union > 0.0 && intersection / union > #iou_threshold
+                            });
+                            if !suppressed {
+                                kept.push(i);
+                            }
+                        }
+
+                        selected.extend(
+                            kept.into_iter()
+                                .flat_map(|i| [batch as i64, class as i64, i as i64]),
+                        );
+                    }
+                }
+
+                let count = selected.len() / 3;
+                Tensor::<B, 2, Int>::from_data(TensorData::new(selected, [count, 3]), &*self.device)
+            };
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::NonMaxSuppression(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn::{graph::BurnGraph, node::test::assert_tokens};
+    use burn::record::FullPrecisionSettings;
+
+    #[test]
+    fn test_codegen_non_max_suppression() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(NonMaxSuppressionNode::new(
+            TensorType::new_float("boxes", 3),
+            TensorType::new_float("scores", 3),
+            TensorType::new_int("selected", 2),
+            NonMaxSuppressionConfig {
+                center_point_box: false,
+                max_output_boxes_per_class: 2,
+                iou_threshold: 0.5,
+                score_threshold: None,
+            },
+        ));
+        graph.register_input_output(
+            vec!["boxes".to_string(), "scores".to_string()],
+            vec!["selected".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::prelude::*;
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, boxes: Tensor<B, 3>, scores: Tensor<B, 3>) -> Tensor<B, 2, Int> {
+                    let selected = {
+                        let boxes = boxes.into_data();
+                        let scores = scores.into_data();
+                        let [num_batches, num_classes, num_boxes] = [scores.shape[0], scores.shape[1], scores.shape[2]];
+                        let boxes = boxes.iter::<f32>().collect::<alloc::vec::Vec<_>>();
+                        let scores = scores.iter::<f32>().collect::<alloc::vec::Vec<_>>();
+                        let mut selected = alloc::vec::Vec::new();
+
+                        for batch in 0..num_batches {
+                            let corners = boxes[batch * num_boxes * 4..(batch + 1) * num_boxes * 4]
+                                .chunks(4)
+                                .map(|b| [b[0].min(b[2]), b[1].min(b[3]), b[0].max(b[2]), b[1].max(b[3])])
+                                .collect::<alloc::vec::Vec<_>>();
+                            let area = |[y1, x1, y2, x2]: [f32; 4]| (y2 - y1) * (x2 - x1);
+
+                            for class in 0..num_classes {
+                                let offset = (batch * num_classes + class) * num_boxes;
+                                let scores = &scores[offset..offset + num_boxes];
+                                let mut candidates = (0..num_boxes).collect::<alloc::vec::Vec<_>>();
+                                candidates.sort_by(|&a, &b| scores[b].total_cmp(&scores[a]));
+
+                                let mut kept = alloc::vec::Vec::<usize>::new();
+                                for i in candidates {
+                                    if kept.len() == 2 {
+                                        break;
+                                    }
+                                    let [y1, x1, y2, x2] = corners[i];
+                                    let suppressed = kept.iter().any(|&j| {
+                                        let [other_y1, other_x1, other_y2, other_x2] = corners[j];
+                                        let height = (y2.min(other_y2) - y1.max(other_y1)).max(0.0);
+                                        let width = (x2.min(other_x2) - x1.max(other_x1)).max(0.0);
+                                        let intersection = height * width;
+                                        let union = area(corners[i]) + area(corners[j]) - intersection;
+                                        union > 0.0 && intersection / union > 0.5
+                                    });
+                                    if !suppressed {
+                                        kept.push(i);
+                                    }
+                                }
+
+                                selected.extend(
+                                    kept.into_iter()
+                                        .flat_map(|i| [batch as i64, class as i64, i as i64]),
+                                );
+                            }
+                        }
+
+                        let count = selected.len() / 3;
+                        Tensor::<B, 2, Int>::from_data(TensorData::new(selected, [count, 3]), &*self.device)
+                    };
+
+                    selected
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
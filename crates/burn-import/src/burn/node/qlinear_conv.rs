@@ -0,0 +1,172 @@
+use super::{
+    Node, NodeCodegen,
+    conv2d::Conv2dNode,
+    quantization::{dequantize, quantize},
+};
+use crate::burn::{BurnImports, Scope, TensorType, Type};
+use burn::record::PrecisionSettings;
+use onnx_ir::node::quantization::QLinearConvConfig;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Maps an ONNX QLinearConv to a [burn `Conv2d`](burn::nn::conv::Conv2d).
+///
+/// The input is dequantized before the convolution, and the output is quantized again. The
+/// weights are kept quantized when they are symmetric int8 values quantized per tensor or per
+/// output channel.
+#[derive(Debug, Clone)]
+pub struct QLinearConvNode {
+    pub conv: Conv2dNode,
+    pub config: QLinearConvConfig,
+}
+
+impl QLinearConvNode {
+    pub fn new(conv: Conv2dNode, config: QLinearConvConfig) -> Self {
+        Self { conv, config }
+    }
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for QLinearConvNode {
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.conv.input.clone())]
+    }
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.conv.output.clone())]
+    }
+
+    fn field_type(&self) -> Option<Type> {
+        NodeCodegen::<PS>::field_type(&self.conv)
+    }
+
+    fn field_init(&self) -> Option<TokenStream> {
+        NodeCodegen::<PS>::field_init(&self.conv)
+    }
+
+    fn field_serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        NodeCodegen::<PS>::field_serialize(&self.conv, serializer)
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let TensorType { name, .. } = &self.conv.input;
+        let input = scope.tensor_use_owned(&self.conv.input, node_position);
+        let output = &self.conv.output.name;
+        let field = &self.conv.field.name;
+
+        let input = dequantize(input, &self.config.x, 4);
+        let quantized = quantize(quote! { #output }, &self.config.y, 4);
+
+        quote! {
+            let #output = {
+                let #name = #input;
+                let #output = self.#field.forward(#name);
+                #quantized
+            };
+        }
+    }
+
+    fn register_imports(&self, imports: &mut BurnImports) {
+        NodeCodegen::<PS>::register_imports(&self.conv, imports)
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::QLinearConv(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn::{graph::BurnGraph, node::test::assert_tokens};
+    use burn::{record::FullPrecisionSettings, tensor::TensorData};
+    use onnx_ir::{
+        ElementType,
+        node::{conv2d::Conv2dConfig, padding::PaddingConfig2d, quantization::QuantizationParams},
+    };
+
+    fn params(scale: Vec<f32>, zero_point: Vec<i32>, elem_type: ElementType) -> QuantizationParams {
+        QuantizationParams {
+            scale,
+            zero_point,
+            axis: 0,
+            elem_type,
+        }
+    }
+
+    #[test]
+    fn test_codegen() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+        let config = Conv2dConfig::new(
+            [3, 2],
+            [3, 3],
+            [1, 1],
+            PaddingConfig2d::Valid,
+            [1, 1],
+            1,
+            false,
+        );
+
+        graph.register(QLinearConvNode::new(
+            Conv2dNode::new(
+                "qlinearconv1",
+                TensorType::new_int("x", 4),
+                TensorType::new_int("y", 4),
+                TensorData::from([2f32]),
+                None,
+                config.clone(),
+            ),
+            QLinearConvConfig {
+                conv: config,
+                x: params(vec![0.5], vec![0], ElementType::Int8),
+                w: params(vec![0.1, 0.2], vec![0, 0], ElementType::Int8),
+                y: params(vec![0.25], vec![0], ElementType::Int8),
+            },
+        ));
+
+        graph.register_input_output(vec!["x".to_string()], vec!["y".to_string()]);
+
+        let expected = quote! {
+            use burn::prelude::*;
+            use burn::nn::PaddingConfig2d;
+            use burn::nn::conv::Conv2d;
+            use burn::nn::conv::Conv2dConfig;
+
+            #[derive(Module, Debug)]
+            pub struct Model <B: Backend> {
+                qlinearconv1: Conv2d<B>,
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    let qlinearconv1 = Conv2dConfig::new([3, 2], [3, 3])
+                        .with_stride([1, 1])
+                        .with_padding(PaddingConfig2d::Valid)
+                        .with_dilation([1, 1])
+                        .with_groups(1)
+                        .with_bias(false)
+                        .init(device);
+
+                    Self {
+                        qlinearconv1,
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, x: Tensor<B, 4, Int>) -> Tensor<B, 4, Int> {
+                    let y = {
+                        let x = x.float().mul_scalar(0.5);
+                        let y = self.qlinearconv1.forward(x);
+                        y.div_scalar(0.25).round().clamp(-128, 127).int()
+                    };
+
+                    y
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -0,0 +1,156 @@
+use super::{
+    Node, NodeCodegen,
+    linear::LinearNode,
+    quantization::{dequantize, quantize},
+};
+use crate::burn::{BurnImports, Scope, TensorType, Type};
+use burn::record::PrecisionSettings;
+use onnx_ir::node::quantization::QLinearMatMulConfig;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Maps an ONNX QLinearMatMul with constant weights to a [burn `Linear`](burn::nn::Linear).
+///
+/// The input is dequantized before the linear layer, and the output is quantized again. The
+/// weights are kept quantized when they are symmetric int8 values quantized per tensor.
+#[derive(Debug, Clone)]
+pub struct QLinearMatMulNode {
+    pub linear: LinearNode,
+    pub config: QLinearMatMulConfig,
+}
+
+impl QLinearMatMulNode {
+    pub fn new(linear: LinearNode, config: QLinearMatMulConfig) -> Self {
+        Self { linear, config }
+    }
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for QLinearMatMulNode {
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.linear.input.clone())]
+    }
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.linear.output.clone())]
+    }
+
+    fn field_type(&self) -> Option<Type> {
+        NodeCodegen::<PS>::field_type(&self.linear)
+    }
+
+    fn field_init(&self) -> Option<TokenStream> {
+        NodeCodegen::<PS>::field_init(&self.linear)
+    }
+
+    fn field_serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        NodeCodegen::<PS>::field_serialize(&self.linear, serializer)
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let TensorType { name, rank, .. } = &self.linear.input;
+        let input = scope.tensor_use_owned(&self.linear.input, node_position);
+        let output = &self.linear.output.name;
+        let field = &self.linear.field.name;
+
+        let input = dequantize(input, &self.config.a, *rank);
+        let quantized = quantize(quote! { #output }, &self.config.y, self.linear.output.rank);
+
+        quote! {
+            let #output = {
+                let #name = #input;
+                let #output = self.#field.forward(#name);
+                #quantized
+            };
+        }
+    }
+
+    fn register_imports(&self, imports: &mut BurnImports) {
+        NodeCodegen::<PS>::register_imports(&self.linear, imports)
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::QLinearMatMul(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn::{graph::BurnGraph, node::test::assert_tokens};
+    use burn::{record::FullPrecisionSettings, tensor::TensorData};
+    use onnx_ir::{
+        ElementType,
+        node::{linear::LinearConfig, quantization::QuantizationParams},
+    };
+
+    fn params(scale: f32, zero_point: i32) -> QuantizationParams {
+        QuantizationParams {
+            scale: vec![scale],
+            zero_point: vec![zero_point],
+            axis: 0,
+            elem_type: ElementType::Uint8,
+        }
+    }
+
+    #[test]
+    fn test_codegen() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(QLinearMatMulNode::new(
+            LinearNode::new(
+                "qlinearmatmul1",
+                TensorType::new_int("a", 2),
+                TensorType::new_int("y", 2),
+                TensorData::from([[2f32]]),
+                None,
+                LinearConfig::new(4, 3).with_bias(false),
+            ),
+            QLinearMatMulConfig {
+                a: params(0.5, 128),
+                b: params(0.1, 0),
+                y: params(0.25, 10),
+            },
+        ));
+
+        graph.register_input_output(vec!["a".to_string()], vec!["y".to_string()]);
+
+        let expected = quote! {
+            use burn::prelude::*;
+            use burn::nn::Linear;
+            use burn::nn::LinearConfig;
+
+            #[derive(Module, Debug)]
+            pub struct Model <B: Backend> {
+                qlinearmatmul1: Linear<B>,
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    let qlinearmatmul1 = LinearConfig::new(4, 3)
+                        .with_bias(false)
+                        .init(device);
+
+                    Self {
+                        qlinearmatmul1,
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, a: Tensor<B, 2, Int>) -> Tensor<B, 2, Int> {
+                    let y = {
+                        let a = a.float().sub_scalar(128).mul_scalar(0.5);
+                        let y = self.qlinearmatmul1.forward(a);
+                        y.div_scalar(0.25).round().add_scalar(10).clamp(0, 255).int()
+                    };
+
+                    y
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -0,0 +1,82 @@
+use crate::burn::ToTokens;
+use onnx_ir::{ElementType, node::quantization::QuantizationParams};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// The scale and zero point of a per-axis quantization, as float tensors that broadcast along the
+/// quantization axis of a tensor of the given rank.
+fn per_axis_params(params: &QuantizationParams, rank: usize) -> (TokenStream, TokenStream) {
+    let mut shape = vec![1; rank];
+    shape[params.axis] = params.scale.len();
+    let shape = shape.to_tokens();
+    let scale = params.scale.to_tokens();
+    let zero_point = params
+        .zero_point
+        .iter()
+        .map(|zero_point| *zero_point as f32)
+        .collect::<Vec<_>>()
+        .to_tokens();
+
+    (
+        quote! { Tensor::<B, 1>::from_floats(#scale, &*self.device).reshape(#shape) },
+        quote! { Tensor::<B, 1>::from_floats(#zero_point, &*self.device).reshape(#shape) },
+    )
+}
+
+/// Generate the dequantization of an int tensor of the given rank, `(x - zero_point) * scale`.
+pub(crate) fn dequantize(
+    input: TokenStream,
+    params: &QuantizationParams,
+    rank: usize,
+) -> TokenStream {
+    if !params.is_per_tensor() {
+        let (scale, zero_point) = per_axis_params(params, rank);
+        return quote! { #input.float().sub(#zero_point).mul(#scale) };
+    }
+
+    let mut dequantized = quote! { #input.float() };
+    if params.zero_point[0] != 0 {
+        let zero_point = (params.zero_point[0] as i64).to_tokens();
+        dequantized = quote! { #dequantized.sub_scalar(#zero_point) };
+    }
+    if params.scale[0] != 1.0 {
+        let scale = params.scale[0].to_tokens();
+        dequantized = quote! { #dequantized.mul_scalar(#scale) };
+    }
+
+    dequantized
+}
+
+/// Generate the quantization of a float tensor of the given rank, which rounds `x / scale` to the
+/// nearest even integer and saturates `round(x / scale) + zero_point` to the quantized range.
+pub(crate) fn quantize(
+    input: TokenStream,
+    params: &QuantizationParams,
+    rank: usize,
+) -> TokenStream {
+    let rounded = match params.is_per_tensor() {
+        true => {
+            let scale = params.scale[0].to_tokens();
+            match params.zero_point[0] {
+                0 => quote! { #input.div_scalar(#scale).round() },
+                zero_point => {
+                    let zero_point = (zero_point as i64).to_tokens();
+                    quote! { #input.div_scalar(#scale).round().add_scalar(#zero_point) }
+                }
+            }
+        }
+        false => {
+            let (scale, zero_point) = per_axis_params(params, rank);
+            quote! { #input.div(#scale).round().add(#zero_point) }
+        }
+    };
+
+    // Int32 values use the whole range of the int tensors
+    if params.elem_type == ElementType::Int32 {
+        return quote! { #rounded.int() };
+    }
+
+    let (min, max) = params.range();
+    let (min, max) = ((min as i64).to_tokens(), (max as i64).to_tokens());
+    quote! { #rounded.clamp(#min, #max).int() }
+}
@@ -0,0 +1,97 @@
+use super::{Node, NodeCodegen, quantization::quantize};
+use crate::burn::{Scope, TensorType, Type};
+use burn::record::PrecisionSettings;
+use onnx_ir::node::quantization::QuantizationParams;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Quantizes a float tensor to an int tensor holding the quantized values.
+#[derive(Debug, Clone, new)]
+pub struct QuantizeLinearNode {
+    pub input: TensorType,
+    pub output: TensorType,
+    pub params: QuantizationParams,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for QuantizeLinearNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.input.clone())]
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let output = &self.output.name;
+        let quantized = quantize(input, &self.params, self.input.rank);
+
+        quote! {
+            let #output = #quantized;
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::QuantizeLinear(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{graph::BurnGraph, node::test::assert_tokens};
+    use onnx_ir::ElementType;
+
+    #[test]
+    fn test_codegen_per_axis() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(QuantizeLinearNode::new(
+            TensorType::new_float("tensor1", 2),
+            TensorType::new_int("tensor2", 2),
+            QuantizationParams {
+                scale: vec![0.5, 0.25],
+                zero_point: vec![0, 1],
+                axis: 1,
+                elem_type: ElementType::Int8,
+            },
+        ));
+
+        graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor2".to_string()]);
+
+        let expected = quote! {
+            use burn::prelude::*;
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model<B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 2>) -> Tensor<B, 2, Int> {
+                    let tensor2 = tensor1
+                        .div(Tensor::<B, 1>::from_floats([0.5, 0.25,], &*self.device).reshape([1, 2,]))
+                        .round()
+                        .add(Tensor::<B, 1>::from_floats([0.0, 1.0,], &*self.device).reshape([1, 2,]))
+                        .clamp(-128, 127)
+                        .int();
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -0,0 +1,344 @@
+use super::{SerializationBackend, subgraph::tuple};
+use crate::burn::{Scope, TensorType, ToTokens, Type};
+use burn::{
+    module::{Param, ParamId},
+    nn::{GateControllerRecord, LinearRecord},
+    record::PrecisionSettings,
+    tensor::{Tensor, TensorData},
+};
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+
+/// The lengths of the sequences in a batch, which are either constant or provided at runtime.
+#[derive(Debug, Clone)]
+pub enum SequenceLens {
+    Static(Vec<usize>),
+    Runtime(TensorType),
+}
+
+/// The weights of a recurrent operator in the ONNX layout.
+///
+/// The gates are stacked along the second dimension of the weights, while the bias holds the input
+/// biases of all the gates followed by their hidden biases.
+#[derive(Debug, Clone, new)]
+pub struct RnnWeights {
+    /// Input weights of shape `[num_directions, num_gates * hidden_size, input_size]`.
+    pub input: TensorData,
+    /// Hidden weights of shape `[num_directions, num_gates * hidden_size, hidden_size]`.
+    pub hidden: TensorData,
+    /// Bias of shape `[num_directions, 2 * num_gates * hidden_size]`.
+    pub bias: Option<TensorData>,
+}
+
+impl RnnWeights {
+    /// The record of the gate at the given position in the ONNX layout.
+    pub(crate) fn gate_record<PS: PrecisionSettings>(
+        &self,
+        direction: usize,
+        gate: usize,
+        num_gates: usize,
+    ) -> GateControllerRecord<SerializationBackend> {
+        let device = Default::default();
+        let weight = |data: &TensorData| {
+            let tensor = Tensor::<SerializationBackend, 3>::from_data(
+                data.clone().convert::<PS::FloatElem>(),
+                &device,
+            );
+            let d_hidden = tensor.dims()[1] / num_gates;
+            // The linear layers of burn use the `[d_input, d_output]` layout.
+            let weight = tensor
+                .slice([
+                    direction..direction + 1,
+                    gate * d_hidden..(gate + 1) * d_hidden,
+                ])
+                .squeeze::<2>(0)
+                .transpose();
+
+            Param::initialized(ParamId::new(), weight)
+        };
+        let bias = |offset: usize| {
+            self.bias.as_ref().map(|data| {
+                let tensor = Tensor::<SerializationBackend, 2>::from_data(
+                    data.clone().convert::<PS::FloatElem>(),
+                    &device,
+                );
+                let d_hidden = tensor.dims()[1] / (2 * num_gates);
+                let start = (offset * num_gates + gate) * d_hidden;
+                let bias = tensor
+                    .slice([direction..direction + 1, start..start + d_hidden])
+                    .squeeze::<1>(0);
+
+                Param::initialized(ParamId::new(), bias)
+            })
+        };
+
+        GateControllerRecord {
+            input_transform: LinearRecord {
+                weight: weight(&self.input),
+                bias: bias(0),
+            },
+            hidden_transform: LinearRecord {
+                weight: weight(&self.hidden),
+                bias: bias(1),
+            },
+        }
+    }
+}
+
+/// The inputs and outputs of a recurrent operator.
+#[derive(Debug, Clone, new)]
+pub struct RnnIO {
+    pub input: TensorType,
+    pub sequence_lens: Option<SequenceLens>,
+    pub initial_h: Option<TensorType>,
+    /// Only used by LSTM.
+    pub initial_c: Option<TensorType>,
+    /// `Y`, `Y_h` and `Y_c`, up to the last output of the node.
+    pub outputs: Vec<TensorType>,
+}
+
+impl RnnIO {
+    pub(crate) fn input_types(&self) -> Vec<Type> {
+        let mut inputs = vec![Type::Tensor(self.input.clone())];
+        if let Some(SequenceLens::Runtime(sequence_lens)) = &self.sequence_lens {
+            inputs.push(Type::Tensor(sequence_lens.clone()));
+        }
+        inputs.extend(self.initial_h.iter().cloned().map(Type::Tensor));
+        inputs.extend(self.initial_c.iter().cloned().map(Type::Tensor));
+        inputs
+    }
+
+    pub(crate) fn output_types(&self) -> Vec<Type> {
+        self.outputs.iter().cloned().map(Type::Tensor).collect()
+    }
+
+    /// Whether the initial states are provided.
+    pub(crate) fn has_state(&self) -> bool {
+        self.initial_h.is_some() || self.initial_c.is_some()
+    }
+
+    /// Whether the final hidden state is returned.
+    pub(crate) fn has_hidden_output(&self) -> bool {
+        self.outputs.len() > 1
+    }
+
+    /// Whether the final cell state is returned.
+    pub(crate) fn has_cell_output(&self) -> bool {
+        self.outputs.len() > 2
+    }
+
+    /// Generate the forward pass of a recurrent operator.
+    ///
+    /// The inputs are converted to the batch first layout of the burn modules, with the states
+    /// of shape `[batch_size, hidden_size]`, or `[2, batch_size, hidden_size]` when bidirectional.
+    /// The `run` tokens consume `input`, `initial_h` and `initial_c`, and define `output` of shape
+    /// `[batch_size, seq_length, num_directions * hidden_size]` as well as the final `hidden` and
+    /// `cell` states when they are returned.
+    ///
+    /// With `sequence_lens`, every sequence of the batch is processed separately on its valid
+    /// steps, and the outputs are padded with zeros.
+    pub(crate) fn forward(
+        &self,
+        scope: &mut Scope,
+        node_position: usize,
+        layout: RnnLayout,
+        run: TokenStream,
+    ) -> TokenStream {
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let sequence_lens = self.sequence_lens.as_ref().map(|lens| match lens {
+            SequenceLens::Static(lens) => {
+                let lens = lens.iter().map(|len| len.to_tokens());
+                quote! { [#(#lens),*].into_iter() }
+            }
+            SequenceLens::Runtime(tensor) => {
+                let lens = scope.tensor_use_owned(tensor, node_position);
+                quote! {
+                    #lens
+                        .into_data()
+                        .convert::<i64>()
+                        .into_vec::<i64>()
+                        .unwrap()
+                        .into_iter()
+                        .map(|length| length as usize)
+                }
+            }
+        });
+        let initial_h = self
+            .initial_h
+            .as_ref()
+            .map(|state| layout.state_input(scope.tensor_use_owned(state, node_position)));
+        let initial_c = self
+            .initial_c
+            .as_ref()
+            .map(|state| layout.state_input(scope.tensor_use_owned(state, node_position)));
+
+        // A missing initial state is zero when the other one is provided.
+        let states = match (initial_h, initial_c) {
+            (Some(initial_h), Some(initial_c)) => quote! {
+                let initial_h = #initial_h;
+                let initial_c = #initial_c;
+            },
+            (Some(initial_h), None) if layout.cell => quote! {
+                let initial_h = #initial_h;
+                let initial_c = initial_h.zeros_like();
+            },
+            (Some(initial_h), None) => quote! {
+                let initial_h = #initial_h;
+            },
+            (None, Some(initial_c)) => quote! {
+                let initial_c = #initial_c;
+                let initial_h = initial_c.zeros_like();
+            },
+            (None, None) => quote! {},
+        };
+        let input = match layout.batch_first {
+            true => input,
+            false => quote! { #input.swap_dims(0, 1) },
+        };
+
+        let mut results = vec![layout.output(quote! { output })];
+        if self.has_hidden_output() {
+            results.push(layout.state_output(quote! { hidden }));
+        }
+        if self.has_cell_output() {
+            results.push(layout.state_output(quote! { cell }));
+        }
+        let results = tuple(&results);
+        let outputs = self
+            .outputs
+            .iter()
+            .map(|output| {
+                let name = &output.name;
+                quote! { #name }
+            })
+            .collect::<Vec<_>>();
+        let outputs = tuple(&outputs);
+
+        let Some(sequence_lens) = sequence_lens else {
+            // The input may already be named `input`.
+            let input = match input.to_string() == "input" {
+                true => quote! {},
+                false => quote! {
+                    let input = #input;
+                },
+            };
+
+            return quote! {
+                let #outputs = {
+                    #states
+                    #input
+                    #run
+                    #results
+                };
+            };
+        };
+
+        let state_dim = layout.state_batch_dim().to_tokens();
+        let mut state_slices = quote! {};
+        let mut initial_states = vec![];
+        if self.has_state() {
+            initial_states.push(format_ident!("initial_h"));
+        }
+        if self.has_state() && layout.cell {
+            initial_states.push(format_ident!("initial_c"));
+        }
+        for state in initial_states {
+            state_slices.extend(quote! {
+                let #state = #state.clone().narrow(#state_dim, batch, 1);
+            });
+        }
+
+        let mut final_states = vec![];
+        if self.has_hidden_output() {
+            final_states.push(format_ident!("hidden"));
+        }
+        if self.has_cell_output() {
+            final_states.push(format_ident!("cell"));
+        }
+        let state_lists = final_states
+            .iter()
+            .map(|state| format_ident!("{}s", state))
+            .collect::<Vec<_>>();
+
+        quote! {
+            let #outputs = {
+                #states
+                let sequence = #input;
+                let seq_length = sequence.dims()[1];
+                let mut outputs = [].to_vec();
+                #(let mut #state_lists = [].to_vec();)*
+                for (batch, length) in #sequence_lens.enumerate() {
+                    let input = sequence.clone().narrow(0, batch, 1).narrow(1, 0, length);
+                    #state_slices
+                    #run
+                    outputs.push(output.pad((0, 0, 0, seq_length - length), 0.0));
+                    #(#state_lists.push(#final_states);)*
+                }
+                let output = Tensor::cat(outputs, 0);
+                #(let #final_states = Tensor::cat(#state_lists, #state_dim);)*
+                #results
+            };
+        }
+    }
+}
+
+/// How the tensors of the ONNX operator map to the tensors of the burn modules.
+#[derive(Debug, Clone, Copy, new)]
+pub struct RnnLayout {
+    pub batch_first: bool,
+    pub bidirectional: bool,
+    pub hidden_size: usize,
+    /// Whether the operator has a cell state (LSTM).
+    pub cell: bool,
+}
+
+impl RnnLayout {
+    /// The batch dimension of the states of the burn modules.
+    fn state_batch_dim(&self) -> usize {
+        match self.bidirectional {
+            true => 1,
+            false => 0,
+        }
+    }
+
+    /// Convert an ONNX initial state to the state of the burn modules.
+    fn state_input(&self, state: TokenStream) -> TokenStream {
+        match (self.bidirectional, self.batch_first) {
+            (false, false) => quote! { #state.squeeze::<2>(0) },
+            (false, true) => quote! { #state.squeeze::<2>(1) },
+            (true, false) => state,
+            (true, true) => quote! { #state.swap_dims(0, 1) },
+        }
+    }
+
+    /// Convert a final state of the burn modules to the ONNX layout.
+    fn state_output(&self, state: TokenStream) -> TokenStream {
+        match (self.bidirectional, self.batch_first) {
+            (false, false) => quote! { #state.unsqueeze_dim::<3>(0) },
+            (false, true) => quote! { #state.unsqueeze_dim::<3>(1) },
+            (true, false) => state,
+            (true, true) => quote! { #state.swap_dims(0, 1) },
+        }
+    }
+
+    /// Convert the output of the burn modules to the ONNX layout, where the directions have their
+    /// own dimension.
+    fn output(&self, output: TokenStream) -> TokenStream {
+        let hidden_size = self.hidden_size.to_tokens();
+        match (self.bidirectional, self.batch_first) {
+            (false, false) => quote! { #output.swap_dims(0, 1).unsqueeze_dim::<4>(1) },
+            (false, true) => quote! { #output.unsqueeze_dim::<4>(2) },
+            (true, false) => quote! {
+                #output.reshape([0, 0, 2, #hidden_size]).permute([1, 2, 0, 3])
+            },
+            (true, true) => quote! { #output.reshape([0, 0, 2, #hidden_size]) },
+        }
+    }
+}
+
+/// The hidden state at the last step of an output of shape `[batch_size, seq_length, hidden_size]`.
+pub(crate) fn last_step(output: &Ident) -> TokenStream {
+    quote! {
+        #output.clone().narrow(1, #output.dims()[1] - 1, 1).squeeze::<2>(1)
+    }
+}
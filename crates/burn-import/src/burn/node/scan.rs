@@ -0,0 +1,274 @@
+use super::{
+    Node, NodeCodegen,
+    loop_op::update,
+    subgraph::{SubGraph, release_captured, tuple, use_value},
+};
+use crate::burn::{BurnImports, Scope, ToTokens, Type};
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+/// Executes a subgraph on the slices of the scan inputs, with a Rust `for` loop.
+///
+/// The scan outputs are stacked along their axis, so the scan inputs must not be empty when there
+/// are scan outputs.
+#[derive(Debug, Clone, new)]
+pub struct ScanNode<PS: PrecisionSettings> {
+    pub initial_states: Vec<Type>,
+    pub scan_inputs: Vec<Type>,
+    pub outputs: Vec<Type>,
+    /// The inputs are the states and the slices of the scan inputs. The outputs are the states and
+    /// the slices of the scan outputs.
+    pub body: SubGraph<PS>,
+    pub scan_input_axes: Vec<usize>,
+    pub scan_input_reverse: Vec<bool>,
+    pub scan_output_axes: Vec<usize>,
+    pub scan_output_reverse: Vec<bool>,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for ScanNode<PS> {
+    fn output_types(&self) -> Vec<Type> {
+        self.outputs.clone()
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        self.initial_states
+            .iter()
+            .chain(self.scan_inputs.iter())
+            .chain(self.body.captured.iter())
+            .cloned()
+            .collect()
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let initial_states = self
+            .initial_states
+            .iter()
+            .map(|ty| use_value(ty, scope, node_position))
+            .collect::<Vec<_>>();
+        let scan_inputs = self
+            .scan_inputs
+            .iter()
+            .map(|ty| use_value(ty, scope, node_position))
+            .collect::<Vec<_>>();
+        release_captured(&self.body.captured, scope, node_position);
+
+        let num_states = self.initial_states.len();
+        let states = self.body.inputs[..num_states]
+            .iter()
+            .map(|ty| ty.name().clone())
+            .collect::<Vec<_>>();
+        let slices = &self.body.inputs[num_states..];
+        let sequences = slices
+            .iter()
+            .map(|ty| format_ident!("{}_sequence", ty.name()))
+            .collect::<Vec<_>>();
+        let scans = self.outputs[num_states..]
+            .iter()
+            .map(|ty| format_ident!("{}_scans", ty.name()))
+            .collect::<Vec<_>>();
+
+        let mut init = quote! {
+            #(let mut #states = #initial_states;)*
+            #(let #sequences = #scan_inputs;)*
+        };
+        for scan in scans.iter() {
+            init.extend(quote! {
+                let mut #scan = [].to_vec();
+            });
+        }
+        let length = {
+            let sequence = &sequences[0];
+            let axis = self.scan_input_axes[0].to_tokens();
+            quote! { #sequence.dims()[#axis] }
+        };
+
+        let mut bindings = quote! {};
+        for (index, (slice, sequence)) in slices.iter().zip(sequences.iter()).enumerate() {
+            let name = slice.name();
+            let axis = self.scan_input_axes[index].to_tokens();
+            let rank = slice.as_tensor().rank.to_tokens();
+            let position = match self.scan_input_reverse[index] {
+                true => quote! { scan_length - 1 - scan_index },
+                false => quote! { scan_index },
+            };
+            bindings.extend(quote! {
+                let #name = #sequence.clone().narrow(#axis, #position, 1).squeeze::<#rank>(#axis);
+            });
+        }
+
+        let (body, body_outputs) = self.body.forward();
+        let update = update(
+            &states,
+            &body_outputs[..num_states],
+            &self.body.inputs,
+            &scans,
+            &body_outputs[num_states..],
+        );
+
+        let results = states
+            .iter()
+            .map(|name| quote! { #name })
+            .chain(
+                self.outputs[num_states..]
+                    .iter()
+                    .zip(scans.iter())
+                    .enumerate()
+                    .map(|(index, (output, scan))| {
+                        let rank = output.as_tensor().rank.to_tokens();
+                        let axis = self.scan_output_axes[index].to_tokens();
+                        let stack = quote! { Tensor::stack::<#rank>(#scan, #axis) };
+                        match self.scan_output_reverse[index] {
+                            true => quote! {
+                                {
+                                    #scan.reverse();
+                                    #stack
+                                }
+                            },
+                            false => stack,
+                        }
+                    }),
+            )
+            .collect::<Vec<_>>();
+        let results = tuple(&results);
+        let outputs = self
+            .outputs
+            .iter()
+            .map(|output| {
+                let name = output.name();
+                quote! { #name }
+            })
+            .collect::<Vec<_>>();
+        let outputs = tuple(&outputs);
+
+        quote! {
+            let #outputs = {
+                #init
+                let scan_length = #length;
+                for scan_index in 0..scan_length {
+                    #bindings
+                    #body
+                    #update
+                }
+                #results
+            };
+        }
+    }
+
+    fn register_imports(&self, imports: &mut BurnImports) {
+        self.body.register_imports(imports);
+    }
+
+    fn nested_nodes(&self) -> Vec<&Node<PS>> {
+        self.body.nodes.iter().collect()
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Scan(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        TensorType,
+        graph::BurnGraph,
+        node::{binary::BinaryNode, test::assert_tokens},
+    };
+
+    #[test]
+    fn test_codegen_scan() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        // Cumulative sum of the rows, in reverse order.
+        let body = SubGraph::new(
+            vec![
+                BinaryNode::add(
+                    Type::Tensor(TensorType::new_float("sum", 1)),
+                    Type::Tensor(TensorType::new_float("row", 1)),
+                    Type::Tensor(TensorType::new_float("add1_out1", 1)),
+                )
+                .into_node(),
+            ],
+            vec![
+                Type::Tensor(TensorType::new_float("sum", 1)),
+                Type::Tensor(TensorType::new_float("row", 1)),
+            ],
+            vec![
+                Type::Tensor(TensorType::new_float("add1_out1", 1)),
+                Type::Tensor(TensorType::new_float("add1_out1", 1)),
+            ],
+            vec![],
+        );
+
+        graph.register(ScanNode::new(
+            vec![Type::Tensor(TensorType::new_float("tensor1", 1))],
+            vec![Type::Tensor(TensorType::new_float("tensor2", 2))],
+            vec![
+                Type::Tensor(TensorType::new_float("output1", 1)),
+                Type::Tensor(TensorType::new_float("output2", 2)),
+            ],
+            body,
+            vec![0],
+            vec![true],
+            vec![0],
+            vec![false],
+        ));
+
+        graph.register_input_output(
+            vec!["tensor1".to_string(), "tensor2".to_string()],
+            vec!["output1".to_string(), "output2".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::prelude::*;
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(
+                    &self,
+                    tensor1: Tensor<B, 1>,
+                    tensor2: Tensor<B, 2>
+                ) -> (Tensor<B, 1>, Tensor<B, 2>) {
+                    let (output1, output2) = {
+                        let mut sum = tensor1;
+                        let row_sequence = tensor2;
+                        let mut output2_scans = [].to_vec();
+                        let scan_length = row_sequence.dims()[0];
+                        for scan_index in 0..scan_length {
+                            let row = row_sequence
+                                .clone()
+                                .narrow(0, scan_length - 1 - scan_index, 1)
+                                .squeeze::<1>(0);
+                            let add1_out1 = sum.add(row);
+                            let (sum_next, output2_scans_next) = (add1_out1.clone(), add1_out1);
+                            sum = sum_next;
+                            output2_scans.push(output2_scans_next);
+                        }
+                        (sum, Tensor::stack::<2>(output2_scans, 0))
+                    };
+
+                    (output1, output2)
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
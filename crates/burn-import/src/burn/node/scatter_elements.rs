@@ -0,0 +1,127 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{Scope, TensorType, ToTokens, Type};
+use burn::record::PrecisionSettings;
+use onnx_ir::node::scatter::{ScatterElementsConfig, ScatterReduction};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+#[derive(Debug, Clone, new)]
+pub struct ScatterElementsNode {
+    pub data: TensorType,
+    pub indices: TensorType,
+    pub updates: TensorType,
+    pub output: TensorType,
+    pub config: ScatterElementsConfig,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for ScatterElementsNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        vec![
+            Type::Tensor(self.data.clone()),
+            Type::Tensor(self.indices.clone()),
+            Type::Tensor(self.updates.clone()),
+        ]
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let data = scope.tensor_use_owned(&self.data, node_position);
+        let indices = scope.tensor_use_owned(&self.indices, node_position);
+        let updates = scope.tensor_use_owned(&self.updates, node_position);
+        let output = &self.output.name;
+        let axis = self.config.axis.to_tokens();
+
+        match self.config.reduction {
+            ScatterReduction::Add => quote! {
+                let #output = #data.scatter(#axis, #indices, #updates);
+            },
+            // The scatter of burn adds the updates, so the existing values are subtracted first
+            ScatterReduction::None => quote! {
+                let #output = {
+                    let values = #data;
+                    let index = #indices;
+                    values
+                        .clone()
+                        .scatter(#axis, index.clone(), #updates.sub(values.gather(#axis, index)))
+                };
+            },
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::ScatterElements(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn::{graph::BurnGraph, node::test::assert_tokens};
+    use burn::record::FullPrecisionSettings;
+
+    #[test]
+    fn test_codegen_scatter_elements() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(ScatterElementsNode::new(
+            TensorType::new_float("data", 2),
+            TensorType::new_int("indices", 2),
+            TensorType::new_float("updates", 2),
+            TensorType::new_float("output", 2),
+            ScatterElementsConfig {
+                axis: 1,
+                reduction: ScatterReduction::None,
+            },
+        ));
+        graph.register_input_output(
+            vec![
+                "data".to_string(),
+                "indices".to_string(),
+                "updates".to_string(),
+            ],
+            vec!["output".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::prelude::*;
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(
+                    &self,
+                    data: Tensor<B, 2>,
+                    indices: Tensor<B, 2, Int>,
+                    updates: Tensor<B, 2>
+                ) -> Tensor<B, 2> {
+                    let output = {
+                        let values = data;
+                        let index = indices;
+                        values
+                            .clone()
+                            .scatter(1, index.clone(), updates.sub(values.gather(1, index)))
+                    };
+
+                    output
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
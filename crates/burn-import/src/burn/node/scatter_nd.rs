@@ -0,0 +1,166 @@
+use super::{
+    Node, NodeCodegen,
+    gather_nd::{dims_product, flat_index},
+};
+use crate::burn::{Scope, TensorType, Type};
+use burn::record::PrecisionSettings;
+use onnx_ir::node::scatter::{ScatterNDConfig, ScatterReduction};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+#[derive(Debug, Clone, new)]
+pub struct ScatterNDNode {
+    pub data: TensorType,
+    pub indices: TensorType,
+    pub updates: TensorType,
+    pub output: TensorType,
+    pub config: ScatterNDConfig,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for ScatterNDNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        vec![
+            Type::Tensor(self.data.clone()),
+            Type::Tensor(self.indices.clone()),
+            Type::Tensor(self.updates.clone()),
+        ]
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let data = scope.tensor_use_owned(&self.data, node_position);
+        let indices = scope.tensor_use_owned(&self.indices, node_position);
+        let updates = scope.tensor_use_owned(&self.updates, node_position);
+        let output = &self.output.name;
+
+        let data_rank = self.data.rank;
+        let indices_rank = self.indices.rank;
+        let depth = data_rank + indices_rank - 1 - self.updates.rank;
+
+        let index_dims = match indices_rank {
+            1 => quote! {},
+            _ => quote! { let index_dims = index.dims(); },
+        };
+        let count = dims_product("index_dims", 0..indices_rank - 1);
+        let index = flat_index(0, depth);
+        let rows = dims_product("dims", 0..depth);
+        let inner = dims_product("dims", depth..data_rank);
+
+        // Every update is a row of the data flattened to the indexed dimensions. The select_assign
+        // of burn adds the updates, so the existing rows are subtracted first.
+        let scatter = match self.config.reduction {
+            ScatterReduction::Add => quote! {
+                values.select_assign(0, index, updates)
+            },
+            ScatterReduction::None => quote! {
+                values
+                    .clone()
+                    .select_assign(0, index.clone(), updates.sub(values.select(0, index)))
+            },
+        };
+
+        quote! {
+            let #output = {
+                let values = #data;
+                let dims = values.dims();
+                let index = #indices;
+                #index_dims
+                let count = #count;
+                let index = #index;
+                let values = values.reshape([#rows, #inner]);
+                let updates = #updates.reshape([count, #inner]);
+                #scatter.reshape(dims)
+            };
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::ScatterND(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn::{graph::BurnGraph, node::test::assert_tokens};
+    use burn::record::FullPrecisionSettings;
+
+    #[test]
+    fn test_codegen_scatter_nd() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(ScatterNDNode::new(
+            TensorType::new_float("data", 3),
+            TensorType::new_int("indices", 2),
+            TensorType::new_float("updates", 2),
+            TensorType::new_float("output", 3),
+            ScatterNDConfig {
+                reduction: ScatterReduction::None,
+            },
+        ));
+        graph.register_input_output(
+            vec![
+                "data".to_string(),
+                "indices".to_string(),
+                "updates".to_string(),
+            ],
+            vec!["output".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::prelude::*;
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(
+                    &self,
+                    data: Tensor<B, 3>,
+                    indices: Tensor<B, 2, Int>,
+                    updates: Tensor<B, 2>
+                ) -> Tensor<B, 3> {
+                    let output = {
+                        let values = data;
+                        let dims = values.dims();
+                        let index = indices;
+                        let index_dims = index.dims();
+                        let count = index_dims[0];
+                        let index = index
+                            .reshape([count, 2])
+                            .mul(
+                                Tensor::<B, 1, Int>::from_ints([dims[1] as i64, 1], &*self.device)
+                                    .unsqueeze()
+                            )
+                            .sum_dim(1)
+                            .reshape([count]);
+                        let values = values.reshape([dims[0] * dims[1], dims[2]]);
+                        let updates = updates.reshape([count, dims[2]]);
+                        values
+                            .clone()
+                            .select_assign(0, index.clone(), updates.sub(values.select(0, index)))
+                            .reshape(dims)
+                    };
+
+                    output
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
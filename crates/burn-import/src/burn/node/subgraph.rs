@@ -0,0 +1,169 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{BurnImports, Scope, TensorType, Type};
+use burn::record::PrecisionSettings;
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+
+/// A graph executed by a control flow node, e.g. a branch of an [if node](super::if_op::IfNode).
+///
+/// The forward pass of the subgraph is generated inline in the forward pass of the model, so the
+/// nodes can use the variables of the enclosing graph. These captured values are always cloned,
+/// since the subgraph can be executed multiple times.
+#[derive(Debug, Clone)]
+pub struct SubGraph<PS: PrecisionSettings> {
+    pub nodes: Vec<Node<PS>>,
+    /// The values bound by the control flow node before executing the subgraph.
+    pub inputs: Vec<Type>,
+    pub outputs: Vec<Type>,
+    /// The values of the enclosing graphs used by the nodes.
+    pub captured: Vec<Type>,
+}
+
+impl<PS: PrecisionSettings> SubGraph<PS> {
+    pub fn new(
+        nodes: Vec<Node<PS>>,
+        inputs: Vec<Type>,
+        outputs: Vec<Type>,
+        captured: Vec<Type>,
+    ) -> Self {
+        Self {
+            nodes,
+            inputs,
+            outputs,
+            captured,
+        }
+    }
+
+    /// Generate the forward pass of the nodes, and the values of the outputs.
+    pub fn forward(&self) -> (TokenStream, Vec<TokenStream>) {
+        let mut scope = self.build_scope();
+
+        let mut body = quote! {};
+        self.nodes
+            .iter()
+            .enumerate()
+            .map(|(index, node)| node.forward(&mut scope, index))
+            .for_each(|code| body.extend(code));
+
+        let outputs = self
+            .outputs
+            .iter()
+            .map(|output| match output {
+                Type::Tensor(tensor) => scope.tensor_use_owned(tensor, self.nodes.len()),
+                _ => {
+                    let name = output.name();
+                    quote! { #name }
+                }
+            })
+            .collect();
+
+        (body, outputs)
+    }
+
+    /// Register the imports of the nodes.
+    pub fn register_imports(&self, imports: &mut BurnImports) {
+        self.nodes
+            .iter()
+            .for_each(|node| node.register_imports(imports));
+    }
+
+    /// Whether a node or one of the outputs starting at `first_output` uses the variable.
+    pub fn uses(&self, name: &Ident, first_output: usize) -> bool {
+        self.nodes
+            .iter()
+            .flat_map(|node| node.input_types())
+            .chain(self.outputs.iter().skip(first_output).cloned())
+            .any(|ty| ty.name() == name)
+    }
+
+    fn build_scope(&self) -> Scope {
+        let mut scope = Scope::default();
+
+        // The inputs are bound before the nodes, like the graph inputs.
+        self.inputs.iter().flat_map(to_tensor).for_each(|tensor| {
+            scope.tensor_register_variable(tensor, 0);
+        });
+        // The captured values are still owned by the enclosing graph after the last node.
+        self.captured.iter().flat_map(to_tensor).for_each(|tensor| {
+            scope.tensor_register_variable(tensor, 0);
+            scope.tensor_register_future_use(tensor, self.nodes.len() + 1);
+        });
+
+        self.nodes
+            .iter()
+            .enumerate()
+            .for_each(|(node_position, node)| {
+                node.output_types()
+                    .iter()
+                    .flat_map(to_tensor)
+                    .for_each(|tensor| {
+                        scope.tensor_register_variable(tensor, node_position + 1);
+                    });
+                node.input_types()
+                    .iter()
+                    .flat_map(to_tensor)
+                    .for_each(|tensor| scope.tensor_register_future_use(tensor, node_position));
+            });
+
+        self.outputs.iter().flat_map(to_tensor).for_each(|tensor| {
+            scope.tensor_register_future_use(tensor, self.nodes.len());
+        });
+
+        scope
+    }
+}
+
+fn to_tensor(ty: &Type) -> Option<&TensorType> {
+    match ty {
+        Type::Tensor(tensor) => Some(tensor),
+        _ => None,
+    }
+}
+
+/// Use a value of the enclosing graph, moving the tensors when they are not used afterward.
+pub(crate) fn use_value(ty: &Type, scope: &mut Scope, node_position: usize) -> TokenStream {
+    match ty {
+        Type::Tensor(tensor) => scope.tensor_use_owned(tensor, node_position),
+        _ => {
+            let name = ty.name();
+            quote! { #name }
+        }
+    }
+}
+
+/// Use a value of the enclosing graph as a scalar of the given type, e.g. a loop condition.
+///
+/// Single element tensors are read back from the device.
+pub(crate) fn use_scalar(
+    ty: &Type,
+    elem: TokenStream,
+    scope: &mut Scope,
+    node_position: usize,
+) -> TokenStream {
+    match ty {
+        Type::Tensor(tensor) => {
+            let tensor = scope.tensor_use_owned(tensor, node_position);
+            quote! { #tensor.into_scalar().elem::<#elem>() }
+        }
+        Type::Scalar(scalar) => {
+            let name = &scalar.name;
+            quote! { #name }
+        }
+        _ => panic!("Expected a scalar or a single element tensor, got {ty:?}"),
+    }
+}
+
+/// Release the uses of the captured values by the control flow node, which clones them.
+pub(crate) fn release_captured(captured: &[Type], scope: &mut Scope, node_position: usize) {
+    captured.iter().flat_map(to_tensor).for_each(|tensor| {
+        scope.tensor_use_owned(tensor, node_position);
+    });
+}
+
+/// A single value, or a tuple of values.
+pub(crate) fn tuple(values: &[TokenStream]) -> TokenStream {
+    match values {
+        [value] => value.clone(),
+        values => quote! { (#(#values),*) },
+    }
+}
@@ -1,2 +1,5 @@
+mod runtime;
 mod to_burn;
+
+pub use runtime::*;
 pub use to_burn::*;
@@ -0,0 +1,60 @@
+use std::path::PathBuf;
+
+/// Error returned when loading or running an [ONNX model](super::OnnxModel).
+#[derive(thiserror::Error, Debug)]
+pub enum OnnxRuntimeError {
+    /// The ONNX file couldn't be parsed.
+    #[error("Failed to parse the ONNX file {path}: {message}")]
+    Parse {
+        /// The path of the ONNX file.
+        path: PathBuf,
+        /// The reason of the failure.
+        message: String,
+    },
+
+    /// The graph contains nodes that the runtime can't execute.
+    #[error("Unsupported ONNX nodes: {}", .0.join(", "))]
+    UnsupportedNodes(Vec<String>),
+
+    /// A node couldn't be compiled or executed.
+    #[error("Invalid node {node}: {message}")]
+    InvalidNode {
+        /// The name of the node.
+        node: String,
+        /// The reason of the failure.
+        message: String,
+    },
+
+    /// The number of inputs doesn't match the graph inputs.
+    #[error("Expected {expected} inputs, got {actual}")]
+    InputCount {
+        /// The number of graph inputs.
+        expected: usize,
+        /// The number of provided inputs.
+        actual: usize,
+    },
+
+    /// An input doesn't match the type of its graph input.
+    #[error("Invalid input {name}: expected {expected}, got {actual}")]
+    InvalidInput {
+        /// The name of the graph input.
+        name: String,
+        /// The expected value type.
+        expected: String,
+        /// The provided value type.
+        actual: String,
+    },
+
+    /// A value couldn't be converted to the requested type.
+    #[error("Invalid value: expected {expected}, got {actual}")]
+    InvalidValue {
+        /// The requested value type.
+        expected: String,
+        /// The actual value type.
+        actual: String,
+    },
+
+    /// A value wasn't produced by any node of the graph.
+    #[error("Missing value {0}")]
+    MissingValue(String),
+}
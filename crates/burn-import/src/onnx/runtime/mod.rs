@@ -0,0 +1,8 @@
+mod error;
+mod model;
+mod ops;
+mod value;
+
+pub use error::*;
+pub use model::*;
+pub use value::*;
@@ -0,0 +1,427 @@
+use std::{
+    any::Any,
+    collections::HashMap,
+    panic::{AssertUnwindSafe, catch_unwind},
+    path::Path,
+};
+
+use burn::tensor::backend::Backend;
+use onnx_ir::{
+    ir::{Argument, OnnxGraph},
+    try_parse_onnx,
+};
+
+use super::{
+    OnnxRuntimeError, OnnxValue,
+    ops::{Op, compile},
+    value::describe_type,
+};
+
+/// An ONNX model executed at runtime on any backend, without generating code.
+///
+/// The graph parsed by `onnx-ir` is kept in memory and executed node by node, so the model can be
+/// swapped without recompiling the application. The nodes are compiled when the model is loaded,
+/// where the unsupported nodes are all reported at once, and the weights are loaded on the device.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let model = OnnxModel::<Backend>::load("model.onnx", &device)?;
+///
+/// let outputs = model.forward(vec![input.into()])?;
+/// let output: Tensor<Backend, 2> = outputs[0].clone().into_float()?;
+/// ```
+#[derive(Debug)]
+pub struct OnnxModel<B: Backend> {
+    inputs: Vec<Argument>,
+    outputs: Vec<Argument>,
+    nodes: Vec<CompiledNode<B>>,
+    device: B::Device,
+}
+
+#[derive(Debug)]
+struct CompiledNode<B: Backend> {
+    name: String,
+    op: Op<B>,
+    inputs: Vec<NodeInput<B>>,
+    outputs: Vec<Argument>,
+    /// The values used for the last time by the node, which are released after its execution.
+    released: Vec<String>,
+}
+
+#[derive(Debug)]
+enum NodeInput<B: Backend> {
+    /// A value produced by a node or given as graph input.
+    Value(String),
+    /// A constant, e.g. a weight.
+    Constant(OnnxValue<B>),
+    /// A missing optional input.
+    None,
+}
+
+impl<B: Backend> OnnxModel<B> {
+    /// Loads the ONNX file on the device.
+    ///
+    /// The files that can't be read and the graphs that `onnx-ir` fails to convert, e.g. with
+    /// nodes that aren't topologically sorted, are reported as [parse](OnnxRuntimeError::Parse)
+    /// errors.
+    pub fn load<P: AsRef<Path>>(path: P, device: &B::Device) -> Result<Self, OnnxRuntimeError> {
+        let path = path.as_ref();
+        let graph = catch_unwind(|| try_parse_onnx(path))
+            .map_err(panic_message)
+            .and_then(|graph| graph.map_err(|err| err.to_string()))
+            .map_err(|message| OnnxRuntimeError::Parse {
+                path: path.to_path_buf(),
+                message,
+            })?;
+
+        Self::from_graph(graph, device)
+    }
+
+    /// Creates the model from a graph parsed by `onnx-ir`.
+    pub fn from_graph(graph: OnnxGraph, device: &B::Device) -> Result<Self, OnnxRuntimeError> {
+        let mut nodes = Vec::with_capacity(graph.nodes.len());
+        let mut unsupported = Vec::new();
+        let mut invalid = None;
+
+        for node in graph.nodes {
+            let compiled = catch_unwind(AssertUnwindSafe(|| compile::<B>(&node, device)))
+                .map_err(panic_message)
+                .and_then(|op| op);
+            let inputs = node
+                .inputs
+                .iter()
+                .map(|arg| match &arg.value {
+                    _ if arg.name.is_empty() => Ok(NodeInput::None),
+                    Some(data) => OnnxValue::from_data(data.clone(), device)
+                        .map(|value| NodeInput::Constant(value.conform(&arg.ty, device))),
+                    None => Ok(NodeInput::Value(arg.name.clone())),
+                })
+                .collect::<Result<Vec<_>, _>>();
+
+            match (compiled, inputs) {
+                (Ok(Some(op)), Ok(inputs)) => nodes.push(CompiledNode {
+                    name: node.name,
+                    op,
+                    inputs,
+                    outputs: node.outputs,
+                    released: Vec::new(),
+                }),
+                (Ok(None), _) => unsupported.push(format!("{} ({})", node.name, node.node_type)),
+                (Err(message), _) | (_, Err(message)) => {
+                    invalid.get_or_insert(OnnxRuntimeError::InvalidNode {
+                        node: node.name,
+                        message,
+                    });
+                }
+            }
+        }
+
+        if !unsupported.is_empty() {
+            return Err(OnnxRuntimeError::UnsupportedNodes(unsupported));
+        }
+        if let Some(error) = invalid {
+            return Err(error);
+        }
+
+        // Release each intermediate value after its last use.
+        let mut last_uses = HashMap::new();
+        for (index, node) in nodes.iter().enumerate() {
+            for input in node.inputs.iter() {
+                if let NodeInput::Value(name) = input {
+                    last_uses.insert(name.clone(), index);
+                }
+            }
+        }
+        for (name, index) in last_uses {
+            if !graph.outputs.iter().any(|output| output.name == name) {
+                nodes[index].released.push(name);
+            }
+        }
+
+        Ok(Self {
+            inputs: graph.inputs,
+            outputs: graph.outputs,
+            nodes,
+            device: device.clone(),
+        })
+    }
+
+    /// The inputs of the graph, in the order expected by [forward](Self::forward).
+    pub fn inputs(&self) -> &[Argument] {
+        &self.inputs
+    }
+
+    /// The outputs of the graph, in the order returned by [forward](Self::forward).
+    pub fn outputs(&self) -> &[Argument] {
+        &self.outputs
+    }
+
+    /// Executes the graph.
+    ///
+    /// The inputs are checked against the types of the graph inputs, but the shapes of the
+    /// tensors are only checked by the backend operations.
+    pub fn forward(
+        &self,
+        inputs: Vec<OnnxValue<B>>,
+    ) -> Result<Vec<OnnxValue<B>>, OnnxRuntimeError> {
+        if inputs.len() != self.inputs.len() {
+            return Err(OnnxRuntimeError::InputCount {
+                expected: self.inputs.len(),
+                actual: inputs.len(),
+            });
+        }
+
+        let mut values = HashMap::new();
+        for (arg, value) in self.inputs.iter().zip(inputs) {
+            if !value.matches(&arg.ty) {
+                return Err(OnnxRuntimeError::InvalidInput {
+                    name: arg.name.clone(),
+                    expected: describe_type(&arg.ty),
+                    actual: value.kind(),
+                });
+            }
+            values.insert(arg.name.clone(), value);
+        }
+
+        for node in self.nodes.iter() {
+            let inputs = node
+                .inputs
+                .iter()
+                .map(|input| match input {
+                    NodeInput::Value(name) => values
+                        .get(name)
+                        .cloned()
+                        .map(Some)
+                        .ok_or_else(|| OnnxRuntimeError::MissingValue(name.clone())),
+                    NodeInput::Constant(value) => Ok(Some(value.clone())),
+                    NodeInput::None => Ok(None),
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            let outputs = node.op.execute(inputs, &self.device).map_err(|message| {
+                OnnxRuntimeError::InvalidNode {
+                    node: node.name.clone(),
+                    message,
+                }
+            })?;
+
+            for (arg, value) in node.outputs.iter().zip(outputs) {
+                values.insert(arg.name.clone(), value.conform(&arg.ty, &self.device));
+            }
+            for name in node.released.iter() {
+                values.remove(name);
+            }
+        }
+
+        self.outputs
+            .iter()
+            .map(|arg| {
+                values
+                    .get(&arg.name)
+                    .cloned()
+                    .ok_or_else(|| OnnxRuntimeError::MissingValue(arg.name.clone()))
+            })
+            .collect()
+    }
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => match payload.downcast::<&str>() {
+            Ok(message) => message.to_string(),
+            Err(_) => "unknown error".into(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn::tensor::{Int, Tensor, TensorData, Tolerance};
+    use burn_ndarray::NdArray;
+
+    type TestBackend = NdArray<f32>;
+
+    fn model_path(name: &str) -> String {
+        format!(
+            "{}/onnx-tests/tests/{name}/{name}.onnx",
+            env!("CARGO_MANIFEST_DIR")
+        )
+    }
+
+    fn load(name: &str) -> OnnxModel<TestBackend> {
+        OnnxModel::load(model_path(name), &Default::default()).unwrap()
+    }
+
+    #[test]
+    fn should_run_relu() {
+        let device = Default::default();
+        let model = load("relu");
+        let input = Tensor::<TestBackend, 2>::from_floats(
+            [
+                [0.33669037, 0.128_809_4, 0.23446237],
+                [0.23033303, -1.122_856_4, -0.18632829],
+            ],
+            &device,
+        );
+
+        let output = model.forward(vec![input.into()]).unwrap();
+
+        assert_eq!(output.len(), 1);
+        output[0]
+            .clone()
+            .into_float::<2>()
+            .unwrap()
+            .into_data()
+            .assert_eq(
+                &TensorData::from([
+                    [0.33669037f32, 0.128_809_4, 0.23446237],
+                    [0.23033303, 0.0, 0.0],
+                ]),
+                true,
+            );
+    }
+
+    #[test]
+    fn should_run_linear_with_weights() {
+        let device = Default::default();
+        let model = load("linear");
+        let inputs = vec![
+            Tensor::<TestBackend, 2>::full([4, 3], 3.14, &device).into(),
+            Tensor::<TestBackend, 2>::full([2, 5], 3.14, &device).into(),
+            Tensor::<TestBackend, 3>::full([3, 2, 7], 3.14, &device).into(),
+        ];
+
+        let mut outputs = model.forward(inputs).unwrap().into_iter();
+        let output1 = outputs.next().unwrap().into_float::<2>().unwrap();
+        let output2 = outputs.next().unwrap().into_float::<2>().unwrap();
+        let output3 = outputs.next().unwrap().into_float::<3>().unwrap();
+
+        assert_eq!(output1.dims(), [4, 4]);
+        assert_eq!(output2.dims(), [2, 6]);
+        assert_eq!(output3.dims(), [3, 2, 8]);
+        // The sums computed by PyTorch.
+        let sums = Tensor::cat(vec![output1.sum(), output2.sum(), output3.sum()], 0);
+        sums.into_data().assert_approx_eq::<f32>(
+            &TensorData::from([-9.655_477, -8.053_822, 27.575_281]),
+            Tolerance::rel_abs(1e-4, 1e-4),
+        );
+    }
+
+    #[test]
+    fn should_run_with_scalar_input() {
+        let device = Default::default();
+        let model = load("add");
+        let input = Tensor::<TestBackend, 4>::from_floats([[[[1., 2., 3., 4.]]]], &device);
+
+        let output = model.forward(vec![input.into(), 2.0.into()]).unwrap();
+
+        output[0]
+            .clone()
+            .into_float::<4>()
+            .unwrap()
+            .into_data()
+            .assert_eq(&TensorData::from([[[[9f32, 10., 11., 12.]]]]), true);
+    }
+
+    #[test]
+    fn should_validate_inputs() {
+        let device = Default::default();
+        let model = load("relu");
+
+        let error = model.forward(vec![]).unwrap_err();
+        assert!(matches!(
+            error,
+            OnnxRuntimeError::InputCount {
+                expected: 1,
+                actual: 0
+            }
+        ));
+
+        let input = Tensor::<TestBackend, 2, Int>::from_ints([[1, 2]], &device);
+        let error = model.forward(vec![input.into()]).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            format!(
+                "Invalid input {}: expected float tensor of rank 2, got int tensor of rank 2",
+                model.inputs()[0].name
+            )
+        );
+    }
+
+    #[test]
+    fn should_report_unsupported_nodes() {
+        let error =
+            OnnxModel::<TestBackend>::load(model_path("topk"), &Default::default()).unwrap_err();
+
+        match error {
+            OnnxRuntimeError::UnsupportedNodes(nodes) => {
+                assert!(nodes.iter().all(|node| node.ends_with("(TopK)")));
+            }
+            error => panic!("Expected unsupported nodes, got {error}"),
+        }
+    }
+
+    #[test]
+    fn should_report_missing_file() {
+        let error =
+            OnnxModel::<TestBackend>::load("missing.onnx", &Default::default()).unwrap_err();
+
+        assert!(matches!(error, OnnxRuntimeError::Parse { .. }));
+    }
+
+    #[test]
+    fn should_report_invalid_file() {
+        // A truncated varint, which isn't a valid protobuf message.
+        let path = std::env::temp_dir().join("burn-import-invalid.onnx");
+        std::fs::write(&path, [0xff; 4]).unwrap();
+
+        let error = OnnxModel::<TestBackend>::load(&path, &Default::default()).unwrap_err();
+        std::fs::remove_file(&path).ok();
+
+        match error {
+            OnnxRuntimeError::Parse { message, .. } => {
+                assert!(
+                    message.starts_with("Unable to parse ONNX file"),
+                    "{message}"
+                );
+            }
+            error => panic!("Expected a parse error, got {error}"),
+        }
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    fn should_report_graph_conversion_panic() {
+        // Two relu nodes in reverse order, which `onnx-ir` rejects with a debug assertion. The
+        // protobuf fields are the input (1), output (2) and type (4) of the nodes, the nodes of
+        // the graph (1), and the IR version (1) and graph (7) of the model.
+        let relu = |input: u8, output: u8| {
+            [
+                0x0a, 1, input, 0x12, 1, output, 0x22, 4, b'R', b'e', b'l', b'u',
+            ]
+        };
+        let mut graph = Vec::new();
+        for node in [relu(b'a', b'b'), relu(b'x', b'a')] {
+            graph.extend([0x0a, node.len() as u8]);
+            graph.extend(node);
+        }
+        let mut model = vec![0x08, 0x08, 0x3a, graph.len() as u8];
+        model.extend(graph);
+
+        let path = std::env::temp_dir().join("burn-import-unsorted.onnx");
+        std::fs::write(&path, model).unwrap();
+
+        let error = OnnxModel::<TestBackend>::load(&path, &Default::default()).unwrap_err();
+        std::fs::remove_file(&path).ok();
+
+        match error {
+            OnnxRuntimeError::Parse { message, .. } => {
+                assert!(message.contains("topologically sorted"), "{message}");
+            }
+            error => panic!("Expected a parse error, got {error}"),
+        }
+    }
+}
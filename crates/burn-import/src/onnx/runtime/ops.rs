@@ -0,0 +1,945 @@
+use burn::tensor::{
+    ElementConversion, Shape, TensorData, TensorMetadata,
+    backend::Backend,
+    ops::{ConvOptions, FloatTensor},
+};
+use onnx_ir::{
+    convert_constant_value,
+    ir::{ElementType, Node, NodeType},
+    node::{
+        avg_pool2d::avg_pool2d_config,
+        batch_norm::batch_norm_config,
+        cast::cast_config,
+        clip::clip_config,
+        concat::concat_config,
+        conv2d::conv2d_config,
+        flatten::flatten_config,
+        leaky_relu::leaky_relu_config,
+        log_softmax::log_softmax_config,
+        max_pool2d::max_pool2d_config,
+        padding::PaddingConfig2d,
+        reduce::reduce_config,
+        reshape::{ReshapeInput, reshape_config},
+        shape::shape_config,
+        softmax::softmax_config,
+        squeeze::squeeze_config,
+        transpose::transpose_config,
+        unsqueeze::{UnsqueezeConfig, unsqueeze_config},
+    },
+};
+
+use super::value::{ElemKind, OnnxValue};
+
+/// An operation compiled from a node of the graph.
+#[derive(Debug)]
+pub(crate) enum Op<B: Backend> {
+    Identity,
+    Constant(OnnxValue<B>),
+    Binary(BinaryOp),
+    Unary(UnaryOp),
+    LeakyRelu(f64),
+    Clip {
+        min: Option<f64>,
+        max: Option<f64>,
+    },
+    Softmax {
+        dim: usize,
+        log: bool,
+    },
+    MatMul,
+    Linear,
+    /// The target shape, read from the second input when it isn't static.
+    Reshape(Option<Vec<i64>>),
+    Flatten(usize),
+    Transpose(Vec<usize>),
+    Concat(usize),
+    /// The inserted axes, read from the second input when they aren't static.
+    Unsqueeze(Option<Vec<i64>>),
+    Squeeze(Vec<i64>),
+    Shape {
+        start: usize,
+        end: usize,
+    },
+    Reduce {
+        op: ReduceOp,
+        dims: Vec<usize>,
+        keepdims: bool,
+    },
+    Conv2d(ConvOptions<2>),
+    MaxPool2d {
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        dilation: [usize; 2],
+    },
+    AvgPool2d {
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        count_include_pad: bool,
+    },
+    GlobalAveragePool,
+    BatchNorm {
+        epsilon: f64,
+    },
+    Cast(ElemKind),
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum BinaryOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Pow,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum UnaryOp {
+    Abs,
+    Neg,
+    Exp,
+    Log,
+    Sqrt,
+    Reciprocal,
+    Sin,
+    Cos,
+    Tanh,
+    Erf,
+    Floor,
+    Ceil,
+    Round,
+    Relu,
+    Sigmoid,
+    Gelu,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum ReduceOp {
+    Sum,
+    Mean,
+    Max,
+    Min,
+}
+
+/// Compiles a node, returning `None` when the node type isn't supported.
+///
+/// The node configurations of `onnx-ir` panic on invalid attributes, so this should be called
+/// within [catch_unwind](std::panic::catch_unwind).
+pub(crate) fn compile<B: Backend>(
+    node: &Node,
+    device: &B::Device,
+) -> Result<Option<Op<B>>, String> {
+    let op = match node.node_type {
+        NodeType::Identity | NodeType::Dropout => Op::Identity,
+        NodeType::Constant => {
+            let data = convert_constant_value(node)
+                .value
+                .ok_or("The constant has no value")?;
+            let value = OnnxValue::from_data(data, device)?.conform(&node.outputs[0].ty, device);
+
+            Op::Constant(value)
+        }
+        NodeType::Add => Op::Binary(BinaryOp::Add),
+        NodeType::Sub => Op::Binary(BinaryOp::Sub),
+        NodeType::Mul => Op::Binary(BinaryOp::Mul),
+        NodeType::Div => Op::Binary(BinaryOp::Div),
+        NodeType::Pow => Op::Binary(BinaryOp::Pow),
+        NodeType::Abs => Op::Unary(UnaryOp::Abs),
+        NodeType::Neg => Op::Unary(UnaryOp::Neg),
+        NodeType::Exp => Op::Unary(UnaryOp::Exp),
+        NodeType::Log => Op::Unary(UnaryOp::Log),
+        NodeType::Sqrt => Op::Unary(UnaryOp::Sqrt),
+        NodeType::Reciprocal => Op::Unary(UnaryOp::Reciprocal),
+        NodeType::Sin => Op::Unary(UnaryOp::Sin),
+        NodeType::Cos => Op::Unary(UnaryOp::Cos),
+        NodeType::Tanh => Op::Unary(UnaryOp::Tanh),
+        NodeType::Erf => Op::Unary(UnaryOp::Erf),
+        NodeType::Floor => Op::Unary(UnaryOp::Floor),
+        NodeType::Ceil => Op::Unary(UnaryOp::Ceil),
+        NodeType::Round => Op::Unary(UnaryOp::Round),
+        NodeType::Relu => Op::Unary(UnaryOp::Relu),
+        NodeType::Sigmoid => Op::Unary(UnaryOp::Sigmoid),
+        NodeType::Gelu => Op::Unary(UnaryOp::Gelu),
+        NodeType::LeakyRelu => Op::LeakyRelu(leaky_relu_config(node)),
+        NodeType::Clip => {
+            let (min, max) = clip_config(node);
+            Op::Clip { min, max }
+        }
+        NodeType::Softmax => Op::Softmax {
+            dim: softmax_config(node),
+            log: false,
+        },
+        NodeType::LogSoftmax => Op::Softmax {
+            dim: log_softmax_config(node),
+            log: true,
+        },
+        NodeType::MatMul => Op::MatMul,
+        NodeType::Linear => Op::Linear,
+        NodeType::Reshape => match reshape_config(node).shape {
+            ReshapeInput::Static(shape) => Op::Reshape(Some(shape)),
+            ReshapeInput::Runtime(_) => Op::Reshape(None),
+        },
+        NodeType::Flatten => Op::Flatten(flatten_config(node)),
+        NodeType::Transpose => Op::Transpose(
+            transpose_config(node)
+                .into_iter()
+                .map(|dim| dim as usize)
+                .collect(),
+        ),
+        NodeType::Concat => Op::Concat(concat_config(node)),
+        NodeType::Unsqueeze => match unsqueeze_config(node) {
+            UnsqueezeConfig::Static(axes) => Op::Unsqueeze(Some(axes)),
+            UnsqueezeConfig::Runtime(_) => Op::Unsqueeze(None),
+        },
+        NodeType::Squeeze => Op::Squeeze(squeeze_config(node)),
+        NodeType::Shape => {
+            let (start, end) = shape_config(node);
+            Op::Shape { start, end }
+        }
+        NodeType::ReduceSum | NodeType::ReduceMean | NodeType::ReduceMax | NodeType::ReduceMin => {
+            let op = match node.node_type {
+                NodeType::ReduceSum => ReduceOp::Sum,
+                NodeType::ReduceMean => ReduceOp::Mean,
+                NodeType::ReduceMax => ReduceOp::Max,
+                _ => ReduceOp::Min,
+            };
+            let config = reduce_config(node);
+
+            Op::Reduce {
+                op,
+                dims: config.dims,
+                keepdims: config.keepdims,
+            }
+        }
+        NodeType::Conv2d => {
+            let config = conv2d_config(node);
+
+            Op::Conv2d(ConvOptions::new(
+                config.stride,
+                padding(&config.padding),
+                config.dilation,
+                config.groups,
+            ))
+        }
+        NodeType::MaxPool2d => {
+            let config = max_pool2d_config(node);
+
+            Op::MaxPool2d {
+                kernel_size: config.kernel_size,
+                stride: config.strides,
+                padding: padding(&config.padding),
+                dilation: config.dilation,
+            }
+        }
+        NodeType::AveragePool2d => {
+            let config = avg_pool2d_config(node);
+
+            Op::AvgPool2d {
+                kernel_size: config.kernel_size,
+                stride: config.strides,
+                padding: padding(&config.padding),
+                count_include_pad: config.count_include_pad,
+            }
+        }
+        NodeType::GlobalAveragePool => Op::GlobalAveragePool,
+        NodeType::BatchNormalization => Op::BatchNorm {
+            epsilon: batch_norm_config(node).epsilon,
+        },
+        NodeType::Cast => match cast_config(node).to {
+            ElementType::String => return Err("Cast to string is not supported".into()),
+            elem_type => Op::Cast(ElemKind::from(&elem_type)),
+        },
+        _ => return Ok(None),
+    };
+
+    Ok(Some(op))
+}
+
+fn padding(config: &PaddingConfig2d) -> [usize; 2] {
+    match config {
+        PaddingConfig2d::Valid => [0, 0],
+        PaddingConfig2d::Explicit(height, width) => [*height, *width],
+    }
+}
+
+type Inputs<B> = Vec<Option<OnnxValue<B>>>;
+
+impl<B: Backend> Op<B> {
+    /// Executes the operation, the missing optional inputs being `None`.
+    pub(crate) fn execute(
+        &self,
+        mut inputs: Inputs<B>,
+        device: &B::Device,
+    ) -> Result<Vec<OnnxValue<B>>, String> {
+        let output = match self {
+            Op::Identity => input(&mut inputs, 0)?,
+            Op::Constant(value) => value.clone(),
+            Op::Binary(op) => {
+                let lhs = input(&mut inputs, 0)?;
+                let rhs = input(&mut inputs, 1)?;
+                binary(*op, lhs, rhs, device)?
+            }
+            Op::Unary(op) => unary(*op, input(&mut inputs, 0)?, device)?,
+            Op::LeakyRelu(slope) => {
+                let tensor = float(input(&mut inputs, 0)?, device)?;
+                OnnxValue::Float(B::leaky_relu(tensor, (*slope).elem()))
+            }
+            Op::Clip { min, max } => {
+                let tensor = float(input(&mut inputs, 0)?, device)?;
+                // The bounds are inputs since opset 11.
+                let min = optional_scalar(&mut inputs, 1).or(*min);
+                let max = optional_scalar(&mut inputs, 2).or(*max);
+                let tensor = match min {
+                    Some(min) => B::float_clamp_min(tensor, min.elem()),
+                    None => tensor,
+                };
+                let tensor = match max {
+                    Some(max) => B::float_clamp_max(tensor, max.elem()),
+                    None => tensor,
+                };
+                OnnxValue::Float(tensor)
+            }
+            Op::Softmax { dim, log } => {
+                let tensor = float(input(&mut inputs, 0)?, device)?;
+                OnnxValue::Float(softmax::<B>(tensor, *dim, *log))
+            }
+            Op::MatMul => {
+                let lhs = float(input(&mut inputs, 0)?, device)?;
+                let rhs = float(input(&mut inputs, 1)?, device)?;
+                OnnxValue::Float(matmul::<B>(lhs, rhs))
+            }
+            Op::Linear => {
+                let x = float(input(&mut inputs, 0)?, device)?;
+                let weight = float(input(&mut inputs, 1)?, device)?;
+                // The weight of shape [d_input, d_output] is broadcast over the batch dimensions.
+                let rank = x.shape().num_dims();
+                let shape = expand_rank(weight.shape(), rank);
+                let output = B::float_matmul(x, B::float_reshape(weight, shape));
+
+                match optional(&mut inputs, 2) {
+                    Some(bias) => {
+                        let bias = float(bias, device)?;
+                        let shape = expand_rank(bias.shape(), rank);
+                        OnnxValue::Float(B::float_add(output, B::float_reshape(bias, shape)))
+                    }
+                    None => OnnxValue::Float(output),
+                }
+            }
+            Op::Reshape(shape) => {
+                let tensor = input(&mut inputs, 0)?;
+                let target = match shape {
+                    Some(shape) => shape.clone(),
+                    None => input(&mut inputs, 1)?.to_i64s(),
+                };
+                let shape = reshape_shape(&tensor_shape(&tensor)?, &target)?;
+                reshape(tensor, shape)?
+            }
+            Op::Flatten(axis) => {
+                let tensor = input(&mut inputs, 0)?;
+                let dims = tensor_shape(&tensor)?.dims;
+                let outer = dims[..*axis].iter().product::<usize>();
+                let inner = dims[*axis..].iter().product::<usize>();
+                reshape(tensor, Shape::new([outer, inner]))?
+            }
+            Op::Transpose(perm) => {
+                let tensor = input(&mut inputs, 0)?;
+                let perm = match perm.is_empty() {
+                    true => (0..tensor_shape(&tensor)?.num_dims()).rev().collect(),
+                    false => perm.clone(),
+                };
+                permute(tensor, &perm)?
+            }
+            Op::Concat(dim) => concat(inputs.into_iter().flatten().collect(), *dim, device)?,
+            Op::Unsqueeze(axes) => {
+                let tensor = input(&mut inputs, 0)?;
+                let axes = match axes {
+                    Some(axes) => axes.clone(),
+                    None => input(&mut inputs, 1)?.to_i64s(),
+                };
+                match tensor {
+                    // The scalar is converted to the tensor of the output type.
+                    OnnxValue::Scalar(_) => tensor,
+                    tensor => {
+                        let dims = tensor_shape(&tensor)?.dims;
+                        reshape(tensor, unsqueeze_shape(dims, &axes))?
+                    }
+                }
+            }
+            Op::Squeeze(axes) => {
+                let tensor = input(&mut inputs, 0)?;
+                // The axes are an input since opset 13.
+                let axes = match optional(&mut inputs, 1) {
+                    Some(axes) => axes.to_i64s(),
+                    None => axes.clone(),
+                };
+                match tensor {
+                    OnnxValue::Scalar(_) | OnnxValue::Shape(_) => tensor,
+                    tensor => {
+                        let dims = tensor_shape(&tensor)?.dims;
+                        reshape(tensor, squeeze_shape(dims, &axes))?
+                    }
+                }
+            }
+            Op::Shape { start, end } => match input(&mut inputs, 0)? {
+                OnnxValue::Shape(dims) => OnnxValue::Shape(vec![dims.len() as i64]),
+                tensor => {
+                    let dims = tensor_shape(&tensor)?.dims;
+                    OnnxValue::Shape(dims[*start..*end].iter().map(|dim| *dim as i64).collect())
+                }
+            },
+            Op::Reduce { op, dims, keepdims } => {
+                reduce(*op, input(&mut inputs, 0)?, dims, *keepdims)?
+            }
+            Op::Conv2d(options) => {
+                let x = float(input(&mut inputs, 0)?, device)?;
+                let weight = float(input(&mut inputs, 1)?, device)?;
+                let bias = optional(&mut inputs, 2)
+                    .map(|bias| float(bias, device))
+                    .transpose()?;
+                OnnxValue::Float(B::conv2d(x, weight, bias, options.clone()))
+            }
+            Op::MaxPool2d {
+                kernel_size,
+                stride,
+                padding,
+                dilation,
+            } => {
+                let x = float(input(&mut inputs, 0)?, device)?;
+                OnnxValue::Float(B::max_pool2d(x, *kernel_size, *stride, *padding, *dilation))
+            }
+            Op::AvgPool2d {
+                kernel_size,
+                stride,
+                padding,
+                count_include_pad,
+            } => {
+                let x = float(input(&mut inputs, 0)?, device)?;
+                OnnxValue::Float(B::avg_pool2d(
+                    x,
+                    *kernel_size,
+                    *stride,
+                    *padding,
+                    *count_include_pad,
+                ))
+            }
+            Op::GlobalAveragePool => {
+                let x = float(input(&mut inputs, 0)?, device)?;
+                let rank = x.shape().num_dims();
+                if rank != 4 {
+                    return Err(format!(
+                        "Only 2D global average pooling is supported, got a tensor of rank {rank}"
+                    ));
+                }
+                OnnxValue::Float(B::adaptive_avg_pool2d(x, [1, 1]))
+            }
+            Op::BatchNorm { epsilon } => {
+                let x = float(input(&mut inputs, 0)?, device)?;
+                let mut params = Vec::with_capacity(4);
+                for index in 1..5 {
+                    params.push(float(input(&mut inputs, index)?, device)?);
+                }
+                OnnxValue::Float(batch_norm::<B>(x, params, *epsilon))
+            }
+            Op::Cast(kind) => cast(input(&mut inputs, 0)?, *kind, device),
+        };
+
+        Ok(vec![output])
+    }
+}
+
+fn input<B: Backend>(inputs: &mut Inputs<B>, index: usize) -> Result<OnnxValue<B>, String> {
+    optional(inputs, index).ok_or_else(|| format!("Missing input {index}"))
+}
+
+fn optional<B: Backend>(inputs: &mut Inputs<B>, index: usize) -> Option<OnnxValue<B>> {
+    inputs.get_mut(index).and_then(Option::take)
+}
+
+fn optional_scalar<B: Backend>(inputs: &mut Inputs<B>, index: usize) -> Option<f64> {
+    optional(inputs, index).and_then(|value| value.to_f64s().first().copied())
+}
+
+/// The float tensor of the value, a scalar being converted to a tensor of shape `[1]`.
+fn float<B: Backend>(value: OnnxValue<B>, device: &B::Device) -> Result<FloatTensor<B>, String> {
+    match value {
+        OnnxValue::Float(tensor) => Ok(tensor),
+        OnnxValue::Scalar(value) => Ok(B::float_from_data(
+            TensorData::new(vec![value], [1]).convert::<B::FloatElem>(),
+            device,
+        )),
+        value => Err(format!("Expected a float tensor, got a {}", value.kind())),
+    }
+}
+
+fn tensor_shape<B: Backend>(value: &OnnxValue<B>) -> Result<Shape, String> {
+    match value {
+        OnnxValue::Float(tensor) => Ok(tensor.shape()),
+        OnnxValue::Int(tensor) => Ok(tensor.shape()),
+        OnnxValue::Bool(tensor) => Ok(tensor.shape()),
+        OnnxValue::Shape(dims) => Ok(Shape::new([dims.len()])),
+        OnnxValue::Scalar(_) => Err("Expected a tensor, got a scalar".into()),
+    }
+}
+
+fn reshape<B: Backend>(value: OnnxValue<B>, shape: Shape) -> Result<OnnxValue<B>, String> {
+    match value {
+        OnnxValue::Float(tensor) => Ok(OnnxValue::Float(B::float_reshape(tensor, shape))),
+        OnnxValue::Int(tensor) => Ok(OnnxValue::Int(B::int_reshape(tensor, shape))),
+        OnnxValue::Bool(tensor) => Ok(OnnxValue::Bool(B::bool_reshape(tensor, shape))),
+        // A shape is a vector, so its dimensions are preserved.
+        OnnxValue::Shape(dims) if shape.num_dims() == 1 => Ok(OnnxValue::Shape(dims)),
+        value => Err(format!("Can't reshape a {}", value.kind())),
+    }
+}
+
+fn permute<B: Backend>(value: OnnxValue<B>, axes: &[usize]) -> Result<OnnxValue<B>, String> {
+    match value {
+        OnnxValue::Float(tensor) => Ok(OnnxValue::Float(B::float_permute(tensor, axes))),
+        OnnxValue::Int(tensor) => Ok(OnnxValue::Int(B::int_permute(tensor, axes))),
+        OnnxValue::Bool(tensor) => Ok(OnnxValue::Bool(B::bool_permute(tensor, axes))),
+        value => Err(format!("Can't transpose a {}", value.kind())),
+    }
+}
+
+/// Prepends dimensions of size 1 to the shape up to the given rank, to follow the broadcasting
+/// rules of ONNX.
+fn expand_rank(shape: Shape, rank: usize) -> Shape {
+    let mut dims = vec![1; rank.saturating_sub(shape.num_dims())];
+    dims.extend(shape.dims);
+
+    Shape::from(dims)
+}
+
+/// The shape of a reshape, where `0` copies the input dimension and `-1` is inferred.
+fn reshape_shape(input: &Shape, target: &[i64]) -> Result<Shape, String> {
+    let mut dims = target
+        .iter()
+        .enumerate()
+        .map(|(index, dim)| match dim {
+            0 => input.dims.get(index).copied().unwrap_or(0),
+            -1 => 1,
+            dim => *dim as usize,
+        })
+        .collect::<Vec<_>>();
+
+    if let Some(index) = target.iter().position(|dim| *dim == -1) {
+        let known = dims.iter().product::<usize>();
+        dims[index] = input.num_elements() / known.max(1);
+    }
+
+    let shape = Shape::from(dims);
+    if shape.num_elements() != input.num_elements() {
+        return Err(format!(
+            "Can't reshape a tensor of shape {:?} to {target:?}",
+            input.dims
+        ));
+    }
+
+    Ok(shape)
+}
+
+fn unsqueeze_shape(mut dims: Vec<usize>, axes: &[i64]) -> Shape {
+    let rank = (dims.len() + axes.len()) as i64;
+    let mut axes = axes
+        .iter()
+        .map(|axis| if *axis < 0 { axis + rank } else { *axis } as usize)
+        .collect::<Vec<_>>();
+    axes.sort();
+
+    for axis in axes {
+        dims.insert(axis, 1);
+    }
+
+    Shape::from(dims)
+}
+
+fn squeeze_shape(dims: Vec<usize>, axes: &[i64]) -> Shape {
+    let rank = dims.len() as i64;
+    let axes = axes
+        .iter()
+        .map(|axis| if *axis < 0 { axis + rank } else { *axis } as usize)
+        .collect::<Vec<_>>();
+
+    let dims = dims
+        .into_iter()
+        .enumerate()
+        .filter(|(index, dim)| match axes.is_empty() {
+            true => *dim != 1,
+            false => !axes.contains(index),
+        })
+        .map(|(_, dim)| dim)
+        .collect::<Vec<_>>();
+
+    Shape::from(dims)
+}
+
+fn binary<B: Backend>(
+    op: BinaryOp,
+    lhs: OnnxValue<B>,
+    rhs: OnnxValue<B>,
+    device: &B::Device,
+) -> Result<OnnxValue<B>, String> {
+    let output = match (lhs, rhs) {
+        (OnnxValue::Scalar(lhs), OnnxValue::Scalar(rhs)) => {
+            OnnxValue::Scalar(op.apply_f64(lhs, rhs))
+        }
+        // Shape arithmetic, e.g. to compute the target shape of a reshape.
+        (
+            lhs @ (OnnxValue::Shape(_) | OnnxValue::Scalar(_)),
+            rhs @ (OnnxValue::Shape(_) | OnnxValue::Scalar(_)),
+        ) => {
+            let (lhs, rhs) = (lhs.to_i64s(), rhs.to_i64s());
+            let len = lhs.len().max(rhs.len());
+            let value = |values: &[i64], index: usize| match values.len() {
+                1 => values[0],
+                _ => values[index],
+            };
+
+            OnnxValue::Shape(
+                (0..len)
+                    .map(|index| op.apply_i64(value(&lhs, index), value(&rhs, index)))
+                    .collect(),
+            )
+        }
+        (lhs, rhs) => {
+            let lhs_tensor = as_tensor_like(lhs, &rhs, device);
+            let rhs_tensor = as_tensor_like(rhs, &lhs_tensor, device);
+
+            match (lhs_tensor, rhs_tensor) {
+                (OnnxValue::Float(lhs), OnnxValue::Float(rhs)) => {
+                    let rank = lhs.shape().num_dims().max(rhs.shape().num_dims());
+                    let lhs_shape = expand_rank(lhs.shape(), rank);
+                    let rhs_shape = expand_rank(rhs.shape(), rank);
+                    let lhs = B::float_reshape(lhs, lhs_shape);
+                    let rhs = B::float_reshape(rhs, rhs_shape);
+
+                    OnnxValue::Float(match op {
+                        BinaryOp::Add => B::float_add(lhs, rhs),
+                        BinaryOp::Sub => B::float_sub(lhs, rhs),
+                        BinaryOp::Mul => B::float_mul(lhs, rhs),
+                        BinaryOp::Div => B::float_div(lhs, rhs),
+                        BinaryOp::Pow => B::float_powf(lhs, rhs),
+                    })
+                }
+                (OnnxValue::Int(lhs), OnnxValue::Int(rhs)) => {
+                    let rank = lhs.shape().num_dims().max(rhs.shape().num_dims());
+                    let lhs_shape = expand_rank(lhs.shape(), rank);
+                    let rhs_shape = expand_rank(rhs.shape(), rank);
+                    let lhs = B::int_reshape(lhs, lhs_shape);
+                    let rhs = B::int_reshape(rhs, rhs_shape);
+
+                    OnnxValue::Int(match op {
+                        BinaryOp::Add => B::int_add(lhs, rhs),
+                        BinaryOp::Sub => B::int_sub(lhs, rhs),
+                        BinaryOp::Mul => B::int_mul(lhs, rhs),
+                        BinaryOp::Div => B::int_div(lhs, rhs),
+                        BinaryOp::Pow => B::int_powf(lhs, B::int_into_float(rhs)),
+                    })
+                }
+                (lhs, rhs) => {
+                    return Err(format!(
+                        "Unsupported {op:?} operands: {} and {}",
+                        lhs.kind(),
+                        rhs.kind()
+                    ));
+                }
+            }
+        }
+    };
+
+    Ok(output)
+}
+
+/// Converts a shape or a scalar to a tensor of the kind of the other operand.
+fn as_tensor_like<B: Backend>(
+    value: OnnxValue<B>,
+    other: &OnnxValue<B>,
+    device: &B::Device,
+) -> OnnxValue<B> {
+    let elem_type = match other {
+        OnnxValue::Float(_) => ElementType::Float32,
+        OnnxValue::Int(_) | OnnxValue::Shape(_) => ElementType::Int64,
+        _ => return value,
+    };
+
+    match value {
+        OnnxValue::Scalar(value) => {
+            let rank = other.rank().unwrap_or(1);
+            OnnxValue::from_f64s(vec![value], vec![1; rank], &elem_type, device)
+        }
+        OnnxValue::Shape(dims) => {
+            let shape = vec![dims.len()];
+            let values = dims.into_iter().map(|dim| dim as f64).collect();
+            OnnxValue::from_f64s(values, shape, &elem_type, device)
+        }
+        value => value,
+    }
+}
+
+impl BinaryOp {
+    fn apply_f64(&self, lhs: f64, rhs: f64) -> f64 {
+        match self {
+            BinaryOp::Add => lhs + rhs,
+            BinaryOp::Sub => lhs - rhs,
+            BinaryOp::Mul => lhs * rhs,
+            BinaryOp::Div => lhs / rhs,
+            BinaryOp::Pow => lhs.powf(rhs),
+        }
+    }
+
+    fn apply_i64(&self, lhs: i64, rhs: i64) -> i64 {
+        match self {
+            BinaryOp::Add => lhs + rhs,
+            BinaryOp::Sub => lhs - rhs,
+            BinaryOp::Mul => lhs * rhs,
+            BinaryOp::Div => lhs / rhs,
+            BinaryOp::Pow => lhs.pow(rhs as u32),
+        }
+    }
+}
+
+fn unary<B: Backend>(
+    op: UnaryOp,
+    value: OnnxValue<B>,
+    device: &B::Device,
+) -> Result<OnnxValue<B>, String> {
+    if let OnnxValue::Int(tensor) = value {
+        return match op {
+            UnaryOp::Abs => Ok(OnnxValue::Int(B::int_abs(tensor))),
+            UnaryOp::Neg => Ok(OnnxValue::Int(B::int_neg(tensor))),
+            op => Err(format!("Unsupported {op:?} of an int tensor")),
+        };
+    }
+
+    let tensor = float(value, device)?;
+    let output = match op {
+        UnaryOp::Abs => B::float_abs(tensor),
+        UnaryOp::Neg => B::float_neg(tensor),
+        UnaryOp::Exp => B::float_exp(tensor),
+        UnaryOp::Log => B::float_log(tensor),
+        UnaryOp::Sqrt => B::float_sqrt(tensor),
+        UnaryOp::Reciprocal => B::float_recip(tensor),
+        UnaryOp::Sin => B::float_sin(tensor),
+        UnaryOp::Cos => B::float_cos(tensor),
+        UnaryOp::Tanh => B::float_tanh(tensor),
+        UnaryOp::Erf => B::float_erf(tensor),
+        UnaryOp::Floor => B::float_floor(tensor),
+        UnaryOp::Ceil => B::float_ceil(tensor),
+        UnaryOp::Round => B::float_round(tensor),
+        UnaryOp::Relu => B::relu(tensor),
+        UnaryOp::Sigmoid => B::sigmoid(tensor),
+        UnaryOp::Gelu => B::gelu(tensor),
+    };
+
+    Ok(OnnxValue::Float(output))
+}
+
+fn softmax<B: Backend>(tensor: FloatTensor<B>, dim: usize, log: bool) -> FloatTensor<B> {
+    let max = B::float_max_dim(tensor.clone(), dim);
+    let shifted = B::float_sub(tensor, max);
+    let sum = B::float_sum_dim(B::float_exp(shifted.clone()), dim);
+
+    match log {
+        true => B::float_sub(shifted, B::float_log(sum)),
+        false => B::float_div(B::float_exp(shifted), sum),
+    }
+}
+
+/// Matrix product following `numpy.matmul`, where vectors are promoted to matrices and the batch
+/// dimensions are broadcast.
+fn matmul<B: Backend>(lhs: FloatTensor<B>, rhs: FloatTensor<B>) -> FloatTensor<B> {
+    let lhs_vector = lhs.shape().num_dims() == 1;
+    let rhs_vector = rhs.shape().num_dims() == 1;
+    let lhs = match lhs_vector {
+        true => {
+            let size = lhs.shape().dims[0];
+            B::float_reshape(lhs, Shape::new([1, size]))
+        }
+        false => lhs,
+    };
+    let rhs = match rhs_vector {
+        true => {
+            let size = rhs.shape().dims[0];
+            B::float_reshape(rhs, Shape::new([size, 1]))
+        }
+        false => rhs,
+    };
+
+    let rank = lhs.shape().num_dims().max(rhs.shape().num_dims());
+    let lhs_shape = expand_rank(lhs.shape(), rank);
+    let rhs_shape = expand_rank(rhs.shape(), rank);
+    let output = B::float_matmul(
+        B::float_reshape(lhs, lhs_shape),
+        B::float_reshape(rhs, rhs_shape),
+    );
+
+    let mut dims = output.shape().dims;
+    if rhs_vector {
+        dims.pop();
+    }
+    if lhs_vector {
+        dims.remove(dims.len() - 1 - (!rhs_vector) as usize);
+    }
+
+    B::float_reshape(output, Shape::from(dims))
+}
+
+fn concat<B: Backend>(
+    values: Vec<OnnxValue<B>>,
+    dim: usize,
+    device: &B::Device,
+) -> Result<OnnxValue<B>, String> {
+    if values
+        .iter()
+        .all(|value| matches!(value, OnnxValue::Shape(_)))
+    {
+        return Ok(OnnxValue::Shape(
+            values.iter().flat_map(OnnxValue::to_i64s).collect(),
+        ));
+    }
+
+    let reference = values
+        .iter()
+        .find(|value| !matches!(value, OnnxValue::Shape(_)))
+        .cloned()
+        .ok_or("Missing input 0")?;
+    let values = values
+        .into_iter()
+        .map(|value| as_tensor_like(value, &reference, device));
+
+    match reference {
+        OnnxValue::Float(_) => Ok(OnnxValue::Float(B::float_cat(
+            values
+                .map(|value| float(value, device))
+                .collect::<Result<_, _>>()?,
+            dim,
+        ))),
+        OnnxValue::Int(_) => Ok(OnnxValue::Int(B::int_cat(
+            values
+                .map(|value| match value {
+                    OnnxValue::Int(tensor) => Ok(tensor),
+                    value => Err(format!("Can't concatenate a {}", value.kind())),
+                })
+                .collect::<Result<_, String>>()?,
+            dim,
+        ))),
+        OnnxValue::Bool(_) => Ok(OnnxValue::Bool(B::bool_cat(
+            values
+                .map(|value| match value {
+                    OnnxValue::Bool(tensor) => Ok(tensor),
+                    value => Err(format!("Can't concatenate a {}", value.kind())),
+                })
+                .collect::<Result<_, String>>()?,
+            dim,
+        ))),
+        value => Err(format!("Can't concatenate a {}", value.kind())),
+    }
+}
+
+fn reduce<B: Backend>(
+    op: ReduceOp,
+    value: OnnxValue<B>,
+    dims: &[usize],
+    keepdims: bool,
+) -> Result<OnnxValue<B>, String> {
+    let shape = tensor_shape(&value)?;
+    // No axes reduce all the dimensions.
+    let dims = match dims.is_empty() {
+        true => (0..shape.num_dims()).collect(),
+        false => dims.to_vec(),
+    };
+
+    let mut output = match value {
+        OnnxValue::Float(mut tensor) => {
+            for dim in dims.iter() {
+                tensor = match op {
+                    ReduceOp::Sum => B::float_sum_dim(tensor, *dim),
+                    ReduceOp::Mean => B::float_mean_dim(tensor, *dim),
+                    ReduceOp::Max => B::float_max_dim(tensor, *dim),
+                    ReduceOp::Min => B::float_min_dim(tensor, *dim),
+                };
+            }
+            OnnxValue::Float(tensor)
+        }
+        OnnxValue::Int(mut tensor) => {
+            for dim in dims.iter() {
+                tensor = match op {
+                    ReduceOp::Sum => B::int_sum_dim(tensor, *dim),
+                    ReduceOp::Mean => B::int_mean_dim(tensor, *dim),
+                    ReduceOp::Max => B::int_max_dim(tensor, *dim),
+                    ReduceOp::Min => B::int_min_dim(tensor, *dim),
+                };
+            }
+            OnnxValue::Int(tensor)
+        }
+        value => return Err(format!("Can't reduce a {}", value.kind())),
+    };
+
+    if !keepdims {
+        let mut reduced = shape
+            .dims
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| !dims.contains(index))
+            .map(|(_, dim)| *dim)
+            .collect::<Vec<_>>();
+        // The reduction of all the dimensions is a scalar, stored as a single element tensor.
+        if reduced.is_empty() {
+            reduced.push(1);
+        }
+        output = reshape(output, Shape::from(reduced))?;
+    }
+
+    Ok(output)
+}
+
+fn batch_norm<B: Backend>(
+    x: FloatTensor<B>,
+    params: Vec<FloatTensor<B>>,
+    epsilon: f64,
+) -> FloatTensor<B> {
+    // The parameters of shape [channels] are broadcast to [1, channels, 1, ...].
+    let rank = x.shape().num_dims();
+    let mut params = params.into_iter().map(|param| {
+        let mut dims = vec![1; rank];
+        dims[1] = param.shape().num_elements();
+        B::float_reshape(param, Shape::from(dims))
+    });
+    let (scale, bias, mean, var) = (
+        params.next().unwrap(),
+        params.next().unwrap(),
+        params.next().unwrap(),
+        params.next().unwrap(),
+    );
+
+    let std = B::float_sqrt(B::float_add_scalar(var, epsilon.elem()));
+    let normalized = B::float_div(B::float_sub(x, mean), std);
+
+    B::float_add(B::float_mul(normalized, scale), bias)
+}
+
+fn cast<B: Backend>(value: OnnxValue<B>, kind: ElemKind, device: &B::Device) -> OnnxValue<B> {
+    match (value, kind) {
+        (OnnxValue::Float(tensor), ElemKind::Int) => OnnxValue::Int(B::float_into_int(tensor)),
+        (OnnxValue::Float(tensor), ElemKind::Bool) => {
+            OnnxValue::Bool(B::float_not_equal_elem(tensor, 0.elem()))
+        }
+        (OnnxValue::Int(tensor), ElemKind::Float) => OnnxValue::Float(B::int_into_float(tensor)),
+        (OnnxValue::Int(tensor), ElemKind::Bool) => {
+            OnnxValue::Bool(B::int_not_equal_elem(tensor, 0.elem()))
+        }
+        (OnnxValue::Bool(tensor), ElemKind::Float) => OnnxValue::Float(B::bool_into_float(tensor)),
+        (OnnxValue::Bool(tensor), ElemKind::Int) => OnnxValue::Int(B::bool_into_int(tensor)),
+        (OnnxValue::Scalar(value), ElemKind::Int) => OnnxValue::Scalar(value.trunc()),
+        (OnnxValue::Scalar(value), ElemKind::Bool) => {
+            OnnxValue::Scalar((value != 0.0) as u8 as f64)
+        }
+        (OnnxValue::Shape(dims), ElemKind::Float) => {
+            let shape = vec![dims.len()];
+            let values = dims.into_iter().map(|dim| dim as f64).collect();
+            OnnxValue::from_f64s(values, shape, &ElementType::Float32, device)
+        }
+        (value, _) => value,
+    }
+}
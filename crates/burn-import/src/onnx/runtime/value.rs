@@ -0,0 +1,317 @@
+use burn::tensor::{
+    Bool, ElementConversion, Int, Shape, Tensor, TensorData, TensorMetadata, TensorPrimitive,
+    backend::Backend,
+    ops::{BoolTensor, FloatTensor, IntTensor},
+};
+use onnx_ir::ir::{ArgType, Data, ElementType, TensorData as OnnxTensorData};
+
+use super::OnnxRuntimeError;
+
+/// A value of an [ONNX model](super::OnnxModel), i.e. an input, an output or an intermediate value.
+///
+/// Tensors are stored as backend primitives, so a value can hold a tensor of any rank, which is
+/// checked when converting it to a typed tensor.
+#[derive(Debug, Clone)]
+pub enum OnnxValue<B: Backend> {
+    /// A float tensor.
+    Float(FloatTensor<B>),
+    /// An int tensor.
+    Int(IntTensor<B>),
+    /// A bool tensor.
+    Bool(BoolTensor<B>),
+    /// The dimensions of a tensor.
+    Shape(Vec<i64>),
+    /// A scalar.
+    Scalar(f64),
+}
+
+impl<B: Backend> OnnxValue<B> {
+    /// Converts the value to a float tensor of rank `D`.
+    pub fn into_float<const D: usize>(self) -> Result<Tensor<B, D>, OnnxRuntimeError> {
+        match self {
+            Self::Float(tensor) if tensor.shape().num_dims() == D => {
+                Ok(Tensor::from_primitive(TensorPrimitive::Float(tensor)))
+            }
+            value => Err(value.mismatch(format!("float tensor of rank {D}"))),
+        }
+    }
+
+    /// Converts the value to an int tensor of rank `D`.
+    pub fn into_int<const D: usize>(self) -> Result<Tensor<B, D, Int>, OnnxRuntimeError> {
+        match self {
+            Self::Int(tensor) if tensor.shape().num_dims() == D => {
+                Ok(Tensor::from_primitive(tensor))
+            }
+            value => Err(value.mismatch(format!("int tensor of rank {D}"))),
+        }
+    }
+
+    /// Converts the value to a bool tensor of rank `D`.
+    pub fn into_bool<const D: usize>(self) -> Result<Tensor<B, D, Bool>, OnnxRuntimeError> {
+        match self {
+            Self::Bool(tensor) if tensor.shape().num_dims() == D => {
+                Ok(Tensor::from_primitive(tensor))
+            }
+            value => Err(value.mismatch(format!("bool tensor of rank {D}"))),
+        }
+    }
+
+    /// Converts the value to the dimensions of a tensor.
+    pub fn into_shape(self) -> Result<Vec<i64>, OnnxRuntimeError> {
+        match self {
+            Self::Shape(dims) => Ok(dims),
+            value => Err(value.mismatch("shape".into())),
+        }
+    }
+
+    /// Converts the value to a scalar.
+    pub fn into_scalar(self) -> Result<f64, OnnxRuntimeError> {
+        match self {
+            Self::Scalar(value) => Ok(value),
+            value => Err(value.mismatch("scalar".into())),
+        }
+    }
+
+    /// The rank of the tensor, `None` for shapes and scalars.
+    pub fn rank(&self) -> Option<usize> {
+        match self {
+            Self::Float(tensor) => Some(tensor.shape().num_dims()),
+            Self::Int(tensor) => Some(tensor.shape().num_dims()),
+            Self::Bool(tensor) => Some(tensor.shape().num_dims()),
+            Self::Shape(_) | Self::Scalar(_) => None,
+        }
+    }
+
+    /// A description of the value type, e.g. `float tensor of rank 2`.
+    pub fn kind(&self) -> String {
+        match self {
+            Self::Float(tensor) => format!("float tensor of rank {}", tensor.shape().num_dims()),
+            Self::Int(tensor) => format!("int tensor of rank {}", tensor.shape().num_dims()),
+            Self::Bool(tensor) => format!("bool tensor of rank {}", tensor.shape().num_dims()),
+            Self::Shape(dims) => format!("shape of length {}", dims.len()),
+            Self::Scalar(_) => "scalar".into(),
+        }
+    }
+
+    fn mismatch(&self, expected: String) -> OnnxRuntimeError {
+        OnnxRuntimeError::InvalidValue {
+            expected,
+            actual: self.kind(),
+        }
+    }
+
+    /// Whether the value has the given ONNX type.
+    pub(crate) fn matches(&self, ty: &ArgType) -> bool {
+        match (self, ty) {
+            (Self::Scalar(_), ArgType::Scalar(_)) => true,
+            (Self::Shape(dims), ArgType::Shape(rank)) => dims.len() == *rank,
+            (value, ArgType::Tensor(tensor)) => {
+                let kind_matches = matches!(
+                    (value, ElemKind::from(&tensor.elem_type)),
+                    (Self::Float(_), ElemKind::Float)
+                        | (Self::Int(_), ElemKind::Int)
+                        | (Self::Bool(_), ElemKind::Bool)
+                );
+
+                kind_matches && value.rank() == Some(tensor.rank)
+            }
+            _ => false,
+        }
+    }
+
+    /// Converts the value to the given ONNX type when they only differ by their representation,
+    /// e.g. a single element tensor used as a scalar, or a shape used as a tensor.
+    pub(crate) fn conform(self, ty: &ArgType, device: &B::Device) -> Self {
+        match (self, ty) {
+            (value @ (Self::Float(_) | Self::Int(_) | Self::Bool(_)), ArgType::Scalar(_))
+                if value.num_elements() == Some(1) =>
+            {
+                Self::Scalar(value.to_f64s()[0])
+            }
+            (Self::Int(tensor), ArgType::Shape(_)) if tensor.shape().num_dims() == 1 => {
+                Self::Shape(Self::Int(tensor).to_i64s())
+            }
+            (Self::Scalar(value), ArgType::Shape(_)) => Self::Shape(vec![value as i64]),
+            (Self::Shape(dims), ArgType::Tensor(tensor)) => {
+                let shape = vec![dims.len()];
+                let values = dims.into_iter().map(|dim| dim as f64).collect();
+                Self::from_f64s(values, shape, &tensor.elem_type, device)
+            }
+            (Self::Scalar(value), ArgType::Tensor(tensor)) => {
+                Self::from_f64s(vec![value], vec![1; tensor.rank], &tensor.elem_type, device)
+            }
+            (value, _) => value,
+        }
+    }
+
+    pub(crate) fn num_elements(&self) -> Option<usize> {
+        match self {
+            Self::Float(tensor) => Some(tensor.shape().num_elements()),
+            Self::Int(tensor) => Some(tensor.shape().num_elements()),
+            Self::Bool(tensor) => Some(tensor.shape().num_elements()),
+            Self::Shape(_) | Self::Scalar(_) => None,
+        }
+    }
+
+    /// Reads the elements of the value.
+    pub(crate) fn to_f64s(&self) -> Vec<f64> {
+        match self.clone() {
+            Self::Float(tensor) => {
+                let num_elements = tensor.shape().num_elements();
+                let tensor = B::float_reshape(tensor, Shape::new([num_elements]));
+                Tensor::<B, 1>::from_primitive(TensorPrimitive::Float(tensor))
+                    .into_data()
+                    .iter::<f64>()
+                    .collect()
+            }
+            Self::Int(tensor) => {
+                let num_elements = tensor.shape().num_elements();
+                let tensor = B::int_reshape(tensor, Shape::new([num_elements]));
+                Tensor::<B, 1, Int>::from_primitive(tensor)
+                    .into_data()
+                    .iter::<f64>()
+                    .collect()
+            }
+            Self::Bool(tensor) => Self::Int(B::bool_into_int(tensor)).to_f64s(),
+            Self::Shape(dims) => dims.into_iter().map(|dim| dim as f64).collect(),
+            Self::Scalar(value) => vec![value],
+        }
+    }
+
+    /// Reads the elements of the value as integers, e.g. the axes or the shape given as input.
+    pub(crate) fn to_i64s(&self) -> Vec<i64> {
+        match self {
+            Self::Shape(dims) => dims.clone(),
+            value => value.to_f64s().into_iter().map(|v| v as i64).collect(),
+        }
+    }
+
+    /// Creates a tensor of the kind of the element type.
+    pub(crate) fn from_f64s(
+        values: Vec<f64>,
+        shape: Vec<usize>,
+        elem_type: &ElementType,
+        device: &B::Device,
+    ) -> Self {
+        let data = TensorData::new(values, shape);
+
+        match ElemKind::from(elem_type) {
+            ElemKind::Float => {
+                Self::Float(B::float_from_data(data.convert::<B::FloatElem>(), device))
+            }
+            ElemKind::Int => Self::Int(B::int_from_data(data.convert::<B::IntElem>(), device)),
+            ElemKind::Bool => {
+                let tensor = B::int_from_data(data.convert::<B::IntElem>(), device);
+                Self::Bool(B::int_not_equal_elem(tensor, 0.elem()))
+            }
+        }
+    }
+
+    /// Creates a value from the data of a constant or an initializer.
+    pub(crate) fn from_data(data: OnnxTensorData, device: &B::Device) -> Result<Self, String> {
+        // Vector data without dimensions holds a single element.
+        let shape = match data.shape.is_empty() {
+            true => vec![1],
+            false => data.shape,
+        };
+        let float = |data: TensorData| {
+            Self::Float(B::float_from_data(data.convert::<B::FloatElem>(), device))
+        };
+        let int =
+            |data: TensorData| Self::Int(B::int_from_data(data.convert::<B::IntElem>(), device));
+
+        let value = match data.data {
+            Data::Float16s(values) => float(TensorData::new(values, shape)),
+            Data::Float32s(values) => float(TensorData::new(values, shape)),
+            Data::Float64s(values) => float(TensorData::new(values, shape)),
+            Data::Int8s(values) => int(TensorData::new(values, shape)),
+            Data::Uint8s(values) => int(TensorData::new(values, shape)),
+            Data::Int32s(values) => int(TensorData::new(values, shape)),
+            Data::Int64s(values) => int(TensorData::new(values, shape)),
+            quantized @ Data::QInt8s { .. } => float(TensorData::new(quantized.into_f32s(), shape)),
+            Data::Bools(values) => {
+                Self::Bool(B::bool_from_data(TensorData::new(values, shape), device))
+            }
+            Data::Float16(value) => Self::Scalar(value.to_f64()),
+            Data::Float32(value) => Self::Scalar(value as f64),
+            Data::Float64(value) => Self::Scalar(value),
+            Data::Int8(value) => Self::Scalar(value as f64),
+            Data::Uint8(value) => Self::Scalar(value as f64),
+            Data::Int32(value) => Self::Scalar(value as f64),
+            Data::Int64(value) => Self::Scalar(value as f64),
+            Data::Bool(value) => Self::Scalar(value as u8 as f64),
+            Data::String(_) | Data::Strings(_) => {
+                return Err("string values are not supported".into());
+            }
+        };
+
+        Ok(value)
+    }
+}
+
+/// A description of an ONNX type, e.g. `float tensor of rank 2`.
+pub(crate) fn describe_type(ty: &ArgType) -> String {
+    match ty {
+        ArgType::Scalar(_) => "scalar".into(),
+        ArgType::Shape(rank) => format!("shape of length {rank}"),
+        ArgType::Tensor(tensor) => {
+            let kind = match ElemKind::from(&tensor.elem_type) {
+                ElemKind::Float => "float",
+                ElemKind::Int => "int",
+                ElemKind::Bool => "bool",
+            };
+            format!("{kind} tensor of rank {}", tensor.rank)
+        }
+    }
+}
+
+/// The tensor kind used to store an ONNX element type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ElemKind {
+    Float,
+    Int,
+    Bool,
+}
+
+impl From<&ElementType> for ElemKind {
+    fn from(elem_type: &ElementType) -> Self {
+        match elem_type {
+            ElementType::Float16 | ElementType::Float32 | ElementType::Float64 => Self::Float,
+            ElementType::Int8 | ElementType::Uint8 | ElementType::Int32 | ElementType::Int64 => {
+                Self::Int
+            }
+            ElementType::Bool => Self::Bool,
+            ElementType::String => panic!("String tensors are not supported"),
+        }
+    }
+}
+
+impl<B: Backend, const D: usize> From<Tensor<B, D>> for OnnxValue<B> {
+    fn from(tensor: Tensor<B, D>) -> Self {
+        Self::Float(tensor.into_primitive().tensor())
+    }
+}
+
+impl<B: Backend, const D: usize> From<Tensor<B, D, Int>> for OnnxValue<B> {
+    fn from(tensor: Tensor<B, D, Int>) -> Self {
+        Self::Int(tensor.into_primitive())
+    }
+}
+
+impl<B: Backend, const D: usize> From<Tensor<B, D, Bool>> for OnnxValue<B> {
+    fn from(tensor: Tensor<B, D, Bool>) -> Self {
+        Self::Bool(tensor.into_primitive())
+    }
+}
+
+impl<B: Backend> From<f64> for OnnxValue<B> {
+    fn from(value: f64) -> Self {
+        Self::Scalar(value)
+    }
+}
+
+impl<B: Backend> From<Vec<i64>> for OnnxValue<B> {
+    fn from(dims: Vec<i64>) -> Self {
+        Self::Shape(dims)
+    }
+}
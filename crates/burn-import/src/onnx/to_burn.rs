@@ -9,7 +9,14 @@ use burn::{
     record::{
         DoublePrecisionSettings, FullPrecisionSettings, HalfPrecisionSettings, PrecisionSettings,
     },
-    tensor::{Element, TensorData},
+    tensor::{
+        Element, TensorData,
+        ops::QuantizedTensor,
+        quantization::{
+            QTensorPrimitive, QuantLevel, QuantParam, QuantValue, QuantizationStrategy,
+            SymmetricQuantization,
+        },
+    },
 };
 use log::warn;
 
@@ -18,11 +25,13 @@ use crate::{
         ScalarKind, ScalarType, ShapeType, TensorKind, TensorType, Type,
         graph::BurnGraph,
         node::{
+            SerializationBackend,
             argmax::ArgMaxNode,
             argmin::ArgMinNode,
             attention::{AttentionNode, AttentionNodeInputs, AttentionNodeOutputs},
             avg_pool1d::AvgPool1dNode,
             avg_pool2d::AvgPool2dNode,
+            avg_pool3d::AvgPool3dNode,
             batch_norm::BatchNormNode,
             bernoulli::BernoulliNode,
             binary::BinaryNode,
@@ -37,32 +46,47 @@ use crate::{
             concat::ConcatNode,
             constant::{ConstantNode, ConstantValue},
             constant_of_shape::ConstantOfShapeNode,
+            conv_integer::ConvIntegerNode,
             conv_transpose_1d::ConvTranspose1dNode,
             conv_transpose_2d::ConvTranspose2dNode,
             conv_transpose_3d::ConvTranspose3dNode,
             conv1d::Conv1dNode,
             conv2d::Conv2dNode,
             conv3d::Conv3dNode,
+            cumsum::CumSumNode,
             depth_to_space::DepthToSpaceNode,
+            dequantize_linear::DequantizeLinearNode,
             dropout::DropoutNode,
+            einsum::EinsumNode,
             expand::ExpandNode,
             floor::FloorNode,
             gather::GatherNode,
             gather_elements::GatherElementsNode,
+            gather_nd::GatherNDNode,
             gemm::GemmNode,
             global_avg_pool::GlobalAvgPoolNode,
             group_norm::GroupNormNode,
+            gru::GruNode,
             identity::IdentityNode,
+            if_op::IfNode,
             instance_norm::InstanceNormNode,
             layer_norm::LayerNormNode,
             linear::LinearNode,
+            loop_op::LoopNode,
+            lstm::LstmNode,
             matmul::MatmulNode,
             matmul_integer::MatMulIntegerNode,
             max_pool1d::MaxPool1dNode,
             max_pool2d::MaxPool2dNode,
+            max_pool3d::MaxPool3dNode,
+            non_max_suppression::NonMaxSuppressionNode,
+            nonzero::NonZeroNode,
             one_hot::OneHotNode,
             pad::PadNode,
             prelu::PReluNode,
+            qlinear_conv::QLinearConvNode,
+            qlinear_matmul::QLinearMatMulNode,
+            quantize_linear::QuantizeLinearNode,
             random_normal::RandomNormalNode,
             random_normal_like::RandomNormalLikeNode,
             random_uniform::RandomUniformNode,
@@ -71,11 +95,16 @@ use crate::{
             reduce::{ReduceNode, ReductionType},
             reshape::ReshapeNode,
             resize::ResizeNode,
+            rnn::{RnnIO, RnnWeights, SequenceLens},
             round::RoundNode,
+            scan::ScanNode,
+            scatter_elements::ScatterElementsNode,
+            scatter_nd::ScatterNDNode,
             slice::SliceNode,
             space_to_depth::SpaceToDepthNode,
             split::SplitNode,
             squeeze::SqueezeNode,
+            subgraph::SubGraph,
             sum::SumNode,
             tile::TileNode,
             top_k::TopKNode,
@@ -98,6 +127,7 @@ use onnx_ir::{
         attention::attention_config,
         avg_pool1d::avg_pool1d_config,
         avg_pool2d::avg_pool2d_config,
+        avg_pool3d::avg_pool3d_config,
         batch_norm::batch_norm_config,
         cast::cast_config,
         clip::clip_config,
@@ -109,28 +139,43 @@ use onnx_ir::{
         conv1d::conv1d_config,
         conv2d::conv2d_config,
         conv3d::conv3d_config,
+        cumsum::cumsum_config,
         depth_to_space::depth_to_space_config,
         dropout::dropout_config,
+        einsum::einsum_config,
         expand::expand_config,
         flatten::flatten_config,
         gather::{GatherInput, gather_config},
+        gather_nd::gather_nd_config,
         gemm::gemm_config,
         group_norm::group_norm_config,
+        gru::gru_config,
         hard_sigmoid::hard_sigmoid_config,
+        if_op::if_config,
         instance_norm::instance_norm_config,
         is_inf::is_inf_config,
         layer_norm::layer_norm_config,
         leaky_relu::leaky_relu_config,
-        linear::linear_config,
+        linear::{LinearConfig, linear_config},
         log_softmax::log_softmax_config,
+        loop_op::loop_config,
+        lstm::lstm_config,
         max_pool1d::max_pool1d_config,
         max_pool2d::max_pool2d_config,
+        max_pool3d::max_pool3d_config,
+        non_max_suppression::non_max_suppression_config,
         one_hot::one_hot_config,
         pad::pad_config,
+        quantization::{
+            QuantizationParams, conv_integer_config, dequantize_data, dequantize_linear_config,
+            qlinear_conv_config, qlinear_matmul_config, quantize_linear_config,
+        },
         range::range_config,
         reduce::reduce_config,
         reshape::reshape_config,
         resize::resize_config,
+        scan::scan_config,
+        scatter::{scatter_elements_config, scatter_nd_config},
         slice::slice_config,
         softmax::softmax_config,
         space_to_depth::space_to_depth_config,
@@ -347,9 +392,37 @@ impl ParsedOnnxGraph {
     pub fn into_burn<PS: PrecisionSettings + 'static>(self) -> BurnGraph<PS> {
         let mut graph = BurnGraph::<PS>::default();
 
+        Self::register_nodes(&mut graph, self.0.nodes);
+
+        // Get input and output names
+        let input_names = self
+            .0
+            .inputs
+            .iter()
+            .map(|input| input.name.clone())
+            .collect::<Vec<_>>();
+
+        let output_names = self
+            .0
+            .outputs
+            .iter()
+            .map(|output| output.name.clone())
+            .collect::<Vec<_>>();
+
+        // Register inputs and outputs with the graph
+        graph.register_input_output(input_names, output_names);
+
+        graph
+    }
+
+    /// Registers the nodes of a graph or subgraph, in execution order.
+    fn register_nodes<PS: PrecisionSettings + 'static>(
+        graph: &mut BurnGraph<PS>,
+        nodes: Vec<Node>,
+    ) {
         let mut unsupported_ops = vec![];
 
-        for node in self.0.nodes {
+        for node in nodes {
             match node.node_type {
                 NodeType::Add => graph.register(Self::add_conversion(node)),
                 NodeType::ArgMax => graph.register(Self::argmax_conversion(node)),
@@ -376,14 +449,25 @@ impl ParsedOnnxGraph {
                 NodeType::Conv1d => graph.register(Self::conv1d_conversion::<PS>(node)),
                 NodeType::Conv2d => graph.register(Self::conv2d_conversion::<PS>(node)),
                 NodeType::Conv3d => graph.register(Self::conv3d_conversion::<PS>(node)),
+                NodeType::ConvInteger => graph.register(Self::conv_integer_conversion::<PS>(node)),
+                NodeType::QLinearConv => graph.register(Self::qlinear_conv_conversion::<PS>(node)),
+                NodeType::QLinearMatMul => {
+                    graph.register(Self::qlinear_matmul_conversion::<PS>(node))
+                }
+                NodeType::QuantizeLinear => graph.register(Self::quantize_linear_conversion(node)),
+                NodeType::DequantizeLinear => {
+                    graph.register(Self::dequantize_linear_conversion(node))
+                }
                 NodeType::DepthToSpace => graph.register(Self::depth_to_space_conversion(node)),
                 NodeType::Max => graph.register(Self::max_conversion(node)),
                 NodeType::MaxPool1d => graph.register(Self::max_pool1d_conversion(node)),
                 NodeType::MaxPool2d => graph.register(Self::max_pool2d_conversion(node)),
+                NodeType::MaxPool3d => graph.register(Self::max_pool3d_conversion(node)),
                 NodeType::Mean => graph.register(Self::mean_conversion(node)),
                 NodeType::PRelu => graph.register(Self::prelu_conversion::<PS>(node)),
                 NodeType::AveragePool1d => graph.register(Self::avg_pool_1d_conversion(node)),
                 NodeType::AveragePool2d => graph.register(Self::avg_pool_2d_conversion(node)),
+                NodeType::AveragePool3d => graph.register(Self::avg_pool_3d_conversion(node)),
                 NodeType::MatMul => graph.register(Self::matmul_conversion(node)),
                 NodeType::MatMulInteger => graph.register(Self::matmul_integer_conversion(node)),
                 NodeType::Neg => graph.register(Self::neg_conversion(node)),
@@ -409,11 +493,24 @@ impl ParsedOnnxGraph {
                 NodeType::GroupNormalization => {
                     graph.register(Self::group_norm_conversion::<PS>(node))
                 }
+                NodeType::LSTM => graph.register(Self::lstm_conversion::<PS>(node)),
+                NodeType::GRU => graph.register(Self::gru_conversion::<PS>(node)),
                 NodeType::Relu => graph.register(Self::relu_conversion(node)),
                 NodeType::Gelu => graph.register(Self::gelu_conversion(node)),
                 NodeType::Flatten => graph.register(Self::flatten_conversion(node)),
                 NodeType::Gather => graph.register(Self::gather_conversion(node)),
                 NodeType::GatherElements => graph.register(Self::gather_elements_conversion(node)),
+                NodeType::GatherND => graph.register(Self::gather_nd_conversion(node)),
+                NodeType::ScatterElements => {
+                    graph.register(Self::scatter_elements_conversion(node))
+                }
+                NodeType::ScatterND => graph.register(Self::scatter_nd_conversion(node)),
+                NodeType::CumSum => graph.register(Self::cumsum_conversion(node)),
+                NodeType::NonZero => graph.register(Self::nonzero_conversion(node)),
+                NodeType::Einsum => graph.register(Self::einsum_conversion(node)),
+                NodeType::NonMaxSuppression => {
+                    graph.register(Self::non_max_suppression_conversion(node))
+                }
                 NodeType::HardSigmoid => graph.register(Self::hard_sigmoid_conversion(node)),
                 NodeType::Log => graph.register(Self::log_conversion(node)),
                 NodeType::LeakyRelu => graph.register(Self::leaky_relu_conversion(node)),
@@ -492,6 +589,9 @@ impl ParsedOnnxGraph {
                 NodeType::IsNaN => graph.register(Self::is_nan_conversion(node)),
                 NodeType::IsInf => graph.register(Self::is_inf_conversion(node)),
                 NodeType::Identity => graph.register(Self::identity_conversion(node)),
+                NodeType::If => graph.register(Self::if_conversion::<PS>(node)),
+                NodeType::Loop => graph.register(Self::loop_conversion::<PS>(node)),
+                NodeType::Scan => graph.register(Self::scan_conversion::<PS>(node)),
                 NodeType::Abs => graph.register(Self::abs_conversion(node)),
                 node_type => unsupported_ops.push(node_type),
             }
@@ -500,26 +600,86 @@ impl ParsedOnnxGraph {
         if !unsupported_ops.is_empty() {
             panic!("Unsupported ops: {unsupported_ops:?}");
         }
+    }
 
-        // Get input and output names
-        let input_names = self
-            .0
-            .inputs
-            .iter()
-            .map(|input| input.name.clone())
-            .collect::<Vec<_>>();
+    /// Converts the subgraph of a control flow node.
+    ///
+    /// The captured values are the inputs of the node coming from the enclosing graphs.
+    fn subgraph_conversion<PS: PrecisionSettings + 'static>(
+        subgraph: OnnxGraph,
+        captured: &[OnnxArgument],
+    ) -> SubGraph<PS> {
+        let mut graph = BurnGraph::<PS>::default();
+        Self::register_nodes(&mut graph, subgraph.nodes);
 
-        let output_names = self
-            .0
-            .outputs
+        SubGraph::new(
+            graph.into_nodes(),
+            subgraph.inputs.iter().map(Type::from).collect(),
+            subgraph.outputs.iter().map(Type::from).collect(),
+            captured.iter().map(Type::from).collect(),
+        )
+    }
+
+    fn if_conversion<PS: PrecisionSettings + 'static>(node: Node) -> IfNode<PS> {
+        let config = if_config(&node);
+        let condition = Type::from(node.inputs.first().unwrap());
+        let captured = &node.inputs[1..];
+        let outputs = node.outputs.iter().map(Type::from).collect();
+
+        IfNode::new(
+            condition,
+            outputs,
+            Self::subgraph_conversion(config.then_branch, captured),
+            Self::subgraph_conversion(config.else_branch, captured),
+        )
+    }
+
+    fn loop_conversion<PS: PrecisionSettings + 'static>(node: Node) -> LoopNode<PS> {
+        let config = loop_config(&node);
+        // The trip count and the condition are optional, and empty names mark absent inputs.
+        let optional = |index: usize| {
+            node.inputs
+                .get(index)
+                .filter(|input| !input.name.is_empty())
+                .map(Type::from)
+        };
+        let max_trip_count = optional(0);
+        let condition = optional(1);
+        let num_explicit = 2 + config.num_carried;
+        let initial_values = node.inputs[2..num_explicit]
             .iter()
-            .map(|output| output.name.clone())
-            .collect::<Vec<_>>();
+            .map(Type::from)
+            .collect();
+        let outputs = node.outputs.iter().map(Type::from).collect();
+        let body = Self::subgraph_conversion(config.body, &node.inputs[num_explicit..]);
 
-        // Register inputs and outputs with the graph
-        graph.register_input_output(input_names, output_names);
+        LoopNode::new(max_trip_count, condition, initial_values, outputs, body)
+    }
 
-        graph
+    fn scan_conversion<PS: PrecisionSettings + 'static>(node: Node) -> ScanNode<PS> {
+        let config = scan_config(&node);
+        let num_explicit = config.body.inputs.len();
+        let initial_states = node.inputs[..config.num_states]
+            .iter()
+            .map(Type::from)
+            .collect();
+        let scan_inputs = node.inputs[config.num_states..num_explicit]
+            .iter()
+            .map(Type::from)
+            .collect();
+        let outputs = node.outputs.iter().map(Type::from).collect();
+        let body = Self::subgraph_conversion(config.body, &node.inputs[num_explicit..]);
+
+        ScanNode::new(
+            initial_states,
+            scan_inputs,
+            outputs,
+            body,
+            config.scan_input_axes,
+            config.scan_input_reverse,
+            config.scan_output_axes,
+            config.scan_output_reverse,
+        )
     }
 
     fn constant_conversion<PS: PrecisionSettings>(node: Node) -> ConstantNode {
@@ -1061,6 +1221,67 @@ impl ParsedOnnxGraph {
         GatherElementsNode::new(input, index, output, config.axis)
     }
 
+    fn gather_nd_conversion(node: Node) -> GatherNDNode {
+        let data = TensorType::from(node.inputs.first().unwrap());
+        let indices = TensorType::from(node.inputs.get(1).unwrap());
+        let output = TensorType::from(node.outputs.first().unwrap());
+        let config = gather_nd_config(&node);
+
+        GatherNDNode::new(data, indices, output, config)
+    }
+
+    fn scatter_elements_conversion(node: Node) -> ScatterElementsNode {
+        let data = TensorType::from(node.inputs.first().unwrap());
+        let indices = TensorType::from(node.inputs.get(1).unwrap());
+        let updates = TensorType::from(node.inputs.get(2).unwrap());
+        let output = TensorType::from(node.outputs.first().unwrap());
+        let config = scatter_elements_config(&node);
+
+        ScatterElementsNode::new(data, indices, updates, output, config)
+    }
+
+    fn scatter_nd_conversion(node: Node) -> ScatterNDNode {
+        let data = TensorType::from(node.inputs.first().unwrap());
+        let indices = TensorType::from(node.inputs.get(1).unwrap());
+        let updates = TensorType::from(node.inputs.get(2).unwrap());
+        let output = TensorType::from(node.outputs.first().unwrap());
+        let config = scatter_nd_config(&node);
+
+        ScatterNDNode::new(data, indices, updates, output, config)
+    }
+
+    fn cumsum_conversion(node: Node) -> CumSumNode {
+        let input = TensorType::from(node.inputs.first().unwrap());
+        let output = TensorType::from(node.outputs.first().unwrap());
+        let config = cumsum_config(&node);
+
+        CumSumNode::new(input, output, config)
+    }
+
+    fn nonzero_conversion(node: Node) -> NonZeroNode {
+        let input = TensorType::from(node.inputs.first().unwrap());
+        let output = TensorType::from(node.outputs.first().unwrap());
+
+        NonZeroNode::new(input, output)
+    }
+
+    fn einsum_conversion(node: Node) -> EinsumNode {
+        let inputs = node.inputs.iter().map(TensorType::from).collect();
+        let output = Type::from(node.outputs.first().unwrap());
+        let config = einsum_config(&node);
+
+        EinsumNode::new(inputs, output, config)
+    }
+
+    fn non_max_suppression_conversion(node: Node) -> NonMaxSuppressionNode {
+        let boxes = TensorType::from(node.inputs.first().unwrap());
+        let scores = TensorType::from(node.inputs.get(1).unwrap());
+        let output = TensorType::from(node.outputs.first().unwrap());
+        let config = non_max_suppression_config(&node);
+
+        NonMaxSuppressionNode::new(boxes, scores, output, config)
+    }
+
     fn transpose_conversion(node: Node) -> UnaryNode {
         let input = Type::from(node.inputs.first().unwrap());
         let output = Type::from(node.outputs.first().unwrap());
@@ -1470,6 +1691,22 @@ impl ParsedOnnxGraph {
         LinearNode::new(name, input, output, weight, bias, config)
     }
 
+    fn lstm_conversion<PS: PrecisionSettings>(node: Node) -> LstmNode {
+        let config = lstm_config(&node);
+        let io = rnn_io(&node, true);
+        let weights = rnn_weights::<PS>(&node);
+
+        LstmNode::new(&node.name, io, weights, config)
+    }
+
+    fn gru_conversion<PS: PrecisionSettings>(node: Node) -> GruNode {
+        let config = gru_config(&node);
+        let io = rnn_io(&node, false);
+        let weights = rnn_weights::<PS>(&node);
+
+        GruNode::new(&node.name, io, weights, config)
+    }
+
     fn dropout_conversion(node: Node) -> DropoutNode {
         let name = &node.name;
         let input = TensorType::from(node.inputs.first().unwrap());
@@ -1586,6 +1823,83 @@ impl ParsedOnnxGraph {
         Conv2dNode::new(name, input, output, weight, bias, config)
     }
 
+    fn quantize_linear_conversion(node: Node) -> QuantizeLinearNode {
+        let input = TensorType::from(node.inputs.first().unwrap());
+        let output = TensorType::from(node.outputs.first().unwrap());
+        let params = quantize_linear_config(&node);
+
+        QuantizeLinearNode::new(input, output, params)
+    }
+
+    fn dequantize_linear_conversion(node: Node) -> DequantizeLinearNode {
+        let input = TensorType::from(node.inputs.first().unwrap());
+        let output = TensorType::from(node.outputs.first().unwrap());
+        let params = dequantize_linear_config(&node);
+
+        DequantizeLinearNode::new(input, output, params)
+    }
+
+    fn qlinear_matmul_conversion<PS: PrecisionSettings>(node: Node) -> QLinearMatMulNode {
+        let input = TensorType::from(node.inputs.first().unwrap());
+        let output = TensorType::from(node.outputs.first().unwrap());
+        let config = qlinear_matmul_config(&node);
+
+        let weight = match node.inputs[3].value.as_ref() {
+            Some(weight) if weight.shape.len() == 2 => dequantize_data(weight, &config.b),
+            _ => panic!("QLinearMatMul: the second input must be a constant 2D tensor"),
+        };
+        let linear_config = LinearConfig::new(weight.shape[0], weight.shape[1]).with_bias(false);
+        let weight = serialize_data::<PS::FloatElem>(weight.data, weight.shape);
+
+        let linear = LinearNode::new(&node.name, input, output, weight, None, linear_config);
+        QLinearMatMulNode::new(linear, config)
+    }
+
+    fn qlinear_conv_conversion<PS: PrecisionSettings>(node: Node) -> QLinearConvNode {
+        let input = TensorType::from(node.inputs.first().unwrap());
+        let output = TensorType::from(node.outputs.first().unwrap());
+        let config = qlinear_conv_config(&node);
+
+        let weight = dequantize_data(node.inputs[3].value.as_ref().unwrap(), &config.w);
+        let weight = serialize_data::<PS::FloatElem>(weight.data, weight.shape);
+
+        // The int32 bias is quantized with the scales of the input and the weights
+        let bias = node
+            .inputs
+            .get(8)
+            .and_then(|bias| bias.value.as_ref())
+            .map(|bias| {
+                let params = QuantizationParams {
+                    scale: config
+                        .w
+                        .scale
+                        .iter()
+                        .map(|w| w * config.x.scale[0])
+                        .collect(),
+                    zero_point: vec![0; config.w.scale.len()],
+                    axis: 0,
+                    elem_type: ElementType::Int32,
+                };
+                let bias = dequantize_data(bias, &params);
+                serialize_data::<PS::FloatElem>(bias.data, bias.shape)
+            });
+
+        let conv = Conv2dNode::new(&node.name, input, output, weight, bias, config.conv.clone());
+        QLinearConvNode::new(conv, config)
+    }
+
+    fn conv_integer_conversion<PS: PrecisionSettings>(node: Node) -> ConvIntegerNode {
+        let input = TensorType::from(node.inputs.first().unwrap());
+        let output = TensorType::from(node.outputs.first().unwrap());
+        let config = conv_integer_config(&node);
+
+        let weight = dequantize_data(node.inputs[1].value.as_ref().unwrap(), &config.w);
+        let weight = serialize_data::<PS::FloatElem>(weight.data, weight.shape);
+
+        let conv = Conv2dNode::new(&node.name, input, output, weight, None, config.conv.clone());
+        ConvIntegerNode::new(conv, config)
+    }
+
     fn conv3d_conversion<PS: PrecisionSettings>(node: Node) -> Conv3dNode {
         let input = TensorType::from(node.inputs.first().unwrap());
         let output = TensorType::from(node.outputs.first().unwrap());
@@ -1630,6 +1944,15 @@ impl ParsedOnnxGraph {
         MaxPool2dNode::new(name, input, output, config)
     }
 
+    fn max_pool3d_conversion(node: Node) -> MaxPool3dNode {
+        let input = TensorType::from(node.inputs.first().unwrap());
+        let output = TensorType::from(node.outputs.first().unwrap());
+        let config = max_pool3d_config(&node);
+
+        let name = &node.name;
+        MaxPool3dNode::new(name, input, output, config)
+    }
+
     fn mean_conversion(node: Node) -> MeanNode {
         let inputs = node.inputs.iter().map(TensorType::from).collect();
         let output = TensorType::from(node.outputs.first().unwrap());
@@ -1736,6 +2059,15 @@ impl ParsedOnnxGraph {
         AvgPool2dNode::new(name, input, output, config)
     }
 
+    fn avg_pool_3d_conversion(node: Node) -> AvgPool3dNode {
+        let input = TensorType::from(node.inputs.first().unwrap());
+        let output = TensorType::from(node.outputs.first().unwrap());
+        let config = avg_pool3d_config(&node);
+
+        let name = &node.name;
+        AvgPool3dNode::new(name, input, output, config)
+    }
+
     fn global_avg_pool_conversion(node: Node) -> GlobalAvgPoolNode {
         let input = TensorType::from(node.inputs.first().unwrap());
         let output = TensorType::from(node.outputs.first().unwrap());
@@ -2013,6 +2345,56 @@ fn extract_data_serialize<E: Element>(input_index: usize, node: &Node) -> Option
     }
 }
 
+/// The inputs and outputs of a recurrent operator (LSTM or GRU).
+///
+/// The optional inputs are `sequence_lens` at index 4, `initial_h` at index 5 and, for LSTM,
+/// `initial_c` at index 6.
+fn rnn_io(node: &Node, cell: bool) -> RnnIO {
+    let optional_input = |index: usize| {
+        node.inputs
+            .get(index)
+            .filter(|input| !input.name.is_empty())
+    };
+    // Constant initial states are only supported when they are zero, which is the default.
+    let initial_state = |index: usize| {
+        let input = optional_input(index)?;
+        match &input.value {
+            None => Some(TensorType::from(input)),
+            Some(value) if value.data.clone().into_f32s().iter().all(|x| *x == 0.0) => None,
+            Some(_) => panic!(
+                "{:?}: only zero constant initial states are supported",
+                node.node_type
+            ),
+        }
+    };
+
+    let sequence_lens = optional_input(4).map(|input| match &input.value {
+        Some(value) => SequenceLens::Static(value.data.clone().into_usizes()),
+        None => SequenceLens::Runtime(TensorType::from(input)),
+    });
+    let initial_c = match cell {
+        true => initial_state(6),
+        false => None,
+    };
+
+    RnnIO::new(
+        TensorType::from(node.inputs.first().unwrap()),
+        sequence_lens,
+        initial_state(5),
+        initial_c,
+        node.outputs.iter().map(TensorType::from).collect(),
+    )
+}
+
+/// The weights `W`, `R` and `B` of a recurrent operator (LSTM or GRU).
+fn rnn_weights<PS: PrecisionSettings>(node: &Node) -> RnnWeights {
+    RnnWeights::new(
+        extract_data_serialize::<PS::FloatElem>(1, node).expect("W is required"),
+        extract_data_serialize::<PS::FloatElem>(2, node).expect("R is required"),
+        extract_data_serialize::<PS::FloatElem>(3, node),
+    )
+}
+
 /// Convert data to `TensorData`.
 fn serialize_data<E: Element>(data: Data, shape: Vec<usize>) -> TensorData {
     match data {
@@ -2021,10 +2403,39 @@ fn serialize_data<E: Element>(data: Data, shape: Vec<usize>) -> TensorData {
         Data::Float64s(val) => TensorData::new(val, shape).convert::<E>(),
         Data::Int32s(val) => TensorData::new(val, shape).convert::<E>(),
         Data::Int64s(val) => TensorData::new(val, shape).convert::<E>(),
+        Data::QInt8s { values, scales } => serialize_quantized_data(values, scales, shape),
         _ => panic!("Unsupported tensor element type"),
     }
 }
 
+/// Convert symmetric int8 values to quantized `TensorData`, where each contiguous block of values
+/// shares a scale.
+fn serialize_quantized_data(values: Vec<i8>, scales: Vec<f32>, shape: Vec<usize>) -> TensorData {
+    let scheme = QuantizedTensor::<SerializationBackend>::default_scheme()
+        .with_value(QuantValue::Q8S)
+        .with_param(QuantParam::F32);
+    let mut symmetric = scales
+        .into_iter()
+        .map(|scale| SymmetricQuantization::init(scale, QuantValue::Q8S))
+        .collect::<Vec<_>>();
+
+    let (strategy, scheme) = match symmetric.len() {
+        1 => (
+            QuantizationStrategy::PerTensorSymmetric(symmetric.remove(0)),
+            scheme.with_level(QuantLevel::Tensor),
+        ),
+        blocks => {
+            let block_size = values.len() / blocks;
+            (
+                QuantizationStrategy::PerBlockSymmetric(symmetric, block_size),
+                scheme.with_level(QuantLevel::Block(block_size)),
+            )
+        }
+    };
+
+    TensorData::quantized(values, shape, strategy, scheme)
+}
+
 /// Convert boolean data to `TensorData`.
 fn serialize_bool_data(data: Data, shape: Vec<usize>) -> TensorData {
     match data {
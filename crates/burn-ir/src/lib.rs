@@ -11,6 +11,8 @@ mod handle;
 mod operation;
 mod tensor;
 
+pub mod optim;
+
 pub use backend::*;
 pub use handle::*;
 pub use operation::*;
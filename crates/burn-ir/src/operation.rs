@@ -27,6 +27,12 @@ pub struct CustomOpIr {
     pub outputs: Vec<TensorIr>,
 }
 
+macro_rules! custom_nodes {
+    ($s:ident $(, $mut:tt)?) => {
+        IntoIterator::into_iter(&$($mut)? $s.inputs).chain(&$($mut)? $s.outputs).collect()
+    };
+}
+
 impl CustomOpIr {
     /// Create a new custom operation intermediate representation.
     pub fn new(id: &'static str, inputs: &[TensorIr], outputs: &[TensorIr]) -> Self {
@@ -52,7 +58,11 @@ impl CustomOpIr {
     }
 
     fn nodes(&self) -> Vec<&TensorIr> {
-        self.inputs.iter().chain(self.outputs.iter()).collect()
+        custom_nodes!(self)
+    }
+
+    fn nodes_mut(&mut self) -> Vec<&mut TensorIr> {
+        custom_nodes!(self, mut)
     }
 }
 
@@ -153,6 +163,8 @@ pub enum ModuleOperationIr {
     AvgPool1d(AvgPool1dOpIr),
     /// Operation corresponding to [avg pool 2d](burn_tensor::ops::ModuleOps::avg_pool2d).
     AvgPool2d(AvgPool2dOpIr),
+    /// Operation corresponding to [avg pool 3d](burn_tensor::ops::ModuleOps::avg_pool3d).
+    AvgPool3d(AvgPool3dOpIr),
     /// Operation corresponding to
     /// [avg pool 1d backward](burn_tensor::ops::ModuleOps::avg_pool1d_backward).
     AvgPool1dBackward(AvgPool1dBackwardOpIr),
@@ -160,18 +172,27 @@ pub enum ModuleOperationIr {
     /// [avg pool 2d backward](burn_tensor::ops::ModuleOps::avg_pool2d_backward).
     AvgPool2dBackward(AvgPool2dBackwardOpIr),
     /// Operation corresponding to
+    /// [avg pool 3d backward](burn_tensor::ops::ModuleOps::avg_pool3d_backward).
+    AvgPool3dBackward(AvgPool3dBackwardOpIr),
+    /// Operation corresponding to
     /// [adaptive avg pool 1d](burn_tensor::ops::ModuleOps::adaptive_avg_pool1d).
     AdaptiveAvgPool1d(AdaptiveAvgPool1dOpIr),
     /// Operation corresponding to
     /// [adaptive avg pool 2d](burn_tensor::ops::ModuleOps::adaptive_avg_pool2d).
     AdaptiveAvgPool2d(AdaptiveAvgPool2dOpIr),
     /// Operation corresponding to
+    /// [adaptive avg pool 3d](burn_tensor::ops::ModuleOps::adaptive_avg_pool3d).
+    AdaptiveAvgPool3d(AdaptiveAvgPool3dOpIr),
+    /// Operation corresponding to
     /// [adaptive avg pool 1d backward](burn_tensor::ops::ModuleOps::adaptive_avg_pool1d_backward).
     AdaptiveAvgPool1dBackward(AdaptiveAvgPool1dBackwardOpIr),
     /// Operation corresponding to
     /// [adaptive avg pool 2d backward](burn_tensor::ops::ModuleOps::adaptive_avg_pool2d_backward).
     AdaptiveAvgPool2dBackward(AdaptiveAvgPool2dBackwardOpIr),
     /// Operation corresponding to
+    /// [adaptive avg pool 3d backward](burn_tensor::ops::ModuleOps::adaptive_avg_pool3d_backward).
+    AdaptiveAvgPool3dBackward(AdaptiveAvgPool3dBackwardOpIr),
+    /// Operation corresponding to
     /// [max pool 1d](burn_tensor::ops::ModuleOps::max_pool1d).
     MaxPool1d(MaxPool1dOpIr),
     /// Operation corresponding to
@@ -189,6 +210,15 @@ pub enum ModuleOperationIr {
     /// Operation corresponding to
     /// [max pool 2d with indices backward](burn_tensor::ops::ModuleOps::max_pool2d_with_indices_backward).
     MaxPool2dWithIndicesBackward(MaxPool2dWithIndicesBackwardOpIr),
+    /// Operation corresponding to
+    /// [max pool 3d](burn_tensor::ops::ModuleOps::max_pool3d).
+    MaxPool3d(MaxPool3dOpIr),
+    /// Operation corresponding to
+    /// [max pool 3d with indices](burn_tensor::ops::ModuleOps::max_pool3d_with_indices).
+    MaxPool3dWithIndices(MaxPool3dWithIndicesOpIr),
+    /// Operation corresponding to
+    /// [max pool 3d with indices backward](burn_tensor::ops::ModuleOps::max_pool3d_with_indices_backward).
+    MaxPool3dWithIndicesBackward(MaxPool3dWithIndicesBackwardOpIr),
     /// Operation corresponding to [interpolate](burn_tensor::ops::ModuleOps::interpolate).
     Interpolate(InterpolateOpIr),
     /// Operation corresponding to [interpolate backward](burn_tensor::ops::ModuleOps::interpolate_backward).
@@ -1183,6 +1213,17 @@ pub struct AvgPool2dOpIr {
     pub out: TensorIr,
 }
 
+#[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub struct AvgPool3dOpIr {
+    pub x: TensorIr,
+    pub kernel_size: [usize; 3],
+    pub stride: [usize; 3],
+    pub padding: [usize; 3],
+    pub count_include_pad: bool,
+    pub out: TensorIr,
+}
+
 #[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
 #[allow(missing_docs)]
 pub struct AvgPool1dBackwardOpIr {
@@ -1207,6 +1248,18 @@ pub struct AvgPool2dBackwardOpIr {
     pub out: TensorIr,
 }
 
+#[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub struct AvgPool3dBackwardOpIr {
+    pub x: TensorIr,
+    pub grad: TensorIr,
+    pub kernel_size: [usize; 3],
+    pub stride: [usize; 3],
+    pub padding: [usize; 3],
+    pub count_include_pad: bool,
+    pub out: TensorIr,
+}
+
 #[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
 #[allow(missing_docs)]
 pub struct AdaptiveAvgPool1dOpIr {
@@ -1223,6 +1276,14 @@ pub struct AdaptiveAvgPool2dOpIr {
     pub out: TensorIr,
 }
 
+#[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub struct AdaptiveAvgPool3dOpIr {
+    pub x: TensorIr,
+    pub output_size: [usize; 3],
+    pub out: TensorIr,
+}
+
 #[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
 #[allow(missing_docs)]
 pub struct AdaptiveAvgPool1dBackwardOpIr {
@@ -1239,6 +1300,14 @@ pub struct AdaptiveAvgPool2dBackwardOpIr {
     pub out: TensorIr,
 }
 
+#[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub struct AdaptiveAvgPool3dBackwardOpIr {
+    pub x: TensorIr,
+    pub grad: TensorIr,
+    pub out: TensorIr,
+}
+
 #[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
 #[allow(missing_docs)]
 pub struct MaxPool1dOpIr {
@@ -1311,6 +1380,42 @@ pub struct MaxPool2dWithIndicesBackwardOpIr {
     pub out: TensorIr,
 }
 
+#[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub struct MaxPool3dOpIr {
+    pub x: TensorIr,
+    pub kernel_size: [usize; 3],
+    pub stride: [usize; 3],
+    pub padding: [usize; 3],
+    pub dilation: [usize; 3],
+    pub out: TensorIr,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub struct MaxPool3dWithIndicesOpIr {
+    pub x: TensorIr,
+    pub kernel_size: [usize; 3],
+    pub stride: [usize; 3],
+    pub padding: [usize; 3],
+    pub dilation: [usize; 3],
+    pub out: TensorIr,
+    pub out_indices: TensorIr,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub struct MaxPool3dWithIndicesBackwardOpIr {
+    pub x: TensorIr,
+    pub grad: TensorIr,
+    pub indices: TensorIr,
+    pub kernel_size: [usize; 3],
+    pub stride: [usize; 3],
+    pub padding: [usize; 3],
+    pub dilation: [usize; 3],
+    pub out: TensorIr,
+}
+
 #[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
 #[allow(missing_docs)]
 pub enum InterpolateModeIr {
@@ -1380,23 +1485,38 @@ pub struct InterpolateBackwardOpIr {
     pub out: TensorIr,
 }
 
+/// Dispatches `nodes` and `nodes_mut` to each kind of operation.
+///
+/// Each kind of operation lists its tensors once in a macro, expanded with `mut` to get mutable
+/// references, so that both methods can't diverge.
+macro_rules! operation_nodes {
+    ($s:ident, $nodes:ident) => {
+        match $s {
+            OperationIr::BaseFloat(repr) => repr.$nodes(),
+            OperationIr::BaseInt(repr) => repr.$nodes(),
+            OperationIr::BaseBool(repr) => repr.$nodes(),
+            OperationIr::NumericFloat(_dtype, repr) => repr.$nodes(),
+            OperationIr::NumericInt(_dtype, repr) => repr.$nodes(),
+            OperationIr::Bool(repr) => repr.$nodes(),
+            OperationIr::Int(repr) => repr.$nodes(),
+            OperationIr::Float(_dtype, repr) => repr.$nodes(),
+            OperationIr::Module(repr) => repr.$nodes(),
+            OperationIr::Init(repr) => repr.$nodes(),
+            OperationIr::Custom(repr) => repr.$nodes(),
+            OperationIr::Drop(repr) => vec![repr],
+        }
+    };
+}
+
 impl OperationIr {
     /// Get all [tensor](TensorIr) involved with the current operation.
     pub fn nodes(&self) -> Vec<&TensorIr> {
-        match self {
-            OperationIr::BaseFloat(repr) => repr.nodes(),
-            OperationIr::BaseInt(repr) => repr.nodes(),
-            OperationIr::BaseBool(repr) => repr.nodes(),
-            OperationIr::NumericFloat(_dtype, repr) => repr.nodes(),
-            OperationIr::NumericInt(_dtype, repr) => repr.nodes(),
-            OperationIr::Bool(repr) => repr.nodes(),
-            OperationIr::Int(repr) => repr.nodes(),
-            OperationIr::Float(_dtype, repr) => repr.nodes(),
-            OperationIr::Module(repr) => repr.nodes(),
-            OperationIr::Init(repr) => repr.nodes(),
-            OperationIr::Custom(repr) => repr.nodes(),
-            OperationIr::Drop(repr) => vec![repr],
-        }
+        operation_nodes!(self, nodes)
+    }
+
+    /// Get mutable references to all [tensors](TensorIr) involved with the current operation.
+    pub fn nodes_mut(&mut self) -> Vec<&mut TensorIr> {
+        operation_nodes!(self, nodes_mut)
     }
 
     /// Set the given nodes that are [read write](super::TensorStatus::ReadWrite) to
@@ -1433,47 +1553,57 @@ impl OperationIr {
     }
 }
 
-impl BaseOperationIr {
-    fn nodes(&self) -> Vec<&TensorIr> {
-        match self {
+macro_rules! base_nodes {
+    ($s:ident $(, $mut:tt)?) => {
+        match $s {
             BaseOperationIr::ToDevice(repr) => vec![repr],
             BaseOperationIr::Reshape(repr) => {
-                vec![&repr.input, &repr.out]
+                vec![&$($mut)? repr.input, &$($mut)? repr.out]
             }
             BaseOperationIr::SwapDims(repr) => {
-                vec![&repr.input, &repr.out]
+                vec![&$($mut)? repr.input, &$($mut)? repr.out]
             }
             BaseOperationIr::Permute(repr) => {
-                vec![&repr.input, &repr.out]
+                vec![&$($mut)? repr.input, &$($mut)? repr.out]
             }
 
             BaseOperationIr::Expand(repr) => {
-                vec![&repr.input, &repr.out]
+                vec![&$($mut)? repr.input, &$($mut)? repr.out]
             }
 
             BaseOperationIr::Flip(repr) => {
-                vec![&repr.input, &repr.out]
+                vec![&$($mut)? repr.input, &$($mut)? repr.out]
             }
             BaseOperationIr::Slice(repr) => {
-                vec![&repr.tensor, &repr.out]
+                vec![&$($mut)? repr.tensor, &$($mut)? repr.out]
             }
             BaseOperationIr::SliceAssign(repr) => {
-                vec![&repr.tensor, &repr.value, &repr.out]
+                vec![&$($mut)? repr.tensor, &$($mut)? repr.value, &$($mut)? repr.out]
             }
             BaseOperationIr::Equal(repr) => {
-                vec![&repr.lhs, &repr.rhs, &repr.out]
+                vec![&$($mut)? repr.lhs, &$($mut)? repr.rhs, &$($mut)? repr.out]
             }
             BaseOperationIr::RepeatDim(repr) => {
-                vec![&repr.tensor, &repr.out]
+                vec![&$($mut)? repr.tensor, &$($mut)? repr.out]
             }
             BaseOperationIr::Cat(repr) => {
-                let mut tensors: Vec<_> = repr.tensors.iter().collect();
-                tensors.push(&repr.out);
+                let mut tensors: Vec<_> = IntoIterator::into_iter(&$($mut)? repr.tensors).collect();
+                tensors.push(&$($mut)? repr.out);
                 tensors
             }
-            BaseOperationIr::Cast(repr) => vec![&repr.input, &repr.out],
+            BaseOperationIr::Cast(repr) => vec![&$($mut)? repr.input, &$($mut)? repr.out],
             BaseOperationIr::Empty(repr) => vec![repr],
         }
+    };
+}
+
+impl BaseOperationIr {
+    fn nodes(&self) -> Vec<&TensorIr> {
+        base_nodes!(self)
+    }
+
+    fn nodes_mut(&mut self) -> Vec<&mut TensorIr> {
+        base_nodes!(self, mut)
     }
 
     fn mark_read_only(&mut self, nodes: &[TensorId]) -> Vec<TensorIr> {
@@ -1529,148 +1659,173 @@ impl BaseOperationIr {
     }
 }
 
-impl<E: Element> NumericOperationIr<E> {
-    fn nodes(&self) -> Vec<&TensorIr> {
-        match self {
+macro_rules! numeric_nodes {
+    ($s:ident $(, $mut:tt)?) => {
+        match $s {
             NumericOperationIr::Add(repr) => {
-                vec![&repr.lhs, &repr.rhs, &repr.out]
+                vec![&$($mut)? repr.lhs, &$($mut)? repr.rhs, &$($mut)? repr.out]
             }
             NumericOperationIr::AddScalar(repr) => {
-                vec![&repr.lhs, &repr.out]
+                vec![&$($mut)? repr.lhs, &$($mut)? repr.out]
             }
             NumericOperationIr::Sub(repr) => {
-                vec![&repr.lhs, &repr.rhs, &repr.out]
+                vec![&$($mut)? repr.lhs, &$($mut)? repr.rhs, &$($mut)? repr.out]
             }
             NumericOperationIr::SubScalar(repr) => {
-                vec![&repr.lhs, &repr.out]
+                vec![&$($mut)? repr.lhs, &$($mut)? repr.out]
             }
             NumericOperationIr::Mul(repr) => {
-                vec![&repr.lhs, &repr.rhs, &repr.out]
+                vec![&$($mut)? repr.lhs, &$($mut)? repr.rhs, &$($mut)? repr.out]
             }
             NumericOperationIr::MulScalar(repr) => {
-                vec![&repr.lhs, &repr.out]
+                vec![&$($mut)? repr.lhs, &$($mut)? repr.out]
             }
             NumericOperationIr::Div(repr) => {
-                vec![&repr.lhs, &repr.rhs, &repr.out]
+                vec![&$($mut)? repr.lhs, &$($mut)? repr.rhs, &$($mut)? repr.out]
             }
             NumericOperationIr::DivScalar(repr) => {
-                vec![&repr.lhs, &repr.out]
+                vec![&$($mut)? repr.lhs, &$($mut)? repr.out]
             }
             NumericOperationIr::Rem(repr) => {
-                vec![&repr.lhs, &repr.rhs, &repr.out]
+                vec![&$($mut)? repr.lhs, &$($mut)? repr.rhs, &$($mut)? repr.out]
             }
             NumericOperationIr::RemScalar(repr) => {
-                vec![&repr.lhs, &repr.out]
+                vec![&$($mut)? repr.lhs, &$($mut)? repr.out]
             }
             NumericOperationIr::Ones(repr) => vec![repr],
             NumericOperationIr::Gather(repr) => {
-                vec![&repr.tensor, &repr.indices, &repr.out]
+                vec![&$($mut)? repr.tensor, &$($mut)? repr.indices, &$($mut)? repr.out]
             }
             NumericOperationIr::Scatter(repr) => {
-                vec![&repr.tensor, &repr.indices, &repr.value, &repr.out]
+                vec![
+                    &$($mut)? repr.tensor,
+                    &$($mut)? repr.indices,
+                    &$($mut)? repr.value,
+                    &$($mut)? repr.out,
+                ]
             }
             NumericOperationIr::Select(repr) => {
-                vec![&repr.tensor, &repr.indices, &repr.out]
+                vec![&$($mut)? repr.tensor, &$($mut)? repr.indices, &$($mut)? repr.out]
             }
             NumericOperationIr::SelectAssign(repr) => {
-                vec![&repr.tensor, &repr.indices, &repr.value, &repr.out]
+                vec![
+                    &$($mut)? repr.tensor,
+                    &$($mut)? repr.indices,
+                    &$($mut)? repr.value,
+                    &$($mut)? repr.out,
+                ]
             }
             NumericOperationIr::MaskWhere(repr) => {
-                vec![&repr.tensor, &repr.mask, &repr.value, &repr.out]
+                vec![
+                    &$($mut)? repr.tensor,
+                    &$($mut)? repr.mask,
+                    &$($mut)? repr.value,
+                    &$($mut)? repr.out,
+                ]
             }
             NumericOperationIr::MaskFill(repr) => {
-                vec![&repr.tensor, &repr.mask, &repr.out]
+                vec![&$($mut)? repr.tensor, &$($mut)? repr.mask, &$($mut)? repr.out]
             }
             NumericOperationIr::EqualElem(repr) => {
-                vec![&repr.lhs, &repr.out]
+                vec![&$($mut)? repr.lhs, &$($mut)? repr.out]
             }
             NumericOperationIr::GreaterElem(repr) => {
-                vec![&repr.lhs, &repr.out]
+                vec![&$($mut)? repr.lhs, &$($mut)? repr.out]
             }
             NumericOperationIr::GreaterEqualElem(repr) => {
-                vec![&repr.lhs, &repr.out]
+                vec![&$($mut)? repr.lhs, &$($mut)? repr.out]
             }
             NumericOperationIr::LowerElem(repr) => {
-                vec![&repr.lhs, &repr.out]
+                vec![&$($mut)? repr.lhs, &$($mut)? repr.out]
             }
             NumericOperationIr::LowerEqualElem(repr) => {
-                vec![&repr.lhs, &repr.out]
+                vec![&$($mut)? repr.lhs, &$($mut)? repr.out]
             }
             NumericOperationIr::Greater(repr) => {
-                vec![&repr.lhs, &repr.rhs, &repr.out]
+                vec![&$($mut)? repr.lhs, &$($mut)? repr.rhs, &$($mut)? repr.out]
             }
             NumericOperationIr::GreaterEqual(repr) => {
-                vec![&repr.lhs, &repr.rhs, &repr.out]
+                vec![&$($mut)? repr.lhs, &$($mut)? repr.rhs, &$($mut)? repr.out]
             }
             NumericOperationIr::Lower(repr) => {
-                vec![&repr.lhs, &repr.rhs, &repr.out]
+                vec![&$($mut)? repr.lhs, &$($mut)? repr.rhs, &$($mut)? repr.out]
             }
             NumericOperationIr::LowerEqual(repr) => {
-                vec![&repr.lhs, &repr.rhs, &repr.out]
+                vec![&$($mut)? repr.lhs, &$($mut)? repr.rhs, &$($mut)? repr.out]
             }
             NumericOperationIr::ArgMax(repr) => {
-                vec![&repr.input, &repr.out]
+                vec![&$($mut)? repr.input, &$($mut)? repr.out]
             }
             NumericOperationIr::ArgMin(repr) => {
-                vec![&repr.input, &repr.out]
+                vec![&$($mut)? repr.input, &$($mut)? repr.out]
             }
             NumericOperationIr::Clamp(repr) => {
-                vec![&repr.tensor, &repr.out]
+                vec![&$($mut)? repr.tensor, &$($mut)? repr.out]
             }
             NumericOperationIr::Abs(repr) => {
-                vec![&repr.input, &repr.out]
+                vec![&$($mut)? repr.input, &$($mut)? repr.out]
             }
             NumericOperationIr::Zeros(repr) => vec![repr],
-            NumericOperationIr::Full(repr) => vec![&repr.0],
+            NumericOperationIr::Full(repr) => vec![&$($mut)? repr.0],
             NumericOperationIr::MeanDim(repr) => {
-                vec![&repr.input, &repr.out]
+                vec![&$($mut)? repr.input, &$($mut)? repr.out]
             }
             NumericOperationIr::Mean(repr) => {
-                vec![&repr.input, &repr.out]
+                vec![&$($mut)? repr.input, &$($mut)? repr.out]
             }
             NumericOperationIr::Sum(repr) => {
-                vec![&repr.input, &repr.out]
+                vec![&$($mut)? repr.input, &$($mut)? repr.out]
             }
             NumericOperationIr::SumDim(repr) => {
-                vec![&repr.input, &repr.out]
+                vec![&$($mut)? repr.input, &$($mut)? repr.out]
             }
             NumericOperationIr::Prod(repr) => {
-                vec![&repr.input, &repr.out]
+                vec![&$($mut)? repr.input, &$($mut)? repr.out]
             }
             NumericOperationIr::ProdDim(repr) => {
-                vec![&repr.input, &repr.out]
+                vec![&$($mut)? repr.input, &$($mut)? repr.out]
             }
             NumericOperationIr::Max(repr) => {
-                vec![&repr.input, &repr.out]
+                vec![&$($mut)? repr.input, &$($mut)? repr.out]
             }
             NumericOperationIr::MaxDimWithIndices(repr) => {
-                vec![&repr.tensor, &repr.out_indices, &repr.out]
+                vec![&$($mut)? repr.tensor, &$($mut)? repr.out_indices, &$($mut)? repr.out]
             }
             NumericOperationIr::MinDimWithIndices(repr) => {
-                vec![&repr.tensor, &repr.out_indices, &repr.out]
+                vec![&$($mut)? repr.tensor, &$($mut)? repr.out_indices, &$($mut)? repr.out]
             }
             NumericOperationIr::Min(repr) => {
-                vec![&repr.input, &repr.out]
+                vec![&$($mut)? repr.input, &$($mut)? repr.out]
             }
             NumericOperationIr::MaxDim(repr) => {
-                vec![&repr.input, &repr.out]
+                vec![&$($mut)? repr.input, &$($mut)? repr.out]
             }
             NumericOperationIr::MinDim(repr) => {
-                vec![&repr.input, &repr.out]
+                vec![&$($mut)? repr.input, &$($mut)? repr.out]
             }
             NumericOperationIr::MaxAbs(repr) => {
-                vec![&repr.input, &repr.out]
+                vec![&$($mut)? repr.input, &$($mut)? repr.out]
             }
             NumericOperationIr::MaxAbsDim(repr) => {
-                vec![&repr.input, &repr.out]
+                vec![&$($mut)? repr.input, &$($mut)? repr.out]
             }
             NumericOperationIr::IntRandom(repr) => {
-                vec![&repr.out]
+                vec![&$($mut)? repr.out]
             }
             NumericOperationIr::Powf(repr) => {
-                vec![&repr.lhs, &repr.rhs, &repr.out]
+                vec![&$($mut)? repr.lhs, &$($mut)? repr.rhs, &$($mut)? repr.out]
             }
         }
+    };
+}
+
+impl<E: Element> NumericOperationIr<E> {
+    fn nodes(&self) -> Vec<&TensorIr> {
+        numeric_nodes!(self)
+    }
+
+    fn nodes_mut(&mut self) -> Vec<&mut TensorIr> {
+        numeric_nodes!(self, mut)
     }
     fn mark_read_only(&mut self, nodes: &[TensorId]) -> Vec<TensorIr> {
         let mut output = Vec::new();
@@ -1837,30 +1992,44 @@ impl<E: Element> NumericOperationIr<E> {
     }
 }
 
-impl FloatOperationIr {
-    fn nodes(&self) -> Vec<&TensorIr> {
-        match self {
+macro_rules! float_nodes {
+    ($s:ident $(, $mut:tt)?) => {
+        match $s {
             FloatOperationIr::Matmul(repr) => {
-                vec![&repr.lhs, &repr.rhs, &repr.out]
-            }
-            FloatOperationIr::Random(repr) => vec![&repr.out],
-            FloatOperationIr::Exp(repr) => vec![&repr.input, &repr.out],
-            FloatOperationIr::Log(repr) => vec![&repr.input, &repr.out],
-            FloatOperationIr::Log1p(repr) => vec![&repr.input, &repr.out],
-            FloatOperationIr::Erf(repr) => vec![&repr.input, &repr.out],
-            FloatOperationIr::Recip(repr) => vec![&repr.input, &repr.out],
-            FloatOperationIr::PowfScalar(repr) => vec![&repr.lhs, &repr.out],
-            FloatOperationIr::Sqrt(repr) => vec![&repr.input, &repr.out],
-            FloatOperationIr::Cos(repr) => vec![&repr.input, &repr.out],
-            FloatOperationIr::Sin(repr) => vec![&repr.input, &repr.out],
-            FloatOperationIr::Tanh(repr) => vec![&repr.input, &repr.out],
-            FloatOperationIr::Round(repr) => vec![&repr.input, &repr.out],
-            FloatOperationIr::Floor(repr) => vec![&repr.input, &repr.out],
-            FloatOperationIr::Ceil(repr) => vec![&repr.input, &repr.out],
-            FloatOperationIr::IntoInt(repr) => vec![&repr.input, &repr.out],
-            FloatOperationIr::Quantize(repr) => vec![&repr.tensor, &repr.qparams.scales, &repr.out],
-            FloatOperationIr::Dequantize(repr) => vec![&repr.input, &repr.out],
+                vec![&$($mut)? repr.lhs, &$($mut)? repr.rhs, &$($mut)? repr.out]
+            }
+            FloatOperationIr::Random(repr) => vec![&$($mut)? repr.out],
+            FloatOperationIr::Exp(repr) => vec![&$($mut)? repr.input, &$($mut)? repr.out],
+            FloatOperationIr::Log(repr) => vec![&$($mut)? repr.input, &$($mut)? repr.out],
+            FloatOperationIr::Log1p(repr) => vec![&$($mut)? repr.input, &$($mut)? repr.out],
+            FloatOperationIr::Erf(repr) => vec![&$($mut)? repr.input, &$($mut)? repr.out],
+            FloatOperationIr::Recip(repr) => vec![&$($mut)? repr.input, &$($mut)? repr.out],
+            FloatOperationIr::PowfScalar(repr) => vec![&$($mut)? repr.lhs, &$($mut)? repr.out],
+            FloatOperationIr::Sqrt(repr) => vec![&$($mut)? repr.input, &$($mut)? repr.out],
+            FloatOperationIr::Cos(repr) => vec![&$($mut)? repr.input, &$($mut)? repr.out],
+            FloatOperationIr::Sin(repr) => vec![&$($mut)? repr.input, &$($mut)? repr.out],
+            FloatOperationIr::Tanh(repr) => vec![&$($mut)? repr.input, &$($mut)? repr.out],
+            FloatOperationIr::Round(repr) => vec![&$($mut)? repr.input, &$($mut)? repr.out],
+            FloatOperationIr::Floor(repr) => vec![&$($mut)? repr.input, &$($mut)? repr.out],
+            FloatOperationIr::Ceil(repr) => vec![&$($mut)? repr.input, &$($mut)? repr.out],
+            FloatOperationIr::IntoInt(repr) => vec![&$($mut)? repr.input, &$($mut)? repr.out],
+            FloatOperationIr::Quantize(repr) => vec![
+                &$($mut)? repr.tensor,
+                &$($mut)? repr.qparams.scales,
+                &$($mut)? repr.out,
+            ],
+            FloatOperationIr::Dequantize(repr) => vec![&$($mut)? repr.input, &$($mut)? repr.out],
         }
+    };
+}
+
+impl FloatOperationIr {
+    fn nodes(&self) -> Vec<&TensorIr> {
+        float_nodes!(self)
+    }
+
+    fn nodes_mut(&mut self) -> Vec<&mut TensorIr> {
+        float_nodes!(self, mut)
     }
 
     fn mark_read_only(&mut self, nodes: &[TensorId]) -> Vec<TensorIr> {
@@ -1927,47 +2096,57 @@ impl FloatOperationIr {
     }
 }
 
-impl IntOperationIr {
-    fn nodes(&self) -> Vec<&TensorIr> {
-        match self {
+macro_rules! int_nodes {
+    ($s:ident $(, $mut:tt)?) => {
+        match $s {
             IntOperationIr::Matmul(repr) => {
-                vec![&repr.lhs, &repr.rhs, &repr.out]
+                vec![&$($mut)? repr.lhs, &$($mut)? repr.rhs, &$($mut)? repr.out]
             }
-            IntOperationIr::IntoFloat(repr) => vec![&repr.input, &repr.out],
+            IntOperationIr::IntoFloat(repr) => vec![&$($mut)? repr.input, &$($mut)? repr.out],
             IntOperationIr::BitwiseAnd(repr) => {
-                vec![&repr.lhs, &repr.rhs, &repr.out]
+                vec![&$($mut)? repr.lhs, &$($mut)? repr.rhs, &$($mut)? repr.out]
             }
             IntOperationIr::BitwiseAndScalar(repr) => {
-                vec![&repr.lhs, &repr.out]
+                vec![&$($mut)? repr.lhs, &$($mut)? repr.out]
             }
             IntOperationIr::BitwiseOr(repr) => {
-                vec![&repr.lhs, &repr.rhs, &repr.out]
+                vec![&$($mut)? repr.lhs, &$($mut)? repr.rhs, &$($mut)? repr.out]
             }
             IntOperationIr::BitwiseOrScalar(repr) => {
-                vec![&repr.lhs, &repr.out]
+                vec![&$($mut)? repr.lhs, &$($mut)? repr.out]
             }
             IntOperationIr::BitwiseXor(repr) => {
-                vec![&repr.lhs, &repr.rhs, &repr.out]
+                vec![&$($mut)? repr.lhs, &$($mut)? repr.rhs, &$($mut)? repr.out]
             }
             IntOperationIr::BitwiseXorScalar(repr) => {
-                vec![&repr.lhs, &repr.out]
+                vec![&$($mut)? repr.lhs, &$($mut)? repr.out]
             }
             IntOperationIr::BitwiseNot(repr) => {
-                vec![&repr.input, &repr.out]
+                vec![&$($mut)? repr.input, &$($mut)? repr.out]
             }
             IntOperationIr::BitwiseLeftShift(repr) => {
-                vec![&repr.lhs, &repr.rhs, &repr.out]
+                vec![&$($mut)? repr.lhs, &$($mut)? repr.rhs, &$($mut)? repr.out]
             }
             IntOperationIr::BitwiseLeftShiftScalar(repr) => {
-                vec![&repr.lhs, &repr.out]
+                vec![&$($mut)? repr.lhs, &$($mut)? repr.out]
             }
             IntOperationIr::BitwiseRightShift(repr) => {
-                vec![&repr.lhs, &repr.rhs, &repr.out]
+                vec![&$($mut)? repr.lhs, &$($mut)? repr.rhs, &$($mut)? repr.out]
             }
             IntOperationIr::BitwiseRightShiftScalar(repr) => {
-                vec![&repr.lhs, &repr.out]
+                vec![&$($mut)? repr.lhs, &$($mut)? repr.out]
             }
         }
+    };
+}
+
+impl IntOperationIr {
+    fn nodes(&self) -> Vec<&TensorIr> {
+        int_nodes!(self)
+    }
+
+    fn nodes_mut(&mut self) -> Vec<&mut TensorIr> {
+        int_nodes!(self, mut)
     }
 
     fn mark_read_only(&mut self, nodes: &[TensorId]) -> Vec<TensorIr> {
@@ -2025,17 +2204,35 @@ impl IntOperationIr {
     }
 }
 
-impl BoolOperationIr {
-    fn nodes(&self) -> Vec<&TensorIr> {
-        match self {
+macro_rules! bool_nodes {
+    ($s:ident $(, $mut:tt)?) => {
+        match $s {
             BoolOperationIr::Zeros(repr) => vec![repr],
             BoolOperationIr::Ones(repr) => vec![repr],
-            BoolOperationIr::IntoFloat(repr) => vec![&repr.input, &repr.out],
-            BoolOperationIr::IntoInt(repr) => vec![&repr.input, &repr.out],
-            BoolOperationIr::Not(repr) => vec![&repr.input, &repr.out],
-            BoolOperationIr::And(repr) => vec![&repr.lhs, &repr.rhs, &repr.out],
-            BoolOperationIr::Or(repr) => vec![&repr.lhs, &repr.rhs, &repr.out],
+            BoolOperationIr::IntoFloat(repr) => vec![&$($mut)? repr.input, &$($mut)? repr.out],
+            BoolOperationIr::IntoInt(repr) => vec![&$($mut)? repr.input, &$($mut)? repr.out],
+            BoolOperationIr::Not(repr) => vec![&$($mut)? repr.input, &$($mut)? repr.out],
+            BoolOperationIr::And(repr) => vec![
+                &$($mut)? repr.lhs,
+                &$($mut)? repr.rhs,
+                &$($mut)? repr.out,
+            ],
+            BoolOperationIr::Or(repr) => vec![
+                &$($mut)? repr.lhs,
+                &$($mut)? repr.rhs,
+                &$($mut)? repr.out,
+            ],
         }
+    };
+}
+
+impl BoolOperationIr {
+    fn nodes(&self) -> Vec<&TensorIr> {
+        bool_nodes!(self)
+    }
+
+    fn nodes_mut(&mut self) -> Vec<&mut TensorIr> {
+        bool_nodes!(self, mut)
     }
     fn mark_read_only(&mut self, nodes: &[TensorId]) -> Vec<TensorIr> {
         let mut output = Vec::new();
@@ -2066,128 +2263,202 @@ impl BoolOperationIr {
     }
 }
 
-impl ModuleOperationIr {
-    fn nodes(&self) -> Vec<&TensorIr> {
-        match self {
+macro_rules! module_nodes {
+    ($s:ident $(, $mut:tt)?) => {
+        match $s {
             ModuleOperationIr::Embedding(repr) => {
-                vec![&repr.weights, &repr.indices, &repr.out]
+                vec![&$($mut)? repr.weights, &$($mut)? repr.indices, &$($mut)? repr.out]
             }
             ModuleOperationIr::EmbeddingBackward(repr) => {
-                vec![&repr.weights, &repr.out_grad, &repr.indices, &repr.out]
+                vec![
+                    &$($mut)? repr.weights,
+                    &$($mut)? repr.out_grad,
+                    &$($mut)? repr.indices,
+                    &$($mut)? repr.out,
+                ]
             }
             ModuleOperationIr::Conv1d(repr) => {
-                if let Some(bias) = &repr.bias {
-                    vec![&repr.x, &repr.weight, &bias, &repr.out]
+                if let Some(bias) = &$($mut)? repr.bias {
+                    vec![&$($mut)? repr.x, &$($mut)? repr.weight, bias, &$($mut)? repr.out]
                 } else {
-                    vec![&repr.x, &repr.weight, &repr.out]
+                    vec![&$($mut)? repr.x, &$($mut)? repr.weight, &$($mut)? repr.out]
                 }
             }
             ModuleOperationIr::Conv2d(repr) => {
-                if let Some(bias) = &repr.bias {
-                    vec![&repr.x, &repr.weight, &bias, &repr.out]
+                if let Some(bias) = &$($mut)? repr.bias {
+                    vec![&$($mut)? repr.x, &$($mut)? repr.weight, bias, &$($mut)? repr.out]
                 } else {
-                    vec![&repr.x, &repr.weight, &repr.out]
+                    vec![&$($mut)? repr.x, &$($mut)? repr.weight, &$($mut)? repr.out]
                 }
             }
             ModuleOperationIr::Conv3d(repr) => {
-                if let Some(bias) = &repr.bias {
-                    vec![&repr.x, &repr.weight, &bias, &repr.out]
+                if let Some(bias) = &$($mut)? repr.bias {
+                    vec![&$($mut)? repr.x, &$($mut)? repr.weight, bias, &$($mut)? repr.out]
                 } else {
-                    vec![&repr.x, &repr.weight, &repr.out]
+                    vec![&$($mut)? repr.x, &$($mut)? repr.weight, &$($mut)? repr.out]
                 }
             }
-            ModuleOperationIr::DeformableConv2d(repr) => match (&repr.mask, &repr.bias) {
-                (Some(mask), Some(bias)) => vec![&repr.x, &repr.offset, &repr.weight, &mask, &bias],
-                (Some(mask), None) => vec![&repr.x, &repr.offset, &repr.weight, &mask],
-                (None, Some(bias)) => vec![&repr.x, &repr.offset, &repr.weight, &bias],
-                (None, None) => vec![&repr.x, &repr.offset, &repr.weight],
+            ModuleOperationIr::DeformableConv2d(repr) => match (
+                &$($mut)? repr.mask,
+                &$($mut)? repr.bias,
+            ) {
+                (Some(mask), Some(bias)) => vec![
+                    &$($mut)? repr.x,
+                    &$($mut)? repr.offset,
+                    &$($mut)? repr.weight,
+                    mask,
+                    bias,
+                ],
+                (Some(mask), None) => vec![
+                    &$($mut)? repr.x,
+                    &$($mut)? repr.offset,
+                    &$($mut)? repr.weight,
+                    mask,
+                ],
+                (None, Some(bias)) => vec![
+                    &$($mut)? repr.x,
+                    &$($mut)? repr.offset,
+                    &$($mut)? repr.weight,
+                    bias,
+                ],
+                (None, None) => vec![
+                    &$($mut)? repr.x,
+                    &$($mut)? repr.offset,
+                    &$($mut)? repr.weight,
+                ],
             },
             ModuleOperationIr::DeformableConv2dBackward(repr) => {
                 let mut nodes = Vec::with_capacity(6);
-                nodes.push(&repr.x);
-                nodes.push(&repr.offset);
-                nodes.push(&repr.weight);
-                nodes.push(&repr.out_grad);
+                nodes.push(&$($mut)? repr.x);
+                nodes.push(&$($mut)? repr.offset);
+                nodes.push(&$($mut)? repr.weight);
+                nodes.push(&$($mut)? repr.out_grad);
 
-                if let Some(mask) = repr.mask.as_ref() {
+                if let Some(mask) = &$($mut)? repr.mask {
                     nodes.push(mask);
                 }
-                if let Some(bias) = repr.bias.as_ref() {
+                if let Some(bias) = &$($mut)? repr.bias {
                     nodes.push(bias);
                 }
 
                 nodes
             }
             ModuleOperationIr::ConvTranspose1d(repr) => {
-                if let Some(bias) = &repr.bias {
-                    vec![&repr.x, &repr.weight, &bias, &repr.out]
+                if let Some(bias) = &$($mut)? repr.bias {
+                    vec![&$($mut)? repr.x, &$($mut)? repr.weight, bias, &$($mut)? repr.out]
                 } else {
-                    vec![&repr.x, &repr.weight, &repr.out]
+                    vec![&$($mut)? repr.x, &$($mut)? repr.weight, &$($mut)? repr.out]
                 }
             }
             ModuleOperationIr::ConvTranspose2d(repr) => {
-                if let Some(bias) = &repr.bias {
-                    vec![&repr.x, &repr.weight, &bias, &repr.out]
+                if let Some(bias) = &$($mut)? repr.bias {
+                    vec![&$($mut)? repr.x, &$($mut)? repr.weight, bias, &$($mut)? repr.out]
                 } else {
-                    vec![&repr.x, &repr.weight, &repr.out]
+                    vec![&$($mut)? repr.x, &$($mut)? repr.weight, &$($mut)? repr.out]
                 }
             }
             ModuleOperationIr::ConvTranspose3d(repr) => {
-                if let Some(bias) = &repr.bias {
-                    vec![&repr.x, &repr.weight, &bias, &repr.out]
+                if let Some(bias) = &$($mut)? repr.bias {
+                    vec![&$($mut)? repr.x, &$($mut)? repr.weight, bias, &$($mut)? repr.out]
                 } else {
-                    vec![&repr.x, &repr.weight, &repr.out]
+                    vec![&$($mut)? repr.x, &$($mut)? repr.weight, &$($mut)? repr.out]
                 }
             }
             ModuleOperationIr::AvgPool1d(repr) => {
-                vec![&repr.x, &repr.out]
+                vec![&$($mut)? repr.x, &$($mut)? repr.out]
             }
             ModuleOperationIr::AvgPool2d(repr) => {
-                vec![&repr.x, &repr.out]
+                vec![&$($mut)? repr.x, &$($mut)? repr.out]
+            }
+            ModuleOperationIr::AvgPool3d(repr) => {
+                vec![&$($mut)? repr.x, &$($mut)? repr.out]
             }
             ModuleOperationIr::AvgPool1dBackward(repr) => {
-                vec![&repr.x, &repr.out, &repr.grad]
+                vec![&$($mut)? repr.x, &$($mut)? repr.out, &$($mut)? repr.grad]
             }
             ModuleOperationIr::AvgPool2dBackward(repr) => {
-                vec![&repr.x, &repr.out, &repr.grad]
+                vec![&$($mut)? repr.x, &$($mut)? repr.out, &$($mut)? repr.grad]
+            }
+            ModuleOperationIr::AvgPool3dBackward(repr) => {
+                vec![&$($mut)? repr.x, &$($mut)? repr.out, &$($mut)? repr.grad]
             }
             ModuleOperationIr::AdaptiveAvgPool1d(repr) => {
-                vec![&repr.x, &repr.out]
+                vec![&$($mut)? repr.x, &$($mut)? repr.out]
             }
             ModuleOperationIr::AdaptiveAvgPool2d(repr) => {
-                vec![&repr.x, &repr.out]
+                vec![&$($mut)? repr.x, &$($mut)? repr.out]
+            }
+            ModuleOperationIr::AdaptiveAvgPool3d(repr) => {
+                vec![&$($mut)? repr.x, &$($mut)? repr.out]
             }
             ModuleOperationIr::AdaptiveAvgPool1dBackward(repr) => {
-                vec![&repr.x, &repr.out, &repr.grad]
+                vec![&$($mut)? repr.x, &$($mut)? repr.out, &$($mut)? repr.grad]
             }
             ModuleOperationIr::AdaptiveAvgPool2dBackward(repr) => {
-                vec![&repr.x, &repr.out, &repr.grad]
+                vec![&$($mut)? repr.x, &$($mut)? repr.out, &$($mut)? repr.grad]
+            }
+            ModuleOperationIr::AdaptiveAvgPool3dBackward(repr) => {
+                vec![&$($mut)? repr.x, &$($mut)? repr.out, &$($mut)? repr.grad]
             }
             ModuleOperationIr::MaxPool1d(repr) => {
-                vec![&repr.x, &repr.out]
+                vec![&$($mut)? repr.x, &$($mut)? repr.out]
             }
             ModuleOperationIr::MaxPool1dWithIndices(repr) => {
-                vec![&repr.x, &repr.out, &repr.out_indices]
+                vec![&$($mut)? repr.x, &$($mut)? repr.out, &$($mut)? repr.out_indices]
             }
             ModuleOperationIr::MaxPool1dWithIndicesBackward(repr) => {
-                vec![&repr.x, &repr.out, &repr.indices, &repr.grad]
+                vec![
+                    &$($mut)? repr.x,
+                    &$($mut)? repr.out,
+                    &$($mut)? repr.indices,
+                    &$($mut)? repr.grad,
+                ]
             }
             ModuleOperationIr::MaxPool2d(repr) => {
-                vec![&repr.x, &repr.out]
+                vec![&$($mut)? repr.x, &$($mut)? repr.out]
             }
             ModuleOperationIr::MaxPool2dWithIndices(repr) => {
-                vec![&repr.x, &repr.out, &repr.out_indices]
+                vec![&$($mut)? repr.x, &$($mut)? repr.out, &$($mut)? repr.out_indices]
             }
             ModuleOperationIr::MaxPool2dWithIndicesBackward(repr) => {
-                vec![&repr.x, &repr.out, &repr.indices, &repr.grad]
+                vec![
+                    &$($mut)? repr.x,
+                    &$($mut)? repr.out,
+                    &$($mut)? repr.indices,
+                    &$($mut)? repr.grad,
+                ]
+            }
+            ModuleOperationIr::MaxPool3d(repr) => {
+                vec![&$($mut)? repr.x, &$($mut)? repr.out]
+            }
+            ModuleOperationIr::MaxPool3dWithIndices(repr) => {
+                vec![&$($mut)? repr.x, &$($mut)? repr.out, &$($mut)? repr.out_indices]
+            }
+            ModuleOperationIr::MaxPool3dWithIndicesBackward(repr) => {
+                vec![
+                    &$($mut)? repr.x,
+                    &$($mut)? repr.out,
+                    &$($mut)? repr.indices,
+                    &$($mut)? repr.grad,
+                ]
             }
             ModuleOperationIr::Interpolate(repr) => {
-                vec![&repr.x, &repr.out]
+                vec![&$($mut)? repr.x, &$($mut)? repr.out]
             }
             ModuleOperationIr::InterpolateBackward(repr) => {
-                vec![&repr.x, &repr.out, &repr.grad]
+                vec![&$($mut)? repr.x, &$($mut)? repr.out, &$($mut)? repr.grad]
             }
         }
+    };
+}
+
+impl ModuleOperationIr {
+    fn nodes(&self) -> Vec<&TensorIr> {
+        module_nodes!(self)
+    }
+
+    fn nodes_mut(&mut self) -> Vec<&mut TensorIr> {
+        module_nodes!(self, mut)
     }
 
     fn mark_read_only(&mut self, nodes: &[TensorId]) -> Vec<TensorIr> {
@@ -2289,6 +2560,9 @@ impl ModuleOperationIr {
             ModuleOperationIr::AvgPool2d(repr) => {
                 repr.x.mark_read_only(nodes, &mut output);
             }
+            ModuleOperationIr::AvgPool3d(repr) => {
+                repr.x.mark_read_only(nodes, &mut output);
+            }
             ModuleOperationIr::AvgPool1dBackward(repr) => {
                 repr.x.mark_read_only(nodes, &mut output);
                 repr.grad.mark_read_only(nodes, &mut output);
@@ -2297,12 +2571,19 @@ impl ModuleOperationIr {
                 repr.x.mark_read_only(nodes, &mut output);
                 repr.grad.mark_read_only(nodes, &mut output);
             }
+            ModuleOperationIr::AvgPool3dBackward(repr) => {
+                repr.x.mark_read_only(nodes, &mut output);
+                repr.grad.mark_read_only(nodes, &mut output);
+            }
             ModuleOperationIr::AdaptiveAvgPool1d(repr) => {
                 repr.x.mark_read_only(nodes, &mut output);
             }
             ModuleOperationIr::AdaptiveAvgPool2d(repr) => {
                 repr.x.mark_read_only(nodes, &mut output);
             }
+            ModuleOperationIr::AdaptiveAvgPool3d(repr) => {
+                repr.x.mark_read_only(nodes, &mut output);
+            }
             ModuleOperationIr::AdaptiveAvgPool1dBackward(repr) => {
                 repr.x.mark_read_only(nodes, &mut output);
                 repr.grad.mark_read_only(nodes, &mut output);
@@ -2311,6 +2592,10 @@ impl ModuleOperationIr {
                 repr.x.mark_read_only(nodes, &mut output);
                 repr.grad.mark_read_only(nodes, &mut output);
             }
+            ModuleOperationIr::AdaptiveAvgPool3dBackward(repr) => {
+                repr.x.mark_read_only(nodes, &mut output);
+                repr.grad.mark_read_only(nodes, &mut output);
+            }
             ModuleOperationIr::MaxPool1d(repr) => {
                 repr.x.mark_read_only(nodes, &mut output);
             }
@@ -2331,6 +2616,16 @@ impl ModuleOperationIr {
                 repr.x.mark_read_only(nodes, &mut output);
                 repr.grad.mark_read_only(nodes, &mut output);
             }
+            ModuleOperationIr::MaxPool3d(repr) => {
+                repr.x.mark_read_only(nodes, &mut output);
+            }
+            ModuleOperationIr::MaxPool3dWithIndices(repr) => {
+                repr.x.mark_read_only(nodes, &mut output);
+            }
+            ModuleOperationIr::MaxPool3dWithIndicesBackward(repr) => {
+                repr.x.mark_read_only(nodes, &mut output);
+                repr.grad.mark_read_only(nodes, &mut output);
+            }
             ModuleOperationIr::Interpolate(repr) => {
                 repr.x.mark_read_only(nodes, &mut output);
             }
@@ -2350,9 +2645,19 @@ impl core::hash::Hash for InitOperationIr {
     }
 }
 
+macro_rules! init_nodes {
+    ($s:ident $(, $mut:tt)?) => {
+        vec![&$($mut)? $s.out]
+    };
+}
+
 impl InitOperationIr {
     fn nodes(&self) -> Vec<&TensorIr> {
-        vec![&self.out]
+        init_nodes!(self)
+    }
+
+    fn nodes_mut(&mut self) -> Vec<&mut TensorIr> {
+        init_nodes!(self, mut)
     }
 }
 
@@ -0,0 +1,330 @@
+use alloc::{collections::VecDeque, vec::Vec};
+use core::hash::BuildHasher;
+use hashbrown::{DefaultHashBuilder, HashMap, HashSet};
+
+use crate::{
+    BaseOperationIr, FloatOperationIr, NumericOperationIr, OperationIr, TensorId, TensorIr,
+    TensorStatus, UnaryOpIr,
+};
+
+/// A graph-level optimization pass on a stream of [operations](OperationIr).
+///
+/// Passes rewrite each operation as it is recorded, only looking at the operations that were
+/// recorded before it.
+pub trait OptimizationPass: Send + Sync {
+    /// The name of the pass, useful for debugging.
+    fn name(&self) -> &'static str;
+
+    /// Try to rewrite the given operation knowing the operations that were recorded before it.
+    ///
+    /// Returns `None` when the operation should be kept as is.
+    fn rewrite(&self, history: &History<'_>, operation: &OperationIr) -> Option<Rewrite>;
+}
+
+/// A rewrite of a single [operation](OperationIr) into equivalent operations.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Rewrite {
+    /// Each output of the operation is equal to an existing tensor.
+    ///
+    /// Stored as `(source, output)` pairs.
+    Forward(Vec<(TensorIr, TensorIr)>),
+    /// The operation is replaced by another one writing the same outputs.
+    Replace(OperationIr),
+}
+
+impl Rewrite {
+    /// Convert the rewrite into the operations replacing the `original` one.
+    ///
+    /// Input tensors that were consumed by the original operation and aren't consumed by the
+    /// new operations are explicitly dropped, so that no handle is leaked.
+    pub fn into_operations(self, original: &OperationIr) -> Vec<OperationIr> {
+        let consumed = inputs(original)
+            .into_iter()
+            .filter(|tensor| tensor.status == TensorStatus::ReadWrite)
+            .collect::<Vec<_>>();
+
+        let mut operations = match self {
+            Rewrite::Forward(pairs) => {
+                let mut operations = Vec::with_capacity(pairs.len());
+
+                for (i, (mut source, out)) in pairs.iter().cloned().enumerate() {
+                    // A forwarded input can be consumed by its last forward when the original
+                    // operation consumed it.
+                    let is_last = !pairs[i + 1..].iter().any(|(s, _)| s.id == source.id);
+                    source.status = match is_last && consumed.iter().any(|t| t.id == source.id) {
+                        true => TensorStatus::ReadWrite,
+                        false => TensorStatus::ReadOnly,
+                    };
+                    operations.push(forward(source, out));
+                }
+
+                operations
+            }
+            Rewrite::Replace(operation) => alloc::vec![operation],
+        };
+
+        for tensor in consumed {
+            let is_consumed = operations.iter().any(|op| {
+                inputs(op)
+                    .iter()
+                    .any(|t| t.id == tensor.id && t.status == TensorStatus::ReadWrite)
+            });
+
+            if !is_consumed {
+                operations.push(OperationIr::Drop(tensor.clone()));
+            }
+        }
+
+        operations
+    }
+}
+
+/// The operations recorded before the one being rewritten.
+#[derive(Clone, Copy, Debug)]
+pub struct History<'a> {
+    operations: &'a [OperationIr],
+    index: &'a HistoryIndex,
+}
+
+impl<'a> History<'a> {
+    /// Create a new history from the recorded operations and their [index](HistoryIndex).
+    pub fn new(operations: &'a [OperationIr], index: &'a HistoryIndex) -> Self {
+        debug_assert_eq!(
+            operations.len(),
+            index.len(),
+            "The index should be up to date with the recorded operations"
+        );
+
+        Self { operations, index }
+    }
+
+    /// The recorded operations, in execution order.
+    pub fn operations(&self) -> &'a [OperationIr] {
+        self.operations
+    }
+
+    /// Find the operation that wrote the given tensor.
+    pub fn producer(&self, id: TensorId) -> Option<&'a OperationIr> {
+        self.index
+            .producers
+            .get(&id)
+            .map(|position| &self.operations[position - self.index.start])
+    }
+
+    /// Find the pure operations computing the same thing as the given one, most recent first.
+    ///
+    /// Only the outputs of the returned operations can differ from the ones of the given
+    /// operation.
+    pub fn equivalents(&self, operation: &OperationIr) -> impl Iterator<Item = &'a OperationIr> {
+        let normalized = normalize(operation);
+        let operations = self.operations;
+        let start = self.index.start;
+
+        self.index
+            .expressions
+            .get(&self.index.hasher.hash_one(&normalized))
+            .into_iter()
+            .flat_map(|positions| positions.iter().rev())
+            .map(move |position| &operations[position - start])
+            .filter(move |candidate| normalize(candidate) == normalized)
+    }
+
+    /// Whether the tensor can still be read after the last recorded operation.
+    ///
+    /// Tensors that aren't part of the history are considered unavailable, since nothing is known
+    /// about their lifetime.
+    pub fn is_available(&self, id: TensorId) -> bool {
+        self.index.references.contains_key(&id) && !self.index.consumed.contains(&id)
+    }
+}
+
+/// An index of the recorded operations, so that [history](History) lookups don't scan every
+/// recorded operation.
+///
+/// The index has to be updated with every operation pushed at the end of the recorded
+/// operations or drained from their front.
+#[derive(Clone, Debug, Default)]
+pub struct HistoryIndex {
+    hasher: DefaultHashBuilder,
+    /// The position of the operation writing each tensor.
+    producers: HashMap<TensorId, usize>,
+    /// The number of operations reading or writing each tensor.
+    references: HashMap<TensorId, usize>,
+    /// The tensors that were dropped or consumed.
+    consumed: HashSet<TensorId>,
+    /// The positions of the pure operations, keyed on what they compute.
+    expressions: HashMap<u64, VecDeque<usize>>,
+    start: usize,
+    end: usize,
+}
+
+impl HistoryIndex {
+    /// Index an operation recorded after all the indexed ones.
+    pub fn push(&mut self, operation: &OperationIr) {
+        let position = self.end;
+        self.end += 1;
+
+        if let OperationIr::Drop(tensor) = operation {
+            self.consumed.insert(tensor.id);
+            return;
+        }
+
+        if is_pure(operation) {
+            let key = self.hasher.hash_one(normalize(operation));
+            self.expressions.entry(key).or_default().push_back(position);
+        }
+
+        for tensor in operation.nodes() {
+            *self.references.entry(tensor.id).or_default() += 1;
+
+            match tensor.status {
+                TensorStatus::NotInit => {
+                    self.producers.insert(tensor.id, position);
+                }
+                TensorStatus::ReadWrite => {
+                    self.consumed.insert(tensor.id);
+                }
+                TensorStatus::ReadOnly => {}
+            }
+        }
+    }
+
+    /// Remove the given operations, drained from the front of the indexed ones.
+    pub fn drain(&mut self, operations: &[OperationIr]) {
+        for operation in operations {
+            let position = self.start;
+            self.start += 1;
+
+            if let OperationIr::Drop(tensor) = operation {
+                self.consumed.remove(&tensor.id);
+                continue;
+            }
+
+            if is_pure(operation) {
+                let key = self.hasher.hash_one(normalize(operation));
+                // The drained operation is the oldest one, so it's first in its bucket.
+                if let Some(positions) = self.expressions.get_mut(&key) {
+                    positions.pop_front();
+                    if positions.is_empty() {
+                        self.expressions.remove(&key);
+                    }
+                }
+            }
+
+            for tensor in operation.nodes() {
+                if let Some(count) = self.references.get_mut(&tensor.id) {
+                    *count -= 1;
+                    if *count == 0 {
+                        self.references.remove(&tensor.id);
+                    }
+                }
+                if self.producers.get(&tensor.id) == Some(&position) {
+                    self.producers.remove(&tensor.id);
+                }
+                if tensor.status == TensorStatus::ReadWrite {
+                    self.consumed.remove(&tensor.id);
+                }
+            }
+        }
+    }
+
+    /// The number of indexed operations.
+    pub fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    /// If no operation is indexed.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// The tensors written by the operation.
+pub fn outputs(operation: &OperationIr) -> Vec<&TensorIr> {
+    match operation {
+        OperationIr::Drop(_) => Vec::new(),
+        _ => operation
+            .nodes()
+            .into_iter()
+            .filter(|tensor| tensor.status == TensorStatus::NotInit)
+            .collect(),
+    }
+}
+
+/// The tensors read by the operation.
+pub fn inputs(operation: &OperationIr) -> Vec<&TensorIr> {
+    match operation {
+        OperationIr::Drop(tensor) => alloc::vec![tensor],
+        _ => operation
+            .nodes()
+            .into_iter()
+            .filter(|tensor| tensor.status != TensorStatus::NotInit)
+            .collect(),
+    }
+}
+
+/// Whether the operation only computes its outputs from its inputs, without any side effect.
+///
+/// Only pure operations can be deduplicated.
+pub fn is_pure(operation: &OperationIr) -> bool {
+    let is_pure = match operation {
+        OperationIr::BaseFloat(op) | OperationIr::BaseInt(op) | OperationIr::BaseBool(op) => {
+            !matches!(op, BaseOperationIr::ToDevice(_) | BaseOperationIr::Empty(_))
+        }
+        OperationIr::NumericFloat(_, op) => !matches!(op, NumericOperationIr::IntRandom(_)),
+        OperationIr::NumericInt(_, op) => !matches!(op, NumericOperationIr::IntRandom(_)),
+        OperationIr::Float(_, op) => !matches!(op, FloatOperationIr::Random(_)),
+        OperationIr::Bool(_) | OperationIr::Int(_) | OperationIr::Module(_) => true,
+        OperationIr::Init(_) | OperationIr::Custom(_) | OperationIr::Drop(_) => false,
+    };
+
+    is_pure && !outputs(operation).is_empty()
+}
+
+/// Create an operation writing the `source` tensor into `out`.
+///
+/// Forwarding is represented as a reshape to the same shape, which every backend supports as a
+/// no-op.
+pub fn forward(source: TensorIr, out: TensorIr) -> OperationIr {
+    let dtype = out.dtype;
+    let op = BaseOperationIr::Reshape(UnaryOpIr { input: source, out });
+
+    if dtype.is_bool() {
+        OperationIr::BaseBool(op)
+    } else if dtype.is_int() || dtype.is_uint() {
+        OperationIr::BaseInt(op)
+    } else {
+        OperationIr::BaseFloat(op)
+    }
+}
+
+/// Whether the operation is a [forward](forward) of a tensor.
+pub fn is_forward(operation: &OperationIr) -> bool {
+    match operation {
+        OperationIr::BaseFloat(BaseOperationIr::Reshape(op))
+        | OperationIr::BaseInt(BaseOperationIr::Reshape(op))
+        | OperationIr::BaseBool(BaseOperationIr::Reshape(op)) => op.input.shape == op.out.shape,
+        _ => false,
+    }
+}
+
+/// Remove everything that is specific to a single execution of the operation: the input
+/// statuses and the output ids.
+fn normalize(operation: &OperationIr) -> OperationIr {
+    let mut operation = operation.clone();
+    let mut num_outputs = 0;
+
+    for tensor in operation.nodes_mut() {
+        match tensor.status {
+            TensorStatus::NotInit => {
+                tensor.id = TensorId::new(u64::MAX - num_outputs);
+                num_outputs += 1;
+            }
+            TensorStatus::ReadOnly | TensorStatus::ReadWrite => {
+                tensor.status = TensorStatus::ReadOnly;
+            }
+        }
+    }
+
+    operation
+}
@@ -0,0 +1,38 @@
+use super::{History, OptimizationPass, Rewrite, is_pure, outputs};
+use crate::OperationIr;
+
+/// Common-subexpression elimination.
+///
+/// When an operation computes the same thing as a previously recorded one whose outputs are
+/// still available, its outputs are forwarded from the previous results instead of being
+/// recomputed. Previous operations are found with the [history index](super::HistoryIndex), so
+/// the history isn't scanned for every operation.
+#[derive(Clone, Debug, Default)]
+pub struct CommonSubexpressionElimination;
+
+impl OptimizationPass for CommonSubexpressionElimination {
+    fn name(&self) -> &'static str {
+        "common-subexpression-elimination"
+    }
+
+    fn rewrite(&self, history: &History<'_>, operation: &OperationIr) -> Option<Rewrite> {
+        if !is_pure(operation) {
+            return None;
+        }
+
+        let candidate = history.equivalents(operation).next()?;
+        let sources = outputs(candidate);
+
+        if !sources.iter().all(|source| history.is_available(source.id)) {
+            return None;
+        }
+
+        let pairs = sources
+            .into_iter()
+            .zip(outputs(operation))
+            .map(|(source, out)| (source.clone(), out.clone()))
+            .collect();
+
+        Some(Rewrite::Forward(pairs))
+    }
+}
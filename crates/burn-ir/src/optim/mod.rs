@@ -0,0 +1,14 @@
+//! Graph-level optimization passes on captured [operations](crate::OperationIr).
+//!
+//! A [pipeline](PassPipeline) is made of [passes](OptimizationPass) applied on each operation as
+//! it is recorded (peephole [rewrites](Rewrite)).
+
+mod base;
+mod cse;
+mod pipeline;
+mod simplify;
+
+pub use base::*;
+pub use cse::*;
+pub use pipeline::*;
+pub use simplify::*;
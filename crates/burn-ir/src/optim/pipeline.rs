@@ -0,0 +1,264 @@
+use alloc::{boxed::Box, vec::Vec};
+
+use super::{
+    AlgebraicSimplification, CommonSubexpressionElimination, History, HistoryIndex,
+    OptimizationPass, Rewrite,
+};
+use crate::OperationIr;
+
+/// An ordered list of [optimization passes](OptimizationPass).
+///
+/// The default pipeline applies algebraic simplifications and common-subexpression elimination.
+/// Operations executed lazily can only be [forwarded](PassPipeline::forward) to existing tensors,
+/// while a fully captured program can be [optimized](PassPipeline::optimize) with every rewrite.
+pub struct PassPipeline {
+    passes: Vec<Box<dyn OptimizationPass>>,
+}
+
+impl Default for PassPipeline {
+    fn default() -> Self {
+        Self::empty()
+            .with_pass(AlgebraicSimplification)
+            .with_pass(CommonSubexpressionElimination)
+    }
+}
+
+impl core::fmt::Debug for PassPipeline {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list()
+            .entries(self.passes.iter().map(|pass| pass.name()))
+            .finish()
+    }
+}
+
+impl PassPipeline {
+    /// Create a pipeline without any pass.
+    pub fn empty() -> Self {
+        Self { passes: Vec::new() }
+    }
+
+    /// Add a pass at the end of the pipeline.
+    pub fn with_pass<P: OptimizationPass + 'static>(mut self, pass: P) -> Self {
+        self.passes.push(Box::new(pass));
+        self
+    }
+
+    /// If the pipeline has no pass.
+    pub fn is_empty(&self) -> bool {
+        self.passes.is_empty()
+    }
+
+    /// Rewrite a single operation knowing the operations recorded before it.
+    ///
+    /// The first pass that rewrites the operation wins.
+    pub fn rewrite(&self, history: &History<'_>, operation: &OperationIr) -> Option<Rewrite> {
+        self.passes
+            .iter()
+            .find_map(|pass| pass.rewrite(history, operation))
+    }
+
+    /// Rewrite a single operation by [forwarding](Rewrite::Forward) existing tensors, ignoring
+    /// the other rewrites.
+    ///
+    /// The first pass that forwards the operation wins.
+    pub fn forward(&self, history: &History<'_>, operation: &OperationIr) -> Option<Rewrite> {
+        self.passes
+            .iter()
+            .filter_map(|pass| pass.rewrite(history, operation))
+            .find(|rewrite| matches!(rewrite, Rewrite::Forward(_)))
+    }
+
+    /// Optimize a fully captured program.
+    pub fn optimize(&self, operations: Vec<OperationIr>) -> Vec<OperationIr> {
+        let mut output = Vec::with_capacity(operations.len());
+        let mut index = HistoryIndex::default();
+
+        for operation in operations {
+            let rewritten = match self.rewrite(&History::new(&output, &index), &operation) {
+                Some(rewrite) => rewrite.into_operations(&operation),
+                None => alloc::vec![operation],
+            };
+
+            for operation in rewritten {
+                index.push(&operation);
+                output.push(operation);
+            }
+        }
+
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        BaseOperationIr, FloatOperationIr, SwapDimsOpIr, TensorId, TensorIr, TensorStatus,
+        UnaryOpIr,
+    };
+    use alloc::vec;
+    use burn_tensor::DType;
+
+    #[test]
+    fn should_eliminate_common_subexpressions() {
+        let ops = vec![
+            exp(
+                tensor(0, TensorStatus::ReadOnly),
+                tensor(1, TensorStatus::NotInit),
+            ),
+            exp(
+                tensor(0, TensorStatus::ReadWrite),
+                tensor(2, TensorStatus::NotInit),
+            ),
+        ];
+
+        let output = PassPipeline::default().optimize(ops);
+
+        assert_eq!(
+            output,
+            vec![
+                exp(
+                    tensor(0, TensorStatus::ReadOnly),
+                    tensor(1, TensorStatus::NotInit),
+                ),
+                reshape(
+                    tensor(1, TensorStatus::ReadOnly),
+                    tensor(2, TensorStatus::NotInit),
+                ),
+                OperationIr::Drop(tensor(0, TensorStatus::ReadWrite)),
+            ]
+        );
+    }
+
+    #[test]
+    fn should_forward_double_transpose() {
+        let ops = vec![
+            swap_dims(
+                tensor(0, TensorStatus::ReadOnly),
+                tensor(1, TensorStatus::NotInit),
+            ),
+            swap_dims(
+                tensor(1, TensorStatus::ReadWrite),
+                tensor(2, TensorStatus::NotInit),
+            ),
+        ];
+
+        let output = PassPipeline::default().optimize(ops);
+
+        assert_eq!(
+            output,
+            vec![
+                swap_dims(
+                    tensor(0, TensorStatus::ReadOnly),
+                    tensor(1, TensorStatus::NotInit),
+                ),
+                reshape(
+                    tensor(0, TensorStatus::ReadOnly),
+                    tensor(2, TensorStatus::NotInit),
+                ),
+                OperationIr::Drop(tensor(1, TensorStatus::ReadWrite)),
+            ]
+        );
+    }
+
+    #[test]
+    fn should_keep_operations_with_escaping_outputs() {
+        let ops = vec![exp(
+            tensor(0, TensorStatus::ReadWrite),
+            tensor(1, TensorStatus::NotInit),
+        )];
+
+        let output = PassPipeline::default().optimize(ops.clone());
+
+        assert_eq!(output, ops);
+    }
+
+    #[test]
+    fn should_only_forward_when_requested() {
+        let history = vec![reshape(
+            tensor(0, TensorStatus::ReadOnly),
+            with_shape(tensor(1, TensorStatus::NotInit), vec![4]),
+        )];
+        let index = index(&history);
+        let op = reshape(
+            with_shape(tensor(1, TensorStatus::ReadWrite), vec![4]),
+            with_shape(tensor(2, TensorStatus::NotInit), vec![1, 4]),
+        );
+
+        let pipeline = PassPipeline::default();
+        let history = History::new(&history, &index);
+
+        assert_eq!(
+            pipeline.rewrite(&history, &op),
+            Some(Rewrite::Replace(reshape(
+                tensor(0, TensorStatus::ReadOnly),
+                with_shape(tensor(2, TensorStatus::NotInit), vec![1, 4]),
+            )))
+        );
+        assert_eq!(pipeline.forward(&history, &op), None);
+    }
+
+    #[test]
+    fn should_not_forward_drained_operations() {
+        let mut history = vec![exp(
+            tensor(0, TensorStatus::ReadOnly),
+            tensor(1, TensorStatus::NotInit),
+        )];
+        let mut index = index(&history);
+        let op = exp(
+            tensor(0, TensorStatus::ReadOnly),
+            tensor(2, TensorStatus::NotInit),
+        );
+        let pipeline = PassPipeline::default();
+
+        assert_eq!(
+            pipeline.forward(&History::new(&history, &index), &op),
+            Some(Rewrite::Forward(vec![(
+                tensor(1, TensorStatus::NotInit),
+                tensor(2, TensorStatus::NotInit),
+            )]))
+        );
+
+        index.drain(&history);
+        history.clear();
+
+        assert_eq!(pipeline.forward(&History::new(&history, &index), &op), None);
+    }
+
+    fn index(operations: &[OperationIr]) -> HistoryIndex {
+        let mut index = HistoryIndex::default();
+        operations.iter().for_each(|op| index.push(op));
+        index
+    }
+
+    fn tensor(id: u64, status: TensorStatus) -> TensorIr {
+        TensorIr {
+            id: TensorId::new(id),
+            shape: vec![2, 2],
+            status,
+            dtype: DType::F32,
+        }
+    }
+
+    fn with_shape(mut tensor: TensorIr, shape: Vec<usize>) -> TensorIr {
+        tensor.shape = shape;
+        tensor
+    }
+
+    fn exp(input: TensorIr, out: TensorIr) -> OperationIr {
+        OperationIr::Float(DType::F32, FloatOperationIr::Exp(UnaryOpIr { input, out }))
+    }
+
+    fn reshape(input: TensorIr, out: TensorIr) -> OperationIr {
+        OperationIr::BaseFloat(BaseOperationIr::Reshape(UnaryOpIr { input, out }))
+    }
+
+    fn swap_dims(input: TensorIr, out: TensorIr) -> OperationIr {
+        OperationIr::BaseFloat(BaseOperationIr::SwapDims(SwapDimsOpIr {
+            input,
+            out,
+            dim1: 0,
+            dim2: 1,
+        }))
+    }
+}
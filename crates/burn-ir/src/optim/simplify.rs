@@ -0,0 +1,195 @@
+use alloc::vec::Vec;
+
+use super::{History, OptimizationPass, Rewrite};
+use crate::{
+    BaseOperationIr, BoolOperationIr, FloatOperationIr, NumericOperationIr, OperationIr,
+    PermuteOpIr, TensorIr, TensorStatus, UnaryOpIr,
+};
+
+/// Algebraic simplifications.
+///
+/// - Operations that don't change their input (e.g. `x * 1`, `x.swap_dims(d, d)`) are forwarded.
+/// - Operations undoing a previous one (e.g. double transpose) forward the original tensor.
+/// - Chains of reshapes and permutations are merged into a single operation.
+#[derive(Clone, Debug, Default)]
+pub struct AlgebraicSimplification;
+
+impl OptimizationPass for AlgebraicSimplification {
+    fn name(&self) -> &'static str {
+        "algebraic-simplification"
+    }
+
+    fn rewrite(&self, history: &History<'_>, operation: &OperationIr) -> Option<Rewrite> {
+        match operation {
+            OperationIr::BaseFloat(op) | OperationIr::BaseInt(op) | OperationIr::BaseBool(op) => {
+                simplify_base(operation, op, history)
+            }
+            OperationIr::NumericFloat(_, op) => simplify_numeric(op, 0.0, 1.0),
+            OperationIr::NumericInt(_, op) => simplify_numeric(op, 0, 1),
+            OperationIr::Float(_, FloatOperationIr::PowfScalar(op)) if op.rhs == 1.0 => {
+                identity(&op.lhs, &op.out)
+            }
+            OperationIr::Bool(BoolOperationIr::Not(op)) => match history.producer(op.input.id) {
+                Some(OperationIr::Bool(BoolOperationIr::Not(previous))) => {
+                    forward_previous(history, &previous.input, &op.out)
+                }
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+fn simplify_base(
+    operation: &OperationIr,
+    op: &BaseOperationIr,
+    history: &History<'_>,
+) -> Option<Rewrite> {
+    match op {
+        BaseOperationIr::Reshape(op) => {
+            // Same-shape reshapes are how tensors are forwarded, they can't be simplified further.
+            if op.input.shape == op.out.shape {
+                return None;
+            }
+
+            let previous = match history.producer(op.input.id)? {
+                OperationIr::BaseFloat(BaseOperationIr::Reshape(previous))
+                | OperationIr::BaseInt(BaseOperationIr::Reshape(previous))
+                | OperationIr::BaseBool(BaseOperationIr::Reshape(previous)) => previous,
+                _ => return None,
+            };
+
+            if previous.input.shape == op.out.shape {
+                return forward_previous(history, &previous.input, &op.out);
+            }
+
+            if !history.is_available(previous.input.id) {
+                return None;
+            }
+
+            let reshape = BaseOperationIr::Reshape(UnaryOpIr {
+                input: read_only(&previous.input),
+                out: op.out.clone(),
+            });
+            Some(Rewrite::Replace(with_kind(operation, reshape)))
+        }
+        BaseOperationIr::SwapDims(op) => {
+            if op.dim1 == op.dim2 {
+                return identity(&op.input, &op.out);
+            }
+
+            match history.producer(op.input.id)? {
+                OperationIr::BaseFloat(BaseOperationIr::SwapDims(previous))
+                | OperationIr::BaseInt(BaseOperationIr::SwapDims(previous))
+                | OperationIr::BaseBool(BaseOperationIr::SwapDims(previous))
+                    if (previous.dim1 == op.dim1 && previous.dim2 == op.dim2)
+                        || (previous.dim1 == op.dim2 && previous.dim2 == op.dim1) =>
+                {
+                    forward_previous(history, &previous.input, &op.out)
+                }
+                _ => None,
+            }
+        }
+        BaseOperationIr::Permute(op) => {
+            if is_identity(&op.axes) {
+                return identity(&op.input, &op.out);
+            }
+
+            let previous = match history.producer(op.input.id)? {
+                OperationIr::BaseFloat(BaseOperationIr::Permute(previous))
+                | OperationIr::BaseInt(BaseOperationIr::Permute(previous))
+                | OperationIr::BaseBool(BaseOperationIr::Permute(previous)) => previous,
+                _ => return None,
+            };
+
+            // out[i] = input[axes[i]] = previous_input[previous_axes[axes[i]]]
+            let axes = op
+                .axes
+                .iter()
+                .map(|axis| previous.axes[*axis])
+                .collect::<Vec<_>>();
+
+            if is_identity(&axes) {
+                return forward_previous(history, &previous.input, &op.out);
+            }
+
+            if !history.is_available(previous.input.id) {
+                return None;
+            }
+
+            let permute = BaseOperationIr::Permute(PermuteOpIr {
+                input: read_only(&previous.input),
+                out: op.out.clone(),
+                axes,
+            });
+            Some(Rewrite::Replace(with_kind(operation, permute)))
+        }
+        BaseOperationIr::Flip(op) if op.axes.is_empty() => identity(&op.input, &op.out),
+        BaseOperationIr::Expand(op) if op.input.shape == op.out.shape => {
+            identity(&op.input, &op.out)
+        }
+        BaseOperationIr::Slice(op)
+            if op
+                .ranges
+                .iter()
+                .zip(op.tensor.shape.iter())
+                .all(|(range, dim)| range.start == 0 && range.end == *dim) =>
+        {
+            identity(&op.tensor, &op.out)
+        }
+        BaseOperationIr::RepeatDim(op) if op.times == 1 => identity(&op.tensor, &op.out),
+        BaseOperationIr::Cast(op) if op.input.dtype == op.out.dtype => identity(&op.input, &op.out),
+        _ => None,
+    }
+}
+
+fn simplify_numeric<E: PartialEq>(op: &NumericOperationIr<E>, zero: E, one: E) -> Option<Rewrite> {
+    match op {
+        NumericOperationIr::AddScalar(op) | NumericOperationIr::SubScalar(op) if op.rhs == zero => {
+            identity(&op.lhs, &op.out)
+        }
+        NumericOperationIr::MulScalar(op) | NumericOperationIr::DivScalar(op) if op.rhs == one => {
+            identity(&op.lhs, &op.out)
+        }
+        _ => None,
+    }
+}
+
+/// The operation returns its input unchanged.
+fn identity(input: &TensorIr, out: &TensorIr) -> Option<Rewrite> {
+    if input.shape != out.shape || input.dtype != out.dtype {
+        return None;
+    }
+
+    Some(Rewrite::Forward(alloc::vec![(input.clone(), out.clone())]))
+}
+
+/// The operation returns the input of a previous operation, which must still be available.
+fn forward_previous(history: &History<'_>, source: &TensorIr, out: &TensorIr) -> Option<Rewrite> {
+    if !history.is_available(source.id) || source.shape != out.shape {
+        return None;
+    }
+
+    Some(Rewrite::Forward(alloc::vec![(
+        read_only(source),
+        out.clone()
+    )]))
+}
+
+fn read_only(tensor: &TensorIr) -> TensorIr {
+    let mut tensor = tensor.clone();
+    tensor.status = TensorStatus::ReadOnly;
+    tensor
+}
+
+fn is_identity(axes: &[usize]) -> bool {
+    axes.iter().enumerate().all(|(i, axis)| i == *axis)
+}
+
+fn with_kind(operation: &OperationIr, op: BaseOperationIr) -> OperationIr {
+    match operation {
+        OperationIr::BaseInt(_) => OperationIr::BaseInt(op),
+        OperationIr::BaseBool(_) => OperationIr::BaseBool(op),
+        _ => OperationIr::BaseFloat(op),
+    }
+}
@@ -0,0 +1,93 @@
+use alloc::boxed::Box;
+use alloc::vec;
+use alloc::vec::Vec;
+use burn_fusion::{
+    Fusion, FusionBackend, FusionRuntime, Optimization, OptimizationBuilder,
+    client::MutexFusionClient,
+    stream::{Context, OrderedExecution},
+};
+use burn_ir::{HandleKind, optim::PassPipeline};
+use burn_tensor::{DType, ops::FloatTensor, ops::FloatTensorOps};
+use core::marker::PhantomData;
+
+use super::{ElemwiseBuilder, NdArrayOptimization, NdArrayOptimizationState};
+use crate::{
+    NdArray, NdArrayDevice, NdArrayTensor, SharedArray,
+    element::{FloatNdArrayElement, IntNdArrayElement, QuantElement},
+};
+
+/// The [ndarray backend](NdArray) with element-wise operations fused into single-pass loops.
+pub type NdArrayFusion<E = f32, I = i64, Q = i8> = Fusion<NdArray<E, I, Q>>;
+
+/// Fusion runtime for the ndarray backend.
+#[derive(Debug)]
+pub struct NdArrayFusionRuntime<E, I, Q> {
+    _e: PhantomData<E>,
+    _i: PhantomData<I>,
+    _q: PhantomData<Q>,
+}
+
+impl<E: FloatNdArrayElement, I: IntNdArrayElement, Q: QuantElement> FusionRuntime
+    for NdArrayFusionRuntime<E, I, Q>
+where
+    NdArrayTensor: From<SharedArray<E>>,
+    NdArrayTensor: From<SharedArray<I>>,
+{
+    type OptimizationState = NdArrayOptimizationState;
+    type Optimization = NdArrayOptimization;
+    type FusionHandle = HandleKind<NdArray<E, I, Q>>;
+    type FusionDevice = NdArrayDevice;
+    type FusionClient = MutexFusionClient<Self>;
+    type BoolRepr = bool;
+
+    fn optimizations(
+        _device: NdArrayDevice,
+    ) -> Vec<Box<dyn OptimizationBuilder<Self::Optimization>>> {
+        vec![Box::new(ElemwiseBuilder::default())]
+    }
+
+    fn passes(_device: &NdArrayDevice) -> PassPipeline {
+        PassPipeline::default()
+    }
+}
+
+impl<E: FloatNdArrayElement, I: IntNdArrayElement, Q: QuantElement>
+    Optimization<NdArrayFusionRuntime<E, I, Q>> for NdArrayOptimization
+where
+    NdArrayTensor: From<SharedArray<E>>,
+    NdArrayTensor: From<SharedArray<I>>,
+{
+    fn execute(
+        &mut self,
+        context: &mut Context<'_, HandleKind<NdArray<E, I, Q>>>,
+        _execution: &OrderedExecution<NdArrayFusionRuntime<E, I, Q>>,
+    ) {
+        match self {
+            Self::Elemwise(op) => op.execute(context),
+        }
+    }
+
+    fn to_state(&self) -> NdArrayOptimizationState {
+        self.to_opt_state()
+    }
+
+    fn from_state(_device: &NdArrayDevice, state: NdArrayOptimizationState) -> Self {
+        Self::from_opt_state(state)
+    }
+}
+
+impl<E: FloatNdArrayElement, I: IntNdArrayElement, Q: QuantElement> FusionBackend
+    for NdArray<E, I, Q>
+where
+    NdArrayTensor: From<SharedArray<E>>,
+    NdArrayTensor: From<SharedArray<I>>,
+{
+    type FusionRuntime = NdArrayFusionRuntime<E, I, Q>;
+
+    // Tensors of every float type are stored in the same handles, so the runtime can't change.
+    type FullPrecisionBackend = Self;
+
+    fn cast_float(tensor: FloatTensor<Self>, dtype: DType) -> Self::Handle {
+        HandleKind::Float(Self::float_cast(tensor, dtype.into()))
+    }
+}
@@ -0,0 +1,116 @@
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use burn_fusion::{OptimizationBuilder, OptimizationProperties, OptimizationStatus};
+use burn_ir::OperationIr;
+use burn_tensor::DType;
+
+use super::{ElemwiseOptimization, ElemwiseProgram, NdArrayOptimization, program::fusable};
+
+/// Limits the number of registers allocated per chunk.
+const MAX_OPERATIONS: usize = 32;
+
+/// Fuse chains of element-wise float operations on tensors of the same shape.
+#[derive(Clone)]
+pub struct ElemwiseBuilder {
+    operations: Vec<OperationIr>,
+    num_fused: usize,
+    shape: Option<Vec<usize>>,
+    dtype: Option<DType>,
+    status: OptimizationStatus,
+}
+
+impl Default for ElemwiseBuilder {
+    fn default() -> Self {
+        Self {
+            operations: Vec::new(),
+            num_fused: 0,
+            shape: None,
+            dtype: None,
+            status: OptimizationStatus::Open,
+        }
+    }
+}
+
+impl ElemwiseBuilder {
+    /// Broadcasting and mixed precision aren't supported, so every tensor must have the same
+    /// shape and data type.
+    fn accept(&mut self, operation: &OperationIr) -> bool {
+        if !fusable(operation) {
+            return false;
+        }
+
+        for node in operation.nodes() {
+            if !matches!(node.dtype, DType::F32 | DType::F64) {
+                return false;
+            }
+
+            let shape = self.shape.get_or_insert_with(|| node.shape.clone());
+            let dtype = self.dtype.get_or_insert(node.dtype);
+
+            if *shape != node.shape || *dtype != node.dtype {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+impl OptimizationBuilder<NdArrayOptimization> for ElemwiseBuilder {
+    fn register(&mut self, operation: &OperationIr) {
+        if let OptimizationStatus::Closed = self.status {
+            return;
+        }
+
+        if let OperationIr::Drop(_) = operation {
+            if self.num_fused == 0 {
+                self.status = OptimizationStatus::Closed;
+            } else {
+                self.operations.push(operation.clone());
+            }
+            return;
+        }
+
+        if !self.accept(operation) {
+            self.status = OptimizationStatus::Closed;
+            return;
+        }
+
+        self.operations.push(operation.clone());
+        self.num_fused += 1;
+
+        if self.num_fused == MAX_OPERATIONS {
+            self.status = OptimizationStatus::Closed;
+        }
+    }
+
+    fn build(&self) -> NdArrayOptimization {
+        let program = ElemwiseProgram::compile(self.dtype.unwrap(), &self.operations);
+
+        NdArrayOptimization::Elemwise(ElemwiseOptimization::new(program, self.operations.len()))
+    }
+
+    fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    fn status(&self) -> OptimizationStatus {
+        self.status
+    }
+
+    fn properties(&self) -> OptimizationProperties {
+        OptimizationProperties {
+            score: self.operations.len() as u64,
+            // A single operation is executed just as fast without fusion.
+            ready: self.num_fused > 1,
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.operations.len()
+    }
+
+    fn clone_dyn(&self) -> Box<dyn OptimizationBuilder<NdArrayOptimization>> {
+        Box::new(self.clone())
+    }
+}
@@ -0,0 +1,283 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use burn_common::{iter_range_par, run_par};
+use burn_fusion::stream::ScalarValue;
+use burn_tensor::{ElementConversion, TensorMetadata};
+use libm::erf;
+use ndarray::{ArrayD, IxDyn};
+use num_traits::Float;
+
+use super::{
+    ElemwiseProgram,
+    program::{BinaryOp, Instruction, Register, UnaryOp},
+};
+use crate::{NdArrayElement, NdArrayTensor, SharedArray, sharing::UnsafeSharedRef};
+
+#[cfg(feature = "simd")]
+use crate::ops::simd::{
+    MinMax,
+    binary::try_binary_slice_simd,
+    binary_elemwise::{VecAdd, VecClamp, VecDiv, VecMul, VecSub, try_binary_scalar_slice_simd},
+    unary::{VecAbs, try_unary_slice_simd},
+};
+#[cfg(feature = "simd")]
+use macerator::{VAbs, VAdd, VDiv, VMul, VOrd, VSub};
+
+/// Number of elements processed by a task, small enough for all registers to stay in cache.
+const CHUNK_SIZE: usize = 1024;
+
+/// Float element that can be used in a fused program.
+#[cfg(feature = "simd")]
+pub(crate) trait FusedElement:
+    NdArrayElement
+    + Float
+    + num_traits::Signed
+    + macerator::Scalar
+    + VAdd
+    + VSub
+    + VMul
+    + VDiv
+    + VAbs
+    + VOrd
+    + MinMax
+{
+    fn from_tensor(tensor: NdArrayTensor) -> SharedArray<Self>;
+    fn from_scalar(scalar: &ScalarValue) -> Self;
+}
+
+/// Float element that can be used in a fused program.
+#[cfg(not(feature = "simd"))]
+pub(crate) trait FusedElement: NdArrayElement + Float {
+    fn from_tensor(tensor: NdArrayTensor) -> SharedArray<Self>;
+    fn from_scalar(scalar: &ScalarValue) -> Self;
+}
+
+macro_rules! fused_element {
+    ($ty:ty, $dtype:ident) => {
+        impl FusedElement for $ty {
+            fn from_tensor(tensor: NdArrayTensor) -> SharedArray<Self> {
+                match tensor {
+                    NdArrayTensor::$dtype(array) => array,
+                    _ => panic!(
+                        "Expected {} tensor, got {:?}",
+                        stringify!($ty),
+                        tensor.dtype()
+                    ),
+                }
+            }
+
+            fn from_scalar(scalar: &ScalarValue) -> Self {
+                match scalar {
+                    ScalarValue::F64(value) => (*value).elem(),
+                    ScalarValue::F32(value) => (*value).elem(),
+                    _ => panic!("Expected float scalar, got {scalar:?}"),
+                }
+            }
+        }
+    };
+}
+
+fused_element!(f32, F32);
+fused_element!(f64, F64);
+
+/// An instruction with its scalars resolved.
+enum Step<F> {
+    Unary(UnaryOp, Register),
+    Binary(BinaryOp, Register, Register),
+    Scalar(BinaryOp, Register, F),
+    Clamp(Register, F, F),
+}
+
+/// Execute the program over contiguous inputs, returning one tensor per program output.
+pub(crate) fn execute<F: FusedElement>(
+    program: &ElemwiseProgram,
+    inputs: Vec<NdArrayTensor>,
+    shape: &[usize],
+    scalars: impl Fn(u64) -> ScalarValue,
+) -> Vec<NdArrayTensor>
+where
+    NdArrayTensor: From<SharedArray<F>>,
+{
+    let num_elems = shape.iter().product::<usize>();
+    let scalar = |id: u64| F::from_scalar(&scalars(id));
+
+    let inputs = inputs
+        .into_iter()
+        .map(|tensor| {
+            let array = F::from_tensor(tensor);
+            if array.is_standard_layout() {
+                array
+            } else {
+                array.as_standard_layout().into_owned().into_shared()
+            }
+        })
+        .collect::<Vec<_>>();
+    let inputs = inputs
+        .iter()
+        .map(|array| array.as_slice().unwrap())
+        .collect::<Vec<_>>();
+
+    let steps = program
+        .instructions
+        .iter()
+        .map(|instruction| match instruction {
+            Instruction::Unary(op, input) => Step::Unary(*op, *input),
+            Instruction::Binary(op, lhs, rhs) => Step::Binary(*op, *lhs, *rhs),
+            Instruction::Scalar(op, lhs, rhs) => Step::Scalar(*op, *lhs, scalar(*rhs)),
+            Instruction::Clamp(input, min, max) => Step::Clamp(*input, scalar(*min), scalar(*max)),
+        })
+        .collect::<Vec<_>>();
+
+    let mut outputs = program
+        .outputs
+        .iter()
+        .map(|_| vec![F::zero(); num_elems])
+        .collect::<Vec<_>>();
+    let unsafe_shared_out = UnsafeSharedRef::new(&mut outputs);
+    let num_chunks = num_elems.div_ceil(CHUNK_SIZE);
+
+    run_par!(|| {
+        iter_range_par!(0, num_chunks).for_each(|chunk| {
+            let start = chunk * CHUNK_SIZE;
+            let end = usize::min(start + CHUNK_SIZE, num_elems);
+            let len = end - start;
+            let mut locals = vec![F::zero(); steps.len() * len];
+
+            for (index, step) in steps.iter().enumerate() {
+                let (previous, current) = locals.split_at_mut(index * len);
+                let out = &mut current[..len];
+                let register = |register: Register| match register {
+                    Register::Input(pos) => &inputs[pos][start..end],
+                    Register::Local(pos) => &previous[pos * len..(pos + 1) * len],
+                };
+
+                match step {
+                    Step::Unary(op, input) => unary(*op, register(*input), out),
+                    Step::Binary(op, lhs, rhs) => binary(*op, register(*lhs), register(*rhs), out),
+                    Step::Scalar(op, lhs, rhs) => scalar_binary(*op, register(*lhs), *rhs, out),
+                    Step::Clamp(input, min, max) => clamp(register(*input), *min, *max, out),
+                }
+            }
+
+            // SAFETY: Each task writes to a distinct range of the outputs.
+            let outputs = unsafe { unsafe_shared_out.get() };
+            for (output, program_output) in outputs.iter_mut().zip(program.outputs.iter()) {
+                let pos = program_output.local;
+                output[start..end].copy_from_slice(&locals[pos * len..(pos + 1) * len]);
+            }
+        })
+    });
+
+    outputs
+        .into_iter()
+        .map(|output| {
+            ArrayD::from_shape_vec(IxDyn(shape), output)
+                .unwrap()
+                .into_shared()
+                .into()
+        })
+        .collect()
+}
+
+macro_rules! map {
+    ($out:expr, $input:expr, $func:expr) => {
+        for (out, input) in $out.iter_mut().zip($input.iter()) {
+            *out = $func(*input);
+        }
+    };
+    ($out:expr, $lhs:expr, $rhs:expr, $func:expr) => {
+        for (out, (lhs, rhs)) in $out.iter_mut().zip($lhs.iter().zip($rhs.iter())) {
+            *out = $func(*lhs, *rhs);
+        }
+    };
+}
+
+fn unary<F: FusedElement>(op: UnaryOp, input: &[F], out: &mut [F]) {
+    #[cfg(feature = "simd")]
+    if op == UnaryOp::Abs && try_unary_slice_simd::<F, F, VecAbs>(input, out) {
+        return;
+    }
+
+    match op {
+        UnaryOp::Exp => map!(out, input, |a: F| a.exp_elem()),
+        UnaryOp::Log => map!(out, input, |a: F| a.log_elem()),
+        UnaryOp::Log1p => map!(out, input, |a: F| a.log1p_elem()),
+        UnaryOp::Sqrt => map!(out, input, |a: F| a.sqrt_elem()),
+        UnaryOp::Abs => map!(out, input, |a: F| a.abs_elem()),
+        UnaryOp::Recip => map!(out, input, |a: F| 1.elem::<F>() / a),
+        UnaryOp::Erf => map!(out, input, |a: F| erf(a.elem()).elem()),
+        UnaryOp::Cos => map!(out, input, |a: F| a.elem::<f64>().cos().elem()),
+        UnaryOp::Sin => map!(out, input, |a: F| a.elem::<f64>().sin().elem()),
+        UnaryOp::Tanh => map!(out, input, |a: F| a.elem::<f64>().tanh().elem()),
+        UnaryOp::Floor => map!(out, input, |a: F| a.elem::<f64>().floor().elem()),
+        UnaryOp::Ceil => map!(out, input, |a: F| a.elem::<f64>().ceil().elem()),
+    }
+}
+
+fn binary<F: FusedElement>(op: BinaryOp, lhs: &[F], rhs: &[F], out: &mut [F]) {
+    #[cfg(feature = "simd")]
+    {
+        let accelerated = match op {
+            BinaryOp::Add => try_binary_slice_simd::<F, F, VecAdd>(lhs, rhs, out),
+            BinaryOp::Sub => try_binary_slice_simd::<F, F, VecSub>(lhs, rhs, out),
+            BinaryOp::Mul => try_binary_slice_simd::<F, F, VecMul>(lhs, rhs, out),
+            BinaryOp::Div => try_binary_slice_simd::<F, F, VecDiv>(lhs, rhs, out),
+            BinaryOp::Powf => false,
+        };
+        if accelerated {
+            return;
+        }
+    }
+
+    match op {
+        BinaryOp::Add => map!(out, lhs, rhs, |a: F, b: F| a + b),
+        BinaryOp::Sub => map!(out, lhs, rhs, |a: F, b: F| a - b),
+        BinaryOp::Mul => map!(out, lhs, rhs, |a: F, b: F| a * b),
+        BinaryOp::Div => map!(out, lhs, rhs, |a: F, b: F| a / b),
+        BinaryOp::Powf => map!(out, lhs, rhs, |a: F, b: F| Float::powf(a, b)),
+    }
+}
+
+fn scalar_binary<F: FusedElement>(op: BinaryOp, lhs: &[F], rhs: F, out: &mut [F]) {
+    #[cfg(feature = "simd")]
+    {
+        let accelerated = match op {
+            BinaryOp::Add => try_binary_scalar_slice_simd::<F, F, VecAdd>(lhs, out, rhs),
+            BinaryOp::Sub => try_binary_scalar_slice_simd::<F, F, VecSub>(lhs, out, rhs),
+            BinaryOp::Mul => try_binary_scalar_slice_simd::<F, F, VecMul>(lhs, out, rhs),
+            BinaryOp::Div => try_binary_scalar_slice_simd::<F, F, VecDiv>(lhs, out, rhs),
+            BinaryOp::Powf => false,
+        };
+        if accelerated {
+            return;
+        }
+    }
+
+    match op {
+        BinaryOp::Add => map!(out, lhs, |a: F| a + rhs),
+        BinaryOp::Sub => map!(out, lhs, |a: F| a - rhs),
+        BinaryOp::Mul => map!(out, lhs, |a: F| a * rhs),
+        BinaryOp::Div => map!(out, lhs, |a: F| a / rhs),
+        BinaryOp::Powf => powf_scalar(lhs, rhs.elem(), out),
+    }
+}
+
+/// Same special cases as [float_powf_scalar](burn_tensor::ops::FloatTensorOps::float_powf_scalar).
+fn powf_scalar<F: FusedElement>(lhs: &[F], value: f32, out: &mut [F]) {
+    if value == 2.0 {
+        map!(out, lhs, |a: F| a * a);
+    } else if value.floor() == value {
+        map!(out, lhs, |a: F| a.powi_elem(value as i32));
+    } else {
+        map!(out, lhs, |a: F| a.powf_elem(value));
+    }
+}
+
+fn clamp<F: FusedElement>(input: &[F], min: F, max: F, out: &mut [F]) {
+    #[cfg(feature = "simd")]
+    if try_binary_scalar_slice_simd::<F, F, VecClamp>(input, out, (min, max)) {
+        return;
+    }
+
+    map!(out, input, |a: F| Float::max(Float::min(a, max), min));
+}
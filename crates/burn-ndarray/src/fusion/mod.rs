@@ -0,0 +1,10 @@
+mod base;
+mod builder;
+mod kernel;
+mod optimization;
+mod program;
+
+pub use base::*;
+pub use builder::*;
+pub use optimization::*;
+pub use program::ElemwiseProgram;
@@ -0,0 +1,141 @@
+use alloc::vec::Vec;
+use burn_fusion::{
+    NumOperations,
+    stream::{Context, ScalarId},
+};
+use burn_ir::{HandleKind, TensorStatus};
+use burn_tensor::{DType, backend::Backend};
+use serde::{Deserialize, Serialize};
+
+use super::{ElemwiseProgram, kernel};
+use crate::NdArrayTensor;
+
+/// Fused operations executed by the ndarray backend.
+#[derive(Debug)]
+pub enum NdArrayOptimization {
+    /// Element-wise operations executed in a single pass.
+    Elemwise(ElemwiseOptimization),
+}
+
+/// The serializable state of a [ndarray optimization](NdArrayOptimization).
+#[derive(Debug, Serialize, Deserialize)]
+pub enum NdArrayOptimizationState {
+    /// Element-wise operations executed in a single pass.
+    Elemwise(ElemwiseOptimizationState),
+}
+
+/// Execute a chain of element-wise operations chunk by chunk, without materializing the
+/// intermediate tensors.
+#[derive(Debug)]
+pub struct ElemwiseOptimization {
+    program: ElemwiseProgram,
+    num_operations: usize,
+}
+
+/// The serializable state of an [element-wise optimization](ElemwiseOptimization).
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ElemwiseOptimizationState {
+    program: ElemwiseProgram,
+    num_operations: usize,
+}
+
+impl NumOperations for NdArrayOptimization {
+    fn len(&self) -> usize {
+        match self {
+            Self::Elemwise(op) => op.num_operations,
+        }
+    }
+}
+
+impl NdArrayOptimization {
+    pub(crate) fn to_opt_state(&self) -> NdArrayOptimizationState {
+        match self {
+            Self::Elemwise(op) => NdArrayOptimizationState::Elemwise(op.to_state()),
+        }
+    }
+
+    pub(crate) fn from_opt_state(state: NdArrayOptimizationState) -> Self {
+        match state {
+            NdArrayOptimizationState::Elemwise(state) => {
+                Self::Elemwise(ElemwiseOptimization::from_state(state))
+            }
+        }
+    }
+}
+
+impl ElemwiseOptimization {
+    pub(crate) fn new(program: ElemwiseProgram, num_operations: usize) -> Self {
+        Self {
+            program,
+            num_operations,
+        }
+    }
+
+    /// Execute the fused operations.
+    pub fn execute<B: Backend<FloatTensorPrimitive = NdArrayTensor>>(
+        &self,
+        context: &mut Context<'_, HandleKind<B>>,
+    ) {
+        let program = &self.program;
+        let mut shape = Vec::new();
+
+        let inputs = program
+            .inputs
+            .iter()
+            .map(|input| {
+                let tensor = &context.tensors[&input.tensor];
+                shape.clone_from(&tensor.shape);
+
+                match context
+                    .handles
+                    .get_handle(&tensor.id, &TensorStatus::ReadOnly)
+                {
+                    HandleKind::Float(handle) => handle,
+                    handle => panic!("Expected float handle, got {}", handle.name()),
+                }
+            })
+            .collect::<Vec<_>>();
+
+        if !program.outputs.is_empty() {
+            let scalars = |id| context.scalars[&ScalarId { value: id }].clone();
+            let outputs = match program.dtype {
+                DType::F64 => kernel::execute::<f64>(program, inputs, &shape, scalars),
+                DType::F32 => kernel::execute::<f32>(program, inputs, &shape, scalars),
+                dtype => unreachable!("Unsupported fused dtype {dtype:?}"),
+            };
+
+            for (output, handle) in program.outputs.iter().zip(outputs) {
+                let tensor = &context.tensors[&output.tensor];
+                context
+                    .handles
+                    .register_handle(tensor.id, HandleKind::Float(handle));
+            }
+        }
+
+        let freed = program
+            .inputs
+            .iter()
+            .filter(|input| input.consumed)
+            .map(|input| &input.tensor)
+            .chain(program.dropped.iter());
+
+        for id in freed {
+            if let Some(tensor) = context.tensors.get(id) {
+                context.handles.remove_handle(tensor.id);
+            }
+        }
+    }
+
+    /// Returns the serializable state.
+    pub fn to_state(&self) -> ElemwiseOptimizationState {
+        ElemwiseOptimizationState {
+            program: self.program.clone(),
+            num_operations: self.num_operations,
+        }
+    }
+
+    /// Create the optimization from its state.
+    pub fn from_state(state: ElemwiseOptimizationState) -> Self {
+        Self::new(state.program, state.num_operations)
+    }
+}
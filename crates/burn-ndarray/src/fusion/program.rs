@@ -0,0 +1,324 @@
+use alloc::vec::Vec;
+use burn_ir::{
+    FloatOperationIr, NumericOperationIr, OperationIr, TensorId, TensorIr, TensorStatus,
+};
+use burn_tensor::DType;
+use hashbrown::{HashMap, HashSet};
+use serde::{Deserialize, Serialize};
+
+/// A chain of element-wise operations compiled into instructions over registers.
+///
+/// Tensor ids are relative to the fused block.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ElemwiseProgram {
+    pub(crate) dtype: DType,
+    pub(crate) inputs: Vec<ProgramInput>,
+    pub(crate) instructions: Vec<Instruction>,
+    pub(crate) outputs: Vec<ProgramOutput>,
+    /// Tensors dropped without being used by any instruction.
+    pub(crate) dropped: Vec<TensorId>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub(crate) struct ProgramInput {
+    pub(crate) tensor: TensorId,
+    /// The tensor isn't used after the program, so its handle can be freed.
+    pub(crate) consumed: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub(crate) struct ProgramOutput {
+    pub(crate) tensor: TensorId,
+    /// The instruction producing the output.
+    pub(crate) local: usize,
+}
+
+/// Either a program input or the result of a previous instruction.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub(crate) enum Register {
+    Input(usize),
+    Local(usize),
+}
+
+/// Scalars are referenced by their [id](burn_fusion::stream::ScalarId).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub(crate) enum Instruction {
+    Unary(UnaryOp, Register),
+    Binary(BinaryOp, Register, Register),
+    Scalar(BinaryOp, Register, u64),
+    Clamp(Register, u64, u64),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) enum UnaryOp {
+    Exp,
+    Log,
+    Log1p,
+    Sqrt,
+    Abs,
+    Recip,
+    Erf,
+    Cos,
+    Sin,
+    Tanh,
+    Floor,
+    Ceil,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) enum BinaryOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Powf,
+}
+
+/// If the operation can be compiled into an [instruction](Instruction).
+pub(crate) fn fusable(operation: &OperationIr) -> bool {
+    match operation {
+        OperationIr::NumericFloat(_, op) => matches!(
+            op,
+            NumericOperationIr::Add(_)
+                | NumericOperationIr::Sub(_)
+                | NumericOperationIr::Mul(_)
+                | NumericOperationIr::Div(_)
+                | NumericOperationIr::Powf(_)
+                | NumericOperationIr::AddScalar(_)
+                | NumericOperationIr::SubScalar(_)
+                | NumericOperationIr::MulScalar(_)
+                | NumericOperationIr::DivScalar(_)
+                | NumericOperationIr::Abs(_)
+                | NumericOperationIr::Clamp(_)
+        ),
+        OperationIr::Float(_, op) => matches!(
+            op,
+            FloatOperationIr::Exp(_)
+                | FloatOperationIr::Log(_)
+                | FloatOperationIr::Log1p(_)
+                | FloatOperationIr::Erf(_)
+                | FloatOperationIr::Sqrt(_)
+                | FloatOperationIr::Cos(_)
+                | FloatOperationIr::Sin(_)
+                | FloatOperationIr::Tanh(_)
+                | FloatOperationIr::Floor(_)
+                | FloatOperationIr::Ceil(_)
+                | FloatOperationIr::Recip(_)
+                | FloatOperationIr::PowfScalar(_)
+        ),
+        _ => false,
+    }
+}
+
+impl ElemwiseProgram {
+    /// Compile [fusable](fusable) operations and drops into a program.
+    pub(crate) fn compile(dtype: DType, operations: &[OperationIr]) -> Self {
+        let mut compiler = Compiler::default();
+
+        for operation in operations {
+            compiler.register(operation);
+        }
+
+        compiler.finish(dtype)
+    }
+}
+
+#[derive(Default)]
+struct Compiler {
+    registers: HashMap<TensorId, Register>,
+    inputs: Vec<TensorId>,
+    instructions: Vec<Instruction>,
+    produced: Vec<TensorId>,
+    consumed: HashSet<TensorId>,
+    dropped: Vec<TensorId>,
+}
+
+impl Compiler {
+    fn register(&mut self, operation: &OperationIr) {
+        let (instruction, out) = match operation {
+            OperationIr::Drop(tensor) => {
+                self.consumed.insert(tensor.id);
+                if !self.registers.contains_key(&tensor.id) {
+                    self.dropped.push(tensor.id);
+                }
+                return;
+            }
+            OperationIr::NumericFloat(_, op) => match op {
+                NumericOperationIr::Add(op) => {
+                    (self.binary(BinaryOp::Add, &op.lhs, &op.rhs), &op.out)
+                }
+                NumericOperationIr::Sub(op) => {
+                    (self.binary(BinaryOp::Sub, &op.lhs, &op.rhs), &op.out)
+                }
+                NumericOperationIr::Mul(op) => {
+                    (self.binary(BinaryOp::Mul, &op.lhs, &op.rhs), &op.out)
+                }
+                NumericOperationIr::Div(op) => {
+                    (self.binary(BinaryOp::Div, &op.lhs, &op.rhs), &op.out)
+                }
+                NumericOperationIr::Powf(op) => {
+                    (self.binary(BinaryOp::Powf, &op.lhs, &op.rhs), &op.out)
+                }
+                NumericOperationIr::AddScalar(op) => {
+                    (self.scalar(BinaryOp::Add, &op.lhs, op.rhs), &op.out)
+                }
+                NumericOperationIr::SubScalar(op) => {
+                    (self.scalar(BinaryOp::Sub, &op.lhs, op.rhs), &op.out)
+                }
+                NumericOperationIr::MulScalar(op) => {
+                    (self.scalar(BinaryOp::Mul, &op.lhs, op.rhs), &op.out)
+                }
+                NumericOperationIr::DivScalar(op) => {
+                    (self.scalar(BinaryOp::Div, &op.lhs, op.rhs), &op.out)
+                }
+                NumericOperationIr::Abs(op) => (self.unary(UnaryOp::Abs, &op.input), &op.out),
+                NumericOperationIr::Clamp(op) => {
+                    let input = self.read(&op.tensor);
+                    (
+                        Instruction::Clamp(input, op.min as u64, op.max as u64),
+                        &op.out,
+                    )
+                }
+                _ => unreachable!("Operation can't be fused: {operation:?}"),
+            },
+            OperationIr::Float(_, op) => match op {
+                FloatOperationIr::Exp(op) => (self.unary(UnaryOp::Exp, &op.input), &op.out),
+                FloatOperationIr::Log(op) => (self.unary(UnaryOp::Log, &op.input), &op.out),
+                FloatOperationIr::Log1p(op) => (self.unary(UnaryOp::Log1p, &op.input), &op.out),
+                FloatOperationIr::Erf(op) => (self.unary(UnaryOp::Erf, &op.input), &op.out),
+                FloatOperationIr::Sqrt(op) => (self.unary(UnaryOp::Sqrt, &op.input), &op.out),
+                FloatOperationIr::Cos(op) => (self.unary(UnaryOp::Cos, &op.input), &op.out),
+                FloatOperationIr::Sin(op) => (self.unary(UnaryOp::Sin, &op.input), &op.out),
+                FloatOperationIr::Tanh(op) => (self.unary(UnaryOp::Tanh, &op.input), &op.out),
+                FloatOperationIr::Floor(op) => (self.unary(UnaryOp::Floor, &op.input), &op.out),
+                FloatOperationIr::Ceil(op) => (self.unary(UnaryOp::Ceil, &op.input), &op.out),
+                FloatOperationIr::Recip(op) => (self.unary(UnaryOp::Recip, &op.input), &op.out),
+                FloatOperationIr::PowfScalar(op) => {
+                    (self.scalar(BinaryOp::Powf, &op.lhs, op.rhs), &op.out)
+                }
+                _ => unreachable!("Operation can't be fused: {operation:?}"),
+            },
+            _ => unreachable!("Operation can't be fused: {operation:?}"),
+        };
+
+        self.registers
+            .insert(out.id, Register::Local(self.instructions.len()));
+        self.instructions.push(instruction);
+        self.produced.push(out.id);
+    }
+
+    fn read(&mut self, tensor: &TensorIr) -> Register {
+        if tensor.status == TensorStatus::ReadWrite {
+            self.consumed.insert(tensor.id);
+        }
+
+        *self.registers.entry(tensor.id).or_insert_with(|| {
+            self.inputs.push(tensor.id);
+            Register::Input(self.inputs.len() - 1)
+        })
+    }
+
+    fn unary(&mut self, op: UnaryOp, input: &TensorIr) -> Instruction {
+        Instruction::Unary(op, self.read(input))
+    }
+
+    fn binary(&mut self, op: BinaryOp, lhs: &TensorIr, rhs: &TensorIr) -> Instruction {
+        Instruction::Binary(op, self.read(lhs), self.read(rhs))
+    }
+
+    /// In relative operations, scalars are replaced by their id.
+    fn scalar(&mut self, op: BinaryOp, lhs: &TensorIr, rhs: f32) -> Instruction {
+        Instruction::Scalar(op, self.read(lhs), rhs as u64)
+    }
+
+    fn finish(self, dtype: DType) -> ElemwiseProgram {
+        let inputs = self
+            .inputs
+            .into_iter()
+            .map(|tensor| ProgramInput {
+                tensor,
+                consumed: self.consumed.contains(&tensor),
+            })
+            .collect();
+        let outputs = self
+            .produced
+            .into_iter()
+            .filter(|tensor| !self.consumed.contains(tensor))
+            .map(|tensor| match self.registers[&tensor] {
+                Register::Local(local) => ProgramOutput { tensor, local },
+                Register::Input(_) => unreachable!("Outputs are produced by instructions"),
+            })
+            .collect();
+
+        ElemwiseProgram {
+            dtype,
+            inputs,
+            instructions: self.instructions,
+            outputs,
+            dropped: self.dropped,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use burn_ir::{ScalarOpIr, UnaryOpIr};
+
+    #[test]
+    fn should_only_output_tensors_used_after_the_program() {
+        let operations = vec![
+            OperationIr::Float(
+                DType::F32,
+                FloatOperationIr::Exp(UnaryOpIr {
+                    input: tensor(0, TensorStatus::ReadOnly),
+                    out: tensor(1, TensorStatus::NotInit),
+                }),
+            ),
+            OperationIr::NumericFloat(
+                DType::F32,
+                NumericOperationIr::MulScalar(ScalarOpIr {
+                    lhs: tensor(1, TensorStatus::ReadWrite),
+                    rhs: 0.0,
+                    out: tensor(2, TensorStatus::NotInit),
+                }),
+            ),
+            OperationIr::Drop(tensor(3, TensorStatus::ReadWrite)),
+        ];
+
+        let program = ElemwiseProgram::compile(DType::F32, &operations);
+
+        assert_eq!(
+            program.inputs,
+            vec![ProgramInput {
+                tensor: TensorId::new(0),
+                consumed: false
+            }]
+        );
+        assert_eq!(
+            program.instructions,
+            vec![
+                Instruction::Unary(UnaryOp::Exp, Register::Input(0)),
+                Instruction::Scalar(BinaryOp::Mul, Register::Local(0), 0),
+            ]
+        );
+        assert_eq!(
+            program.outputs,
+            vec![ProgramOutput {
+                tensor: TensorId::new(2),
+                local: 1
+            }]
+        );
+        assert_eq!(program.dropped, vec![TensorId::new(3)]);
+    }
+
+    fn tensor(id: u64, status: TensorStatus) -> TensorIr {
+        TensorIr {
+            id: TensorId::new(id),
+            shape: vec![0],
+            status,
+            dtype: DType::F32,
+        }
+    }
+}
@@ -13,6 +13,8 @@ extern crate blas_src;
 
 mod backend;
 mod element;
+#[cfg(feature = "fusion")]
+mod fusion;
 mod ops;
 mod rand;
 mod sharing;
@@ -20,6 +22,8 @@ mod tensor;
 
 pub use backend::*;
 pub use element::*;
+#[cfg(feature = "fusion")]
+pub use fusion::*;
 pub(crate) use sharing::*;
 pub use tensor::*;
 
@@ -46,3 +50,16 @@ mod tests {
     burn_tensor::testgen_quantize!();
     burn_tensor::testgen_q_data!();
 }
+
+#[cfg(all(test, feature = "fusion"))]
+mod tests_fusion {
+    type TestBackend = crate::NdArrayFusion<f32>;
+    type TestTensor<const D: usize> = burn_tensor::Tensor<TestBackend, D>;
+    type TestTensorInt<const D: usize> = burn_tensor::Tensor<TestBackend, D, burn_tensor::Int>;
+    type TestTensorBool<const D: usize> = burn_tensor::Tensor<TestBackend, D, burn_tensor::Bool>;
+
+    use alloc::format;
+    use alloc::vec;
+
+    burn_tensor::testgen_all!();
+}
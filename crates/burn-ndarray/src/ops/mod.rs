@@ -5,7 +5,7 @@ mod int_tensor;
 mod module;
 mod qtensor;
 #[cfg(feature = "simd")]
-mod simd;
+pub(crate) mod simd;
 mod tensor;
 mod transaction;
 
@@ -188,6 +188,23 @@ pub fn try_binary_simd<
     Ok(out)
 }
 
+/// Execute the operation on contiguous slices, returning `false` when it isn't accelerated.
+pub fn try_binary_slice_simd<
+    T: NdArrayElement + Scalar,
+    Out: NdArrayElement + Scalar,
+    Op: SimdBinop<T, Out>,
+>(
+    lhs: &[T],
+    rhs: &[T],
+    out: &mut [Out],
+) -> bool {
+    if !is_accelerated::<T, Out, Op>(PhantomData) {
+        return false;
+    }
+    binary(lhs, rhs, out, PhantomData::<Op>);
+    true
+}
+
 fn binary_simd_same<
     T: NdArrayElement + Scalar,
     Out: NdArrayElement + Scalar,
@@ -280,6 +280,23 @@ pub fn try_binary_scalar_simd<
     Ok(out)
 }
 
+/// Execute the operation on contiguous slices, returning `false` when it isn't accelerated.
+pub fn try_binary_scalar_slice_simd<
+    T: NdArrayElement + Scalar,
+    Out: NdArrayElement + Scalar,
+    Op: ScalarSimdBinop<T, Out>,
+>(
+    input: &[T],
+    out: &mut [Out],
+    elem: Op::Rhs,
+) -> bool {
+    if !is_accelerated::<T, Out, Op>(PhantomData) {
+        return false;
+    }
+    binary_scalar_slice::<T, Out, Op>(input, out, elem, PhantomData);
+    true
+}
+
 /// Execute operation in place on an owned tensor
 /// SAFETY:
 /// Must ensure `size_of::<T> == size_of::<Out>` and `align_of::<T> >= align_of::<Out>`.
@@ -103,6 +103,22 @@ pub fn try_unary_simd<
     Ok(out)
 }
 
+/// Execute the operation on contiguous slices, returning `false` when it isn't accelerated.
+pub fn try_unary_slice_simd<
+    T: NdArrayElement + Scalar,
+    Out: NdArrayElement + Scalar,
+    Op: SimdUnop<T, Out>,
+>(
+    input: &[T],
+    out: &mut [Out],
+) -> bool {
+    if !is_accelerated::<T, Out, Op>(PhantomData) {
+        return false;
+    }
+    unary_slice::<T, Out, Op>(input, out, PhantomData);
+    true
+}
+
 /// Execute operation in line.
 /// SAFETY:
 /// Must ensure `size_of::<T> == size_of::<Out>` and `align_of::<T> >= align_of::<Out>`.
@@ -2,12 +2,14 @@ use alloc::{boxed::Box, vec};
 
 use burn_ir::{
     AdaptiveAvgPool1dBackwardOpIr, AdaptiveAvgPool1dOpIr, AdaptiveAvgPool2dBackwardOpIr,
-    AdaptiveAvgPool2dOpIr, AvgPool1dBackwardOpIr, AvgPool1dOpIr, AvgPool2dBackwardOpIr,
-    AvgPool2dOpIr, Conv1dOpIr, Conv2dOpIr, Conv3dOpIr, ConvTranspose1dOpIr, ConvTranspose2dOpIr,
-    ConvTranspose3dOpIr, DeformConv2dBackwardOpIr, DeformConv2dOpIr, InterpolateBackwardOpIr,
-    InterpolateOpIr, MaxPool1dOpIr, MaxPool1dWithIndicesBackwardOpIr, MaxPool1dWithIndicesOpIr,
-    MaxPool2dOpIr, MaxPool2dWithIndicesBackwardOpIr, MaxPool2dWithIndicesOpIr, ModuleOperationIr,
-    OperationIr,
+    AdaptiveAvgPool2dOpIr, AdaptiveAvgPool3dBackwardOpIr, AdaptiveAvgPool3dOpIr,
+    AvgPool1dBackwardOpIr, AvgPool1dOpIr, AvgPool2dBackwardOpIr, AvgPool2dOpIr,
+    AvgPool3dBackwardOpIr, AvgPool3dOpIr, Conv1dOpIr, Conv2dOpIr, Conv3dOpIr, ConvTranspose1dOpIr,
+    ConvTranspose2dOpIr, ConvTranspose3dOpIr, DeformConv2dBackwardOpIr, DeformConv2dOpIr,
+    InterpolateBackwardOpIr, InterpolateOpIr, MaxPool1dOpIr, MaxPool1dWithIndicesBackwardOpIr,
+    MaxPool1dWithIndicesOpIr, MaxPool2dOpIr, MaxPool2dWithIndicesBackwardOpIr,
+    MaxPool2dWithIndicesOpIr, MaxPool3dOpIr, MaxPool3dWithIndicesBackwardOpIr,
+    MaxPool3dWithIndicesOpIr, ModuleOperationIr, OperationIr,
 };
 use burn_tensor::Element;
 use burn_tensor::ops::conv::{
@@ -19,7 +21,7 @@ use burn_tensor::ops::{
 };
 use burn_tensor::ops::{
     IntTensor, InterpolateOptions, MaxPool1dBackward, MaxPool1dWithIndices, MaxPool2dBackward,
-    MaxPool2dWithIndices,
+    MaxPool2dWithIndices, MaxPool3dBackward, MaxPool3dWithIndices,
 };
 
 use crate::{BackendRouter, RunnerChannel, RunnerClient};
@@ -328,6 +330,38 @@ impl<R: RunnerChannel> ModuleOps<Self> for BackendRouter<R> {
         out
     }
 
+    fn avg_pool3d(
+        x: FloatTensor<Self>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        count_include_pad: bool,
+    ) -> FloatTensor<Self> {
+        let size_0 =
+            calculate_pool_output_size(kernel_size[0], stride[0], padding[0], 1, x.shape[2]);
+        let size_1 =
+            calculate_pool_output_size(kernel_size[1], stride[1], padding[1], 1, x.shape[3]);
+        let size_2 =
+            calculate_pool_output_size(kernel_size[2], stride[2], padding[2], 1, x.shape[4]);
+
+        let shape = vec![x.shape[0], x.shape[1], size_0, size_1, size_2];
+        let client = x.client.clone();
+        let out = client.register_empty_tensor(shape, x.dtype);
+
+        let desc = AvgPool3dOpIr {
+            x: x.into_ir(),
+            kernel_size,
+            stride,
+            padding,
+            count_include_pad,
+            out: out.to_ir_out(),
+        };
+
+        client.register(OperationIr::Module(ModuleOperationIr::AvgPool3d(desc)));
+
+        out
+    }
+
     fn avg_pool1d_backward(
         x: FloatTensor<Self>,
         grad: FloatTensor<Self>,
@@ -384,6 +418,34 @@ impl<R: RunnerChannel> ModuleOps<Self> for BackendRouter<R> {
         out
     }
 
+    fn avg_pool3d_backward(
+        x: FloatTensor<Self>,
+        grad: FloatTensor<Self>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        count_include_pad: bool,
+    ) -> FloatTensor<Self> {
+        let client = x.client.clone();
+        let out = client.register_empty_tensor(x.shape.clone(), x.dtype);
+
+        let desc = AvgPool3dBackwardOpIr {
+            x: x.into_ir(),
+            grad: grad.into_ir(),
+            kernel_size,
+            stride,
+            padding,
+            count_include_pad,
+            out: out.to_ir_out(),
+        };
+
+        client.register(OperationIr::Module(ModuleOperationIr::AvgPool3dBackward(
+            desc,
+        )));
+
+        out
+    }
+
     fn max_pool1d(
         x: FloatTensor<Self>,
         kernel_size: usize,
@@ -451,6 +513,53 @@ impl<R: RunnerChannel> ModuleOps<Self> for BackendRouter<R> {
         out
     }
 
+    fn max_pool3d(
+        x: FloatTensor<Self>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        dilation: [usize; 3],
+    ) -> FloatTensor<Self> {
+        let size_0 = calculate_pool_output_size(
+            kernel_size[0],
+            stride[0],
+            padding[0],
+            dilation[0],
+            x.shape[2],
+        );
+        let size_1 = calculate_pool_output_size(
+            kernel_size[1],
+            stride[1],
+            padding[1],
+            dilation[1],
+            x.shape[3],
+        );
+        let size_2 = calculate_pool_output_size(
+            kernel_size[2],
+            stride[2],
+            padding[2],
+            dilation[2],
+            x.shape[4],
+        );
+
+        let shape = vec![x.shape[0], x.shape[1], size_0, size_1, size_2];
+        let client = x.client.clone();
+        let out = client.register_empty_tensor(shape, x.dtype);
+
+        let desc = MaxPool3dOpIr {
+            x: x.into_ir(),
+            kernel_size,
+            stride,
+            padding,
+            dilation,
+            out: out.to_ir_out(),
+        };
+
+        client.register(OperationIr::Module(ModuleOperationIr::MaxPool3d(desc)));
+
+        out
+    }
+
     fn max_pool1d_with_indices(
         x: FloatTensor<Self>,
         kernel_size: usize,
@@ -526,6 +635,57 @@ impl<R: RunnerChannel> ModuleOps<Self> for BackendRouter<R> {
         MaxPool2dWithIndices::new(out, out_indices)
     }
 
+    fn max_pool3d_with_indices(
+        x: FloatTensor<Self>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        dilation: [usize; 3],
+    ) -> MaxPool3dWithIndices<Self> {
+        let size_0 = calculate_pool_output_size(
+            kernel_size[0],
+            stride[0],
+            padding[0],
+            dilation[0],
+            x.shape[2],
+        );
+        let size_1 = calculate_pool_output_size(
+            kernel_size[1],
+            stride[1],
+            padding[1],
+            dilation[1],
+            x.shape[3],
+        );
+        let size_2 = calculate_pool_output_size(
+            kernel_size[2],
+            stride[2],
+            padding[2],
+            dilation[2],
+            x.shape[4],
+        );
+
+        let shape = vec![x.shape[0], x.shape[1], size_0, size_1, size_2];
+        let client = x.client.clone();
+        let out = client.register_empty_tensor(shape.clone(), x.dtype);
+        let out_indices = client.register_empty_tensor(shape, IntElem::<Self>::dtype());
+
+        let desc = MaxPool3dWithIndicesOpIr {
+            x: x.into_ir(),
+            kernel_size,
+            stride,
+            padding,
+            dilation,
+            out: out.to_ir_out(),
+            out_indices: out_indices.to_ir_out(),
+        };
+
+        client.register(OperationIr::Module(
+            ModuleOperationIr::MaxPool3dWithIndices(desc),
+        ));
+
+        MaxPool3dWithIndices::new(out, out_indices)
+    }
+
     fn max_pool1d_with_indices_backward(
         x: FloatTensor<Self>,
         kernel_size: usize,
@@ -586,6 +746,36 @@ impl<R: RunnerChannel> ModuleOps<Self> for BackendRouter<R> {
         MaxPool2dBackward::new(out)
     }
 
+    fn max_pool3d_with_indices_backward(
+        x: FloatTensor<Self>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        dilation: [usize; 3],
+        output_grad: FloatTensor<Self>,
+        indices: IntTensor<Self>,
+    ) -> MaxPool3dBackward<Self> {
+        let client = x.client.clone();
+        let out = client.register_empty_tensor(x.shape.clone(), x.dtype);
+
+        let desc = MaxPool3dWithIndicesBackwardOpIr {
+            x: x.into_ir(),
+            grad: output_grad.into_ir(),
+            indices: indices.into_ir(),
+            kernel_size,
+            stride,
+            padding,
+            dilation,
+            out: out.to_ir_out(),
+        };
+
+        client.register(OperationIr::Module(
+            ModuleOperationIr::MaxPool3dWithIndicesBackward(desc),
+        ));
+
+        MaxPool3dBackward::new(out)
+    }
+
     fn adaptive_avg_pool1d(x: FloatTensor<Self>, output_size: usize) -> FloatTensor<Self> {
         let shape = vec![x.shape[0], x.shape[1], output_size];
 
@@ -624,6 +814,31 @@ impl<R: RunnerChannel> ModuleOps<Self> for BackendRouter<R> {
         out
     }
 
+    fn adaptive_avg_pool3d(x: FloatTensor<Self>, output_size: [usize; 3]) -> FloatTensor<Self> {
+        let shape = vec![
+            x.shape[0],
+            x.shape[1],
+            output_size[0],
+            output_size[1],
+            output_size[2],
+        ];
+
+        let client = x.client.clone();
+        let out = client.register_empty_tensor(shape.clone(), x.dtype);
+
+        let desc = AdaptiveAvgPool3dOpIr {
+            x: x.into_ir(),
+            output_size,
+            out: out.to_ir_out(),
+        };
+
+        client.register(OperationIr::Module(ModuleOperationIr::AdaptiveAvgPool3d(
+            desc,
+        )));
+
+        out
+    }
+
     fn adaptive_avg_pool1d_backward(
         x: FloatTensor<Self>,
         grad: FloatTensor<Self>,
@@ -664,6 +879,26 @@ impl<R: RunnerChannel> ModuleOps<Self> for BackendRouter<R> {
         out
     }
 
+    fn adaptive_avg_pool3d_backward(
+        x: FloatTensor<Self>,
+        grad: FloatTensor<Self>,
+    ) -> FloatTensor<Self> {
+        let client = x.client.clone();
+        let out = client.register_empty_tensor(x.shape.clone(), x.dtype);
+
+        let desc = AdaptiveAvgPool3dBackwardOpIr {
+            x: x.into_ir(),
+            grad: grad.into_ir(),
+            out: out.to_ir_out(),
+        };
+
+        client.register(OperationIr::Module(
+            ModuleOperationIr::AdaptiveAvgPool3dBackward(desc),
+        ));
+
+        out
+    }
+
     fn interpolate(
         x: FloatTensor<Self>,
         output_size: [usize; 2],
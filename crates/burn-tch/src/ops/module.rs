@@ -4,7 +4,7 @@ use burn_tensor::{
     ops::{
         ConvOptions, ConvTransposeOptions, DeformConv2dBackward, DeformConvOptions,
         InterpolateMode, InterpolateOptions, MaxPool1dWithIndices, MaxPool2dBackward,
-        MaxPool2dWithIndices, ModuleOps,
+        MaxPool2dWithIndices, MaxPool3dBackward, MaxPool3dWithIndices, ModuleOps,
     },
 };
 
@@ -229,6 +229,56 @@ impl<E: TchElement> ModuleOps<Self> for LibTorch<E> {
         TchTensor::new(tensor)
     }
 
+    fn avg_pool3d(
+        x: TchTensor,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        count_include_pad: bool,
+    ) -> TchTensor {
+        let tensor = tch::Tensor::avg_pool3d(
+            &x.tensor,
+            [
+                kernel_size[0] as i64,
+                kernel_size[1] as i64,
+                kernel_size[2] as i64,
+            ],
+            [stride[0] as i64, stride[1] as i64, stride[2] as i64],
+            [padding[0] as i64, padding[1] as i64, padding[2] as i64],
+            false,
+            count_include_pad,
+            None,
+        );
+
+        TchTensor::new(tensor)
+    }
+
+    fn avg_pool3d_backward(
+        x: TchTensor,
+        grad: TchTensor,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        count_include_pad: bool,
+    ) -> TchTensor {
+        let tensor = tch::Tensor::avg_pool3d_backward(
+            &x.tensor,
+            &grad.tensor,
+            [
+                kernel_size[0] as i64,
+                kernel_size[1] as i64,
+                kernel_size[2] as i64,
+            ],
+            [stride[0] as i64, stride[1] as i64, stride[2] as i64],
+            [padding[0] as i64, padding[1] as i64, padding[2] as i64],
+            false,
+            count_include_pad,
+            None,
+        );
+
+        TchTensor::new(tensor)
+    }
+
     fn max_pool1d(
         x: TchTensor,
         kernel_size: usize,
@@ -328,6 +378,79 @@ impl<E: TchElement> ModuleOps<Self> for LibTorch<E> {
         MaxPool2dBackward::new(TchTensor::new(grad))
     }
 
+    fn max_pool3d(
+        x: TchTensor,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        dilation: [usize; 3],
+    ) -> TchTensor {
+        let tensor = tch::Tensor::max_pool3d(
+            &x.tensor,
+            [
+                kernel_size[0] as i64,
+                kernel_size[1] as i64,
+                kernel_size[2] as i64,
+            ],
+            [stride[0] as i64, stride[1] as i64, stride[2] as i64],
+            [padding[0] as i64, padding[1] as i64, padding[2] as i64],
+            [dilation[0] as i64, dilation[1] as i64, dilation[2] as i64],
+            false,
+        );
+
+        TchTensor::new(tensor)
+    }
+
+    fn max_pool3d_with_indices(
+        x: TchTensor,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        dilation: [usize; 3],
+    ) -> MaxPool3dWithIndices<LibTorch<E>> {
+        let (tensor, indices) = tch::Tensor::max_pool3d_with_indices(
+            &x.tensor,
+            [
+                kernel_size[0] as i64,
+                kernel_size[1] as i64,
+                kernel_size[2] as i64,
+            ],
+            [stride[0] as i64, stride[1] as i64, stride[2] as i64],
+            [padding[0] as i64, padding[1] as i64, padding[2] as i64],
+            [dilation[0] as i64, dilation[1] as i64, dilation[2] as i64],
+            false,
+        );
+
+        MaxPool3dWithIndices::new(TchTensor::new(tensor), TchTensor::new(indices))
+    }
+
+    fn max_pool3d_with_indices_backward(
+        x: TchTensor,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        dilation: [usize; 3],
+        output_grad: TchTensor,
+        indices: TchTensor,
+    ) -> MaxPool3dBackward<LibTorch<E>> {
+        let grad = tch::Tensor::max_pool3d_with_indices_backward(
+            &x.tensor,
+            &output_grad.tensor,
+            [
+                kernel_size[0] as i64,
+                kernel_size[1] as i64,
+                kernel_size[2] as i64,
+            ],
+            [stride[0] as i64, stride[1] as i64, stride[2] as i64],
+            [padding[0] as i64, padding[1] as i64, padding[2] as i64],
+            [dilation[0] as i64, dilation[1] as i64, dilation[2] as i64],
+            false,
+            &indices.tensor,
+        );
+
+        MaxPool3dBackward::new(TchTensor::new(grad))
+    }
+
     fn adaptive_avg_pool2d(x: TchTensor, output_size: [usize; 2]) -> TchTensor {
         let tensor = tch::Tensor::adaptive_avg_pool2d(&x.tensor, output_size.map(|e| e as i64));
 
@@ -340,6 +463,18 @@ impl<E: TchElement> ModuleOps<Self> for LibTorch<E> {
         TchTensor::new(tensor)
     }
 
+    fn adaptive_avg_pool3d(x: TchTensor, output_size: [usize; 3]) -> TchTensor {
+        let tensor = tch::Tensor::adaptive_avg_pool3d(&x.tensor, output_size.map(|e| e as i64));
+
+        TchTensor::new(tensor)
+    }
+
+    fn adaptive_avg_pool3d_backward(x: TchTensor, grad: TchTensor) -> TchTensor {
+        let tensor = tch::Tensor::internal_adaptive_avg_pool3d_backward(&x.tensor, &grad.tensor);
+
+        TchTensor::new(tensor)
+    }
+
     fn adaptive_avg_pool1d(x: TchTensor, output_size: usize) -> TchTensor {
         let tensor = tch::Tensor::adaptive_avg_pool1d(&x.tensor, output_size as i64);
 
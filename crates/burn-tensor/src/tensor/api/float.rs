@@ -397,6 +397,32 @@ where
         Tensor::new(TensorPrimitive::Float(self.primitive.tensor()))
     }
 
+    /// Quantize and dequantize the tensor, simulating the quantization error in full precision.
+    ///
+    /// When differentiated, the gradient of the input uses a straight-through estimator and is
+    /// only propagated for values inside the quantization range. The gradient of the scales
+    /// follows [LSQ](https://arxiv.org/abs/1902.08153), so they can be learned.
+    ///
+    /// # Arguments
+    ///
+    /// * `scheme` - The quantization scheme.
+    /// * `qparams` - The quantization parameters.
+    ///
+    /// # Returns
+    ///
+    /// The fake quantized tensor.
+    pub fn fake_quantize(
+        self,
+        scheme: &QuantScheme,
+        qparams: QuantizationParameters<B>,
+    ) -> Tensor<B, D> {
+        Tensor::new(TensorPrimitive::Float(B::fake_quantize(
+            self.primitive.tensor(),
+            scheme,
+            qparams.into(),
+        )))
+    }
+
     /// Checks element wise if the tensor is close to another tensor.
     ///
     /// The tolerance is defined by the following equation:
@@ -1,3 +1,4 @@
+use alloc::vec;
 use alloc::vec::Vec;
 
 use crate::alloc::borrow::ToOwned;
@@ -516,6 +517,48 @@ where
         Self::new(K::sum_dim(self.primitive, dim))
     }
 
+    /// Computes the cumulative sum of the elements along the given *dimension* or *axis*.
+    ///
+    /// The output has the same shape as the input, where each element is the sum of the elements
+    /// up to and including it along the dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension or axis along which to accumulate the elements.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Tensor, Shape};
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = B::Device::default();
+    ///    let tensor = Tensor::<B, 2>::from_data([[1.0, -2.0, 3.0], [5.0, 9.0, 6.0]], &device);
+    ///    let tensor = tensor.cumsum(1);
+    ///    println!("{tensor}");
+    ///    // [[1.0, -1.0, 2.0], [5.0, 14.0, 20.0]]
+    /// }
+    /// ```
+    pub fn cumsum(self, dim: usize) -> Self {
+        check!(TensorCheck::aggregate_dim::<D>("CumSum", dim));
+        let size = self.dims()[dim];
+
+        // Inclusive scan that adds the partial sums `shift` positions before, doubling the shift
+        // at each step, so it only needs a logarithmic number of operations.
+        let mut output = self;
+        let mut shift = 1;
+        while shift < size {
+            let head = output.clone().narrow(dim, 0, shift);
+            let shifted = output.clone().narrow(dim, 0, size - shift);
+            let tail = output.narrow(dim, shift, size - shift).add(shifted);
+            output = Tensor::cat(vec![head, tail], dim);
+            shift *= 2;
+        }
+
+        output
+    }
+
     /// Aggregate all elements in the tensor with the product operation.
     ///
     /// # Example
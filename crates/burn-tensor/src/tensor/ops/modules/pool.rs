@@ -1,10 +1,10 @@
 use crate::{
-    Shape, TensorMetadata,
+    ElementConversion, Shape, TensorMetadata,
     backend::Backend,
     ops::{FloatTensor, IntTensor},
 };
 
-use super::{MaxPool1dBackward, MaxPool1dWithIndices};
+use super::{MaxPool1dBackward, MaxPool1dWithIndices, MaxPool3dBackward, MaxPool3dWithIndices};
 
 pub(crate) fn avg_pool1d_from_2d<B: Backend>(
     x: FloatTensor<B>,
@@ -165,3 +165,276 @@ pub(crate) fn max_pool1d_with_indices_backward_from_2d<B: Backend>(
         Shape::from([batch_size, channels, length_in]),
     ))
 }
+
+// 3D pooling is separable: the height and width are pooled first with the depth folded into the
+// channels, then the depth is pooled with the height and width flattened into a single dimension.
+
+pub(crate) fn avg_pool3d_from_2d<B: Backend>(
+    x: FloatTensor<B>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+    count_include_pad: bool,
+) -> FloatTensor<B> {
+    let [batch_size, channels, depth, height, width] = x.shape().dims();
+
+    let x = B::float_reshape(
+        x,
+        Shape::from([batch_size, channels * depth, height, width]),
+    );
+    let x = B::avg_pool2d(
+        x,
+        [kernel_size[1], kernel_size[2]],
+        [stride[1], stride[2]],
+        [padding[1], padding[2]],
+        count_include_pad,
+    );
+
+    let [_, _, height, width] = x.shape().dims();
+    let x = B::float_reshape(
+        x,
+        Shape::from([batch_size, channels, depth, height * width]),
+    );
+    let x = B::avg_pool2d(
+        x,
+        [kernel_size[0], 1],
+        [stride[0], 1],
+        [padding[0], 0],
+        count_include_pad,
+    );
+
+    let [_, _, depth, _] = x.shape().dims();
+
+    B::float_reshape(x, Shape::from([batch_size, channels, depth, height, width]))
+}
+
+pub(crate) fn avg_pool3d_backward_from_2d<B: Backend>(
+    x: FloatTensor<B>,
+    grad: FloatTensor<B>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+    count_include_pad: bool,
+) -> FloatTensor<B> {
+    let [batch_size, channels, depth_in, height_in, width_in] = x.shape().dims();
+    let [_, _, depth_out, height_out, width_out] = grad.shape().dims();
+
+    // Only the shape of the intermediate input is needed by the backward pass.
+    let x_depth = B::float_zeros(
+        Shape::from([batch_size, channels, depth_in, height_out * width_out]),
+        &B::float_device(&x),
+        x.dtype().into(),
+    );
+    let grad = B::float_reshape(
+        grad,
+        Shape::from([batch_size, channels, depth_out, height_out * width_out]),
+    );
+    let grad = B::avg_pool2d_backward(
+        x_depth,
+        grad,
+        [kernel_size[0], 1],
+        [stride[0], 1],
+        [padding[0], 0],
+        count_include_pad,
+    );
+
+    let x = B::float_reshape(
+        x,
+        Shape::from([batch_size, channels * depth_in, height_in, width_in]),
+    );
+    let grad = B::float_reshape(
+        grad,
+        Shape::from([batch_size, channels * depth_in, height_out, width_out]),
+    );
+    let grad = B::avg_pool2d_backward(
+        x,
+        grad,
+        [kernel_size[1], kernel_size[2]],
+        [stride[1], stride[2]],
+        [padding[1], padding[2]],
+        count_include_pad,
+    );
+
+    B::float_reshape(
+        grad,
+        Shape::from([batch_size, channels, depth_in, height_in, width_in]),
+    )
+}
+
+pub(crate) fn adaptive_avg_pool3d_from_2d<B: Backend>(
+    x: FloatTensor<B>,
+    output_size: [usize; 3],
+) -> FloatTensor<B> {
+    let [batch_size, channels, depth, height, width] = x.shape().dims();
+    let [depth_out, height_out, width_out] = output_size;
+
+    let x = B::float_reshape(
+        x,
+        Shape::from([batch_size, channels * depth, height, width]),
+    );
+    let x = B::adaptive_avg_pool2d(x, [height_out, width_out]);
+
+    let x = B::float_reshape(
+        x,
+        Shape::from([batch_size, channels, depth, height_out * width_out]),
+    );
+    let x = B::adaptive_avg_pool2d(x, [depth_out, height_out * width_out]);
+
+    B::float_reshape(
+        x,
+        Shape::from([batch_size, channels, depth_out, height_out, width_out]),
+    )
+}
+
+pub(crate) fn adaptive_avg_pool3d_backward_from_2d<B: Backend>(
+    x: FloatTensor<B>,
+    grad: FloatTensor<B>,
+) -> FloatTensor<B> {
+    let [batch_size, channels, depth_in, height_in, width_in] = x.shape().dims();
+    let [_, _, depth_out, height_out, width_out] = grad.shape().dims();
+
+    // Only the shape of the intermediate input is needed by the backward pass.
+    let x_depth = B::float_zeros(
+        Shape::from([batch_size, channels, depth_in, height_out * width_out]),
+        &B::float_device(&x),
+        x.dtype().into(),
+    );
+    let grad = B::float_reshape(
+        grad,
+        Shape::from([batch_size, channels, depth_out, height_out * width_out]),
+    );
+    let grad = B::adaptive_avg_pool2d_backward(x_depth, grad);
+
+    let x = B::float_reshape(
+        x,
+        Shape::from([batch_size, channels * depth_in, height_in, width_in]),
+    );
+    let grad = B::float_reshape(
+        grad,
+        Shape::from([batch_size, channels * depth_in, height_out, width_out]),
+    );
+    let grad = B::adaptive_avg_pool2d_backward(x, grad);
+
+    B::float_reshape(
+        grad,
+        Shape::from([batch_size, channels, depth_in, height_in, width_in]),
+    )
+}
+
+pub(crate) fn max_pool3d_from_2d<B: Backend>(
+    x: FloatTensor<B>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+    dilation: [usize; 3],
+) -> FloatTensor<B> {
+    let [batch_size, channels, depth, height, width] = x.shape().dims();
+
+    let x = B::float_reshape(
+        x,
+        Shape::from([batch_size, channels * depth, height, width]),
+    );
+    let x = B::max_pool2d(
+        x,
+        [kernel_size[1], kernel_size[2]],
+        [stride[1], stride[2]],
+        [padding[1], padding[2]],
+        [dilation[1], dilation[2]],
+    );
+
+    let [_, _, height, width] = x.shape().dims();
+    let x = B::float_reshape(
+        x,
+        Shape::from([batch_size, channels, depth, height * width]),
+    );
+    let x = B::max_pool2d(
+        x,
+        [kernel_size[0], 1],
+        [stride[0], 1],
+        [padding[0], 0],
+        [dilation[0], 1],
+    );
+
+    let [_, _, depth, _] = x.shape().dims();
+
+    B::float_reshape(x, Shape::from([batch_size, channels, depth, height, width]))
+}
+
+pub(crate) fn max_pool3d_with_indices_from_2d<B: Backend>(
+    x: FloatTensor<B>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+    dilation: [usize; 3],
+) -> MaxPool3dWithIndices<B> {
+    let [batch_size, channels, depth, height_in, width_in] = x.shape().dims();
+
+    let x = B::float_reshape(
+        x,
+        Shape::from([batch_size, channels * depth, height_in, width_in]),
+    );
+    let plane = B::max_pool2d_with_indices(
+        x,
+        [kernel_size[1], kernel_size[2]],
+        [stride[1], stride[2]],
+        [padding[1], padding[2]],
+        [dilation[1], dilation[2]],
+    );
+
+    let [_, _, height, width] = plane.output.shape().dims();
+    let shape = Shape::from([batch_size, channels, depth, height * width]);
+    let x = B::float_reshape(plane.output, shape.clone());
+    let plane_indices = B::int_reshape(plane.indices, shape);
+    let volume = B::max_pool2d_with_indices(
+        x,
+        [kernel_size[0], 1],
+        [stride[0], 1],
+        [padding[0], 0],
+        [dilation[0], 1],
+    );
+
+    // The depth pooling returns `depth_index * height * width + position`, the position being
+    // unchanged since the last dimension isn't pooled.
+    let depth_indices = B::int_div_scalar(volume.indices, ((height * width) as i64).elem());
+    let plane_indices = B::int_gather(2, plane_indices, depth_indices.clone());
+    let indices = B::int_add(
+        B::int_mul_scalar(depth_indices, ((height_in * width_in) as i64).elem()),
+        plane_indices,
+    );
+
+    let [_, _, depth, _] = volume.output.shape().dims();
+    let shape = Shape::from([batch_size, channels, depth, height, width]);
+
+    MaxPool3dWithIndices::new(
+        B::float_reshape(volume.output, shape.clone()),
+        B::int_reshape(indices, shape),
+    )
+}
+
+pub(crate) fn max_pool3d_with_indices_backward_from_scatter<B: Backend>(
+    x: FloatTensor<B>,
+    output_grad: FloatTensor<B>,
+    indices: IntTensor<B>,
+) -> MaxPool3dBackward<B> {
+    let [batch_size, channels, depth_in, height_in, width_in] = x.shape().dims();
+    let [_, _, depth_out, height_out, width_out] = output_grad.shape().dims();
+    let num_elems_in = depth_in * height_in * width_in;
+    let num_elems_out = depth_out * height_out * width_out;
+
+    let x_grad = B::float_zeros(
+        Shape::from([batch_size, channels, num_elems_in]),
+        &B::float_device(&x),
+        x.dtype().into(),
+    );
+    let output_grad = B::float_reshape(
+        output_grad,
+        Shape::from([batch_size, channels, num_elems_out]),
+    );
+    let indices = B::int_reshape(indices, Shape::from([batch_size, channels, num_elems_out]));
+    let x_grad = B::float_scatter(2, x_grad, indices, output_grad);
+
+    MaxPool3dBackward::new(B::float_reshape(
+        x_grad,
+        Shape::from([batch_size, channels, depth_in, height_in, width_in]),
+    ))
+}
@@ -3,11 +3,11 @@ use core::ops::Range;
 use cubecl_quant::scheme::QuantScheme;
 
 use crate::{
-    Device, Shape, TensorData, TensorMetadata, TensorPrimitive,
+    Device, ElementConversion, Shape, TensorData, TensorMetadata, TensorPrimitive,
     backend::Backend,
     quantization::{
         Calibration, QTensorPrimitive, QuantPropagation, QuantizationParametersPrimitive,
-        compute_q_params_primitive, compute_range_primitive,
+        compute_q_params_primitive, compute_range_primitive, quantization_blocks,
     },
 };
 
@@ -146,6 +146,33 @@ pub trait QTensorOps<B: Backend> {
     /// Convert the tensor back to a higher precision data type.
     fn dequantize(tensor: QuantizedTensor<B>) -> FloatTensor<B>;
 
+    /// Quantize and dequantize the tensor, simulating the quantization error in full precision.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `scheme` - The quantization scheme.
+    /// * `qparams` - The quantization parameters.
+    ///
+    /// # Returns
+    ///
+    /// The fake quantized tensor.
+    fn fake_quantize(
+        tensor: FloatTensor<B>,
+        scheme: &QuantScheme,
+        qparams: QuantizationParametersPrimitive<B>,
+    ) -> FloatTensor<B> {
+        let shape = tensor.shape();
+        let (a, b) = scheme.value.range();
+        let (blocks, scales) = quantization_blocks::<B>(scheme, tensor, qparams.scales);
+
+        // x_q = clamp(round(x / scale), a, b) * scale
+        let values = B::float_round(B::float_div(blocks, scales.clone()));
+        let values = B::float_clamp(values, a.elem(), b.elem());
+
+        B::float_reshape(B::float_mul(values, scales), shape)
+    }
+
     /// Gets the device of the tensor.
     ///
     /// # Arguments
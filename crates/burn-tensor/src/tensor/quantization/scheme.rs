@@ -90,6 +90,35 @@ pub(crate) fn compute_range_primitive<B: Backend>(
     }
 }
 
+/// Reshape the tensor into `[num_blocks, block_size]` and the scales into `[num_blocks, 1]`, so
+/// that each row is quantized with its own scale.
+///
+/// With [tensor level](QuantLevel::Tensor) quantization, the whole tensor is a single block.
+pub fn quantization_blocks<B: Backend>(
+    scheme: &QuantScheme,
+    tensor: B::FloatTensorPrimitive,
+    scales: B::FloatTensorPrimitive,
+) -> (B::FloatTensorPrimitive, B::FloatTensorPrimitive) {
+    let shape = tensor.shape();
+    let numel = shape.num_elements();
+    let num_blocks = match scheme.level {
+        QuantLevel::Tensor => 1,
+        QuantLevel::Block(block_size) => {
+            assert_eq!(
+                numel % block_size,
+                0,
+                "Tensor {shape:?} must be evenly divisible by block size {block_size}"
+            );
+            numel / block_size
+        }
+    };
+
+    (
+        B::float_reshape(tensor, Shape::new([num_blocks, numel / num_blocks])),
+        B::float_reshape(scales, Shape::new([num_blocks, 1])),
+    )
+}
+
 /// Compute the quantization parameters.
 pub fn compute_q_params<B: Backend>(
     scheme: &QuantScheme,
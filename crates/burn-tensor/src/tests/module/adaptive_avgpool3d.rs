@@ -0,0 +1,73 @@
+#[burn_tensor_testgen::testgen(module_adaptive_avg_pool3d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::adaptive_avg_pool3d;
+    use burn_tensor::{Shape, Tensor};
+    use burn_tensor::{Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn test_adaptive_avg_pool3d_simple() {
+        let test = AdaptiveAvgPool3dTestCase {
+            batch_size: 1,
+            channels: 1,
+            depth: 4,
+            height: 4,
+            width: 4,
+            output_size: [2, 2, 2],
+        };
+
+        test.assert_output(TestTensor::from([[[
+            [[10.5, 12.5], [18.5, 20.5]],
+            [[42.5, 44.5], [50.5, 52.5]],
+        ]]]));
+    }
+
+    #[test]
+    fn test_adaptive_avg_pool3d_dyn_filter_size() {
+        let test = AdaptiveAvgPool3dTestCase {
+            batch_size: 1,
+            channels: 1,
+            depth: 5,
+            height: 3,
+            width: 4,
+            output_size: [3, 2, 3],
+        };
+
+        test.assert_output(TestTensor::from([[[
+            [[8.5, 9.5, 10.5], [12.5, 13.5, 14.5]],
+            [[26.5, 27.5, 28.5], [30.5, 31.5, 32.5]],
+            [[44.5, 45.5, 46.5], [48.5, 49.5, 50.5]],
+        ]]]));
+    }
+
+    struct AdaptiveAvgPool3dTestCase {
+        batch_size: usize,
+        channels: usize,
+        depth: usize,
+        height: usize,
+        width: usize,
+        output_size: [usize; 3],
+    }
+
+    impl AdaptiveAvgPool3dTestCase {
+        fn assert_output(self, y: TestTensor<5>) {
+            let shape_x = Shape::new([
+                self.batch_size,
+                self.channels,
+                self.depth,
+                self.height,
+                self.width,
+            ]);
+            let x = TestTensor::from(
+                TestTensorInt::arange(0..shape_x.num_elements() as i64, &y.device())
+                    .reshape::<5, _>(shape_x)
+                    .into_data(),
+            );
+            let output = adaptive_avg_pool3d(x, self.output_size);
+
+            y.to_data()
+                .assert_approx_eq::<FT>(&output.into_data(), Tolerance::default());
+        }
+    }
+}
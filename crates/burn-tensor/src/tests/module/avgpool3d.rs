@@ -0,0 +1,109 @@
+#[burn_tensor_testgen::testgen(module_avg_pool3d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::avg_pool3d;
+    use burn_tensor::{Shape, Tensor};
+    use burn_tensor::{Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn test_avg_pool3d_simple() {
+        let test = AvgPool3dTestCase {
+            batch_size: 1,
+            channels: 1,
+            kernel_size: [2, 2, 2],
+            padding: [0, 0, 0],
+            stride: [2, 2, 2],
+            depth: 4,
+            height: 4,
+            width: 4,
+            count_include_pad: true,
+        };
+
+        test.assert_output(TestTensor::from([[[
+            [[10.5, 12.5], [18.5, 20.5]],
+            [[42.5, 44.5], [50.5, 52.5]],
+        ]]]));
+    }
+
+    #[test]
+    fn test_avg_pool3d_complex() {
+        let test = AvgPool3dTestCase {
+            batch_size: 1,
+            channels: 1,
+            kernel_size: [3, 3, 2],
+            padding: [1, 1, 1],
+            stride: [2, 2, 2],
+            depth: 3,
+            height: 4,
+            width: 4,
+            count_include_pad: true,
+        };
+
+        test.assert_output(TestTensor::from([[[
+            [[2.2222, 5.1111, 2.8889], [5.3333, 11.6667, 6.3333]],
+            [[5.7778, 12.2222, 6.4444], [10.6667, 22.3333, 11.6667]],
+        ]]]));
+    }
+
+    #[test]
+    fn test_avg_pool3d_complex_dont_include_pad() {
+        let test = AvgPool3dTestCase {
+            batch_size: 1,
+            channels: 1,
+            kernel_size: [3, 3, 2],
+            padding: [1, 1, 1],
+            stride: [2, 2, 2],
+            depth: 3,
+            height: 4,
+            width: 4,
+            count_include_pad: false,
+        };
+
+        test.assert_output(TestTensor::from([[[
+            [[10.0, 11.5, 13.0], [16.0, 17.5, 19.0]],
+            [[26.0, 27.5, 29.0], [32.0, 33.5, 35.0]],
+        ]]]));
+    }
+
+    struct AvgPool3dTestCase {
+        batch_size: usize,
+        channels: usize,
+        kernel_size: [usize; 3],
+        padding: [usize; 3],
+        stride: [usize; 3],
+        depth: usize,
+        height: usize,
+        width: usize,
+        count_include_pad: bool,
+    }
+
+    impl AvgPool3dTestCase {
+        fn assert_output(self, y: TestTensor<5>) {
+            let shape_x = Shape::new([
+                self.batch_size,
+                self.channels,
+                self.depth,
+                self.height,
+                self.width,
+            ]);
+            let x = TestTensor::from(
+                TestTensorInt::arange(0..shape_x.num_elements() as i64, &y.device())
+                    .reshape::<5, _>(shape_x)
+                    .into_data(),
+            );
+            let output = avg_pool3d(
+                x,
+                self.kernel_size,
+                self.stride,
+                self.padding,
+                self.count_include_pad,
+            );
+
+            y.to_data().assert_approx_eq::<FT>(
+                &output.into_data(),
+                Tolerance::default().set_half_precision_relative(1e-3),
+            );
+        }
+    }
+}
@@ -0,0 +1,117 @@
+#[burn_tensor_testgen::testgen(module_max_pool3d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::{max_pool3d, max_pool3d_with_indices};
+    use burn_tensor::{Shape, Tensor, TensorData};
+    use burn_tensor::{Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn test_max_pool3d_simple() {
+        let test = MaxPool3dTestCase {
+            batch_size: 1,
+            channels: 1,
+            kernel_size: [2, 2, 2],
+            padding: [0, 0, 0],
+            stride: [2, 2, 2],
+            dilation: [1, 1, 1],
+            depth: 4,
+            height: 4,
+            width: 4,
+        };
+
+        test.assert_output(
+            TestTensor::from([[[[[57., 63.], [60., 59.]], [[52., 62.], [61., 55.]]]]]),
+            TensorData::from([[[[[5, 19], [12, 31]], [[36, 38], [57, 43]]]]]),
+        );
+    }
+
+    #[test]
+    fn test_max_pool3d_different_padding_stride_kernel_dilation() {
+        let test = MaxPool3dTestCase {
+            batch_size: 1,
+            channels: 2,
+            kernel_size: [3, 2, 2],
+            padding: [1, 1, 0],
+            stride: [1, 2, 1],
+            dilation: [1, 1, 2],
+            depth: 3,
+            height: 4,
+            width: 3,
+        };
+
+        test.assert_output(
+            TestTensor::from([[
+                [
+                    [[14.], [53.], [59.]],
+                    [[26.], [65.], [71.]],
+                    [[26.], [65.], [71.]],
+                ],
+                [
+                    [[50.], [56.], [23.]],
+                    [[62.], [68.], [35.]],
+                    [[62.], [68.], [35.]],
+                ],
+            ]]),
+            TensorData::from([[
+                [[[14], [17], [23]], [[26], [29], [35]], [[26], [29], [35]]],
+                [[[14], [20], [23]], [[26], [32], [35]], [[26], [32], [35]]],
+            ]]),
+        );
+    }
+
+    struct MaxPool3dTestCase {
+        batch_size: usize,
+        channels: usize,
+        kernel_size: [usize; 3],
+        padding: [usize; 3],
+        stride: [usize; 3],
+        dilation: [usize; 3],
+        depth: usize,
+        height: usize,
+        width: usize,
+    }
+
+    impl MaxPool3dTestCase {
+        fn assert_output(self, y: TestTensor<5>, y_indices: TensorData) {
+            let shape_x = Shape::new([
+                self.batch_size,
+                self.channels,
+                self.depth,
+                self.height,
+                self.width,
+            ]);
+            let num_elements = shape_x.num_elements() as i64;
+            // Shuffle the values with a multiplier coprime with the number of elements, so that
+            // the maximum isn't always the last element of the window.
+            let x = TestTensor::from(
+                TestTensorInt::arange(0..num_elements, &y.device())
+                    .mul_scalar(37)
+                    .remainder_scalar(num_elements)
+                    .reshape::<5, _>(shape_x)
+                    .into_data(),
+            );
+
+            let output = max_pool3d(
+                x.clone(),
+                self.kernel_size,
+                self.stride,
+                self.padding,
+                self.dilation,
+            );
+            let (output_with_indices, indices) = max_pool3d_with_indices(
+                x,
+                self.kernel_size,
+                self.stride,
+                self.padding,
+                self.dilation,
+            );
+
+            y.to_data()
+                .assert_approx_eq::<FT>(&output.into_data(), Tolerance::default());
+            y.to_data()
+                .assert_approx_eq::<FT>(&output_with_indices.into_data(), Tolerance::default());
+            indices.into_data().assert_eq(&y_indices, false);
+        }
+    }
+}
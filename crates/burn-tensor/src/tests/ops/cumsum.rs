@@ -0,0 +1,49 @@
+#[burn_tensor_testgen::testgen(cumsum)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_cumsum_float_dim_0() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from_data([[1.0, -2.0, 3.0], [5.0, 9.0, 6.0]], &device);
+
+        let output = tensor.cumsum(0);
+        let expected = TensorData::from([[1.0, -2.0, 3.0], [6.0, 7.0, 9.0]]);
+
+        output.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn should_cumsum_float_dim_1() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from_data([[1.0, -2.0, 3.0], [5.0, 9.0, 6.0]], &device);
+
+        let output = tensor.cumsum(1);
+        let expected = TensorData::from([[1.0, -1.0, 2.0], [5.0, 14.0, 20.0]]);
+
+        output.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn should_cumsum_int_non_power_of_two() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<1>::arange(1..8, &device);
+
+        let output = tensor.cumsum(0);
+        let expected = TensorData::from([1, 3, 6, 10, 15, 21, 28]);
+
+        output.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn should_cumsum_single_element() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<2>::from_data([[4], [7]], &device);
+
+        let output = tensor.cumsum(1);
+        let expected = TensorData::from([[4], [7]]);
+
+        output.into_data().assert_eq(&expected, false);
+    }
+}
@@ -226,4 +226,26 @@ mod tests {
             .into_data()
             .assert_approx_eq::<FT>(&expected, Tolerance::default());
     }
+
+    #[test]
+    fn should_support_fake_quantize_per_block() {
+        let device = Default::default();
+        let tensor =
+            TestTensor::<1>::from_floats([-1.8, -1.0, 0.0, 0.5, 0.01, 0.025, 0.03, 0.04], &device);
+        let scheme = QuantizedTensor::<TestBackend>::default_scheme()
+            .with_value(QuantValue::Q8S)
+            .with_level(QuantLevel::Block(4));
+        let qparams = QuantizationParameters {
+            scales: Tensor::from_floats([0.014_173_228, 0.000_314_96], &device),
+        };
+
+        let x = tensor.fake_quantize(&scheme, qparams);
+
+        x.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([
+                -1.8, -1.0062993, 0.0, 0.496063, 0.0100787, 0.0248818, 0.0299213, 0.04,
+            ]),
+            Tolerance::default(),
+        );
+    }
 }
@@ -37,7 +37,7 @@ impl<EC: EvaluatorComponentTypes> Evaluator<EC> {
             iteration += 1;
 
             let item = self.model.step(item);
-            let item = LearnerItem::new(item, progress, 0, 1, iteration, None);
+            let item = LearnerItem::new(item, progress, 0, 1, iteration, None, None);
 
             self.event_processor
                 .process_test(EvaluatorEvent::ProcessedItem(name.clone(), item));
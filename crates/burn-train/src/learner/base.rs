@@ -19,6 +19,7 @@ pub struct Learner<LC: LearnerComponentTypes> {
     pub(crate) num_epochs: usize,
     pub(crate) checkpoint: Option<usize>,
     pub(crate) grad_accumulation: Option<usize>,
+    pub(crate) grad_max_norm: Option<f32>,
     pub(crate) checkpointer: Option<LearnerCheckpointer<LC>>,
     pub(crate) learning_strategy: LearningStrategy<LC::Backend>,
     pub(crate) interrupter: Interrupter,
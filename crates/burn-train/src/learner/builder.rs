@@ -57,6 +57,7 @@ where
     checkpoint: Option<usize>,
     directory: PathBuf,
     grad_accumulation: Option<usize>,
+    grad_max_norm: Option<f32>,
     learning_strategy: LearningStrategy<B>,
     renderer: Option<Box<dyn MetricsRenderer + 'static>>,
     metrics: MetricsTraining<TO, VO>,
@@ -98,6 +99,7 @@ where
             checkpointers: None,
             directory,
             grad_accumulation: None,
+            grad_max_norm: None,
             learning_strategy: LearningStrategy::default(),
             metrics: MetricsTraining::default(),
             event_store: LogEventStore::default(),
@@ -210,6 +212,20 @@ where
         self
     }
 
+    /// Clip the gradients jointly by their global L2 norm before each optimizer step.
+    ///
+    /// # Notes
+    ///
+    /// The norm before clipping is reported by the [gradient norm metric](crate::metric::GradientNormMetric),
+    /// use `f32::INFINITY` to track it without clipping.
+    ///
+    /// With [distributed data parallel](LearningStrategy::DistributedDataParallel) training, the
+    /// gradients are clipped after being all-reduced, so every device applies the same scaling.
+    pub fn grads_clipping_global_norm(mut self, max_norm: f32) -> Self {
+        self.grad_max_norm = Some(max_norm);
+        self
+    }
+
     /// Register a [numeric](crate::metric::Numeric) training [metric](Metric).
     pub fn metric_train_numeric<Me>(mut self, metric: Me) -> Self
     where
@@ -395,6 +411,7 @@ where
             event_store,
             checkpoint: self.checkpoint,
             grad_accumulation: self.grad_accumulation,
+            grad_max_norm: self.grad_max_norm,
             learning_strategy,
             interrupter: self.interrupter,
             early_stopping: self.early_stopping,
@@ -2,13 +2,17 @@ use std::sync::Arc;
 
 #[cfg(feature = "ddp")]
 use burn_collective::CollectiveConfig;
-use burn_core::{module::AutodiffModule, tensor::backend::AutodiffBackend};
+use burn_core::{
+    grad_clipping::GradientClipping, module::AutodiffModule, optim::GradientsParams,
+    tensor::backend::AutodiffBackend,
+};
 
 use crate::{
     EarlyStoppingStrategyRef, Interrupter, Learner, LearnerCheckpointer, TrainLoader,
     TrainingResult, ValidLoader,
     components::LearnerComponentTypes,
     metric::{
+        GradientNorm,
         processor::{EventProcessorTraining, LearnerEvent},
         store::EventStoreClient,
     },
@@ -100,6 +104,7 @@ pub(crate) trait LearningMethod<LC: LearnerComponentTypes> {
             num_epochs: learner.num_epochs,
             checkpointer: learner.checkpointer,
             grad_accumulation: learner.grad_accumulation,
+            grad_max_norm: learner.grad_max_norm,
             interrupter: learner.interrupter,
             early_stopping: learner.early_stopping,
             event_processor: learner.event_processor,
@@ -157,9 +162,22 @@ pub(crate) struct LearnerComponents<LC: LearnerComponentTypes> {
     pub lr_scheduler: LC::LrScheduler,
     pub num_epochs: usize,
     pub grad_accumulation: Option<usize>,
+    pub grad_max_norm: Option<f32>,
     pub checkpointer: Option<LearnerCheckpointer<LC>>,
     pub interrupter: Interrupter,
     pub early_stopping: Option<EarlyStoppingStrategyRef>,
     pub event_processor: LC::EventProcessor,
     pub event_store: Arc<EventStoreClient>,
 }
+
+/// Clip the gradients by their global norm when a maximum norm is set.
+///
+/// Returns the norm before clipping, which stays on the device until a metric reads it.
+pub(crate) fn clip_grads_global_norm<B: AutodiffBackend>(
+    grads: &mut GradientsParams,
+    max_norm: Option<f32>,
+) -> Option<GradientNorm> {
+    let norm = GradientClipping::clip_gradients_global_norm::<B::InnerBackend>(grads, max_norm?)?;
+
+    Some(GradientNorm::new(norm))
+}
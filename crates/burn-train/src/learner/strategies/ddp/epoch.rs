@@ -6,6 +6,7 @@ use std::marker::PhantomData;
 use std::sync::mpsc::{Receiver, SyncSender};
 use std::sync::{Arc, Mutex};
 
+use crate::learner::strategies::clip_grads_global_norm;
 use crate::metric::processor::{EventProcessorTraining, LearnerEvent, LearnerItem};
 use crate::{TrainLoader, TrainStep, ValidLoader, ValidStep};
 use crate::{components::LearnerComponentTypes, learner::base::Interrupter};
@@ -25,6 +26,7 @@ pub struct DdpTrainEpoch<LC: LearnerComponentTypes> {
     epoch: usize,
     epoch_total: usize,
     grad_accumulation: Option<usize>,
+    grad_max_norm: Option<f32>,
 }
 
 impl<LC: LearnerComponentTypes> DdpValidEpoch<LC> {
@@ -58,6 +60,7 @@ impl<LC: LearnerComponentTypes> DdpValidEpoch<LC> {
                 self.epoch_total,
                 iteration,
                 None,
+                None,
             );
 
             processor.process_valid(LearnerEvent::ProcessedItem(item));
@@ -102,6 +105,8 @@ impl<LC: LearnerComponentTypes> DdpTrainEpoch<LC> {
         let mut iteration = 0;
         let mut accumulator = GradientsAccumulator::new();
         let mut accumulation_current = 0;
+        // Norm of the last optimizer step.
+        let mut grad_norm = None;
 
         let grads_syncer = GradsSyncer::<LC::Backend, LC::Model>::new(false, peer_id);
 
@@ -129,7 +134,11 @@ impl<LC: LearnerComponentTypes> DdpTrainEpoch<LC> {
 
                         // With double buffering, these are the previous iteration's gradients
                         let grads = grads_syncer.sync(grads);
-                        if let Some(grads) = grads {
+                        if let Some(mut grads) = grads {
+                            grad_norm = clip_grads_global_norm::<LC::Backend>(
+                                &mut grads,
+                                self.grad_max_norm,
+                            );
                             model = model.optimize(&mut optim, lr, grads);
                         }
 
@@ -140,7 +149,9 @@ impl<LC: LearnerComponentTypes> DdpTrainEpoch<LC> {
                     // With double buffering, these are the previous iteration's gradients
                     let grads = grads_syncer.sync(item.grads);
 
-                    if let Some(grads) = grads {
+                    if let Some(mut grads) = grads {
+                        grad_norm =
+                            clip_grads_global_norm::<LC::Backend>(&mut grads, self.grad_max_norm);
                         model = model.optimize(&mut optim, lr, grads);
                     }
                 }
@@ -153,6 +164,7 @@ impl<LC: LearnerComponentTypes> DdpTrainEpoch<LC> {
                 self.epoch_total,
                 iteration,
                 Some(lr),
+                grad_norm.clone(),
             );
 
             {
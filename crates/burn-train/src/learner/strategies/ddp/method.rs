@@ -86,6 +86,7 @@ impl<LC: LearnerComponentTypes + Send + 'static> LearningMethod<LC> for DdpLearn
             starting_epoch,
             components.num_epochs,
             components.grad_accumulation,
+            components.grad_max_norm,
             peer_count,
             true,
         );
@@ -111,6 +112,7 @@ impl<LC: LearnerComponentTypes + Send + 'static> LearningMethod<LC> for DdpLearn
                 starting_epoch,
                 components.num_epochs,
                 components.grad_accumulation,
+                components.grad_max_norm,
                 peer_count,
                 false,
             );
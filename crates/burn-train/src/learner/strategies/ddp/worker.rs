@@ -32,6 +32,7 @@ where
     starting_epoch: usize,
     num_epochs: usize,
     grad_accumulation: Option<usize>,
+    grad_max_norm: Option<f32>,
     peer_count: usize,
     is_main: bool,
     _p: PhantomData<LC>,
@@ -60,6 +61,7 @@ where
         starting_epoch: usize,
         num_epochs: usize,
         grad_accumulation: Option<usize>,
+        grad_max_norm: Option<f32>,
         peer_count: usize,
         is_main: bool,
     ) -> JoinHandle<LC::Model> {
@@ -80,6 +82,7 @@ where
             starting_epoch,
             num_epochs,
             grad_accumulation,
+            grad_max_norm,
             peer_count,
             is_main,
             _p: PhantomData,
@@ -103,6 +106,7 @@ where
             self.starting_epoch,
             self.num_epochs,
             self.grad_accumulation,
+            self.grad_max_norm,
         );
 
         for epoch in self.starting_epoch..self.num_epochs + 1 {
@@ -1,3 +1,4 @@
+use crate::learner::strategies::clip_grads_global_norm;
 use crate::metric::processor::{EventProcessorTraining, LearnerEvent, LearnerItem};
 use crate::{MultiDevicesTrainStep, TrainLoader, TrainStep};
 use crate::{components::LearnerComponentTypes, learner::base::Interrupter};
@@ -10,6 +11,7 @@ pub struct MultiDeviceTrainEpoch<LC: LearnerComponentTypes> {
     epoch: usize,
     epoch_total: usize,
     grad_accumulation: Option<usize>,
+    grad_max_norm: Option<f32>,
 }
 
 impl<LC: LearnerComponentTypes> MultiDeviceTrainEpoch<LC> {
@@ -56,6 +58,8 @@ impl<LC: LearnerComponentTypes> MultiDeviceTrainEpoch<LC> {
         // The main device is always the first in the list.
         let device_main = devices.first().expect("A minimum of one device.").clone();
         let mut interrupted = false;
+        // Norm of the last optimizer step.
+        let mut grad_norm = None;
 
         loop {
             let (items, progress) = step.step(iterators.as_mut_slice(), &model);
@@ -73,7 +77,9 @@ impl<LC: LearnerComponentTypes> MultiDeviceTrainEpoch<LC> {
                 accumulation_current += 1;
 
                 if accumulation <= accumulation_current {
-                    let grads = accumulator.grads();
+                    let mut grads = accumulator.grads();
+                    grad_norm =
+                        clip_grads_global_norm::<LC::Backend>(&mut grads, self.grad_max_norm);
                     model = model.optimize(&mut optim, lr, grads);
                     accumulation_current = 0;
                 }
@@ -85,6 +91,7 @@ impl<LC: LearnerComponentTypes> MultiDeviceTrainEpoch<LC> {
                     self.epoch_total,
                     iteration,
                     Some(lr),
+                    grad_norm.clone(),
                 );
 
                 processor.process_train(LearnerEvent::ProcessedItem(item));
@@ -56,6 +56,7 @@ impl<LC: LearnerComponentTypes> LearningMethod<LC> for MultiDeviceLearningStrate
             starting_epoch,
             components.num_epochs,
             components.grad_accumulation,
+            components.grad_max_norm,
         );
 
         for epoch in starting_epoch..components.num_epochs + 1 {
@@ -4,6 +4,7 @@ use burn_core::{lr_scheduler::LrScheduler, module::AutodiffModule, optim::Gradie
 use std::sync::Arc;
 
 use crate::components::OutputTrain;
+use crate::learner::strategies::clip_grads_global_norm;
 use crate::metric::processor::{EventProcessorTraining, LearnerEvent, LearnerItem};
 use crate::{TrainStep, ValidLoader, ValidStep};
 use crate::{components::LearnerComponentTypes, learner::base::Interrupter};
@@ -23,6 +24,7 @@ pub struct SingleDeviceTrainEpoch<B: AutodiffBackend, TI> {
     epoch: usize,
     epoch_total: usize,
     grad_accumulation: Option<usize>,
+    grad_max_norm: Option<f32>,
 }
 
 impl<LC: LearnerComponentTypes> SingleDeviceValidEpoch<LC> {
@@ -56,6 +58,7 @@ impl<LC: LearnerComponentTypes> SingleDeviceValidEpoch<LC> {
                 self.epoch_total,
                 iteration,
                 None,
+                None,
             );
 
             processor.process_valid(LearnerEvent::ProcessedItem(item));
@@ -100,6 +103,8 @@ impl<B: AutodiffBackend, TI> SingleDeviceTrainEpoch<B, TI> {
         let mut iteration = 0;
         let mut accumulator = GradientsAccumulator::new();
         let mut accumulation_current = 0;
+        // Norm of the last optimizer step.
+        let mut grad_norm = None;
 
         while let Some(item) = iterator.next() {
             iteration += 1;
@@ -115,12 +120,17 @@ impl<B: AutodiffBackend, TI> SingleDeviceTrainEpoch<B, TI> {
                     accumulation_current += 1;
 
                     if accumulation <= accumulation_current {
-                        let grads = accumulator.grads();
+                        let mut grads = accumulator.grads();
+                        grad_norm = clip_grads_global_norm::<B>(&mut grads, self.grad_max_norm);
                         model = model.optimize(&mut optim, lr, grads);
                         accumulation_current = 0;
                     }
                 }
-                None => model = model.optimize(&mut optim, lr, item.grads),
+                None => {
+                    let mut grads = item.grads;
+                    grad_norm = clip_grads_global_norm::<B>(&mut grads, self.grad_max_norm);
+                    model = model.optimize(&mut optim, lr, grads);
+                }
             }
 
             let item = LearnerItem::new(
@@ -130,6 +140,7 @@ impl<B: AutodiffBackend, TI> SingleDeviceTrainEpoch<B, TI> {
                 self.epoch_total,
                 iteration,
                 Some(lr),
+                grad_norm.clone(),
             );
 
             processor.process_train(LearnerEvent::ProcessedItem(item));
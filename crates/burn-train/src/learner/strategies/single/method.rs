@@ -54,6 +54,7 @@ impl<LC: LearnerComponentTypes> LearningMethod<LC> for SingleDeviceLearningStrat
             starting_epoch,
             components.num_epochs,
             components.grad_accumulation,
+            components.grad_max_norm,
         );
 
         for epoch in starting_epoch..components.num_epochs + 1 {
@@ -2,6 +2,8 @@ use std::sync::Arc;
 
 use burn_core::{LearningRate, data::dataloader::Progress};
 
+use super::GradientNorm;
+
 /// Metric metadata that can be used when computing metrics.
 pub struct MetricMetadata {
     /// The current progress.
@@ -18,6 +20,9 @@ pub struct MetricMetadata {
 
     /// The current learning rate.
     pub lr: Option<LearningRate>,
+
+    /// The global norm of the gradients before clipping.
+    pub grad_norm: Option<GradientNorm>,
 }
 
 impl MetricMetadata {
@@ -33,6 +38,7 @@ impl MetricMetadata {
             epoch_total: 1,
             iteration: 0,
             lr: None,
+            grad_norm: None,
         }
     }
 }
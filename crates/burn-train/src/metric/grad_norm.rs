@@ -0,0 +1,135 @@
+use std::sync::{Arc, OnceLock};
+
+use burn_core::tensor::{ElementConversion, Tensor, backend::Backend};
+
+use super::{
+    MetricMetadata, Numeric,
+    state::{FormatOptions, NumericMetricState},
+};
+use crate::metric::{Metric, MetricEntry, MetricName};
+
+/// The global norm of the gradients before clipping.
+///
+/// The norm is kept on the device and only read back when its [value](Self::value) is needed, so
+/// that training steps don't wait for it when no metric uses it.
+#[derive(Clone)]
+pub struct GradientNorm {
+    value: Arc<OnceLock<f64>>,
+    read: Arc<dyn Fn() -> f64 + Send + Sync>,
+}
+
+impl GradientNorm {
+    /// Creates the gradient norm from a tensor of shape `[1]`.
+    pub fn new<B: Backend>(norm: Tensor<B, 1>) -> Self {
+        Self {
+            value: Arc::new(OnceLock::new()),
+            read: Arc::new(move || norm.clone().into_scalar().elem()),
+        }
+    }
+
+    /// Returns the norm, read back from the device on the first call.
+    pub fn value(&self) -> f64 {
+        *self.value.get_or_init(|| (self.read)())
+    }
+}
+
+impl From<f64> for GradientNorm {
+    fn from(value: f64) -> Self {
+        Self {
+            value: Arc::new(OnceLock::from(value)),
+            read: Arc::new(move || value),
+        }
+    }
+}
+
+/// Track the global norm of the gradients before clipping.
+///
+/// The norm is only computed when the learner clips the gradients, see
+/// [grads_clipping_global_norm](crate::LearnerBuilder::grads_clipping_global_norm). While
+/// accumulating gradients, the norm of the last optimizer step is reported. Steps without a norm
+/// are skipped and don't count toward the epoch average.
+#[derive(Clone)]
+pub struct GradientNormMetric {
+    name: MetricName,
+    state: NumericMetricState,
+}
+
+impl GradientNormMetric {
+    /// Creates a new gradient norm metric.
+    pub fn new() -> Self {
+        Self {
+            name: Arc::new("Gradient Norm".to_string()),
+            state: NumericMetricState::new(),
+        }
+    }
+}
+
+impl Default for GradientNormMetric {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Metric for GradientNormMetric {
+    type Input = ();
+
+    fn update(&mut self, _item: &(), metadata: &MetricMetadata) -> MetricEntry {
+        let format = FormatOptions::new(self.name()).precision(4);
+
+        match &metadata.grad_norm {
+            Some(grad_norm) => self.state.update(grad_norm.value(), 1, format),
+            None => self.state.skip(format),
+        }
+    }
+
+    fn clear(&mut self) {
+        self.state.reset()
+    }
+
+    fn name(&self) -> MetricName {
+        self.name.clone()
+    }
+}
+
+impl Numeric for GradientNormMetric {
+    fn value(&self) -> super::NumericEntry {
+        self.state.value()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{TestBackend, metric::NumericEntry};
+
+    #[test]
+    fn should_read_gradient_norm_from_device() {
+        let norm = Tensor::<TestBackend, 1>::from_floats([2.5], &Default::default());
+        let grad_norm = GradientNorm::new(norm);
+
+        assert_eq!(grad_norm.clone().value(), 2.5);
+        assert_eq!(grad_norm.value(), 2.5);
+    }
+
+    #[test]
+    fn should_skip_steps_without_gradient_norm() {
+        let mut metric = GradientNormMetric::new();
+        let mut metadata = MetricMetadata::fake();
+
+        metadata.grad_norm = Some(2.0.into());
+        metric.update(&(), &metadata);
+        metadata.grad_norm = None;
+        let entry = metric.update(&(), &metadata);
+        metadata.grad_norm = Some(4.0.into());
+        metric.update(&(), &metadata);
+
+        match NumericEntry::deserialize(&entry.serialize).unwrap() {
+            NumericEntry::Aggregated { count, .. } => assert_eq!(count, 0),
+            NumericEntry::Value(_) => panic!("Expected an aggregated entry"),
+        }
+        match metric.value() {
+            NumericEntry::Aggregated { sum, count, .. } => assert_eq!(sum / count as f64, 3.0),
+            NumericEntry::Value(_) => panic!("Expected an aggregated entry"),
+        }
+    }
+}
@@ -29,6 +29,7 @@ mod auroc;
 mod base;
 mod confusion_stats;
 mod fbetascore;
+mod grad_norm;
 mod hamming;
 mod iteration;
 mod learning_rate;
@@ -42,6 +43,7 @@ pub use auroc::*;
 pub use base::*;
 pub use confusion_stats::ConfusionStatsInput;
 pub use fbetascore::*;
+pub use grad_norm::*;
 pub use hamming::*;
 pub use iteration::*;
 pub use learning_rate::*;
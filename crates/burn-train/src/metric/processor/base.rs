@@ -1,6 +1,7 @@
 use burn_core::LearningRate;
 use burn_core::data::dataloader::Progress;
 
+use crate::metric::GradientNorm;
 use crate::renderer::{EvaluationName, MetricsRenderer};
 
 /// Event happening during the training/validation process.
@@ -79,6 +80,9 @@ pub struct LearnerItem<T> {
 
     /// The learning rate.
     pub lr: Option<LearningRate>,
+
+    /// The global norm of the gradients before clipping.
+    pub grad_norm: Option<GradientNorm>,
 }
 
 impl<T: ItemLazy> ItemLazy for LearnerItem<T> {
@@ -92,6 +96,7 @@ impl<T: ItemLazy> ItemLazy for LearnerItem<T> {
             epoch_total: self.epoch_total,
             iteration: self.iteration,
             lr: self.lr,
+            grad_norm: self.grad_norm,
         }
     }
 }
@@ -216,6 +216,7 @@ impl<T> From<&LearnerItem<T>> for MetricMetadata {
             epoch_total: item.epoch_total,
             iteration: item.iteration,
             lr: item.lr,
+            grad_norm: item.grad_norm.clone(),
         }
     }
 }
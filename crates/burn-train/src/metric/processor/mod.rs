@@ -57,6 +57,7 @@ pub(crate) mod test_utils {
             num_epochs,
             dummy_iteration,
             None,
+            None,
         )));
     }
 
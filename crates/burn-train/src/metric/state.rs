@@ -68,7 +68,18 @@ impl NumericMetricState {
         self.count += batch_size;
         self.current = value;
 
-        let value_current = value;
+        self.entry(batch_size, format)
+    }
+
+    /// Create an entry for the current state without recording a new value.
+    ///
+    /// The entry is serialized with a count of zero, so it doesn't affect the aggregated value.
+    pub fn skip(&self, format: FormatOptions) -> MetricEntry {
+        self.entry(0, format)
+    }
+
+    fn entry(&self, batch_size: usize, format: FormatOptions) -> MetricEntry {
+        let value_current = self.current;
         let value_running = self.sum / self.count as f64;
         // Numeric metric state is an aggregated value
         let serialized = NumericEntry::Aggregated {
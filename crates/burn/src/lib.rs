@@ -90,6 +90,7 @@
 //!   - `openblas-system`: If supported, Openblas installed on the system will be use
 //!   - `autotune`: Enable running benchmarks to select the best kernel in backends that support it.
 //!   - `fusion`: Enable operation fusion in backends that support it.
+//!   - `fusion-cache`: Persist the operations fused on a device between runs.
 //! - Backend decorators
 //!   - `autodiff`: Makes available the Autodiff backend
 //! - Others:
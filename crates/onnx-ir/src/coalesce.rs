@@ -103,6 +103,36 @@ fn transpose_linear_node_weights(node: &mut Node) {
 
             node.inputs[1].value = Some(tensor_data);
         }
+        Data::QInt8s { values, scales } if scales.len() == 1 => {
+            let data_t = transpose_flattened(values.clone(), shape[0], shape[1]);
+
+            let tensor_data = TensorData {
+                data: Data::QInt8s {
+                    values: data_t,
+                    scales: scales.clone(),
+                },
+                shape: new_shape,
+            };
+
+            node.inputs[1].value = Some(tensor_data);
+        }
+        Data::QInt8s { .. } => {
+            // The scales of the rows would apply to the columns of the transposed weights, which
+            // can't be expressed with blocks of contiguous values.
+            log::warn!(
+                "{}: the weights quantized per output channel are dequantized to float, \
+                only the weights quantized per tensor are kept quantized",
+                node.name
+            );
+            let data_t = transpose_flattened(data.clone().into_f32s(), shape[0], shape[1]);
+
+            let tensor_data = TensorData {
+                data: Data::Float32s(data_t),
+                shape: new_shape,
+            };
+
+            node.inputs[1].value = Some(tensor_data);
+        }
         _ => panic!("Only float types are supported for Linear node"),
     }
 }
@@ -188,3 +218,63 @@ fn convert_and_remove_add_node(bias_node: &Node, current_node: &mut Node) {
         .name
         .clone_from(&bias_node.outputs[0].name);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::ElementType;
+    use crate::node::test_utils::NodeBuilder;
+
+    fn gemm_node(weights: Data) -> Node {
+        NodeBuilder::new(NodeType::Gemm, "test_gemm")
+            .input_tensor_f32("input", 2, None)
+            .input_tensor_with_data("weight", ElementType::Float32, 2, weights, vec![2, 3])
+            .output_tensor_f32("output", 2, None)
+            .attr_float("alpha", 1.0)
+            .attr_float("beta", 1.0)
+            .attr_int("transB", 1)
+            .build()
+    }
+
+    fn weights(node: &Node) -> &TensorData {
+        node.inputs[1].value.as_ref().unwrap()
+    }
+
+    #[test]
+    fn test_gemm_to_linear_keeps_weights_quantized_per_tensor() {
+        let mut node = gemm_node(Data::QInt8s {
+            values: vec![1, 2, 3, 4, 5, 6],
+            scales: vec![0.5],
+        });
+
+        convert_gemm_to_linear(&mut node);
+
+        assert_eq!(node.node_type, NodeType::Linear);
+        assert_eq!(weights(&node).shape, vec![3, 2]);
+        match &weights(&node).data {
+            Data::QInt8s { values, scales } => {
+                assert_eq!(values, &vec![1, 4, 2, 5, 3, 6]);
+                assert_eq!(scales, &vec![0.5]);
+            }
+            other => panic!("Expected quantized weights, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_gemm_to_linear_dequantizes_weights_quantized_per_channel() {
+        let mut node = gemm_node(Data::QInt8s {
+            values: vec![1, 2, 3, 4, 5, 6],
+            scales: vec![0.5, 2.0],
+        });
+
+        convert_gemm_to_linear(&mut node);
+
+        assert_eq!(weights(&node).shape, vec![3, 2]);
+        match &weights(&node).data {
+            Data::Float32s(values) => {
+                assert_eq!(values, &vec![0.5, 8.0, 1.0, 10.0, 1.5, 12.0]);
+            }
+            other => panic!("Expected float weights, got {other:?}"),
+        }
+    }
+}
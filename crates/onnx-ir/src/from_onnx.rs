@@ -1,9 +1,11 @@
 use std::{
     collections::{HashMap, HashSet},
+    fmt,
     fs::File,
-    path::Path,
+    path::{Path, PathBuf},
 };
 
+use crate::node::quantization::{dequantize_data, dequantize_linear_config};
 use crate::node_remap::remap_node_type;
 use crate::util::verify_opsets;
 
@@ -11,32 +13,44 @@ use super::{
     coalesce::coalesce,
     ir::{Data, ElementType, OnnxGraph, TensorData, TensorType},
     proto_conversion::convert_node_proto,
-    protos::{ModelProto, NodeProto, TensorProto, ValueInfoProto},
+    protos::{
+        GraphProto, ModelProto, NodeProto, TensorProto, ValueInfoProto,
+        attribute_proto::AttributeType,
+    },
 };
 
-use super::ir::{ArgType, Argument, Node, NodeType};
+use super::ir::{ArgType, Argument, AttributeValue, Node, NodeType};
 use super::rank_inference::rank_inference;
 
 use protobuf::Message;
 
-const LIFT_CONSTANTS_FOR_NODE_TYPES: [NodeType; 28] = [
+const LIFT_CONSTANTS_FOR_NODE_TYPES: [NodeType; 37] = [
     NodeType::BatchNormalization,
     NodeType::Clip,
     NodeType::Conv1d,
     NodeType::Conv2d,
     NodeType::Conv3d,
+    NodeType::ConvInteger,
     NodeType::ConvTranspose1d,
     NodeType::ConvTranspose2d,
     NodeType::ConvTranspose3d,
+    NodeType::CumSum,
+    NodeType::DequantizeLinear,
     NodeType::Dropout,
     NodeType::Expand,
     NodeType::GroupNormalization,
+    NodeType::GRU,
     NodeType::InstanceNormalization,
     NodeType::LayerNormalization,
     NodeType::Linear,
+    NodeType::LSTM,
+    NodeType::NonMaxSuppression,
     NodeType::OneHot,
     NodeType::PRelu,
     NodeType::Pad,
+    NodeType::QLinearConv,
+    NodeType::QLinearMatMul,
+    NodeType::QuantizeLinear,
     NodeType::Range,
     NodeType::ReduceSum,
     NodeType::Reshape,
@@ -88,6 +102,8 @@ pub struct GraphData {
     input_name_map: HashMap<String, IOEntry>,
     /// Maps the updated input name to the original input name. Required to check if the input is an initializer
     input_key_map: HashMap<String, String>,
+    /// The values visible from the enclosing graphs, keyed by their original name. Only set for subgraphs
+    outer_scope: Option<HashMap<String, Argument>>,
 }
 
 impl GraphData {
@@ -95,6 +111,33 @@ impl GraphData {
         inputs: &[ValueInfoProto],
         outputs: &[ValueInfoProto],
         initializers: &[TensorProto],
+    ) -> Self {
+        Self::with_scope(inputs, outputs, initializers, "input", None)
+    }
+
+    /// Create the graph data of a subgraph, which can use the values of the enclosing graphs.
+    ///
+    /// The inputs are renamed with the given prefix to avoid conflicts with the outer inputs.
+    pub(crate) fn new_subgraph(
+        graph: &GraphProto,
+        input_prefix: &str,
+        outer_scope: HashMap<String, Argument>,
+    ) -> Self {
+        Self::with_scope(
+            &graph.input,
+            &graph.output,
+            &graph.initializer,
+            input_prefix,
+            Some(outer_scope),
+        )
+    }
+
+    fn with_scope(
+        inputs: &[ValueInfoProto],
+        outputs: &[ValueInfoProto],
+        initializers: &[TensorProto],
+        input_prefix: &str,
+        outer_scope: Option<HashMap<String, Argument>>,
     ) -> Self {
         let mut input_name_map = HashMap::new();
         let mut input_key_map = HashMap::new();
@@ -111,7 +154,7 @@ impl GraphData {
             .iter()
             .enumerate()
             .map(|(i, x)| {
-                let in_name = format!("input{}", i + 1);
+                let in_name = format!("{input_prefix}{}", i + 1);
 
                 input_name_map.insert(x.name.clone(), IOEntry::In(i));
                 input_key_map.insert(in_name.clone(), x.name.clone());
@@ -133,6 +176,7 @@ impl GraphData {
             processed_nodes: Vec::new(),
             input_name_map,
             input_key_map,
+            outer_scope,
         }
     }
 
@@ -144,6 +188,13 @@ impl GraphData {
                 //need to confirm) then we could pop the initializer from the map
                 if let Some(init_arg) = self.initializers.get(proto_str) {
                     init_arg.clone()
+                } else if let Some(outer_arg) = self
+                    .outer_scope
+                    .as_ref()
+                    .and_then(|scope| scope.get(proto_str))
+                {
+                    // Value captured from an enclosing graph
+                    outer_arg.clone()
                 } else {
                     log::warn!("Input {proto_str} not found, should only happen when peeking");
                     Argument::new(proto_str.to_string())
@@ -154,6 +205,16 @@ impl GraphData {
         }
     }
 
+    /// All the values visible to a subgraph of the next node, keyed by their original name
+    fn scope(&self) -> HashMap<String, Argument> {
+        let mut scope = self.outer_scope.clone().unwrap_or_default();
+        scope.extend(self.initializers.clone());
+        for name in self.input_name_map.keys() {
+            scope.insert(name.clone(), self.init_in(name));
+        }
+        scope
+    }
+
     /// Mark the graph_inputs to a node as passed, unless they are also initializers
     fn mark_input_passed(&mut self, node: &Node) {
         // we have to double map the inputs because the input might be replaced by an initializer
@@ -180,10 +241,14 @@ impl GraphData {
         self.mark_input_passed(&node);
         let mut out_count = 1;
         for output in node.outputs.iter_mut() {
-            self.input_name_map.insert(
-                output.name.clone(),
-                IOEntry::Node(self.processed_nodes.len(), out_count - 1),
-            );
+            // An empty name marks an optional output that is not used, and must not be
+            // confused with the optional inputs that are not provided
+            if !output.name.is_empty() {
+                self.input_name_map.insert(
+                    output.name.clone(),
+                    IOEntry::Node(self.processed_nodes.len(), out_count - 1),
+                );
+            }
             output.name = format!("{}_out{}", node.name, out_count);
             out_count += 1;
         }
@@ -192,7 +257,10 @@ impl GraphData {
 
     /// Consumes the graph data and returns the processed nodes, filtered inputs and outputs
     fn consume(mut self) -> (Vec<Node>, Vec<Argument>, Vec<Argument>) {
-        self.inputs.retain(|x| x.passed);
+        // The inputs of a subgraph are positional, so they are all kept
+        if self.outer_scope.is_none() {
+            self.inputs.retain(|x| x.passed);
+        }
         let outputs = self
             .outputs
             .into_iter()
@@ -202,7 +270,11 @@ impl GraphData {
                     // Output maps directly to an input (e.g., when Identity nodes are removed)
                     Some(self.inputs[*i].clone())
                 }
-                _ => None,
+                None => self
+                    .outer_scope
+                    .as_ref()
+                    .and_then(|scope| scope.get(&x.name))
+                    .cloned(),
             })
             .collect();
         (self.processed_nodes, self.inputs, outputs)
@@ -237,9 +309,7 @@ pub(crate) struct OnnxGraphBuilder {
 
 impl OnnxGraphBuilder {
     pub(crate) fn build(mut self, model_proto: &ModelProto) -> OnnxGraph {
-        self.constants_types = LIFT_CONSTANTS_FOR_NODE_TYPES.into_iter().collect();
-
-        let mut graph_data = GraphData::new(
+        let graph_data = GraphData::new(
             &model_proto.graph.input,
             &model_proto.graph.output,
             &model_proto.graph.initializer,
@@ -257,16 +327,28 @@ impl OnnxGraphBuilder {
                 t.double_data.len(),
             );
         }
+        self.build_graph(&model_proto.graph.node, graph_data)
+    }
+
+    fn build_graph(&mut self, nodes: &[NodeProto], mut graph_data: GraphData) -> OnnxGraph {
+        self.constants_types = LIFT_CONSTANTS_FOR_NODE_TYPES.into_iter().collect();
+
         // First pass: count constant usage
-        self.count_constant_usage(&model_proto.graph.node);
+        self.count_constant_usage(nodes);
 
-        let mut node_iter = model_proto.graph.node.iter().peekable();
+        let mut node_iter = nodes.iter().peekable();
 
         while let Some(node_proto) = node_iter.next() {
             let mut node = convert_node_proto(node_proto, &graph_data);
 
+            // The quantized weights of QDQ models become initializers of their consumers
+            if fold_dequantize_linear(&node, &mut graph_data) {
+                continue;
+            }
+
             remap_node_type(&mut node);
             self.handle_node_renaming(&mut node);
+            self.convert_subgraphs(node_proto, &mut node, &graph_data);
             coalesce(&mut node, &mut node_iter, &graph_data);
             self.handle_identity(&mut node);
             self.check_constants(&mut node, &graph_data);
@@ -303,6 +385,69 @@ impl OnnxGraphBuilder {
         }
     }
 
+    /// Convert the graph attributes of the node (e.g. the branches of `If`) into subgraphs.
+    ///
+    /// A subgraph can use the values of the enclosing graphs by name. These captured values are
+    /// appended to the node inputs after the ONNX inputs, so that the outer graph keeps track of
+    /// their uses.
+    fn convert_subgraphs(
+        &mut self,
+        node_proto: &NodeProto,
+        node: &mut Node,
+        graph_data: &GraphData,
+    ) {
+        let graph_attrs = node_proto
+            .attribute
+            .iter()
+            .filter(|attr| attr.type_.unwrap() == AttributeType::GRAPH)
+            .collect::<Vec<_>>();
+
+        if graph_attrs.is_empty() {
+            return;
+        }
+
+        let scope = graph_data.scope();
+        let mut captured: Vec<Argument> = Vec::new();
+
+        for attr in graph_attrs {
+            let input_prefix = format!("{}_{}_input", node.name, attr.name);
+            let subgraph = self.build_subgraph(&attr.g, &input_prefix, scope.clone());
+
+            for arg in captured_values(&subgraph) {
+                if !captured.contains(&arg) {
+                    captured.push(arg);
+                }
+            }
+            node.attrs
+                .insert(attr.name.clone(), AttributeValue::Graph(subgraph));
+        }
+
+        log::debug!(
+            "Node {} captures {:?} from the outer scope",
+            node.name,
+            captured.iter().map(|arg| &arg.name).collect::<Vec<_>>()
+        );
+        node.inputs.extend(captured);
+    }
+
+    fn build_subgraph(
+        &mut self,
+        graph: &GraphProto,
+        input_prefix: &str,
+        scope: HashMap<String, Argument>,
+    ) -> OnnxGraph {
+        // The node names are shared with the outer graph, so that the generated variables are unique
+        let mut builder = OnnxGraphBuilder {
+            node_name_counter: std::mem::take(&mut self.node_name_counter),
+            ..Default::default()
+        };
+        let graph_data = GraphData::new_subgraph(graph, input_prefix, scope);
+        let subgraph = builder.build_graph(&graph.node, graph_data);
+
+        self.node_name_counter = builder.node_name_counter;
+        subgraph
+    }
+
     fn handle_node_renaming(&mut self, node: &mut Node) {
         self.node_name_counter
             .entry(node.node_type.clone())
@@ -716,7 +861,7 @@ impl OnnxGraphBuilder {
                 continue; // Skip constant nodes themselves
             }
 
-            for input in &node.input {
+            for input in node.input.iter().chain(subgraph_inputs(node)) {
                 if constant_outputs.contains(input) {
                     *self.constant_usage_count.entry(input.clone()).or_insert(0) += 1;
                 }
@@ -1093,6 +1238,41 @@ impl OnnxGraphBuilder {
     }
 }
 
+/// Error returned by [try_parse_onnx] when the ONNX file can't be read.
+#[derive(Debug)]
+pub enum ParseError {
+    /// The file can't be opened.
+    Io {
+        /// The path of the file.
+        path: PathBuf,
+        /// The IO error.
+        source: std::io::Error,
+    },
+    /// The file isn't a valid ONNX protobuf.
+    Decode(protobuf::Error),
+    /// The model uses an opset older than [MIN_OPSET_VERSION].
+    UnsupportedOpset,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io { path, source } => {
+                write!(f, "Unable to open file: {} ({source})", path.display())
+            }
+            Self::Decode(err) => write!(f, "Unable to parse ONNX file: {err}"),
+            Self::UnsupportedOpset => write!(
+                f,
+                "Unsupported ONNX opset version. This implementation requires opset {MIN_OPSET_VERSION} or higher. \
+                Please upgrade your model using the ONNX shape inference tool. \
+                See documentation (https://burn.dev/books/burn/import/onnx-model.html) for details."
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
 /// Parses an ONNX model file and converts it to an intermediate representation.
 ///
 /// This function reads an ONNX model from the specified path, validates its opset version,
@@ -1113,21 +1293,29 @@ impl OnnxGraphBuilder {
 /// * If the model uses an unsupported opset version (must be >= MIN_OPSET_VERSION)
 /// * If the nodes in the graph are not topologically sorted
 pub fn parse_onnx(onnx_path: &Path) -> OnnxGraph {
+    try_parse_onnx(onnx_path).unwrap_or_else(|err| panic!("{err}"))
+}
+
+/// Parses an ONNX model file like [parse_onnx], returning an error when the file can't be read.
+///
+/// # Panics
+///
+/// The conversion of the graph still panics on invalid or unsupported nodes, e.g. when a node has
+/// invalid attributes or when the nodes aren't topologically sorted.
+pub fn try_parse_onnx(onnx_path: &Path) -> Result<OnnxGraph, ParseError> {
     log::info!("Parsing ONNX file: {}", onnx_path.display());
 
     // Open the file
-    let mut file = File::open(onnx_path)
-        .unwrap_or_else(|_| panic!("Unable to open file: {}", onnx_path.display()));
+    let mut file = File::open(onnx_path).map_err(|source| ParseError::Io {
+        path: onnx_path.to_path_buf(),
+        source,
+    })?;
     let onnx_model: ModelProto =
-        Message::parse_from_reader(&mut file).expect("Unable to parse ONNX file");
+        Message::parse_from_reader(&mut file).map_err(ParseError::Decode)?;
 
     // Check opset versions - must be >= MIN_OPSET_VERSION
     if !verify_opsets(&onnx_model.opset_import, MIN_OPSET_VERSION) {
-        panic!(
-            "Unsupported ONNX opset version. This implementation requires opset {MIN_OPSET_VERSION} or higher. \
-            Please upgrade your model using the ONNX shape inference tool. \
-            See documentation (https://burn.dev/books/burn/import/onnx-model.html) for details."
-        );
+        return Err(ParseError::UnsupportedOpset);
     }
 
     // ONNX nodes must be topologically sorted per spec:
@@ -1164,13 +1352,99 @@ pub fn parse_onnx(onnx_path: &Path) -> OnnxGraph {
 
     log::info!("Finished parsing ONNX file: {}", onnx_path.display());
 
-    graph
+    Ok(graph)
+}
+
+/// The input names of the nodes of all the subgraphs of a node, including the nested ones.
+fn subgraph_inputs(node: &NodeProto) -> Vec<&String> {
+    node.attribute
+        .iter()
+        .filter(|attr| attr.type_.unwrap() == AttributeType::GRAPH)
+        .flat_map(|attr| attr.g.node.iter())
+        .flat_map(|node| node.input.iter().chain(subgraph_inputs(node)))
+        .collect()
+}
+
+/// The values of the enclosing graphs used by a subgraph, in order of first use.
+///
+/// The values captured by nested subgraphs are already inputs of their parent node.
+fn captured_values(graph: &OnnxGraph) -> Vec<Argument> {
+    let defined = graph
+        .inputs
+        .iter()
+        .chain(graph.nodes.iter().flat_map(|node| node.outputs.iter()))
+        .map(|arg| arg.name.as_str())
+        .collect::<HashSet<_>>();
+    let used = graph
+        .nodes
+        .iter()
+        .flat_map(|node| node.inputs.iter())
+        .chain(graph.outputs.iter());
+
+    let mut captured: Vec<Argument> = Vec::new();
+    for arg in used {
+        if !arg.name.is_empty()
+            && arg.value.is_none()
+            && !defined.contains(arg.name.as_str())
+            && !captured.contains(arg)
+        {
+            captured.push(arg.clone());
+        }
+    }
+
+    captured
 }
 
 /// Remap the unsqueeze node to a reshape node, Should only be called after
 /// node renaming has been done. avoids marking rhs as passed so that it can be
 /// properly deleted if nothing else uses it
 /// Remap the unsqueeze node to a reshape node
+/// Fold a DequantizeLinear node of constant inputs into an initializer named after its output.
+///
+/// This is how the weights appear in the QDQ format, where every quantized tensor is followed by a
+/// DequantizeLinear node. Once folded, the weights are constants of the consuming nodes, e.g. the
+/// weights of a convolution, and symmetric int8 weights are kept quantized.
+///
+/// Returns true if the node was folded.
+fn fold_dequantize_linear(node: &Node, graph_data: &mut GraphData) -> bool {
+    if node.node_type != NodeType::DequantizeLinear
+        || node
+            .inputs
+            .iter()
+            .any(|input| !input.name.is_empty() && input.value.is_none())
+    {
+        return false;
+    }
+
+    let params = dequantize_linear_config(node);
+    let value = dequantize_data(node.inputs[0].value.as_ref().unwrap(), &params);
+    let name = node.outputs[0].name.clone();
+    log::debug!(
+        "Folding DequantizeLinear {} into initializer {name}",
+        node.name
+    );
+
+    let ty = match value.shape.is_empty() {
+        true => ArgType::Scalar(ElementType::Float32),
+        false => ArgType::Tensor(TensorType {
+            elem_type: ElementType::Float32,
+            rank: value.shape.len(),
+            static_shape: Some(value.shape.clone()),
+        }),
+    };
+    graph_data.initializers.insert(
+        name.clone(),
+        Argument {
+            name,
+            ty,
+            value: Some(value),
+            passed: false,
+        },
+    );
+
+    true
+}
+
 pub(crate) fn remap_unsqueeze_to_reshape(node: &mut Node, out_arg: &Argument) {
     if let Some(value) = &out_arg.value {
         let shape_vec = value.shape.clone();
@@ -192,6 +192,8 @@ pub enum AttributeValue {
     Strings(Vec<String>),
     Tensor(TensorData),
     Tensors(Vec<TensorData>),
+    /// A subgraph, e.g. the branches of `If` or the body of `Loop` and `Scan`.
+    Graph(OnnxGraph),
 }
 
 pub type Attributes = HashMap<String, AttributeValue>;
@@ -293,7 +295,7 @@ impl TensorData {
         match &self.data {
             Data::Bool(_) | Data::Bools(_) => ElementType::Bool,
             Data::Float16(_) | Data::Float16s(_) => ElementType::Float16,
-            Data::Float32(_) | Data::Float32s(_) => ElementType::Float32,
+            Data::Float32(_) | Data::Float32s(_) | Data::QInt8s { .. } => ElementType::Float32,
             Data::Float64(_) | Data::Float64s(_) => ElementType::Float64,
             Data::Uint8(_) | Data::Uint8s(_) => ElementType::Uint8,
             Data::Int8(_) | Data::Int8s(_) => ElementType::Int8,
@@ -323,6 +325,12 @@ pub enum Data {
     Int32s(Vec<i32>),
     Int64(i64),
     Int64s(Vec<i64>),
+    /// Symmetric int8 values of a float tensor, where each contiguous block of
+    /// `values.len() / scales.len()` values shares a scale.
+    QInt8s {
+        values: Vec<i8>,
+        scales: Vec<f32>,
+    },
     String(String),
     Strings(Vec<String>),
 }
@@ -415,6 +423,7 @@ pub enum NodeType {
     AveragePool,
     AveragePool1d,
     AveragePool2d,
+    AveragePool3d,
     BatchNormalization,
     Bernoulli,
     BitShift,
@@ -505,6 +514,7 @@ pub enum NodeType {
     MaxPool,
     MaxPool1d,
     MaxPool2d,
+    MaxPool3d,
     MaxRoiPool,
     MaxUnpool,
     Mean,
@@ -631,6 +641,9 @@ impl fmt::Debug for Data {
             Data::Float64s(v) => write!(f, "Float64s({})", trunc(v)),
             Data::Int32s(v) => write!(f, "Int32s({})", trunc(v)),
             Data::Int64s(v) => write!(f, "Int64s({})", trunc(v)),
+            Data::QInt8s { values, scales } => {
+                write!(f, "QInt8s({}, scales: {})", trunc(values), trunc(scales))
+            }
             Data::Strings(v) => write!(f, "Strings({})", trunc(v)),
             Data::Bools(v) => write!(f, "Bools({})", trunc(v)),
             Data::Float16(v) => write!(f, "Float16({v})"),
@@ -774,6 +787,14 @@ impl Data {
             Data::Int64s(elem) => elem.into_iter().map(|x| x as f32).collect(),
             Data::Uint8s(v) => v.into_iter().map(|x| x as f32).collect(),
             Data::Int8s(v) => v.into_iter().map(|x| x as f32).collect(),
+            Data::QInt8s { values, scales } => {
+                let block_size = values.len() / scales.len();
+                values
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, x)| x as f32 * scales[i / block_size])
+                    .collect()
+            }
             _ => panic!("Cannot convert {self:?} to Vec<f32>"),
         }
     }
@@ -910,6 +931,14 @@ impl AttributeValue {
             panic!("Expected Tensors, got {self:?}");
         }
     }
+
+    pub fn into_graph(self) -> OnnxGraph {
+        if let AttributeValue::Graph(elem) = self {
+            elem
+        } else {
+            panic!("Expected Graph, got {self:?}");
+        }
+    }
 }
 
 /// Convert AttributeValue to an Argument
@@ -12,5 +12,5 @@ mod rank_inference;
 pub mod util;
 
 pub use from_onnx::convert_constant_value;
-pub use from_onnx::parse_onnx;
+pub use from_onnx::{ParseError, parse_onnx, try_parse_onnx};
 pub use ir::*;
@@ -30,10 +30,12 @@ pub fn constant_update_outputs(node: &mut Node) {
                     tensor.shape.len(),
                     node.name
                 );
+                // The shape is known, e.g. the depth of the GatherND indices given by a constant
+                // that isn't lifted.
                 ArgType::Tensor(TensorType {
                     elem_type: tensor.elem_type(),
                     rank: tensor.shape.len(),
-                    static_shape: None,
+                    static_shape: Some(tensor.shape.clone()),
                 })
             }
             AttributeValue::Float32(_) => {
@@ -111,6 +113,7 @@ mod tests {
             ArgType::Tensor(tensor) => {
                 assert_eq!(tensor.elem_type, ElementType::Float32);
                 assert_eq!(tensor.rank, 2);
+                assert_eq!(tensor.static_shape, Some(vec![2, 3]));
             }
             _ => panic!("Expected tensor output"),
         }
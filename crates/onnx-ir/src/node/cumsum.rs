@@ -0,0 +1,94 @@
+use crate::ir::Node;
+
+/// Configuration for the CumSum operation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CumSumConfig {
+    /// The axis along which the sum is accumulated.
+    pub axis: usize,
+    /// Whether the sum excludes the element itself (`exclusive` = 1).
+    pub exclusive: bool,
+    /// Whether the sum is accumulated in the reverse direction (`reverse` = 1).
+    pub reverse: bool,
+}
+
+/// Creates a CumSumConfig from the node attributes and inputs.
+///
+/// The axis is the second input, and must be a constant.
+pub fn cumsum_config(node: &Node) -> CumSumConfig {
+    let rank = node.inputs[0].ty.rank() as i64;
+
+    let mut axis = match node.inputs.get(1).and_then(|axis| axis.value.as_ref()) {
+        Some(value) => value.data.clone().into_i64(),
+        None => panic!("CumSum: only a constant axis is supported"),
+    };
+    if axis < 0 {
+        axis += rank;
+    }
+    if axis < 0 || axis >= rank {
+        panic!("CumSum: axis {axis} is out of bounds for a tensor of rank {rank}");
+    }
+
+    let mut exclusive = false;
+    let mut reverse = false;
+    for (key, value) in node.attrs.iter() {
+        match key.as_str() {
+            "exclusive" => exclusive = value.clone().into_i64() != 0,
+            "reverse" => reverse = value.clone().into_i64() != 0,
+            _ => {}
+        }
+    }
+
+    CumSumConfig {
+        axis: axis as usize,
+        exclusive,
+        reverse,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::NodeType;
+    use crate::node::test_utils::NodeBuilder;
+
+    fn create_test_node(axis: i64) -> NodeBuilder {
+        NodeBuilder::new(NodeType::CumSum, "test_cumsum")
+            .input_tensor_f32("x", 3, None)
+            .input_tensor_i64_data("axis", vec![axis], vec![])
+            .output_tensor_f32("y", 3, None)
+    }
+
+    #[test]
+    fn test_cumsum_config_default() {
+        let node = create_test_node(1).build();
+        let config = cumsum_config(&node);
+
+        assert_eq!(config.axis, 1);
+        assert!(!config.exclusive);
+        assert!(!config.reverse);
+    }
+
+    #[test]
+    fn test_cumsum_config_negative_axis() {
+        let node = create_test_node(-1)
+            .attr_int("exclusive", 1)
+            .attr_int("reverse", 1)
+            .build();
+        let config = cumsum_config(&node);
+
+        assert_eq!(config.axis, 2);
+        assert!(config.exclusive);
+        assert!(config.reverse);
+    }
+
+    #[test]
+    #[should_panic(expected = "only a constant axis is supported")]
+    fn test_cumsum_config_runtime_axis() {
+        let node = NodeBuilder::new(NodeType::CumSum, "test_cumsum")
+            .input_tensor_f32("x", 3, None)
+            .input_tensor_i64("axis", 0, None)
+            .output_tensor_f32("y", 3, None)
+            .build();
+        let _ = cumsum_config(&node);
+    }
+}
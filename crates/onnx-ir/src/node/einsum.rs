@@ -0,0 +1,175 @@
+use crate::ir::{ArgType, Node, TensorType};
+
+/// Configuration for the Einsum operation, parsed from its equation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EinsumConfig {
+    /// The subscripts of the dimensions of every input
+    pub inputs: Vec<Vec<char>>,
+    /// The subscripts of the dimensions of the output
+    pub output: Vec<char>,
+}
+
+/// Creates an EinsumConfig from the `equation` attribute of the node.
+///
+/// Only one or two inputs without ellipsis (`...`) or repeated subscripts in the same input
+/// (diagonals) are supported.
+pub fn einsum_config(node: &Node) -> EinsumConfig {
+    let equation = node
+        .attrs
+        .get("equation")
+        .expect("Einsum: the equation attribute is required")
+        .clone()
+        .into_string();
+    let equation = equation.replace(' ', "");
+
+    if equation.contains("...") {
+        panic!("Einsum: ellipsis are not supported (equation {equation})");
+    }
+
+    let (inputs, output) = match equation.split_once("->") {
+        Some((inputs, output)) => (inputs, Some(output)),
+        None => (equation.as_str(), None),
+    };
+    let inputs = inputs
+        .split(',')
+        .map(|subscripts| subscripts.chars().collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+
+    if inputs.is_empty() || inputs.len() > 2 {
+        panic!(
+            "Einsum: only one or two inputs are supported (got {})",
+            inputs.len()
+        );
+    }
+    if inputs.len() != node.inputs.len() {
+        panic!(
+            "Einsum: the equation {equation} has {} inputs, but the node has {}",
+            inputs.len(),
+            node.inputs.len()
+        );
+    }
+    for (subscripts, input) in inputs.iter().zip(node.inputs.iter()) {
+        if subscripts.len() != input.ty.rank() {
+            panic!(
+                "Einsum: the subscripts {} do not match the rank {} of input {}",
+                subscripts.iter().collect::<String>(),
+                input.ty.rank(),
+                input.name
+            );
+        }
+        if subscripts
+            .iter()
+            .enumerate()
+            .any(|(i, subscript)| subscripts[..i].contains(subscript))
+        {
+            panic!("Einsum: repeated subscripts in the same input are not supported");
+        }
+    }
+
+    // In implicit mode, the output has the subscripts that appear exactly once, in alphabetical
+    // order
+    let output = match output {
+        Some(output) => output.chars().collect::<Vec<_>>(),
+        None => {
+            let mut output = inputs
+                .iter()
+                .flatten()
+                .filter(|subscript| {
+                    inputs
+                        .iter()
+                        .flatten()
+                        .filter(|other| other == subscript)
+                        .count()
+                        == 1
+                })
+                .copied()
+                .collect::<Vec<_>>();
+            output.sort();
+            output
+        }
+    };
+    if let Some(subscript) = output
+        .iter()
+        .find(|subscript| !inputs.iter().flatten().any(|input| input == *subscript))
+    {
+        panic!("Einsum: the output subscript {subscript} does not appear in the inputs");
+    }
+
+    EinsumConfig { inputs, output }
+}
+
+/// Update output rank for Einsum, the number of subscripts of the output.
+pub fn einsum_update_outputs(node: &mut Node) {
+    log::debug!("Einsum rank inference for node {}", node.name);
+
+    let config = einsum_config(node);
+    log::debug!(
+        "Einsum output rank for {}: {}",
+        node.name,
+        config.output.len()
+    );
+
+    let elem_type = node.inputs[0].ty.elem_type().clone();
+    node.outputs[0].ty = match config.output.is_empty() {
+        // A full contraction is a scalar
+        true => ArgType::Scalar(elem_type),
+        false => ArgType::Tensor(TensorType {
+            elem_type,
+            rank: config.output.len(),
+            static_shape: None,
+        }),
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::NodeType;
+    use crate::node::test_utils::NodeBuilder;
+
+    fn create_test_node(equation: &str, ranks: &[usize]) -> Node {
+        let mut builder = NodeBuilder::new(NodeType::Einsum, "test_einsum")
+            .attr_string("equation", equation)
+            .output_default("output");
+        for (i, rank) in ranks.iter().enumerate() {
+            builder = builder.input_tensor_f32(&format!("input{i}"), *rank, None);
+        }
+
+        builder.build()
+    }
+
+    #[test]
+    fn test_einsum_config_explicit() {
+        let node = create_test_node("bij, bjk -> bik", &[3, 3]);
+        let config = einsum_config(&node);
+
+        assert_eq!(
+            config.inputs,
+            vec![vec!['b', 'i', 'j'], vec!['b', 'j', 'k']]
+        );
+        assert_eq!(config.output, vec!['b', 'i', 'k']);
+    }
+
+    #[test]
+    fn test_einsum_config_implicit() {
+        let node = create_test_node("ji,jk", &[2, 2]);
+        let config = einsum_config(&node);
+
+        assert_eq!(config.output, vec!['i', 'k']);
+    }
+
+    #[test]
+    fn test_einsum_update_outputs() {
+        let mut node = create_test_node("ij,ij->", &[2, 2]);
+        einsum_update_outputs(&mut node);
+
+        assert!(matches!(node.outputs[0].ty, ArgType::Scalar(_)));
+    }
+
+    #[test]
+    #[should_panic(expected = "repeated subscripts in the same input are not supported")]
+    fn test_einsum_config_diagonal() {
+        let node = create_test_node("ii->i", &[2]);
+        let _ = einsum_config(&node);
+    }
+}
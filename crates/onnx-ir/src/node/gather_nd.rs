@@ -0,0 +1,143 @@
+use crate::ir::{ArgType, Node, TensorType};
+
+/// Configuration for the GatherND operation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GatherNDConfig {
+    /// The number of leading batch dimensions shared by the data and the indices.
+    pub batch_dims: usize,
+}
+
+/// Creates a GatherNDConfig from the node attributes.
+pub fn gather_nd_config(node: &Node) -> GatherNDConfig {
+    let batch_dims = match node.attrs.get("batch_dims") {
+        Some(batch_dims) => batch_dims.clone().into_i64() as usize,
+        None => 0,
+    };
+
+    let data_rank = node.inputs[0].ty.rank();
+    let indices_rank = node.inputs[1].ty.rank();
+    if batch_dims >= data_rank.min(indices_rank) {
+        panic!(
+            "GatherND: batch_dims ({batch_dims}) must be smaller than the rank of the data and the indices"
+        );
+    }
+
+    GatherNDConfig { batch_dims }
+}
+
+/// The size of the last dimension of the indices, which is the number of indexed dimensions.
+fn gather_nd_index_depth(node: &Node) -> usize {
+    let indices = &node.inputs[1];
+    let shape = match (&indices.value, &indices.ty) {
+        (Some(value), _) => Some(value.shape.clone()),
+        (None, ArgType::Tensor(tensor)) => tensor.static_shape.clone(),
+        _ => None,
+    };
+
+    shape
+        .and_then(|shape| shape.last().copied())
+        .unwrap_or_else(|| panic!("GatherND: the last dimension of the indices must be known"))
+}
+
+/// Update output rank for GatherND, `q + r - k - 1 - batch_dims` where `q` and `r` are the ranks
+/// of the indices and the data, and `k` is the last dimension of the indices.
+pub fn gather_nd_update_outputs(node: &mut Node) {
+    log::debug!("GatherND rank inference for node {}", node.name);
+
+    let config = gather_nd_config(node);
+    let data_rank = node.inputs[0].ty.rank();
+    let indices_rank = node.inputs[1].ty.rank();
+    let depth = gather_nd_index_depth(node);
+
+    if depth > data_rank - config.batch_dims {
+        panic!(
+            "GatherND: the indices index {depth} dimensions of data of rank {data_rank} with {} batch dimensions",
+            config.batch_dims
+        );
+    }
+
+    let rank = indices_rank + data_rank - depth - 1 - config.batch_dims;
+    log::debug!("GatherND output rank for {}: {}", node.name, rank);
+
+    node.outputs[0].ty = ArgType::Tensor(TensorType {
+        elem_type: node.inputs[0].ty.elem_type().clone(),
+        rank,
+        static_shape: None,
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::{Data, ElementType, NodeType, TensorData};
+    use crate::node::constant::constant_update_outputs;
+    use crate::node::test_utils::NodeBuilder;
+
+    fn create_test_node(indices_shape: Vec<usize>) -> NodeBuilder {
+        NodeBuilder::new(NodeType::GatherND, "test_gather_nd")
+            .input_tensor_f32("data", 3, None)
+            .input_tensor_i64("indices", indices_shape.len(), Some(indices_shape))
+            .output_default("output")
+    }
+
+    #[test]
+    fn test_gather_nd_update_outputs() {
+        let mut node = create_test_node(vec![4, 2]).build();
+        gather_nd_update_outputs(&mut node);
+
+        match &node.outputs[0].ty {
+            ArgType::Tensor(tensor) => {
+                assert_eq!(tensor.elem_type, ElementType::Float32);
+                assert_eq!(tensor.rank, 2);
+            }
+            _ => panic!("Expected tensor output"),
+        }
+    }
+
+    #[test]
+    fn test_gather_nd_update_outputs_batch_dims() {
+        let mut node = create_test_node(vec![2, 3, 1])
+            .attr_int("batch_dims", 1)
+            .build();
+        gather_nd_update_outputs(&mut node);
+
+        assert_eq!(gather_nd_config(&node).batch_dims, 1);
+        assert_eq!(node.outputs[0].ty.rank(), 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "the last dimension of the indices must be known")]
+    fn test_gather_nd_unknown_index_depth() {
+        let mut node = NodeBuilder::new(NodeType::GatherND, "test_gather_nd")
+            .input_tensor_f32("data", 3, None)
+            .input_tensor_i64("indices", 2, None)
+            .output_default("output")
+            .build();
+        gather_nd_update_outputs(&mut node);
+    }
+
+    #[test]
+    fn test_gather_nd_update_outputs_constant_indices() {
+        let mut constant = NodeBuilder::new(NodeType::Constant, "test_constant")
+            .output_default("indices")
+            .attr_tensor(
+                "value",
+                TensorData {
+                    data: Data::Int64s(vec![0, 1, 1, 0]),
+                    shape: vec![2, 2],
+                },
+            )
+            .build();
+        constant_update_outputs(&mut constant);
+
+        // The indices produced by the constant aren't lifted, so only their type is known
+        let mut node = NodeBuilder::new(NodeType::GatherND, "test_gather_nd")
+            .input_tensor_f32("data", 3, None)
+            .add_input("indices", constant.outputs[0].ty.clone())
+            .output_default("output")
+            .build();
+        gather_nd_update_outputs(&mut node);
+
+        assert_eq!(node.outputs[0].ty.rank(), 2);
+    }
+}
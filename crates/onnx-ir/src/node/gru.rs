@@ -0,0 +1,135 @@
+use crate::ir::Node;
+
+use super::rnn::{RnnDirection, rnn_attributes, rnn_has_input, rnn_weight_shape};
+
+/// Configuration for GRU operations extracted from ONNX nodes
+#[derive(Debug, Clone)]
+pub struct GruConfig {
+    /// Size of the input features
+    pub input_size: usize,
+    /// Size of the hidden state
+    pub hidden_size: usize,
+    /// Direction in which the sequence is processed
+    pub direction: RnnDirection,
+    /// Whether the batch is the first dimension of the input and outputs (`layout` = 1)
+    pub batch_first: bool,
+    /// Whether bias is used
+    pub bias: bool,
+    /// Whether the reset gate is applied after the linear transformation of the hidden state
+    pub linear_before_reset: bool,
+}
+
+/// Create a GruConfig from the attributes of the node
+///
+/// The inputs are `X`, `W`, `R`, `B`, `sequence_lens` and `initial_h`. The weights `W` and `R`,
+/// and the bias `B` must be constants.
+pub fn gru_config(node: &Node) -> GruConfig {
+    let attrs = rnn_attributes(node, &["Sigmoid", "Tanh"]);
+
+    let linear_before_reset = node
+        .attrs
+        .get("linear_before_reset")
+        .is_some_and(|value| value.clone().into_i64() != 0);
+
+    let weight_shape = rnn_weight_shape(node, 1, "W");
+    if weight_shape.len() != 3 {
+        panic!(
+            "GRU: W tensor must have 3 dimensions (got {:?})",
+            weight_shape.len()
+        );
+    }
+
+    let num_directions = attrs.direction.num_directions();
+    if weight_shape[0] != num_directions {
+        panic!(
+            "GRU: W tensor has {} directions, expected {num_directions}",
+            weight_shape[0]
+        );
+    }
+
+    let hidden_size = weight_shape[1] / 3;
+    if let Some(expected) = attrs.hidden_size
+        && expected != hidden_size
+    {
+        panic!("GRU: hidden_size is {expected}, but W has a hidden size of {hidden_size}");
+    }
+
+    // The recurrence weights and the optional bias must be constants as well
+    rnn_weight_shape(node, 2, "R");
+    let bias = rnn_has_input(node, 3);
+    if bias {
+        rnn_weight_shape(node, 3, "B");
+    }
+
+    GruConfig {
+        input_size: weight_shape[2],
+        hidden_size,
+        direction: attrs.direction,
+        batch_first: attrs.batch_first,
+        bias,
+        linear_before_reset,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::NodeType;
+    use crate::node::test_utils::NodeBuilder;
+
+    fn create_test_node(num_directions: usize) -> NodeBuilder {
+        let (input_size, hidden_size) = (4, 3);
+        let gates = 3 * hidden_size;
+
+        NodeBuilder::new(NodeType::GRU, "test_gru")
+            .input_tensor_f32("X", 3, None)
+            .input_tensor_f32_data(
+                "W",
+                vec![0.0; num_directions * gates * input_size],
+                vec![num_directions, gates, input_size],
+            )
+            .input_tensor_f32_data(
+                "R",
+                vec![0.0; num_directions * gates * hidden_size],
+                vec![num_directions, gates, hidden_size],
+            )
+            .input_tensor_f32_data(
+                "B",
+                vec![0.0; num_directions * 2 * gates],
+                vec![num_directions, 2 * gates],
+            )
+            .output_default("Y")
+            .output_default("Y_h")
+    }
+
+    #[test]
+    fn test_gru_config_default() {
+        let node = create_test_node(1).build();
+        let config = gru_config(&node);
+
+        assert_eq!(config.input_size, 4);
+        assert_eq!(config.hidden_size, 3);
+        assert_eq!(config.direction, RnnDirection::Forward);
+        assert!(config.bias);
+        assert!(!config.linear_before_reset);
+    }
+
+    #[test]
+    fn test_gru_config_reverse_linear_before_reset() {
+        let node = create_test_node(1)
+            .attr_string("direction", "reverse")
+            .attr_int("linear_before_reset", 1)
+            .build();
+        let config = gru_config(&node);
+
+        assert_eq!(config.direction, RnnDirection::Reverse);
+        assert!(config.linear_before_reset);
+    }
+
+    #[test]
+    #[should_panic(expected = "hidden_size is 4, but W has a hidden size of 3")]
+    fn test_gru_config_hidden_size_mismatch() {
+        let node = create_test_node(1).attr_int("hidden_size", 4).build();
+        let _ = gru_config(&node);
+    }
+}
@@ -0,0 +1,103 @@
+use crate::ir::{Node, OnnxGraph};
+
+/// Update output types for If (same as the outputs of the then branch).
+pub fn if_update_outputs(node: &mut Node) {
+    log::debug!("If rank inference for node {}", node.name);
+
+    let config = if_config(node);
+
+    if config.then_branch.outputs.len() != config.else_branch.outputs.len() {
+        panic!(
+            "If: the branches must have the same number of outputs (got {} and {})",
+            config.then_branch.outputs.len(),
+            config.else_branch.outputs.len()
+        );
+    }
+
+    for (output, branch_output) in node.outputs.iter_mut().zip(config.then_branch.outputs) {
+        output.ty = branch_output.ty;
+        log::debug!("If output {} type: {:?}", output.name, output.ty);
+    }
+}
+
+/// Configuration for the If operation.
+#[derive(Debug, Clone)]
+pub struct IfConfig {
+    /// The graph executed when the condition is true.
+    pub then_branch: OnnxGraph,
+    /// The graph executed when the condition is false.
+    pub else_branch: OnnxGraph,
+}
+
+/// Creates an IfConfig from the node attributes.
+///
+/// The first input is the condition, the following inputs are the values of the outer scope used
+/// by the branches.
+pub fn if_config(node: &Node) -> IfConfig {
+    let branch = |name: &str| {
+        node.attrs
+            .get(name)
+            .unwrap_or_else(|| panic!("If: missing '{name}' attribute"))
+            .clone()
+            .into_graph()
+    };
+
+    IfConfig {
+        then_branch: branch("then_branch"),
+        else_branch: branch("else_branch"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::{ArgType, Argument, ElementType, NodeType, TensorType};
+    use crate::node::test_utils::NodeBuilder;
+
+    fn branch(output: &str, rank: usize) -> OnnxGraph {
+        let mut output = Argument::new(output.to_string());
+        output.ty = ArgType::Tensor(TensorType {
+            elem_type: ElementType::Float32,
+            rank,
+            static_shape: None,
+        });
+
+        OnnxGraph {
+            nodes: vec![],
+            inputs: vec![],
+            outputs: vec![output],
+        }
+    }
+
+    #[test]
+    fn test_if_update_outputs() {
+        let mut node = NodeBuilder::new(NodeType::If, "test_if")
+            .input_scalar("cond", ElementType::Bool)
+            .output_default("output")
+            .attr_graph("then_branch", branch("then_out", 3))
+            .attr_graph("else_branch", branch("else_out", 3))
+            .build();
+
+        if_update_outputs(&mut node);
+
+        match &node.outputs[0].ty {
+            ArgType::Tensor(tensor) => {
+                assert_eq!(tensor.elem_type, ElementType::Float32);
+                assert_eq!(tensor.rank, 3);
+            }
+            _ => panic!("Expected tensor output"),
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "missing 'else_branch' attribute")]
+    fn test_if_config_missing_branch() {
+        let node = NodeBuilder::new(NodeType::If, "test_if")
+            .input_scalar("cond", ElementType::Bool)
+            .output_default("output")
+            .attr_graph("then_branch", branch("then_out", 3))
+            .build();
+
+        let _ = if_config(&node);
+    }
+}
@@ -0,0 +1,164 @@
+use crate::ir::{ArgType, ElementType, Node, OnnxGraph, TensorType};
+
+/// Update output types for Loop.
+///
+/// The final values of the loop carried dependencies have the types of the body outputs, while the
+/// scan outputs are stacked along a new first dimension.
+pub fn loop_update_outputs(node: &mut Node) {
+    log::debug!("Loop rank inference for node {}", node.name);
+
+    let config = loop_config(node);
+    let body_outputs = config.body.outputs.into_iter().skip(1);
+
+    for (index, (output, body_output)) in node.outputs.iter_mut().zip(body_outputs).enumerate() {
+        output.ty = if index < config.num_carried {
+            body_output.ty
+        } else {
+            stacked_type(&body_output.ty)
+        };
+        log::debug!("Loop output {} type: {:?}", output.name, output.ty);
+    }
+}
+
+/// The type of the values of the given type stacked along a new first dimension.
+pub(crate) fn stacked_type(ty: &ArgType) -> ArgType {
+    let (elem_type, rank) = match ty {
+        ArgType::Tensor(tensor) => (tensor.elem_type.clone(), tensor.rank),
+        ArgType::Scalar(elem_type) => (elem_type.clone(), 0),
+        ArgType::Shape(_) => (ElementType::Int64, 1),
+    };
+
+    ArgType::Tensor(TensorType {
+        elem_type,
+        rank: rank + 1,
+        static_shape: None,
+    })
+}
+
+/// Configuration for the Loop operation.
+#[derive(Debug, Clone)]
+pub struct LoopConfig {
+    /// The graph executed at each iteration.
+    ///
+    /// Its inputs are the iteration number, the condition and the loop carried dependencies. Its
+    /// outputs are the condition, the loop carried dependencies and the scan outputs.
+    pub body: OnnxGraph,
+    /// The number of loop carried dependencies.
+    pub num_carried: usize,
+    /// The number of scan outputs, whose values are stacked over the iterations.
+    pub num_scan_outputs: usize,
+}
+
+/// Creates a LoopConfig from the node attributes.
+///
+/// The inputs are the optional maximum trip count, the optional condition and the initial values
+/// of the loop carried dependencies, followed by the values of the outer scope used by the body.
+pub fn loop_config(node: &Node) -> LoopConfig {
+    let body = node
+        .attrs
+        .get("body")
+        .expect("Loop: missing 'body' attribute")
+        .clone()
+        .into_graph();
+
+    if body.inputs.len() < 2 {
+        panic!(
+            "Loop: the body must have the iteration number and the condition as inputs (got {} inputs)",
+            body.inputs.len()
+        );
+    }
+    let num_carried = body.inputs.len() - 2;
+
+    if body.outputs.len() < num_carried + 1 {
+        panic!(
+            "Loop: the body must output the condition and {num_carried} loop carried dependencies (got {} outputs)",
+            body.outputs.len()
+        );
+    }
+    let num_scan_outputs = body.outputs.len() - num_carried - 1;
+
+    LoopConfig {
+        body,
+        num_carried,
+        num_scan_outputs,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::{Argument, NodeType};
+    use crate::node::test_utils::NodeBuilder;
+
+    fn arg(name: &str, ty: ArgType) -> Argument {
+        let mut arg = Argument::new(name.to_string());
+        arg.ty = ty;
+        arg
+    }
+
+    fn tensor(rank: usize) -> ArgType {
+        ArgType::Tensor(TensorType {
+            elem_type: ElementType::Float32,
+            rank,
+            static_shape: None,
+        })
+    }
+
+    fn create_test_node() -> Node {
+        // One loop carried dependency and one scan output
+        let body = OnnxGraph {
+            nodes: vec![],
+            inputs: vec![
+                arg("iter", ArgType::Scalar(ElementType::Int64)),
+                arg("cond_in", ArgType::Scalar(ElementType::Bool)),
+                arg("state_in", tensor(2)),
+            ],
+            outputs: vec![
+                arg("cond_out", ArgType::Scalar(ElementType::Bool)),
+                arg("state_out", tensor(2)),
+                arg("scan_out", tensor(2)),
+            ],
+        };
+
+        NodeBuilder::new(NodeType::Loop, "test_loop")
+            .input_scalar_i64("max_trip_count")
+            .input_scalar("cond", ElementType::Bool)
+            .input_tensor_f32("state", 2, None)
+            .output_default("state_final")
+            .output_default("scan_final")
+            .attr_graph("body", body)
+            .build()
+    }
+
+    #[test]
+    fn test_loop_config() {
+        let config = loop_config(&create_test_node());
+
+        assert_eq!(config.num_carried, 1);
+        assert_eq!(config.num_scan_outputs, 1);
+    }
+
+    #[test]
+    fn test_loop_update_outputs() {
+        let mut node = create_test_node();
+
+        loop_update_outputs(&mut node);
+
+        assert_eq!(node.outputs[0].ty, tensor(2));
+        assert_eq!(node.outputs[1].ty, tensor(3));
+    }
+
+    #[test]
+    fn test_stacked_scalar() {
+        let ty = stacked_type(&ArgType::Scalar(ElementType::Int64));
+
+        assert_eq!(
+            ty,
+            ArgType::Tensor(TensorType {
+                elem_type: ElementType::Int64,
+                rank: 1,
+                static_shape: None,
+            })
+        );
+    }
+}
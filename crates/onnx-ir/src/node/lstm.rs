@@ -0,0 +1,175 @@
+use crate::ir::Node;
+
+use super::rnn::{RnnDirection, rnn_attributes, rnn_has_input, rnn_weight_shape};
+
+/// Configuration for LSTM operations extracted from ONNX nodes
+#[derive(Debug, Clone)]
+pub struct LstmConfig {
+    /// Size of the input features
+    pub input_size: usize,
+    /// Size of the hidden state
+    pub hidden_size: usize,
+    /// Direction in which the sequence is processed
+    pub direction: RnnDirection,
+    /// Whether the batch is the first dimension of the input and outputs (`layout` = 1)
+    pub batch_first: bool,
+    /// Whether bias is used
+    pub bias: bool,
+}
+
+/// Create a LstmConfig from the attributes of the node
+///
+/// The inputs are `X`, `W`, `R`, `B`, `sequence_lens`, `initial_h`, `initial_c` and `P`. The
+/// weights `W` and `R`, and the bias `B` must be constants.
+pub fn lstm_config(node: &Node) -> LstmConfig {
+    let attrs = rnn_attributes(node, &["Sigmoid", "Tanh", "Tanh"]);
+
+    if let Some(input_forget) = node.attrs.get("input_forget")
+        && input_forget.clone().into_i64() != 0
+    {
+        panic!("LSTM: coupling the input and forget gates is not supported");
+    }
+    if rnn_has_input(node, 7) {
+        panic!("LSTM: peephole weights are not supported");
+    }
+
+    let weight_shape = rnn_weight_shape(node, 1, "W");
+    if weight_shape.len() != 3 {
+        panic!(
+            "LSTM: W tensor must have 3 dimensions (got {:?})",
+            weight_shape.len()
+        );
+    }
+
+    let num_directions = attrs.direction.num_directions();
+    if weight_shape[0] != num_directions {
+        panic!(
+            "LSTM: W tensor has {} directions, expected {num_directions}",
+            weight_shape[0]
+        );
+    }
+
+    let hidden_size = weight_shape[1] / 4;
+    if let Some(expected) = attrs.hidden_size
+        && expected != hidden_size
+    {
+        panic!("LSTM: hidden_size is {expected}, but W has a hidden size of {hidden_size}");
+    }
+
+    // The recurrence weights and the optional bias must be constants as well
+    rnn_weight_shape(node, 2, "R");
+    let bias = rnn_has_input(node, 3);
+    if bias {
+        rnn_weight_shape(node, 3, "B");
+    }
+
+    LstmConfig {
+        input_size: weight_shape[2],
+        hidden_size,
+        direction: attrs.direction,
+        batch_first: attrs.batch_first,
+        bias,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::{ArgType, ElementType, NodeType};
+    use crate::node::rnn::rnn_update_outputs;
+    use crate::node::test_utils::NodeBuilder;
+
+    fn create_test_node(num_directions: usize, bias: bool) -> NodeBuilder {
+        let (input_size, hidden_size) = (3, 2);
+        let gates = 4 * hidden_size;
+
+        let mut builder = NodeBuilder::new(NodeType::LSTM, "test_lstm")
+            .input_tensor_f32("X", 3, None)
+            .input_tensor_f32_data(
+                "W",
+                vec![0.0; num_directions * gates * input_size],
+                vec![num_directions, gates, input_size],
+            )
+            .input_tensor_f32_data(
+                "R",
+                vec![0.0; num_directions * gates * hidden_size],
+                vec![num_directions, gates, hidden_size],
+            )
+            .output_default("Y")
+            .output_default("Y_h")
+            .output_default("Y_c");
+
+        if bias {
+            builder = builder.input_tensor_f32_data(
+                "B",
+                vec![0.0; num_directions * 2 * gates],
+                vec![num_directions, 2 * gates],
+            );
+        }
+
+        builder
+    }
+
+    #[test]
+    fn test_lstm_config_forward() {
+        let node = create_test_node(1, true).build();
+        let config = lstm_config(&node);
+
+        assert_eq!(config.input_size, 3);
+        assert_eq!(config.hidden_size, 2);
+        assert_eq!(config.direction, RnnDirection::Forward);
+        assert!(!config.batch_first);
+        assert!(config.bias);
+    }
+
+    #[test]
+    fn test_lstm_config_bidirectional() {
+        let node = create_test_node(2, false)
+            .attr_string("direction", "bidirectional")
+            .attr_int("hidden_size", 2)
+            .attr_int("layout", 1)
+            .build();
+        let config = lstm_config(&node);
+
+        assert_eq!(config.direction, RnnDirection::Bidirectional);
+        assert!(config.batch_first);
+        assert!(!config.bias);
+    }
+
+    #[test]
+    #[should_panic(expected = "W tensor has 1 directions, expected 2")]
+    fn test_lstm_config_direction_mismatch() {
+        let node = create_test_node(1, false)
+            .attr_string("direction", "bidirectional")
+            .build();
+        let _ = lstm_config(&node);
+    }
+
+    #[test]
+    #[should_panic(expected = "only the default activations")]
+    fn test_lstm_config_unsupported_activations() {
+        let node = create_test_node(1, false)
+            .attr_strings(
+                "activations",
+                vec!["Relu".to_string(), "Tanh".to_string(), "Tanh".to_string()],
+            )
+            .build();
+        let _ = lstm_config(&node);
+    }
+
+    #[test]
+    fn test_lstm_update_outputs() {
+        let mut node = create_test_node(1, false).build();
+        rnn_update_outputs(&mut node);
+
+        for (output, rank) in node.outputs.iter().zip([4, 3, 3]) {
+            match &output.ty {
+                ArgType::Tensor(tensor) => {
+                    assert_eq!(tensor.elem_type, ElementType::Float32);
+                    assert_eq!(tensor.rank, rank);
+                }
+                _ => panic!("Expected tensor output"),
+            }
+        }
+    }
+}
@@ -15,6 +15,7 @@ pub mod argmin;
 pub mod attention;
 pub mod avg_pool1d;
 pub mod avg_pool2d;
+pub mod avg_pool3d;
 pub mod batch_norm;
 pub mod bernoulli;
 pub mod bitshift;
@@ -30,33 +31,47 @@ pub mod conv3d;
 pub mod conv_transpose1d;
 pub mod conv_transpose2d;
 pub mod conv_transpose3d;
+pub mod cumsum;
 pub mod depth_to_space;
 pub mod dropout;
+pub mod einsum;
 pub mod expand;
 pub mod flatten;
 pub mod gather;
+pub mod gather_nd;
 pub mod gemm;
 pub mod group_norm;
+pub mod gru;
 pub mod hard_sigmoid;
+pub mod if_op;
 pub mod instance_norm;
 pub mod is_inf;
 pub mod layer_norm;
 pub mod leaky_relu;
 pub mod linear;
 pub mod log_softmax;
+pub mod loop_op;
+pub mod lstm;
 pub mod matmul;
 pub mod matmulinteger;
 pub mod max_pool1d;
 pub mod max_pool2d;
+pub mod max_pool3d;
+pub mod non_max_suppression;
+pub mod nonzero;
 pub mod one_hot;
 pub mod pad;
 pub mod padding;
+pub mod quantization;
 pub mod random;
 pub mod random_like;
 pub mod range;
 pub mod reduce;
 pub mod reshape;
 pub mod resize;
+pub mod rnn;
+pub mod scan;
+pub mod scatter;
 pub mod shape;
 pub mod size;
 pub mod slice;
@@ -0,0 +1,123 @@
+use crate::ir::{ArgType, ElementType, Node, TensorType};
+
+/// Configuration for the NonMaxSuppression operation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NonMaxSuppressionConfig {
+    /// Whether the boxes are `[x_center, y_center, width, height]` (`center_point_box` = 1)
+    /// instead of the diagonal corners `[y1, x1, y2, x2]`.
+    pub center_point_box: bool,
+    /// The maximum number of boxes selected per batch and class, or 0 for no box.
+    pub max_output_boxes_per_class: usize,
+    /// The IoU above which a box is suppressed by a box with a higher score.
+    pub iou_threshold: f32,
+    /// The score below which a box is ignored, if any.
+    pub score_threshold: Option<f32>,
+}
+
+/// Creates a NonMaxSuppressionConfig from the node attributes and inputs.
+///
+/// The inputs are `boxes`, `scores`, `max_output_boxes_per_class`, `iou_threshold` and
+/// `score_threshold`, where the last three are optional and must be constants.
+pub fn non_max_suppression_config(node: &Node) -> NonMaxSuppressionConfig {
+    let constant = |idx: usize, name: &str| {
+        node.inputs
+            .get(idx)
+            .filter(|input| !input.name.is_empty())
+            .map(|input| {
+                input
+                    .value
+                    .as_ref()
+                    .unwrap_or_else(|| {
+                        panic!("NonMaxSuppression: only a constant {name} is supported")
+                    })
+                    .data
+                    .clone()
+            })
+    };
+
+    let center_point_box = match node.attrs.get("center_point_box") {
+        Some(center_point_box) => center_point_box.clone().into_i64() != 0,
+        None => false,
+    };
+    let max_output_boxes_per_class = constant(2, "max_output_boxes_per_class")
+        .map(|data| data.into_i64().max(0) as usize)
+        .unwrap_or(0);
+    let iou_threshold = constant(3, "iou_threshold")
+        .map(|data| data.into_f32())
+        .unwrap_or(0.0);
+    let score_threshold = constant(4, "score_threshold").map(|data| data.into_f32());
+
+    NonMaxSuppressionConfig {
+        center_point_box,
+        max_output_boxes_per_class,
+        iou_threshold,
+        score_threshold,
+    }
+}
+
+/// Update output type for NonMaxSuppression, the 2D int64 `[batch_index, class_index,
+/// box_index]` triplets of the selected boxes.
+pub fn non_max_suppression_update_outputs(node: &mut Node) {
+    log::debug!("NonMaxSuppression rank inference for node {}", node.name);
+
+    node.outputs[0].ty = ArgType::Tensor(TensorType {
+        elem_type: ElementType::Int64,
+        rank: 2,
+        static_shape: None,
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::NodeType;
+    use crate::node::test_utils::NodeBuilder;
+
+    fn create_test_node() -> NodeBuilder {
+        NodeBuilder::new(NodeType::NonMaxSuppression, "test_nms")
+            .input_tensor_f32("boxes", 3, None)
+            .input_tensor_f32("scores", 3, None)
+            .output_default("selected_indices")
+    }
+
+    #[test]
+    fn test_non_max_suppression_config() {
+        let node = create_test_node()
+            .input_tensor_i64_data("max_output_boxes_per_class", vec![3], vec![1])
+            .input_scalar_tensor_f32("iou_threshold", Some(0.5))
+            .input_scalar_tensor_f32("score_threshold", Some(0.1))
+            .attr_int("center_point_box", 1)
+            .build();
+        let config = non_max_suppression_config(&node);
+
+        assert!(config.center_point_box);
+        assert_eq!(config.max_output_boxes_per_class, 3);
+        assert_eq!(config.iou_threshold, 0.5);
+        assert_eq!(config.score_threshold, Some(0.1));
+    }
+
+    #[test]
+    fn test_non_max_suppression_config_defaults() {
+        let node = create_test_node().build();
+        let config = non_max_suppression_config(&node);
+
+        assert!(!config.center_point_box);
+        assert_eq!(config.max_output_boxes_per_class, 0);
+        assert_eq!(config.iou_threshold, 0.0);
+        assert_eq!(config.score_threshold, None);
+    }
+
+    #[test]
+    fn test_non_max_suppression_update_outputs() {
+        let mut node = create_test_node().build();
+        non_max_suppression_update_outputs(&mut node);
+
+        match &node.outputs[0].ty {
+            ArgType::Tensor(tensor) => {
+                assert_eq!(tensor.elem_type, ElementType::Int64);
+                assert_eq!(tensor.rank, 2);
+            }
+            _ => panic!("Expected tensor output"),
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use crate::ir::{ArgType, ElementType, Node, TensorType};
+
+/// Update output type for NonZero, a 2D int64 tensor with the indices of the non-zero elements
+/// of every dimension of the input.
+pub fn nonzero_update_outputs(node: &mut Node) {
+    log::debug!("NonZero rank inference for node {}", node.name);
+
+    node.outputs[0].ty = ArgType::Tensor(TensorType {
+        elem_type: ElementType::Int64,
+        rank: 2,
+        static_shape: None,
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::NodeType;
+    use crate::node::test_utils::NodeBuilder;
+
+    #[test]
+    fn test_nonzero_update_outputs() {
+        let mut node = NodeBuilder::new(NodeType::NonZero, "test_nonzero")
+            .input_tensor_f32("x", 3, None)
+            .output_default("y")
+            .build();
+        nonzero_update_outputs(&mut node);
+
+        match &node.outputs[0].ty {
+            ArgType::Tensor(tensor) => {
+                assert_eq!(tensor.elem_type, ElementType::Int64);
+                assert_eq!(tensor.rank, 2);
+            }
+            _ => panic!("Expected tensor output"),
+        }
+    }
+}
@@ -0,0 +1,548 @@
+use crate::from_onnx::element_type_from_proto;
+use crate::ir::{ArgType, Data, ElementType, Node, TensorData, TensorType};
+use crate::node::conv2d::{Conv2dConfig, conv2d_config};
+
+/// The parameters of a linear quantization, where a quantized value `q` represents the real value
+/// `(q - zero_point) * scale`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuantizationParams {
+    /// The scale of the whole tensor, or of every slice along `axis`
+    pub scale: Vec<f32>,
+    /// The zero point of the whole tensor, or of every slice along `axis`
+    pub zero_point: Vec<i32>,
+    /// The axis of a per-axis quantization
+    pub axis: usize,
+    /// The type of the quantized values
+    pub elem_type: ElementType,
+}
+
+impl QuantizationParams {
+    /// Whether the whole tensor shares the same parameters.
+    pub fn is_per_tensor(&self) -> bool {
+        self.scale.len() == 1
+    }
+
+    /// Whether the zero points are all zero.
+    pub fn is_symmetric(&self) -> bool {
+        self.zero_point.iter().all(|zero_point| *zero_point == 0)
+    }
+
+    /// The range of the quantized values.
+    pub fn range(&self) -> (i32, i32) {
+        match self.elem_type {
+            ElementType::Int8 => (i8::MIN as i32, i8::MAX as i32),
+            ElementType::Uint8 => (u8::MIN as i32, u8::MAX as i32),
+            ElementType::Int32 => (i32::MIN, i32::MAX),
+            ref other => panic!("Unsupported quantized type {other:?}"),
+        }
+    }
+}
+
+/// Configuration for QLinearMatMul operations extracted from ONNX nodes
+#[derive(Debug, Clone)]
+pub struct QLinearMatMulConfig {
+    /// Quantization of `a`, per tensor or per row
+    pub a: QuantizationParams,
+    /// Quantization of `b`, per tensor or per column
+    pub b: QuantizationParams,
+    /// Quantization of the output
+    pub y: QuantizationParams,
+}
+
+/// Configuration for QLinearConv operations extracted from ONNX nodes
+#[derive(Debug, Clone)]
+pub struct QLinearConvConfig {
+    /// The convolution of the dequantized values
+    pub conv: Conv2dConfig,
+    /// Quantization of the input
+    pub x: QuantizationParams,
+    /// Quantization of the weights, per tensor or per output channel
+    pub w: QuantizationParams,
+    /// Quantization of the output
+    pub y: QuantizationParams,
+}
+
+/// Configuration for ConvInteger operations extracted from ONNX nodes
+#[derive(Debug, Clone)]
+pub struct ConvIntegerConfig {
+    /// The convolution of the values shifted by their zero point
+    pub conv: Conv2dConfig,
+    /// The zero point of the input, with a scale of 1
+    pub x: QuantizationParams,
+    /// The zero points of the weights, with a scale of 1
+    pub w: QuantizationParams,
+}
+
+/// Create the QuantizationParams of a QuantizeLinear node.
+///
+/// The quantized type is the type of the zero point, or `uint8` by default.
+pub fn quantize_linear_config(node: &Node) -> QuantizationParams {
+    let axis = quantization_axis(node);
+    quantization_params(node, 1, 2, axis, quantized_elem_type(node))
+}
+
+/// Create the QuantizationParams of a DequantizeLinear node.
+pub fn dequantize_linear_config(node: &Node) -> QuantizationParams {
+    let axis = quantization_axis(node);
+    let elem_type = node.inputs[0].ty.elem_type().clone();
+    quantization_params(node, 1, 2, axis, elem_type)
+}
+
+/// Create a QLinearMatMulConfig from the inputs of the node
+///
+/// The inputs are `a`, `a_scale`, `a_zero_point`, `b`, `b_scale`, `b_zero_point`, `y_scale` and
+/// `y_zero_point`. The scales and zero points must be constants.
+pub fn qlinear_matmul_config(node: &Node) -> QLinearMatMulConfig {
+    let a_rank = node.inputs[0].ty.rank();
+    let b_rank = node.inputs[3].ty.rank();
+    let zero_point_type = |idx: usize| node.inputs[idx].ty.elem_type().clone();
+
+    let config = QLinearMatMulConfig {
+        a: quantization_params(node, 1, 2, a_rank.saturating_sub(2), zero_point_type(2)),
+        b: quantization_params(node, 4, 5, b_rank.saturating_sub(1), zero_point_type(5)),
+        y: quantization_params(node, 6, 7, 0, zero_point_type(7)),
+    };
+    if !config.y.is_per_tensor() {
+        panic!("QLinearMatMul: the output must be quantized per tensor");
+    }
+
+    config
+}
+
+/// Create a QLinearConvConfig from the attributes and inputs of the node
+///
+/// The inputs are `x`, `x_scale`, `x_zero_point`, `w`, `w_scale`, `w_zero_point`, `y_scale`,
+/// `y_zero_point` and `B`. The weights and the quantization parameters must be constants.
+pub fn qlinear_conv_config(node: &Node) -> QLinearConvConfig {
+    let zero_point_type = |idx: usize| node.inputs[idx].ty.elem_type().clone();
+
+    let config = QLinearConvConfig {
+        conv: quantized_conv_config(node, 3, Some(8)),
+        x: quantization_params(node, 1, 2, 1, zero_point_type(2)),
+        w: quantization_params(node, 4, 5, 0, zero_point_type(5)),
+        y: quantization_params(node, 6, 7, 1, zero_point_type(7)),
+    };
+    if !config.x.is_per_tensor() || !config.y.is_per_tensor() {
+        panic!("QLinearConv: the input and output must be quantized per tensor");
+    }
+
+    config
+}
+
+/// Create a ConvIntegerConfig from the attributes and inputs of the node
+///
+/// The inputs are `x`, `w`, `x_zero_point` and `w_zero_point`. The weights and the zero points
+/// must be constants.
+pub fn conv_integer_config(node: &Node) -> ConvIntegerConfig {
+    // The zero points of `x` and `w` are two inputs after them
+    let zero_point = |idx: usize, axis: usize| {
+        let elem_type = node.inputs[idx - 2].ty.elem_type().clone();
+        let zero_point = match node.inputs.get(idx).filter(|input| !input.name.is_empty()) {
+            Some(input) => input
+                .value
+                .as_ref()
+                .unwrap_or_else(|| panic!("ConvInteger: the zero points must be constants"))
+                .data
+                .clone()
+                .into_i32s(),
+            None => vec![0],
+        };
+
+        QuantizationParams {
+            scale: vec![1.0; zero_point.len()],
+            zero_point,
+            axis,
+            elem_type,
+        }
+    };
+
+    let config = ConvIntegerConfig {
+        conv: quantized_conv_config(node, 1, None),
+        x: zero_point(2, 1),
+        w: zero_point(3, 0),
+    };
+    if !config.x.is_per_tensor() {
+        panic!("ConvInteger: the input must have a single zero point");
+    }
+
+    config
+}
+
+/// The configuration of the convolution of a quantized node, with the weights at the given index.
+fn quantized_conv_config(node: &Node, weight: usize, bias: Option<usize>) -> Conv2dConfig {
+    let mut conv = node.clone();
+    conv.inputs = vec![node.inputs[0].clone(), node.inputs[weight].clone()];
+    if let Some(bias) = bias.and_then(|idx| node.inputs.get(idx))
+        && !bias.name.is_empty()
+    {
+        conv.inputs.push(bias.clone());
+    }
+
+    let rank = conv.inputs[1].ty.rank();
+    if rank != 4 {
+        panic!(
+            "{:?}: only 2D convolutions are supported (got weights of rank {rank})",
+            node.node_type
+        );
+    }
+
+    conv2d_config(&conv)
+}
+
+/// Dequantize constant data.
+///
+/// Symmetric int8 tensors that are quantized per tensor, or per slice along the first axis, are
+/// kept quantized since they map to a block quantization of the contiguous values. The weights of
+/// a `Gemm` converted to `Linear` are transposed, so only the ones quantized per tensor stay
+/// quantized.
+pub fn dequantize_data(data: &TensorData, params: &QuantizationParams) -> TensorData {
+    if let Data::Int8s(values) = &data.data
+        && !data.shape.is_empty()
+        && params.is_symmetric()
+        && (params.is_per_tensor() || params.axis == 0)
+    {
+        return TensorData {
+            data: Data::QInt8s {
+                values: values.clone(),
+                scales: params.scale.clone(),
+            },
+            shape: data.shape.clone(),
+        };
+    }
+
+    let inner = data.shape.iter().skip(params.axis + 1).product::<usize>();
+    let values = data
+        .data
+        .clone()
+        .into_i32s()
+        .into_iter()
+        .enumerate()
+        .map(|(i, x)| {
+            let slice = match params.is_per_tensor() {
+                true => 0,
+                false => (i / inner) % params.scale.len(),
+            };
+            (x - params.zero_point[slice]) as f32 * params.scale[slice]
+        })
+        .collect();
+
+    TensorData {
+        data: Data::Float32s(values),
+        shape: data.shape.clone(),
+    }
+}
+
+/// The normalized `axis` attribute of QuantizeLinear and DequantizeLinear nodes.
+fn quantization_axis(node: &Node) -> usize {
+    let rank = node.inputs[0].ty.rank() as i64;
+    let mut axis = 1;
+
+    for (key, value) in node.attrs.iter() {
+        match key.as_str() {
+            "axis" => axis = value.clone().into_i64(),
+            "block_size" => {
+                if value.clone().into_i64() != 0 {
+                    panic!(
+                        "{:?}: blocked quantization is not supported",
+                        node.node_type
+                    );
+                }
+            }
+            // Only used to infer the quantized type
+            "output_dtype" | "saturate" => {}
+            _ => panic!("Unexpected attribute for {:?}: {key}", node.node_type),
+        }
+    }
+
+    if axis < 0 {
+        axis += rank;
+    }
+
+    axis as usize
+}
+
+/// The parameters given by the scale and zero point inputs at the given indices.
+fn quantization_params(
+    node: &Node,
+    scale: usize,
+    zero_point: usize,
+    axis: usize,
+    elem_type: ElementType,
+) -> QuantizationParams {
+    let value = |idx: usize, name: &str| {
+        let input = node
+            .inputs
+            .get(idx)
+            .filter(|input| !input.name.is_empty())?;
+        let value = input.value.as_ref().unwrap_or_else(|| {
+            panic!(
+                "{:?}: the {name} must be a constant (input {idx})",
+                node.node_type
+            )
+        });
+
+        Some(value.data.clone())
+    };
+
+    let scale = value(scale, "scale")
+        .unwrap_or_else(|| panic!("{:?}: the scale is required", node.node_type))
+        .into_f32s();
+    let zero_point = value(zero_point, "zero point")
+        .map(|data| data.into_i32s())
+        .unwrap_or_else(|| vec![0; scale.len()]);
+
+    if scale.len() != zero_point.len() {
+        panic!(
+            "{:?}: the scale has {} values but the zero point has {}",
+            node.node_type,
+            scale.len(),
+            zero_point.len()
+        );
+    }
+
+    QuantizationParams {
+        scale,
+        zero_point,
+        axis,
+        elem_type,
+    }
+}
+
+/// The quantized type of a QuantizeLinear node.
+fn quantized_elem_type(node: &Node) -> ElementType {
+    if let Some(zero_point) = node.inputs.get(2).filter(|input| !input.name.is_empty()) {
+        return zero_point.ty.elem_type().clone();
+    }
+
+    match node.attrs.get("output_dtype") {
+        Some(dtype) if dtype.clone().into_i64() != 0 => {
+            element_type_from_proto(dtype.clone().into_i64() as i32).unwrap()
+        }
+        _ => ElementType::Uint8,
+    }
+}
+
+fn update_output(node: &mut Node, rank: usize, elem_type: ElementType) {
+    node.outputs[0].ty = ArgType::Tensor(TensorType {
+        elem_type,
+        rank,
+        static_shape: None,
+    });
+}
+
+/// Update the output of QuantizeLinear, which has the quantized type.
+pub fn quantize_linear_update_outputs(node: &mut Node) {
+    let rank = node.inputs[0].ty.rank();
+    let elem_type = quantized_elem_type(node);
+    update_output(node, rank, elem_type);
+}
+
+/// Update the output of DequantizeLinear, which has the type of the scale.
+pub fn dequantize_linear_update_outputs(node: &mut Node) {
+    let rank = node.inputs[0].ty.rank();
+    let elem_type = node.inputs[1].ty.elem_type().clone();
+    update_output(node, rank, elem_type);
+}
+
+/// Update the output of QLinearMatMul, which has the rank of a matrix product and the type of the
+/// output zero point.
+pub fn qlinear_matmul_update_outputs(node: &mut Node) {
+    let (a, b) = (node.inputs[0].ty.rank(), node.inputs[3].ty.rank());
+    let mut rank = a.max(b);
+    if a == 1 || b == 1 {
+        rank -= 1;
+    }
+    let elem_type = node.inputs[7].ty.elem_type().clone();
+    update_output(node, rank, elem_type);
+}
+
+/// Update the output of QLinearConv, which has the type of the output zero point.
+pub fn qlinear_conv_update_outputs(node: &mut Node) {
+    let rank = node.inputs[0].ty.rank();
+    let elem_type = node.inputs[7].ty.elem_type().clone();
+    update_output(node, rank, elem_type);
+}
+
+/// Update the output of ConvInteger, which is always `int32`.
+pub fn conv_integer_update_outputs(node: &mut Node) {
+    let rank = node.inputs[0].ty.rank();
+    update_output(node, rank, ElementType::Int32);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::NodeType;
+    use crate::node::test_utils::NodeBuilder;
+
+    fn params(scale: Vec<f32>, zero_point: Vec<i32>, elem_type: ElementType) -> QuantizationParams {
+        QuantizationParams {
+            scale,
+            zero_point,
+            axis: 0,
+            elem_type,
+        }
+    }
+
+    #[test]
+    fn test_quantize_linear_config() {
+        let mut node = NodeBuilder::new(NodeType::QuantizeLinear, "test_quantize")
+            .input_tensor_f32("x", 2, None)
+            .input_tensor_f32_data("y_scale", vec![0.5, 0.25], vec![2])
+            .input_tensor_with_data(
+                "y_zero_point",
+                ElementType::Int8,
+                1,
+                Data::Int8s(vec![0, 1]),
+                vec![2],
+            )
+            .output_default("y")
+            .attr_int("axis", -1)
+            .build();
+
+        let config = quantize_linear_config(&node);
+        assert_eq!(config.scale, vec![0.5, 0.25]);
+        assert_eq!(config.zero_point, vec![0, 1]);
+        assert_eq!(config.axis, 1);
+        assert_eq!(config.range(), (-128, 127));
+
+        quantize_linear_update_outputs(&mut node);
+        match &node.outputs[0].ty {
+            ArgType::Tensor(tensor) => {
+                assert_eq!(tensor.elem_type, ElementType::Int8);
+                assert_eq!(tensor.rank, 2);
+            }
+            _ => panic!("Expected tensor output"),
+        }
+    }
+
+    #[test]
+    fn test_quantize_linear_default_type() {
+        let mut node = NodeBuilder::new(NodeType::QuantizeLinear, "test_quantize")
+            .input_tensor_f32("x", 3, None)
+            .input_tensor_f32_data("y_scale", vec![0.5], vec![])
+            .output_default("y")
+            .build();
+
+        let config = quantize_linear_config(&node);
+        assert!(config.is_per_tensor());
+        assert!(config.is_symmetric());
+        assert_eq!(config.elem_type, ElementType::Uint8);
+
+        quantize_linear_update_outputs(&mut node);
+        assert_eq!(node.outputs[0].ty.elem_type(), &ElementType::Uint8);
+    }
+
+    #[test]
+    #[should_panic(expected = "the scale must be a constant")]
+    fn test_dequantize_linear_runtime_scale() {
+        let node = NodeBuilder::new(NodeType::DequantizeLinear, "test_dequantize")
+            .input_tensor_i32("x", 2, None)
+            .input_tensor_f32("x_scale", 0, None)
+            .output_default("y")
+            .build();
+
+        let _ = dequantize_linear_config(&node);
+    }
+
+    #[test]
+    fn test_dequantize_data_symmetric_int8_per_channel() {
+        let data = TensorData {
+            data: Data::Int8s(vec![1, -2, 3, 4]),
+            shape: vec![2, 2],
+        };
+        let result = dequantize_data(
+            &data,
+            &params(vec![0.5, 2.0], vec![0, 0], ElementType::Int8),
+        );
+
+        match &result.data {
+            Data::QInt8s { values, scales } => {
+                assert_eq!(values, &vec![1, -2, 3, 4]);
+                assert_eq!(scales, &vec![0.5, 2.0]);
+            }
+            other => panic!("Expected quantized data, got {other:?}"),
+        }
+        assert_eq!(result.data.into_f32s(), vec![0.5, -1.0, 6.0, 8.0]);
+    }
+
+    #[test]
+    fn test_dequantize_data_asymmetric_uint8() {
+        let data = TensorData {
+            data: Data::Uint8s(vec![10, 12, 10, 12]),
+            shape: vec![2, 2],
+        };
+        let mut params = params(vec![0.5, 2.0], vec![10, 12], ElementType::Uint8);
+        params.axis = 1;
+        let result = dequantize_data(&data, &params);
+
+        assert_eq!(result.shape, vec![2, 2]);
+        assert_eq!(result.data.into_f32s(), vec![0.0, 0.0, 0.0, 0.0]);
+
+        let data = TensorData {
+            data: Data::Uint8s(vec![12, 12, 8, 8]),
+            shape: vec![2, 2],
+        };
+        let result = dequantize_data(&data, &params);
+        assert_eq!(result.data.into_f32s(), vec![1.0, 0.0, -1.0, -8.0]);
+    }
+
+    #[test]
+    fn test_qlinear_conv_config() {
+        let mut node = NodeBuilder::new(NodeType::QLinearConv, "test_qlinear_conv")
+            .add_input(
+                "x",
+                ArgType::Tensor(TensorType {
+                    elem_type: ElementType::Uint8,
+                    rank: 4,
+                    static_shape: None,
+                }),
+            )
+            .input_tensor_f32_data("x_scale", vec![0.1], vec![])
+            .input_tensor_with_data(
+                "x_zero_point",
+                ElementType::Uint8,
+                0,
+                Data::Uint8s(vec![128]),
+                vec![],
+            )
+            .input_tensor_with_data(
+                "w",
+                ElementType::Int8,
+                4,
+                Data::Int8s(vec![0; 2 * 3 * 3 * 3]),
+                vec![2, 3, 3, 3],
+            )
+            .input_tensor_f32_data("w_scale", vec![0.1, 0.2], vec![2])
+            .input_tensor_with_data(
+                "w_zero_point",
+                ElementType::Int8,
+                1,
+                Data::Int8s(vec![0, 0]),
+                vec![2],
+            )
+            .input_tensor_f32_data("y_scale", vec![0.3], vec![])
+            .input_tensor_with_data(
+                "y_zero_point",
+                ElementType::Uint8,
+                0,
+                Data::Uint8s(vec![0]),
+                vec![],
+            )
+            .output_default("y")
+            .attr_ints("pads", vec![1, 1, 1, 1])
+            .build();
+
+        let config = qlinear_conv_config(&node);
+        assert_eq!(config.conv.channels, [3, 2]);
+        assert_eq!(config.conv.kernel_size, [3, 3]);
+        assert!(!config.conv.bias);
+        assert_eq!(config.x.zero_point, vec![128]);
+        assert_eq!(config.w.scale, vec![0.1, 0.2]);
+        assert_eq!(config.w.axis, 0);
+
+        qlinear_conv_update_outputs(&mut node);
+        assert_eq!(node.outputs[0].ty.elem_type(), &ElementType::Uint8);
+        assert_eq!(node.outputs[0].ty.rank(), 4);
+    }
+}
@@ -0,0 +1,123 @@
+use crate::ir::{ArgType, Node, TensorType};
+
+/// Direction of the recurrent operators (LSTM and GRU)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RnnDirection {
+    /// Process the sequence from the first to the last element
+    Forward,
+    /// Process the sequence from the last to the first element
+    Reverse,
+    /// Process the sequence in both directions
+    Bidirectional,
+}
+
+impl RnnDirection {
+    /// The size of the `num_directions` dimension of the weights, outputs and states.
+    pub fn num_directions(&self) -> usize {
+        match self {
+            RnnDirection::Forward | RnnDirection::Reverse => 1,
+            RnnDirection::Bidirectional => 2,
+        }
+    }
+}
+
+/// Update output types for the recurrent operators.
+///
+/// `Y` has rank 4, while the final states `Y_h` and `Y_c` (LSTM only) have rank 3.
+pub fn rnn_update_outputs(node: &mut Node) {
+    log::debug!("{:?} rank inference for node {}", node.node_type, node.name);
+
+    let elem_type = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.elem_type.clone(),
+        _ => panic!("{:?}: only tensor input is valid", node.node_type),
+    };
+
+    for (index, output) in node.outputs.iter_mut().enumerate() {
+        let rank = if index == 0 { 4 } else { 3 };
+        output.ty = ArgType::Tensor(TensorType {
+            elem_type: elem_type.clone(),
+            rank,
+            static_shape: None,
+        });
+    }
+}
+
+/// Attributes shared by the recurrent operators.
+#[derive(Debug, Clone)]
+pub(crate) struct RnnAttributes {
+    pub direction: RnnDirection,
+    pub hidden_size: Option<usize>,
+    pub batch_first: bool,
+}
+
+/// Read the attributes shared by the recurrent operators.
+///
+/// Only the default activations are supported, since they are the ones computed by the burn
+/// modules.
+pub(crate) fn rnn_attributes(node: &Node, default_activations: &[&str]) -> RnnAttributes {
+    let op = &node.node_type;
+    let mut direction = RnnDirection::Forward;
+    let mut hidden_size = None;
+    let mut batch_first = false;
+    let mut activations = None;
+
+    for (key, value) in node.attrs.iter() {
+        match key.as_str() {
+            "direction" => {
+                direction = match value.clone().into_string().as_str() {
+                    "forward" => RnnDirection::Forward,
+                    "reverse" => RnnDirection::Reverse,
+                    "bidirectional" => RnnDirection::Bidirectional,
+                    other => panic!("{op:?}: unsupported direction '{other}'"),
+                }
+            }
+            "hidden_size" => hidden_size = Some(value.clone().into_i64() as usize),
+            "layout" => batch_first = value.clone().into_i64() == 1,
+            "activations" => activations = Some(value.clone().into_strings()),
+            "clip" => panic!("{op:?}: the 'clip' attribute is not supported"),
+            _ => {}
+        }
+    }
+
+    if let Some(activations) = activations {
+        // One list of activations per direction
+        let count = default_activations.len() * direction.num_directions();
+        let expected = default_activations.iter().cycle().take(count);
+        let is_default = activations.len() == count
+            && activations
+                .iter()
+                .zip(expected)
+                .all(|(activation, expected)| activation.eq_ignore_ascii_case(expected));
+
+        if !is_default {
+            panic!(
+                "{op:?}: only the default activations {default_activations:?} are supported (got {activations:?})"
+            );
+        }
+    }
+
+    RnnAttributes {
+        direction,
+        hidden_size,
+        batch_first,
+    }
+}
+
+/// Read the shape of a weight input, which must be a constant.
+pub(crate) fn rnn_weight_shape(node: &Node, index: usize, name: &str) -> Vec<usize> {
+    node.inputs
+        .get(index)
+        .unwrap_or_else(|| panic!("{:?}: missing {name} tensor", node.node_type))
+        .value
+        .as_ref()
+        .unwrap_or_else(|| panic!("{:?}: {name} tensor must be a constant", node.node_type))
+        .shape
+        .clone()
+}
+
+/// Whether an optional input is present.
+pub(crate) fn rnn_has_input(node: &Node, index: usize) -> bool {
+    node.inputs
+        .get(index)
+        .is_some_and(|input| !input.name.is_empty())
+}
@@ -0,0 +1,211 @@
+use crate::ir::{Node, OnnxGraph};
+
+use super::loop_op::stacked_type;
+
+/// Update output types for Scan.
+///
+/// The final values of the state variables have the types of the body outputs, while the scan
+/// outputs are stacked along a new dimension.
+pub fn scan_update_outputs(node: &mut Node) {
+    log::debug!("Scan rank inference for node {}", node.name);
+
+    let config = scan_config(node);
+
+    for (index, (output, body_output)) in
+        node.outputs.iter_mut().zip(config.body.outputs).enumerate()
+    {
+        output.ty = if index < config.num_states {
+            body_output.ty
+        } else {
+            stacked_type(&body_output.ty)
+        };
+        log::debug!("Scan output {} type: {:?}", output.name, output.ty);
+    }
+}
+
+/// Configuration for the Scan operation.
+#[derive(Debug, Clone)]
+pub struct ScanConfig {
+    /// The graph executed for each slice of the scan inputs.
+    ///
+    /// Its inputs are the state variables and the slices of the scan inputs. Its outputs are the
+    /// state variables and the slices of the scan outputs.
+    pub body: OnnxGraph,
+    /// The number of state variables.
+    pub num_states: usize,
+    /// The axis of each scan input along which it is sliced.
+    pub scan_input_axes: Vec<usize>,
+    /// Whether each scan input is iterated in reverse order.
+    pub scan_input_reverse: Vec<bool>,
+    /// The axis of each scan output along which the slices are stacked.
+    pub scan_output_axes: Vec<usize>,
+    /// Whether the slices of each scan output are stacked in reverse order.
+    pub scan_output_reverse: Vec<bool>,
+}
+
+/// Creates a ScanConfig from the node attributes and inputs.
+///
+/// The inputs are the initial values of the state variables and the scan inputs, followed by the
+/// values of the outer scope used by the body.
+pub fn scan_config(node: &Node) -> ScanConfig {
+    let body = node
+        .attrs
+        .get("body")
+        .expect("Scan: missing 'body' attribute")
+        .clone()
+        .into_graph();
+    let num_scan_inputs = node
+        .attrs
+        .get("num_scan_inputs")
+        .expect("Scan: missing 'num_scan_inputs' attribute")
+        .clone()
+        .into_i64() as usize;
+
+    if body.inputs.len() < num_scan_inputs {
+        panic!(
+            "Scan: the body must have {num_scan_inputs} scan inputs (got {} inputs)",
+            body.inputs.len()
+        );
+    }
+    let num_states = body.inputs.len() - num_scan_inputs;
+
+    if body.outputs.len() < num_states {
+        panic!(
+            "Scan: the body must output {num_states} state variables (got {} outputs)",
+            body.outputs.len()
+        );
+    }
+    let num_scan_outputs = body.outputs.len() - num_states;
+
+    // The scan inputs are sliced, so the body inputs have one dimension less
+    let scan_input_ranks = body.inputs[num_states..]
+        .iter()
+        .map(|arg| arg.ty.rank() + 1)
+        .collect::<Vec<_>>();
+    let scan_output_ranks = body.outputs[num_states..]
+        .iter()
+        .map(|arg| arg.ty.rank() + 1)
+        .collect::<Vec<_>>();
+
+    let axes = |name: &str, ranks: &[usize]| -> Vec<usize> {
+        match node.attrs.get(name) {
+            Some(axes) => {
+                let axes = axes.clone().into_i64s();
+                if axes.len() != ranks.len() {
+                    panic!(
+                        "Scan: '{name}' must have {} values (got {})",
+                        ranks.len(),
+                        axes.len()
+                    );
+                }
+                axes.into_iter()
+                    .zip(ranks)
+                    .map(|(axis, rank)| {
+                        // If axis is negative, it is counted from the end
+                        if axis < 0 {
+                            (axis + *rank as i64) as usize
+                        } else {
+                            axis as usize
+                        }
+                    })
+                    .collect()
+            }
+            None => vec![0; ranks.len()],
+        }
+    };
+    let reverse = |name: &str, count: usize| -> Vec<bool> {
+        match node.attrs.get(name) {
+            Some(directions) => {
+                let directions = directions.clone().into_i64s();
+                if directions.len() != count {
+                    panic!(
+                        "Scan: '{name}' must have {count} values (got {})",
+                        directions.len()
+                    );
+                }
+                directions.into_iter().map(|dir| dir == 1).collect()
+            }
+            None => vec![false; count],
+        }
+    };
+
+    ScanConfig {
+        scan_input_axes: axes("scan_input_axes", &scan_input_ranks),
+        scan_input_reverse: reverse("scan_input_directions", num_scan_inputs),
+        scan_output_axes: axes("scan_output_axes", &scan_output_ranks),
+        scan_output_reverse: reverse("scan_output_directions", num_scan_outputs),
+        num_states,
+        body,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::{ArgType, Argument, ElementType, NodeType, TensorType};
+    use crate::node::test_utils::NodeBuilder;
+
+    fn arg(name: &str, rank: usize) -> Argument {
+        let mut arg = Argument::new(name.to_string());
+        arg.ty = ArgType::Tensor(TensorType {
+            elem_type: ElementType::Float32,
+            rank,
+            static_shape: None,
+        });
+        arg
+    }
+
+    fn create_test_node() -> NodeBuilder {
+        // One state variable, one scan input and one scan output
+        let body = OnnxGraph {
+            nodes: vec![],
+            inputs: vec![arg("state_in", 2), arg("slice_in", 2)],
+            outputs: vec![arg("state_out", 2), arg("slice_out", 2)],
+        };
+
+        NodeBuilder::new(NodeType::Scan, "test_scan")
+            .input_tensor_f32("state", 2, None)
+            .input_tensor_f32("sequence", 3, None)
+            .output_default("state_final")
+            .output_default("scan_final")
+            .attr_graph("body", body)
+            .attr_int("num_scan_inputs", 1)
+    }
+
+    #[test]
+    fn test_scan_config_defaults() {
+        let config = scan_config(&create_test_node().build());
+
+        assert_eq!(config.num_states, 1);
+        assert_eq!(config.scan_input_axes, vec![0]);
+        assert_eq!(config.scan_input_reverse, vec![false]);
+        assert_eq!(config.scan_output_axes, vec![0]);
+        assert_eq!(config.scan_output_reverse, vec![false]);
+    }
+
+    #[test]
+    fn test_scan_config_axes_and_directions() {
+        let node = create_test_node()
+            .attr_ints("scan_input_axes", vec![-2])
+            .attr_ints("scan_input_directions", vec![1])
+            .attr_ints("scan_output_axes", vec![2])
+            .build();
+
+        let config = scan_config(&node);
+
+        assert_eq!(config.scan_input_axes, vec![1]);
+        assert_eq!(config.scan_input_reverse, vec![true]);
+        assert_eq!(config.scan_output_axes, vec![2]);
+        assert_eq!(config.scan_output_reverse, vec![false]);
+    }
+
+    #[test]
+    fn test_scan_update_outputs() {
+        let mut node = create_test_node().build();
+
+        scan_update_outputs(&mut node);
+
+        assert_eq!(node.outputs[0].ty.rank(), 2);
+        assert_eq!(node.outputs[1].ty.rank(), 3);
+    }
+}
@@ -0,0 +1,118 @@
+use crate::ir::Node;
+
+/// How the updates of a scatter are combined with the existing values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScatterReduction {
+    /// The updates replace the existing values.
+    None,
+    /// The updates are added to the existing values.
+    Add,
+}
+
+/// Configuration for the ScatterElements operation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScatterElementsConfig {
+    /// The axis along which the updates are scattered.
+    pub axis: usize,
+    /// How the updates are combined with the existing values.
+    pub reduction: ScatterReduction,
+}
+
+/// Configuration for the ScatterND operation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScatterNDConfig {
+    /// How the updates are combined with the existing values.
+    pub reduction: ScatterReduction,
+}
+
+/// Creates a ScatterElementsConfig from the node attributes.
+pub fn scatter_elements_config(node: &Node) -> ScatterElementsConfig {
+    let rank = node.inputs[0].ty.rank() as i64;
+    let mut axis = 0;
+    let mut reduction = ScatterReduction::None;
+
+    for (key, value) in node.attrs.iter() {
+        match key.as_str() {
+            "axis" => axis = value.clone().into_i64(),
+            "reduction" => reduction = scatter_reduction(node, value.clone().into_string()),
+            _ => {}
+        }
+    }
+
+    if axis < 0 {
+        axis += rank;
+    }
+    if axis < 0 || axis >= rank {
+        panic!("ScatterElements: axis {axis} is out of bounds for a tensor of rank {rank}");
+    }
+
+    ScatterElementsConfig {
+        axis: axis as usize,
+        reduction,
+    }
+}
+
+/// Creates a ScatterNDConfig from the node attributes.
+pub fn scatter_nd_config(node: &Node) -> ScatterNDConfig {
+    let reduction = match node.attrs.get("reduction") {
+        Some(reduction) => scatter_reduction(node, reduction.clone().into_string()),
+        None => ScatterReduction::None,
+    };
+
+    ScatterNDConfig { reduction }
+}
+
+fn scatter_reduction(node: &Node, reduction: String) -> ScatterReduction {
+    match reduction.as_str() {
+        "none" => ScatterReduction::None,
+        "add" => ScatterReduction::Add,
+        other => panic!(
+            "{:?}: the {other} reduction is not supported",
+            node.node_type
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::NodeType;
+    use crate::node::test_utils::NodeBuilder;
+
+    fn create_test_node(node_type: NodeType, indices_rank: usize) -> NodeBuilder {
+        NodeBuilder::new(node_type, "test_scatter")
+            .input_tensor_f32("data", 3, None)
+            .input_tensor_i64("indices", indices_rank, None)
+            .input_tensor_f32("updates", 3, None)
+            .output_tensor_f32("output", 3, None)
+    }
+
+    #[test]
+    fn test_scatter_elements_config() {
+        let node = create_test_node(NodeType::ScatterElements, 3)
+            .attr_int("axis", -2)
+            .attr_string("reduction", "add")
+            .build();
+        let config = scatter_elements_config(&node);
+
+        assert_eq!(config.axis, 1);
+        assert_eq!(config.reduction, ScatterReduction::Add);
+    }
+
+    #[test]
+    fn test_scatter_nd_config_default() {
+        let node = create_test_node(NodeType::ScatterND, 2).build();
+        let config = scatter_nd_config(&node);
+
+        assert_eq!(config.reduction, ScatterReduction::None);
+    }
+
+    #[test]
+    #[should_panic(expected = "the mul reduction is not supported")]
+    fn test_scatter_nd_config_unsupported_reduction() {
+        let node = create_test_node(NodeType::ScatterND, 2)
+            .attr_string("reduction", "mul")
+            .build();
+        let _ = scatter_nd_config(&node);
+    }
+}
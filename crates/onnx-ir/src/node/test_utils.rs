@@ -1,5 +1,6 @@
 use crate::ir::{
-    ArgType, Argument, AttributeValue, Data, ElementType, Node, NodeType, TensorData, TensorType,
+    ArgType, Argument, AttributeValue, Data, ElementType, Node, NodeType, OnnxGraph, TensorData,
+    TensorType,
 };
 use std::collections::HashMap;
 
@@ -478,6 +479,13 @@ impl NodeBuilder {
         self
     }
 
+    /// Add a graph attribute
+    pub fn attr_graph(mut self, name: &str, graph: OnnxGraph) -> Self {
+        self.attrs
+            .insert(name.to_string(), AttributeValue::Graph(graph));
+        self
+    }
+
     /// Add a default output with the given name
     pub fn output_default(mut self, name: &str) -> Self {
         self.outputs.push(Argument {
@@ -45,14 +45,16 @@ pub fn remap_node_type(node: &mut Node) {
             remap_node_with_kernel_shape(node, |spatial_dims| match spatial_dims {
                 1 => NodeType::MaxPool1d,
                 2 => NodeType::MaxPool2d,
-                _ => panic!("Only max_pool 1d and 2d are supported"),
+                3 => NodeType::MaxPool3d,
+                _ => panic!("Only max_pool 1d, 2d and 3d are supported"),
             })
         }
         NodeType::AveragePool => {
             remap_node_with_kernel_shape(node, |spatial_dims| match spatial_dims {
                 1 => NodeType::AveragePool1d,
                 2 => NodeType::AveragePool2d,
-                _ => panic!("Only avg_pool 1d and 2d are supported"),
+                3 => NodeType::AveragePool3d,
+                _ => panic!("Only avg_pool 1d, 2d and 3d are supported"),
             })
         }
         _ => (),
@@ -104,4 +106,16 @@ mod tests {
         remap_node_type(&mut node);
         assert_eq!(node.node_type, NodeType::ConvTranspose1d);
     }
+
+    #[test]
+    fn should_infer_max_pool3d_node_from_kernel_shape() {
+        let mut node = NodeBuilder::new(NodeType::MaxPool, "test_maxpool3d")
+            .input_tensor_f32("data", 5, None)
+            .output_tensor_f32("output", 5, None)
+            .attr_ints("kernel_shape", vec![2, 2, 2])
+            .build();
+
+        remap_node_type(&mut node);
+        assert_eq!(node.node_type, NodeType::MaxPool3d);
+    }
 }
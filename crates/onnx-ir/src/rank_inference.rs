@@ -1,21 +1,51 @@
 use crate::{
     ir::{Node, NodeType},
     node::{
-        argmax::argmax_update_outputs, argmin::argmin_update_outputs,
-        attention::attention_update_output, bernoulli::bernoulli_update_output,
-        cast::cast_update_outputs, comparison::elementwise_comparison_outputs,
-        concat::concat_update_outputs, constant::constant_update_outputs,
+        argmax::argmax_update_outputs,
+        argmin::argmin_update_outputs,
+        attention::attention_update_output,
+        bernoulli::bernoulli_update_output,
+        cast::cast_update_outputs,
+        comparison::elementwise_comparison_outputs,
+        concat::concat_update_outputs,
+        constant::constant_update_outputs,
         constant_of_shape::constant_of_shape_update_output,
-        depth_to_space::depth_to_space_update_outputs, expand::expand_update_outputs,
-        flatten::flatten_update_outputs, gather::gather_update_outputs, gemm::gemm_output_shape,
-        linear::linear_update_outputs, matmul::matmul_update_outputs,
-        matmulinteger::matmulinteger_update_outputs, one_hot::one_hot_output_shape,
-        random::random_update_output, random_like::random_like_update_output,
-        range::range_update_outputs, reduce::reduce_update_outputs,
-        reshape::reshape_update_outputs, shape::shape_update_outputs, size::size_update_outputs,
-        slice::slice_update_output_rank, space_to_depth::space_to_depth_update_outputs,
-        split::split_update_outputs, squeeze::squeeze_update_output, topk::top_k_update_output,
-        unsqueeze::unsqueeze_update_output, where_op::where_update_outputs,
+        depth_to_space::depth_to_space_update_outputs,
+        einsum::einsum_update_outputs,
+        expand::expand_update_outputs,
+        flatten::flatten_update_outputs,
+        gather::gather_update_outputs,
+        gather_nd::gather_nd_update_outputs,
+        gemm::gemm_output_shape,
+        if_op::if_update_outputs,
+        linear::linear_update_outputs,
+        loop_op::loop_update_outputs,
+        matmul::matmul_update_outputs,
+        matmulinteger::matmulinteger_update_outputs,
+        non_max_suppression::non_max_suppression_update_outputs,
+        nonzero::nonzero_update_outputs,
+        one_hot::one_hot_output_shape,
+        quantization::{
+            conv_integer_update_outputs, dequantize_linear_update_outputs,
+            qlinear_conv_update_outputs, qlinear_matmul_update_outputs,
+            quantize_linear_update_outputs,
+        },
+        random::random_update_output,
+        random_like::random_like_update_output,
+        range::range_update_outputs,
+        reduce::reduce_update_outputs,
+        reshape::reshape_update_outputs,
+        rnn::rnn_update_outputs,
+        scan::scan_update_outputs,
+        shape::shape_update_outputs,
+        size::size_update_outputs,
+        slice::slice_update_output_rank,
+        space_to_depth::space_to_depth_update_outputs,
+        split::split_update_outputs,
+        squeeze::squeeze_update_output,
+        topk::top_k_update_output,
+        unsqueeze::unsqueeze_update_output,
+        where_op::where_update_outputs,
     },
     util::{same_as_input, same_as_input_broadcast, temporary_pass_through_stub},
 };
@@ -31,6 +61,7 @@ pub fn rank_inference(node: &mut Node) {
         NodeType::Attention => attention_update_output(node),
         NodeType::AveragePool1d => same_as_input(node),
         NodeType::AveragePool2d => same_as_input(node),
+        NodeType::AveragePool3d => same_as_input(node),
         NodeType::BatchNormalization => same_as_input(node),
         NodeType::BitShift => same_as_input_broadcast(node),
         NodeType::BitwiseAnd => same_as_input_broadcast(node),
@@ -46,11 +77,14 @@ pub fn rank_inference(node: &mut Node) {
         NodeType::ConstantOfShape => constant_of_shape_update_output(node),
         NodeType::Conv1d => same_as_input(node),
         NodeType::Conv2d => same_as_input(node),
+        NodeType::ConvInteger => conv_integer_update_outputs(node),
         NodeType::Cos => same_as_input(node),
         NodeType::Cosh => same_as_input(node),
+        NodeType::CumSum => same_as_input(node),
         NodeType::Div => same_as_input_broadcast(node),
         NodeType::Dropout => same_as_input(node),
         NodeType::Equal => elementwise_comparison_outputs(node),
+        NodeType::Einsum => einsum_update_outputs(node),
         NodeType::Erf => same_as_input(node),
         NodeType::Exp => same_as_input(node),
         NodeType::Expand => expand_update_outputs(node),
@@ -59,10 +93,13 @@ pub fn rank_inference(node: &mut Node) {
         NodeType::Gelu => same_as_input(node),
         NodeType::Gather => gather_update_outputs(node),
         NodeType::GatherElements => same_as_input(node),
+        NodeType::GatherND => gather_nd_update_outputs(node),
         NodeType::Gemm => gemm_output_shape(node),
         NodeType::Greater => elementwise_comparison_outputs(node),
         NodeType::GreaterOrEqual => elementwise_comparison_outputs(node),
+        NodeType::GRU => rnn_update_outputs(node),
         NodeType::HardSigmoid => same_as_input(node),
+        NodeType::If => if_update_outputs(node),
         NodeType::GlobalAveragePool => same_as_input(node),
         NodeType::ConvTranspose1d => same_as_input(node),
         NodeType::ConvTranspose2d => same_as_input(node),
@@ -72,20 +109,26 @@ pub fn rank_inference(node: &mut Node) {
         NodeType::LayerNormalization => same_as_input(node),
         NodeType::GroupNormalization => same_as_input(node),
         NodeType::DepthToSpace => depth_to_space_update_outputs(node),
+        NodeType::DequantizeLinear => dequantize_linear_update_outputs(node),
         NodeType::LeakyRelu => same_as_input(node),
         NodeType::Less => elementwise_comparison_outputs(node),
         NodeType::LessOrEqual => elementwise_comparison_outputs(node),
         NodeType::Linear => linear_update_outputs(node),
         NodeType::Log => same_as_input(node),
         NodeType::LogSoftmax => same_as_input(node),
+        NodeType::LSTM => rnn_update_outputs(node),
+        NodeType::Loop => loop_update_outputs(node),
         NodeType::MatMul => matmul_update_outputs(node),
         NodeType::MatMulInteger => matmulinteger_update_outputs(node),
         NodeType::Max => same_as_input_broadcast(node),
         NodeType::MaxPool1d => same_as_input(node),
         NodeType::MaxPool2d => same_as_input(node),
+        NodeType::MaxPool3d => same_as_input(node),
         NodeType::Min => same_as_input_broadcast(node),
         NodeType::Mul => same_as_input_broadcast(node),
         NodeType::Neg => same_as_input(node),
+        NodeType::NonMaxSuppression => non_max_suppression_update_outputs(node),
+        NodeType::NonZero => nonzero_update_outputs(node),
         NodeType::Not => same_as_input(node),
         NodeType::And => same_as_input(node),
         NodeType::Or => same_as_input(node),
@@ -94,6 +137,9 @@ pub fn rank_inference(node: &mut Node) {
         NodeType::Pad => same_as_input(node),
         NodeType::PRelu => same_as_input_broadcast(node),
         NodeType::Pow => same_as_input_broadcast(node),
+        NodeType::QLinearConv => qlinear_conv_update_outputs(node),
+        NodeType::QLinearMatMul => qlinear_matmul_update_outputs(node),
+        NodeType::QuantizeLinear => quantize_linear_update_outputs(node),
         NodeType::RandomNormal => random_update_output(node),
         NodeType::RandomNormalLike => random_like_update_output(node),
         NodeType::RandomUniform => random_update_output(node),
@@ -114,6 +160,9 @@ pub fn rank_inference(node: &mut Node) {
         NodeType::Reshape => reshape_update_outputs(node),
         NodeType::Resize => same_as_input(node),
         NodeType::Round => same_as_input(node),
+        NodeType::Scan => scan_update_outputs(node),
+        NodeType::ScatterElements => same_as_input(node),
+        NodeType::ScatterND => same_as_input(node),
         NodeType::Shape => shape_update_outputs(node),
         NodeType::Sigmoid => same_as_input(node),
         NodeType::Sign => same_as_input(node),